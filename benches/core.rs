@@ -0,0 +1,54 @@
+//! Criterion benchmarks of the Rust core
+//!
+//! Run with `cargo bench`; every group reports throughput in bytes, so
+//! results read as MB/s and regressions show up in Criterion's comparison
+//! with the previous run.
+
+use _durak_core::bench;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+/// Informal Turkish with URLs, emoji and typos, as in the test corpus
+static STORY: &str = include_str!("../tests/data/sample_story.txt");
+
+fn tokenize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tokenize");
+    group.throughput(Throughput::Bytes(STORY.len() as u64));
+    group.bench_function("story", |b| b.iter(|| bench::tokenize(black_box(STORY))));
+    group.finish();
+}
+
+fn normalize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("normalize");
+    group.throughput(Throughput::Bytes(STORY.len() as u64));
+    group.bench_function("story", |b| b.iter(|| bench::normalize(black_box(STORY))));
+    group.finish();
+}
+
+fn lemmatize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lemmatize");
+    group.throughput(Throughput::Bytes(STORY.len() as u64));
+    for (name, maxsize) in [("cached", 100_000), ("uncached", 0)] {
+        bench::set_lemma_cache_size(maxsize);
+        group.bench_function(name, |b| b.iter(|| bench::lemmatize(black_box(STORY))));
+    }
+    bench::set_lemma_cache_size(100_000);
+    group.finish();
+}
+
+fn batch(c: &mut Criterion) {
+    let texts: Vec<String> = STORY.lines().map(str::to_string).collect();
+    let bytes: usize = texts.iter().map(String::len).sum();
+    let mut group = c.benchmark_group("batch");
+    group.throughput(Throughput::Bytes(bytes as u64));
+    for threads in [1, 0] {
+        group.bench_with_input(
+            BenchmarkId::new("threads", threads),
+            &threads,
+            |b, &threads| b.iter(|| bench::batch(black_box(&texts), threads)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, tokenize, normalize, lemmatize, batch);
+criterion_main!(benches);
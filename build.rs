@@ -0,0 +1,134 @@
+//! Build script: precompiles embedded linguistic resources.
+//!
+//! The Turkish lemma dictionary is shipped as a TSV file but is compiled here
+//! into a finite-state transducer (FST) so that the extension embeds a compact,
+//! already-indexed automaton instead of parsing text on first use.
+//!
+//! Outputs (in `OUT_DIR`):
+//! - `lemma_forms.fst`: map from inflected form to a packed (offset, len)
+//!   reference into `lemma_blob.bin`
+//! - `lemma_set.fst`: set of all lemmas (root forms)
+//! - `lemma_blob.bin`: concatenated UTF-8 bytes of all unique lemmas
+//!
+//! Setting `DURAK_LEMMA_TOP_N=<n>` keeps only the forms of the `n` most
+//! frequent lemmas, trading coverage for binary size (WASM, mobile). The
+//! ranking is read from `lemma_frequency.txt`, or from the file named by
+//! `DURAK_LEMMA_FREQUENCIES`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const LEMMA_DICT_PATH: &str = "resources/tr/lemmas/turkish_lemma_dict.txt";
+const LEMMA_FREQUENCY_PATH: &str = "resources/tr/lemmas/lemma_frequency.txt";
+
+/// Lemmas ranked by frequency, most frequent first (one lemma per line)
+fn read_frequency_ranking(path: &str) -> Vec<String> {
+    fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e))
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.split('\t').next().unwrap_or(line).to_string())
+        .collect()
+}
+
+/// Keep only the forms of the `top_n` most frequent lemmas
+fn prune(forms: &mut BTreeMap<String, String>, top_n: usize, ranking: &[String]) {
+    let rank: BTreeMap<&str, usize> = ranking
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(i, lemma)| (lemma.as_str(), i))
+        .collect();
+
+    // Unranked lemmas come last, alphabetically, so the result is deterministic
+    let mut lemmas: Vec<&str> = forms.values().map(String::as_str).collect();
+    lemmas.sort_by_key(|lemma| (rank.get(lemma).copied().unwrap_or(usize::MAX), *lemma));
+    lemmas.dedup();
+
+    let kept: BTreeSet<String> = lemmas.into_iter().take(top_n).map(String::from).collect();
+    forms.retain(|_, lemma| kept.contains(lemma));
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", LEMMA_DICT_PATH);
+    println!("cargo:rerun-if-changed={}", LEMMA_FREQUENCY_PATH);
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=DURAK_LEMMA_TOP_N");
+    println!("cargo:rerun-if-env-changed=DURAK_LEMMA_FREQUENCIES");
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set by cargo");
+    let out_dir = Path::new(&out_dir);
+
+    let raw = fs::read_to_string(LEMMA_DICT_PATH)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", LEMMA_DICT_PATH, e));
+
+    // Format: inflected_form<TAB>lemma
+    // BTreeMap keeps keys sorted (required by the FST builder); later
+    // duplicates override earlier ones, matching the old HashMap behavior.
+    let mut forms: BTreeMap<String, String> = BTreeMap::new();
+    for line in raw.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some((inflected, lemma)) = line.split_once('\t') {
+            forms.insert(inflected.trim().to_string(), lemma.trim().to_string());
+        }
+    }
+
+    let top_n = env::var("DURAK_LEMMA_TOP_N").ok().map(|value| {
+        value
+            .trim()
+            .parse::<usize>()
+            .unwrap_or_else(|_| panic!("DURAK_LEMMA_TOP_N must be an integer, got {:?}", value))
+    });
+    if let Some(top_n) = top_n {
+        let ranking_path =
+            env::var("DURAK_LEMMA_FREQUENCIES").unwrap_or_else(|_| LEMMA_FREQUENCY_PATH.to_string());
+        println!("cargo:rerun-if-changed={}", ranking_path);
+        prune(&mut forms, top_n, &read_frequency_ranking(&ranking_path));
+    }
+
+    let lemmas: BTreeSet<&str> = forms.values().map(String::as_str).collect();
+    // Reported by get_build_info() so pruned builds are identifiable
+    println!(
+        "cargo:rustc-env=DURAK_LEMMA_TOP_N={}",
+        top_n.map_or_else(|| "all".to_string(), |n| n.to_string())
+    );
+    println!("cargo:rustc-env=DURAK_LEMMA_FORMS={}", forms.len());
+
+    // Lay out every unique lemma once in a blob and remember its position
+    let mut blob = Vec::new();
+    let mut positions: BTreeMap<&str, u64> = BTreeMap::new();
+    for lemma in &lemmas {
+        let offset = blob.len() as u64;
+        let len = lemma.len() as u64;
+        blob.extend_from_slice(lemma.as_bytes());
+        positions.insert(lemma, (offset << 32) | len);
+    }
+
+    let mut map_builder = fst::MapBuilder::memory();
+    for (form, lemma) in &forms {
+        map_builder
+            .insert(form, positions[lemma.as_str()])
+            .expect("lemma forms must be inserted in sorted order");
+    }
+    let map_bytes = map_builder.into_inner().expect("Failed to build lemma FST");
+
+    let mut set_builder = fst::SetBuilder::memory();
+    for lemma in &lemmas {
+        set_builder
+            .insert(lemma)
+            .expect("lemmas must be inserted in sorted order");
+    }
+    let set_bytes = set_builder
+        .into_inner()
+        .expect("Failed to build lemma set FST");
+
+    fs::write(out_dir.join("lemma_forms.fst"), map_bytes).expect("Failed to write lemma_forms.fst");
+    fs::write(out_dir.join("lemma_set.fst"), set_bytes).expect("Failed to write lemma_set.fst");
+    fs::write(out_dir.join("lemma_blob.bin"), blob).expect("Failed to write lemma_blob.bin");
+}
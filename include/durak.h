@@ -0,0 +1,54 @@
+/*
+ * durak.h - C interface to the Durak Turkish NLP core
+ *
+ * Link against the static library produced by scripts/build_mobile.sh
+ * (lib_durak_core.a), or the shared library built with
+ * `cargo build --release --no-default-features --features ffi`
+ * (lib_durak_core.so / .dylib / .dll). All strings are NUL-terminated UTF-8.
+ *
+ * Every char * returned by a durak_* function is owned by the caller and
+ * must be released with durak_string_free(); token arrays are released with
+ * durak_tokens_free(). Functions return NULL when the input is NULL or not
+ * valid UTF-8.
+ */
+
+#ifndef DURAK_H
+#define DURAK_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Library version, e.g. "0.4.0". Static string; do not free. */
+const char *durak_version(void);
+
+/* Lowercase text with Turkish I/ı and İ/i handling. */
+char *durak_normalize(const char *text);
+
+/* Lemma of a word: dictionary lookup, then suffix stripping, with the
+ * defaults of the Python Lemmatizer(strategy="hybrid"). */
+char *durak_lemmatize(const char *word);
+
+/* Tokens of a text as a JSON array of strings, e.g. ["Merhaba","dünya","!"]. */
+char *durak_tokenize_json(const char *text);
+
+/*
+ * Tokens of a text as an array of *count strings; release it with
+ * durak_tokens_free(tokens, *count). Returns NULL and sets *count to 0 on
+ * invalid input; returns NULL without writing when count is NULL.
+ */
+char **durak_tokenize(const char *text, size_t *count);
+
+/* Release an array returned by durak_tokenize() and its strings. */
+void durak_tokens_free(char **tokens, size_t count);
+
+/* Release a string returned by a durak_* function. */
+void durak_string_free(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* DURAK_H */
@@ -4,13 +4,48 @@ from __future__ import annotations
 
 from importlib import metadata
 
-from .cleaning import clean_text, collapse_whitespace, normalize_case, normalize_unicode
+from .augment import add_noise, augment, reinflect_cases
+from .batch import (
+    BATCH_ERROR_MODES,
+    SERIES_STAGES,
+    ArrowArray,
+    BatchResult,
+    CancellationToken,
+    lemmatize_arrow,
+    normalize_arrow,
+    process_series,
+    tokenize_arrow,
+)
+from .cleaning import (
+    Deduplicator,
+    clean_text,
+    collapse_whitespace,
+    dedup_lines,
+    fix_pdf_artifacts,
+    isolate_rtl,
+    normalize_case,
+    normalize_unicode,
+    rtl_spans,
+    strip_bidi_controls,
+)
+from .config import PipelineConfig, list_profiles
+from .defaults import configure, get_config, set_config
+from .dialect import DIALECT_VARIETIES, detect_dialect, dialect_flags
+from .etymology import (
+    ETYMOLOGY_ORIGINS,
+    etymology_tags,
+    takes_front_harmony,
+    word_origin,
+)
 from .info import (
     get_bibtex_citation,
     get_build_info,
     get_resource_info,
     print_reproducibility_report,
+    warm_up,
+)
 from .exceptions import (
+    CancelledError,
     ConfigurationError,
     DurakError,
     LemmatizerError,
@@ -22,50 +57,198 @@ from .exceptions import (
     StopwordMetadataError,  # Backward compatibility alias
     TokenizationError,
 )
-from .lemmatizer import Lemmatizer
-from .normalizer import Normalizer
-from .pipeline import Pipeline, process_text, process_text_with_steps
+from .keyboard import (
+    keyboard_confusion_pairs,
+    keyboard_distance,
+    keyboard_edit_distance,
+    keyboard_layout,
+    keyboard_neighbors,
+)
+from .language import SUPPORTED_LANGUAGES, detect_language, is_turkish
+from .lemmatizer import (
+    Lemmatizer,
+    LemmatizerEvaluation,
+    clear_lemma_cache,
+    evaluate_lemmatizer,
+    lemma_cache_info,
+    lemma_candidates,
+    set_lemma_cache_size,
+)
+from .morphology import (
+    POS_TAGS,
+    analyze,
+    analyze_all,
+    check_agreement,
+    generate,
+    pos_tag,
+)
+from .ner import (
+    ENTITY_LABELS,
+    LINK_KINDS,
+    MASK_LABELS,
+    KeywordMatcher,
+    PhraseMatcher,
+    extract_dates,
+    extract_entities,
+    link_pronouns,
+    mask_entities,
+    unmask_entities,
+)
+from .normalizer import (
+    Normalizer,
+    fast_uppercase,
+    normalize_with_alignment,
+    turkish_casefold,
+    unicode_normalize,
+)
+from .numbers import extract_numbers, number_to_words, words_to_number
+from .pipeline import (
+    Pipeline,
+    process,
+    process_text,
+    process_text_with_steps,
+    read_conllu,
+    reannotate_conllu,
+    to_conllu,
+)
+from .readers import NdjsonReader
+from .resources import ResourceRegistry, get_registry
+from .sampling import sample_lines, sample_sentences
+from .similarity import closest_matches, similarity
+from .spell import (
+    SUGGESTION_SOURCES,
+    Suggestion,
+    check_grammar,
+    is_correct,
+    ranked_suggestions,
+    suggest,
+)
+from .stats import (
+    CorpusStats,
+    char_ngrams,
+    formality_score,
+    line_anomalies,
+    morphological_complexity,
+    ngrams,
+    syllabify,
+    term_counts,
+    text_stats,
+)
+from .subword import (
+    BpeModel,
+    UnigramModel,
+    segment_morphemes,
+    segment_word,
+    train_bpe,
+    train_unigram,
+)
+from .stemmer import stem
 from .stopwords import (
     BASE_STOPWORDS,
     DEFAULT_STOPWORD_RESOURCE,
+    StopwordDiff,
     StopwordManager,
     StopwordSnapshot,
+    diff_stopwords,
+    discover_stopwords,
     is_stopword,
+    list_stopword_domains,
     list_stopwords,
+    load_stopword_domain,
     load_stopword_resource,
     load_stopword_resources,
     load_stopwords,
+    merge_stopwords,
     remove_stopwords,
+    stopword_domain,
 )
 from .suffixes import (
     APOSTROPHE_TOKENS,
     DEFAULT_DETACHED_SUFFIXES,
     attach_detached_suffixes,
+    split_clitics,
 )
 from .tokenizer import (
+    Doc,
+    RegexTokenizer,
+    RewriteRules,
+    SegmentationDiff,
+    Token,
+    TokenDoc,
+    TokenPattern,
+    TokenStream,
     Tokenizer,
+    TokenizerConfig,
+    compare_segmentation,
+    detokenize,
     normalize_tokens,
+    pattern_cache_info,
+    pre_tokenize,
+    set_pattern_cache_size,
+    split_hashtag,
     split_sentences,
     tokenize,
+    tokenize_columns,
+    tokenize_for_spacy,
+    tokenize_spans,
     tokenize_text,
     tokenize_with_offsets,
     tokenize_with_normalized_offsets,
+    tokenize_with_types,
+    tokenize_with_whitespace,
 )
+from .worker import WorkerConfig, init_worker, process_documents
 
 __all__ = [
     "__version__",
     "APOSTROPHE_TOKENS",
     "BASE_STOPWORDS",
+    "BATCH_ERROR_MODES",
     "DEFAULT_STOPWORD_RESOURCE",
     "DEFAULT_DETACHED_SUFFIXES",
+    "DIALECT_VARIETIES",
+    "ETYMOLOGY_ORIGINS",
+    "ENTITY_LABELS",
+    "LINK_KINDS",
+    "MASK_LABELS",
+    "POS_TAGS",
+    "SERIES_STAGES",
+    "SUGGESTION_SOURCES",
+    "SUPPORTED_LANGUAGES",
     # Modules
+    "ArrowArray",
+    "BatchResult",
+    "BpeModel",
+    "CancellationToken",
+    "CorpusStats",
+    "Deduplicator",
+    "Doc",
+    "KeywordMatcher",
     "Lemmatizer",
+    "LemmatizerEvaluation",
+    "NdjsonReader",
     "Normalizer",
+    "PhraseMatcher",
     "Pipeline",
+    "PipelineConfig",
+    "RegexTokenizer",
+    "ResourceRegistry",
+    "RewriteRules",
+    "SegmentationDiff",
+    "StopwordDiff",
     "StopwordManager",
     "StopwordSnapshot",
+    "Suggestion",
+    "Token",
+    "TokenDoc",
+    "TokenPattern",
+    "TokenStream",
     "Tokenizer",
+    "TokenizerConfig",
+    "UnigramModel",
+    "WorkerConfig",
     # Exceptions
+    "CancelledError",
     "ConfigurationError",
     "DurakError",
     "LemmatizerError",
@@ -77,31 +260,128 @@ __all__ = [
     "StopwordMetadataError",  # Backward compatibility
     "TokenizationError",
     # Functions
+    "add_noise",
+    "analyze",
+    "analyze_all",
     "attach_detached_suffixes",
+    "augment",
+    "check_agreement",
+    "char_ngrams",
+    "check_grammar",
     "clean_text",
+    "clear_lemma_cache",
+    "closest_matches",
     "collapse_whitespace",
+    "compare_segmentation",
+    "configure",
+    "detect_dialect",
+    "detect_language",
+    "dedup_lines",
+    "detokenize",
+    "dialect_flags",
+    "diff_stopwords",
+    "discover_stopwords",
+    "etymology_tags",
+    "evaluate_lemmatizer",
+    "extract_dates",
+    "extract_entities",
+    "extract_numbers",
+    "fast_uppercase",
+    "fix_pdf_artifacts",
+    "formality_score",
+    "generate",
     "get_bibtex_citation",
     "get_build_info",
+    "get_config",
+    "get_registry",
     "get_resource_info",
+    "init_worker",
+    "isolate_rtl",
+    "is_correct",
+    "is_turkish",
     "is_stopword",
+    "keyboard_confusion_pairs",
+    "keyboard_distance",
+    "keyboard_edit_distance",
+    "keyboard_layout",
+    "keyboard_neighbors",
+    "lemma_cache_info",
+    "lemma_candidates",
+    "lemmatize_arrow",
+    "line_anomalies",
+    "link_pronouns",
+    "list_profiles",
+    "list_stopword_domains",
     "list_stopwords",
+    "load_stopword_domain",
     "load_stopword_resource",
     "load_stopword_resources",
     "load_stopwords",
+    "mask_entities",
+    "merge_stopwords",
+    "morphological_complexity",
+    "ngrams",
+    "normalize_arrow",
     "normalize_case",
     "normalize_tokens",
+    "normalize_with_alignment",
+    "number_to_words",
+    "pattern_cache_info",
+    "pos_tag",
+    "pre_tokenize",
     "print_reproducibility_report",
     "normalize_unicode",
+    "process",
+    "process_documents",
+    "process_series",
     "process_text",
     "process_text_with_steps",
+    "ranked_suggestions",
+    "read_conllu",
+    "reannotate_conllu",
+    "reinflect_cases",
+    "rtl_spans",
     "remove_stopwords",
+    "sample_lines",
+    "sample_sentences",
+    "segment_morphemes",
+    "segment_word",
+    "similarity",
+    "set_config",
+    "set_lemma_cache_size",
+    "set_pattern_cache_size",
+    "split_clitics",
+    "split_hashtag",
     "split_sentences",
+    "stem",
+    "stopword_domain",
+    "strip_bidi_controls",
+    "suggest",
+    "syllabify",
+    "takes_front_harmony",
+    "term_counts",
+    "text_stats",
+    "to_conllu",
     "tokenize",
+    "tokenize_arrow",
+    "tokenize_columns",
+    "tokenize_for_spacy",
+    "tokenize_spans",
     "tokenize_text",
     "tokenize_with_offsets",
     "tokenize_with_normalized_offsets",
+    "tokenize_with_types",
+    "tokenize_with_whitespace",
+    "train_bpe",
+    "train_unigram",
+    "turkish_casefold",
+    "unicode_normalize",
+    "unmask_entities",
     "Tokenizer",
     "TokenizationError",
+    "warm_up",
+    "word_origin",
+    "words_to_number",
 ]
 
 try:
@@ -6,10 +6,16 @@ for Turkish text processing.
 
 from __future__ import annotations
 
+import os
+from collections.abc import Iterable, Iterator, Mapping, Sequence
+from pathlib import Path
+from typing import IO, Literal, overload
+
 def fast_normalize(
     text: str,
     lowercase: bool = True,
     handle_turkish_i: bool = True,
+    unicode_form: str | None = None,
 ) -> str:
     """Fast normalization for Turkish text with configurable options.
 
@@ -20,6 +26,10 @@ def fast_normalize(
         text: The text to normalize
         lowercase: If True, convert text to lowercase (default: True)
         handle_turkish_i: If True, handle Turkish I/ı/İ/i conversion (default: True)
+        unicode_form: Optional Unicode normalization form ("NFC", "NFKC", "NFD"
+            or "NFKD"). Text is composed before the Turkish I rules run, so a
+            decomposed "I" + U+0307 is treated as "İ"; NFD/NFKD output is
+            decomposed after casing. Default: no Unicode normalization.
 
     Returns:
         Normalized text with configurable lowercase and Turkish I handling
@@ -33,19 +43,257 @@ def fast_normalize(
         'istanbul'
         >>> fast_normalize("İSTANBUL", lowercase=False, handle_turkish_i=False)
         'İSTANBUL'
+        >>> fast_normalize("DAG\u0306", unicode_form="NFC")
+        'dağ'
+
+    Raises:
+        ValueError: If unicode_form is not a known normalization form
+    """
+    ...
+
+def normalize_with_alignment(
+    text: str,
+    lowercase: bool = True,
+    handle_turkish_i: bool = True,
+    unicode_form: str | None = None,
+) -> tuple[str, list[int]]:
+    """Normalize as ``fast_normalize`` and keep the way back to the input.
+
+    Returns the normalized text and, for every normalized character, its
+    index in ``text``, followed by ``len(text)``; a span ``[start, end)``
+    found in the normalized text covers ``text[alignment[start]:
+    alignment[end]]``. Casing maps characters one to one; where Unicode
+    normalization changes the length of a character and its combining
+    marks ("g" + U+0306 → "ğ", NFKC "ﬁ" → "fi"), all its output characters
+    point to the start of the original.
+
+    Raises:
+        ValueError: For an unknown normalization form
+
+    Examples:
+        >>> normalize_with_alignment("Dag\u0306 ISTANBUL", unicode_form="NFC")
+        ('dağ ıstanbul', [0, 1, 2, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13])
+    """
+    ...
+
+def fast_uppercase(text: str) -> str:
+    """Uppercase Turkish text: i→İ, ı→I; other characters by Unicode rules.
+
+    Examples:
+        >>> fast_uppercase("istanbul ışık")
+        'İSTANBUL IŞIK'
+    """
+    ...
+
+def turkish_casefold(text: str, mode: str = "fold") -> str:
+    """Turkish-aware case conversion.
+
+    Args:
+        text: Input text
+        mode: "lower" (İ→i, I→ı), "upper" (i→İ, ı→I) or "fold". "fold"
+            lowercases with the Turkish rules and applies full Unicode case
+            folding (ß→ss), giving keys for case-insensitive dictionaries and
+            deduplication. Dotted and dotless i stay distinct letters.
+
+    Returns:
+        Converted text
+
+    Raises:
+        ValueError: If mode is not "lower", "upper" or "fold"
+
+    Examples:
+        >>> turkish_casefold("IŞIK") == turkish_casefold("ışık")
+        True
+        >>> turkish_casefold("kırmızı", "upper")
+        'KIRMIZI'
+    """
+    ...
+
+def unicode_normalize(text: str, form: str = "NFC") -> str:
+    """Apply Unicode normalization to text.
+
+    Text copied from PDFs and web pages often contains decomposed characters
+    ("g" + combining breve instead of "ğ") that do not match the tokenizer's
+    character classes. NFC composes them; NFKC also folds compatibility
+    characters such as ligatures and full-width letters.
+
+    Args:
+        text: Input text
+        form: "NFC", "NFKC", "NFD" or "NFKD" (case-insensitive)
+
+    Returns:
+        Normalized text; casing is unchanged
+
+    Raises:
+        ValueError: If form is not a known normalization form
+
+    Examples:
+        >>> unicode_normalize("Dag\u0306")
+        'Dağ'
+        >>> unicode_normalize("ﬁlm", "NFKC")
+        'film'
+    """
+    ...
+
+def correct_confusions(text: str) -> str:
+    """Replace common Turkish misspellings with their correct spelling.
+
+    Uses the curated confusion pairs embedded from
+    resources/tr/config/confusion_pairs.txt. Matching is whole-word and
+    Turkish case-insensitive; the casing of the original word is preserved.
+
+    Args:
+        text: Input text
+
+    Returns:
+        Text with known misspellings corrected
+
+    Examples:
+        >>> correct_confusions("Herşey yanlız")
+        'Her şey yalnız'
+        >>> correct_confusions("telefonu şarz et")
+        'telefonu şarj et'
+    """
+    ...
+
+def fix_pdf_artifacts(text: str) -> str:
+    """Join words broken by hard line wrapping in PDF-extracted text.
+
+    Soft hyphens (U+00AD) are removed. A hyphen before a line break is
+    dropped unless the next line starts with a capital or both halves are
+    words while the joined form is not ("Türk-Alman"). A line break inside
+    a word without a hyphen is joined only when the joined form is a word
+    and the halves are not both words. Words are validated with the
+    morphological analyzer.
+
+    Args:
+        text: Text extracted from a PDF, with its original line breaks
+
+    Returns:
+        Text with broken words joined; other line breaks are kept
+
+    Examples:
+        >>> fix_pdf_artifacts("kitap-\nları okudum")
+        'kitapları okudum'
+        >>> fix_pdf_artifacts("Türk-\nAlman işbirliği")
+        'Türk-Alman işbirliği'
+    """
+    ...
+
+def rtl_spans(text: str) -> list[tuple[str, str, int, int]]:
+    """Tag the Arabic and Hebrew runs of a text.
+
+    A run is a maximal sequence of characters of one script, including its
+    combining marks; spaces and bidi controls between two of its characters
+    belong to the run, so a quoted phrase is one span.
+
+    Returns:
+        List of (text, script, start, end) with character offsets into
+        ``text``; script is "ARABIC" or "HEBREW"
+
+    Examples:
+        >>> rtl_spans("Ayette «بسم الله» yazar")
+        [('بسم الله', 'ARABIC', 8, 16)]
+    """
+    ...
+
+def isolate_rtl(text: str) -> str:
+    """Make text with right-to-left runs safe to display.
+
+    Bidi controls are removed (an unterminated RLO reverses everything
+    after it) and every Arabic or Hebrew run is wrapped in RLI (U+2067) and
+    PDI (U+2069), so it cannot reorder the Turkish text around it. The
+    result is for display; tokenize the original text for offsets.
+
+    Examples:
+        >>> isolate_rtl("Ayette \u202eبسم الله yazar")
+        'Ayette \u2067بسم الله\u2069 yazar'
+    """
+    ...
+
+def check_grammar(text: str) -> list[tuple[str, str, str, str | None, int, int]]:
+    """Flag frequent Turkish spelling and grammar errors.
+
+    Applies the data-driven rules of resources/tr/config/grammar_rules.txt:
+    the clitic "de/da" written together, hardened or against vowel harmony
+    ("bende geldim", "kitap ta", "ev da"), the suffix "-ki" written apart
+    and the conjunction "ki" written together ("evde ki", "dedimki"), the
+    question particle written together ("geldinmi") and double negation
+    under "ne ... ne ..." ("ne çay ne kahve içmedim").
+
+    Args:
+        text: Input text, any number of sentences
+
+    Returns:
+        List of (text, rule, message, suggestion, start, end) tuples sorted
+        by position, where rule identifies the rule ("de-da-attached"),
+        suggestion is the corrected text in the original casing (None when
+        no fix could be generated) and start/end are character offsets
+
+    Examples:
+        >>> [(t, s) for t, _, _, s, _, _ in check_grammar("Bende geldim.")]
+        [('Bende geldim', 'Ben de geldim')]
+        >>> check_grammar("Geldinmi?")[0][3]
+        'Geldin mi'
+    """
+    ...
+
+def split_joined(token: str) -> list[str]:
+    """Split an accidentally concatenated token into its words.
+
+    Uses dictionary-guided dynamic programming: the token is segmented into
+    the fewest pieces that are all known words (lemma dictionary entries,
+    stopwords or question particles). Pieces keep the original casing.
+
+    Args:
+        token: A single token without whitespace
+
+    Returns:
+        List of words; the token itself as a single element when it is
+        already a known word or cannot be fully segmented
+
+    Examples:
+        >>> split_joined("çokgüzel")
+        ['çok', 'güzel']
+        >>> split_joined("geliyorummusun")
+        ['geliyorum', 'musun']
+        >>> split_joined("kitapta")
+        ['kitapta']
+    """
+    ...
+
+def split_hashtag(tag: str) -> list[str]:
+    """Split a hashtag into lowercase words.
+
+    The leading "#" is dropped and the tag is cut at underscores, camelCase
+    humps, the end of an acronym and letter/digit changes. Each part is
+    lowercased with Turkish I rules and de-concatenated with
+    :func:`split_joined`.
+
+    Examples:
+        >>> split_hashtag("#GünaydınTürkiye")
+        ['günaydın', 'türkiye']
+        >>> split_hashtag("#çokgüzel")
+        ['çok', 'güzel']
+        >>> split_hashtag("#HDPKongresi")
+        ['hdp', 'kongresi']
     """
     ...
 
-def tokenize_with_offsets(text: str) -> list[tuple[str, int, int]]:
+def tokenize_with_offsets(
+    text: str, *, offsets: Literal["char", "byte"] | None = None
+) -> list[tuple[str, int, int]]:
     """Tokenize text and return tokens with their character offsets.
 
     Returns tokens along with their start and end character positions in the
     original text. Offsets are character indices (not byte indices) for Python
-    compatibility.
+    compatibility; ``offsets="byte"`` gives UTF-8 byte offsets instead, and
+    None uses the process-wide default (see :func:`set_config`).
 
     Handles:
     - URLs (http://, https://, www.)
     - Emoticons (:), ;), :D, etc.)
+    - Emoji as whole grapheme clusters (👍🏽, 👨‍👩‍👧, 🇹🇷, 1️⃣)
     - Apostrophes (Turkish possessive/case markers)
     - Numbers (including decimals and ranges)
     - Hyphenated words
@@ -53,11 +301,15 @@ def tokenize_with_offsets(text: str) -> list[tuple[str, int, int]]:
 
     Args:
         text: The text to tokenize
+        offsets: Offset unit, "char" or "byte"
 
     Returns:
         List of (token, start_index, end_index) tuples where indices are
         character positions
 
+    Raises:
+        ValueError: If the offset unit is unknown
+
     Examples:
         >>> tokenize_with_offsets("Merhaba dünya!")
         [('Merhaba', 0, 7), ('dünya', 8, 13), ('!', 13, 14)]
@@ -66,196 +318,2661 @@ def tokenize_with_offsets(text: str) -> list[tuple[str, int, int]]:
     """
     ...
 
-def lookup_lemma(word: str) -> str | None:
-    """Perform exact dictionary lookup for lemmatization.
+def tokenize_with_types(
+    text: str, *, offsets: Literal["char", "byte"] | None = None
+) -> list[tuple[str, str, int, int]]:
+    """Tokenize text and return each token with its type and character offsets.
 
-    Tier 1 lemmatization: Fast exact lookup in the embedded Turkish lemma dictionary.
-    The dictionary contains 1,362+ inflected forms mapped to their base lemmas,
-    loaded from resources/tr/lemmas/turkish_lemma_dict.txt at build time.
+    ``offsets`` works as in :func:`tokenize_with_offsets`.
 
-    Coverage:
-    - High-frequency nouns with case/plural suffixes
-    - Common verb inflections (tense, aspect, person markers)
-    - Systematic vowel harmony patterns (front/back vowel classes)
+    Types are URL, EMAIL, IBAN (Turkish, compact or grouped by four), PHONE
+    (Turkish numbers with a 0 or +90 prefix, "0532 123 45 67"), DATE
+    ("12.03.2024", "3 Mart 2024"), TIME ("14:30", "saat 14:30"), HASHTAG
+    ("#etiket"), MENTION ("@kullanici"), EMOTICON (ASCII ":)"), EMOJI, WORD,
+    NUMBER and PUNCT. A "#" or "@" only starts a hashtag or mention when it
+    does not follow a word character. Phone numbers, grouped IBANs and dates
+    or times with words ("3 Mart 2024", "saat 9") are single tokens that
+    contain spaces; ``extract_dates`` gives their ISO forms.
+    Emoji are whole extended grapheme clusters: skin tones, ZWJ sequences,
+    flags and keycaps are one EMOJI token, not one token per code point.
+    Tokens longer than 128 characters (base64 data, runaway "words") are
+    BLOB, except URLs; lemmatization, analysis and spell checking return
+    such tokens unchanged (or no analyses) without scanning them.
 
-    Args:
-        word: The inflected word to lemmatize
+    Returns:
+        List of (token, type, start_index, end_index) tuples
+
+    Examples:
+        >>> tokenize_with_types("Harika 👍🏽 :)")
+        [('Harika', 'WORD', 0, 6), ('👍🏽', 'EMOJI', 7, 9), (':)', 'EMOTICON', 10, 12)]
+    """
+    ...
+
+def tokenize_columns(
+    text: str,
+) -> tuple[list[str], list[int], list[int], list[str]]:
+    """Tokenize text into parallel columns instead of one tuple per token.
+
+    The same tokens as :func:`tokenize_with_types`, as four lists of equal
+    length (struct-of-arrays). Building four lists is cheaper than one tuple
+    per token, and each list converts directly into a NumPy array or Polars
+    column.
 
     Returns:
-        The base lemma if found in dictionary, None otherwise
+        Tuple of (texts, starts, ends, kinds); offsets are character indices
 
     Examples:
-        >>> lookup_lemma("kitaplar")
-        'kitap'
-        >>> lookup_lemma("geliyorum")
-        'gel'
-        >>> lookup_lemma("unknown")
-        None
+        >>> tokenize_columns("Harika 👍🏽 :)")
+        (['Harika', '👍🏽', ':)'], [0, 7, 10], [6, 9, 12], ['WORD', 'EMOJI', 'EMOTICON'])
+        >>> texts, starts, ends, kinds = tokenize_columns(text)
+        >>> frame = pl.DataFrame({"token": texts, "start": starts, "kind": kinds})
     """
     ...
 
-def strip_suffixes(word: str) -> str:
-    """Heuristic suffix stripping for Turkish morphology.
+def tokenize_with_whitespace(text: str) -> tuple[str, list[tuple[str, str]]]:
+    """Tokenize text keeping the text between tokens.
 
-    Tier 2 lemmatization: Rule-based suffix stripping with basic vowel harmony.
-    Recursively strips common Turkish suffixes while preventing over-stripping
-    of short roots (minimum length constraint).
+    Each token carries the whitespace after it up to the next token, so
+    concatenating the prefix and every token with its trailing text gives
+    back the input exactly. ``durak.TokenDoc.from_text`` wraps the result
+    for masking and removal.
 
-    Args:
-        word: The word to strip suffixes from
+    Returns:
+        Tuple of (prefix, [(token, trailing), ...]); prefix is the text
+        before the first token
+
+    Examples:
+        >>> tokenize_with_whitespace(" Merhaba  dünya!\n")
+        (' ', [('Merhaba', '  '), ('dünya', ''), ('!', '\n')])
+    """
+    ...
+
+def detokenize(tokens: list[str]) -> str:
+    """Join tokens into text with Turkish spacing conventions.
+
+    Tokens are separated by one space, except before closing punctuation
+    (``. , ; : ! ? … ) ] }``) and suffix apostrophes, after opening brackets
+    and the percent sign ("%20"), and inside double quotes. Use
+    ``durak.TokenDoc`` to reconstruct the original spacing exactly.
+
+    Examples:
+        >>> detokenize(["Merhaba", ",", "dünya", "!"])
+        'Merhaba, dünya!'
+        >>> detokenize(["(", "Ankara", "'", "da", ")", "%", "20"])
+        "(Ankara'da) %20"
+    """
+    ...
+
+def tokenize_for_spacy(
+    text: str,
+) -> tuple[list[str], list[bool], list[tuple[int, int]]]:
+    """Tokenize text into the words and spaces of a ``spacy.tokens.Doc``.
+
+    ``spaces[i]`` tells whether ``words[i]`` is followed by a single space.
+    Other whitespace (double spaces, line breaks, leading whitespace) becomes
+    a word of its own, as in spaCy's tokenizer, so ``Doc.text`` equals the
+    input and spaCy character offsets match durak's.
 
     Returns:
-        The word with suffixes removed
+        Tuple of (words, spaces, spans); spans are (start, end) character
+        offsets of the words
 
     Examples:
-        >>> strip_suffixes("kitaplardan")
-        'kitap'
-        >>> strip_suffixes("geliyorum")
-        'gel'
+        >>> tokenize_for_spacy("Merhaba  dünya!")
+        (['Merhaba', ' ', 'dünya', '!'], [True, False, False, False],
+         [(0, 7), (8, 9), (9, 14), (14, 15)])
+        >>> words, spaces, _ = tokenize_for_spacy(text)
+        >>> doc = spacy.tokens.Doc(nlp.vocab, words=words, spaces=spaces)
     """
     ...
 
-def strip_suffixes_validated(
-    word: str,
-    strict: bool = False,
-    min_root_length: int = 2,
-    check_harmony: bool = True
-) -> str:
-    """Strip suffixes with root validation and morphotactic constraints.
+def pre_tokenize(text: str) -> list[tuple[str, tuple[int, int]]]:
+    """Tokenize text with byte offsets, as HuggingFace pre-tokenizers report.
 
-    Advanced lemmatization that validates candidate roots against a dictionary,
-    checks vowel harmony, and ensures morphologically valid suffix ordering.
-    Prevents over-stripping by applying multiple validation layers.
+    The tokens of :func:`tokenize`, each with the (start, end) **byte**
+    offsets of its UTF-8 encoding, matching the offsets of a ``tokenizers``
+    ``PreTokenizedString``. ``durak.huggingface.DurakPreTokenizer`` plugs
+    durak into a ``tokenizers.Tokenizer`` before BPE or WordPiece training.
+
+    Returns:
+        List of (token, (byte_start, byte_end)) tuples
+
+    Examples:
+        >>> pre_tokenize("Çok güzel!")
+        [('Çok', (0, 4)), ('güzel', (5, 11)), ('!', (11, 12))]
+    """
+    ...
+
+def extract_entities(text: str) -> list[tuple[str, str, int, int]]:
+    """Extract named entities using rules and embedded gazetteers.
+
+    Recognizes:
+    - PER: given names from the gazetteer plus following capitalized surnames
+    - LOC: Turkish provinces
+    - ORG: known organizations, or capitalized phrases ending in a head word
+      ("Üniversitesi", "Bakanlığı", "Holding", ...)
+    - DATE: "12 Mart 2024", "12.03.2024", "2024-03-12"
+    - MONEY: "1.250 TL", "₺100", "5 milyon dolar"
+    - URL: http(s):// and www. addresses
+
+    Suffixes after an apostrophe are not part of the entity span.
 
     Args:
-        word: The word to process
-        strict: If True, check dictionary first; if False, use phonotactic rules only
-        min_root_length: Minimum acceptable root length (default: 2)
-        check_harmony: If True, validate vowel harmony before stripping (default: True)
+        text: Input text
 
     Returns:
-        The word with validated suffix stripping
+        List of (text, label, start, end) tuples sorted by position, where
+        start/end are character offsets into the input
 
     Examples:
-        >>> strip_suffixes_validated("kitaplardan")
-        'kitap'
-        >>> strip_suffixes_validated("geliyorum", strict=True)
-        'gel'
-        >>> strip_suffixes_validated("evlerimizden", check_harmony=True)
-        'ev'
+        >>> extract_entities("Ahmet Yılmaz İstanbul'a gitti")
+        [('Ahmet Yılmaz', 'PER', 0, 12), ('İstanbul', 'LOC', 13, 21)]
     """
     ...
 
-def check_vowel_harmony_py(root: str, suffix: str) -> bool:
-    """Check if a suffix harmonizes with a root word.
+def link_pronouns(
+    text: str, window: int = 2
+) -> list[tuple[str, str, int, int, str, int, int]]:
+    """Link pronouns and dropped subjects to earlier names (co-reference lite).
 
-    Validates Turkish vowel harmony rules between a root word and a suffix.
-    Turkish vowel harmony requires suffixes to match the vowel category
-    (front/back and rounded/unrounded) of the root word's last vowel.
+    A heuristic linker for summarization and information extraction, not a
+    full co-reference resolver. Each anaphor is tied to the most recent
+    person or organization found by ``extract_entities``, at most
+    ``window`` sentences back:
+
+    - PRONOUN: 3rd person singular "o" and reflexive "kendisi" in any case
+      ("onu", "ona", "kendisine"); "o" before a noun or adjective is a
+      demonstrative ("o kitap") and is skipped
+    - ZERO: a dropped subject, reported as the finite 3rd person singular
+      verb closing a clause that has no name, subject pronoun or bare noun
+      ("Bugün eve gitti.")
+
+    Plural and 1st/2nd person forms are not linked, and an anaphor never
+    links to another anaphor.
 
     Args:
-        root: The root word to check
-        suffix: The suffix to validate against the root
+        text: Input text
+        window: How many sentences back an antecedent may be (0: same
+            sentence only)
 
     Returns:
-        True if the suffix harmonizes with the root, False otherwise
+        List of (text, kind, start, end, antecedent, antecedent_start,
+        antecedent_end) tuples in order of appearance, with character
+        offsets into the input
 
     Examples:
-        >>> check_vowel_harmony_py("ev", "ler")  # Back vowel + front vowel suffix
-        False
-        >>> check_vowel_harmony_py("ev", "lar")  # Back vowel + back vowel suffix
-        True
-        >>> check_vowel_harmony_py("kitap", "de")
-        True
+        >>> link_pronouns("Ayşe Kaya dün geldi. Bugün eve gitti. Onu çok özledik.")
+        [('gitti', 'ZERO', 31, 36, 'Ayşe Kaya', 0, 9), ('Onu', 'PRONOUN', 38, 41, 'Ayşe Kaya', 0, 9)]
     """
     ...
 
-def get_detached_suffixes() -> list[str]:
-    """Get embedded detached suffixes list.
+def extract_dates(text: str) -> list[tuple[str, str, str, int, int]]:
+    """Extract dates and times in Turkish formats with ISO 8601 forms.
 
-    Returns the list of Turkish detached suffixes compiled into the binary
-    from resources/tr/labels/DETACHED_SUFFIXES.txt at build time.
+    Recognizes:
+    - DATE: "2024-03-12", "12.03.2024", "12/03/2024" (day first),
+      "12 Mart 2024", "12 Mart" ("--03-12") and "Mart 2024" ("2024-03")
+    - TIME: "14:30", "14:30:15", "saat 14:30", "saat 14.30", "saat 9"
+      ("09:00")
+
+    The tokenizer keeps the same spans as single DATE and TIME tokens.
+    Dates the calendar does not have ("31.02.2024") are skipped here.
+
+    Args:
+        text: Input text
 
     Returns:
-        List of detached suffix strings
+        List of (text, label, iso, start, end) tuples sorted by position,
+        where start/end are character offsets into the input
 
     Examples:
-        >>> suffixes = get_detached_suffixes()
-        >>> 'da' in suffixes
-        True
-        >>> 'de' in suffixes
-        True
+        >>> extract_dates("3 Mart 2024")
+        [('3 Mart 2024', 'DATE', '2024-03-03', 0, 11)]
+        >>> extract_dates("saat 9'da")
+        [('saat 9', 'TIME', '09:00', 0, 6)]
     """
     ...
 
-def get_stopwords_base() -> list[str]:
-    """Get embedded Turkish base stopwords list.
+def extract_numbers(text: str) -> list[tuple[str, int, int, int]]:
+    """Extract numbers spelled out in Turkish words with their values.
 
-    Returns the base Turkish stopwords compiled into the binary
-    from resources/tr/stopwords/base/turkish.txt at build time.
+    Runs of number words are read as the longest numbers they spell, so
+    "iki üç" gives 2 and 3. A lone word that is also an everyday word
+    ("bir", "on", "altı", "yüz", "bin") is skipped.
+
+    Args:
+        text: Input text
 
     Returns:
-        List of Turkish stopwords
+        List of (text, value, start, end) tuples sorted by position,
+        where start/end are character offsets into the input
 
     Examples:
-        >>> stopwords = get_stopwords_base()
-        >>> 've' in stopwords
-        True
-        >>> 'ama' in stopwords
-        True
+        >>> extract_numbers("Üç yüz kırk iki kişi geldi")
+        [('Üç yüz kırk iki', 342, 0, 15)]
     """
     ...
 
-def get_stopwords_metadata() -> str:
-    """Get embedded stopwords metadata JSON.
+def detect_dialect(text: str) -> list[tuple[str, str, str | None, int, int]]:
+    """Detect dialectal spellings and Azerbaijani-influenced forms.
 
-    Returns the stopwords metadata JSON compiled into the binary
-    from resources/tr/stopwords/metadata.json at build time.
+    Uses the cue lexicon embedded from resources/tr/config/dialect_cues.txt:
+    whole words ("gız", "çox"), word-final dialectal suffixes ("gidecağam",
+    "geliyrum") and words with the Azerbaijani letter "ə".
+
+    Args:
+        text: Input text
 
     Returns:
-        JSON string containing stopword metadata
+        List of (text, variety, standard, start, end) tuples sorted by
+        position, where variety is AZERBAIJANI, ANATOLIAN, EASTERN or
+        BLACK_SEA, standard is the standard spelling in the casing of the
+        text (None when unknown) and start/end are character offsets
 
     Examples:
-        >>> import json
-        >>> metadata = json.loads(get_stopwords_metadata())
-        >>> 'sets' in metadata
+        >>> detect_dialect("Gız bööle dedi")
+        [('Gız', 'ANATOLIAN', 'Kız', 0, 3), ('bööle', 'ANATOLIAN', 'böyle', 4, 9)]
+    """
+    ...
+
+def dialect_flags(tokens: list[str]) -> list[str | None]:
+    """Dialect variety of each token, None for tokens without a cue.
+
+    Examples:
+        >>> dialect_flags(["çox", "güzel", "gidecağam"])
+        ['AZERBAIJANI', None, 'EASTERN']
+    """
+    ...
+
+def word_origin(word: str) -> str | None:
+    """Loanword origin of a word: ARABIC, PERSIAN, FRENCH or ENGLISH.
+
+    Uses the lexicon embedded from resources/tr/config/etymology.txt.
+    Inflected words are tagged through their root (the analyzer's, then the
+    suffix stripper's). Native words and unlisted loanwords give None.
+
+    Examples:
+        >>> word_origin("Kitaplarımız")
+        'ARABIC'
+        >>> word_origin("ev") is None
         True
     """
     ...
 
-def get_stopwords_social_media() -> list[str]:
-    """Get embedded social media stopwords.
+def etymology_tags(tokens: list[str]) -> list[str | None]:
+    """Loanword origin of each token, None for tokens without one.
 
-    Returns the social media domain-specific stopwords compiled into the binary
-    from resources/tr/stopwords/domains/social_media.txt at build time.
+    Examples:
+        >>> etymology_tags(["Saatler", "evde", "telefonla"])
+        ['ARABIC', None, 'FRENCH']
+    """
+    ...
+
+def takes_front_harmony(root: str) -> bool:
+    """Whether a lowercase root's suffixes take front vowels against its last
+    vowel, as for loanwords with a palatal final consonant ("saat" → "saatler",
+    "gol" → "golü"). The analyzer and ``check_vowel_harmony_py`` honor these.
+    """
+    ...
+
+def detect_language(text: str) -> tuple[str, float]:
+    """Detect the language of a text with character trigram profiles.
+
+    Profiles of Turkish, Azerbaijani, Kurmanji Kurdish, English, German,
+    French, Spanish and Italian are embedded at build time
+    (resources/tr/config/language_profiles.txt).
+
+    Args:
+        text: Input text; a line or a whole document
 
     Returns:
-        List of social media stopwords
+        (language, confidence) with an ISO 639-1 code and the posterior
+        probability of that language, or ("und", 0.0) when the text has no
+        trigram of any profile (digits only, other scripts)
 
     Examples:
-        >>> sm_stopwords = get_stopwords_social_media()
-        >>> len(sm_stopwords) > 0
-        True
+        >>> detect_language("Bugün hava çok güzel")[0]
+        'tr'
+        >>> detect_language("12345")
+        ('und', 0.0)
+    """
+    ...
+
+def is_turkish(text: str, threshold: float = 0.5) -> bool:
+    """Whether a text is Turkish with at least `threshold` confidence.
+
+    A fast filter for dropping non-Turkish lines before expensive
+    processing, see detect_language().
+
+    Examples:
+        >>> is_turkish("Toplantı yarın saat onda")
+        True
+        >>> is_turkish("The meeting is tomorrow")
+        False
+    """
+    ...
+
+def number_to_words(n: int) -> str:
+    """Spell out a non-negative integer in Turkish words.
+
+    "bir" is left out before "yüz" and "bin" but kept before larger scales.
+
+    Examples:
+        >>> number_to_words(1250)
+        'bin iki yüz elli'
+        >>> number_to_words(1000000)
+        'bir milyon'
+    """
+    ...
+
+def words_to_number(text: str) -> int:
+    """Read a number spelled in Turkish words.
+
+    Case is ignored and the solid spelling of cheques ("binikiyüzelli") is
+    accepted.
+
+    Raises:
+        ValueError: If the words do not spell a number ("bir yüz", "iki iki")
+
+    Examples:
+        >>> words_to_number("üç yüz kırk iki")
+        342
+    """
+    ...
+
+def lookup_lemma(word: str) -> str | None:
+    """Perform exact dictionary lookup for lemmatization.
+
+    Tier 1 lemmatization: Fast exact lookup in the embedded Turkish lemma dictionary.
+    The dictionary contains 1,362+ inflected forms mapped to their base lemmas,
+    compiled from resources/tr/lemmas/turkish_lemma_dict.txt into a finite-state
+    transducer (FST) at build time.
+
+    Coverage:
+    - High-frequency nouns with case/plural suffixes
+    - Common verb inflections (tense, aspect, person markers)
+    - Systematic vowel harmony patterns (front/back vowel classes)
+
+    Args:
+        word: The inflected word to lemmatize
+
+    Returns:
+        The base lemma if found in dictionary, None otherwise
+
+    Examples:
+        >>> lookup_lemma("kitaplar")
+        'kitap'
+        >>> lookup_lemma("geliyorum")
+        'gel'
+        >>> lookup_lemma("unknown")
+        None
+    """
+    ...
+
+def lookup_lemma_prefix(
+    prefix: str, limit: int | None = None
+) -> list[tuple[str, str]]:
+    """Prefix query over the embedded lemma dictionary.
+
+    Streams dictionary entries whose inflected form starts with ``prefix``
+    directly from the FST, in lexicographic order.
+
+    Args:
+        prefix: Prefix of the inflected form (empty string matches everything)
+        limit: Maximum number of entries to return (default: no limit)
+
+    Returns:
+        List of (inflected_form, lemma) tuples
+
+    Examples:
+        >>> lookup_lemma_prefix("kitap", limit=2)
+        [('kitaplar', 'kitap'), ('kitapları', 'kitap')]
+        >>> lookup_lemma_prefix("xyz")
+        []
+    """
+    ...
+
+def strip_suffixes(word: str) -> str:
+    """Heuristic suffix stripping for Turkish morphology.
+
+    Tier 2 lemmatization: Rule-based suffix stripping with basic vowel harmony.
+    Recursively strips common Turkish suffixes while preventing over-stripping
+    of short roots (minimum length constraint).
+
+    Args:
+        word: The word to strip suffixes from
+
+    Returns:
+        The word with suffixes removed
+
+    Examples:
+        >>> strip_suffixes("kitaplardan")
+        'kitap'
+        >>> strip_suffixes("geliyorum")
+        'gel'
+    """
+    ...
+
+def strip_suffixes_validated(
+    word: str,
+    strict: bool = False,
+    min_root_length: int | None = None,
+    check_harmony: bool | None = None,
+    preserve_proper_nouns: bool = True,
+) -> str:
+    """Strip suffixes with root validation and morphotactic constraints.
+
+    Advanced lemmatization that validates candidate roots against a dictionary,
+    checks vowel harmony, and ensures morphologically valid suffix ordering.
+    Prevents over-stripping by applying multiple validation layers.
+
+    A word with an apostrophe is a proper noun whose suffixes follow the
+    apostrophe (Ankara'dan): only those are stripped and the name keeps its
+    casing, as split by ``split_clitics``.
+
+    Args:
+        word: The word to process
+        strict: If True, check dictionary first; if False, use phonotactic rules only
+        min_root_length: Minimum acceptable root length; None uses the
+            process-wide default (2 unless changed with :func:`set_config`)
+        check_harmony: If True, validate vowel harmony before stripping; None
+            uses the process-wide default (True unless changed)
+        preserve_proper_nouns: If True, strip only what follows an apostrophe
+            (default: True)
+
+    Returns:
+        The word with validated suffix stripping
+
+    Examples:
+        >>> strip_suffixes_validated("kitaplardan")
+        'kitap'
+        >>> strip_suffixes_validated("geliyorum", strict=True)
+        'gel'
+        >>> strip_suffixes_validated("evlerimizden", check_harmony=True)
+        'ev'
+        >>> strip_suffixes_validated("Ankara'dan")
+        'Ankara'
+    """
+    ...
+
+def stem(
+    word: str,
+    max_suffixes: int | None = None,
+    *,
+    method: Literal["cascade", "prefix"] = "cascade",
+    prefix_length: int = 5,
+) -> str:
+    """Aggressive stem of a word for search indexing.
+
+    Stems are not lemmas: no dictionary is consulted, and unrelated words
+    may share a stem ("okul" → "ok"). Use :class:`Lemmatizer` when the
+    result must be a real word. The word is lowercased with Turkish I rules,
+    and a proper noun is cut at its apostrophe and nothing more.
+
+    Args:
+        word: Word to stem
+        max_suffixes: Most suffixes ``cascade`` strips; None strips all
+        method: ``cascade`` strips known suffixes longest first while the
+            stem keeps two characters and a vowel; ``prefix`` keeps the
+            first ``prefix_length`` characters
+        prefix_length: Characters kept by ``prefix``
+
+    Raises:
+        ValueError: Unknown method
+
+    Examples:
+        >>> stem("kitaplarımızdan")
+        'kitap'
+        >>> stem("kitaplarımızdan", max_suffixes=1)
+        'kitaplarımız'
+        >>> stem("Bilgisayarlar", method="prefix")
+        'bilgi'
+        >>> stem("Ankara'dan")
+        'ankara'
+    """
+    ...
+
+def check_vowel_harmony_py(root: str, suffix: str) -> bool:
+    """Check if a suffix harmonizes with a root word.
+
+    Validates Turkish vowel harmony rules between a root word and a suffix.
+    Turkish vowel harmony requires suffixes to match the vowel category
+    (front/back and rounded/unrounded) of the root word's last vowel.
+
+    Args:
+        root: The root word to check
+        suffix: The suffix to validate against the root
+
+    Returns:
+        True if the suffix harmonizes with the root, False otherwise
+
+    Examples:
+        >>> check_vowel_harmony_py("ev", "ler")  # Back vowel + front vowel suffix
+        False
+        >>> check_vowel_harmony_py("ev", "lar")  # Back vowel + back vowel suffix
+        True
+        >>> check_vowel_harmony_py("kitap", "de")
+        True
+    """
+    ...
+
+def analyze(word: str) -> list[tuple[str, str, list[tuple[str, str]]]]:
+    """Morphologically analyze a word.
+
+    Finds every segmentation of the word into a dictionary root and a
+    morphotactically valid suffix chain. Suffixes carry Oflazer-style tags
+    (Pl, P1sg, Loc, Past, Prog1, A1sg, ...). Vowel harmony, buffer letters
+    and consonant softening (kitap → kitabı) are handled.
+
+    Args:
+        word: Word to analyze
+
+    Returns:
+        List of (root, root_pos, [(tag, surface), ...]) parses, preferred
+        parse first (longest root, then fewest suffixes). Empty if the word
+        cannot be analyzed.
+
+    Examples:
+        >>> analyze("kitaplarımdan")
+        [('kitap', 'Noun', [('Pl', 'lar'), ('P1sg', 'ım'), ('Abl', 'dan')])]
+        >>> analyze("geliyorum")[0]
+        ('gel', 'Verb', [('Prog1', 'iyor'), ('A1sg', 'um')])
+    """
+    ...
+
+def analyze_all(word: str) -> list[tuple[str, str, list[tuple[str, str]], float]]:
+    """Analyze a word and rank its segmentations by probability.
+
+    Returns the same parses as analyze(), each with the probability of that
+    reading, most probable first. Probabilities come from embedded suffix
+    frequency statistics: after the root and after each suffix the word
+    either ends (with the suffix's word-final share) or takes one of the
+    suffixes allowed next, in proportion to their frequencies. They sum to 1.
+    Parses with the same tags on different readings of a root tie.
+
+    Args:
+        word: Word to analyze
+
+    Returns:
+        List of (root, root_pos, [(tag, surface), ...], probability) tuples;
+        empty if the word cannot be analyzed
+
+    Examples:
+        >>> [(tags, round(p, 2)) for _, _, tags, p in analyze_all("evin")]
+        [([('Gen', 'in')], 0.94), ([('P2sg', 'in')], 0.06)]
+    """
+    ...
+
+def check_agreement(text: str) -> list[tuple[str, str, str, str, int, int]]:
+    """Flag person and possessor agreement errors.
+
+    - POSSESSOR: a genitive possessor (benim, senin, Ali'nin, ...) and the
+      possessive suffix of its head noun disagree ("benim arabası")
+    - PERSON: a subject pronoun and the person ending of the finite verb
+      closing its clause disagree ("ben geldin")
+
+    Words are only flagged when none of their analyses agrees; heads
+    without a possessive ("bizim ev") and clauses without a finite verb
+    are not judged. Useful for grammar checking and for spotting erroneous
+    or machine-generated text.
+
+    Args:
+        text: Input text, any number of sentences
+
+    Returns:
+        List of (text, kind, expected, found, start, end) tuples sorted by
+        position, where expected and found are suffix tags ("P1sg",
+        "A2sg"; unmarked 3rd person singular verbs are "A3sg") and
+        start/end are character offsets of the span from the controlling
+        word to the disagreeing one
+
+    Examples:
+        >>> check_agreement("Benim arabası bozuldu.")
+        [('Benim arabası', 'POSSESSOR', 'P1sg', 'P3sg', 0, 13)]
+        >>> check_agreement("Ben dün geldin.")
+        [('Ben dün geldin', 'PERSON', 'A1sg', 'A2sg', 0, 14)]
+    """
+    ...
+
+def generate(root: str, suffix_tags: list[str], pos: str | None = None) -> str:
+    """Generate a surface form from a root and suffix tags.
+
+    The inverse of analyze(): suffixes are attached with vowel harmony,
+    buffer letters and consonant softening (p→b, ç→c, t→d, k→ğ). Tags are
+    the ones analyze() returns (Pl, P1sg, Loc, Neg, Fut, A1sg, ...).
+
+    Args:
+        root: Root to inflect
+        suffix_tags: Suffix tags in order
+        pos: Part of speech of the root (Noun, Adj, Verb, Pron). Known roots
+            are tried with each of their readings by default; unknown roots
+            are treated as nouns.
+
+    Returns:
+        The inflected surface form
+
+    Raises:
+        ValueError: If a tag is unknown, the tag sequence is not allowed by
+            Turkish morphotactics, or pos is not a known part of speech
+
+    Examples:
+        >>> generate("kitap", ["P1sg", "Loc"])
+        'kitabımda'
+        >>> generate("git", ["Fut", "A1sg"])
+        'gideceğim'
+    """
+    ...
+
+def morphological_complexity(text: str) -> dict[str, float]:
+    """Compute morphological complexity metrics for a document.
+
+    Uses the preferred analysis of each word token.
+
+    Args:
+        text: Input text
+
+    Returns:
+        Dictionary with keys:
+        - words: number of word tokens
+        - analyzed: number of tokens the analyzer could parse
+        - coverage: analyzed / words
+        - mean_morphemes_per_word: mean root + suffix count
+        - suffix_entropy: Shannon entropy (bits) of suffix tags
+        - verb_noun_ratio: verbs / nouns by final part of speech
+
+    Examples:
+        >>> stats = morphological_complexity("Kitaplarımdan birini okudum.")
+        >>> stats["mean_morphemes_per_word"] > 1
+        True
+    """
+    ...
+
+def formality_score(text: str) -> dict[str, float]:
+    """Score formal vs. informal register from lexical cues.
+
+    Informal cues are slang, interjections and chat abbreviations (kanka,
+    valla, tmm), 2nd person singular pronouns and finite verbs (sen,
+    geldin), contracted verb forms (geliyom, gelcem) and emoji. Each word
+    counts as at most one cue. Useful for routing customer messages and
+    stratifying corpora by register.
+
+    Args:
+        text: Input text
+
+    Returns:
+        Dictionary with keys:
+        - words: number of word tokens
+        - slang, second_person, contractions, emoji: cue counts
+        - emoji_density: emoji per word
+        - formality: exp(-5 * cues / words), 1.0 without informal cues
+
+    Examples:
+        >>> formality_score("Toplantı yarın saat onda yapılacaktır.")["formality"]
+        1.0
+        >>> formality_score("kanka geliyom bekle 😂")["formality"] < 0.01
+        True
+    """
+    ...
+
+def text_stats(text: str) -> dict[str, float]:
+    """Size and readability statistics of a text.
+
+    Built on the tokenizer, the sentence splitter and the syllabifier.
+    Readability is Ateşman's formula for Turkish:
+    198.825 - 40.175 * syllables per word - 2.610 * words per sentence,
+    from about 90-100 (very easy) to below 30 (very difficult).
+
+    Args:
+        text: Input text, any number of sentences
+
+    Returns:
+        Dictionary with keys:
+        - tokens: tokenizer tokens, punctuation and emoji included
+        - words: word tokens
+        - types: distinct lowercased words
+        - sentences: number of sentences
+        - avg_word_length: mean characters per word
+        - avg_syllables_per_word: mean syllables per word
+        - avg_sentence_length: mean words per sentence
+        - atesman: Ateşman readability score (0.0 without words)
+
+    Examples:
+        >>> stats = text_stats("Ali okula gitti. Ali eve geldi.")
+        >>> stats["words"], stats["types"], stats["sentences"]
+        (6.0, 5.0, 2.0)
+    """
+    ...
+
+def line_anomalies(text: str) -> list[dict[str, float]]:
+    """Score each line of a text for spam, table and markup artifacts.
+
+    Args:
+        text: Input text; every line is scored, empty lines included
+
+    Returns:
+        One dictionary per line with keys:
+        - punctuation: punctuation and non-emoji symbols per non-space char
+        - uppercase: uppercase letters per letter (0.0 below 8 letters, so
+          acronyms pass)
+        - digits: digits per non-space character
+        - whitespace: whitespace per character
+        - anomaly: the largest statistic relative to its limit for running
+          text (0.2, 0.6, 0.3 and 0.35); above 1.0 flags the line
+
+    Examples:
+        >>> [s["anomaly"] > 1 for s in line_anomalies("Merhaba dünya.\n!!!!!!")]
+        [False, True]
+    """
+    ...
+
+def syllabify(word: str) -> list[str]:
+    """Split a word into syllables.
+
+    Every syllable holds one vowel and a single consonant before a vowel
+    opens the next syllable; a word without vowels is one syllable.
+
+    Examples:
+        >>> syllabify("kitaplar")
+        ['ki', 'tap', 'lar']
+        >>> syllabify("Türkçe")
+        ['Türk', 'çe']
+    """
+    ...
+
+@overload
+def ngrams(
+    tokens: list[str], n: int = 2, *, counts: Literal[False] = False
+) -> list[tuple[str, ...]]: ...
+@overload
+def ngrams(
+    tokens: list[str], n: int = 2, *, counts: Literal[True]
+) -> dict[tuple[str, ...], int]: ...
+def ngrams(
+    tokens: list[str], n: int = 2, *, counts: bool = False
+) -> list[tuple[str, ...]] | dict[tuple[str, ...], int]:
+    """Word n-grams of a token sequence.
+
+    Args:
+        tokens: Tokens, e.g. from ``tokenize``
+        n: N-gram size (default: 2)
+        counts: Return a dict of n-gram → count instead of the n-grams;
+            most frequent first, ties in order of first appearance
+
+    Returns:
+        N-grams as tuples in order, or their counts
+
+    Raises:
+        ValueError: If n is 0
+
+    Examples:
+        >>> ngrams(["ben", "de", "geldim"])
+        [('ben', 'de'), ('de', 'geldim')]
+        >>> ngrams(["a", "b", "a", "b"], counts=True)
+        {('a', 'b'): 2, ('b', 'a'): 1}
+    """
+    ...
+
+@overload
+def char_ngrams(
+    text: str, n: int = 2, *, counts: Literal[False] = False
+) -> list[str]: ...
+@overload
+def char_ngrams(text: str, n: int = 2, *, counts: Literal[True]) -> dict[str, int]: ...
+def char_ngrams(
+    text: str, n: int = 2, *, counts: bool = False
+) -> list[str] | dict[str, int]:
+    """Character n-grams of a text, keeping whitespace and punctuation.
+
+    Args:
+        text: Input text
+        n: N-gram size (default: 2)
+        counts: Return a dict of n-gram → count instead of the n-grams;
+            most frequent first, ties in order of first appearance
+
+    Returns:
+        N-grams as strings in order, or their counts
+
+    Raises:
+        ValueError: If n is 0
+
+    Examples:
+        >>> char_ngrams("kitap", 3)
+        ['kit', 'ita', 'tap']
+        >>> char_ngrams("anana", counts=True)
+        {'an': 2, 'na': 2}
+    """
+    ...
+
+def pos_tag(tokens: list[str]) -> list[tuple[str, str]]:
+    """Assign coarse part-of-speech tags to tokens.
+
+    Punctuation and numerals are recognized by shape, frequent adverbs,
+    pronouns and number words by a closed-class lexicon, and everything else
+    by the final part of speech of its preferred morphological analysis.
+    Verb forms ending in a converb (gelip, gelince, gelerek) are adverbs.
+    Words the analyzer cannot parse are tagged from their ending (-yor → Verb,
+    -lı → Adj, -ca → Adv), defaulting to Noun.
+
+    Args:
+        tokens: Tokens to tag, e.g. the output of tokenize()
+
+    Returns:
+        List of (token, tag) pairs, one per input token. Tags are Noun, Verb,
+        Adj, Adv, Pron, Punc and Num.
+
+    Examples:
+        >>> pos_tag(["Ben", "yarın", "gideceğim", "."])
+        [('Ben', 'Pron'), ('yarın', 'Adv'), ('gideceğim', 'Verb'), ('.', 'Punc')]
+    """
+    ...
+
+def is_correct(word: str) -> bool:
+    """Check whether a word is spelled correctly.
+
+    A word is correct when the morphological analyzer can parse it (any
+    valid inflection of a known root) or it is a stopword or question
+    particle. Tokens without letters and capitalized tokens with an
+    apostrophe suffix (proper nouns such as "Ankara'da") are accepted.
+
+    Args:
+        word: Word to check
+
+    Returns:
+        True if the word is spelled correctly
+
+    Examples:
+        >>> is_correct("kitaplarımdan")
+        True
+        >>> is_correct("geliyorm")
+        False
+    """
+    ...
+
+def suggest(word: str, max_edits: int = 2, k: int = 5) -> list[str]:
+    """Suggest spelling corrections for a word.
+
+    Candidates are inflections generated from known roots, so they always
+    respect vowel harmony and suffix order. Missing diacritics (ç/c, ğ/g,
+    ı/i, ö/o, ş/s, ü/u) cost no edit and are only used to break ties.
+
+    Args:
+        word: Word to correct
+        max_edits: Maximum edit distance ignoring diacritics (default: 2)
+        k: Maximum number of suggestions (default: 5)
+
+    Returns:
+        Up to k lowercase suggestions, best first. A correctly spelled word
+        is returned as its only suggestion.
+
+    Examples:
+        >>> suggest("cocugu", k=3)
+        ['çocuğu', 'çocuğa', 'çocuğum']
+        >>> "gelmiyorum" in suggest("gelmiyrum")
+        True
+    """
+    ...
+
+def ranked_suggestions(
+    word: str, max_edits: int = 2, k: int = 5
+) -> list[tuple[str, float, str]]:
+    """Rank correction candidates from spelling, deascii and confusion pairs.
+
+    Each candidate is scored by the Zipf frequency of its root times the
+    likelihood of its edits; neighbouring-key typos and missing diacritics
+    are likelier than other edits. Scores are normalized over all candidates.
+
+    Args:
+        word: Word to correct
+        max_edits: Maximum edit distance ignoring diacritics (default: 2)
+        k: Maximum number of suggestions (default: 5)
+
+    Returns:
+        Up to k (text, score, source) tuples with descending scores, where
+        source is "spell", "deascii" or "confusion"
+
+    Examples:
+        >>> ranked_suggestions("yanlız", k=1)
+        [('yalnız', 0.99..., 'confusion')]
+    """
+    ...
+
+@overload
+def process_series(
+    values: Iterable[object],
+    stage: str,
+    *,
+    errors: Literal["raise"] = "raise",
+    cancel: CancellationToken | None = None,
+) -> list[str | list[str] | None]: ...
+@overload
+def process_series(
+    values: Iterable[object],
+    stage: str,
+    *,
+    errors: Literal["isolate"],
+    cancel: CancellationToken | None = None,
+) -> list[BatchResult]: ...
+def process_series(
+    values: Iterable[object],
+    stage: str,
+    *,
+    errors: str = "raise",
+    cancel: CancellationToken | None = None,
+) -> list[str | list[str] | None] | list[BatchResult]:
+    """Apply a processing stage to every value of a column without the GIL.
+
+    Stages: normalize, correct_confusions, tokenize (token lists),
+    lemmatize (hybrid Lemmatizer defaults). Missing values (None, NaN,
+    pd.NA) map to None at the same position. With ``errors="isolate"``
+    each value comes back as a ``BatchResult``. Ctrl-C or a cancelled
+    ``cancel`` token stops the call as in ``lemmatize_batch``.
+
+    Raises:
+        ValueError: If stage or errors is unknown
+        TypeError: Under ``errors="raise"``, if a value is neither a string
+            nor missing, or a str instead of an iterable is passed
+        CancelledError: If ``cancel`` is cancelled during the call
+
+    Examples:
+        >>> process_series(["kitaplar", None], "lemmatize")
+        ['kitap', None]
+    """
+    ...
+
+class Token:
+    """A token of a :class:`Doc` with its offsets and type.
+
+    For ``Doc(text)``, ``norm``, ``is_stop`` and ``lemma`` are computed in
+    Rust on first access and kept, so tokens that are never asked for them
+    cost nothing extra; :func:`process` fills them in up front.
+
+    Attributes:
+        text: Token text as it appears in the input
+        start: Character offset of the first character
+        end: Character offset just past the last character
+        kind: Token type as in :func:`tokenize_with_types`
+        norm: Text lowercased with Turkish I/ı handling
+        is_stop: Whether ``norm`` is a base Turkish stopword
+        lemma: ``Lemmatizer()`` lemma of ``norm``; proper nouns lose their
+            apostrophe suffixes and tokens other than words keep ``norm``
+
+    Examples:
+        >>> token = Doc("İstanbul'a geldim")[0]
+        >>> token.text, token.start, token.end, token.kind
+        ("İstanbul'a", 0, 10, 'WORD')
+        >>> token.norm, token.lemma
+        ("istanbul'a", 'istanbul')
+    """
+
+    @property
+    def text(self) -> str: ...
+    @property
+    def start(self) -> int: ...
+    @property
+    def end(self) -> int: ...
+    @property
+    def kind(self) -> str: ...
+    @property
+    def norm(self) -> str: ...
+    @property
+    def is_stop(self) -> bool: ...
+    @property
+    def lemma(self) -> str: ...
+    def __len__(self) -> int: ...
+
+class Doc:
+    """Tokenized text as :class:`Token` objects.
+
+    The structured form of :func:`tokenize_with_offsets` and
+    :func:`tokenize_with_types`: indexing, ``len()`` and iteration go over
+    the tokens, and every token carries its offsets into :attr:`text`.
+
+    Examples:
+        >>> doc = Doc("Kitapları okudum.")
+        >>> [(t.text, t.lemma) for t in doc if t.kind == "WORD"]
+        [('Kitapları', 'kitap'), ('okudum', 'oku')]
+        >>> [t.text for t in doc if not t.is_stop]
+        ['Kitapları', 'okudum', '.']
+    """
+
+    def __init__(self, text: str) -> None: ...
+    @property
+    def text(self) -> str: ...
+    @property
+    def tokens(self) -> list[Token]: ...
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> Token: ...
+    def __iter__(self) -> Iterator[Token]: ...
+
+class RegexTokenizer:
+    """Regex tokenizer that keeps only some token classes whole.
+
+    Classes named in ``disable`` (``url``, ``email``, ``iban``, ``phone``,
+    ``date``, ``time``, ``hashtag``, ``mention``, ``emoticon``) fall apart
+    into word, number and punctuation tokens. The per-class patterns are
+    compiled once and shared, so creating a tokenizer compiles nothing.
+
+    Raises:
+        ValueError: If a name is not an optional token class
+
+    Examples:
+        >>> RegexTokenizer(disable=["email"]).tokenize("ali@x.com")
+        ['ali', '@', 'x', '.', 'com']
+    """
+
+    def __init__(self, disable: Sequence[str] = ...) -> None: ...
+    @property
+    def classes(self) -> list[str]:
+        """Names of the optional classes kept whole, in priority order."""
+        ...
+    def tokenize(self, text: str) -> list[str]: ...
+    def tokenize_with_offsets(
+        self, text: str, *, offsets: Literal["char", "byte"] | None = None
+    ) -> list[tuple[str, int, int]]: ...
+    def tokenize_with_types(
+        self, text: str, *, offsets: Literal["char", "byte"] | None = None
+    ) -> list[tuple[str, str, int, int]]: ...
+    @overload
+    def tokenize_batch(
+        self,
+        texts: Iterable[object],
+        threads: int = 1,
+        *,
+        errors: Literal["raise"] = "raise",
+        cancel: CancellationToken | None = None,
+    ) -> list[list[str] | None]: ...
+    @overload
+    def tokenize_batch(
+        self,
+        texts: Iterable[object],
+        threads: int = 1,
+        *,
+        errors: Literal["isolate"],
+        cancel: CancellationToken | None = None,
+    ) -> list[BatchResult]: ...
+
+class RewriteRules:
+    """Rewrite rules over token lists, for house-style normalizations.
+
+    One rule per line, ``pattern => replacement``, with ``#`` comments. A
+    pattern is a sequence of tokens: literals match case-insensitively
+    (Turkish I/ı aware) and ``<TYPE>`` matches any token of a
+    :func:`tokenize_with_types` type (``<NUMBER>``, ``<DATE>``, ``<URL>``).
+    The replacement is a sequence of tokens, possibly empty: literals are
+    copied, ``$n`` copies the n-th matched token, ``$n:lower`` lowercases it
+    and ``$n:iso`` turns a DATE or TIME token into its ISO 8601 form. A
+    leading backslash makes ``<``, ``$`` or ``\\`` literal.
+
+    Rules apply left to right in one pass: at each position the longest
+    matching pattern wins, earlier rules break ties, and rewritten tokens
+    are not matched again.
+
+    Raises:
+        ValueError: If a rule is malformed; the message names its line
+
+    Examples:
+        >>> rules = RewriteRules("TL => lira\\n<NUMBER> TL => $1 lira\\n<DATE> => $1:iso")
+        >>> rules(["50", "TL", "12.03.2024"])
+        ['50', 'lira', '2024-03-12']
+    """
+
+    def __init__(self, rules: str) -> None: ...
+    @staticmethod
+    def from_file(path: str | os.PathLike[str]) -> RewriteRules:
+        """Read a UTF-8 rules file."""
+        ...
+    def apply(self, tokens: list[str]) -> list[str]: ...
+    def __call__(self, tokens: list[str]) -> list[str]: ...
+    def __len__(self) -> int: ...
+
+class KeywordMatcher:
+    """Spot many phrases in one scan (Aho–Corasick), for brand and entity
+    watch lists over large text streams.
+
+    ``keywords`` is a list of phrases, each its own label, or a mapping of
+    phrase to label. With ``normalize`` phrases and text are compared
+    lowercased (Turkish I/ı aware); with ``whole_words`` a match cannot
+    start or end inside a word, so "Koç" matches "Koç'un" but not "Koçak".
+    Duplicate phrases keep the label of their first occurrence.
+
+    Raises:
+        ValueError: If a phrase is empty
+
+    Examples:
+        >>> matcher = KeywordMatcher({"Türk Telekom": "BRAND", "İstanbul": "LOC"})
+        >>> matcher.find("TÜRK TELEKOM istanbul'da")
+        [('TÜRK TELEKOM', 'BRAND', 0, 12), ('istanbul', 'LOC', 13, 21)]
+    """
+
+    def __init__(
+        self,
+        keywords: Iterable[str] | Mapping[str, str],
+        *,
+        normalize: bool = True,
+        whole_words: bool = True,
+    ) -> None: ...
+    @staticmethod
+    def from_file(
+        path: str | os.PathLike[str],
+        *,
+        normalize: bool = True,
+        whole_words: bool = True,
+    ) -> KeywordMatcher:
+        """Read one phrase per line, optionally ``phrase<TAB>label``; blank
+        lines and ``# `` comments are skipped, so hashtags can be phrases."""
+        ...
+    def find(
+        self, text: str, overlapping: bool = False
+    ) -> list[tuple[str, str, int, int]]:
+        """``(text, label, start, end)`` of the matches, by start, with
+        character offsets. Without ``overlapping`` the leftmost-longest
+        matches that do not overlap are kept ("Türk Telekom", not also
+        "Türk")."""
+        ...
+    def find_batch(
+        self, texts: list[str], overlapping: bool = False, threads: int = 1
+    ) -> list[list[tuple[str, str, int, int]]]:
+        """``find`` over many texts; ``threads=0`` uses one worker per core."""
+        ...
+    def __len__(self) -> int: ...
+
+class PhraseMatcher:
+    """Match patterns by lemma instead of surface form.
+
+    Patterns and texts are tokenized, lowercased and lemmatized as by
+    :func:`process`, and a pattern matches consecutive tokens with the same
+    lemmas, so "kitap oku" finds "kitabı okudum" and "Kitapları
+    okuyorlar". ``patterns`` is a list of patterns, each its own label, or
+    a mapping of pattern to label; duplicates (by lemma) keep the label of
+    their first occurrence.
+
+    Raises:
+        ValueError: If a pattern has no tokens
+
+    Examples:
+        >>> matcher = PhraseMatcher({"kitap oku": "READING"})
+        >>> matcher.find("Dün kitabı okudum.")
+        [('kitabı okudum', 'READING', 4, 17)]
+    """
+
+    def __init__(self, patterns: Iterable[str] | Mapping[str, str]) -> None: ...
+    @staticmethod
+    def from_file(path: str | os.PathLike[str]) -> PhraseMatcher:
+        """Read one pattern per line, optionally ``pattern<TAB>label``; blank
+        lines and ``# `` comments are skipped."""
+        ...
+    def find(
+        self, text: str, overlapping: bool = False
+    ) -> list[tuple[str, str, int, int]]:
+        """``(text, label, start, end)`` of the matches, by start, with
+        character offsets; as in :meth:`KeywordMatcher.find`."""
+        ...
+    def find_batch(
+        self, texts: list[str], overlapping: bool = False, threads: int = 1
+    ) -> list[list[tuple[str, str, int, int]]]:
+        """``find`` over many texts; ``threads=0`` uses one worker per core."""
+        ...
+    def __len__(self) -> int: ...
+
+def process(
+    text: str,
+    *,
+    normalize: bool = True,
+    stopwords: bool = True,
+    lemmatize: bool = True,
+) -> Doc:
+    """Run the whole preprocessing pipeline over a text in one Rust call.
+
+    Tokenizes the text, then normalizes, flags stopwords and lemmatizes
+    every token without the GIL, and returns a :class:`Doc` whose tokens
+    carry the results, instead of four Python-orchestrated calls per
+    document. Offsets point into the original text.
+
+    Args:
+        text: Input text
+        normalize: Lowercase with Turkish I/ı handling; when off the later
+            stages see the token text and ``norm`` is the token text
+        stopwords: Flag base Turkish stopwords; when off ``is_stop`` is False
+        lemmatize: Lemmatize words (``Lemmatizer()`` defaults); when off
+            ``lemma`` is ``norm``
+
+    Examples:
+        >>> doc = process("Ve kitapları okudum.")
+        >>> [(t.norm, t.is_stop, t.lemma) for t in doc]
+        [('ve', True, 've'), ('kitapları', False, 'kitap'), ('okudum', False, 'oku'), ('.', False, '.')]
+        >>> [t.lemma for t in process("Kitapları", lemmatize=False)]
+        ['kitapları']
+    """
+    ...
+
+def to_conllu(doc: Doc, sent_id: str | None = None) -> str:
+    """Format a :class:`Doc` as one CoNLL-U sentence for UD tooling.
+
+    Writes ``# sent_id`` (when given) and ``# text`` comments, then one line
+    per token with ID, FORM, LEMMA, UPOS, XPOS (durak's coarse tag) and
+    FEATS from the analyzer's preferred parse; HEAD, DEPREL and DEPS are
+    ``_``, and MISC is ``SpaceAfter=No`` for tokens directly followed by the
+    next one. The result ends with the blank line that closes the sentence,
+    so sentences can be concatenated into a file.
+
+    Args:
+        doc: Document to format, from :func:`process` or :class:`Doc`
+        sent_id: Value of the ``# sent_id`` comment; None omits it
+
+    Examples:
+        >>> print(to_conllu(process("Kitaplarımızı okudum."), sent_id="1"))
+        # sent_id = 1
+        # text = Kitaplarımızı okudum.
+        1	Kitaplarımızı	kitap	NOUN	Noun	Case=Acc|Number=Plur|...	_	_	_	_
+        2	okudum	oku	VERB	Verb	Evident=Fh|...|Tense=Past	_	_	_	SpaceAfter=No
+        3	.	.	PUNCT	_	_	_	_	_	_
+    """
+    ...
+
+def read_conllu(path: str | os.PathLike[str]) -> list[Doc]:
+    """Read a CoNLL-U file into one :class:`Doc` per sentence.
+
+    The text of each document is rebuilt from the surface tokens, with a
+    space after each one unless its MISC column says ``SpaceAfter=No``.
+    Multiword tokens ("1-2") count as one token and empty nodes are skipped.
+    Tokens keep the file's LEMMA unless it is ``_``; everything else about a
+    token is computed on first access as for ``Doc(text)``.
+
+    Args:
+        path: CoNLL-U file, UTF-8
+
+    Raises:
+        ValueError: A line is neither a comment, blank, nor ten
+            tab-separated columns
+
+    Examples:
+        >>> docs = read_conllu("tr_imst-ud-test.conllu")
+        >>> [(t.text, t.lemma) for t in docs[0]][:2]
+        [('Evden', 'ev'), ('çıktım', 'çık')]
+    """
+    ...
+
+def reannotate_conllu(text: str, *, columns: Sequence[str] | None = None) -> str:
+    """Recompute annotation columns of CoNLL-U text with durak.
+
+    Each word's LEMMA, UPOS, XPOS or FEATS (all four by default) is
+    recomputed from its FORM as :func:`to_conllu` would write it. Comments,
+    IDs, HEAD, DEPREL, DEPS and MISC, multiword tokens with the words they
+    span, and empty nodes are written back unchanged, so a treebank can be
+    re-lemmatized or re-tagged and diffed against the original.
+
+    Args:
+        text: CoNLL-U text
+        columns: Columns to rewrite, out of ``lemma``, ``upos``, ``xpos``
+            and ``feats``
+
+    Raises:
+        ValueError: Unknown column name or malformed line
+
+    Examples:
+        >>> with open("train.conllu", encoding="utf-8") as f:
+        ...     fixed = reannotate_conllu(f.read(), columns=["lemma"])
+    """
+    ...
+
+class TokenStream:
+    """Lazy tokenizer over a large file.
+
+    Reads the source ``chunk_size`` bytes at a time and tokenizes each chunk
+    in Rust, cutting chunks on sentence boundaries (or whitespace), so the
+    result is the same as tokenizing the whole text while memory use stays
+    bounded by the chunk size.
+
+    Args:
+        source: Path to a UTF-8 file, or a file-like object opened in text
+            or binary mode
+        chunk_size: Bytes read per block (default: 64 KiB)
+        strip_punct: Drop single-character punctuation tokens
+
+    Raises:
+        OSError: If the path cannot be opened
+        ValueError: If the input is not valid UTF-8
+
+    Examples:
+        >>> for token in TokenStream("corpus.txt", strip_punct=True):
+        ...     counts[token] += 1
+    """
+
+    def __init__(
+        self,
+        source: str | os.PathLike[str] | IO[str] | IO[bytes],
+        chunk_size: int = 65536,
+        strip_punct: bool = False,
+    ) -> None: ...
+    def __iter__(self) -> TokenStream: ...
+    def __next__(self) -> str: ...
+
+class NdjsonReader:
+    """Streaming reader of ndjson (JSON lines) dumps with field selection.
+
+    Splits the input into lines in Rust and yields the string at the dotted
+    ``field`` path of each JSON object line without building the rest of
+    the record. Lines not starting with ``{`` are plain-text records. Blank
+    lines and records without a string at ``field`` are skipped; the latter
+    are counted in :attr:`skipped`.
+
+    Args:
+        source: Path to a UTF-8 file, or a file-like object opened in text
+            or binary mode
+        field: Dotted path of the text field, e.g. ``"user.description"``
+            (default: ``"text"``)
+        raw: Also yield the input line
+        block_size: Bytes read per block (default: 1 MiB)
+
+    Yields:
+        ``(line_no, text)``, or ``(line_no, text, line)`` with ``raw=True``;
+        line numbers start at 1
+
+    Raises:
+        OSError: If the path cannot be opened
+        ValueError: If the field path is empty, or a line is not valid JSON
+            or UTF-8 (the message names the line)
+
+    Examples:
+        >>> reader = NdjsonReader("tweets.jsonl", "retweeted_status.text")
+        >>> for line_no, text in reader:
+        ...     counts.update(tokenize(text))
+        >>> reader.skipped
+        1204
+    """
+
+    def __init__(
+        self,
+        source: str | os.PathLike[str] | IO[str] | IO[bytes],
+        field: str = "text",
+        *,
+        raw: bool = False,
+        block_size: int = 1048576,
+    ) -> None: ...
+    @property
+    def skipped(self) -> int: ...
+    def __iter__(self) -> NdjsonReader: ...
+    def __next__(self) -> tuple[int, str] | tuple[int, str, str]: ...
+
+class BatchResult:
+    """Outcome of one item of a batch run with ``errors="isolate"``.
+
+    Printed as ``Ok(value)`` or ``Err(reason)``.
+
+    Attributes:
+        ok: Whether the item was processed
+        value: The result the batch function returns for the item, None when
+            it failed
+        error: Why the item failed ("TypeError: ... at position 3"), None
+            when it was processed
+
+    Examples:
+        >>> lemmatize_batch(["kitaplar", 42], errors="isolate")
+        [Ok('kitap'), Err('TypeError: lemmatize_batch expects strings ...')]
+    """
+
+    @property
+    def ok(self) -> bool: ...
+    @property
+    def value(self) -> object: ...
+    @property
+    def error(self) -> str | None: ...
+    def unwrap(self) -> object:
+        """The result for the item.
+
+        Raises:
+            ValueError: With the reason, if the item failed
+        """
+        ...
+
+class CancellationToken:
+    """A flag that stops batch and sampling calls from another thread.
+
+    Pass it as ``cancel=``; once ``cancel()`` is called the running call
+    stops at its next item and raises ``durak.exceptions.CancelledError``.
+    A cancelled token stays cancelled, so use a new one per job.
+
+    Examples:
+        >>> token = CancellationToken()
+        >>> threading.Timer(60, token.cancel).start()
+        >>> lemmatize_batch(words, threads=0, cancel=token)
+    """
+
+    def __init__(self) -> None: ...
+    def cancel(self) -> None:
+        """Stop the calls using this token at their next item."""
+        ...
+    @property
+    def cancelled(self) -> bool: ...
+
+class ArrowArray:
+    """A column built by durak, exported through the Arrow PyCapsule interface.
+
+    ``pyarrow.array(column)`` and ``polars.Series(column)`` take it over
+    without copying.
+
+    Examples:
+        >>> column = ArrowArray.from_pylist(["kitap", None])
+        >>> column
+        ArrowArray(type=large_string, length=2)
+    """
+
+    @staticmethod
+    def from_pylist(values: list[str | None]) -> ArrowArray:
+        """A ``large_string`` column of the given strings and Nones."""
+        ...
+    @property
+    def type(self) -> str:
+        """Arrow type name, e.g. ``large_list<large_string>``."""
+        ...
+    def to_pylist(self) -> list[object]:
+        """Values as Python strings, ints, lists and Nones."""
+        ...
+    def __arrow_c_array__(
+        self, requested_schema: object | None = None
+    ) -> tuple[object, object]: ...
+    def __arrow_c_stream__(self, requested_schema: object | None = None) -> object: ...
+    def __len__(self) -> int: ...
+
+def tokenize_arrow(
+    array: object, *, threads: int = 1, cancel: CancellationToken | None = None
+) -> tuple[ArrowArray, ArrowArray, ArrowArray]:
+    """Tokenize an Arrow string column without copying its strings.
+
+    Accepts anything with ``__arrow_c_array__`` or ``__arrow_c_stream__``:
+    pyarrow arrays and chunked arrays, polars Series. ``string``,
+    ``large_string`` and ``string_view`` columns are read in place.
+
+    Args:
+        array: Arrow string column
+        threads: Worker threads, as in ``lemmatize_batch``
+        cancel: Token that stops the call, as in ``lemmatize_batch``
+
+    Returns:
+        (tokens, starts, ends) ``large_list`` columns: the tokens of each
+        string and their start and end character offsets, as
+        ``tokenize_with_offsets`` returns them; null strings give null lists
+
+    Raises:
+        TypeError: If array does not implement the Arrow PyCapsule interface
+        ValueError: If it is not a string column
+        CancelledError: If ``cancel`` is cancelled during the call
+
+    Examples:
+        >>> tokens, starts, ends = tokenize_arrow(pa.array(["Merhaba dünya!"]))
+        >>> tokens.to_pylist(), starts.to_pylist()
+        ([['Merhaba', 'dünya', '!']], [[0, 8, 13]])
+    """
+    ...
+
+def normalize_arrow(
+    array: object,
+    *,
+    lowercase: bool = True,
+    handle_turkish_i: bool = True,
+    threads: int = 1,
+    cancel: CancellationToken | None = None,
+) -> ArrowArray:
+    """Apply fast_normalize to an Arrow string column without copying it in.
+
+    Reads the column as ``tokenize_arrow`` does and returns a
+    ``large_string`` column; nulls stay null. ``threads`` and ``cancel``
+    work as in ``lemmatize_batch``.
+
+    Examples:
+        >>> normalize_arrow(pl.Series(["İSTANBUL", None])).to_pylist()
+        ['istanbul', None]
+    """
+    ...
+
+def lemmatize_arrow(
+    array: object,
+    strategy: str = "hybrid",
+    *,
+    validate_roots: bool = False,
+    strict_validation: bool = False,
+    min_root_length: int | None = None,
+    threads: int = 1,
+    cancel: CancellationToken | None = None,
+) -> ArrowArray:
+    """Lemmatize an Arrow string column of words without copying it in.
+
+    Same options and results as ``lemmatize_batch``; returns a
+    ``large_string`` column with nulls kept.
+
+    Raises:
+        ValueError: If strategy is unknown or the column is not a string
+            column
+
+    Examples:
+        >>> lemmatize_arrow(pa.array(["kitaplar", None])).to_pylist()
+        ['kitap', None]
+    """
+    ...
+
+def lemma_candidates(
+    word: str,
+    strategy: str = "hybrid",
+    *,
+    validate_roots: bool = False,
+    strict_validation: bool = False,
+    min_root_length: int | None = None,
+    tie_break: str = "prefer-dictionary",
+) -> list[str]:
+    """Candidate lemmas of a word, best first under ``tie_break``.
+
+    The candidates are the ``Lemmatizer`` result, the roots of the other
+    dictionary readings of the word (from ``analyze``), and the stripped
+    root when the dictionary had an entry; ties keep that order.
+
+    Args:
+        word: Word to lemmatize
+        strategy: lookup, heuristic or hybrid, as in ``Lemmatizer``
+        tie_break: prefer-dictionary (the ``Lemmatizer`` result),
+            prefer-shortest, prefer-most-frequent (by lemma_frequency.txt),
+            or return-all for every candidate
+
+    Raises:
+        ValueError: If strategy or tie_break is unknown
+
+    Examples:
+        >>> lemma_candidates("gelir", tie_break="return-all")
+        ['gel', 'gelir']
+        >>> lemma_candidates("gözlükçü", tie_break="prefer-shortest")
+        ['göz']
+    """
+    ...
+
+def lemma_cache_info() -> dict[str, int]:
+    """Hits, misses, current size and maximum size of the lemma cache.
+
+    Every lemmatization through the Rust core (``Lemmatizer``, ``process``,
+    ``Doc``, the batch functions) looks the word up in a process-wide LRU
+    cache keyed by word and options first; on Zipfian corpus text most
+    tokens are hits.
+
+    Examples:
+        >>> lemma_cache_info()
+        {'hits': 9120, 'misses': 880, 'size': 880, 'maxsize': 100000}
+    """
+    ...
+
+def set_lemma_cache_size(maxsize: int) -> None:
+    """Set how many lemmas the cache keeps, least recently used evicted
+    first; ``0`` disables it. Drops the cached lemmas and resets the
+    counters. The default is 100000."""
+    ...
+
+def clear_lemma_cache() -> None:
+    """Drop the cached lemmas and reset the counters. ``set_resource_dirs``
+    does this itself when the lemma dictionary changes."""
+    ...
+
+@overload
+def lemmatize_batch(
+    words: Iterable[object],
+    strategy: str = "hybrid",
+    validate_roots: bool = False,
+    strict_validation: bool = False,
+    min_root_length: int | None = None,
+    threads: int = 1,
+    *,
+    errors: Literal["raise"] = "raise",
+    tie_break: str = "prefer-dictionary",
+    cancel: CancellationToken | None = None,
+) -> list[str | None]: ...
+@overload
+def lemmatize_batch(
+    words: Iterable[object],
+    strategy: str = "hybrid",
+    validate_roots: bool = False,
+    strict_validation: bool = False,
+    min_root_length: int | None = None,
+    threads: int = 1,
+    *,
+    errors: Literal["isolate"],
+    tie_break: str = "prefer-dictionary",
+    cancel: CancellationToken | None = None,
+) -> list[BatchResult]: ...
+def lemmatize_batch(
+    words: Iterable[object],
+    strategy: str = "hybrid",
+    validate_roots: bool = False,
+    strict_validation: bool = False,
+    min_root_length: int | None = None,
+    threads: int = 1,
+    *,
+    errors: str = "raise",
+    tie_break: str = "prefer-dictionary",
+    cancel: CancellationToken | None = None,
+) -> list[str | None] | list[list[str] | None] | list[BatchResult]:
+    """Lemmatize many words in a single call.
+
+    Same options and results as ``Lemmatizer``, applied to a whole column
+    chunk without a Python call per word. Missing entries (None, NaN, pd.NA)
+    come back as None, so dataframe nulls stay null. ``threads`` spreads the
+    batch over that many worker threads (0: one per core); order is
+    preserved.
+
+    By default a malformed entry (not a string or missing, or a string with
+    lone surrogates) fails the whole batch. ``errors="isolate"`` returns a
+    ``BatchResult`` per entry instead, with the reason in place of the lemma
+    for malformed ones.
+
+    The batch runs without the GIL but stops at the next word on Ctrl-C,
+    raising KeyboardInterrupt, or once ``cancel`` is cancelled from another
+    thread, raising ``CancelledError``.
+
+    ``tie_break`` picks the lemma as in ``lemma_candidates``; with
+    ``"return-all"`` each word maps to its list of candidates.
+
+    Raises:
+        ValueError: If strategy is not lookup, heuristic or hybrid, errors
+            is not raise or isolate, or tie_break is unknown
+        TypeError: Under ``errors="raise"``, for a malformed entry
+        CancelledError: If ``cancel`` is cancelled during the call
+
+    Examples:
+        >>> lemmatize_batch(["kitaplar", None, "geliyorum"])
+        ['kitap', None, 'gel']
+    """
+    ...
+
+@overload
+def normalize_batch(
+    texts: Iterable[object],
+    lowercase: bool = True,
+    handle_turkish_i: bool = True,
+    threads: int = 1,
+    *,
+    errors: Literal["raise"] = "raise",
+    cancel: CancellationToken | None = None,
+) -> list[str | None]: ...
+@overload
+def normalize_batch(
+    texts: Iterable[object],
+    lowercase: bool = True,
+    handle_turkish_i: bool = True,
+    threads: int = 1,
+    *,
+    errors: Literal["isolate"],
+    cancel: CancellationToken | None = None,
+) -> list[BatchResult]: ...
+def normalize_batch(
+    texts: Iterable[object],
+    lowercase: bool = True,
+    handle_turkish_i: bool = True,
+    threads: int = 1,
+    *,
+    errors: str = "raise",
+    cancel: CancellationToken | None = None,
+) -> list[str | None] | list[BatchResult]:
+    """Apply fast_normalize to many texts; missing entries are passed through.
+
+    ``threads``, ``errors`` and ``cancel`` work as in ``lemmatize_batch``.
+    """
+    ...
+
+@overload
+def tokenize_batch(
+    texts: Iterable[object],
+    threads: int = 1,
+    *,
+    errors: Literal["raise"] = "raise",
+    cancel: CancellationToken | None = None,
+) -> list[list[str] | None]: ...
+@overload
+def tokenize_batch(
+    texts: Iterable[object],
+    threads: int = 1,
+    *,
+    errors: Literal["isolate"],
+    cancel: CancellationToken | None = None,
+) -> list[BatchResult]: ...
+def tokenize_batch(
+    texts: Iterable[object],
+    threads: int = 1,
+    *,
+    errors: str = "raise",
+    cancel: CancellationToken | None = None,
+) -> list[list[str] | None] | list[BatchResult]:
+    """Tokenize many texts; missing entries are passed through.
+
+    ``threads``, ``errors`` and ``cancel`` work as in ``lemmatize_batch``.
+    """
+    ...
+
+def get_detached_suffixes() -> list[str]:
+    """Get embedded detached suffixes list.
+
+    Returns the list of Turkish detached suffixes compiled into the binary
+    from resources/tr/labels/DETACHED_SUFFIXES.txt at build time.
+
+    Returns:
+        List of detached suffix strings
+
+    Examples:
+        >>> suffixes = get_detached_suffixes()
+        >>> 'da' in suffixes
+        True
+        >>> 'de' in suffixes
+        True
+    """
+    ...
+
+def split_clitics(token: str) -> tuple[str, str]:
+    """Split a token at the apostrophe before its suffixes.
+
+    The last ' or ’ is used when lowercase letters follow it, or any
+    letters in an uppercase token. Other tokens come back whole with an
+    empty suffix.
+
+    Returns:
+        (base, suffix) with the apostrophe kept at the start of the suffix
+
+    Examples:
+        >>> split_clitics("İstanbul'a")
+        ('İstanbul', "'a")
+        >>> split_clitics("O'Neill")
+        ("O'Neill", '')
+    """
+    ...
+
+def get_stopwords_base() -> list[str]:
+    """Get embedded Turkish base stopwords list.
+
+    Returns the base Turkish stopwords compiled into the binary
+    from resources/tr/stopwords/base/turkish.txt at build time, or the
+    list installed by :func:`set_resource_dirs`.
+
+    Returns:
+        List of Turkish stopwords
+
+    Examples:
+        >>> stopwords = get_stopwords_base()
+        >>> 've' in stopwords
+        True
+        >>> 'ama' in stopwords
+        True
+    """
+    ...
+
+def set_resource_dirs(
+    directories: list[str | os.PathLike[str]],
+) -> list[tuple[str, Path]]:
+    """Layer resource directories over the embedded resources.
+
+    The lemma dictionary (``lemmas/turkish_lemma_dict.txt``), base
+    stopwords (``stopwords/base/turkish.txt``) and detached suffixes
+    (``labels/DETACHED_SUFFIXES.txt``) are read from the first directory
+    that has the file and replace the embedded data as a whole for every
+    later call, on all threads. An empty list restores the embedded data.
+    Other data, such as the suffixes the lemmatizer strips, cannot be
+    overridden.
+    Prefer :class:`durak.ResourceRegistry`, which also covers the Python
+    helpers.
+
+    Returns:
+        (resource, path) pairs of the resources now overridden
+
+    Raises:
+        OSError: If a file cannot be read or a lemma line has no tab
+    """
+    ...
+
+def resource_overrides() -> list[tuple[str, Path]]:
+    """The (resource, path) pairs installed by :func:`set_resource_dirs`."""
+    ...
+
+def get_config() -> dict[str, object]:
+    """The process-wide defaults: ``min_root_length``, ``check_harmony``,
+    ``offsets`` and ``stopword_domains``."""
+    ...
+
+def set_config(
+    *,
+    min_root_length: int | None = None,
+    check_harmony: bool | None = None,
+    offsets: Literal["char", "byte"] | None = None,
+    stopword_domains: list[str] | None = None,
+) -> dict[str, object]:
+    """Change process-wide defaults for every thread.
+
+    Options left as None keep their value. Prefer
+    :func:`durak.defaults.set_config`, which also checks stopword domain names.
+
+    Returns:
+        The previous defaults
+
+    Raises:
+        ValueError: If ``min_root_length`` is below 1 or the offset unit is
+            unknown
+    """
+    ...
+
+def get_stopwords_metadata() -> str:
+    """Get embedded stopwords metadata JSON.
+
+    Returns the stopwords metadata JSON compiled into the binary
+    from resources/tr/stopwords/metadata.json at build time.
+
+    Returns:
+        JSON string containing stopword metadata
+
+    Examples:
+        >>> import json
+        >>> metadata = json.loads(get_stopwords_metadata())
+        >>> 'sets' in metadata
+        True
+    """
+    ...
+
+def get_stopwords_social_media() -> list[str]:
+    """Get embedded social media stopwords.
+
+    Returns the social media domain-specific stopwords compiled into the binary
+    from resources/tr/stopwords/domains/social_media.txt at build time.
+
+    Returns:
+        List of social media stopwords
+
+    Examples:
+        >>> sm_stopwords = get_stopwords_social_media()
+        >>> len(sm_stopwords) > 0
+        True
+    """
+    ...
+
+def get_confusion_pairs() -> list[tuple[str, str]]:
+    """Get embedded confusion pairs.
+
+    Returns:
+        List of (misspelling, correction) tuples sorted by misspelling
+
+    Examples:
+        >>> ("herşey", "her şey") in get_confusion_pairs()
+        True
     """
     ...
 
 __all__ = [
     "fast_normalize",
+    "normalize_with_alignment",
+    "unicode_normalize",
+    "fast_uppercase",
+    "turkish_casefold",
+    "correct_confusions",
+    "split_joined",
+    "split_hashtag",
     "tokenize_with_offsets",
+    "tokenize_with_types",
+    "tokenize_columns",
+    "tokenize_with_whitespace",
+    "detokenize",
+    "tokenize_for_spacy",
+    "pre_tokenize",
+    "Token",
+    "Doc",
+    "process",
+    "RewriteRules",
+    "KeywordMatcher",
+    "PhraseMatcher",
+    "extract_entities",
+    "extract_dates",
+    "link_pronouns",
+    "extract_numbers",
+    "detect_dialect",
+    "dialect_flags",
+    "word_origin",
+    "etymology_tags",
+    "takes_front_harmony",
+    "detect_language",
+    "is_turkish",
+    "number_to_words",
+    "words_to_number",
     "lookup_lemma",
+    "lookup_lemma_prefix",
     "strip_suffixes",
     "strip_suffixes_validated",
     "check_vowel_harmony_py",
+    "analyze",
+    "analyze_all",
+    "check_agreement",
+    "check_grammar",
+    "fix_pdf_artifacts",
+    "rtl_spans",
+    "isolate_rtl",
+    "generate",
+    "morphological_complexity",
+    "formality_score",
+    "text_stats",
+    "syllabify",
+    "line_anomalies",
+    "ngrams",
+    "char_ngrams",
+    "CorpusStats",
+    "pos_tag",
+    "is_correct",
+    "ranked_suggestions",
+    "suggest",
+    "TokenStream",
+    "NdjsonReader",
+    "ArrowArray",
+    "BatchResult",
+    "CancellationToken",
+    "lemmatize_batch",
+    "lemma_candidates",
+    "lemma_cache_info",
+    "set_lemma_cache_size",
+    "clear_lemma_cache",
+    "normalize_batch",
+    "tokenize_batch",
+    "tokenize_arrow",
+    "normalize_arrow",
+    "lemmatize_arrow",
+    "process_series",
     "get_detached_suffixes",
+    "split_clitics",
     "get_stopwords_base",
+    "set_resource_dirs",
+    "resource_overrides",
     "get_stopwords_metadata",
     "get_stopwords_social_media",
+    "get_confusion_pairs",
+    "sample_lines",
+    "sample_sentences",
+    "term_counts",
+    "discover_stopwords",
+    "dedup_lines",
+    "Deduplicator",
+    "reinflect_cases",
+    "augment",
+    "add_noise",
+    "keyboard_layout",
+    "keyboard_neighbors",
+    "keyboard_distance",
+    "keyboard_edit_distance",
+    "keyboard_confusion_pairs",
+    "similarity",
+    "closest_matches",
+    "UnigramModel",
+    "train_unigram",
+    "BpeModel",
+    "train_bpe",
+    "segment_word",
+    "segment_morphemes",
+    "warm_up",
     "get_build_info",
     "get_resource_info",
     "get_build_info",
     "get_resource_info",
 ]
 
+@overload
+def sample_lines(
+    source: str | os.PathLike[str] | IO[str] | IO[bytes],
+    n: int,
+    seed: int = 0,
+    *,
+    line_numbers: Literal[False] = False,
+    cancel: CancellationToken | None = None,
+) -> list[str]: ...
+@overload
+def sample_lines(
+    source: str | os.PathLike[str] | IO[str] | IO[bytes],
+    n: int,
+    seed: int = 0,
+    *,
+    line_numbers: Literal[True],
+    cancel: CancellationToken | None = None,
+) -> list[tuple[int, str]]: ...
+def sample_lines(
+    source: str | os.PathLike[str] | IO[str] | IO[bytes],
+    n: int,
+    seed: int = 0,
+    *,
+    line_numbers: bool = False,
+    cancel: CancellationToken | None = None,
+) -> list[str] | list[tuple[int, str]]:
+    """Seeded reservoir sample of n non-blank lines of a file.
+
+    Reads the input once, keeping only the sampled lines in memory. The
+    generator (SplitMix64) and the algorithm are fixed, so the same input,
+    n and seed give the same sample on every platform and Durak release.
+
+    Args:
+        source: File path or file-like object (text or binary, UTF-8)
+        n: Sample size; every line is returned if there are fewer
+        seed: Non-negative random seed
+        line_numbers: Return (1-based line number, line) pairs
+        cancel: Token that stops the read, as in ``lemmatize_batch``
+
+    Returns:
+        Sampled lines, without line endings, in file order
+
+    Raises:
+        ValueError: If the input is not valid UTF-8
+
+    Examples:
+        >>> sample_lines("corpus.txt", 1000, seed=42)  # doctest: +SKIP
+    """
+    ...
+
+def term_counts(
+    source: str | os.PathLike[str] | IO[str] | IO[bytes],
+    n: int = 1,
+    *,
+    min_count: int = 1,
+    top: int | None = None,
+    max_memory_mb: int | None = None,
+    cancel: CancellationToken | None = None,
+) -> list[tuple[str, int]]:
+    """Word n-gram counts of a corpus file, one document per line.
+
+    Lines are lowercased and split into words as for ``CorpusStats``. The
+    counts table grows with the vocabulary; with ``max_memory_mb`` the part
+    that does not fit is spilled to sorted files in the temporary directory
+    and merged at the end, so memory stays near the cap on any corpus. The
+    result does not depend on the cap.
+
+    Args:
+        source: File path or file-like object (text or binary, UTF-8)
+        n: Words per n-gram, joined with a space
+        min_count: Leave out n-grams seen fewer times
+        top: Return only the ``top`` most frequent n-grams
+        max_memory_mb: Memory cap for the counts table
+        cancel: Token that stops the read, as in ``lemmatize_batch``
+
+    Returns:
+        (n-gram, count) pairs, most frequent first, ties in alphabetical order
+
+    Raises:
+        ValueError: If n is 0 or the input is not valid UTF-8
+
+    Examples:
+        >>> term_counts(io.StringIO("Kitap okudum.\nKitap aldım."), top=1)
+        [('kitap', 2)]
+        >>> term_counts("dump.txt", 2, min_count=5, max_memory_mb=512)  # doctest: +SKIP
+    """
+    ...
+
+def discover_stopwords(
+    documents: Iterable[str],
+    top_k: int = 50,
+    min_df: int = 2,
+    merge_base: bool = False,
+) -> list[str]:
+    """Corpus-specific stopword candidates, likeliest first.
+
+    Documents are lowercased and split into words as for ``CorpusStats``.
+    Terms in at least ``min_df`` documents are scored by relative corpus
+    frequency over IDF, so frequent terms spread across many documents rank
+    first (see :meth:`CorpusStats.stopword_candidates` for the scores).
+
+    Args:
+        documents: Iterable of document texts
+        top_k: Number of candidates
+        min_df: Leave out terms in fewer documents
+        merge_base: Append the base stopwords not among the candidates,
+            alphabetically
+
+    Examples:
+        >>> docs = ["Bu sabah yine kahve içtik.", "Bu akşam yine çay var."]
+        >>> discover_stopwords(docs, top_k=2)
+        ['bu', 'yine']
+    """
+    ...
+
+def dedup_lines(
+    source: str | os.PathLike[str],
+    destination: str | os.PathLike[str],
+    *,
+    max_memory_mb: int | None = None,
+    cancel: CancellationToken | None = None,
+) -> int:
+    """Copy the first occurrence of every distinct line of a file, in order.
+
+    Lines are compared exactly, without their line endings, and written with
+    ``\n``. The source is read twice. With ``max_memory_mb`` the lines seen
+    so far spill to sorted files in the temporary directory once they pass
+    the cap, so files larger than memory can be deduplicated.
+
+    Args:
+        source: Path of the input file (UTF-8)
+        destination: Path of the output file, replaced if it exists
+        max_memory_mb: Memory cap for the seen lines
+        cancel: Token that stops the call, as in ``lemmatize_batch``
+
+    Returns:
+        Number of lines written
+
+    Raises:
+        ValueError: If destination is source or the input is not valid UTF-8
+
+    Examples:
+        >>> dedup_lines("dump.txt", "unique.txt", max_memory_mb=1024)  # doctest: +SKIP
+    """
+    ...
+
+class Deduplicator:
+    """Near-duplicate detection with MinHash signatures and LSH.
+
+    Documents are lowercased and split into word tokens as for
+    :class:`CorpusStats`, reduced to their ``shingle_size``-word shingles
+    and summarized by ``num_perm`` MinHash values. A document is a near
+    duplicate when the estimated Jaccard similarity of its shingles to an
+    earlier distinct document reaches ``threshold``. Candidates are found
+    through LSH bands, so adding a document does not compare it with every
+    earlier one. Signatures depend only on ``seed``, not on the platform or
+    the release.
+
+    Raises:
+        ValueError: If threshold is not in (0, 1] or num_perm or
+            shingle_size is 0
+
+    Examples:
+        >>> dedup = Deduplicator(threshold=0.7)
+        >>> originals = dedup.add_batch(documents)  # doctest: +SKIP
+        >>> originals  # doctest: +SKIP
+        [None, None, 0, None, 1]
+        >>> unique = [doc for doc, of in zip(documents, originals) if of is None]
+    """
+
+    def __init__(
+        self,
+        threshold: float = 0.8,
+        *,
+        num_perm: int = 128,
+        shingle_size: int = 3,
+        seed: int = 0,
+    ) -> None: ...
+    def add(self, text: str) -> int | None:
+        """Add the next document; the id of the earlier document it
+        near-duplicates, or None for a distinct document. Ids count every
+        added document, duplicates included, from 0."""
+        ...
+    def add_batch(self, texts: list[str], threads: int = 1) -> list[int | None]:
+        """``add`` for many documents in order; signatures are computed
+        across ``threads`` workers (0: one per core)."""
+        ...
+    def query(self, text: str) -> tuple[int, float] | None:
+        """``(id, estimated similarity)`` of the most similar distinct
+        document at or above the threshold, without adding ``text``."""
+        ...
+    def similarity(self, a: str, b: str) -> float:
+        """Estimated Jaccard similarity of the word shingles of two texts."""
+        ...
+    @property
+    def distinct(self) -> int:
+        """Distinct documents indexed so far."""
+        ...
+    def __len__(self) -> int: ...
+
+def sample_sentences(text: str, n: int, seed: int = 0) -> list[str]:
+    """Seeded reservoir sample of n sentences of text, in text order.
+
+    Sentences are split as by ``split_sentences``; sampling is reproducible
+    as in ``sample_lines``.
+
+    Examples:
+        >>> len(sample_sentences("Bir. İki. Üç.", 2, seed=1))
+        2
+    """
+    ...
+
+def reinflect_cases(word: str) -> list[str]:
+    """Other case forms of a case-marked noun.
+
+    The word's preferred analysis must be a noun ending in an accusative,
+    dative, locative, ablative or instrumental case; the case is replaced by
+    each of the others and the word regenerated. Possessive and plural
+    suffixes are kept, and so is an initial capital.
+
+    Returns:
+        The re-inflected forms, or an empty list for other words
+
+    Examples:
+        >>> reinflect_cases("evde")
+        ['evi', 'eve', 'evden', 'evle']
+        >>> reinflect_cases("kitabımı")
+        ['kitabıma', 'kitabımda', 'kitabımdan', 'kitabımla']
+    """
+    ...
+
+def augment(
+    text: str,
+    n: int = 1,
+    *,
+    operations: Sequence[str] | None = None,
+    seed: int = 0,
+) -> list[str]:
+    """Lemma-preserving variants of text for data augmentation.
+
+    Every variant is one edit away from the input:
+
+    - ``"case"``: a case-marked noun takes another case (okula → okulda)
+    - ``"order"``: two case-marked nouns or adverbs before a clause-final
+      verb swap places (Turkish constituent order is free before the verb)
+    - ``"clitic"``: "da"/"de" is inserted after a noun or personal pronoun,
+      or a standalone one is removed
+
+    All edits of the chosen operations are enumerated and a seeded uniform
+    sample of ``n`` is applied, so the same seed gives the same variants.
+    Words are re-joined with single spaces.
+
+    Args:
+        text: Input sentence or short text
+        n: Maximum number of variants
+        operations: Subset of "case", "order" and "clitic" (default: all)
+        seed: Seed selecting the variants
+
+    Returns:
+        Up to n distinct variants, none equal to the input
+
+    Raises:
+        ValueError: For an unknown operation name
+
+    Examples:
+        >>> augment("Ali yarın okula gidecek.", 5, operations=["order"])
+        ['Ali okula yarın gidecek.']
+    """
+    ...
+
+def add_noise(
+    text: str,
+    profile: Literal["social", "keyboard", "ascii"] = "social",
+    rate: float = 0.1,
+    seed: int = 0,
+    *,
+    keyboard: Literal["q", "f"] = "q",
+) -> str:
+    """Corrupt text with realistic Turkish noise for robustness testing.
+
+    Each word is corrupted with probability ``rate`` by one error from the
+    profile's mix:
+
+    - missing diacritics ("çocuğu" → "cocugu"), the spell checker's table
+    - keyboard typos: a neighbouring key of the Turkish ``keyboard`` layout
+      (``"q"`` or ``"f"``) substituted or inserted, a letter dropped, doubled
+      or swapped
+    - confusion pairs in reverse ("yalnız" → "yanlız", "her şey" → "herşey")
+    - clitic spacing errors ("ben de" → "bende", "evde" → "ev de")
+
+    ``"social"`` mixes all four, ``"keyboard"`` only makes typos and
+    ``"ascii"`` only drops diacritics. The same seed gives the same noise.
+
+    Raises:
+        ValueError: For an unknown profile or layout, or a rate outside [0, 1]
+
+    Examples:
+        >>> add_noise("Çocuğu gördüm", profile="ascii", rate=1.0)
+        'Cocugu gordum'
+    """
+    ...
+
+def keyboard_layout(layout: Literal["q", "f"] = "q") -> list[str]:
+    """Letter rows of the Turkish Q or F keyboard, top row first.
+
+    Raises:
+        ValueError: For a layout other than ``"q"`` or ``"f"``
+
+    Examples:
+        >>> keyboard_layout("q")
+        ['qwertyuıopğü', 'asdfghjklşi', 'zxcvbnmöç']
+    """
+    ...
+
+def keyboard_neighbors(letter: str, layout: Literal["q", "f"] = "q") -> list[str]:
+    """Letters on the keys around ``letter``'s key, row by row.
+
+    Keys next to each other in a row and keys in the rows above and below
+    that overlap it are neighbours. Case is ignored; characters that are not
+    on the layout have no neighbours.
+
+    Examples:
+        >>> keyboard_neighbors("g")
+        ['t', 'y', 'f', 'h', 'v', 'b']
+        >>> keyboard_neighbors("a", "f")
+        ['ı', 'o', 'e', 'ü', 'v', 'c']
+    """
+    ...
+
+def keyboard_distance(
+    a: str, b: str, layout: Literal["q", "f"] = "q"
+) -> float | None:
+    """Distance between the centres of two keys in key widths.
+
+    Returns None when either letter is not on the layout.
+
+    Examples:
+        >>> keyboard_distance("f", "g")
+        1.0
+    """
+    ...
+
+def keyboard_edit_distance(a: str, b: str, layout: Literal["q", "f"] = "q") -> float:
+    """Levenshtein distance where hitting a neighbouring key costs 0.5.
+
+    Insertions, deletions and other substitutions cost 1, so typos on nearby
+    keys score as closer matches. This is the tie-breaker ``suggest`` uses
+    between candidates with the same number of edits.
+
+    Examples:
+        >>> keyboard_edit_distance("kitap", "kitao")
+        0.5
+        >>> keyboard_edit_distance("kitap", "kitak")
+        1.0
+    """
+    ...
+
+def keyboard_confusion_pairs(layout: Literal["q", "f"] = "q") -> list[tuple[str, str]]:
+    """Every (letter, neighbouring letter) pair of a layout.
+
+    These are the non-zero cells of a keyboard confusion matrix: the
+    substitutions a typo is most likely to make. Each pair appears in both
+    directions.
+    """
+    ...
+
+def similarity(
+    a: str,
+    b: str,
+    metric: Literal["levenshtein", "jaro", "jaro_winkler"] = "jaro_winkler",
+    *,
+    ignore_case: bool = True,
+) -> float:
+    """Similarity of two strings in [0, 1], 1 for equal strings.
+
+    A Turkish letter and the ASCII letter it is typed as (ı/i, ş/s, ç/c,
+    ğ/g, ö/o, ü/u, and â/a, î/i, û/u) differ by a fifth of an edit, so
+    deasciified input stays close to the original. ``levenshtein`` is one
+    minus the weighted edit distance over the longer length; ``jaro`` and
+    ``jaro_winkler`` count such pairs as slightly weaker matches, and
+    Jaro-Winkler also rewards a common prefix of up to four letters. Case is
+    folded with Turkish I rules unless ``ignore_case`` is False.
+
+    Raises:
+        ValueError: For an unknown metric
+
+    Examples:
+        >>> round(similarity("Eskisehir", "Eskişehir", "levenshtein"), 2)
+        0.98
+        >>> round(similarity("Eskisehir", "Eskimeler", "levenshtein"), 2)
+        0.67
+    """
+    ...
+
+def closest_matches(
+    query: str,
+    candidates: list[str],
+    k: int = 5,
+    metric: Literal["levenshtein", "jaro", "jaro_winkler"] = "jaro_winkler",
+    *,
+    ignore_case: bool = True,
+    min_score: float = 0.0,
+) -> list[tuple[str, float]]:
+    """The ``k`` candidates most similar to ``query`` as ``(candidate,
+    score)``, best first, scored by :func:`similarity`.
+
+    Candidates scoring below ``min_score`` are left out; ties keep the
+    order of ``candidates``.
+
+    Examples:
+        >>> closest_matches("kirsehir", ["Eskişehir", "Kırşehir"], k=1)
+        [('Kırşehir', 0.98)]
+    """
+    ...
+
+class CorpusStats:
+    """Corpus-level term statistics for keyword and collocation extraction.
+
+    Counts term frequencies, document frequencies and adjacent word pairs
+    over the documents added so far. Documents are split into word tokens
+    (punctuation, numbers and apostrophe suffixes dropped) and lowercased;
+    term lookups are case-insensitive.
+
+    - TF-IDF: relative corpus frequency times the smoothed inverse document
+      frequency ln((1 + N) / (1 + df)) + 1
+    - PMI: log2(p(xy) / (p(x) p(y))) of adjacent word pairs, in bits
+
+    Examples:
+        >>> stats = CorpusStats(["Türk kahvesi içtim.", "Türk kahvesi ve çay."])
+        >>> [(a, b, n) for a, b, _, n in stats.top_collocations(1)]
+        [('türk', 'kahvesi', 2)]
+        >>> stats.top_keywords(1)
+        [('kahvesi', 0.2857142857142857)]
+    """
+
+    def __init__(self, documents: Iterable[str] | None = None) -> None:
+        """Create statistics over an iterable of documents (or none yet)."""
+        ...
+    def add_document(self, text: str) -> None: ...
+    def term_frequency(self, term: str) -> int:
+        """Occurrences of a term in the corpus."""
+        ...
+    def document_frequency(self, term: str) -> int:
+        """Documents containing a term."""
+        ...
+    def tfidf(self, term: str) -> float:
+        """TF-IDF of a term over the corpus; 0.0 for unseen terms."""
+        ...
+    def top_keywords(self, k: int = 10) -> list[tuple[str, float]]:
+        """``(term, tfidf)`` pairs of the k highest scoring terms."""
+        ...
+    def stopword_candidates(
+        self, k: int = 50, min_df: int = 2
+    ) -> list[tuple[str, float]]:
+        """``(term, score)`` of the k likeliest corpus-specific stopwords among
+        terms in at least min_df documents; the score is relative corpus
+        frequency over IDF."""
+        ...
+    def top_collocations(
+        self, k: int = 10, min_count: int = 2
+    ) -> list[tuple[str, str, float, int]]:
+        """``(first, second, pmi, count)`` of the k word pairs with the highest
+        PMI among pairs seen at least min_count times."""
+        ...
+    @property
+    def documents(self) -> int: ...
+    @property
+    def tokens(self) -> int: ...
+    def __len__(self) -> int: ...
+
+class UnigramModel:
+    """Unigram subword model in SentencePiece ``.model`` format.
+
+    Files written by ``save`` load in ``sentencepiece.SentencePieceProcessor``,
+    whose identity normalizer expects text already passed through
+    ``fast_normalize``; ``encode`` here normalizes by itself.
+
+    Examples:
+        >>> model = train_unigram(open("corpus.txt"), vocab_size=8000)  # doctest: +SKIP
+        >>> model.encode("Kitaplarımdan")  # doctest: +SKIP
+        ['▁kitap', 'lar', 'ım', 'dan']
+    """
+
+    @staticmethod
+    def load(path: str | os.PathLike[str]) -> UnigramModel:
+        """Load a ``.model`` file (``ValueError`` if it is not a unigram model)."""
+        ...
+    @staticmethod
+    def from_bytes(data: bytes) -> UnigramModel: ...
+    def to_bytes(self) -> bytes: ...
+    def save(self, prefix: str) -> None:
+        """Write ``<prefix>.model`` and ``<prefix>.vocab``, like ``spm_train``."""
+        ...
+    def encode(self, text: str) -> list[str]:
+        """Normalize and segment text into pieces."""
+        ...
+    def encode_ids(self, text: str) -> list[int]: ...
+    def decode(self, pieces: list[str]) -> str: ...
+    def decode_ids(self, ids: list[int]) -> str: ...
+    def piece_to_id(self, piece: str) -> int:
+        """Id of a piece; unknown pieces map to the ``<unk>`` id (0)."""
+        ...
+    def id_to_piece(self, id: int) -> str: ...
+    @property
+    def pieces(self) -> list[tuple[str, float]]:
+        """``(piece, log probability)`` pairs in id order."""
+        ...
+    def __len__(self) -> int: ...
+
+def train_unigram(
+    texts: Iterable[str],
+    vocab_size: int = 8000,
+    *,
+    max_piece_length: int = 16,
+    character_coverage: float = 0.9995,
+    seed_morphemes: bool = True,
+    byte_fallback: bool = False,
+    threads: int = 1,
+) -> UnigramModel:
+    """Train a SentencePiece-style unigram subword vocabulary.
+
+    Texts are normalized with ``fast_normalize`` and split on whitespace.
+    With ``seed_morphemes`` the analyzer's roots and suffixes seed the
+    vocabulary and training penalizes pieces crossing their boundaries, so
+    pieces follow Turkish morphology.
+
+    Args:
+        texts: Iterable of texts; an open file works (one text per line)
+        vocab_size: Number of pieces, including ``<unk>``, ``<s>``, ``</s>``
+        max_piece_length: Longest piece in characters
+        character_coverage: Share of character occurrences to cover; rarer
+            characters become ``<unk>``
+        seed_morphemes: Guide training with the morphological analyzer
+        byte_fallback: Reserve ids 3-258 for ``<0x00>``..``<0xFF>`` and encode
+            unknown characters as UTF-8 byte pieces instead of ``<unk>``
+        threads: Worker threads (0 = all cores)
+
+    Raises:
+        ValueError: On an empty corpus, or when ``vocab_size`` is too small
+            for the characters or too large for the corpus
+    """
+    ...
+
+class BpeModel:
+    """Byte-pair encoding model in ``vocab.json``/``merges.txt`` format.
+
+    Files written by ``save`` load in ``tokenizers.models.BPE.from_file`` with
+    ``unk_token="<unk>"``; text must be normalized with ``fast_normalize`` and
+    its words prefixed with ``▁`` there, while ``encode`` here does both.
+
+    Examples:
+        >>> model = train_bpe(open("corpus.txt"), vocab_size=8000)  # doctest: +SKIP
+        >>> model.encode("Kitaplarımdan")  # doctest: +SKIP
+        ['▁kitap', 'lar', 'ım', 'dan']
+    """
+
+    @staticmethod
+    def load(prefix: str) -> BpeModel:
+        """Load ``<prefix>-vocab.json`` and ``<prefix>-merges.txt``."""
+        ...
+    def save(self, prefix: str) -> None:
+        """Write ``<prefix>-vocab.json`` and ``<prefix>-merges.txt``."""
+        ...
+    def encode(self, text: str) -> list[str]:
+        """Normalize and segment text into pieces, applying merges by rank."""
+        ...
+    def encode_ids(self, text: str) -> list[int]: ...
+    def decode(self, pieces: list[str]) -> str: ...
+    def decode_ids(self, ids: list[int]) -> str: ...
+    def piece_to_id(self, piece: str) -> int:
+        """Id of a piece; unknown pieces map to the ``<unk>`` id (0)."""
+        ...
+    def id_to_piece(self, id: int) -> str: ...
+    @property
+    def pieces(self) -> list[str]:
+        """Pieces in id order: ``<unk>``, the characters, then merged pieces."""
+        ...
+    @property
+    def merges(self) -> list[tuple[str, str]]:
+        """Merged ``(left, right)`` pairs by rank."""
+        ...
+    def __len__(self) -> int: ...
+
+def train_bpe(
+    texts: Iterable[str],
+    vocab_size: int = 8000,
+    *,
+    min_frequency: int = 2,
+    morpheme_boundaries: bool = True,
+    boundary_penalty: float = 0.9,
+    threads: int = 1,
+) -> BpeModel:
+    """Train a byte-pair encoding vocabulary with morphology-aware merges.
+
+    Texts are normalized with ``fast_normalize`` and split on whitespace.
+    With ``morpheme_boundaries`` a pair occurrence crossing a root/suffix
+    boundary of the analyzer counts only ``1 - boundary_penalty`` times, so
+    merges build roots and suffixes before pieces that cut across them.
+
+    Args:
+        texts: Iterable of texts; an open file works (one text per line)
+        vocab_size: Number of pieces, including ``<unk>`` and the characters;
+            training stops earlier when no pair reaches ``min_frequency``
+        min_frequency: Smallest weighted pair count that is merged
+        morpheme_boundaries: Guide merges with the morphological analyzer
+        boundary_penalty: Share of a boundary-crossing count that is
+            discarded, in [0, 1]; 0.0 is plain BPE
+        threads: Worker threads for the analyzer (0 = all cores)
+
+    Raises:
+        ValueError: On an empty corpus, a ``boundary_penalty`` outside
+            [0, 1], or a ``vocab_size`` too small for the characters
+    """
+    ...
+
+def segment_word(token: str, fallback: Literal["char", "byte"] = "char") -> list[str]:
+    """Split a normalized token into root and suffix pieces.
+
+    The first piece carries the ``▁`` word marker. Tokens the analyzer
+    cannot segment fall back to one piece per character, or with
+    ``fallback="byte"`` to ``"▁"`` followed by one ``<0xNN>`` piece per UTF-8
+    byte, so every token is segmented.
+
+    Raises:
+        ValueError: If fallback is not "char" or "byte"
+
+    Examples:
+        >>> segment_word("Kitaplarımdan")
+        ['▁kitap', 'lar', 'ım', 'dan']
+        >>> segment_word("xq")
+        ['▁x', 'q']
+        >>> segment_word("xq", fallback="byte")
+        ['▁', '<0x78>', '<0x71>']
+    """
+    ...
+
+def segment_morphemes(
+    text: str, fallback: Literal["char", "byte"] = "char"
+) -> list[str]:
+    """Normalize and tokenize text, then segment every token.
+
+    Joining the pieces (decoding byte pieces) and replacing ``▁`` with a
+    space gives back the normalized text.
+
+    Examples:
+        >>> segment_morphemes("Kitapları okudum 😀")
+        ['▁kitap', 'ları', '▁oku', 'du', 'm', '▁😀']
+    """
+    ...
+
+def warm_up(include_spell: bool = False) -> Dict[str, float]:
+    """Force initialization of the embedded dictionaries, gazetteers and regexes.
+
+    Components are built lazily on first use; calling this at start-up keeps
+    that cost off the first request. Runs without the GIL.
+
+    Args:
+        include_spell: Also build the spell-checking BK-tree (slow)
+
+    Returns:
+        Seconds spent initializing each component (near zero when a
+        component was already initialized)
+    """
+    ...
+
 def get_build_info() -> Dict[str, str]:
     """Get Durak build information for reproducibility.
     
@@ -263,7 +2980,9 @@ def get_build_info() -> Dict[str, str]:
     package name for research reproducibility tracking.
     
     Returns:
-        Dictionary with keys: durak_version, build_date, package_name
+        Dictionary with keys: durak_version, build_date, package_name,
+        rust_version, lemma_dict_top_n ("all" unless the dictionary was
+        pruned with DURAK_LEMMA_TOP_N) and lemma_dict_forms
     
     Examples:
         >>> info = get_build_info()
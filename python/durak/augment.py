@@ -0,0 +1,46 @@
+"""Morphology-based augmentation and noise injection for Turkish text.
+
+Augmented variants keep the lemmas of the input and differ from it by one
+edit: a case-marked noun re-inflected to another case, two pre-verbal
+constituents swapped, or the clitic "da"/"de" inserted or removed. They are
+meant as label-preserving extra examples for training classifiers on small
+datasets.
+
+``add_noise`` instead corrupts text the way user-generated content is
+corrupted, for evaluating how robust a model is to it.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+
+from durak.exceptions import RustExtensionError
+
+try:
+    from durak._durak_core import add_noise, augment, reinflect_cases
+except ImportError:
+
+    def add_noise(  # type: ignore[misc]
+        text: str,
+        profile: str = "social",
+        rate: float = 0.1,
+        seed: int = 0,
+        *,
+        keyboard: str = "q",
+    ) -> str:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def augment(  # type: ignore[misc]
+        text: str,
+        n: int = 1,
+        *,
+        operations: Sequence[str] | None = None,
+        seed: int = 0,
+    ) -> list[str]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def reinflect_cases(word: str) -> list[str]:  # type: ignore[misc]
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+__all__ = ["add_noise", "augment", "reinflect_cases"]
@@ -0,0 +1,203 @@
+"""Vectorized helpers for dataframe columns.
+
+``process_series`` replaces the usual ``series.apply(lambda text: ...)``
+pattern: the whole column is handed to Rust in one call and processed
+without the GIL, while missing values keep their position::
+
+    import pandas as pd
+    from durak import process_series
+
+    df = pd.DataFrame({"word": ["kitaplar", None, "geliyorum"]})
+    df["lemma"] = process_series(df["word"], "lemmatize")
+    # lemma: ["kitap", None, "gel"]
+
+By default a value that is neither a string nor missing fails the whole
+call. With ``errors="isolate"`` every item comes back as a
+:class:`BatchResult` instead, so one malformed document in a large batch
+does not discard the work done on the others::
+
+    >>> [r.ok for r in process_series(["kitaplar", 42], "lemmatize", errors="isolate")]
+    [True, False]
+
+The ``lemmatize_batch``, ``normalize_batch`` and ``tokenize_batch`` column
+helpers of the extension take the same ``errors`` argument.
+
+``tokenize_arrow`` takes an Arrow string column instead (a pyarrow array or
+chunked array, a polars Series) through the Arrow PyCapsule interface, reads
+its strings in place and returns list columns that convert back without
+copying::
+
+    tokens, starts, ends = tokenize_arrow(pa.array(["Merhaba dünya!"]))
+    pa.array(tokens)  # <pyarrow.lib.LargeListArray> [["Merhaba", "dünya", "!"]]
+    pl.Series(starts)  # [[0, 8, 13]]
+
+``normalize_arrow`` and ``lemmatize_arrow`` map a string column to a
+``large_string`` column the same way; the ``durak.polars`` namespace is
+built on these three functions.
+
+Long calls release the GIL but still stop on Ctrl-C (the notebook
+interrupt button) with KeyboardInterrupt, keeping the interpreter alive.
+To stop them from code instead, pass a :class:`CancellationToken` as
+``cancel=`` and call its ``cancel()`` method from another thread; the call
+then raises :class:`durak.exceptions.CancelledError`.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Iterable
+from typing import Literal, Union, overload
+
+from durak.exceptions import RustExtensionError
+
+SeriesStage = Literal["normalize", "correct_confusions", "tokenize", "lemmatize"]
+SERIES_STAGES: tuple[str, ...] = (
+    "normalize",
+    "correct_confusions",
+    "tokenize",
+    "lemmatize",
+)
+
+SeriesValue = Union[str, list[str], None]
+
+BatchErrors = Literal["raise", "isolate"]
+BATCH_ERROR_MODES: tuple[str, ...] = ("raise", "isolate")
+
+try:
+    from durak._durak_core import (
+        ArrowArray,
+        BatchResult,
+        CancellationToken,
+        lemmatize_arrow,
+        normalize_arrow,
+        tokenize_arrow,
+    )
+    from durak._durak_core import process_series as _process_series
+except ImportError:
+
+    class ArrowArray:  # type: ignore[no-redef]
+        """Arrow column built by durak; requires the Rust extension."""
+
+        @staticmethod
+        def from_pylist(values: list[str | None]) -> ArrowArray:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+    class CancellationToken:  # type: ignore[no-redef]
+        """Stops batch calls from another thread; requires the Rust extension."""
+
+        def __init__(self) -> None:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+    def tokenize_arrow(
+        array: object, *, threads: int = 1, cancel: CancellationToken | None = None
+    ) -> tuple[ArrowArray, ArrowArray, ArrowArray]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def normalize_arrow(
+        array: object,
+        *,
+        lowercase: bool = True,
+        handle_turkish_i: bool = True,
+        threads: int = 1,
+        cancel: CancellationToken | None = None,
+    ) -> ArrowArray:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def lemmatize_arrow(
+        array: object,
+        strategy: str = "hybrid",
+        *,
+        validate_roots: bool = False,
+        strict_validation: bool = False,
+        min_root_length: int | None = None,
+        threads: int = 1,
+        cancel: CancellationToken | None = None,
+    ) -> ArrowArray:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    class BatchResult:  # type: ignore[no-redef]
+        """Outcome of one batch item; requires the Rust extension."""
+
+        ok: bool
+        value: object
+        error: str | None
+
+    def _process_series(  # type: ignore[misc]
+        values: Iterable[object],
+        stage: str,
+        *,
+        errors: str = "raise",
+        cancel: CancellationToken | None = None,
+    ) -> list[SeriesValue] | list[BatchResult]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+@overload
+def process_series(
+    values: Iterable[object],
+    stage: SeriesStage,
+    *,
+    errors: Literal["raise"] = "raise",
+    cancel: CancellationToken | None = None,
+) -> list[SeriesValue]: ...
+@overload
+def process_series(
+    values: Iterable[object],
+    stage: SeriesStage,
+    *,
+    errors: Literal["isolate"],
+    cancel: CancellationToken | None = None,
+) -> list[BatchResult]: ...
+def process_series(
+    values: Iterable[object],
+    stage: SeriesStage,
+    *,
+    errors: BatchErrors = "raise",
+    cancel: CancellationToken | None = None,
+) -> list[SeriesValue] | list[BatchResult]:
+    """Apply a processing stage to every value of a column.
+
+    Args:
+        values: Strings with optional missing values (None, NaN or pd.NA);
+            any iterable works, including a pandas Series or numpy array
+        stage: One of ``normalize`` (Turkish-aware lowercasing),
+            ``correct_confusions``, ``tokenize`` (returns token lists) or
+            ``lemmatize`` (``Lemmatizer()`` defaults)
+        errors: ``raise`` to fail on the first malformed value, or
+            ``isolate`` to return a :class:`BatchResult` per value, with
+            the reason in place of the result for malformed ones
+        cancel: Token that stops the call once cancelled from another thread
+
+    Returns:
+        A list aligned with ``values``; missing inputs map to None
+
+    Raises:
+        ValueError: If ``stage`` or ``errors`` is unknown
+        TypeError: Under ``errors="raise"``, if a value is neither a string
+            nor missing
+        CancelledError: If ``cancel`` is cancelled before the column is done
+        KeyboardInterrupt: On Ctrl-C; the call stops at the next value
+
+    Examples:
+        >>> process_series(["kitaplar", None, float("nan")], "lemmatize")
+        ['kitap', None, None]
+        >>> process_series(["Merhaba dünya!"], "tokenize")
+        [['Merhaba', 'dünya', '!']]
+    """
+    return _process_series(values, stage, errors=errors, cancel=cancel)
+
+
+__all__ = [
+    "BATCH_ERROR_MODES",
+    "SERIES_STAGES",
+    "ArrowArray",
+    "BatchResult",
+    "CancellationToken",
+    "lemmatize_arrow",
+    "normalize_arrow",
+    "process_series",
+    "tokenize_arrow",
+]
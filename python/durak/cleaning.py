@@ -1,15 +1,75 @@
-"""Text cleaning utilities for Durak."""
+"""Text cleaning utilities for Durak.
+
+``dedup_lines`` removes repeated lines from a corpus file; with
+``max_memory_mb`` the lines seen so far spill to temporary files once they
+pass the cap, so deduplicating a dump larger than memory does not get the
+process killed. :class:`Deduplicator` flags near-duplicate documents
+(MinHash over word shingles), a standard step when cleaning crawled text.
+"""
 
 from __future__ import annotations
 
 import html
+import os
 import re
 import unicodedata
 from collections.abc import Iterable
 from functools import partial
 from typing import Callable
 
-from durak.exceptions import ConfigurationError
+from durak.exceptions import ConfigurationError, RustExtensionError
+
+try:
+    from durak._durak_core import (
+        Deduplicator,
+        dedup_lines,
+        fix_pdf_artifacts,
+        isolate_rtl,
+        rtl_spans,
+    )
+except ImportError:
+    from durak.batch import CancellationToken
+
+    def fix_pdf_artifacts(text: str) -> str:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    class Deduplicator:  # type: ignore[no-redef]
+        """MinHash near-duplicate detection; requires the Rust extension."""
+
+        def __init__(
+            self,
+            threshold: float = 0.8,
+            *,
+            num_perm: int = 128,
+            shingle_size: int = 3,
+            seed: int = 0,
+        ) -> None:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+    def dedup_lines(
+        source: str | os.PathLike[str],
+        destination: str | os.PathLike[str],
+        *,
+        max_memory_mb: int | None = None,
+        cancel: CancellationToken | None = None,
+    ) -> int:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def rtl_spans(text: str) -> list[tuple[str, str, int, int]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def isolate_rtl(text: str) -> str:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+# Bidi formatting characters: LRM, RLM, ALM, the embeddings and overrides
+# (LRE, RLE, PDF, LRO, RLO) and the isolates (LRI, RLI, FSI, PDI). They are
+# invisible but reorder the display of the text around them.
+BIDI_CONTROLS = (
+    "\u200e\u200f\u061c\u202a\u202b\u202c\u202d\u202e\u2066\u2067\u2068\u2069"
+)
 
 # Common stylistic variants mapped to ASCII or Turkish canonical characters.
 UNICODE_REPLACEMENTS = {
@@ -20,6 +80,7 @@ UNICODE_REPLACEMENTS = {
     "\u2014": "-",
     "\u2013": "-",
     "\u00a0": " ",
+    **dict.fromkeys(BIDI_CONTROLS, ""),
 }
 
 # Replace script/style blocks before stripping tags to avoid leaking JS/CSS.
@@ -76,7 +137,8 @@ EMOJI_REMOVE_PATTERN = re.compile(
 
 
 def normalize_unicode(text: str) -> str:
-    """Apply NFC normalization and map variants to standard characters."""
+    """Apply NFC normalization, map variants to standard characters and drop
+    bidi controls."""
     if not text:
         return ""
     normalized = unicodedata.normalize("NFC", text)
@@ -84,6 +146,25 @@ def normalize_unicode(text: str) -> str:
     return normalized.translate(translation_table)
 
 
+def strip_bidi_controls(text: str) -> str:
+    """Remove bidi control characters (``BIDI_CONTROLS``).
+
+    Offsets computed on the result no longer match the original text; the
+    tokenizer skips the controls, so tokenize the original when offsets
+    must point into it.
+
+    Examples:
+        >>> strip_bidi_controls("\u202eevet\u202c \u200fسلام")
+        'evet سلام'
+    """
+    if not text:
+        return ""
+    return text.translate(_BIDI_TABLE)
+
+
+_BIDI_TABLE = str.maketrans(dict.fromkeys(BIDI_CONTROLS, ""))
+
+
 def strip_html(text: str) -> str:
     """Remove HTML tags, script/style content, and unescape HTML entities."""
     if not text:
@@ -243,6 +324,7 @@ def clean_text(
     *,
     steps: Iterable[Callable[[str], str]] | None = None,
     emoji_mode: str = "keep",
+    pdf_artifacts: bool = False,
 ) -> str | tuple[str, list[str]]:
     """Apply the configured cleaning steps sequentially with emoji handling.
     
@@ -253,6 +335,8 @@ def clean_text(
             - "keep": Preserve emojis in the output (default)
             - "remove": Strip all emojis from the text
             - "extract": Return tuple of (cleaned_text, emoji_list)
+        pdf_artifacts: Join words broken by line wrapping in PDF extractions
+            (soft hyphens, hyphenated and mid-word line breaks) before cleaning
             
     Returns:
         - str: Cleaned text (if emoji_mode is "keep" or "remove")
@@ -268,6 +352,8 @@ def clean_text(
         'harika!'
         >>> clean_text("Harika! 🎉", emoji_mode="extract")
         ('harika!', ['🎉'])
+        >>> clean_text("kitap-\nları okudum", pdf_artifacts=True)
+        'kitapları okudum'
     """
     if not text:
         return ("", []) if emoji_mode == "extract" else ""
@@ -282,6 +368,10 @@ def clean_text(
     if emoji_mode == "extract":
         extracted_emojis = extract_emojis(text)
     
+    # Join broken words while the line breaks are still in place
+    if pdf_artifacts:
+        text = fix_pdf_artifacts(text)
+
     # Apply cleaning pipeline
     pipeline = tuple(steps) if steps is not None else DEFAULT_CLEANING_STEPS
     cleaned = text
@@ -304,6 +394,9 @@ def clean_text(
 
 __all__ = [
     "normalize_unicode",
+    "strip_bidi_controls",
+    "rtl_spans",
+    "isolate_rtl",
     "strip_html",
     "collapse_whitespace",
     "normalize_case",
@@ -313,5 +406,7 @@ __all__ = [
     "remove_emojis",
     "extract_emojis",
     "clean_text",
+    "fix_pdf_artifacts",
+    "Deduplicator",
     "DEFAULT_CLEANING_STEPS",
 ]
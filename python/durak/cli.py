@@ -6,21 +6,53 @@ corpus cleaning via the command line.
 
 from __future__ import annotations
 
+import contextlib
+import dataclasses
+import functools
+import hashlib
 import json
 import sys
+import time
+from collections.abc import Callable, Iterable, Iterator, Sequence
+from datetime import datetime, timezone
 from pathlib import Path
-from typing import Any, Literal, cast
+from typing import IO, Any, Literal, TypeVar, cast
 
 import click
+from click.core import ParameterSource
 
 from durak import (
     Lemmatizer,
+    NdjsonReader,
+    StopwordError,
     StopwordManager,
+    analyze,
     attach_detached_suffixes,
+    char_ngrams,
+    check_grammar,
     clean_text,
+    compare_segmentation,
+    detect_language,
+    diff_stopwords,
+    evaluate_lemmatizer,
+    extract_entities,
+    get_build_info,
+    get_resource_info,
+    line_anomalies,
+    list_profiles,
     load_stopword_resource,
+    load_stopwords,
+    merge_stopwords,
+    morphological_complexity,
+    ngrams,
+    sample_lines,
+    split_sentences,
     tokenize,
+    tokenize_spans,
+    train_unigram,
 )
+from durak.exceptions import ConfigurationError, TokenizationError
+from durak.writers import OutputWriter, WriterError, open_writer
 
 try:
     from durak import __version__
@@ -39,9 +71,291 @@ def cli() -> None:
     pass
 
 
+def _document_input(command: Callable[..., None]) -> Callable[..., None]:
+    """Accept the input as INPUT_FILE or --input FILE ('-' for stdin)."""
+
+    @functools.wraps(command)
+    def wrapper(
+        *args: Any,
+        input_file: str | None,
+        input_option: str | None,
+        text_field: str | None,
+        **kwargs: Any,
+    ) -> None:
+        if input_file is not None and input_option is not None:
+            raise click.UsageError("Pass either INPUT_FILE or --input, not both.")
+        source = input_option if input_option is not None else input_file
+        if source is None:
+            raise click.UsageError("Missing INPUT_FILE (or --input FILE).")
+        if text_field is not None:
+            click.get_current_context().meta[_FIELD_KEY] = text_field
+        command(*args, input_file=source, **kwargs)
+
+    path = click.Path(exists=True, allow_dash=True)
+    wrapper = click.option(
+        "--field",
+        "text_field",
+        metavar="PATH",
+        help="For line-by-line modes: read ndjson and take the text from this "
+        "dotted field path (e.g. retweeted_status.text); other fields are not "
+        "copied and records without the field are skipped",
+    )(wrapper)
+    wrapper = click.option(
+        "--input", "input_option", type=path, help="Input file (same as INPUT_FILE)"
+    )(wrapper)
+    return click.argument("input_file", required=False, type=path)(wrapper)
+
+
+# Key in click's ctx.meta where commands record counts for --manifest
+_COUNTS_KEY = "durak.counts"
+
+# Key in click's ctx.meta holding the --field path of the input
+_FIELD_KEY = "durak.field"
+
+
+def _record_counts(**counts: int) -> None:
+    """Record processing counts for the run manifest, if one is requested."""
+    ctx = click.get_current_context(silent=True)
+    if ctx is not None:
+        ctx.meta.setdefault(_COUNTS_KEY, {}).update(counts)
+
+
+def _file_record(path: str | None) -> dict[str, Any] | None:
+    if path is None:
+        return None
+    if path == "-" or not Path(path).is_file():
+        return {"path": path, "sha256": None, "bytes": None}
+    digest = hashlib.sha256()
+    with open(path, "rb") as handle:
+        for block in iter(lambda: handle.read(1 << 20), b""):
+            digest.update(block)
+    return {
+        "path": path,
+        "sha256": digest.hexdigest(),
+        "bytes": Path(path).stat().st_size,
+    }
+
+
+def _run_manifest(command: Callable[..., None]) -> Callable[..., None]:
+    """Add --manifest FILE: a JSON record of the run for reproducibility audits.
+
+    The manifest holds the command and its options, SHA-256 hashes of the
+    input and output files, the Durak version and build, the versions and
+    checksums of the embedded resources, counts and timing.
+    """
+
+    @functools.wraps(command)
+    def wrapper(*args: Any, manifest: str | None, **kwargs: Any) -> None:
+        if manifest is None:
+            command(*args, **kwargs)
+            return
+
+        started = datetime.now(timezone.utc)
+        clock = time.perf_counter()
+        command(*args, **kwargs)
+        elapsed = time.perf_counter() - clock
+
+        ctx = click.get_current_context()
+        counts = ctx.meta.get(_COUNTS_KEY, {"documents": 1})
+        config = {
+            key: value
+            for key, value in kwargs.items()
+            if key not in ("input_file", "output")
+        }
+        resources = {
+            name: {"version": info["version"], "checksum": info["checksum"]}
+            for name, info in sorted(get_resource_info().items())
+        }
+        record = {
+            "durak_version": __version__,
+            "command": ctx.info_name,
+            "argv": sys.argv[1:],
+            "config": config,
+            "input": _file_record(kwargs.get("input_file")),
+            "output": _file_record(kwargs.get("output")),
+            "counts": counts,
+            "started_at": started.isoformat(timespec="seconds"),
+            "elapsed_seconds": round(elapsed, 6),
+            "build": get_build_info(),
+            "resources": resources,
+        }
+        Path(manifest).write_text(
+            json.dumps(record, ensure_ascii=False, indent=2) + "\n", encoding="utf-8"
+        )
+
+    return click.option(
+        "--manifest",
+        type=click.Path(dir_okay=False),
+        help="Write a JSON run manifest (hashes, config, versions, counts)",
+    )(wrapper)
+
+
+_output_option = click.option(
+    "--output",
+    "-o",
+    type=click.Path(),
+    help="Output file or URL (default: stdout)",
+)
+
+
+@contextlib.contextmanager
+def _output(target: str | None) -> Iterator[OutputWriter]:
+    """Writer for --output: stdout, a file, or an http(s):// or s3:// URL."""
+    try:
+        with open_writer(target) as writer:
+            yield writer
+    except WriterError as e:
+        raise click.ClickException(str(e)) from e
+
+
+def _write_result(result: str, output: str | None, message: str) -> None:
+    if not output:
+        click.echo(result)
+        return
+    with _output(output) as writer:
+        writer.write(result)
+    click.echo(message)
+
+
+def _read_input(input_file: str) -> str:
+    if input_file == "-":
+        return sys.stdin.read()
+    return Path(input_file).read_text(encoding="utf-8")
+
+
+def _parse_jsonl_record(
+    line_no: int, line: str, text_field: str
+) -> tuple[str, dict[str, Any]]:
+    """Split a JSONL line into its text field and the remaining fields."""
+    try:
+        record = json.loads(line)
+    except json.JSONDecodeError as e:
+        raise click.ClickException(f"line {line_no}: invalid JSON ({e.msg})") from e
+    if not isinstance(record, dict) or not isinstance(record.get(text_field), str):
+        raise click.ClickException(
+            f"line {line_no}: expected an object with a string '{text_field}' field"
+        )
+    extra = {key: value for key, value in record.items() if key != text_field}
+    return record[text_field], extra
+
+
+def _documents(
+    input_file: str, *, raw: bool
+) -> Iterator[tuple[str, dict[str, Any], str]]:
+    """Yield (text, extra fields, line) for each document of a line-by-line input.
+
+    Each non-empty line is a document: either plain text, or a JSON object
+    whose "text" field is the text and whose other fields (ids, labels) are
+    the extra fields. With --field, the text is selected from each line by
+    NdjsonReader without parsing the rest of the record; the extra fields are
+    then only the line number, and records without the field are skipped.
+    The line is only returned with `raw`.
+    """
+    ctx = click.get_current_context(silent=True)
+    field = ctx.meta.get(_FIELD_KEY) if ctx is not None else None
+    if field is not None:
+        source = sys.stdin.buffer if input_file == "-" else input_file
+        try:
+            reader = NdjsonReader(source, field, raw=raw)
+            for record in reader:
+                yield record[1], {"line": record[0]}, record[2] if raw else ""
+        except ValueError as e:
+            raise click.ClickException(str(e)) from e
+        _record_counts(skipped=reader.skipped)
+        return
+
+    source = sys.stdin if input_file == "-" else open(input_file, encoding="utf-8")
+    try:
+        for line_no, line in enumerate(source, start=1):
+            line = line.rstrip("\r\n")
+            if not line.strip():
+                continue
+            if line.lstrip().startswith("{"):
+                text, extra = _parse_jsonl_record(line_no, line, "text")
+            else:
+                text, extra = line, {}
+            yield text, extra, line
+    finally:
+        if source is not sys.stdin:
+            source.close()
+
+
+def _write_jsonl(
+    input_file: str,
+    output: str | None,
+    process_document: Callable[[str], dict[str, Any]],
+) -> None:
+    """Run a command once per input line and write one JSON object per line.
+
+    Each document (see `_documents`) is processed and its extra fields are
+    copied to the output object.
+    """
+    documents = 0
+    try:
+        with _output(output) as sink:
+            for text, extra, _ in _documents(input_file, raw=False):
+                record = {**extra, **process_document(text)}
+                sink.write(json.dumps(record, ensure_ascii=False) + "\n")
+                documents += 1
+    finally:
+        _record_counts(documents=documents)
+
+    if output:
+        click.echo(f"{documents} documents written to {output}")
+
+
+def _filter_lines(
+    input_file: str,
+    output: str | None,
+    keep: Callable[[str], bool],
+) -> None:
+    """Copy the input lines whose text passes `keep` to the output unchanged.
+
+    JSON object lines are judged by their "text" field (or the --field path);
+    empty lines are dropped.
+    """
+    kept = dropped = 0
+    try:
+        with _output(output) as sink:
+            for text, _, line in _documents(input_file, raw=True):
+                if keep(text):
+                    sink.write(line + "\n")
+                    kept += 1
+                else:
+                    dropped += 1
+    finally:
+        _record_counts(documents=kept, dropped=dropped)
+
+    if output:
+        click.echo(f"{kept} lines written to {output} ({dropped} dropped)")
+
+
+def _process_tokens(
+    text: str, *, keep_emoji: bool, attach_suffixes: bool, remove_stopwords: bool
+) -> list[str]:
+    emoji_mode = "keep" if keep_emoji else "remove"
+    cleaned_result = clean_text(text, emoji_mode=emoji_mode)
+
+    if isinstance(cleaned_result, tuple):
+        cleaned = cleaned_result[0]
+    else:
+        cleaned = cleaned_result
+
+    tokens = tokenize(cleaned)
+
+    if attach_suffixes:
+        tokens = attach_detached_suffixes(tokens)
+
+    if remove_stopwords:
+        tokens = [t for t in tokens if not StopwordManager().is_stopword(t)]
+
+    return tokens
+
+
 @cli.command()
-@click.argument("input_file", type=click.Path(exists=True, allow_dash=True))
-@click.option("--output", "-o", type=click.Path(), help="Output file (default: stdout)")
+@_document_input
+@_run_manifest
+@_output_option
 @click.option("--remove-stopwords", "-s", is_flag=True, help="Remove stopwords")
 @click.option("--attach-suffixes", "-a", is_flag=True, help="Attach detached suffixes")
 @click.option("--lowercase", "-l", is_flag=True, default=True, help="Lowercase text")
@@ -51,7 +365,7 @@ def cli() -> None:
     "-f",
     type=click.Choice(["text", "json", "jsonl"]),
     default="text",
-    help="Output format (default: text)",
+    help="Output format; jsonl processes one document per line (default: text)",
 )
 def process(input_file: str, output: str | None, **kwargs: Any) -> None:
     """Process a text file through the Durak pipeline.
@@ -61,29 +375,25 @@ def process(input_file: str, output: str | None, **kwargs: Any) -> None:
     Example:
         durak process --remove-stopwords input.txt
         echo "İSTANBUL'da" | durak process
+        durak process --input docs.jsonl --output out.jsonl --format jsonl
     """
-    if input_file == "-":
-        text = sys.stdin.read()
-    else:
-        text = Path(input_file).read_text(encoding="utf-8")
-
-    emoji_mode = "keep" if kwargs["keep_emoji"] else "remove"
-    cleaned_result = clean_text(text, emoji_mode=emoji_mode)
-
-    if isinstance(cleaned_result, tuple):
-        cleaned = cleaned_result[0]
-    else:
-        cleaned = cleaned_result
+    options = {
+        "keep_emoji": kwargs["keep_emoji"],
+        "attach_suffixes": kwargs["attach_suffixes"],
+        "remove_stopwords": kwargs["remove_stopwords"],
+    }
+    output_format = kwargs.get("format", "text")
 
-    tokens = tokenize(cleaned)
+    if output_format == "jsonl":
 
-    if kwargs["attach_suffixes"]:
-        tokens = attach_detached_suffixes(tokens)
+        def process_document(text: str) -> dict[str, Any]:
+            tokens = _process_tokens(text, **options)
+            return {"tokens": tokens, "count": len(tokens)}
 
-    if kwargs["remove_stopwords"]:
-        tokens = [t for t in tokens if not StopwordManager().is_stopword(t)]
+        _write_jsonl(input_file, output, process_document)
+        return
 
-    output_format = kwargs.get("format", "text")
+    tokens = _process_tokens(_read_input(input_file), **options)
 
     if output_format == "json":
         result = json.dumps(
@@ -91,19 +401,295 @@ def process(input_file: str, output: str | None, **kwargs: Any) -> None:
             ensure_ascii=False,
             indent=2,
         )
-    elif output_format == "jsonl":
-        result = "\n".join(json.dumps({"token": t}, ensure_ascii=False) for t in tokens)
     else:
         result = " ".join(tokens)
 
-    if output:
-        Path(output).write_text(result, encoding="utf-8")
-        click.echo(f"Processed text written to {output}")
-    else:
-        click.echo(result)
+    _write_result(result, output, f"Processed text written to {output}")
+
+
+# Documents handed to the Rust core per call by `durak pipeline`
+_PIPELINE_BATCH_SIZE = 1024
+
+T = TypeVar("T")
+
+
+def _batches(items: Iterable[T], size: int) -> Iterator[list[T]]:
+    batch: list[T] = []
+    for item in items:
+        batch.append(item)
+        if len(batch) == size:
+            yield batch
+            batch = []
+    if batch:
+        yield batch
+
+
+def _pipeline_documents(
+    lines: Iterable[tuple[int, str]], input_format: str, text_field: str
+) -> Iterator[tuple[int, str, dict[str, Any]]]:
+    """Yield (line number, text, extra fields) for each non-empty input line."""
+    for line_no, line in lines:
+        line = line.rstrip("\r\n")
+        if not line.strip():
+            continue
+        if input_format == "text":
+            yield line_no, line, {}
+            continue
+        text, extra = _parse_jsonl_record(line_no, line, text_field)
+        yield line_no, text, extra
+
+
+def _format_pipeline_record(
+    output_format: str,
+    line_no: int,
+    text: str,
+    extra: dict[str, Any],
+    tokens: list[str],
+    lemmas: list[str] | None,
+) -> str:
+    """Render one processed document (without a trailing newline)."""
+    if output_format == "jsonl":
+        record: dict[str, Any] = {"line": line_no, **extra, "tokens": tokens}
+        if lemmas is not None:
+            record["lemmas"] = lemmas
+        return json.dumps(record, ensure_ascii=False)
+
+    if output_format == "tsv":
+        rows = []
+        for index, token in enumerate(tokens, start=1):
+            columns = [str(line_no), str(index), token]
+            if lemmas is not None:
+                columns.append(lemmas[index - 1])
+            rows.append("\t".join(columns))
+        return "\n".join(rows)
+
+    # CoNLL-U: ID FORM LEMMA UPOS XPOS FEATS HEAD DEPREL DEPS MISC
+    rows = [f"# sent_id = {line_no}", f"# text = {text}"]
+    for index, token in enumerate(tokens, start=1):
+        lemma = lemmas[index - 1] if lemmas is not None else "_"
+        rows.append("\t".join([str(index), token, lemma] + ["_"] * 7))
+    return "\n".join(rows) + "\n"
 
 
 @cli.command()
+@click.argument("input_file", type=click.Path(exists=True, allow_dash=True))
+@_run_manifest
+@_output_option
+@click.option(
+    "--input-format",
+    "-i",
+    type=click.Choice(["text", "jsonl"]),
+    default="text",
+    help="One document per line as plain text or JSON objects (default: text)",
+)
+@click.option(
+    "--text-field",
+    default="text",
+    show_default=True,
+    help="Field holding the document text in JSONL input",
+)
+@click.option(
+    "--config",
+    "config_file",
+    type=click.Path(exists=True, dir_okay=False),
+    help="Pipeline config (.toml, .yaml): steps, stopwords and lemmatizer options",
+)
+@click.option(
+    "--normalize/--no-normalize",
+    default=True,
+    help="Lowercase with Turkish I/ı handling (default: on)",
+)
+@click.option("--remove-stopwords", "-s", is_flag=True, help="Remove stopwords")
+@click.option("--lemmatize", "-l", "lemmatize_tokens", is_flag=True, help="Add lemmas")
+@click.option(
+    "--strategy",
+    type=click.Choice(["lookup", "heuristic", "hybrid"]),
+    default="hybrid",
+    help="Lemmatization strategy (default: hybrid)",
+)
+@click.option(
+    "--format",
+    "-f",
+    "output_format",
+    type=click.Choice(["jsonl", "tsv", "conll", "conllu"]),
+    default="jsonl",
+    help="Output format (default: jsonl)",
+)
+@click.option(
+    "--progress/--no-progress",
+    default=None,
+    help="Show a progress bar on stderr (default: for files on a terminal)",
+)
+@click.option(
+    "--threads",
+    "-j",
+    type=click.IntRange(min=0),
+    default=0,
+    show_default=True,
+    help="Worker threads for document processing (0: all cores)",
+)
+@click.option(
+    "--sample",
+    type=click.IntRange(min=1),
+    help="Process a reproducible random sample of N documents",
+)
+@click.option(
+    "--seed",
+    type=click.IntRange(min=0),
+    default=0,
+    show_default=True,
+    help="Random seed for --sample",
+)
+def pipeline(
+    input_file: str,
+    output: str | None,
+    input_format: str,
+    text_field: str,
+    config_file: str | None,
+    normalize: bool,
+    remove_stopwords: bool,
+    lemmatize_tokens: bool,
+    strategy: str,
+    output_format: str,
+    progress: bool | None,
+    threads: int,
+    sample: int | None,
+    seed: int,
+) -> None:
+    """Preprocess a corpus file line by line.
+
+    Each line of INPUT_FILE (or '-' for stdin) is one document. Documents go
+    through normalize → tokenize → stopword removal → lemmatize according to
+    the flags and are written as JSONL (one object per document), TSV (one
+    token per row: line, index, token[, lemma]) or CoNLL-U. conll keeps the
+    processed tokens with their lemmas; conllu annotates every token as
+    written with LEMMA, UPOS and FEATS for UD tools, so stopword removal and
+    the lemmatizer options do not apply to it. Batches of documents are
+    processed on --threads workers; output keeps input order.
+    --sample N processes N documents drawn by seeded reservoir sampling: the
+    same input, N and --seed always select the same documents, which keep
+    their original line numbers.
+    --config reads the steps, stopword domains and files, and lemmatizer
+    options from a TOML or YAML file; flags given on the command line
+    override it.
+
+    Example:
+        durak pipeline corpus.txt -s -l -o corpus.jsonl
+        durak pipeline corpus.txt --config pipeline.toml -o corpus.jsonl
+        durak pipeline tweets.jsonl -i jsonl --text-field body -f conll
+        durak pipeline corpus.txt -f conllu -o corpus.conllu
+        durak pipeline corpus.txt -l --threads 8 -o corpus.jsonl
+        durak pipeline corpus.txt --sample 1000 --seed 42 -o sample.jsonl
+    """
+    from durak.pipeline import process as process_doc
+    from durak.pipeline import to_conllu
+    from durak.worker import WorkerConfig, process_documents
+
+    config = WorkerConfig(
+        normalize=normalize,
+        remove_stopwords=remove_stopwords,
+        lemmatize=lemmatize_tokens,
+        strategy=cast(Literal["lookup", "heuristic", "hybrid"], strategy),
+    )
+    if config_file is not None:
+        from durak.config import PipelineConfig
+
+        try:
+            config = PipelineConfig.from_file(config_file).to_worker_config()
+        except ConfigurationError as e:
+            raise click.ClickException(str(e)) from e
+        # Flags given on the command line win over the file
+        context = click.get_current_context()
+        overrides = {
+            field: value
+            for field, param, value in (
+                ("normalize", "normalize", normalize),
+                ("remove_stopwords", "remove_stopwords", remove_stopwords),
+                ("lemmatize", "lemmatize_tokens", lemmatize_tokens),
+                ("strategy", "strategy", strategy),
+            )
+            if context.get_parameter_source(param) == ParameterSource.COMMANDLINE
+        }
+        config = dataclasses.replace(config, **overrides)
+
+    if progress is None:
+        progress = input_file != "-" and sys.stderr.isatty()
+
+    source = sys.stdin if input_file == "-" else open(input_file, encoding="utf-8")
+    bar = None
+    documents = 0
+    tokens = 0
+    try:
+        with _output(output) as sink:
+            numbered: Iterable[tuple[int, str]]
+            if sample is not None:
+                # Sampling reads the whole input before anything is processed
+                try:
+                    numbered = sample_lines(source, sample, seed, line_numbers=True)
+                except ValueError as e:
+                    raise click.ClickException(str(e)) from e
+            else:
+                lines: Iterable[str] = source
+                if progress and input_file != "-":
+                    bar = click.progressbar(
+                        length=Path(input_file).stat().st_size,
+                        label="Processing",
+                        file=sys.stderr,
+                    )
+
+                    def tracked(handle: IO[str], bar: Any) -> Iterator[str]:
+                        for line in handle:
+                            bar.update(len(line.encode("utf-8")))
+                            yield line
+
+                    lines = tracked(source, bar)
+                numbered = enumerate(lines, start=1)
+
+            if output_format == "tsv":
+                header = ["line", "index", "token"]
+                if config.lemmatize:
+                    header.append("lemma")
+                sink.write("\t".join(header) + "\n")
+
+            documents_in = _pipeline_documents(numbered, input_format, text_field)
+            if output_format == "conllu":
+                # Whole sentences as written: the Rust core tags and lemmatizes
+                for line_no, text, _ in documents_in:
+                    doc = process_doc(text, normalize=config.normalize, stopwords=False)
+                    sink.write(to_conllu(doc, sent_id=str(line_no)))
+                    documents += 1
+                    tokens += len(doc)
+            else:
+                for batch in _batches(documents_in, _PIPELINE_BATCH_SIZE):
+                    results = process_documents(
+                        [text for _, text, _ in batch], config, threads=threads
+                    )
+                    for (line_no, text, extra), result in zip(batch, results):
+                        assert result is not None
+                        record = _format_pipeline_record(
+                            output_format,
+                            line_no,
+                            text,
+                            extra,
+                            result["tokens"],
+                            result.get("lemmas"),
+                        )
+                        if record:
+                            sink.write(record + "\n")
+                    documents += len(batch)
+                    tokens += sum(len(result["tokens"]) for result in results if result)
+    finally:
+        _record_counts(documents=documents, tokens=tokens)
+        if bar is not None:
+            bar.render_finish()
+        if source is not sys.stdin:
+            source.close()
+
+    if output:
+        click.echo(f"Processed {documents} documents written to {output}")
+
+
+@cli.group(invoke_without_command=True)
 @click.option(
     "--resource",
     "-r",
@@ -117,29 +703,229 @@ def process(input_file: str, output: str | None, **kwargs: Any) -> None:
     default="txt",
     help="Output format (default: txt)",
 )
-@click.option("--output", "-o", type=click.Path(), help="Output file (default: stdout)")
-def stopwords(resource: str, format: str, output: str | None) -> None:  # noqa: A002
-    """List stopwords from a resource.
+@_output_option
+@click.pass_context
+def stopwords(
+    ctx: click.Context,
+    resource: str,
+    format: str,  # noqa: A002
+    output: str | None,
+) -> None:
+    """List, filter with, compare and merge stopword lists.
+
+    Without a subcommand, lists the stopwords of a resource (same as
+    `durak stopwords list`).
 
     Default resource: base/turkish
     Available resources: base/turkish, domains/social_media
     """
-    words = load_stopword_resource(resource)
+    if ctx.invoked_subcommand is not None:
+        return
+
+    _write_stopwords(_load_stopwords(resource, None), format, output)
+
+
+def _load_stopwords(resource: str, domain: str | None) -> set[str]:
+    """Words of a resource; a domain (which extends the base list) wins."""
+    name = f"domains/{domain}" if domain else resource
+    try:
+        return load_stopword_resource(name)
+    except StopwordError as e:
+        raise click.ClickException(str(e)) from e
 
+
+def _write_stopwords(words: set[str], format: str, output: str | None) -> None:
     if format == "json":
         result = json.dumps(sorted(words), ensure_ascii=False, indent=2)
     else:
         result = "\n".join(sorted(words))
 
+    _write_result(result, output, f"Stopwords written to {output}")
+
+
+_resource_option = click.option(
+    "--resource",
+    "-r",
+    default="base/turkish",
+    help="Stopword resource name (default: base/turkish)",
+)
+_domain_option = click.option(
+    "--domain",
+    "-d",
+    help="Domain list, e.g. social_media (includes the base list)",
+)
+
+
+@stopwords.command(name="list")
+@_resource_option
+@_domain_option
+@click.option(
+    "--format",
+    "-f",
+    type=click.Choice(["txt", "json"]),
+    default="txt",
+    help="Output format (default: txt)",
+)
+@_output_option
+def stopwords_list(
+    resource: str,
+    domain: str | None,
+    format: str,  # noqa: A002
+    output: str | None,
+) -> None:
+    """List the embedded stopwords of a resource or domain.
+
+    Example:
+        durak stopwords list
+        durak stopwords list --domain social_media --format json
+    """
+    _write_stopwords(_load_stopwords(resource, domain), format, output)
+
+
+@stopwords.command(name="filter")
+@click.argument("text", nargs=-1)
+@_resource_option
+@_domain_option
+@click.option(
+    "--keep",
+    "-k",
+    multiple=True,
+    help="Word never treated as a stopword (repeatable)",
+)
+@click.option(
+    "--format",
+    "-f",
+    type=click.Choice(["text", "json"]),
+    default="text",
+    help="Output format (default: text)",
+)
+def stopwords_filter(
+    text: tuple[str, ...],
+    resource: str,
+    domain: str | None,
+    keep: tuple[str, ...],
+    format: str,  # noqa: A002
+) -> None:
+    """Remove stopwords from TEXT, or from each line of stdin.
+
+    Tokens are printed space-separated, one output line per input line.
+
+    Example:
+        durak stopwords filter "bu kitap çok güzel"
+        cat tweets.txt | durak stopwords filter --domain social_media
+    """
+    manager = StopwordManager(base=_load_stopwords(resource, domain), keep=keep)
+    lines: Iterable[str] = [" ".join(text)] if text else sys.stdin
+
+    for line in lines:
+        tokens = [t for t in tokenize(line) if not manager.is_stopword(t)]
+        if format == "json":
+            click.echo(json.dumps({"tokens": tokens}, ensure_ascii=False))
+        else:
+            click.echo(" ".join(tokens))
+
+
+@stopwords.command(name="diff")
+@click.argument("list_a", type=click.Path(exists=True, dir_okay=False))
+@click.argument("list_b", type=click.Path(exists=True, dir_okay=False))
+@click.option(
+    "--case-sensitive",
+    is_flag=True,
+    help="Compare case-sensitively (default: Turkish-aware lowercasing)",
+)
+@click.option(
+    "--format",
+    "-f",
+    type=click.Choice(["text", "json"]),
+    default="text",
+    help="Output format (default: text)",
+)
+@click.option(
+    "--exit-code",
+    is_flag=True,
+    help="Exit with status 1 when the lists differ",
+)
+def stopwords_diff(
+    list_a: str,
+    list_b: str,
+    case_sensitive: bool,
+    format: str,  # noqa: A002
+    exit_code: bool,
+) -> None:
+    """Compare two stopword lists.
+
+    Entries are normalized (Unicode NFC, Turkish-aware lowercasing) before
+    comparison. Text output marks words only in LIST_A with '-' and words
+    only in LIST_B with '+'.
+
+    Example:
+        durak stopwords diff base.txt custom.txt
+    """
+    diff = diff_stopwords(
+        load_stopwords(list_a, case_sensitive=True),
+        load_stopwords(list_b, case_sensitive=True),
+        case_sensitive=case_sensitive,
+    )
+
+    if format == "json":
+        click.echo(json.dumps(diff.to_dict(), ensure_ascii=False, indent=2))
+    else:
+        for word in sorted(diff.only_in_a):
+            click.echo(f"- {word}")
+        for word in sorted(diff.only_in_b):
+            click.echo(f"+ {word}")
+        click.echo(
+            f"{len(diff.only_in_a)} only in {Path(list_a).name}, "
+            f"{len(diff.only_in_b)} only in {Path(list_b).name}, "
+            f"{len(diff.common)} common"
+        )
+
+    if exit_code and not diff.identical:
+        sys.exit(1)
+
+
+@stopwords.command(name="merge")
+@click.argument(
+    "lists", nargs=-1, required=True, type=click.Path(exists=True, dir_okay=False)
+)
+@click.option(
+    "--case-sensitive",
+    is_flag=True,
+    help="Deduplicate case-sensitively (default: Turkish-aware lowercasing)",
+)
+@_output_option
+def stopwords_merge(
+    lists: tuple[str, ...], case_sensitive: bool, output: str | None
+) -> None:
+    """Merge stopword lists into one sorted, deduplicated list.
+
+    Example:
+        durak stopwords merge base.txt legal.txt -o merged.txt
+    """
+    merged = merge_stopwords(
+        *(load_stopwords(path, case_sensitive=True) for path in lists),
+        case_sensitive=case_sensitive,
+    )
+    result = "\n".join(merged)
+
     if output:
-        Path(output).write_text(result, encoding="utf-8")
-        click.echo(f"Stopwords written to {output}")
+        message = f"Merged {len(merged)} stopwords into {output}"
+        _write_result(result + "\n", output, message)
     else:
         click.echo(result)
 
 
 @cli.command()
 @click.argument("tokens", nargs=-1)
+@_run_manifest
+@click.option(
+    "--input",
+    "input_file",
+    type=click.Path(exists=True, allow_dash=True),
+    help="Read words from a file ('-' for stdin); with --format jsonl, "
+    "each line is a document",
+)
+@_output_option
 @click.option(
     "--strategy",
     "-s",
@@ -156,7 +942,12 @@ def stopwords(resource: str, format: str, output: str | None) -> None:  # noqa:
     help="Output format (default: text)",
 )
 def lemmatize(
-    tokens: tuple[str, ...], strategy: str, metrics: bool, **kwargs: Any
+    tokens: tuple[str, ...],
+    input_file: str | None,
+    output: str | None,
+    strategy: str,
+    metrics: bool,
+    **kwargs: Any,
 ) -> None:
     """Lemmatize words.
 
@@ -165,17 +956,31 @@ def lemmatize(
     Example:
         durak lemmatize kitaplar evler geliyorum
         durak lemmatize --strategy hybrid kitaplar --format json
+        durak lemmatize --input docs.jsonl --output lemmas.jsonl --format jsonl
     """
+    strategy_literal = cast(Literal["lookup", "heuristic", "hybrid"], strategy)
+    lemmatizer_obj = Lemmatizer(strategy=strategy_literal, collect_metrics=metrics)
+
+    output_format = kwargs.get("format", "text")
+
+    if input_file is not None and output_format == "jsonl":
+
+        def lemmatize_document(text: str) -> dict[str, Any]:
+            words = tokenize(text, strip_punct=True)
+            return {"tokens": words, "lemmas": [lemmatizer_obj(w) for w in words]}
+
+        _write_jsonl(input_file, output, lemmatize_document)
+        return
+
+    if input_file is not None:
+        tokens = tokens + tuple(_read_input(input_file).split())
+
     if not tokens:
         click.echo("Error: No tokens provided", err=True)
         sys.exit(1)
 
-    strategy_literal = cast(Literal["lookup", "heuristic", "hybrid"], strategy)
-    lemmatizer_obj = Lemmatizer(strategy=strategy_literal, collect_metrics=metrics)
-
     results = [lemmatizer_obj(token) for token in tokens]
-
-    output_format = kwargs.get("format", "text")
+    _record_counts(tokens=len(tokens))
 
     if output_format == "json":
         result = json.dumps(
@@ -192,9 +997,9 @@ def lemmatize(
             for t, lemma in zip(tokens, results)
         )
     else:
-        for token, lemma in zip(tokens, results):
-            click.echo(f"{token} → {lemma}")
-        result = ""
+        result = "\n".join(
+            f"{token} → {lemma}" for token, lemma in zip(tokens, results)
+        )
 
     if metrics:
         if output_format != "text":
@@ -206,40 +1011,163 @@ def lemmatize(
                     {"metrics": metrics_obj.to_dict()}, ensure_ascii=False
                 )
         else:
-            click.echo("\n" + str(lemmatizer_obj.get_metrics()))
+            result += "\n\n" + str(lemmatizer_obj.get_metrics())
 
-    if output_format != "text":
-        click.echo(result)
+    _write_result(result, output, f"Lemmas written to {output}")
 
 
-@cli.command(name="tokenize")
-@click.argument("input_file", type=click.Path(exists=True, allow_dash=True))
-@click.option("--output", "-o", type=click.Path(), help="Output file (default: stdout)")
-@click.option("--stopwords", "-s", is_flag=True, help="Remove stopwords")
-@click.option("--suffixes", "-a", is_flag=True, help="Attach detached suffixes")
+def _read_gold_pairs(path: str) -> list[tuple[str, ...]]:
+    """Read word<TAB>lemma[<TAB>pos] lines, skipping blanks and # comments."""
+    pairs = []
+    for line_no, line in enumerate(_read_input(path).splitlines(), start=1):
+        if not line.strip() or line.startswith("#"):
+            continue
+        fields = tuple(field.strip() for field in line.split("\t"))
+        if len(fields) not in (2, 3) or not all(fields):
+            raise click.ClickException(
+                f"{path}:{line_no}: expected word<TAB>lemma[<TAB>pos]"
+            )
+        pairs.append(fields)
+    return pairs
+
+
+@cli.command(name="eval")
+@_run_manifest
+@click.option(
+    "--gold",
+    "input_file",
+    type=click.Path(exists=True, allow_dash=True),
+    required=True,
+    help="Gold lemmas as word<TAB>lemma[<TAB>pos] lines ('-' for stdin)",
+)
+@_output_option
+@click.option(
+    "--sample",
+    type=click.IntRange(min=0),
+    default=20,
+    show_default=True,
+    help="Tier disagreements to list",
+)
 @click.option(
     "--format",
     "-f",
-    type=click.Choice(["text", "json", "jsonl"]),
+    "output_format",
+    type=click.Choice(["text", "json"]),
     default="text",
     help="Output format (default: text)",
 )
-def tokenize_cmd(
-    input_file: str, output: str | None, stopwords: bool, suffixes: bool, **kwargs: Any
+def eval_cmd(
+    input_file: str, output: str | None, sample: int, output_format: str
 ) -> None:
-    """Tokenize a text file.
+    """Measure lemmatizer accuracy against gold lemmas.
 
-    INPUT_FILE: Path to input text file (or '-' for stdin)
+    Reports exact-match accuracy of lookup_lemma, strip_suffixes and
+    strip_suffixes_validated, a per-POS breakdown (the optional third column,
+    else durak's coarse tag) and a sample of words the tiers disagree on.
 
     Example:
-        durak tokenize --remove-stopwords --rejoin-suffixes input.txt
-        echo "Merhaba dünya" | durak tokenize --format json
+        durak eval --gold gold.tsv
+        durak eval --gold gold.tsv --format json -o report.json
     """
-    if input_file == "-":
-        text = sys.stdin.read()
+    report = evaluate_lemmatizer(_read_gold_pairs(input_file), sample=sample)
+    _record_counts(tokens=report.total)
+    if output_format == "json":
+        result = json.dumps(report.to_dict(), ensure_ascii=False, indent=2)
     else:
-        text = Path(input_file).read_text(encoding="utf-8")
+        result = str(report)
+    _write_result(result, output, f"Evaluation written to {output}")
+
+
+def _analysis_record(word: str) -> dict[str, Any]:
+    return {
+        "word": word,
+        "analyses": [
+            {
+                "root": root,
+                "pos": pos,
+                "tags": [tag for tag, _ in morphemes],
+                "morphemes": [
+                    {"tag": tag, "surface": surface} for tag, surface in morphemes
+                ],
+            }
+            for root, pos, morphemes in analyze(word)
+        ],
+    }
+
+
+def _analysis_rows(record: dict[str, Any], all_parses: bool) -> list[list[str]]:
+    """Columns: word, root, part of speech, morphemes, tags."""
+    analyses = record["analyses"]
+    if not analyses:
+        return [[record["word"], "?", "?", "?", "?"]]
+
+    rows = []
+    for index, analysis in enumerate(analyses if all_parses else analyses[:1]):
+        surfaces = [m["surface"] for m in analysis["morphemes"]]
+        rows.append(
+            [
+                record["word"] if index == 0 else "",
+                analysis["root"],
+                analysis["pos"],
+                "+".join([analysis["root"], *surfaces]),
+                "+".join([analysis["pos"], *analysis["tags"]]),
+            ]
+        )
+    return rows
+
+
+@cli.command(name="analyze")
+@click.argument("words", nargs=-1)
+@click.option(
+    "--all", "-a", "all_parses", is_flag=True, help="Show alternative parses too"
+)
+@click.option(
+    "--format",
+    "-f",
+    type=click.Choice(["text", "json", "jsonl"]),
+    default="text",
+    help="Output format; json and jsonl include all parses (default: text)",
+)
+def analyze_cmd(words: tuple[str, ...], all_parses: bool, **kwargs: Any) -> None:
+    """Show the morphological breakdown of words.
+
+    WORDS: Words to analyze (default: the words of stdin)
+
+    Prints the root, morphemes and suffix tags of the preferred parse in
+    columns, or every alternative parse with --all or a JSON format.
 
+    Example:
+        durak analyze kitaplarımdan
+        durak analyze yüzü --all
+        echo "Kitaplarımdan birini okudum" | durak analyze --format json
+    """
+    if not words:
+        words = tuple(tokenize(sys.stdin.read(), strip_punct=True))
+    if not words:
+        click.echo("Error: No words provided", err=True)
+        sys.exit(1)
+
+    records = [_analysis_record(word) for word in words]
+    output_format = kwargs.get("format", "text")
+
+    if output_format == "json":
+        click.echo(json.dumps(records, ensure_ascii=False, indent=2))
+        return
+    if output_format == "jsonl":
+        for record in records:
+            click.echo(json.dumps(record, ensure_ascii=False))
+        return
+
+    rows = [["WORD", "ROOT", "POS", "MORPHEMES", "TAGS"]]
+    for record in records:
+        rows.extend(_analysis_rows(record, all_parses))
+    widths = [max(len(row[i]) for row in rows) for i in range(len(rows[0]) - 1)]
+    for row in rows:
+        cells = [cell.ljust(width) for cell, width in zip(row, widths)]
+        click.echo("  ".join([*cells, row[-1]]))
+
+
+def _tokenize_document(text: str, *, stopwords: bool, suffixes: bool) -> list[str]:
     cleaned_result = clean_text(text)
 
     if isinstance(cleaned_result, tuple):
@@ -255,7 +1183,71 @@ def tokenize_cmd(
     if stopwords:
         tokens = [t for t in tokens if not StopwordManager().is_stopword(t)]
 
-    output_format = kwargs.get("format", "text")
+    return tokens
+
+
+@cli.command(name="tokenize")
+@_document_input
+@_run_manifest
+@_output_option
+@click.option("--stopwords", "-s", is_flag=True, help="Remove stopwords")
+@click.option("--suffixes", "-a", is_flag=True, help="Attach detached suffixes")
+@click.option(
+    "--profile",
+    "-p",
+    type=click.Choice(list(list_profiles())),
+    help="Run the steps and tokenizer options of a shipped profile",
+)
+@click.option(
+    "--format",
+    "-f",
+    type=click.Choice(["text", "json", "jsonl"]),
+    default="text",
+    help="Output format; jsonl processes one document per line (default: text)",
+)
+def tokenize_cmd(
+    input_file: str, output: str | None, stopwords: bool, suffixes: bool, **kwargs: Any
+) -> None:
+    """Tokenize a text file.
+
+    INPUT_FILE: Path to input text file (or '-' for stdin)
+
+    With --profile the profile's steps replace the default cleaning, so it
+    cannot be combined with --stopwords or --suffixes.
+
+    Example:
+        durak tokenize --remove-stopwords --rejoin-suffixes input.txt
+        echo "Merhaba dünya" | durak tokenize --format json
+        durak tokenize --input docs.jsonl --output tokens.jsonl --format jsonl
+        durak tokenize --profile legal contract.txt
+    """
+    output_format = kwargs.get("format", "text")
+    profile = kwargs.get("profile")
+
+    document: Callable[[str], list[str]]
+    if profile is not None:
+        if stopwords or suffixes:
+            raise click.UsageError(
+                "--profile cannot be combined with --stopwords or --suffixes."
+            )
+        from durak.pipeline import Pipeline
+
+        document = cast(Callable[[str], list[str]], Pipeline.from_profile(profile))
+    else:
+        document = functools.partial(
+            _tokenize_document, stopwords=stopwords, suffixes=suffixes
+        )
+
+    if output_format == "jsonl":
+
+        def tokenize_document(text: str) -> dict[str, Any]:
+            tokens = document(text)
+            return {"tokens": tokens, "count": len(tokens)}
+
+        _write_jsonl(input_file, output, tokenize_document)
+        return
+
+    tokens = document(_read_input(input_file))
 
     if output_format == "json":
         result = json.dumps(
@@ -263,29 +1255,163 @@ def tokenize_cmd(
             ensure_ascii=False,
             indent=2,
         )
-    elif output_format == "jsonl":
-        result = "\n".join(json.dumps({"token": t}, ensure_ascii=False) for t in tokens)
     else:
         result = "\n".join(tokens)
 
-    if output:
-        Path(output).write_text(result, encoding="utf-8")
-        click.echo(f"Tokens written to {output}")
-    else:
-        click.echo(result)
+    _write_result(result, output, f"Tokens written to {output}")
 
 
-@cli.command()
+@cli.command(name="diff-tokenize")
 @click.argument("input_file", type=click.Path(exists=True, allow_dash=True))
-@click.option("--output", "-o", type=click.Path(), help="Output file (default: stdout)")
-@click.option("--keep-emoji", "-e", is_flag=True, help="Keep emojis in output")
+@_run_manifest
+@click.option(
+    "--other-file",
+    type=click.Path(exists=True, dir_okay=False),
+    required=True,
+    help="Reference segmentation of INPUT_FILE",
+)
+@click.option(
+    "--reference-format",
+    type=click.Choice(["lines", "spaces"]),
+    default="lines",
+    show_default=True,
+    help="One segment per line, or tokens separated by whitespace",
+)
+@click.option(
+    "--sentences",
+    is_flag=True,
+    help="Compare sentence boundaries instead of tokens",
+)
+@click.option(
+    "--config",
+    "config_file",
+    type=click.Path(exists=True, dir_okay=False),
+    help="Pipeline config (.toml, .yaml) whose tokenizer options to use",
+)
+@click.option(
+    "--limit",
+    type=click.IntRange(min=0),
+    default=50,
+    show_default=True,
+    help="Mismatching regions to list",
+)
+@_output_option
 @click.option(
     "--format",
     "-f",
+    "output_format",
     type=click.Choice(["text", "json"]),
     default="text",
     help="Output format (default: text)",
 )
+def diff_tokenize(
+    input_file: str,
+    other_file: str,
+    reference_format: str,
+    sentences: bool,
+    config_file: str | None,
+    limit: int,
+    output: str | None,
+    output_format: str,
+) -> None:
+    """Compare durak's segmentation of a text with a reference one.
+
+    INPUT_FILE (or '-' for stdin) is tokenized, or split into sentences with
+    --sentences, and every segment is matched by its character span against
+    the segments of --other-file, which must occur in the text in order.
+    Tokens are placed by the tokenizer's offsets, so --config options that
+    rewrite tokens (split_hashtags) are compared at their source text.
+    Reports precision, recall and F1 and lists the regions where the two
+    segmentations differ, for tuning custom token patterns (--config).
+
+    Example:
+        durak diff-tokenize corpus.txt --other-file gold.tokens
+        durak diff-tokenize corpus.txt --other-file gold.txt --reference-format spaces
+        durak diff-tokenize corpus.txt --other-file gold.sents --sentences
+    """
+    text = _read_input(input_file)
+    reference_text = Path(other_file).read_text(encoding="utf-8")
+    if reference_format == "spaces":
+        reference = reference_text.split()
+    else:
+        reference = [line for line in reference_text.splitlines() if line.strip()]
+
+    # Token spans come from the tokenizer, so rewritten tokens keep their place
+    segment: Callable[[str], Sequence[object]] = tokenize_spans
+    if sentences:
+        segment = split_sentences
+    elif config_file is not None:
+        from durak.config import PipelineConfig
+
+        try:
+            options = PipelineConfig.from_file(config_file).tokenizer_options()
+        except ConfigurationError as e:
+            raise click.ClickException(str(e)) from e
+        segment = functools.partial(tokenize_spans, **options)
+
+    try:
+        diff = compare_segmentation(text, segment(text), reference)
+    except TokenizationError as e:
+        raise click.ClickException(str(e)) from e
+    _record_counts(tokens=len(diff.predicted))
+
+    if output_format == "json":
+        result = json.dumps(diff.to_dict(limit), ensure_ascii=False, indent=2)
+        _write_result(result, output, f"Comparison written to {output}")
+        return
+
+    mismatches = diff.mismatches()
+    lines = [
+        f"durak: {len(diff.predicted):,}  reference: {len(diff.reference):,}  "
+        f"matching: {diff.matching:,}",
+        f"Precision: {diff.precision:.1%}  Recall: {diff.recall:.1%}  "
+        f"F1: {diff.f1:.1%}",
+    ]
+    if mismatches:
+        shown = min(limit, len(mismatches))
+        lines += ["", f"Mismatches ({shown} of {len(mismatches)}):"]
+    for start, _, predicted, reference_segments in mismatches[:limit]:
+        line_no = text.count("\n", 0, start) + 1
+        column = start - (text.rfind("\n", 0, start) + 1) + 1
+        lines.append(
+            f"{line_no}:{column}  durak: "
+            + " ".join(f"[{s}]" for s in predicted)
+            + "  reference: "
+            + " ".join(f"[{s}]" for s in reference_segments)
+        )
+    _write_result("\n".join(lines), output, f"Comparison written to {output}")
+
+
+def _clean_document(
+    text: str, *, keep_emoji: bool, pdf_artifacts: bool = False
+) -> str:
+    emoji_mode = "keep" if keep_emoji else "remove"
+    cleaned_result = clean_text(
+        text, emoji_mode=emoji_mode, pdf_artifacts=pdf_artifacts
+    )
+
+    if isinstance(cleaned_result, tuple):
+        return cleaned_result[0]
+    return cleaned_result
+
+
+@cli.command()
+@_document_input
+@_run_manifest
+@_output_option
+@click.option("--keep-emoji", "-e", is_flag=True, help="Keep emojis in output")
+@click.option(
+    "--pdf-artifacts",
+    is_flag=True,
+    help="Join words broken by line wrapping in PDF-extracted text",
+)
+@click.option(
+    "--format",
+    "-f",
+    type=click.Choice(["text", "json", "jsonl"]),
+    default="text",
+    help="Output format; jsonl processes one document per line (default: text)",
+)
 def clean(input_file: str, output: str | None, **kwargs: Any) -> None:
     """Clean Turkish text (normalization and basic cleanup).
 
@@ -294,21 +1420,27 @@ def clean(input_file: str, output: str | None, **kwargs: Any) -> None:
     Example:
         durak clean input.txt > output.txt
         echo "İSTANBUL'da" | durak clean
+        durak clean --input docs.jsonl --output clean.jsonl --format jsonl
+        durak clean --pdf-artifacts extracted.txt
     """
-    if input_file == "-":
-        text = sys.stdin.read()
-    else:
-        text = Path(input_file).read_text(encoding="utf-8")
+    keep_emoji = kwargs["keep_emoji"]
+    pdf_artifacts = kwargs["pdf_artifacts"]
+    output_format = kwargs.get("format", "text")
 
-    emoji_mode = "keep" if kwargs["keep_emoji"] else "remove"
-    cleaned_result = clean_text(text, emoji_mode=emoji_mode)
+    if output_format == "jsonl":
 
-    if isinstance(cleaned_result, tuple):
-        cleaned = cleaned_result[0]
-    else:
-        cleaned = cleaned_result
+        def clean_document(text: str) -> dict[str, Any]:
+            cleaned = _clean_document(
+                text, keep_emoji=keep_emoji, pdf_artifacts=pdf_artifacts
+            )
+            return {"text": cleaned, "char_count": len(cleaned)}
 
-    output_format = kwargs.get("format", "text")
+        _write_jsonl(input_file, output, clean_document)
+        return
+
+    cleaned = _clean_document(
+        _read_input(input_file), keep_emoji=keep_emoji, pdf_artifacts=pdf_artifacts
+    )
 
     if output_format == "json":
         result = json.dumps(
@@ -319,25 +1451,33 @@ def clean(input_file: str, output: str | None, **kwargs: Any) -> None:
     else:
         result = cleaned
 
-    if output:
-        Path(output).write_text(result, encoding="utf-8")
-        click.echo(f"Cleaned text written to {output}")
-    else:
-        click.echo(result)
+    _write_result(result, output, f"Cleaned text written to {output}")
+
+
+def _normalizer(turkish_i: bool) -> Callable[[str], str]:
+    if turkish_i:
+        from durak.normalizer import Normalizer
+
+        return Normalizer(lowercase=True, handle_turkish_i=True)
+
+    from durak.cleaning import normalize_case
+
+    return lambda text: normalize_case(text, mode="lower")
 
 
 @cli.command()
-@click.argument("input_file", type=click.Path(exists=True, allow_dash=True))
-@click.option("--output", "-o", type=click.Path(), help="Output file (default: stdout)")
+@_document_input
+@_run_manifest
+@_output_option
 @click.option(
     "--turkish-i", is_flag=True, default=True, help="Handle Turkish I/ı conversion"
 )
 @click.option(
     "--format",
     "-f",
-    type=click.Choice(["text", "json"]),
+    type=click.Choice(["text", "json", "jsonl"]),
     default="text",
-    help="Output format (default: text)",
+    help="Output format; jsonl processes one document per line (default: text)",
 )
 def normalize(
     input_file: str, output: str | None, turkish_i: bool, **kwargs: Any
@@ -349,36 +1489,630 @@ def normalize(
     Example:
         durak normalize input.txt
         echo "İSTANBUL" | durak normalize --format json
+        durak normalize --input docs.jsonl --output norm.jsonl --format jsonl
     """
-    if input_file == "-":
-        text = sys.stdin.read()
+    normalizer = _normalizer(turkish_i)
+    output_format = kwargs.get("format", "text")
+
+    if output_format == "jsonl":
+
+        def normalize_document(text: str) -> dict[str, Any]:
+            normalized = normalizer(text)
+            return {"text": normalized, "char_count": len(normalized)}
+
+        _write_jsonl(input_file, output, normalize_document)
+        return
+
+    result = normalizer(_read_input(input_file))
+
+    if output_format == "json":
+        result = json.dumps(
+            {"text": result, "char_count": len(result)},
+            ensure_ascii=False,
+            indent=2,
+        )
+
+    _write_result(result, output, f"Normalized text written to {output}")
+
+
+def _entity_records(
+    text: str, label: tuple[str, ...]
+) -> list[dict[str, str | int]]:
+    entities = extract_entities(text)
+    return [
+        {"text": t, "label": lbl, "start": start, "end": end}
+        for t, lbl, start, end in entities
+        if not label or lbl in label
+    ]
+
+
+@cli.command()
+@_document_input
+@_run_manifest
+@_output_option
+@click.option(
+    "--label",
+    "-l",
+    multiple=True,
+    type=click.Choice(["PER", "LOC", "ORG", "DATE", "MONEY", "URL"]),
+    help="Only report entities with this label (repeatable)",
+)
+@click.option(
+    "--format",
+    "-f",
+    type=click.Choice(["text", "json", "jsonl"]),
+    default="text",
+    help="Output format; jsonl processes one document per line (default: text)",
+)
+def ner(
+    input_file: str, output: str | None, label: tuple[str, ...], **kwargs: Any
+) -> None:
+    """Extract named entities (people, places, organizations, dates, money, URLs).
+
+    INPUT_FILE: Path to input text file (or '-' for stdin)
+
+    Example:
+        durak ner input.txt
+        echo "Ahmet Yılmaz İstanbul'a gitti" | durak ner - --format json
+        durak ner --input docs.jsonl --output entities.jsonl --format jsonl
+    """
+    output_format = kwargs.get("format", "text")
+
+    if output_format == "jsonl":
+
+        def ner_document(text: str) -> dict[str, Any]:
+            entities = _entity_records(text, label)
+            return {"entities": entities, "count": len(entities)}
+
+        _write_jsonl(input_file, output, ner_document)
+        return
+
+    entities = _entity_records(_read_input(input_file), label)
+
+    if output_format == "json":
+        result = json.dumps(
+            {"entities": entities, "count": len(entities)},
+            ensure_ascii=False,
+            indent=2,
+        )
     else:
-        text = Path(input_file).read_text(encoding="utf-8")
+        result = "\n".join(
+            f"{e['text']}\t{e['label']}\t{e['start']}\t{e['end']}" for e in entities
+        )
 
-    if turkish_i:
-        from durak.normalizer import Normalizer
+    _write_result(result, output, f"Entities written to {output}")
+
+
+def _grammar_records(text: str) -> list[dict[str, str | int | None]]:
+    return [
+        {
+            "text": t,
+            "rule": rule,
+            "message": message,
+            "suggestion": suggestion,
+            "start": start,
+            "end": end,
+        }
+        for t, rule, message, suggestion, start, end in check_grammar(text)
+    ]
+
+
+@cli.command()
+@_document_input
+@_run_manifest
+@_output_option
+@click.option(
+    "--format",
+    "-f",
+    type=click.Choice(["text", "json", "jsonl"]),
+    default="text",
+    help="Output format; jsonl processes one document per line (default: text)",
+)
+def grammar(input_file: str, output: str | None, **kwargs: Any) -> None:
+    """Flag frequent spelling and grammar errors with suggested fixes.
+
+    Checks the clitic "de/da", the suffix and conjunction "ki", the question
+    particle "mi" and double negation under "ne ... ne ...".
+
+    INPUT_FILE: Path to input text file (or '-' for stdin)
+
+    Example:
+        durak grammar input.txt
+        echo "Bende geldim" | durak grammar - --format json
+        durak grammar --input docs.jsonl --output issues.jsonl --format jsonl
+    """
+    output_format = kwargs.get("format", "text")
+
+    if output_format == "jsonl":
+
+        def grammar_document(text: str) -> dict[str, Any]:
+            issues = _grammar_records(text)
+            return {"issues": issues, "count": len(issues)}
+
+        _write_jsonl(input_file, output, grammar_document)
+        return
 
-        normalizer = Normalizer(lowercase=True, handle_turkish_i=True)
-        result = normalizer(text)
+    issues = _grammar_records(_read_input(input_file))
+
+    if output_format == "json":
+        result = json.dumps(
+            {"issues": issues, "count": len(issues)},
+            ensure_ascii=False,
+            indent=2,
+        )
     else:
-        from durak.cleaning import normalize_case
+        result = "\n".join(
+            f"{i['text']}\t{i['suggestion'] or ''}\t{i['rule']}"
+            f"\t{i['start']}\t{i['end']}\t{i['message']}"
+            for i in issues
+        )
+
+    _write_result(result, output, f"Grammar issues written to {output}")
+
+
+@cli.command()
+@_document_input
+@_run_manifest
+@_output_option
+@click.option(
+    "--keep",
+    "-k",
+    "keep_language",
+    metavar="LANG",
+    help="Filter mode: copy only the input lines detected as LANG (e.g. tr)",
+)
+@click.option(
+    "--threshold",
+    "-t",
+    type=click.FloatRange(0.0, 1.0),
+    default=0.5,
+    show_default=True,
+    help="Minimum confidence for --keep",
+)
+@click.option(
+    "--format",
+    "-f",
+    type=click.Choice(["text", "json", "jsonl"]),
+    default="text",
+    help="Output format; jsonl processes one document per line (default: text)",
+)
+def detect(input_file: str, output: str | None, **kwargs: Any) -> None:
+    """Detect the language of a text (Turkish vs. other languages).
+
+    Prints the ISO 639-1 code and confidence, "und" when the language cannot
+    be determined. With --keep the command becomes a line filter that keeps
+    only the lines in the given language, e.g. to drop non-Turkish lines of
+    a corpus before expensive processing.
 
-        result = normalize_case(text, mode="lower")
+    INPUT_FILE: Path to input text file (or '-' for stdin)
 
+    Example:
+        echo "Bugün hava çok güzel" | durak detect -
+        durak detect --input docs.jsonl --output langs.jsonl --format jsonl
+        durak detect corpus.txt --keep tr --output turkish.txt
+    """
     output_format = kwargs.get("format", "text")
+    keep_language = kwargs.get("keep_language")
+    threshold = kwargs["threshold"]
+
+    if keep_language is not None:
+
+        def keep(text: str) -> bool:
+            language, confidence = detect_language(text)
+            return language == keep_language and confidence >= threshold
+
+        _filter_lines(input_file, output, keep)
+        return
+
+    if output_format == "jsonl":
+
+        def detect_document(text: str) -> dict[str, Any]:
+            language, confidence = detect_language(text)
+            return {"language": language, "confidence": confidence}
+
+        _write_jsonl(input_file, output, detect_document)
+        return
+
+    language, confidence = detect_language(_read_input(input_file))
 
     if output_format == "json":
         result = json.dumps(
-            {"text": result, "char_count": len(result)},
+            {"language": language, "confidence": confidence},
             ensure_ascii=False,
             indent=2,
         )
+    else:
+        result = f"{language}\t{confidence:.4f}"
 
-    if output:
-        Path(output).write_text(result, encoding="utf-8")
-        click.echo(f"Normalized text written to {output}")
+    _write_result(result, output, f"Detected language written to {output}")
+
+
+@cli.command(name="filter")
+@_document_input
+@_run_manifest
+@_output_option
+@click.option(
+    "--max-anomaly",
+    "-m",
+    type=click.FloatRange(min=0.0),
+    default=1.0,
+    show_default=True,
+    help="Drop lines whose anomaly score is above this value",
+)
+def filter_cmd(input_file: str, output: str | None, max_anomaly: float) -> None:
+    """Drop lines that look like spam, tables or markup.
+
+    Lines are scored by punctuation density, ALL-CAPS ratio, digit ratio and
+    whitespace ratio relative to the limits for running text (see
+    line_anomalies); lines scoring above --max-anomaly are dropped and the
+    others copied unchanged. JSON object lines are judged by their "text"
+    field.
+
+    INPUT_FILE: Path to input text file (or '-' for stdin)
+
+    Example:
+        durak filter corpus.txt --output clean.txt
+        durak filter --input docs.jsonl --max-anomaly 1.5 --output kept.jsonl
+    """
+
+    def keep(text: str) -> bool:
+        return all(s["anomaly"] <= max_anomaly for s in line_anomalies(text))
+
+    _filter_lines(input_file, output, keep)
+
+
+@cli.command()
+@_document_input
+@_run_manifest
+@_output_option
+@click.option(
+    "--format",
+    "-f",
+    type=click.Choice(["text", "json", "jsonl"]),
+    default="text",
+    help="Output format; jsonl processes one document per line (default: text)",
+)
+def stats(input_file: str, output: str | None, **kwargs: Any) -> None:
+    """Show morphological complexity statistics of a text.
+
+    INPUT_FILE: Path to input text file (or '-' for stdin)
+
+    Example:
+        durak stats input.txt
+        echo "Kitaplarımdan birini okudum" | durak stats - --format json
+        durak stats --input docs.jsonl --output stats.jsonl --format jsonl
+    """
+    output_format = kwargs.get("format", "text")
+
+    if output_format == "jsonl":
+        _write_jsonl(input_file, output, morphological_complexity)
+        return
+
+    metrics = morphological_complexity(_read_input(input_file))
+
+    if output_format == "json":
+        result = json.dumps(metrics, ensure_ascii=False, indent=2)
     else:
-        click.echo(result)
+        analyzed = int(metrics["analyzed"])
+        result = "\n".join(
+            [
+                f"Words:                  {int(metrics['words'])}",
+                f"Analyzed:               {analyzed} ({metrics['coverage']:.1%})",
+                "Morphemes per word:     "
+                f"{metrics['mean_morphemes_per_word']:.2f}",
+                f"Suffix entropy (bits):  {metrics['suffix_entropy']:.2f}",
+                f"Verb/noun ratio:        {metrics['verb_noun_ratio']:.2f}",
+            ]
+        )
+
+    _write_result(result, output, f"Statistics written to {output}")
+
+
+def _ngram_records(
+    text: str, n: int, *, chars: bool, top: int
+) -> list[dict[str, str | int]]:
+    if chars:
+        counted = char_ngrams(text, n, counts=True)
+    else:
+        words = tokenize(text, strip_punct=True)
+        word_counts = ngrams(words, n, counts=True)
+        counted = {" ".join(gram): count for gram, count in word_counts.items()}
+    items = list(counted.items())
+    if top:
+        items = items[:top]
+    return [{"ngram": gram, "count": count} for gram, count in items]
+
+
+@cli.command(name="ngrams")
+@_document_input
+@_run_manifest
+@_output_option
+@click.option(
+    "--n", "-n", type=click.IntRange(min=1), default=2, help="N-gram size (default: 2)"
+)
+@click.option(
+    "--chars", "-c", is_flag=True, help="Count character n-grams instead of words"
+)
+@click.option(
+    "--top",
+    "-k",
+    type=click.IntRange(min=0),
+    default=0,
+    help="Only report the k most frequent n-grams (default: all)",
+)
+@click.option(
+    "--format",
+    "-f",
+    type=click.Choice(["text", "json", "jsonl"]),
+    default="text",
+    help="Output format; jsonl processes one document per line (default: text)",
+)
+def ngrams_cmd(
+    input_file: str,
+    output: str | None,
+    n: int,
+    chars: bool,
+    top: int,
+    **kwargs: Any,
+) -> None:
+    """Count word or character n-grams, most frequent first.
+
+    Word n-grams are formed from the tokens without punctuation.
+
+    INPUT_FILE: Path to input text file (or '-' for stdin)
+
+    Example:
+        durak ngrams --n 2 input.txt
+        echo "ben de geldim" | durak ngrams - --n 3 --chars --format json
+        durak ngrams --input docs.jsonl --output bigrams.jsonl --format jsonl
+    """
+    output_format = kwargs.get("format", "text")
+
+    if output_format == "jsonl":
+
+        def ngram_document(text: str) -> dict[str, Any]:
+            records = _ngram_records(text, n, chars=chars, top=top)
+            return {"ngrams": records, "count": len(records)}
+
+        _write_jsonl(input_file, output, ngram_document)
+        return
+
+    records = _ngram_records(_read_input(input_file), n, chars=chars, top=top)
+
+    if output_format == "json":
+        result = json.dumps(
+            {"ngrams": records, "count": len(records)},
+            ensure_ascii=False,
+            indent=2,
+        )
+    else:
+        result = "\n".join(f"{r['ngram']}\t{r['count']}" for r in records)
+
+    _write_result(result, output, f"N-grams written to {output}")
+
+
+@cli.command(name="train-subword")
+@_document_input
+@click.option(
+    "--model-prefix",
+    "-o",
+    required=True,
+    help="Write PREFIX.model and PREFIX.vocab",
+)
+@click.option(
+    "--vocab-size",
+    "-v",
+    type=click.IntRange(min=4),
+    default=8000,
+    show_default=True,
+    help="Number of pieces, including <unk>, <s> and </s>",
+)
+@click.option(
+    "--max-piece-length",
+    type=click.IntRange(min=1),
+    default=16,
+    show_default=True,
+    help="Longest piece in characters",
+)
+@click.option(
+    "--character-coverage",
+    type=click.FloatRange(0, 1, min_open=True),
+    default=0.9995,
+    show_default=True,
+    help="Share of characters covered; rarer ones become <unk>",
+)
+@click.option(
+    "--morpheme-seeds/--no-morpheme-seeds",
+    default=True,
+    help="Guide training with the morphological analyzer (default: on)",
+)
+@click.option(
+    "--byte-fallback",
+    is_flag=True,
+    help="Encode unknown characters as <0xNN> byte pieces instead of <unk>",
+)
+@click.option(
+    "--threads",
+    "-j",
+    type=click.IntRange(min=0),
+    default=0,
+    show_default=True,
+    help="Worker threads for training (0: all cores)",
+)
+def train_subword(
+    input_file: str,
+    model_prefix: str,
+    vocab_size: int,
+    max_piece_length: int,
+    character_coverage: float,
+    morpheme_seeds: bool,
+    byte_fallback: bool,
+    threads: int,
+) -> None:
+    """Train a unigram subword vocabulary in SentencePiece format.
+
+    Each line of INPUT_FILE (or '-' for stdin) is normalized and split into
+    words. The resulting PREFIX.model loads in sentencepiece; normalize text
+    with durak.fast_normalize before encoding it there.
+
+    Example:
+        durak train-subword corpus.txt -v 16000 -o tr_unigram
+    """
+    source = sys.stdin if input_file == "-" else open(input_file, encoding="utf-8")
+    try:
+        model = train_unigram(
+            source,
+            vocab_size,
+            max_piece_length=max_piece_length,
+            character_coverage=character_coverage,
+            seed_morphemes=morpheme_seeds,
+            byte_fallback=byte_fallback,
+            threads=threads,
+        )
+    except ValueError as e:
+        raise click.ClickException(str(e)) from e
+    finally:
+        if source is not sys.stdin:
+            source.close()
+
+    model.save(model_prefix)
+    click.echo(
+        f"Trained {len(model)} pieces: {model_prefix}.model, {model_prefix}.vocab"
+    )
+
+
+def _best_time(run: Callable[[], object], repeat: int) -> float:
+    """Fastest of ``repeat`` timed calls, in seconds."""
+    best = float("inf")
+    for _ in range(repeat):
+        start = time.perf_counter()
+        run()
+        best = min(best, time.perf_counter() - start)
+    return best
+
+
+@cli.command()
+@click.option(
+    "--file",
+    "corpus_file",
+    required=True,
+    type=click.Path(exists=True, dir_okay=False),
+    help="UTF-8 corpus, one document per line",
+)
+@click.option(
+    "--repeat",
+    "-n",
+    type=click.IntRange(min=1),
+    default=3,
+    show_default=True,
+    help="Timed runs per stage; the fastest is reported",
+)
+@click.option(
+    "--threads",
+    "-j",
+    type=click.IntRange(min=0),
+    default=0,
+    show_default=True,
+    help="Worker threads for the batch stage (0: all cores)",
+)
+@click.option("--output", "-o", type=click.Path(), help="Output file (default: stdout)")
+@click.option(
+    "--format",
+    "-f",
+    "output_format",
+    type=click.Choice(["text", "json"]),
+    default="text",
+    help="Output format (default: text)",
+)
+def bench(
+    corpus_file: str,
+    repeat: int,
+    threads: int,
+    output: str | None,
+    output_format: str,
+) -> None:
+    """Measure the throughput of the Rust core on a corpus.
+
+    Times normalization, tokenization and lemmatization on one thread, then
+    the full pipeline (normalize, tokenize, lemmatize) on --threads workers,
+    and reports tokens/sec and MB/sec for each stage. The lemma cache is
+    emptied before every run, so repeated words only hit it within a run.
+
+    Example:
+        durak bench --file corpus.txt
+        durak bench --file corpus.txt --threads 8 --format json
+    """
+    try:
+        from durak._durak_core import (
+            clear_lemma_cache,
+            lemmatize_batch,
+            normalize_batch,
+            tokenize_batch,
+        )
+    except ImportError as e:
+        raise click.ClickException(
+            "Rust extension not installed. Run: maturin develop"
+        ) from e
+    from durak.worker import WorkerConfig, process_documents
+
+    text = Path(corpus_file).read_text(encoding="utf-8")
+    documents = [line for line in text.splitlines() if line.strip()]
+    megabytes = len(text.encode("utf-8")) / 1_000_000
+    normalized = normalize_batch(documents)
+    words = [token for tokens in tokenize_batch(normalized) for token in tokens or []]
+
+    def lemmatize() -> None:
+        clear_lemma_cache()
+        lemmatize_batch(words)
+
+    def pipeline() -> None:
+        clear_lemma_cache()
+        process_documents(documents, WorkerConfig(lemmatize=True), threads=threads)
+
+    stages = [
+        ("normalize", lambda: normalize_batch(documents)),
+        ("tokenize", lambda: tokenize_batch(normalized)),
+        ("lemmatize", lemmatize),
+        ("batch", pipeline),
+    ]
+    records = []
+    for name, run in stages:
+        seconds = _best_time(run, repeat)
+        records.append(
+            {
+                "stage": name,
+                "seconds": seconds,
+                "tokens_per_sec": len(words) / seconds if seconds else 0.0,
+                "mb_per_sec": megabytes / seconds if seconds else 0.0,
+            }
+        )
+    clear_lemma_cache()
+
+    if output_format == "json":
+        summary = {
+            "file": corpus_file,
+            "documents": len(documents),
+            "tokens": len(words),
+            "megabytes": megabytes,
+            "threads": threads,
+            "repeat": repeat,
+            "stages": records,
+        }
+        result = json.dumps(summary, ensure_ascii=False, indent=2)
+    else:
+        lines = [
+            f"Corpus: {len(documents)} documents, {len(words)} tokens, "
+            f"{megabytes:.2f} MB",
+            f"{'Stage':<12}{'Seconds':>10}{'Tokens/sec':>14}{'MB/sec':>10}",
+        ]
+        for record in records:
+            lines.append(
+                f"{record['stage']:<12}{record['seconds']:>10.4f}"
+                f"{record['tokens_per_sec']:>14,.0f}{record['mb_per_sec']:>10.2f}"
+            )
+        result = "\n".join(lines)
+
+    _write_result(result, output, f"Benchmark results written to {output}")
 
 
 @cli.command()
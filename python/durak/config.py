@@ -0,0 +1,435 @@
+"""Pipeline configuration files (TOML or YAML).
+
+A config file describes which stages run and with which resources, so the
+preprocessing of a project can be version-controlled next to its data::
+
+    # pipeline.toml
+    steps = ["normalize", "tokenize", "remove_stopwords", "lemmatize"]
+
+    [stopwords]
+    domains = ["social_media"]
+    additions = ["rt", "via"]
+    keep = ["değil"]
+    files = ["stopwords/project.txt"]
+
+    [rewrite]
+    files = ["rules/house_style.rules"]
+
+    [tokenizer]
+    strip_punct = true
+    clitics = "detach"
+    numbers = true
+
+    [lemmatizer]
+    strategy = "hybrid"
+    validate_roots = true
+    min_root_length = 3
+
+Load it with :meth:`durak.Pipeline.from_config` or pass it to
+``durak pipeline --config pipeline.toml``. Relative ``files`` paths are
+resolved against the directory of the config file. TOML needs Python 3.11
+or ``tomli``, YAML needs PyYAML (``pip install "durak-nlp[config]"``).
+
+The named profiles of ``resources/tr/config/profiles.json`` (news, social,
+legal, medical) use the same keys; load one with
+:meth:`PipelineConfig.from_profile`.
+
+Process-wide defaults, which apply to every call rather than to one
+pipeline, are set with :func:`durak.defaults.set_config`.
+"""
+
+from __future__ import annotations
+
+import functools
+import json
+import os
+from collections.abc import Callable, Mapping
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from durak.cleaning import clean_text
+from durak.exceptions import ConfigurationError
+from durak.lemmatizer import Lemmatizer, Strategy
+from durak.normalizer import Normalizer
+from durak.resources import RESOURCE_DIR, get_registry
+from durak.stopwords import (
+    StopwordManager,
+    load_stopword_resource,
+    load_stopwords,
+    remove_stopwords,
+    stopword_domain,
+)
+from durak.suffixes import attach_detached_suffixes
+from durak.tokenizer import RewriteRules, TokenizerConfig, tokenize
+from durak.worker import WorkerConfig
+
+# Steps that work on text, before "tokenize"
+TEXT_STEPS = ("clean", "normalize")
+# Steps that work on the token list, after "tokenize"
+TOKEN_STEPS = ("attach_suffixes", "rewrite", "remove_stopwords", "lemmatize")
+CONFIG_STEPS = TEXT_STEPS + ("tokenize",) + TOKEN_STEPS
+
+_STOPWORD_KEYS = ("domains", "additions", "keep", "files")
+_LEMMATIZER_TYPES: dict[str, type] = {
+    "strategy": str,
+    "validate_roots": bool,
+    "strict_validation": bool,
+    "min_root_length": int,
+}
+# Pattern toggles of TokenizerConfig, after the tokenize() options
+_TOKENIZER_TYPES: dict[str, type] = {
+    "strip_punct": bool,
+    "split_hashtags": bool,
+    "clitics": str,
+    "emails": bool,
+    "phones": bool,
+    "ibans": bool,
+    "dates": bool,
+    "times": bool,
+    "numbers": bool,
+}
+_PATTERN_KEYS = ("emails", "phones", "ibans", "dates", "times", "numbers")
+_SECTIONS: dict[str, tuple[str, ...]] = {
+    "stopwords": _STOPWORD_KEYS,
+    "rewrite": ("files",),
+    "tokenizer": tuple(_TOKENIZER_TYPES),
+    "lemmatizer": tuple(_LEMMATIZER_TYPES),
+}
+PROFILES_PATH = RESOURCE_DIR / "config" / "profiles.json"
+
+
+def _strings(value: Any, key: str) -> tuple[str, ...]:
+    if not isinstance(value, (list, tuple)) or not all(
+        isinstance(item, str) for item in value
+    ):
+        raise ConfigurationError(f"pipeline config '{key}' must be a list of strings")
+    return tuple(value)
+
+
+def _read(path: Path) -> Any:
+    suffix = path.suffix.lower()
+    if suffix == ".toml":
+        try:
+            import tomllib
+        except ImportError:
+            try:
+                import tomli as tomllib  # type: ignore[no-redef]
+            except ImportError as e:
+                raise ConfigurationError(
+                    "TOML configs need Python 3.11 or tomli: "
+                    'pip install "durak-nlp[config]"'
+                ) from e
+        try:
+            with path.open("rb") as handle:
+                return tomllib.load(handle)
+        except tomllib.TOMLDecodeError as e:
+            raise ConfigurationError(f"{path}: invalid TOML: {e}") from e
+    if suffix in (".yaml", ".yml"):
+        try:
+            import yaml
+        except ImportError as e:
+            raise ConfigurationError(
+                'YAML configs need PyYAML: pip install "durak-nlp[config]"'
+            ) from e
+        try:
+            with path.open(encoding="utf-8") as handle:
+                return yaml.safe_load(handle)
+        except yaml.YAMLError as e:
+            raise ConfigurationError(f"{path}: invalid YAML: {e}") from e
+    raise ConfigurationError(
+        f"unknown pipeline config format '{path.suffix}', expected .toml, .yaml or .yml"
+    )
+
+
+@dataclass(frozen=True)
+class PipelineConfig:
+    """Stages, stopword resources and lemmatizer options of a pipeline.
+
+    Attributes:
+        steps: Stage names in order; text stages (clean, normalize) come
+            before ``tokenize`` and token stages (attach_suffixes,
+            rewrite, remove_stopwords, lemmatize) after it; ``lemmatize``
+            replaces the tokens with their lemmas
+        stopword_domains: Stopword resources added to the base list, by
+            domain ("social_media") or full resource name
+        stopword_additions: Extra stopwords
+        stopword_keep: Words never treated as stopwords
+        stopword_files: Newline-delimited stopword files to add
+        rewrite_files: :class:`~durak.RewriteRules` files the ``rewrite``
+            step applies, in order
+        strip_punct: Drop punctuation tokens (as in ``tokenize``)
+        split_hashtags: Split hashtags into words (as in ``tokenize``)
+        clitics: "attach" or "detach" apostrophe suffixes (as in ``tokenize``)
+        tokenizer_config: Token patterns of the regex tokenizer
+        strategy: Lemmatization strategy (as in ``Lemmatizer``)
+        validate_roots: Validate stripped roots (as in ``Lemmatizer``)
+        strict_validation: Require dictionary roots (as in ``Lemmatizer``)
+        min_root_length: Minimum root length (as in ``Lemmatizer``)
+    """
+
+    steps: tuple[str, ...] = ("normalize", "tokenize")
+    stopword_domains: tuple[str, ...] = ()
+    stopword_additions: tuple[str, ...] = ()
+    stopword_keep: tuple[str, ...] = ()
+    stopword_files: tuple[Path, ...] = ()
+    rewrite_files: tuple[Path, ...] = ()
+    strip_punct: bool = False
+    split_hashtags: bool = False
+    clitics: str = "attach"
+    tokenizer_config: TokenizerConfig = field(default_factory=TokenizerConfig)
+    strategy: Strategy = "hybrid"
+    validate_roots: bool = False
+    strict_validation: bool = False
+    min_root_length: int = 2
+
+    def __post_init__(self) -> None:
+        unknown = [step for step in self.steps if step not in CONFIG_STEPS]
+        if unknown:
+            raise ConfigurationError(
+                f"Unknown pipeline step: '{unknown[0]}'. "
+                f"Available steps: {', '.join(CONFIG_STEPS)}"
+            )
+        tokenized = False
+        for step in self.steps:
+            if step == "tokenize":
+                tokenized = True
+            elif tokenized and step in TEXT_STEPS:
+                raise ConfigurationError(f"step '{step}' must come before 'tokenize'")
+            elif not tokenized and step in TOKEN_STEPS:
+                raise ConfigurationError(f"step '{step}' must come after 'tokenize'")
+        if "rewrite" in self.steps and not self.rewrite_files:
+            raise ConfigurationError("step 'rewrite' needs [rewrite] files")
+        if self.clitics not in ("attach", "detach"):
+            raise ConfigurationError(
+                f"pipeline config 'tokenizer.clitics' must be 'attach' or "
+                f"'detach', got '{self.clitics}'"
+            )
+        # Same errors as constructing the Lemmatizer directly
+        self.lemmatizer()
+
+    @classmethod
+    def from_dict(
+        cls, data: Mapping[str, Any], base_dir: str | os.PathLike[str] = "."
+    ) -> PipelineConfig:
+        """Build a config from parsed file contents, rejecting unknown keys."""
+        if not isinstance(data, Mapping):
+            raise ConfigurationError("pipeline config must be a mapping")
+        unknown = sorted(set(data) - {"steps", *_SECTIONS})
+        if unknown:
+            raise ConfigurationError(
+                f"unknown pipeline config keys: {', '.join(unknown)}"
+            )
+        options: dict[str, Any] = {}
+        patterns: dict[str, bool] = {}
+        if "steps" in data:
+            options["steps"] = _strings(data["steps"], "steps")
+        for section, keys in _SECTIONS.items():
+            values = data.get(section, {})
+            if not isinstance(values, Mapping):
+                raise ConfigurationError(f"pipeline config [{section}] must be a table")
+            unknown = sorted(set(values) - set(keys))
+            if unknown:
+                raise ConfigurationError(
+                    f"unknown pipeline config keys in [{section}]: {', '.join(unknown)}"
+                )
+            for key, value in values.items():
+                if section == "stopwords":
+                    options[f"stopword_{key}"] = _strings(value, f"stopwords.{key}")
+                    continue
+                if section == "rewrite":
+                    options["rewrite_files"] = _strings(value, "rewrite.files")
+                    continue
+                if section == "tokenizer":
+                    expected = _TOKENIZER_TYPES[key]
+                else:
+                    expected = _LEMMATIZER_TYPES[key]
+                # bool is an int subclass; reject True where a number is expected
+                if not isinstance(value, expected) or (
+                    expected is int and isinstance(value, bool)
+                ):
+                    raise ConfigurationError(
+                        f"pipeline config '{section}.{key}' must be "
+                        f"{expected.__name__}, got {type(value).__name__}"
+                    )
+                if key in _PATTERN_KEYS:
+                    patterns[key] = value
+                else:
+                    options[key] = value
+        if patterns:
+            options["tokenizer_config"] = TokenizerConfig(**patterns)
+        for key in ("stopword_files", "rewrite_files"):
+            if key in options:
+                options[key] = tuple(Path(base_dir) / path for path in options[key])
+        return cls(**options)
+
+    @classmethod
+    def from_file(cls, path: str | os.PathLike[str]) -> PipelineConfig:
+        """Read a ``.toml``, ``.yaml`` or ``.yml`` pipeline config.
+
+        Raises:
+            ConfigurationError: If the file cannot be parsed, or has unknown
+                keys, values of the wrong type or an invalid step order
+            OSError: If the file cannot be read
+        """
+        path = Path(path)
+        return cls.from_dict(_read(path), base_dir=path.parent)
+
+    @classmethod
+    def from_profile(cls, name: str) -> PipelineConfig:
+        """The config of a named profile (see :func:`list_profiles`).
+
+        Examples:
+            >>> PipelineConfig.from_profile("legal").clitics
+            'detach'
+
+        Raises:
+            ConfigurationError: If there is no profile ``name``
+        """
+        profiles = _profiles()
+        if name not in profiles:
+            raise ConfigurationError(
+                f"Unknown profile: '{name}'. "
+                f"Available profiles: {', '.join(profiles)}"
+            )
+        data = {
+            key: value
+            for key, value in profiles[name].items()
+            if key != "description"
+        }
+        return cls.from_dict(data, base_dir=PROFILES_PATH.parent)
+
+    def stopword_resources(self) -> set[str]:
+        """Stopwords of the domain resources and files, without the base list."""
+        words: set[str] = set()
+        for domain in self.stopword_domains:
+            if "/" in domain:
+                words |= load_stopword_resource(domain)
+            else:
+                words |= stopword_domain(domain)
+        for path in self.stopword_files:
+            words |= load_stopwords(path)
+        return words
+
+    def stopword_manager(self) -> StopwordManager:
+        """Stopwords of the config: base, domains, files and additions."""
+        return StopwordManager(
+            base=set(get_registry().stopwords()) | self.stopword_resources(),
+            additions=self.stopword_additions,
+            keep=self.stopword_keep,
+        )
+
+    def lemmatizer(self) -> Lemmatizer:
+        return Lemmatizer(
+            strategy=self.strategy,
+            validate_roots=self.validate_roots,
+            strict_validation=self.strict_validation,
+            min_root_length=self.min_root_length,
+        )
+
+    def tokenizer_options(self) -> dict[str, Any]:
+        """Keyword arguments of :func:`~durak.tokenize` for the config."""
+        return {
+            "strip_punct": self.strip_punct,
+            "split_hashtags": self.split_hashtags,
+            "clitics": self.clitics,
+            # None keeps non-regex strategies usable
+            "config": (
+                None
+                if self.tokenizer_config == TokenizerConfig()
+                else self.tokenizer_config
+            ),
+        }
+
+    def rewrite_rules(self) -> list[RewriteRules]:
+        """The compiled rules of ``rewrite_files``.
+
+        Raises:
+            ConfigurationError: If a rules file has a malformed rule
+            OSError: If a rules file cannot be read
+        """
+        try:
+            return [RewriteRules.from_file(path) for path in self.rewrite_files]
+        except ValueError as e:
+            raise ConfigurationError(str(e)) from e
+
+    def step(self, name: str) -> Callable[..., Any]:
+        """The callable running stage ``name`` with the config's options."""
+        if name == "rewrite":
+            rule_sets = self.rewrite_rules()
+
+            def rewrite(tokens: list[str]) -> list[str]:
+                for rules in rule_sets:
+                    tokens = rules(tokens)
+                return tokens
+
+            return rewrite
+        if name == "remove_stopwords":
+            return functools.partial(remove_stopwords, manager=self.stopword_manager())
+        if name == "tokenize":
+            return functools.partial(tokenize, **self.tokenizer_options())
+        if name == "lemmatize":
+            lemmatizer = self.lemmatizer()
+            return lambda tokens: [lemmatizer(token) for token in tokens]
+        steps: dict[str, Callable[..., Any]] = {
+            "clean": clean_text,
+            "normalize": Normalizer(),
+            "attach_suffixes": attach_detached_suffixes,
+        }
+        return steps[name]
+
+    def to_worker_config(self) -> WorkerConfig:
+        """The equivalent :class:`WorkerConfig`, with stopword resources
+        expanded into ``stopword_additions`` so workers need no files.
+
+        Raises:
+            ConfigurationError: If a step has no worker equivalent (clean,
+                attach_suffixes, rewrite) or tokenizer options are set
+        """
+        unsupported = [
+            s for s in self.steps if s in ("clean", "attach_suffixes", "rewrite")
+        ]
+        if unsupported:
+            raise ConfigurationError(
+                f"step '{unsupported[0]}' is not supported by document workers"
+            )
+        tokenizer = (
+            self.strip_punct,
+            self.split_hashtags,
+            self.clitics,
+            self.tokenizer_config,
+        )
+        if tokenizer != (False, False, "attach", TokenizerConfig()):
+            raise ConfigurationError(
+                "tokenizer options are not supported by document workers"
+            )
+        additions = self.stopword_resources() | set(self.stopword_additions)
+        return WorkerConfig(
+            normalize="normalize" in self.steps,
+            remove_stopwords="remove_stopwords" in self.steps,
+            stopword_additions=tuple(sorted(additions)),
+            stopword_keep=self.stopword_keep,
+            lemmatize="lemmatize" in self.steps,
+            strategy=self.strategy,
+            validate_roots=self.validate_roots,
+            strict_validation=self.strict_validation,
+            min_root_length=self.min_root_length,
+        )
+
+
+@functools.lru_cache(maxsize=1)
+def _profiles() -> dict[str, dict[str, Any]]:
+    with PROFILES_PATH.open(encoding="utf-8") as handle:
+        return json.load(handle)["profiles"]
+
+
+def list_profiles() -> dict[str, str]:
+    """Names of the shipped profiles with their descriptions."""
+    return {name: profile["description"] for name, profile in _profiles().items()}
+
+
+__all__ = [
+    "CONFIG_STEPS",
+    "PipelineConfig",
+    "list_profiles",
+]
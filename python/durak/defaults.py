@@ -0,0 +1,122 @@
+"""Process-wide defaults.
+
+Options that most projects set once are changed with :func:`set_config`
+(or temporarily with :func:`configure`) instead of being passed to every
+call::
+
+    durak.set_config(min_root_length=3, offsets="byte")
+
+They live in the Rust core behind a lock, so every thread sees the same
+values. An argument passed explicitly always wins over the default.
+
+Pipeline configuration files, which describe the stages of one pipeline,
+are read by :mod:`durak.config`.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Iterable, Iterator
+from contextlib import contextmanager
+from typing import Any
+
+from durak.exceptions import ConfigurationError, RustExtensionError, StopwordError
+from durak.stopwords import stopword_domain
+
+try:
+    from durak import _durak_core
+except ImportError:
+    _durak_core = None  # type: ignore[assignment]
+
+
+# Process-wide defaults before any set_config call
+DEFAULT_CONFIG: dict[str, Any] = {
+    "min_root_length": 2,
+    "check_harmony": True,
+    "offsets": "char",
+    "stopword_domains": [],
+}
+
+
+def get_config() -> dict[str, Any]:
+    """Process-wide defaults, as a new dict.
+
+    Keys: ``min_root_length`` and ``check_harmony`` (suffix stripping with
+    root validation), ``offsets`` (``"char"`` or ``"byte"``, the unit of
+    ``tokenize_with_offsets`` and ``tokenize_with_types``) and
+    ``stopword_domains`` (added by ``remove_stopwords``).
+
+    Examples:
+        >>> get_config()["offsets"]
+        'char'
+    """
+    if _durak_core is None:
+        return {**DEFAULT_CONFIG, "stopword_domains": []}
+    return dict(_durak_core.get_config())
+
+
+def set_config(
+    *,
+    min_root_length: int | None = None,
+    check_harmony: bool | None = None,
+    offsets: str | None = None,
+    stopword_domains: Iterable[str] | None = None,
+) -> dict[str, Any]:
+    """Change process-wide defaults; options left as None keep their value.
+
+    Returns:
+        The previous defaults, to restore with ``set_config(**previous)``
+
+    Raises:
+        ConfigurationError: If ``min_root_length`` is below 1, the offset
+            mode is unknown or a stopword domain does not exist
+        RustExtensionError: If the Rust extension is not installed
+    """
+    if _durak_core is None:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+    domains = None
+    if stopword_domains is not None:
+        domains = list(stopword_domains)
+        for name in domains:
+            try:
+                stopword_domain(name)
+            except StopwordError as e:
+                raise ConfigurationError(str(e)) from e
+    try:
+        return dict(
+            _durak_core.set_config(
+                min_root_length=min_root_length,
+                check_harmony=check_harmony,
+                offsets=offsets,
+                stopword_domains=domains,
+            )
+        )
+    except ValueError as e:
+        raise ConfigurationError(str(e)) from e
+
+
+@contextmanager
+def configure(**options: Any) -> Iterator[dict[str, Any]]:
+    """Apply :func:`set_config` options for the duration of a block.
+
+    The previous defaults are restored on exit. The defaults are process-wide,
+    so other threads see the change too while the block runs.
+
+    Examples:
+        >>> from durak import tokenize_with_offsets
+        >>> with configure(offsets="byte"):
+        ...     tokenize_with_offsets("ğ a")
+        [('ğ', 0, 2), ('a', 3, 4)]
+    """
+    previous = set_config(**options)
+    try:
+        yield get_config()
+    finally:
+        set_config(**previous)
+
+
+__all__ = [
+    "DEFAULT_CONFIG",
+    "configure",
+    "get_config",
+    "set_config",
+]
@@ -0,0 +1,28 @@
+"""Dialect and variant cue detection for Turkish text.
+
+Flags dialectal spellings ("gız", "bööle"), Azerbaijani-influenced forms
+("çox", "deyil") and dialectal suffixes ("gidecağam", "geliyrum") with an
+embedded cue lexicon, so corpus builders can stratify or filter regional
+variants. Cues are evidence for a variety; aggregate them per document.
+"""
+
+from __future__ import annotations
+
+from durak.exceptions import RustExtensionError
+
+DIALECT_VARIETIES = ("AZERBAIJANI", "ANATOLIAN", "EASTERN", "BLACK_SEA")
+
+try:
+    from durak._durak_core import detect_dialect, dialect_flags
+except ImportError:
+
+    def detect_dialect(
+        text: str,
+    ) -> list[tuple[str, str, str | None, int, int]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def dialect_flags(tokens: list[str]) -> list[str | None]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+__all__ = ["DIALECT_VARIETIES", "detect_dialect", "dialect_flags"]
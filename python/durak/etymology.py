@@ -0,0 +1,38 @@
+"""Loanword origin tags for Turkish words.
+
+Tags common loanwords with the language they entered Turkish from (Arabic,
+Persian, French, English) using an embedded lexicon, for lexicographic
+annotation of corpora. Inflected tokens are tagged through their root, so
+:func:`etymology_tags` works on tokenizer output.
+
+The lexicon also marks loanword roots whose suffixes take front vowels
+against their last vowel ("saatler", "golü"); :func:`takes_front_harmony`
+exposes this, and the analyzer and vowel harmony check honor it.
+"""
+
+from __future__ import annotations
+
+from durak.exceptions import RustExtensionError
+
+ETYMOLOGY_ORIGINS = ("ARABIC", "PERSIAN", "FRENCH", "ENGLISH")
+
+try:
+    from durak._durak_core import etymology_tags, takes_front_harmony, word_origin
+except ImportError:
+
+    def word_origin(word: str) -> str | None:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def etymology_tags(tokens: list[str]) -> list[str | None]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def takes_front_harmony(root: str) -> bool:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+__all__ = [
+    "ETYMOLOGY_ORIGINS",
+    "etymology_tags",
+    "takes_front_harmony",
+    "word_origin",
+]
@@ -5,6 +5,7 @@ and better debugging in production environments.
 
 Exception Hierarchy:
     DurakError
+    ├── CancelledError
     ├── ConfigurationError
     ├── ResourceError
     ├── RustExtensionError
@@ -48,6 +49,28 @@ class DurakError(Exception):
     pass
 
 
+class CancelledError(DurakError):
+    """Raised when a long-running call is stopped through its token.
+
+    Batch and sampling functions accept ``cancel=CancellationToken()``;
+    calling ``cancel()`` on the token from another thread (a UI callback,
+    a watchdog timer) stops the call at its next item. Ctrl-C raises
+    KeyboardInterrupt as usual instead.
+
+    Examples:
+        >>> import threading
+        >>> from durak import CancellationToken, normalize_batch
+        >>> token = CancellationToken()
+        >>> threading.Timer(60, token.cancel).start()
+        >>> normalize_batch(huge_corpus, threads=0, cancel=token)
+        Traceback (most recent call last):
+        ...
+        durak.exceptions.CancelledError: operation cancelled
+    """
+
+    pass
+
+
 class ConfigurationError(DurakError):
     """Raised when module configuration is invalid.
 
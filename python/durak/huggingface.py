@@ -0,0 +1,49 @@
+"""HuggingFace ``tokenizers`` integration: durak as a pre-tokenizer.
+
+Splits text into durak's Turkish tokens (apostrophe suffixes, URLs, dates,
+emoji clusters kept whole) before a BPE, Unigram or WordPiece model is
+trained or applied, so subword merges never cross word boundaries::
+
+    from tokenizers import Tokenizer, models, trainers
+    from durak.huggingface import durak_pre_tokenizer
+
+    tokenizer = Tokenizer(models.BPE(unk_token="[UNK]"))
+    tokenizer.pre_tokenizer = durak_pre_tokenizer()
+    tokenizer.train(["corpus.txt"], trainers.BpeTrainer(vocab_size=32000))
+
+Custom pre-tokenizers are Python objects that ``tokenizers`` cannot
+serialize: set the pre-tokenizer again after ``Tokenizer.from_file``.
+"""
+
+from __future__ import annotations
+
+try:
+    from tokenizers import NormalizedString, PreTokenizedString
+    from tokenizers.pre_tokenizers import PreTokenizer
+except ImportError as exc:  # pragma: no cover - depends on the environment
+    raise ImportError(
+        "durak.huggingface requires tokenizers. "
+        "Install with: pip install 'durak-nlp[huggingface]'"
+    ) from exc
+
+from durak.tokenizer import tokenize_with_offsets
+
+
+class DurakPreTokenizer:
+    """Custom pre-tokenizer splitting on durak's token boundaries."""
+
+    def split(self, index: int, normalized: NormalizedString) -> list[NormalizedString]:
+        # NormalizedString slices take character offsets
+        spans = tokenize_with_offsets(str(normalized), offsets="char")
+        return [normalized[start:end] for _, start, end in spans]
+
+    def pre_tokenize(self, pretok: PreTokenizedString) -> None:
+        pretok.split(self.split)
+
+
+def durak_pre_tokenizer() -> PreTokenizer:
+    """A ``tokenizers`` pre-tokenizer backed by :class:`DurakPreTokenizer`."""
+    return PreTokenizer.custom(DurakPreTokenizer())
+
+
+__all__ = ["DurakPreTokenizer", "durak_pre_tokenizer"]
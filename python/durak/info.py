@@ -37,6 +37,10 @@ def get_build_info() -> Dict[str, str]:
             - durak_version: Semantic version (e.g., '0.4.0')
             - build_date: ISO 8601 timestamp of build
             - package_name: Package name ('_durak_core')
+            - rust_version: Minimum supported Rust version
+            - lemma_dict_top_n: Lemmas kept by build-time pruning
+              (DURAK_LEMMA_TOP_N), or 'all'
+            - lemma_dict_forms: Inflected forms in the embedded dictionary
     
     Example:
         >>> from durak import get_build_info
@@ -172,6 +176,31 @@ def print_reproducibility_report() -> None:
     print()
 
 
+def warm_up(include_spell: bool = False) -> Dict[str, float]:
+    """Initialize Durak's embedded resources ahead of the first call.
+    
+    The lemma dictionary, stopword lists, gazetteers, morphological tables
+    and tokenizer regexes are built lazily on first use. Long-running
+    services should call this once at start-up so first-request latency
+    does not include that initialization. Calling it again is cheap.
+    
+    Args:
+        include_spell: Also build the spell-checking index used by
+            ``is_correct`` and ``suggest`` (the slowest component)
+    
+    Returns:
+        dict: Seconds spent initializing each component, keyed by
+        component name (e.g. 'lemma_dict', 'ner', 'spell')
+    
+    Example:
+        >>> from durak import warm_up
+        >>> timings = warm_up()
+        >>> print(f"Ready in {sum(timings.values()):.3f}s")
+        Ready in 0.041s
+    """
+    return _durak_core.warm_up(include_spell)
+
+
 def get_bibtex_citation() -> str:
     """Get BibTeX citation with exact version information.
     
@@ -222,4 +251,5 @@ __all__ = [
     'get_resource_info',
     'print_reproducibility_report',
     'get_bibtex_citation',
+    'warm_up',
 ]
@@ -0,0 +1,56 @@
+"""Turkish Q and F keyboard geometry for typo-aware correction.
+
+The same adjacency tables rank spelling suggestions and draw the typos of
+``add_noise``; they are exposed here for custom correction and fuzzy
+matching logic.
+"""
+
+from __future__ import annotations
+
+from typing import Literal
+
+from durak.exceptions import RustExtensionError
+
+Layout = Literal["q", "f"]
+
+try:
+    from durak._durak_core import (
+        keyboard_confusion_pairs,
+        keyboard_distance,
+        keyboard_edit_distance,
+        keyboard_layout,
+        keyboard_neighbors,
+    )
+except ImportError:
+
+    def keyboard_layout(layout: Layout = "q") -> list[str]:  # type: ignore[misc]
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def keyboard_neighbors(  # type: ignore[misc]
+        letter: str, layout: Layout = "q"
+    ) -> list[str]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def keyboard_distance(  # type: ignore[misc]
+        a: str, b: str, layout: Layout = "q"
+    ) -> float | None:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def keyboard_edit_distance(  # type: ignore[misc]
+        a: str, b: str, layout: Layout = "q"
+    ) -> float:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def keyboard_confusion_pairs(  # type: ignore[misc]
+        layout: Layout = "q",
+    ) -> list[tuple[str, str]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+__all__ = [
+    "keyboard_confusion_pairs",
+    "keyboard_distance",
+    "keyboard_edit_distance",
+    "keyboard_layout",
+    "keyboard_neighbors",
+]
@@ -0,0 +1,27 @@
+"""Language identification as a fast filter for Turkish corpora.
+
+Scores text against character trigram profiles embedded at build time, so
+pipelines can drop non-Turkish lines before expensive processing. Besides
+Turkish, the profiles cover the languages most often mixed into Turkish
+corpora: Azerbaijani, Kurmanji Kurdish, English, German, French, Spanish and
+Italian.
+"""
+
+from __future__ import annotations
+
+from durak.exceptions import RustExtensionError
+
+SUPPORTED_LANGUAGES = ("tr", "az", "ku", "en", "de", "fr", "es", "it")
+
+try:
+    from durak._durak_core import detect_language, is_turkish
+except ImportError:
+
+    def detect_language(text: str) -> tuple[str, float]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def is_turkish(text: str, threshold: float = 0.5) -> bool:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+__all__ = ["SUPPORTED_LANGUAGES", "detect_language", "is_turkish"]
@@ -1,34 +1,69 @@
 from __future__ import annotations
 
+from collections.abc import Iterable, Sequence
 from dataclasses import dataclass, field
 from time import perf_counter
-from typing import TYPE_CHECKING, Literal
+from typing import TYPE_CHECKING, Any, Literal
 
 from durak.exceptions import ConfigurationError, LemmatizerError, RustExtensionError
+from durak.morphology import pos_tag
+from durak.normalizer import fast_normalize
 
 if TYPE_CHECKING:
     pass
 
 try:
-    from durak._durak_core import lookup_lemma, strip_suffixes, strip_suffixes_validated
+    from durak._durak_core import (
+        clear_lemma_cache,
+        lemma_cache_info,
+        lemma_candidates,
+        lookup_lemma,
+        set_lemma_cache_size,
+        strip_suffixes,
+        strip_suffixes_validated,
+    )
 except ImportError:
 
+    def lemma_candidates(
+        word: str,
+        strategy: str = "hybrid",
+        *,
+        validate_roots: bool = False,
+        strict_validation: bool = False,
+        min_root_length: int | None = None,
+        tie_break: str = "prefer-dictionary",
+    ) -> list[str]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
     def lookup_lemma(word: str) -> str | None:
         raise RustExtensionError("Rust extension not installed. Run: maturin develop")
 
+    def lemma_cache_info() -> dict[str, int]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def set_lemma_cache_size(maxsize: int) -> None:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def clear_lemma_cache() -> None:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
     def strip_suffixes(word: str) -> str:
         raise RustExtensionError("Rust extension not installed. Run: maturin develop")
 
     def strip_suffixes_validated(
         word: str,
         strict: bool = False,
-        min_root_length: int = 2,
-        check_harmony: bool = True,
+        min_root_length: int | None = None,
+        check_harmony: bool | None = None,
+        preserve_proper_nouns: bool = True,
     ) -> str:
         raise RustExtensionError("Rust extension not installed. Run: maturin develop")
 
 
 Strategy = Literal["lookup", "heuristic", "hybrid"]
+TieBreak = Literal[
+    "prefer-dictionary", "prefer-shortest", "prefer-most-frequent", "return-all"
+]
 
 
 @dataclass
@@ -116,8 +151,18 @@ class Lemmatizer:
         strategy: Lemmatization strategy (lookup, heuristic, hybrid)
         validate_roots: Enable root validity checking for heuristic mode
         strict_validation: Require roots to be in lemma dictionary
-        min_root_length: Minimum acceptable root length (characters)
+        min_root_length: Minimum acceptable root length (characters); None
+            uses the process-wide default (see :func:`durak.defaults.set_config`)
         collect_metrics: Enable performance metrics collection (adds ~5-10% overhead)
+        tie_break: Which lemma wins when the dictionary, its other readings
+            and suffix stripping disagree: prefer-dictionary (default),
+            prefer-shortest, prefer-most-frequent, or return-all (``__call__``
+            returns the prefer-dictionary lemma, ``candidates()`` all of them)
+
+    Lemmas are cached in the Rust core per word and options (shared by all
+    lemmatizers, ``process`` and the batch functions), so repeated forms
+    are only lemmatized once; see :func:`lemma_cache_info` and
+    :func:`set_lemma_cache_size`. Metrics collection bypasses the cache.
     """
 
     def __init__(
@@ -125,8 +170,9 @@ class Lemmatizer:
         strategy: Strategy = "hybrid",
         validate_roots: bool = False,
         strict_validation: bool = False,
-        min_root_length: int = 2,
+        min_root_length: int | None = None,
         collect_metrics: bool = False,
+        tie_break: TieBreak = "prefer-dictionary",
     ):
         valid_strategies = ("lookup", "heuristic", "hybrid")
         if strategy not in valid_strategies:
@@ -135,14 +181,27 @@ class Lemmatizer:
                 f"Valid options: {', '.join(valid_strategies)}"
             )
 
-        if min_root_length < 1:
+        valid_tie_breaks = (
+            "prefer-dictionary",
+            "prefer-shortest",
+            "prefer-most-frequent",
+            "return-all",
+        )
+        if tie_break not in valid_tie_breaks:
+            raise ConfigurationError(
+                f"Unknown tie_break: '{tie_break}'. "
+                f"Valid options: {', '.join(valid_tie_breaks)}"
+            )
+
+        if min_root_length is not None and min_root_length < 1:
             raise ConfigurationError("min_root_length must be at least 1")
 
         self.strategy: Strategy = strategy
         self.validate_roots: bool = validate_roots
         self.strict_validation: bool = strict_validation
-        self.min_root_length: int = min_root_length
+        self.min_root_length: int | None = min_root_length
         self.collect_metrics: bool = collect_metrics
+        self.tie_break: TieBreak = tie_break
         self._metrics: LemmatizerMetrics | None = (
             LemmatizerMetrics() if collect_metrics else None
         )
@@ -173,31 +232,48 @@ class Lemmatizer:
         except Exception as e:
             raise LemmatizerError(f"Lemmatization failed: {e}") from e
 
+    def candidates(self, word: str) -> list[str]:
+        """Candidate lemmas of a word, best first under ``tie_break``.
+
+        A single lemma unless ``tie_break="return-all"``.
+
+        Raises:
+            LemmatizerError: If input is not a string
+            RustExtensionError: If Rust extension is not available
+
+        Examples:
+            >>> Lemmatizer(tie_break="return-all").candidates("gelir")
+            ['gel', 'gelir']
+        """
+        if not isinstance(word, str):
+            raise LemmatizerError(f"Input must be a string, got {type(word).__name__}")
+        return lemma_candidates(
+            word,
+            self.strategy,
+            validate_roots=self.validate_roots,
+            strict_validation=self.strict_validation,
+            min_root_length=self.min_root_length,
+            tie_break=self.tie_break,
+        )
+
     def _lemmatize(self, word: str) -> str:
         """Internal lemmatization logic."""
+        if self.tie_break not in ("prefer-dictionary", "return-all"):
+            return self.candidates(word)[0]
         if not self.collect_metrics:
             return self._lemmatize_without_metrics(word)
         return self._lemmatize_with_metrics(word)
 
     def _lemmatize_without_metrics(self, word: str) -> str:
-        """Fast path when metrics are disabled."""
-        if self.strategy in ("lookup", "hybrid"):
-            lemma = lookup_lemma(word)
-            if lemma is not None:
-                return lemma
-            if self.strategy == "lookup":
-                return word
-
-        if self.strategy in ("heuristic", "hybrid"):
-            if self.validate_roots:
-                return strip_suffixes_validated(
-                    word,
-                    strict=self.strict_validation,
-                    min_root_length=self.min_root_length,
-                )
-            return strip_suffixes(word)
-
-        return word
+        """Fast path when metrics are disabled: one call, served from the
+        lemma cache for repeated words."""
+        return lemma_candidates(
+            word,
+            self.strategy,
+            validate_roots=self.validate_roots,
+            strict_validation=self.strict_validation,
+            min_root_length=self.min_root_length,
+        )[0]
 
     def _lemmatize_with_metrics(self, word: str) -> str:
         """Metrics-tracked lemmatization path."""
@@ -284,8 +360,162 @@ class Lemmatizer:
             parts.append("validate_roots=True")
             if self.strict_validation:
                 parts.append("strict_validation=True")
-            if self.min_root_length != 2:
+            if self.min_root_length not in (None, 2):
                 parts.append(f"min_root_length={self.min_root_length}")
+        if self.tie_break != "prefer-dictionary":
+            parts.append(f"tie_break='{self.tie_break}'")
         if self.collect_metrics:
             parts.append("collect_metrics=True")
         return f"Lemmatizer({', '.join(parts)})"
+
+
+# Lemmatizer tiers compared by `evaluate_lemmatizer`, cheapest first
+EVALUATED_TIERS = ("lookup_lemma", "strip_suffixes", "strip_suffixes_validated")
+
+
+@dataclass
+class LemmatizerEvaluation:
+    """Accuracy of each lemmatizer tier against gold (word, lemma) pairs.
+
+    Attributes:
+        total: Number of gold pairs
+        correct: Exact matches per tier
+        only_correct: Pairs that only this tier lemmatized correctly
+        lookup_coverage: Fraction of words found in the lemma dictionary
+        per_pos: POS tag → tier → (correct, total)
+        confusion: Sample of pairs on which the tiers disagree, each with
+            ``word``, ``gold``, ``pos`` and the prediction of every tier
+            (None for a dictionary miss)
+    """
+
+    total: int = 0
+    correct: dict[str, int] = field(
+        default_factory=lambda: dict.fromkeys(EVALUATED_TIERS, 0)
+    )
+    only_correct: dict[str, int] = field(
+        default_factory=lambda: dict.fromkeys(EVALUATED_TIERS, 0)
+    )
+    lookup_coverage: float = 0.0
+    per_pos: dict[str, dict[str, tuple[int, int]]] = field(default_factory=dict)
+    confusion: list[dict[str, str | None]] = field(default_factory=list)
+
+    def accuracy(self, tier: str) -> float:
+        """Exact-match accuracy of a tier (0.0-1.0)."""
+        return self.correct[tier] / self.total if self.total else 0.0
+
+    def __str__(self) -> str:
+        """Human-readable report: tiers, per-POS accuracy, disagreements."""
+        width = max(map(len, EVALUATED_TIERS))
+        lines = [
+            f"Gold pairs: {self.total:,} (lookup coverage "
+            f"{self.lookup_coverage:.1%})",
+            "",
+            f"{'Tier':<{width}}  Accuracy  Correct  Only correct",
+        ]
+        for tier in EVALUATED_TIERS:
+            lines.append(
+                f"{tier:<{width}}  {self.accuracy(tier):>8.1%}  "
+                f"{self.correct[tier]:>7,}  {self.only_correct[tier]:>12,}"
+            )
+        lines += ["", "POS     Count  " + "  ".join(EVALUATED_TIERS)]
+        for pos, scores in sorted(self.per_pos.items()):
+            count = scores[EVALUATED_TIERS[0]][1]
+            cells = [
+                f"{correct / total:>{len(tier)}.1%}"
+                for tier, (correct, total) in scores.items()
+            ]
+            lines.append(f"{pos:<6} {count:>6,}  " + "  ".join(cells))
+        if self.confusion:
+            columns = ["word", "gold", "pos", *EVALUATED_TIERS]
+            lines += ["", "Disagreements:", "\t".join(columns)]
+            for row in self.confusion:
+                lines.append("\t".join(str(row[column]) for column in columns))
+        return "\n".join(lines)
+
+    def to_dict(self) -> dict[str, Any]:
+        """Export the report as a JSON-ready dictionary."""
+        return {
+            "total": self.total,
+            "lookup_coverage": self.lookup_coverage,
+            "tiers": {
+                tier: {
+                    "accuracy": self.accuracy(tier),
+                    "correct": self.correct[tier],
+                    "only_correct": self.only_correct[tier],
+                }
+                for tier in EVALUATED_TIERS
+            },
+            "per_pos": {
+                pos: {
+                    tier: {"correct": correct, "total": total}
+                    for tier, (correct, total) in scores.items()
+                }
+                for pos, scores in sorted(self.per_pos.items())
+            },
+            "confusion": self.confusion,
+        }
+
+
+def evaluate_lemmatizer(
+    gold_pairs: Iterable[Sequence[str]],
+    *,
+    sample: int = 20,
+) -> LemmatizerEvaluation:
+    """Measure each lemmatizer tier against gold lemmas.
+
+    Every word goes through ``lookup_lemma``, ``strip_suffixes`` and
+    ``strip_suffixes_validated`` (non-strict, process-wide defaults) after
+    Turkish lowercasing, and a prediction counts when it equals the
+    lowercased gold lemma exactly; a dictionary miss counts as wrong. Pairs
+    are broken down by POS: a third item in a pair is its gold tag,
+    otherwise durak's coarse tag of the word is used.
+
+    Args:
+        gold_pairs: (word, lemma) or (word, lemma, pos) items
+        sample: Number of tier disagreements to keep, in input order
+
+    Returns:
+        LemmatizerEvaluation with per-tier and per-POS accuracy
+
+    Examples:
+        >>> report = evaluate_lemmatizer([("kitaplar", "kitap"), ("evler", "ev")])
+        >>> report.accuracy("lookup_lemma")
+        1.0
+    """
+    pairs = [tuple(pair) for pair in gold_pairs]
+    for pair in pairs:
+        if len(pair) not in (2, 3):
+            raise ValueError(
+                f"gold pairs must be (word, lemma) or (word, lemma, pos), got {pair!r}"
+            )
+    untagged = [pair[0] for pair in pairs if len(pair) == 2]
+    tags = iter([tag for _, tag in pos_tag(untagged)] if untagged else [])
+
+    report = LemmatizerEvaluation(total=len(pairs))
+    found = 0
+    for pair in pairs:
+        word, gold = fast_normalize(pair[0]), fast_normalize(pair[1])
+        pos = pair[2] if len(pair) == 3 else next(tags)
+        predictions: dict[str, str | None] = {
+            "lookup_lemma": lookup_lemma(word),
+            "strip_suffixes": strip_suffixes(word),
+            "strip_suffixes_validated": strip_suffixes_validated(word),
+        }
+        found += predictions["lookup_lemma"] is not None
+        right = [tier for tier in EVALUATED_TIERS if predictions[tier] == gold]
+        scores = report.per_pos.setdefault(
+            pos, dict.fromkeys(EVALUATED_TIERS, (0, 0))
+        )
+        for tier in EVALUATED_TIERS:
+            correct, total = scores[tier]
+            hit = tier in right
+            scores[tier] = (correct + hit, total + 1)
+            report.correct[tier] += hit
+        if len(right) == 1:
+            report.only_correct[right[0]] += 1
+        if len(set(predictions.values())) > 1 and len(report.confusion) < sample:
+            report.confusion.append(
+                {"word": pair[0], "gold": pair[1], "pos": pos, **predictions}
+            )
+    report.lookup_coverage = found / len(pairs) if pairs else 0.0
+    return report
@@ -0,0 +1,46 @@
+"""Morphological analysis backed by the Rust analyzer."""
+
+from __future__ import annotations
+
+from durak.exceptions import RustExtensionError
+
+try:
+    from durak._durak_core import (
+        analyze,
+        analyze_all,
+        check_agreement,
+        generate,
+        pos_tag,
+    )
+except ImportError:
+
+    def analyze(word: str) -> list[tuple[str, str, list[tuple[str, str]]]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def analyze_all(
+        word: str,
+    ) -> list[tuple[str, str, list[tuple[str, str]], float]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def check_agreement(
+        text: str,
+    ) -> list[tuple[str, str, str, str, int, int]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def generate(root: str, suffix_tags: list[str], pos: str | None = None) -> str:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def pos_tag(tokens: list[str]) -> list[tuple[str, str]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+POS_TAGS = ("Noun", "Verb", "Adj", "Adv", "Pron", "Punc", "Num")
+
+__all__ = [
+    "POS_TAGS",
+    "analyze",
+    "analyze_all",
+    "check_agreement",
+    "generate",
+    "pos_tag",
+]
@@ -0,0 +1,200 @@
+"""Named entity recognition backed by Rust rules and gazetteers.
+
+``extract_entities`` covers the embedded gazetteers; :class:`KeywordMatcher`
+spots a project's own phrase lists (brands, products, watch lists) and
+:class:`PhraseMatcher` matches patterns by lemma ("kitap oku" finds "kitabı
+okudum"), both with the same ``(text, label, start, end)`` output.
+
+:func:`mask_entities` replaces person names, contact details and other
+entities with a placeholder before a corpus is shared (GDPR, KVKK), and
+returns the mapping :func:`unmask_entities` restores them from.
+"""
+
+from __future__ import annotations
+
+import os
+from collections.abc import Iterable, Mapping
+
+from durak.exceptions import ConfigurationError, RustExtensionError
+from durak.tokenizer import tokenize_with_types
+
+try:
+    from durak._durak_core import (
+        KeywordMatcher,
+        PhraseMatcher,
+        extract_dates,
+        extract_entities,
+        link_pronouns,
+    )
+except ImportError:
+
+    class KeywordMatcher:  # type: ignore[no-redef]
+        """Multi-phrase keyword spotting; requires the Rust extension."""
+
+        def __init__(
+            self,
+            keywords: Iterable[str] | Mapping[str, str],
+            *,
+            normalize: bool = True,
+            whole_words: bool = True,
+        ) -> None:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+        @staticmethod
+        def from_file(
+            path: str | os.PathLike[str],
+            *,
+            normalize: bool = True,
+            whole_words: bool = True,
+        ) -> KeywordMatcher:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+    class PhraseMatcher:  # type: ignore[no-redef]
+        """Lemma-level phrase matching; requires the Rust extension."""
+
+        def __init__(self, patterns: Iterable[str] | Mapping[str, str]) -> None:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+        @staticmethod
+        def from_file(path: str | os.PathLike[str]) -> PhraseMatcher:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+    def extract_dates(text: str) -> list[tuple[str, str, str, int, int]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def extract_entities(text: str) -> list[tuple[str, str, int, int]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def link_pronouns(
+        text: str, window: int = 2
+    ) -> list[tuple[str, str, int, int, str, int, int]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+# Labels returned by extract_entities()
+ENTITY_LABELS = ("PER", "LOC", "ORG", "DATE", "MONEY", "URL")
+
+# Anaphor kinds returned by link_pronouns()
+LINK_KINDS = ("PRONOUN", "ZERO")
+
+# Labels mask_entities() can mask: the entity labels and the token types of
+# contact details
+MASK_LABELS = ENTITY_LABELS + ("EMAIL", "PHONE", "IBAN")
+_TOKEN_LABELS = ("EMAIL", "PHONE", "IBAN")
+_LABEL_ALIASES = {"PERSON": "PER"}
+
+
+def mask_entities(
+    text: str,
+    labels: Iterable[str] = ("PER", "PHONE", "EMAIL", "IBAN"),
+    replacement: str = "[MASKED]",
+) -> tuple[str, list[tuple[str, str, int, int]]]:
+    """Replace entities with a placeholder, keeping a reversible mapping.
+
+    Entities come from :func:`extract_entities` (``PER``, ``LOC``, ``ORG``,
+    ``DATE``, ``MONEY``, ``URL``) and the typed tokenizer (``EMAIL``,
+    ``PHONE``, ``IBAN``); ``PERSON`` is accepted for ``PER``. Where spans
+    overlap, the one starting first (then the longest) is masked.
+
+    Args:
+        text: Input text
+        labels: Labels to mask, from ``MASK_LABELS``
+        replacement: Placeholder; ``{label}`` in it is replaced with the
+            entity's label, as in ``"[{label}]"``
+
+    Returns:
+        The masked text and ``(original, label, start, end)`` for every
+        masked entity, with character offsets of its placeholder in the
+        masked text
+
+    Raises:
+        ConfigurationError: For a label not in ``MASK_LABELS``
+
+    Examples:
+        >>> masked, mapping = mask_entities("Ayşe Yılmaz: 0532 123 45 67")
+        >>> masked
+        '[MASKED]: [MASKED]'
+        >>> mapping
+        [('Ayşe Yılmaz', 'PER', 0, 8), ('0532 123 45 67', 'PHONE', 10, 18)]
+        >>> unmask_entities(masked, mapping)
+        'Ayşe Yılmaz: 0532 123 45 67'
+    """
+    wanted = set()
+    for label in labels:
+        label = _LABEL_ALIASES.get(label, label)
+        if label not in MASK_LABELS:
+            raise ConfigurationError(
+                f"Unknown label: '{label}'. "
+                f"Available labels: {', '.join(MASK_LABELS)}"
+            )
+        wanted.add(label)
+
+    spans = [
+        (start, end, label)
+        for _, label, start, end in extract_entities(text)
+        if label in wanted
+    ]
+    if wanted.intersection(_TOKEN_LABELS):
+        spans.extend(
+            (start, end, kind)
+            for _, kind, start, end in tokenize_with_types(text, offsets="char")
+            if kind in wanted
+        )
+    spans.sort(key=lambda span: (span[0], -span[1]))
+
+    parts: list[str] = []
+    mapping: list[tuple[str, str, int, int]] = []
+    position = 0
+    length = 0
+    for start, end, label in spans:
+        if start < position:
+            continue
+        parts.append(text[position:start])
+        length += start - position
+        placeholder = replacement.replace("{label}", label)
+        parts.append(placeholder)
+        mapping.append((text[start:end], label, length, length + len(placeholder)))
+        length += len(placeholder)
+        position = end
+    parts.append(text[position:])
+    return "".join(parts), mapping
+
+
+def unmask_entities(
+    masked: str, mapping: Iterable[tuple[str, str, int, int]]
+) -> str:
+    """Restore the entities :func:`mask_entities` replaced.
+
+    Examples:
+        >>> unmask_entities(*mask_entities("Ali aradı: ali@ornek.com"))
+        'Ali aradı: ali@ornek.com'
+    """
+    parts: list[str] = []
+    position = 0
+    for original, _, start, end in sorted(mapping, key=lambda entry: entry[2]):
+        parts.append(masked[position:start])
+        parts.append(original)
+        position = end
+    parts.append(masked[position:])
+    return "".join(parts)
+
+__all__ = [
+    "ENTITY_LABELS",
+    "LINK_KINDS",
+    "MASK_LABELS",
+    "KeywordMatcher",
+    "PhraseMatcher",
+    "extract_dates",
+    "extract_entities",
+    "link_pronouns",
+    "mask_entities",
+    "unmask_entities",
+]
@@ -2,19 +2,58 @@
 
 from __future__ import annotations
 
+import re
+
 from durak.exceptions import NormalizerError, RustExtensionError
 
 try:
-    from durak._durak_core import fast_normalize
+    from durak._durak_core import (
+        correct_confusions,
+        fast_normalize,
+        fast_uppercase,
+        normalize_with_alignment,
+        split_joined,
+        turkish_casefold,
+        unicode_normalize,
+    )
 except ImportError:
 
     def fast_normalize(
         text: str,
         lowercase: bool = True,
         handle_turkish_i: bool = True,
+        unicode_form: str | None = None,
     ) -> str:
         raise RustExtensionError("Rust extension not installed. Run: maturin develop")
 
+    def unicode_normalize(text: str, form: str = "NFC") -> str:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def normalize_with_alignment(
+        text: str,
+        lowercase: bool = True,
+        handle_turkish_i: bool = True,
+        unicode_form: str | None = None,
+    ) -> tuple[str, list[int]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def fast_uppercase(text: str) -> str:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def turkish_casefold(text: str, mode: str = "fold") -> str:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def correct_confusions(text: str) -> str:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def split_joined(token: str) -> list[str]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+_WORD_PATTERN = re.compile(r"\w+")
+
+_UNICODE_FORMS = ("NFC", "NFKC", "NFD", "NFKD")
+
 
 class Normalizer:
     """
@@ -23,11 +62,40 @@ class Normalizer:
     Args:
         lowercase (bool): If True, lowercases the text (handling Turkish I/ı).
         handle_turkish_i (bool): If True, handles specific Turkish I/İ rules.
+        fix_confusions (bool): If True, rewrites high-frequency misspellings
+            ("herşey" → "her şey", "yanlız" → "yalnız") after normalization.
+        split_joined (bool): If True, splits accidentally concatenated words
+            ("çokgüzel" → "çok güzel") using dictionary-guided segmentation.
+            Intended for noisy text such as social media posts.
+        unicode_form (str | None): Unicode normalization form applied first
+            ("NFC", "NFKC", "NFD" or "NFKD"). Use "NFC" for text copied from
+            PDFs or web pages, where "ğ" may be "g" + combining breve.
+            Default: None (text is used as-is).
+
+    Raises:
+        NormalizerError: If unicode_form is not a known normalization form
     """
 
-    def __init__(self, lowercase: bool = True, handle_turkish_i: bool = True):
+    def __init__(
+        self,
+        lowercase: bool = True,
+        handle_turkish_i: bool = True,
+        fix_confusions: bool = False,
+        split_joined: bool = False,
+        unicode_form: str | None = None,
+    ):
+        if unicode_form is not None:
+            if unicode_form.upper() not in _UNICODE_FORMS:
+                raise NormalizerError(
+                    f"unicode_form must be one of {', '.join(_UNICODE_FORMS)}, "
+                    f"got {unicode_form!r}"
+                )
+            unicode_form = unicode_form.upper()
         self.lowercase = lowercase
         self.handle_turkish_i = handle_turkish_i
+        self.fix_confusions = fix_confusions
+        self.split_joined = split_joined
+        self.unicode_form = unicode_form
 
     def __call__(self, text: str) -> str:
         """
@@ -50,20 +118,32 @@ class Normalizer:
             return ""
         
         # Pass configuration parameters to Rust core
-        return fast_normalize(text, self.lowercase, self.handle_turkish_i)
-        try:
-            return fast_normalize(
+        if self.unicode_form is None:
+            normalized = fast_normalize(text, self.lowercase, self.handle_turkish_i)
+        else:
+            normalized = fast_normalize(
                 text,
-                lowercase=self.lowercase,
-                handle_turkish_i=self.handle_turkish_i,
+                self.lowercase,
+                self.handle_turkish_i,
+                unicode_form=self.unicode_form,
+            )
+        if self.fix_confusions:
+            normalized = correct_confusions(normalized)
+        if self.split_joined:
+            normalized = _WORD_PATTERN.sub(
+                lambda match: " ".join(split_joined(match.group())), normalized
             )
-        except RustExtensionError:
-            raise
-        except Exception as e:
-            raise NormalizerError(f"Normalization failed: {e}") from e
+        return normalized
 
     def __repr__(self) -> str:
-        return (
-            f"Normalizer(lowercase={self.lowercase}, "
-            f"handle_turkish_i={self.handle_turkish_i})"
-        )
+        parts = [
+            f"lowercase={self.lowercase}",
+            f"handle_turkish_i={self.handle_turkish_i}",
+        ]
+        if self.fix_confusions:
+            parts.append("fix_confusions=True")
+        if self.split_joined:
+            parts.append("split_joined=True")
+        if self.unicode_form is not None:
+            parts.append(f"unicode_form={self.unicode_form!r}")
+        return f"Normalizer({', '.join(parts)})"
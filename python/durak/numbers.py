@@ -0,0 +1,28 @@
+"""Turkish cardinal numbers in words.
+
+Spells integers out ("bin iki yüz elli") and reads them back, for ASR/TTS
+pipelines and information extraction. The tokenizer keeps runs of number
+words together with ``TokenizerConfig(numbers=True)``.
+"""
+
+from __future__ import annotations
+
+from durak.exceptions import RustExtensionError
+
+try:
+    from durak._durak_core import extract_numbers, number_to_words, words_to_number
+except ImportError:
+
+    def number_to_words(n: int) -> str:  # type: ignore[misc]
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def words_to_number(text: str) -> int:  # type: ignore[misc]
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def extract_numbers(  # type: ignore[misc]
+        text: str,
+    ) -> list[tuple[str, int, int, int]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+__all__ = ["extract_numbers", "number_to_words", "words_to_number"]
@@ -2,8 +2,9 @@
 
 from __future__ import annotations
 
-import re
+import os
 import warnings
+from collections.abc import Sequence
 from typing import Any, Callable, Union
 
 from durak.cleaning import (
@@ -15,11 +16,35 @@ from durak.cleaning import (
     remove_urls,
     strip_html,
 )
-from durak.exceptions import ConfigurationError, PipelineError
+from durak.exceptions import ConfigurationError, PipelineError, RustExtensionError
 from durak.normalizer import Normalizer
 from durak.stopwords import remove_stopwords as remove_stopwords_fn
 from durak.suffixes import attach_detached_suffixes
-from durak.tokenizer import PUNCT_TOKEN, tokenize
+from durak.tokenizer import is_punct_token, tokenize
+
+try:
+    from durak._durak_core import process, read_conllu, reannotate_conllu, to_conllu
+except ImportError:
+
+    def process(  # type: ignore[misc]
+        text: str,
+        *,
+        normalize: bool = True,
+        stopwords: bool = True,
+        lemmatize: bool = True,
+    ) -> Any:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def to_conllu(doc: Any, sent_id: str | None = None) -> str:  # type: ignore[misc]
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def read_conllu(path: str | os.PathLike[str]) -> list[Any]:  # type: ignore[misc]
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def reannotate_conllu(  # type: ignore[misc]
+        text: str, *, columns: Sequence[str] | None = None
+    ) -> str:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
 
 STEP_REGISTRY: dict[str, Callable[..., Any]] = {
     "clean": clean_text,
@@ -78,6 +103,51 @@ class Pipeline:
                     f"got {type(step).__name__}"
                 )
 
+    @classmethod
+    def from_config(cls, path: str | os.PathLike[str]) -> Pipeline:
+        """
+        Build a pipeline from a TOML or YAML config file.
+
+        The file lists the steps and configures stopword removal (domains,
+        additions, keep words, stopword files), the ``rewrite`` rules files
+        and the ``lemmatize`` step; see :mod:`durak.config` for the format.
+
+        Raises:
+            ConfigurationError: If the file cannot be parsed or describes an
+                invalid pipeline
+
+        Examples:
+            >>> pipeline = Pipeline.from_config("pipeline.toml")
+            >>> pipeline
+            Pipeline(['normalize', 'tokenize', 'remove_stopwords', 'lemmatize'])
+        """
+        from durak.config import PipelineConfig
+
+        return cls._from_pipeline_config(PipelineConfig.from_file(path))
+
+    @classmethod
+    def from_profile(cls, name: str) -> Pipeline:
+        """
+        Build the pipeline of a shipped profile: news, social, legal or
+        medical (see :func:`durak.list_profiles`).
+
+        Raises:
+            ConfigurationError: If there is no profile ``name``
+
+        Examples:
+            >>> Pipeline.from_profile("social")("#GünaydınTürkiye arkadaşlar!")
+            ['günaydın', 'türkiye', 'arkadaşlar']
+        """
+        from durak.config import PipelineConfig
+
+        return cls._from_pipeline_config(PipelineConfig.from_profile(name))
+
+    @classmethod
+    def _from_pipeline_config(cls, config: Any) -> Pipeline:
+        pipeline = cls([config.step(name) for name in config.steps])
+        pipeline.step_names = list(config.steps)
+        return pipeline
+
     def __call__(self, text: str) -> str | list[str]:
         """
         Process text through the pipeline.
@@ -200,7 +270,7 @@ def process_text(
     tokens = tokenize(cleaned)
 
     if strip_punct:
-        tokens = [t for t in tokens if not re.fullmatch(PUNCT_TOKEN, t)]
+        tokens = [t for t in tokens if not is_punct_token(t)]
 
     if rejoin_suffixes:
         tokens = attach_detached_suffixes(tokens)
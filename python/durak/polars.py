@@ -0,0 +1,151 @@
+"""Polars ``durak`` expression and series namespace.
+
+Importing this module registers a ``durak`` namespace on ``pl.Expr`` and
+``pl.Series``::
+
+    import polars as pl
+    import durak.polars  # noqa: F401
+
+    df.with_columns(pl.col("word").durak.lemmatize().alias("lemma"))
+    df.select(pl.col("text").durak.tokenize()).explode("text")
+
+Each expression hands whole column chunks to the Rust core through the
+Arrow C stream interface (``lemmatize_arrow`` / ``normalize_arrow`` /
+``tokenize_arrow``): Rust reads the strings in place from the Polars buffers
+and builds the result column, which Polars takes over without copying. No
+Python object is created per cell, and nulls stay null.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Callable
+
+try:
+    import polars as pl
+except ImportError as exc:  # pragma: no cover - depends on the environment
+    raise ImportError(
+        "durak.polars requires polars. Install with: pip install 'durak-nlp[polars]'"
+    ) from exc
+
+from durak.batch import lemmatize_arrow, normalize_arrow, tokenize_arrow
+from durak.lemmatizer import Lemmatizer, Strategy
+
+
+SeriesFunction = Callable[[pl.Series], pl.Series]
+
+
+def _lemmatize(
+    strategy: Strategy,
+    validate_roots: bool,
+    strict_validation: bool,
+    min_root_length: int | None,
+) -> SeriesFunction:
+    # Validate the options up front with the same errors as Lemmatizer
+    Lemmatizer(
+        strategy=strategy,
+        validate_roots=validate_roots,
+        strict_validation=strict_validation,
+        min_root_length=min_root_length,
+    )
+
+    def apply(series: pl.Series) -> pl.Series:
+        lemmas = lemmatize_arrow(
+            series,
+            strategy,
+            validate_roots=validate_roots,
+            strict_validation=strict_validation,
+            min_root_length=min_root_length,
+        )
+        return pl.Series(series.name, lemmas, dtype=pl.String)
+
+    return apply
+
+
+def _normalize(lowercase: bool, handle_turkish_i: bool) -> SeriesFunction:
+    def apply(series: pl.Series) -> pl.Series:
+        texts = normalize_arrow(
+            series, lowercase=lowercase, handle_turkish_i=handle_turkish_i
+        )
+        return pl.Series(series.name, texts, dtype=pl.String)
+
+    return apply
+
+
+def _tokenize(series: pl.Series) -> pl.Series:
+    tokens, _, _ = tokenize_arrow(series)
+    return pl.Series(series.name, tokens, dtype=pl.List(pl.String))
+
+
+@pl.api.register_expr_namespace("durak")
+class DurakExprNamespace:
+    """``pl.col(...).durak``: Turkish text processing on string columns."""
+
+    def __init__(self, expr: pl.Expr) -> None:
+        self._expr = expr
+
+    def lemmatize(
+        self,
+        strategy: Strategy = "hybrid",
+        *,
+        validate_roots: bool = False,
+        strict_validation: bool = False,
+        min_root_length: int | None = None,
+    ) -> pl.Expr:
+        """Lemmatize one word per cell (options as in ``Lemmatizer``)."""
+        function = _lemmatize(
+            strategy, validate_roots, strict_validation, min_root_length
+        )
+        return self._expr.map_batches(
+            function, return_dtype=pl.String, is_elementwise=True
+        )
+
+    def normalize(
+        self, *, lowercase: bool = True, handle_turkish_i: bool = True
+    ) -> pl.Expr:
+        """Lowercase with Turkish I/ı and İ/i handling."""
+        return self._expr.map_batches(
+            _normalize(lowercase, handle_turkish_i),
+            return_dtype=pl.String,
+            is_elementwise=True,
+        )
+
+    def tokenize(self) -> pl.Expr:
+        """Split each text into a list of tokens."""
+        return self._expr.map_batches(
+            _tokenize, return_dtype=pl.List(pl.String), is_elementwise=True
+        )
+
+
+@pl.api.register_series_namespace("durak")
+class DurakSeriesNamespace:
+    """``series.durak``: eager counterpart of the expression namespace."""
+
+    def __init__(self, series: pl.Series) -> None:
+        self._series = series
+
+    def lemmatize(
+        self,
+        strategy: Strategy = "hybrid",
+        *,
+        validate_roots: bool = False,
+        strict_validation: bool = False,
+        min_root_length: int | None = None,
+    ) -> pl.Series:
+        """Lemmatize one word per element (options as in ``Lemmatizer``)."""
+        function = _lemmatize(
+            strategy, validate_roots, strict_validation, min_root_length
+        )
+        return function(self._series)
+
+    def normalize(
+        self, *, lowercase: bool = True, handle_turkish_i: bool = True
+    ) -> pl.Series:
+        """Lowercase with Turkish I/ı and İ/i handling."""
+        return _normalize(lowercase, handle_turkish_i)(self._series)
+
+    def tokenize(self) -> pl.Series:
+        """Split each text into a list of tokens."""
+        return _tokenize(self._series)
+
+
+__all__ = ["DurakExprNamespace", "DurakSeriesNamespace"]
@@ -0,0 +1,45 @@
+"""Streaming readers for large corpus dumps.
+
+:class:`NdjsonReader` reads ndjson (JSON lines) dumps such as Twitter or
+forum exports line by line in Rust and selects one string field by its
+dotted path, so multi-hundred-GB files can be processed without a separate
+``jq`` pass:
+
+- Lines are split with memchr over a reusable block buffer; memory stays
+  bounded by the longest line
+- The other fields of a record are skipped by the JSON parser without being
+  built, and the selected string is borrowed from the line buffer when it
+  has no escapes
+
+    >>> for line_no, text in NdjsonReader("tweets.jsonl", "retweeted_status.text"):
+    ...     counts.update(tokenize(text))
+"""
+
+from __future__ import annotations
+
+import os
+from typing import IO
+
+from durak.exceptions import RustExtensionError
+
+try:
+    from durak._durak_core import NdjsonReader
+except ImportError:
+
+    class NdjsonReader:  # type: ignore[no-redef]
+        """Streaming ndjson reader; requires the Rust extension."""
+
+        def __init__(
+            self,
+            source: str | os.PathLike[str] | IO[str] | IO[bytes],
+            field: str = "text",
+            *,
+            raw: bool = False,
+            block_size: int = 1 << 20,
+        ) -> None:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+
+__all__ = ["NdjsonReader"]
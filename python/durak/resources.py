@@ -0,0 +1,216 @@
+"""Resource directories layered over the embedded linguistic data.
+
+A :class:`ResourceRegistry` searches user directories before the embedded
+resources, so a deployment can swap the lemma dictionary, the base
+stopwords or the detached suffixes without rebuilding wheels::
+
+    /srv/durak/overrides/
+        lemmas/turkish_lemma_dict.txt     # form<TAB>lemma per line
+        stopwords/base/turkish.txt        # one stopword per line
+        labels/DETACHED_SUFFIXES.txt      # one suffix per line
+
+Directories are searched in order and the first one that has a resource
+file wins; a file replaces that resource as a whole and resources no
+directory has keep the embedded data. The active registry is used by
+``remove_stopwords``, ``StopwordManager``, ``attach_detached_suffixes`` and
+the Rust core (lemmatization, ``process``, ``Doc``). Set
+``DURAK_RESOURCE_PATH`` (directories separated by ``os.pathsep``) to
+activate one at import, call :meth:`ResourceRegistry.activate`, or use a
+registry as a context manager to override resources for a block of calls.
+After editing the files, :meth:`ResourceRegistry.reload` picks them up.
+
+Only these three files can be overridden. The suffixes the lemmatizer
+strips, the analyzer's roots (and with them the spelling suggestions) and
+the other word lists are compiled into the Rust core.
+"""
+
+from __future__ import annotations
+
+import os
+from collections.abc import Iterable
+from pathlib import Path
+from types import TracebackType
+from typing import Any
+
+from durak.exceptions import ConfigurationError, ResourceError, RustExtensionError
+
+try:
+    from durak._durak_core import resource_overrides, set_resource_dirs
+except ImportError:
+
+    def set_resource_dirs(
+        directories: list[str | os.PathLike[str]],
+    ) -> list[tuple[str, Path]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def resource_overrides() -> list[tuple[str, Path]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+# Embedded resources, at the project root: resources/tr
+RESOURCE_DIR = Path(__file__).resolve().parent.parent.parent / "resources" / "tr"
+RESOURCE_PATH_ENV = "DURAK_RESOURCE_PATH"
+# Overridable resources and their paths relative to a resource directory
+RESOURCE_FILES = {
+    "lemmas": "lemmas/turkish_lemma_dict.txt",
+    "stopwords": "stopwords/base/turkish.txt",
+    "detached_suffixes": "labels/DETACHED_SUFFIXES.txt",
+}
+
+
+def _lines(path: Path) -> list[str]:
+    text = path.read_text(encoding="utf-8")
+    return [
+        line.strip()
+        for line in text.splitlines()
+        if line.strip() and not line.strip().startswith("#")
+    ]
+
+
+class ResourceRegistry:
+    """User resource directories searched before the embedded resources.
+
+    Args:
+        directories: Resource directories, highest precedence first; each
+            uses the layout of ``resources/tr``
+
+    Examples:
+        >>> registry = ResourceRegistry(["/srv/durak/overrides"])
+        >>> registry.overrides()
+        {'stopwords': PosixPath('/srv/durak/overrides/stopwords/base/turkish.txt')}
+        >>> with registry:
+        ...     remove_stopwords(["ama", "kitap"])
+    """
+
+    def __init__(self, directories: Iterable[str | os.PathLike[str]] = ()) -> None:
+        self.directories = tuple(Path(directory) for directory in directories)
+        self._cache: dict[str, Any] = {}
+        self._previous: list[ResourceRegistry] = []
+
+    @classmethod
+    def from_env(cls) -> ResourceRegistry:
+        """A registry of the ``DURAK_RESOURCE_PATH`` directories."""
+        value = os.environ.get(RESOURCE_PATH_ENV, "")
+        return cls(part for part in value.split(os.pathsep) if part)
+
+    def _user_path(self, name: str) -> Path | None:
+        try:
+            relative = RESOURCE_FILES[name]
+        except KeyError as e:
+            raise ConfigurationError(
+                f"Unknown resource: '{name}'. "
+                f"Available resources: {', '.join(RESOURCE_FILES)}"
+            ) from e
+        for directory in self.directories:
+            candidate = directory / relative
+            if candidate.is_file():
+                return candidate
+        return None
+
+    def path(self, name: str) -> Path:
+        """The file resource ``name`` is read from.
+
+        Raises:
+            ConfigurationError: If ``name`` is not one of ``RESOURCE_FILES``
+        """
+        return self._user_path(name) or RESOURCE_DIR / RESOURCE_FILES[name]
+
+    def overrides(self) -> dict[str, Path]:
+        """Resources read from a user directory, with their files."""
+        paths = {name: self._user_path(name) for name in RESOURCE_FILES}
+        return {name: path for name, path in paths.items() if path is not None}
+
+    def stopwords(self) -> frozenset[str]:
+        """Base stopwords, lowercased."""
+        if "stopwords" not in self._cache:
+            from durak.stopwords import BASE_STOPWORDS, load_stopwords
+
+            path = self._user_path("stopwords")
+            self._cache["stopwords"] = (
+                frozenset(load_stopwords(path)) if path else BASE_STOPWORDS
+            )
+        return self._cache["stopwords"]
+
+    def detached_suffixes(self) -> tuple[str, ...]:
+        """Suffixes ``attach_detached_suffixes`` joins to the previous token."""
+        if "detached_suffixes" not in self._cache:
+            from durak.suffixes import DEFAULT_DETACHED_SUFFIXES
+
+            path = self._user_path("detached_suffixes")
+            self._cache["detached_suffixes"] = (
+                tuple(_lines(path)) if path else DEFAULT_DETACHED_SUFFIXES
+            )
+        return self._cache["detached_suffixes"]
+
+    def lemmas(self) -> dict[str, str]:
+        """The lemma dictionary as inflected form → lemma.
+
+        Raises:
+            ResourceError: If a line is not ``form<TAB>lemma``
+        """
+        if "lemmas" not in self._cache:
+            path = self.path("lemmas")
+            table: dict[str, str] = {}
+            for number, line in enumerate(
+                path.read_text(encoding="utf-8").splitlines(), 1
+            ):
+                line = line.strip()
+                if not line or line.startswith("#"):
+                    continue
+                form, tab, lemma = line.partition("\t")
+                if not tab:
+                    raise ResourceError(f"{path}:{number}: expected 'form<TAB>lemma'")
+                table[form.strip()] = lemma.strip()
+            self._cache["lemmas"] = table
+        return dict(self._cache["lemmas"])
+
+    def reload(self) -> None:
+        """Re-read the resource files, and re-install them in the Rust core
+        when this is the active registry."""
+        self._cache.clear()
+        if get_registry() is self:
+            set_resource_dirs(list(self.directories))
+
+    def activate(self) -> ResourceRegistry:
+        """Make this the registry of the process (Python and Rust)."""
+        global _active
+        set_resource_dirs(list(self.directories))
+        self._cache.clear()
+        _active = self
+        return self
+
+    def __enter__(self) -> ResourceRegistry:
+        self._previous.append(get_registry())
+        return self.activate()
+
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc: BaseException | None,
+        traceback: TracebackType | None,
+    ) -> None:
+        self._previous.pop().activate()
+
+    def __repr__(self) -> str:
+        directories = [str(directory) for directory in self.directories]
+        return f"ResourceRegistry({directories!r})"
+
+
+_active = ResourceRegistry()
+
+
+def get_registry() -> ResourceRegistry:
+    """The active registry; without one, the embedded resources."""
+    return _active
+
+
+if os.environ.get(RESOURCE_PATH_ENV):
+    ResourceRegistry.from_env().activate()
+
+
+__all__ = [
+    "RESOURCE_FILES",
+    "ResourceRegistry",
+    "get_registry",
+    "resource_overrides",
+]
@@ -0,0 +1,35 @@
+"""Seeded, reproducible sampling of corpus lines and sentences.
+
+Sampling runs in Rust with a fixed generator, so publishing the sample size
+and seed is enough for others to draw the same sample from the same corpus.
+``sample_lines`` reads the whole file without the GIL; Ctrl-C or a
+cancelled ``cancel=CancellationToken()`` stops it between reads.
+"""
+
+from __future__ import annotations
+
+import os
+from typing import IO, Any
+
+from durak.exceptions import RustExtensionError
+
+try:
+    from durak._durak_core import sample_lines, sample_sentences
+except ImportError:
+    from durak.batch import CancellationToken
+
+    def sample_lines(  # type: ignore[misc]
+        source: str | os.PathLike[str] | IO[str] | IO[bytes],
+        n: int,
+        seed: int = 0,
+        *,
+        line_numbers: bool = False,
+        cancel: CancellationToken | None = None,
+    ) -> Any:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def sample_sentences(text: str, n: int, seed: int = 0) -> list[str]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+__all__ = ["sample_lines", "sample_sentences"]
@@ -0,0 +1,42 @@
+"""Turkish-aware fuzzy string matching.
+
+Levenshtein and Jaro-Winkler similarities where a Turkish letter and the
+ASCII letter it is typed as (ı/i, ş/s, ç/c, ğ/g, ö/o, ü/u) are a cheap
+substitution, so deasciified user input ("Eskisehir", "cigkofte") matches
+canonical names ("Eskişehir", "çiğköfte") far better than names that really
+differ.
+"""
+
+from __future__ import annotations
+
+from typing import Literal
+
+from durak.exceptions import RustExtensionError
+
+Metric = Literal["levenshtein", "jaro", "jaro_winkler"]
+
+try:
+    from durak._durak_core import closest_matches, similarity
+except ImportError:
+
+    def similarity(  # type: ignore[misc]
+        a: str, b: str, metric: Metric = "jaro_winkler", *, ignore_case: bool = True
+    ) -> float:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def closest_matches(  # type: ignore[misc]
+        query: str,
+        candidates: list[str],
+        k: int = 5,
+        metric: Metric = "jaro_winkler",
+        *,
+        ignore_case: bool = True,
+        min_score: float = 0.0,
+    ) -> list[tuple[str, float]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+__all__ = [
+    "closest_matches",
+    "similarity",
+]
@@ -0,0 +1,51 @@
+"""spaCy integration: durak as the tokenizer of a spaCy pipeline.
+
+Replaces the tokenizer of a ``Language`` object with durak's Turkish
+tokenizer. The Doc keeps the exact input text (extra whitespace becomes
+whitespace tokens, as in spaCy), so character offsets of entities and
+annotations line up with durak's own::
+
+    import spacy
+    from durak.spacy import DurakTokenizer
+
+    nlp = spacy.blank("tr")
+    nlp.tokenizer = DurakTokenizer(nlp.vocab)
+    doc = nlp("İstanbul'da 3 gün kaldık 😂")
+"""
+
+from __future__ import annotations
+
+try:
+    from spacy.tokens import Doc
+    from spacy.vocab import Vocab
+except ImportError as exc:  # pragma: no cover - depends on the environment
+    raise ImportError(
+        "durak.spacy requires spaCy. Install with: pip install 'durak-nlp[spacy]'"
+    ) from exc
+
+from durak.tokenizer import tokenize_for_spacy
+
+
+def to_spacy_doc(text: str, vocab: Vocab) -> Doc:
+    """Tokenize `text` with durak into a spaCy ``Doc`` over `vocab`."""
+    words, spaces, _ = tokenize_for_spacy(text)
+    return Doc(vocab, words=words, spaces=spaces)
+
+
+class DurakTokenizer:
+    """Callable spaCy tokenizer backed by durak.
+
+    Examples:
+        >>> nlp.tokenizer = DurakTokenizer(nlp.vocab)
+        >>> [t.text for t in nlp("Ankara'ya gittik.")]
+        ["Ankara'ya", 'gittik', '.']
+    """
+
+    def __init__(self, vocab: Vocab) -> None:
+        self.vocab = vocab
+
+    def __call__(self, text: str) -> Doc:
+        return to_spacy_doc(text, self.vocab)
+
+
+__all__ = ["DurakTokenizer", "to_spacy_doc"]
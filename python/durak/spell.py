@@ -0,0 +1,84 @@
+"""Spell checking backed by the Rust morphological analyzer."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+
+from durak.exceptions import RustExtensionError
+
+try:
+    from durak._durak_core import check_grammar, is_correct, suggest
+    from durak._durak_core import ranked_suggestions as _ranked_suggestions
+except ImportError:
+
+    def check_grammar(
+        text: str,
+    ) -> list[tuple[str, str, str, str | None, int, int]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def is_correct(word: str) -> bool:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def suggest(word: str, max_edits: int = 2, k: int = 5) -> list[str]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def _ranked_suggestions(
+        word: str, max_edits: int = 2, k: int = 5
+    ) -> list[tuple[str, float, str]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+SUGGESTION_SOURCES = ("spell", "deascii", "confusion")
+
+
+@dataclass(frozen=True)
+class Suggestion:
+    """A ranked correction candidate.
+
+    Attributes:
+        text: Corrected lowercase text; may contain a space for confusion
+            pairs ("herşey" → "her şey")
+        score: Likelihood in (0, 1], normalized over all candidates of the
+            word so scores are comparable across sources
+        source: Feature that proposed the candidate: "spell" (edit distance),
+            "deascii" (restored diacritics) or "confusion" (curated pair)
+    """
+
+    text: str
+    score: float
+    source: str
+
+
+def ranked_suggestions(word: str, max_edits: int = 2, k: int = 5) -> list[Suggestion]:
+    """Correction candidates from every correction feature, best first.
+
+    Pools spelling suggestions, diacritic restorations and confusion pairs,
+    scoring each by the frequency of its root and the likelihood of the
+    edits that lead to it.
+
+    Args:
+        word: Word to correct
+        max_edits: Maximum edit distance ignoring diacritics (default: 2)
+        k: Maximum number of suggestions (default: 5)
+
+    Returns:
+        Up to k suggestions with descending scores
+
+    Examples:
+        >>> ranked_suggestions("cocugu", k=1)
+        [Suggestion(text='çocuğu', score=..., source='deascii')]
+    """
+    return [
+        Suggestion(text, score, source)
+        for text, score, source in _ranked_suggestions(word, max_edits, k)
+    ]
+
+
+__all__ = [
+    "SUGGESTION_SOURCES",
+    "Suggestion",
+    "check_grammar",
+    "is_correct",
+    "ranked_suggestions",
+    "suggest",
+]
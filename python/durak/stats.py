@@ -0,0 +1,87 @@
+"""Document statistics for Turkish text.
+
+``term_counts`` counts the words of a whole corpus file. Pass
+``max_memory_mb`` on shared machines: counts that do not fit spill to
+temporary files instead of growing until the process is killed, and the
+result is the same as without the cap.
+"""
+
+from __future__ import annotations
+
+import os
+from collections.abc import Iterable
+from typing import IO, Any
+
+from durak.exceptions import RustExtensionError
+
+try:
+    from durak._durak_core import (
+        CorpusStats,
+        char_ngrams,
+        formality_score,
+        line_anomalies,
+        morphological_complexity,
+        ngrams,
+        syllabify,
+        term_counts,
+        text_stats,
+    )
+except ImportError:
+    from durak.batch import CancellationToken
+
+    class CorpusStats:  # type: ignore[no-redef]
+        """Corpus term statistics; requires the Rust extension."""
+
+        def __init__(self, documents: Iterable[str] | None = None) -> None:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+    def formality_score(text: str) -> dict[str, float]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def line_anomalies(text: str) -> list[dict[str, float]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def morphological_complexity(text: str) -> dict[str, float]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def syllabify(word: str) -> list[str]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def text_stats(text: str) -> dict[str, float]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def term_counts(
+        source: str | os.PathLike[str] | IO[str] | IO[bytes],
+        n: int = 1,
+        *,
+        min_count: int = 1,
+        top: int | None = None,
+        max_memory_mb: int | None = None,
+        cancel: CancellationToken | None = None,
+    ) -> list[tuple[str, int]]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def ngrams(  # type: ignore[misc]
+        tokens: list[str], n: int = 2, *, counts: bool = False
+    ) -> Any:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def char_ngrams(  # type: ignore[misc]
+        text: str, n: int = 2, *, counts: bool = False
+    ) -> Any:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+__all__ = [
+    "CorpusStats",
+    "char_ngrams",
+    "formality_score",
+    "line_anomalies",
+    "morphological_complexity",
+    "ngrams",
+    "syllabify",
+    "term_counts",
+    "text_stats",
+]
@@ -0,0 +1,34 @@
+"""Aggressive stemming for search indexing.
+
+Stems are keys, not words: ``stem`` strips suffixes without checking the
+result against the lemma dictionary, or keeps a fixed prefix, so the forms
+of a word collapse together even when the stem is not a real word. For
+dictionary lemmas use :class:`durak.Lemmatizer`.
+"""
+
+from __future__ import annotations
+
+from typing import Literal
+
+from durak.exceptions import RustExtensionError
+
+StemMethod = Literal["cascade", "prefix"]
+
+try:
+    from durak._durak_core import stem
+except ImportError:
+
+    def stem(  # type: ignore[misc]
+        word: str,
+        max_suffixes: int | None = None,
+        *,
+        method: StemMethod = "cascade",
+        prefix_length: int = 5,
+    ) -> str:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+__all__ = [
+    "StemMethod",
+    "stem",
+]
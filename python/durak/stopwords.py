@@ -3,14 +3,35 @@
 from __future__ import annotations
 
 import json
+import urllib.error
+import urllib.request
 from collections.abc import Iterable, MutableSet, Sequence
 from dataclasses import dataclass
 from functools import cache
 from pathlib import Path
 from typing import Any, cast
 
-from durak.cleaning import normalize_case
-from durak.exceptions import ConfigurationError, StopwordError, StopwordMetadataError
+from durak.cleaning import normalize_case, normalize_unicode
+from durak.exceptions import (
+    ConfigurationError,
+    RustExtensionError,
+    StopwordError,
+    StopwordMetadataError,
+)
+from durak.resources import get_registry
+
+try:
+    from durak._durak_core import discover_stopwords
+except ImportError:
+
+    def discover_stopwords(
+        documents: Iterable[str],
+        top_k: int = 50,
+        min_df: int = 2,
+        merge_base: bool = False,
+    ) -> list[str]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
 
 # Resource directory is now at project root: resources/tr/stopwords
 STOPWORD_DATA_DIR = (
@@ -26,13 +47,20 @@ __all__ = [
     "StopwordError",
     "StopwordMetadataError",  # Backward compatibility alias
     "StopwordManager",
+    "StopwordDiff",
     "StopwordSnapshot",
+    "diff_stopwords",
+    "discover_stopwords",
+    "list_stopword_domains",
+    "load_stopword_domain",
     "load_stopword_resource",
     "load_stopword_resources",
     "load_stopwords",
     "is_stopword",
     "list_stopwords",
+    "merge_stopwords",
     "remove_stopwords",
+    "stopword_domain",
 ]
 
 
@@ -233,10 +261,8 @@ def _normalize(token: str, *, case_sensitive: bool) -> str:
     return token if case_sensitive else normalize_case(token, mode="lower")
 
 
-def load_stopwords(path: Path | str, *, case_sensitive: bool = False) -> set[str]:
-    """Load newline-delimited stopwords from a file."""
+def _parse_stopwords(raw_text: str, *, case_sensitive: bool) -> set[str]:
     entries: set[str] = set()
-    raw_text = Path(path).read_text(encoding="utf-8")
     for line in raw_text.splitlines():
         stripped = line.strip()
         if not stripped or stripped.startswith("#"):
@@ -245,6 +271,100 @@ def load_stopwords(path: Path | str, *, case_sensitive: bool = False) -> set[str
     return entries
 
 
+def load_stopwords(path: Path | str, *, case_sensitive: bool = False) -> set[str]:
+    """Load newline-delimited stopwords from a file."""
+    raw_text = Path(path).read_text(encoding="utf-8")
+    return _parse_stopwords(raw_text, case_sensitive=case_sensitive)
+
+
+# Domains registered with load_stopword_domain, by name
+_DOMAINS: dict[str, frozenset[str]] = {}
+_DOMAIN_PREFIX = "domains/"
+
+
+def load_stopword_domain(
+    name: str, source: Path | str, *, timeout: float = 10.0
+) -> frozenset[str]:
+    """Register stopword domain ``name`` from a newline-delimited file or an
+    ``http://`` / ``https://`` URL.
+
+    The domain can then be used by name like the shipped ones: in
+    ``remove_stopwords(domains=...)``, :func:`stopword_domain` and the
+    ``[stopwords] domains`` of pipeline configs. Registering a name again,
+    or the name of a shipped domain, replaces its words. Like
+    ``load_stopwords`` the words are lowercased; unlike the shipped
+    ``social_media`` domain they do not include the base stopwords.
+
+    Args:
+        name: Domain name, e.g. "medical"
+        source: Stopword file or URL
+        timeout: Seconds to wait for a URL
+
+    Returns:
+        The stopwords of the domain
+
+    Raises:
+        ConfigurationError: If ``name`` is empty or contains "/"
+        StopwordError: If the URL cannot be fetched or is not UTF-8
+        OSError: If the file cannot be read
+
+    Examples:
+        >>> load_stopword_domain("medical", "stopwords/medical.txt")
+        frozenset({'hasta', 'tedavi', ...})
+        >>> remove_stopwords(["hasta", "ateşli"], domains=["medical"])
+        ['ateşli']
+    """
+    if not name or "/" in name:
+        raise ConfigurationError(
+            f"Stopword domain name must be non-empty without '/', got '{name}'."
+        )
+    if isinstance(source, str) and source.startswith(("http://", "https://")):
+        try:
+            with urllib.request.urlopen(source, timeout=timeout) as response:
+                raw_text = response.read().decode("utf-8")
+        except (OSError, UnicodeDecodeError) as e:
+            raise StopwordError(
+                f"Failed to load stopword domain '{name}' from {source}: {e}"
+            ) from e
+    else:
+        raw_text = Path(source).read_text(encoding="utf-8")
+    words = frozenset(_parse_stopwords(raw_text, case_sensitive=False))
+    _DOMAINS[name] = words
+    return words
+
+
+def list_stopword_domains() -> list[str]:
+    """Names of the shipped and registered stopword domains.
+
+    Examples:
+        >>> list_stopword_domains()
+        ['social_media']
+    """
+    sets = _read_stopword_metadata(str(STOPWORD_METADATA_PATH.resolve()))["sets"]
+    shipped = {
+        name[len(_DOMAIN_PREFIX) :]
+        for name in sets
+        if name.startswith(_DOMAIN_PREFIX)
+    }
+    return sorted(shipped | set(_DOMAINS))
+
+
+def stopword_domain(name: str) -> frozenset[str]:
+    """Stopwords of a registered or shipped domain, lowercased.
+
+    Raises:
+        StopwordError: If there is no domain ``name``
+    """
+    if name in _DOMAINS:
+        return _DOMAINS[name]
+    if name not in list_stopword_domains():
+        raise StopwordError(
+            f"Unknown stopword domain: '{name}'. "
+            f"Available domains: {', '.join(list_stopword_domains())}"
+        )
+    return frozenset(load_stopword_resource(_DOMAIN_PREFIX + name))
+
+
 BASE_STOPWORDS: frozenset[str] = frozenset(
     load_stopword_resource(DEFAULT_STOPWORD_RESOURCE, case_sensitive=False)
 )
@@ -258,20 +378,41 @@ def remove_stopwords(
     additions: Iterable[str] | None = None,
     keep: Iterable[str] | None = None,
     case_sensitive: bool | None = None,
+    domains: Iterable[str] | None = None,
 ) -> list[str]:
     """Return tokens that are not stopwords.
 
+    ``domains`` adds the stopwords of shipped or registered domains (see
+    :func:`load_stopword_domain`) to the additions; without ``domains`` or
+    ``manager``, the ``stopword_domains`` of the process-wide config are
+    added (see :func:`durak.defaults.set_config`).
+
     Examples:
         >>> remove_stopwords(["bu", "bir", "test"])
         ['test']
+        >>> remove_stopwords(["rt", "harika"], domains=["social_media"])
+        ['harika']
     """
     if tokens is None:
         return []
+    if domains is None and manager is None:
+        from durak.defaults import get_config
+
+        domains = get_config()["stopword_domains"] or None
+    if domains is not None:
+        if manager is not None:
+            raise ConfigurationError(
+                "Cannot provide domains when a manager instance is supplied."
+            )
+        additions = [
+            *(additions or ()),
+            *(word for domain in domains for word in stopword_domain(domain)),
+        ]
     if manager is None:
         resolved_case_sensitive = (
             case_sensitive if case_sensitive is not None else False
         )
-        resolved_base = base if base is not None else BASE_STOPWORDS
+        resolved_base = base if base is not None else get_registry().stopwords()
         manager = StopwordManager(
             base=resolved_base,
             additions=additions,
@@ -310,7 +451,7 @@ def _resolve_stopword_set(
                 case_sensitive=case_sensitive,
             )
             return frozenset(words)
-        return BASE_STOPWORDS
+        return get_registry().stopwords()
 
     if isinstance(resource, str):
         words = load_stopword_resource(
@@ -376,6 +517,81 @@ def list_stopwords(
     return sorted(words) if sort else list(words)
 
 
+def _comparison_key(word: str, *, case_sensitive: bool) -> str:
+    """Normalize a stopword for comparison across independently edited lists."""
+    return _normalize(normalize_unicode(word.strip()), case_sensitive=case_sensitive)
+
+
+def _comparison_set(words: Iterable[str], *, case_sensitive: bool) -> set[str]:
+    keys = (_comparison_key(word, case_sensitive=case_sensitive) for word in words)
+    return {key for key in keys if key}
+
+
+@dataclass(frozen=True)
+class StopwordDiff:
+    """Result of comparing two stopword lists."""
+
+    only_in_a: frozenset[str]
+    only_in_b: frozenset[str]
+    common: frozenset[str]
+
+    @property
+    def identical(self) -> bool:
+        return not self.only_in_a and not self.only_in_b
+
+    def to_dict(self) -> dict[str, list[str]]:
+        """Convert the diff to a dictionary of sorted word lists."""
+        return {
+            "only_in_a": sorted(self.only_in_a),
+            "only_in_b": sorted(self.only_in_b),
+            "common": sorted(self.common),
+        }
+
+
+def diff_stopwords(
+    a: Iterable[str],
+    b: Iterable[str],
+    *,
+    case_sensitive: bool = False,
+) -> StopwordDiff:
+    """Compare two stopword lists after normalization.
+
+    Words are compared after whitespace stripping, Unicode normalization
+    (NFC, typographic variants) and, unless case_sensitive is set, Turkish
+    aware lowercasing, so "İçin" and "için" count as the same entry.
+
+    Examples:
+        >>> diff = diff_stopwords(["ve", "İçin", "ama"], ["ve", "için", "rt"])
+        >>> sorted(diff.only_in_a), sorted(diff.only_in_b)
+        (['ama'], ['rt'])
+    """
+    words_a = _comparison_set(a, case_sensitive=case_sensitive)
+    words_b = _comparison_set(b, case_sensitive=case_sensitive)
+    return StopwordDiff(
+        only_in_a=frozenset(words_a - words_b),
+        only_in_b=frozenset(words_b - words_a),
+        common=frozenset(words_a & words_b),
+    )
+
+
+def merge_stopwords(
+    *word_lists: Iterable[str],
+    case_sensitive: bool = False,
+) -> list[str]:
+    """Merge stopword lists into one sorted, deduplicated list.
+
+    Entries are normalized as in diff_stopwords before deduplication.
+
+    Examples:
+        >>> merge_stopwords(["ve", "ama"], ["VE", "rt"])
+        ['ama', 'rt', 've']
+    """
+    merged: set[str] = set()
+    for words in word_lists:
+        merged |= _comparison_set(words, case_sensitive=case_sensitive)
+    return sorted(merged)
+
+
 @dataclass(frozen=True)
 class StopwordSnapshot:
     stopwords: frozenset[str]
@@ -414,13 +630,14 @@ class StopwordManager:
         """Initialize a StopwordManager with base stopwords and optional customizations.
 
         Args:
-            base: Base stopwords to use. Defaults to BASE_STOPWORDS if not provided.
+            base: Base stopwords to use. Defaults to the base stopwords of the
+                active ``ResourceRegistry`` (BASE_STOPWORDS unless overridden).
             additions: Additional stopwords to add to the base set.
             keep: Words that should never be treated as stopwords (overrides both base and additions).
             case_sensitive: If True, stopword matching is case-sensitive. Defaults to False.
         """
         self.case_sensitive = case_sensitive
-        base_words = set(base) if base is not None else set(BASE_STOPWORDS)
+        base_words = set(base) if base is not None else set(get_registry().stopwords())
         normalized_base = {
             _normalize(word, case_sensitive=case_sensitive) for word in base_words
         }
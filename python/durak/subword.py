@@ -0,0 +1,97 @@
+"""Subword segmentation and unigram vocabularies for Turkish text.
+
+:func:`segment_word` and :func:`segment_morphemes` split tokens into the
+analyzer's root and suffixes, falling back to characters or ``<0xNN>`` bytes
+for tokens it cannot segment, so exports for LM training always cover the
+whole text.
+
+:func:`train_unigram` follows SentencePiece's unigram trainer, guided by the
+morphological analyzer so that pieces fall on morpheme boundaries. Models
+are saved in SentencePiece format::
+
+    >>> model = train_unigram(open("corpus.txt"), vocab_size=8000)  # doctest: +SKIP
+    >>> model.save("tr_unigram")  # doctest: +SKIP
+
+``tr_unigram.model`` loads in ``sentencepiece`` with an identity normalizer,
+so normalize text with :func:`durak.fast_normalize` before encoding it there.
+
+:func:`train_bpe` learns byte-pair merges instead, counting pair occurrences
+that cross the analyzer's morpheme boundaries only ``1 - boundary_penalty``
+times. Models are saved as ``<prefix>-vocab.json`` and ``<prefix>-merges.txt``,
+which ``tokenizers.models.BPE.from_file`` loads with ``unk_token="<unk>"``.
+"""
+
+from __future__ import annotations
+
+import os
+from collections.abc import Iterable
+
+from durak.exceptions import RustExtensionError
+
+try:
+    from durak._durak_core import (
+        BpeModel,
+        UnigramModel,
+        segment_morphemes,
+        segment_word,
+        train_bpe,
+        train_unigram,
+    )
+except ImportError:
+
+    def segment_word(token: str, fallback: str = "char") -> list[str]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def segment_morphemes(text: str, fallback: str = "char") -> list[str]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    class UnigramModel:  # type: ignore[no-redef]
+        """Unigram subword model; requires the Rust extension."""
+
+        @staticmethod
+        def load(path: str | os.PathLike[str]) -> UnigramModel:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+    class BpeModel:  # type: ignore[no-redef]
+        """Byte-pair encoding model; requires the Rust extension."""
+
+        @staticmethod
+        def load(prefix: str) -> BpeModel:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+    def train_bpe(  # type: ignore[misc]
+        texts: Iterable[str],
+        vocab_size: int = 8000,
+        *,
+        min_frequency: int = 2,
+        morpheme_boundaries: bool = True,
+        boundary_penalty: float = 0.9,
+        threads: int = 1,
+    ) -> BpeModel:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def train_unigram(  # type: ignore[misc]
+        texts: Iterable[str],
+        vocab_size: int = 8000,
+        *,
+        max_piece_length: int = 16,
+        character_coverage: float = 0.9995,
+        seed_morphemes: bool = True,
+        byte_fallback: bool = False,
+        threads: int = 1,
+    ) -> UnigramModel:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+__all__ = [
+    "BpeModel",
+    "UnigramModel",
+    "segment_morphemes",
+    "segment_word",
+    "train_bpe",
+    "train_unigram",
+]
@@ -5,9 +5,17 @@ from __future__ import annotations
 from collections.abc import Iterable, Sequence
 from pathlib import Path
 
-from durak.exceptions import ResourceError
+from durak.exceptions import ResourceError, RustExtensionError
 
-APOSTROPHE_TOKENS: tuple[str, ...] = ("'", "'")
+try:
+    from durak._durak_core import split_clitics
+except ImportError:
+
+    def split_clitics(token: str) -> tuple[str, str]:  # type: ignore[misc]
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+APOSTROPHE_TOKENS: tuple[str, ...] = ("'", "’")
 
 
 def _load_detached_suffixes() -> tuple[str, ...]:
@@ -62,7 +70,11 @@ def attach_detached_suffixes(
     if not tokens:
         return []
 
-    suffix_set = {suffix.lower() for suffix in (suffixes or DEFAULT_DETACHED_SUFFIXES)}
+    if suffixes is None:
+        from durak.resources import get_registry
+
+        suffixes = get_registry().detached_suffixes()
+    suffix_set = {suffix.lower() for suffix in suffixes}
     if apostrophes is not None:
         apostrophe_set = tuple(apostrophes)
     else:
@@ -113,4 +125,5 @@ __all__ = [
     "APOSTROPHE_TOKENS",
     "DEFAULT_DETACHED_SUFFIXES",
     "attach_detached_suffixes",
+    "split_clitics",
 ]
\ No newline at end of file
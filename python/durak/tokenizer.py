@@ -2,25 +2,395 @@
 
 from __future__ import annotations
 
+import hashlib
+import os
 import re
-from collections.abc import Callable, Iterable, Sequence
+import threading
+from collections import OrderedDict
+from collections.abc import Callable, Iterable, Iterator, Sequence
 from dataclasses import dataclass
+from functools import lru_cache, partial
+from typing import IO, Literal
 
-from durak.cleaning import normalize_case
+from durak.cleaning import BIDI_CONTROLS, normalize_case
 from durak.exceptions import RustExtensionError, TokenizationError
+from durak.suffixes import split_clitics
 
 # Regex patterns tuned for Turkish tokenisation.
-APOSTROPHE_TOKEN = r"[A-Za-zÇĞİÖŞÜçğıöşü]+(?:'[A-Za-zÇĞİÖŞÜçğıöşü]+)?"
+APOSTROPHE_TOKEN = (
+    r"[A-Za-zÇĞİÖŞÜçğıöşü]+"
+    r"(?:['’][A-Za-zÇĞİÖŞÜçğıöşü]+)?"
+)
 NUMBER_TOKEN = r"\d+(?:[.,]\d+)*(?:[-–]\d+)?"
-URL_TOKEN = r"https?://[^\s]+|www\.[^\s]+"
+URL_TOKEN = rf"https?://[^\s{BIDI_CONTROLS}]+|www\.[^\s{BIDI_CONTROLS}]+"
+EMAIL_TOKEN = r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}"
+# Turkish IBAN, compact or in groups of four: TR33 0006 1005 1978 6457 8413 26
+IBAN_TOKEN = r"TR\d{2}(?: ?\d{4}){5} ?\d{2}\b"
+# Turkish phone numbers with a 0 or +90 prefix: 0532 123 45 67, +90 (212) ...
+PHONE_TOKEN = (
+    r"(?:\+90[ -]?|0[ -]?)(?:\([2-5]\d{2}\)|[2-5]\d{2})[ -]?\d{3}[ -]?\d{2}[ -]?\d{2}\b"
+    r"|\(0[2-5]\d{2}\)[ -]?\d{3}[ -]?\d{2}[ -]?\d{2}\b"
+)
+# Dates and times, same patterns as the Rust core (src/datetime_tr.rs):
+# 2024-03-12, 12.03.2024, 12/03/2024, 3 Mart 2024, 3 Mart, Mart 2024 and
+# 14:30, 14:30:15, saat 14:30, saat 9
+_MONTHS = (
+    "ocak",
+    "şubat",
+    "mart",
+    "nisan",
+    "mayıs",
+    "haziran",
+    "temmuz",
+    "ağustos",
+    "eylül",
+    "ekim",
+    "kasım",
+    "aralık",
+)
+_MONTH_NAMES = "|".join(
+    f"{month}|{month.capitalize()}|{month.replace('i', 'İ').upper()}"
+    for month in _MONTHS
+)
+_DAY = r"(?:0?[1-9]|[12]\d|3[01])"
+_MONTH_NUMBER = r"(?:0?[1-9]|1[0-2])"
+_HOUR = r"(?:[01]?\d|2[0-3])"
+DATE_TOKEN = (
+    r"\b\d{4}-(?:0[1-9]|1[0-2])-(?:0[1-9]|[12]\d|3[01])\b"
+    rf"|\b{_DAY}\.{_MONTH_NUMBER}\.\d{{4}}\b"
+    rf"|\b{_DAY}/{_MONTH_NUMBER}/\d{{4}}\b"
+    rf"|\b{_DAY} (?:{_MONTH_NAMES})(?: \d{{4}})?\b"
+    rf"|\b(?:{_MONTH_NAMES}) \d{{4}}\b"
+)
+TIME_TOKEN = (
+    rf"\b(?:[Ss]aat|SAAT) {_HOUR}(?:[:.][0-5]\d)?\b"
+    rf"|\b{_HOUR}:[0-5]\d(?::[0-5]\d)?\b"
+)
+# Runs of two or more cardinal number words: "üç yüz kırk iki", "Bin Dokuz Yüz"
+_NUMBER_WORDS = (
+    "sıfır bir iki üç dört beş altı yedi sekiz dokuz on yirmi otuz kırk elli "
+    "altmış yetmiş seksen doksan yüz bin milyon milyar trilyon katrilyon kentilyon"
+).split()
+_NUMBER_WORD_NAMES = "|".join(
+    f"{word}|{word.capitalize()}|{word.replace('i', 'İ').upper()}"
+    for word in sorted(_NUMBER_WORDS, key=len, reverse=True)
+)
+NUMBER_WORDS_TOKEN = rf"\b(?:{_NUMBER_WORD_NAMES})(?: (?:{_NUMBER_WORD_NAMES}))+\b"
+HASHTAG_TOKEN = r"\B#\w+"
+MENTION_TOKEN = r"\B@\w+"
 EMOTICON_TOKEN = r"[:;=8][-^']?[)DPOo(\[/\\]"
 WORD_TOKEN = r"[A-Za-zÇĞİÖŞÜçğıöşü]+(?:-[A-Za-zÇĞİÖŞÜçğıöşü]+)*"
-PUNCT_TOKEN = r"[^\w\s]"
-
-REGEX_TOKEN_PATTERN = re.compile(
-    f"({URL_TOKEN}|{EMOTICON_TOKEN}|{APOSTROPHE_TOKEN}|{NUMBER_TOKEN}|{WORD_TOKEN}|{PUNCT_TOKEN})",
-    flags=re.UNICODE,
+# Bidi controls are invisible and never tokens, as in the Rust core
+PUNCT_TOKEN = rf"[^\w\s{BIDI_CONTROLS}]"
+
+# Emoji grapheme clusters, same rules as the Rust core (src/emoji.rs): a
+# pictograph with its modifiers, variation selectors and ZWJ-joined parts, a
+# flag (two regional indicators), a keycap, or a symbol followed by U+FE0F
+_PICTOGRAPHIC = (
+    r"[\U0001F000-\U0001F1E5\U0001F200-\U0001FAFF\U0001FC00-\U0001FFFD"
+    r"\u2600-\u27BF\u231A\u231B\u23E9-\u23F3\u23F8-\u23FA\u2934\u2935"
+    r"\u2B05-\u2B07\u2B1B\u2B1C\u2B50\u2B55\u3030\u303D\u3297\u3299]"
+)
+_REGIONAL_INDICATOR = r"[\U0001F1E6-\U0001F1FF]"
+_EMOJI_EXTEND = (
+    r"[\u0300-\u036F\u200C\u20D0-\u20FF\uFE00-\uFE0F"
+    r"\U0001F3FB-\U0001F3FF\U000E0020-\U000E007F]"
 )
+EMOJI_TOKEN = (
+    rf"[0-9#*]\uFE0F?\u20E3{_EMOJI_EXTEND}*"
+    rf"|{_REGIONAL_INDICATOR}{{1,2}}(?:{_EMOJI_EXTEND}|\u200D)*"
+    rf"|(?:{_PICTOGRAPHIC}|[^\w\s]\uFE0F)"
+    rf"(?:{_EMOJI_EXTEND}|\u200D{_PICTOGRAPHIC}?)*"
+)
+# Priorities of the built-in alternatives; custom patterns (priority 100 by
+# default) are tried before built-ins of the same or a lower priority
+URL_PRIORITY = 90
+OPTIONAL_PRIORITY = 80  # e-mail, IBAN, phone, date, time, number words
+SOCIAL_PRIORITY = 70  # hashtags, mentions
+EMOTICON_PRIORITY = 60  # emoticons, emoji
+WORD_PRIORITY = 50  # apostrophe words, numbers, words
+PUNCT_PRIORITY = 0
+
+
+class _PatternCache:
+    """Process-wide LRU cache of compiled custom regexes.
+
+    Keyed by the SHA-256 of the pattern source, so frameworks that rebuild
+    ``TokenPattern`` and ``TokenizerConfig`` objects per request reuse the
+    compiled automaton. Off (size 0) until enabled with
+    :func:`set_pattern_cache_size`.
+    """
+
+    def __init__(self) -> None:
+        self._patterns: OrderedDict[str, re.Pattern[str]] = OrderedDict()
+        self._lock = threading.Lock()
+        self.maxsize = 0
+        self.hits = 0
+        self.misses = 0
+
+    def compile(self, source: str) -> re.Pattern[str]:
+        if self.maxsize == 0:
+            return re.compile(source)
+        key = hashlib.sha256(source.encode("utf-8")).hexdigest()
+        with self._lock:
+            compiled = self._patterns.get(key)
+            if compiled is not None:
+                self._patterns.move_to_end(key)
+                self.hits += 1
+                return compiled
+        # Compile outside the lock; a concurrent miss compiles twice at worst
+        compiled = re.compile(source)
+        with self._lock:
+            self.misses += 1
+            self._patterns[key] = compiled
+            self._evict()
+        return compiled
+
+    def resize(self, maxsize: int) -> None:
+        with self._lock:
+            self.maxsize = maxsize
+            self._evict()
+            if maxsize == 0:
+                self.hits = self.misses = 0
+
+    def _evict(self) -> None:
+        while len(self._patterns) > self.maxsize:
+            self._patterns.popitem(last=False)
+
+    def info(self) -> dict[str, int]:
+        with self._lock:
+            return {
+                "hits": self.hits,
+                "misses": self.misses,
+                "size": len(self._patterns),
+                "maxsize": self.maxsize,
+            }
+
+
+_PATTERN_CACHE = _PatternCache()
+
+
+def set_pattern_cache_size(maxsize: int) -> None:
+    """Enable the process-wide cache of compiled custom token regexes.
+
+    Custom :class:`TokenPattern` regexes and the tokenizer alternations
+    built from them are compiled once per distinct source and reused until
+    evicted, least recently used first, when more than `maxsize` are
+    cached. ``0`` (the default) disables the cache and clears it.
+
+    Raises:
+        TokenizationError: If `maxsize` is negative
+
+    Examples:
+        >>> set_pattern_cache_size(256)
+        >>> for request in requests:  # new config objects, no recompilation
+        ...     ticker = TokenPattern("ticker", r"[$][A-Z]+")
+        ...     tokenize(request.text, config=TokenizerConfig(patterns=[ticker]))
+    """
+    if maxsize < 0:
+        raise TokenizationError("Pattern cache size must not be negative.")
+    _PATTERN_CACHE.resize(maxsize)
+
+
+def pattern_cache_info() -> dict[str, int]:
+    """Hits, misses, current size and maximum size of the pattern cache."""
+    return _PATTERN_CACHE.info()
+
+
+@dataclass(frozen=True)
+class TokenPattern:
+    """A custom token pattern merged into the regex tokenizer.
+
+    Lets domain tokens (gene names, ticker symbols, legal citations) stay
+    whole without forking the tokenizer.
+
+    Attributes:
+        name: Name of the token class ("gene", "ticker"), unique per config
+        regex: Python regular expression of a whole token
+        priority: Place in the alternation: patterns are tried in order of
+            decreasing priority, and before built-in patterns of the same
+            priority. The built-ins are URLs (90), e-mails, IBANs, phones,
+            dates, times and number words (80), hashtags and mentions (70),
+            emoticons and emoji (60), words and numbers (50) and punctuation
+            (0); the default of 100 tries the pattern first.
+
+    Raises:
+        TokenizationError: If the name is empty, the regex does not compile
+            or it matches the empty string
+    """
+
+    name: str
+    regex: str
+    priority: int = 100
+
+    def __post_init__(self) -> None:
+        if not self.name:
+            raise TokenizationError("Token pattern name must not be empty.")
+        try:
+            compiled = _PATTERN_CACHE.compile(self.regex)
+        except re.error as e:
+            raise TokenizationError(
+                f"Invalid regex for token pattern '{self.name}': {e}"
+            ) from e
+        if compiled.fullmatch("") is not None:
+            raise TokenizationError(
+                f"Token pattern '{self.name}' matches the empty string."
+            )
+
+
+@dataclass(frozen=True)
+class TokenizerConfig:
+    """Optional token patterns of the regex tokenizer.
+
+    Each enabled pattern keeps its matches whole; a disabled one lets them
+    fall apart into word, number and punctuation tokens. The Rust module
+    functions (``tokenize_with_types``, ``TokenStream``, batch helpers) use
+    the default configuration; :meth:`rust_tokenizer` gives a
+    :class:`RegexTokenizer` with this one.
+
+    Attributes:
+        emails: Keep e-mail addresses as single tokens
+        phones: Keep Turkish phone numbers ("0532 123 45 67") as single tokens
+        ibans: Keep Turkish IBANs ("TR33 0006 ...") as single tokens
+        dates: Keep dates ("12.03.2024", "3 Mart 2024") as single tokens
+        times: Keep times ("14:30", "saat 14:30") as single tokens
+        numbers: Keep runs of spelled-out number words ("üç yüz kırk iki") as
+            single tokens; off by default since "bir", "yüz" and "altı" are
+            also everyday words. ``words_to_number`` reads their values.
+        patterns: Custom token patterns merged into the alternation by
+            priority (see :class:`TokenPattern`)
+
+    Examples:
+        >>> ticker = TokenPattern("ticker", "[$][A-Z]{2,5}")
+        >>> tokenize("$THYAO yükseldi", config=TokenizerConfig(patterns=[ticker]))
+        ['$THYAO', 'yükseldi']
+    """
+
+    emails: bool = True
+    phones: bool = True
+    ibans: bool = True
+    dates: bool = True
+    times: bool = True
+    numbers: bool = False
+    patterns: Sequence[TokenPattern] = ()
+
+    def __post_init__(self) -> None:
+        patterns = tuple(self.patterns)
+        names = [pattern.name for pattern in patterns]
+        duplicates = sorted({name for name in names if names.count(name) > 1})
+        if duplicates:
+            raise TokenizationError(
+                f"Duplicate token pattern names: {', '.join(duplicates)}."
+            )
+        # Frozen: normalize lists to a hashable tuple for the pattern cache
+        object.__setattr__(self, "patterns", patterns)
+
+    def pattern(self) -> re.Pattern[str]:
+        """Compiled token regex for this configuration.
+
+        Built-in configurations are compiled once; those with custom
+        patterns go through the pattern cache (see
+        :func:`set_pattern_cache_size`).
+        """
+        options = (
+            self.emails,
+            self.phones,
+            self.ibans,
+            self.dates,
+            self.times,
+            self.numbers,
+        )
+        if not self.patterns:
+            return _builtin_token_pattern(*options)
+        return _PATTERN_CACHE.compile(_token_regex(*options, tuple(self.patterns)))
+
+    def token_name(self, token: str) -> str | None:
+        """Name of the custom pattern matching the whole token, if any."""
+        for pattern in _by_priority(tuple(self.patterns)):
+            if _PATTERN_CACHE.compile(pattern.regex).fullmatch(token):
+                return pattern.name
+        return None
+
+    def tokenizer(self) -> Tokenizer:
+        """Tokenizer function with this configuration, for register_tokenizer."""
+        return partial(regex_tokenize, config=self)
+
+    def rust_tokenizer(self) -> RegexTokenizer:
+        """Rust :class:`RegexTokenizer` with the classes this config disables.
+
+        Raises:
+            TokenizationError: If the config has custom patterns or number
+                words, which the Rust tokenizer does not support
+        """
+        if self.patterns or self.numbers:
+            raise TokenizationError(
+                "Custom patterns and number words need the Python tokenizer."
+            )
+        toggles = {
+            "email": self.emails,
+            "phone": self.phones,
+            "iban": self.ibans,
+            "date": self.dates,
+            "time": self.times,
+        }
+        return RegexTokenizer(
+            disable=[name for name, enabled in toggles.items() if not enabled]
+        )
+
+
+def _by_priority(patterns: tuple[TokenPattern, ...]) -> list[TokenPattern]:
+    return sorted(patterns, key=lambda pattern: -pattern.priority)
+
+
+def _token_regex(
+    emails: bool,
+    phones: bool,
+    ibans: bool,
+    dates: bool,
+    times: bool,
+    numbers: bool,
+    patterns: tuple[TokenPattern, ...] = (),
+) -> str:
+    optional = [
+        pattern
+        for pattern, enabled in (
+            (EMAIL_TOKEN, emails),
+            (IBAN_TOKEN, ibans),
+            (PHONE_TOKEN, phones),
+            (DATE_TOKEN, dates),
+            (TIME_TOKEN, times),
+            (NUMBER_WORDS_TOKEN, numbers),
+        )
+        if enabled
+    ]
+    builtins = [
+        (URL_PRIORITY, URL_TOKEN),
+        *((OPTIONAL_PRIORITY, pattern) for pattern in optional),
+        (SOCIAL_PRIORITY, HASHTAG_TOKEN),
+        (SOCIAL_PRIORITY, MENTION_TOKEN),
+        (EMOTICON_PRIORITY, EMOTICON_TOKEN),
+        (EMOTICON_PRIORITY, EMOJI_TOKEN),
+        (WORD_PRIORITY, APOSTROPHE_TOKEN),
+        (WORD_PRIORITY, NUMBER_TOKEN),
+        (WORD_PRIORITY, WORD_TOKEN),
+        (PUNCT_PRIORITY, PUNCT_TOKEN),
+    ]
+    # Custom patterns come first so they win ties; the sort is stable
+    custom = [(pattern.priority, f"(?:{pattern.regex})") for pattern in patterns]
+    alternatives = sorted(custom + builtins, key=lambda item: -item[0])
+    return f"({'|'.join(regex for _, regex in alternatives)})"
+
+
+@lru_cache(maxsize=None)
+def _builtin_token_pattern(
+    emails: bool, phones: bool, ibans: bool, dates: bool, times: bool, numbers: bool
+) -> re.Pattern[str]:
+    return re.compile(
+        _token_regex(emails, phones, ibans, dates, times, numbers), flags=re.UNICODE
+    )
+
+
+REGEX_TOKEN_PATTERN = TokenizerConfig().pattern()
 
 SENTENCE_END_PATTERN = re.compile(r"([.!?…]+)(\s+|$)")
 ABBREVIATIONS = {
@@ -50,9 +420,19 @@ def register_sentence_splitter(name: str, func: SentenceSplitter) -> None:
     SENTENCE_SPLITTER_REGISTRY[name] = func
 
 
-def regex_tokenize(text: str) -> list[str]:
+def is_punct_token(token: str) -> bool:
+    """True for a lone punctuation or symbol character; emoji do not count."""
+    return (
+        re.fullmatch(PUNCT_TOKEN, token) is not None
+        and re.fullmatch(EMOJI_TOKEN, token) is None
+    )
+
+
+def regex_tokenize(text: str, config: TokenizerConfig | None = None) -> list[str]:
     """Tokenize text using regex patterns."""
-    matches = REGEX_TOKEN_PATTERN.findall(text)
+    pattern = REGEX_TOKEN_PATTERN if config is None else config.pattern()
+    # Whole matches: custom patterns may have groups of their own
+    matches = (match.group(0) for match in pattern.finditer(text))
     return [match for match in matches if match.strip()]
 
 
@@ -94,21 +474,81 @@ def tokenize(
     *,
     strategy: str = "regex",
     strip_punct: bool = False,
+    split_hashtags: bool = False,
+    config: TokenizerConfig | None = None,
+    clitics: Literal["attach", "detach"] = "attach",
+    profile: str | None = None,
 ) -> list[str]:
     """Tokenize text with optional punctuation stripping.
 
+    With ``split_hashtags`` every hashtag token is replaced by the lowercase
+    words of its contents (see :func:`split_hashtag`; needs the Rust
+    extension). ``config`` toggles the e-mail, phone, IBAN, date, time and
+    number-word patterns of the regex strategy and adds custom token
+    patterns (see :class:`TokenPattern`).
+
+    Suffixes written after an apostrophe stay on their word by default
+    ("İstanbul'a"); ``clitics="detach"`` splits them into a token of their
+    own starting with the apostrophe (see :func:`split_clitics`; needs the
+    Rust extension), so proper nouns and their case suffixes can be
+    lemmatized separately.
+
+    ``profile`` takes the tokenizer options of a shipped profile (news,
+    social, legal, medical; see :func:`~durak.list_profiles`) instead of
+    passing them one by one, and cannot be combined with them.
+
     Examples:
         >>> tokenize("Durak, kolay mı?", strip_punct=True)
         ['Durak', 'kolay', 'mı']
+        >>> tokenize("TCK'nın 12 Mart 2024 tarihli", profile="legal")
+        ['TCK', "'nın", '12 Mart 2024', 'tarihli']
+        >>> tokenize("#GünaydınTürkiye @durak", split_hashtags=True)
+        ['günaydın', 'türkiye', '@durak']
+        >>> tokenize("Tel: 0532 123 45 67")
+        ['Tel', ':', '0532 123 45 67']
+        >>> tokenize("3 Mart 2024 saat 14:30")
+        ['3 Mart 2024', 'saat 14:30']
+        >>> tokenize("İstanbul'a gittim", clitics="detach")
+        ['İstanbul', "'a", 'gittim']
     """
+    if profile is not None:
+        if strip_punct or split_hashtags or config is not None or clitics != "attach":
+            raise TokenizationError(
+                "profile cannot be combined with strip_punct, split_hashtags, "
+                "config or clitics."
+            )
+        from durak.config import PipelineConfig
+
+        options = PipelineConfig.from_profile(profile).tokenizer_options()
+        return tokenize(text, strategy=strategy, **options)
     if text is None:
         return []
+    if clitics not in ("attach", "detach"):
+        raise TokenizationError(
+            f"Unknown clitics mode '{clitics}', expected 'attach' or 'detach'."
+        )
     tokenizer = TOKENIZER_REGISTRY.get(strategy)
     if tokenizer is None:
         raise TokenizationError(f"Unknown tokenizer strategy '{strategy}'.")
-    tokens = tokenizer(text)
+    if config is not None and strategy != "regex":
+        raise TokenizationError(
+            f"Tokenizer config only applies to the 'regex' strategy, not '{strategy}'."
+        )
+    tokens = tokenizer(text) if config is None else regex_tokenize(text, config)
     if strip_punct:
-        tokens = [token for token in tokens if not re.fullmatch(PUNCT_TOKEN, token)]
+        tokens = [token for token in tokens if not is_punct_token(token)]
+    if split_hashtags:
+        tokens = [
+            piece
+            for token in tokens
+            for piece in (
+                split_hashtag(token)
+                if re.fullmatch(HASHTAG_TOKEN, token)
+                else [token]
+            )
+        ]
+    if clitics == "detach":
+        tokens = [piece for token in tokens for piece in split_clitics(token) if piece]
     return tokens
 
 
@@ -117,6 +557,10 @@ def tokenize_text(
     strategy: str = "regex",
     *,
     strip_punct: bool = False,
+    split_hashtags: bool = False,
+    config: TokenizerConfig | None = None,
+    clitics: Literal["attach", "detach"] = "attach",
+    profile: str | None = None,
 ) -> list[str]:
     """Backward-compatible wrapper around :func:`tokenize`.
 
@@ -124,7 +568,84 @@ def tokenize_text(
         >>> tokenize_text("Durak, kolay mı?", strip_punct=True)
         ['Durak', 'kolay', 'mı']
     """
-    return tokenize(text, strategy=strategy, strip_punct=strip_punct)
+    return tokenize(
+        text,
+        strategy=strategy,
+        strip_punct=strip_punct,
+        split_hashtags=split_hashtags,
+        config=config,
+        clitics=clitics,
+        profile=profile,
+    )
+
+
+def tokenize_spans(
+    text: str,
+    *,
+    strip_punct: bool = False,
+    split_hashtags: bool = False,
+    config: TokenizerConfig | None = None,
+    clitics: Literal["attach", "detach"] = "attach",
+) -> list[tuple[str, int, int]]:
+    """Regex :func:`tokenize` with the character span each token comes from.
+
+    Tokens the options rewrite keep the offsets of their source text: the
+    words of a split hashtag point at their letters in the hashtag, so
+    ``(token, start, end)`` need not satisfy ``text[start:end] == token``.
+
+    Examples:
+        >>> tokenize_spans("Bugün #GüzelGün", split_hashtags=True)
+        [('Bugün', 0, 5), ('güzel', 7, 12), ('gün', 12, 15)]
+        >>> tokenize_spans("Ankara'da", clitics="detach")
+        [('Ankara', 0, 6), ("'da", 6, 9)]
+    """
+    if clitics not in ("attach", "detach"):
+        raise TokenizationError(
+            f"Unknown clitics mode '{clitics}', expected 'attach' or 'detach'."
+        )
+    pattern = REGEX_TOKEN_PATTERN if config is None else config.pattern()
+    spans = [
+        (match.group(0), match.start(), match.end())
+        for match in pattern.finditer(text)
+        if match.group(0).strip()
+    ]
+    if strip_punct:
+        spans = [span for span in spans if not is_punct_token(span[0])]
+    if split_hashtags:
+        spans = [
+            piece
+            for span in spans
+            for piece in (
+                _hashtag_spans(*span)
+                if re.fullmatch(HASHTAG_TOKEN, span[0])
+                else [span]
+            )
+        ]
+    if clitics == "detach":
+        detached = []
+        for token, start, end in spans:
+            base, suffix = split_clitics(token)
+            split = start + len(base)
+            detached += [(base, start, split), (suffix, split, end)]
+        spans = [span for span in detached if span[0]]
+    return spans
+
+
+def _hashtag_spans(tag: str, start: int, end: int) -> list[tuple[str, int, int]]:
+    """Words of a hashtag with their spans; split_hashtag lowercases letter
+    by letter, so each word is found in the lowercased tag."""
+    words = split_hashtag(tag)
+    lowered = normalize_case(tag)
+    spans = []
+    cursor = 0
+    for word in words:
+        offset = lowered.find(word, cursor)
+        if offset < 0:
+            # Not a slice of the tag after all: span the whole hashtag
+            return [(word, start, end) for word in words]
+        cursor = offset + len(word)
+        spans.append((word, start + offset, start + cursor))
+    return spans
 
 
 def split_sentences(text: str, strategy: str = "regex") -> list[str]:
@@ -141,12 +662,116 @@ try:
     from . import _durak_core
     tokenize_with_offsets = _durak_core.tokenize_with_offsets
     tokenize_with_normalized_offsets = _durak_core.tokenize_with_normalized_offsets
+    tokenize_with_types = _durak_core.tokenize_with_types
+    tokenize_columns = _durak_core.tokenize_columns
+    tokenize_with_whitespace = _durak_core.tokenize_with_whitespace
+    detokenize = _durak_core.detokenize
+    tokenize_for_spacy = _durak_core.tokenize_for_spacy
+    pre_tokenize = _durak_core.pre_tokenize
+    split_hashtag = _durak_core.split_hashtag
+    TokenStream = _durak_core.TokenStream
+    Token = _durak_core.Token
+    Doc = _durak_core.Doc
+    RewriteRules = _durak_core.RewriteRules
+    RegexTokenizer = _durak_core.RegexTokenizer
 except ImportError:
+    def split_hashtag(tag: str) -> list[str]:
+        raise RustExtensionError(
+            "Rust extension not installed. Run: maturin develop"
+        )
+
+    def tokenize_with_types(
+        text: str, *, offsets: str | None = None
+    ) -> list[tuple[str, str, int, int]]:
+        raise RustExtensionError(
+            "Rust extension not installed. Run: maturin develop"
+        )
+
+    def tokenize_columns(
+        text: str,
+    ) -> tuple[list[str], list[int], list[int], list[str]]:
+        raise RustExtensionError(
+            "Rust extension not installed. Run: maturin develop"
+        )
+
+    def tokenize_with_whitespace(text: str) -> tuple[str, list[tuple[str, str]]]:
+        raise RustExtensionError(
+            "Rust extension not installed. Run: maturin develop"
+        )
+
+    def detokenize(tokens: list[str]) -> str:
+        raise RustExtensionError(
+            "Rust extension not installed. Run: maturin develop"
+        )
+
+    def tokenize_for_spacy(
+        text: str,
+    ) -> tuple[list[str], list[bool], list[tuple[int, int]]]:
+        raise RustExtensionError(
+            "Rust extension not installed. Run: maturin develop"
+        )
+
+    def pre_tokenize(text: str) -> list[tuple[str, tuple[int, int]]]:
+        raise RustExtensionError(
+            "Rust extension not installed. Run: maturin develop"
+        )
+
     def tokenize_with_normalized_offsets(text: str) -> list[tuple[str, int, int]]:
         raise RustExtensionError(
             "Rust extension not installed. Run: maturin develop"
         )
 
+    class TokenStream:  # type: ignore[no-redef]
+        """Lazy tokenizer over a file; requires the Rust extension."""
+
+        def __init__(
+            self,
+            source: str | os.PathLike[str] | IO[str] | IO[bytes],
+            chunk_size: int = 65536,
+            strip_punct: bool = False,
+        ) -> None:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+    class Token:  # type: ignore[no-redef]
+        """Token of a :class:`Doc`; requires the Rust extension."""
+
+        def __init__(self) -> None:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+    class Doc:  # type: ignore[no-redef]
+        """Tokenized text as Token objects; requires the Rust extension."""
+
+        def __init__(self, text: str) -> None:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+    class RewriteRules:  # type: ignore[no-redef]
+        """Token rewrite rules; requires the Rust extension."""
+
+        def __init__(self, rules: str) -> None:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+        @staticmethod
+        def from_file(path: str | os.PathLike[str]) -> RewriteRules:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
+    class RegexTokenizer:  # type: ignore[no-redef]
+        """Tokenizer with toggleable token classes; requires the Rust extension."""
+
+        def __init__(self, disable: Sequence[str] = ()) -> None:
+            raise RustExtensionError(
+                "Rust extension not installed. Run: maturin develop"
+            )
+
 
 def normalize_tokens(
     tokens: Iterable[str],
@@ -156,13 +781,226 @@ def normalize_tokens(
 ) -> list[str]:
     normalized: list[str] = []
     for token in tokens:
-        if strip_punct and re.fullmatch(PUNCT_TOKEN, token):
+        if strip_punct and is_punct_token(token):
             continue
         normalized_token = normalize_case(token, mode="lower") if lower else token
         normalized.append(normalized_token)
     return normalized
 
 
+@dataclass(frozen=True)
+class TokenDoc:
+    """Tokens with the whitespace after each, for exact reconstruction.
+
+    Like a spaCy ``Doc``, every token remembers the text between it and the
+    next token, so :attr:`text` gives back the input exactly, and after
+    token-level edits (masking, stopword removal) the rest of the text keeps
+    its original spacing and line breaks.
+
+    Attributes:
+        tokens: Token texts
+        whitespace: Text after each token up to the next one
+        prefix: Text before the first token
+
+    Examples:
+        >>> doc = TokenDoc.from_text("Bu kitap  çok güzel!\n")
+        >>> doc.text == "Bu kitap  çok güzel!\n"
+        True
+        >>> doc.mask(lambda t: t == "kitap").text
+        'Bu [MASK]  çok güzel!\n'
+        >>> doc.remove(lambda t: t in {"Bu", "çok"}).text
+        'kitap  güzel!\n'
+    """
+
+    tokens: tuple[str, ...]
+    whitespace: tuple[str, ...]
+    prefix: str = ""
+
+    def __post_init__(self) -> None:
+        object.__setattr__(self, "tokens", tuple(self.tokens))
+        object.__setattr__(self, "whitespace", tuple(self.whitespace))
+        if len(self.tokens) != len(self.whitespace):
+            raise TokenizationError(
+                "TokenDoc needs one whitespace entry per token: got "
+                f"{len(self.tokens)} tokens and {len(self.whitespace)} entries."
+            )
+
+    @classmethod
+    def from_text(cls, text: str) -> TokenDoc:
+        """Tokenize `text` with the Rust tokenizer, keeping its whitespace."""
+        prefix, pairs = tokenize_with_whitespace(text)
+        return cls(
+            tuple(token for token, _ in pairs),
+            tuple(trailing for _, trailing in pairs),
+            prefix,
+        )
+
+    @property
+    def text(self) -> str:
+        """The tokens joined with their whitespace."""
+        parts = [self.prefix]
+        for token, trailing in zip(self.tokens, self.whitespace):
+            parts.append(token)
+            parts.append(trailing)
+        return "".join(parts)
+
+    def __len__(self) -> int:
+        return len(self.tokens)
+
+    def __iter__(self) -> Iterator[tuple[str, str]]:
+        return iter(zip(self.tokens, self.whitespace))
+
+    def mask(self, predicate: Callable[[str], bool], mask: str = "[MASK]") -> TokenDoc:
+        """Replace the tokens matching `predicate` with `mask`."""
+        tokens = tuple(mask if predicate(t) else t for t in self.tokens)
+        return TokenDoc(tokens, self.whitespace, self.prefix)
+
+    def remove(self, predicate: Callable[[str], bool]) -> TokenDoc:
+        """Drop the tokens matching `predicate` with their whitespace.
+
+        A removed token's whitespace moves to the previous kept token when
+        that token has none ("Ali , gel" without "," keeps "Ali gel") or
+        when it holds a line break the previous whitespace lacks, so line
+        structure survives removal.
+        """
+        tokens: list[str] = []
+        whitespace: list[str] = []
+        for token, trailing in self:
+            if not predicate(token):
+                tokens.append(token)
+                whitespace.append(trailing)
+            elif whitespace and (
+                not whitespace[-1]
+                or ("\n" in trailing and "\n" not in whitespace[-1])
+            ):
+                whitespace[-1] = trailing
+        return TokenDoc(tuple(tokens), tuple(whitespace), self.prefix)
+
+
+def _locate(text: str, segments: Sequence[str]) -> list[tuple[int, int]]:
+    """Character spans of `segments` found in order in `text`."""
+    spans = []
+    cursor = 0
+    for index, segment in enumerate(segments):
+        start = text.find(segment, cursor)
+        if start < 0 or not segment:
+            raise TokenizationError(
+                f"Segment {index + 1} ({segment!r}) not found in the text "
+                f"after offset {cursor}."
+            )
+        cursor = start + len(segment)
+        spans.append((start, cursor))
+    return spans
+
+
+@dataclass(frozen=True)
+class SegmentationDiff:
+    """Spans where a segmentation agrees or disagrees with a reference.
+
+    Spans are character offsets into the text, and a predicted span counts
+    as correct when the reference has a span with the same start
+    and end. Works for tokens and sentences alike.
+
+    Attributes:
+        text: The segmented text
+        predicted: Character spans of the predicted segments
+        reference: Character spans of the reference segments
+    """
+
+    text: str
+    predicted: tuple[tuple[int, int], ...]
+    reference: tuple[tuple[int, int], ...]
+
+    @property
+    def matching(self) -> int:
+        """Number of predicted spans that are also reference spans."""
+        return len(set(self.predicted) & set(self.reference))
+
+    @property
+    def precision(self) -> float:
+        return self.matching / len(self.predicted) if self.predicted else 0.0
+
+    @property
+    def recall(self) -> float:
+        return self.matching / len(self.reference) if self.reference else 0.0
+
+    @property
+    def f1(self) -> float:
+        total = self.precision + self.recall
+        return 2 * self.precision * self.recall / total if total else 0.0
+
+    def mismatches(self) -> list[tuple[int, int, list[str], list[str]]]:
+        """Regions where the segmentations differ, in text order.
+
+        Overlapping unmatched spans of both sides form one region, returned
+        as (start, end, predicted segments, reference segments).
+        """
+        common = set(self.predicted) & set(self.reference)
+        unmatched = sorted(
+            [(span, 0) for span in self.predicted if span not in common]
+            + [(span, 1) for span in self.reference if span not in common]
+        )
+        regions: list[tuple[int, int, list[str], list[str]]] = []
+        for (start, end), side in unmatched:
+            if not regions or start >= regions[-1][1]:
+                regions.append((start, end, [], []))
+            region_start, region_end, predicted, reference = regions[-1]
+            regions[-1] = (region_start, max(region_end, end), predicted, reference)
+            (predicted, reference)[side].append(self.text[start:end])
+        return regions
+
+    def to_dict(self, limit: int | None = None) -> dict[str, object]:
+        """Scores and up to `limit` mismatching regions, JSON-ready."""
+        return {
+            "predicted": len(self.predicted),
+            "reference": len(self.reference),
+            "matching": self.matching,
+            "precision": self.precision,
+            "recall": self.recall,
+            "f1": self.f1,
+            "mismatches": [
+                {"start": start, "end": end, "predicted": pred, "reference": ref}
+                for start, end, pred, ref in self.mismatches()[:limit]
+            ],
+        }
+
+
+def compare_segmentation(
+    text: str,
+    predicted: Sequence[str] | Sequence[tuple[str, int, int]],
+    reference: Sequence[str],
+) -> SegmentationDiff:
+    """Compare two segmentations of `text` into tokens or sentences.
+
+    Args:
+        text: The text both segmentations come from
+        predicted: Segments with their character spans, as
+            :func:`tokenize_spans` gives them, so tokens the tokenizer
+            rewrites (split hashtags) keep their place; plain segments
+            (e.g. from :func:`split_sentences`) are searched for in the text
+        reference: Gold segments, searched for in the text
+
+    Raises:
+        TokenizationError: A searched segment does not occur in the text
+            after the previous one
+
+    Examples:
+        >>> text = "3 Mart 2024 geldi."
+        >>> diff = compare_segmentation(
+        ...     text, tokenize_spans(text), ["3", "Mart", "2024", "geldi", "."]
+        ... )
+        >>> round(diff.precision, 2), round(diff.recall, 2)
+        (0.67, 0.4)
+        >>> diff.mismatches()
+        [(0, 11, ['3 Mart 2024'], ['3', 'Mart', '2024'])]
+    """
+    if all(isinstance(segment, str) for segment in predicted):
+        spans = _locate(text, predicted)  # type: ignore[arg-type]
+    else:
+        spans = [(start, end) for _, start, end in predicted]
+    return SegmentationDiff(text, tuple(spans), tuple(_locate(text, reference)))
+
+
 @dataclass
 class SubwordTokenizer:
     """Placeholder interface for future subword tokenizers."""
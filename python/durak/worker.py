@@ -0,0 +1,286 @@
+"""Stateless worker API for distributed frameworks (Ray, Dask, Spark).
+
+A worker is fully described by a small JSON config string. Ship the string
+to each worker, call :func:`init_worker` once when the worker starts, then
+call :func:`process_documents` on batches of texts::
+
+    import ray
+    from durak.worker import WorkerConfig, init_worker, process_documents
+
+    config = WorkerConfig(remove_stopwords=True, lemmatize=True).to_json()
+
+    @ray.remote
+    def preprocess(texts: list[str]) -> list[dict]:
+        return process_documents(texts, config)
+
+Nothing else has to be serialized: dictionaries, stopwords and suffix
+tables are embedded in the Rust extension, and every other piece of state
+is rebuilt from the config. The config only accepts JSON values (no file
+paths or objects), so two workers given the same string always produce the
+same output. :func:`process_documents` initializes the worker for a config
+it has not seen yet, which makes :func:`init_worker` optional; it only moves
+the set-up cost out of the first batch.
+"""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Iterable
+from dataclasses import asdict, dataclass, fields
+from functools import lru_cache
+from typing import Union, cast
+
+from durak.exceptions import ConfigurationError, RustExtensionError
+from durak.lemmatizer import Lemmatizer, Strategy
+from durak.stopwords import StopwordManager
+
+try:
+    from durak._durak_core import lemmatize_batch, normalize_batch, tokenize_batch
+except ImportError:
+
+    def lemmatize_batch(
+        words: list[str | None],
+        strategy: str = "hybrid",
+        validate_roots: bool = False,
+        strict_validation: bool = False,
+        min_root_length: int | None = None,
+        threads: int = 1,
+    ) -> list[str | None]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def normalize_batch(
+        texts: list[str | None],
+        lowercase: bool = True,
+        handle_turkish_i: bool = True,
+        threads: int = 1,
+    ) -> list[str | None]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+    def tokenize_batch(
+        texts: list[str | None], threads: int = 1
+    ) -> list[list[str] | None]:
+        raise RustExtensionError("Rust extension not installed. Run: maturin develop")
+
+
+Document = dict[str, list[str]]
+"""Per-document result: ``tokens``, plus ``lemmas`` when lemmatizing."""
+
+_JSON_TYPES: dict[str, type | tuple[type, ...]] = {
+    "bool": bool,
+    "int": int,
+    "str": str,
+    "Strategy": str,
+    "tuple[str, ...]": (list, tuple),
+}
+
+
+@dataclass(frozen=True)
+class WorkerConfig:
+    """Everything a worker needs, serializable as a JSON object.
+
+    Attributes:
+        normalize: Lowercase with Turkish I/ı handling before tokenizing
+        remove_stopwords: Drop base stopwords (adjusted by the two lists below)
+        stopword_additions: Extra stopwords to drop
+        stopword_keep: Words never treated as stopwords
+        lemmatize: Add a ``lemmas`` list aligned with ``tokens``
+        strategy: Lemmatization strategy (as in ``Lemmatizer``)
+        validate_roots: Validate stripped roots (as in ``Lemmatizer``)
+        strict_validation: Require dictionary roots (as in ``Lemmatizer``)
+        min_root_length: Minimum root length (as in ``Lemmatizer``)
+    """
+
+    normalize: bool = True
+    remove_stopwords: bool = False
+    stopword_additions: tuple[str, ...] = ()
+    stopword_keep: tuple[str, ...] = ()
+    lemmatize: bool = False
+    strategy: Strategy = "hybrid"
+    validate_roots: bool = False
+    strict_validation: bool = False
+    min_root_length: int = 2
+
+    def __post_init__(self) -> None:
+        for field in fields(self):
+            value = getattr(self, field.name)
+            expected = _JSON_TYPES[str(field.type)]
+            # bool is an int subclass; reject True where a number is expected
+            if not isinstance(value, expected) or (
+                field.type == "int" and isinstance(value, bool)
+            ):
+                raise ConfigurationError(
+                    f"worker config field '{field.name}' must be {field.type}, "
+                    f"got {type(value).__name__}"
+                )
+            if field.type == "tuple[str, ...]":
+                if not all(isinstance(item, str) for item in value):
+                    raise ConfigurationError(
+                        f"worker config field '{field.name}' must contain strings"
+                    )
+                object.__setattr__(self, field.name, tuple(value))
+
+        # Same errors as constructing the Lemmatizer directly
+        Lemmatizer(
+            strategy=self.strategy,
+            validate_roots=self.validate_roots,
+            strict_validation=self.strict_validation,
+            min_root_length=self.min_root_length,
+        )
+
+    @classmethod
+    def from_json(cls, config_json: str) -> WorkerConfig:
+        """Parse a config string, rejecting unknown keys and non-JSON state."""
+        try:
+            data = json.loads(config_json)
+        except json.JSONDecodeError as e:
+            raise ConfigurationError(
+                f"worker config is not valid JSON: {e.msg}"
+            ) from e
+        if not isinstance(data, dict):
+            raise ConfigurationError("worker config must be a JSON object")
+
+        known = {field.name for field in fields(cls)}
+        unknown = sorted(set(data) - known)
+        if unknown:
+            raise ConfigurationError(
+                f"unknown worker config keys: {', '.join(unknown)}"
+            )
+        return cls(**data)
+
+    def to_json(self) -> str:
+        """Canonical JSON form: equal configs give identical strings."""
+        return json.dumps(
+            asdict(self), ensure_ascii=False, sort_keys=True, separators=(",", ":")
+        )
+
+
+class _Worker:
+    """State re-created from a config: the stopword set and batch options."""
+
+    def __init__(self, config: WorkerConfig) -> None:
+        self.config = config
+        self.stopwords = (
+            StopwordManager(
+                additions=config.stopword_additions, keep=config.stopword_keep
+            )
+            if config.remove_stopwords
+            else None
+        )
+
+    def run(
+        self, texts: list[str | None], threads: int = 1
+    ) -> list[Document | None]:
+        config = self.config
+        if config.normalize:
+            texts = normalize_batch(texts, threads=threads)
+
+        token_lists = tokenize_batch(texts, threads=threads)
+        if self.stopwords is not None:
+            stopwords = self.stopwords
+            token_lists = [
+                None
+                if tokens is None
+                else [t for t in tokens if not stopwords.is_stopword(t)]
+                for tokens in token_lists
+            ]
+
+        if not config.lemmatize:
+            return [
+                None if tokens is None else {"tokens": tokens}
+                for tokens in token_lists
+            ]
+
+        # One Rust call for the whole batch, split back per document
+        words: list[str | None] = [
+            token for tokens in token_lists if tokens is not None for token in tokens
+        ]
+        lemmas = lemmatize_batch(
+            words,
+            strategy=config.strategy,
+            validate_roots=config.validate_roots,
+            strict_validation=config.strict_validation,
+            min_root_length=config.min_root_length,
+            threads=threads,
+        )
+
+        documents: list[Document | None] = []
+        offset = 0
+        for tokens in token_lists:
+            if tokens is None:
+                documents.append(None)
+                continue
+            # Tokens are never None, so neither are their lemmas
+            doc_lemmas = cast(list[str], lemmas[offset : offset + len(tokens)])
+            offset += len(tokens)
+            documents.append({"tokens": tokens, "lemmas": doc_lemmas})
+        return documents
+
+
+@lru_cache(maxsize=8)
+def _worker(canonical_json: str) -> _Worker:
+    return _Worker(WorkerConfig.from_json(canonical_json))
+
+
+def _config_key(config: Union[str, WorkerConfig, None]) -> str:
+    if config is None:
+        return WorkerConfig().to_json()
+    if isinstance(config, WorkerConfig):
+        return config.to_json()
+    return WorkerConfig.from_json(config).to_json()
+
+
+def init_worker(config_json: str = "{}") -> WorkerConfig:
+    """Validate a config and prepare this process to use it.
+
+    Builds the worker state and touches the embedded resources so the first
+    :func:`process_documents` call does not pay for them. Calling it again
+    with an equivalent config is a no-op.
+
+    Args:
+        config_json: JSON object with :class:`WorkerConfig` fields; missing
+            keys take their defaults
+
+    Returns:
+        The parsed config
+
+    Raises:
+        ConfigurationError: If the config is not a JSON object, has unknown
+            keys, has values of the wrong type or an invalid lemmatizer option
+
+    Examples:
+        >>> init_worker('{"lemmatize": true}').lemmatize
+        True
+    """
+    key = _config_key(config_json)
+    worker = _worker(key)
+    worker.run(["Kitaplar geldi."])
+    return worker.config
+
+
+def process_documents(
+    texts: Iterable[str | None],
+    config_json: Union[str, WorkerConfig, None] = None,
+    *,
+    threads: int = 1,
+) -> list[Document | None]:
+    """Process a batch of documents; a pure function of its arguments.
+
+    Args:
+        texts: Documents; None entries stay None in the output
+        config_json: Worker config as a JSON string or :class:`WorkerConfig`
+            (default: ``WorkerConfig()``)
+        threads: Threads used by the Rust core for this batch (0: one per
+            core); only affects speed, never the output or its order
+
+    Returns:
+        One ``{"tokens": [...]}`` dict per document, with an aligned
+        ``"lemmas"`` list when the config enables lemmatization
+
+    Examples:
+        >>> process_documents(["Kitaplar geldi."], '{"lemmatize": true}')
+        [{'tokens': ['kitaplar', 'geldi', '.'], 'lemmas': ['kitap', 'gel', '.']}]
+    """
+    return _worker(_config_key(config_json)).run(list(texts), threads=threads)
+
+
+__all__ = ["WorkerConfig", "init_worker", "process_documents"]
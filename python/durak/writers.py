@@ -0,0 +1,212 @@
+"""Output writers for the CLI and batch jobs.
+
+:func:`open_writer` picks a writer from the target string:
+
+- ``None`` or ``"-"``: standard output
+- ``http://...`` / ``https://...``: HTTP PUT of the whole output when the
+  writer is closed. Pre-signed S3, GCS and MinIO upload URLs work as-is.
+- ``s3://bucket/key``: upload with boto3 (``pip install "durak-nlp[s3]"``),
+  using the usual AWS credential chain and ``AWS_ENDPOINT_URL`` for
+  S3-compatible stores
+- anything else: a local file
+
+Remote writers spool output through a temporary file (in memory up to
+8 MiB), so memory stays bounded for large shards and the upload carries an
+exact Content-Length, which object stores require. Nothing is uploaded if
+the writer is closed after an error.
+
+    >>> with open_writer("shard-0001.jsonl") as writer:
+    ...     writer.write('{"tokens": ["merhaba"]}\\n')
+"""
+
+from __future__ import annotations
+
+import sys
+import tempfile
+import urllib.error
+import urllib.request
+from abc import ABC, abstractmethod
+from pathlib import Path
+from types import TracebackType
+from typing import IO, Any
+
+from durak.exceptions import DurakError
+
+# Spooled output stays in memory up to this size, then moves to disk
+_SPOOL_MAX_SIZE = 8 * 1024 * 1024
+
+
+class WriterError(DurakError):
+    """Raised when output cannot be delivered to its target."""
+
+
+class OutputWriter(ABC):
+    """Text sink; output is complete once :meth:`close` returns."""
+
+    @abstractmethod
+    def write(self, text: str) -> None:
+        """Append text to the output."""
+
+    def close(self) -> None:
+        """Finish the output (flush, upload)."""
+
+    def abort(self) -> None:
+        """Discard the output after an error; remote targets are not touched."""
+        self.close()
+
+    def __enter__(self) -> OutputWriter:
+        return self
+
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc: BaseException | None,
+        traceback: TracebackType | None,
+    ) -> None:
+        if exc_type is None:
+            self.close()
+        else:
+            self.abort()
+
+
+class StdoutWriter(OutputWriter):
+    """Writes to standard output, which is left open."""
+
+    def write(self, text: str) -> None:
+        sys.stdout.write(text)
+
+    def close(self) -> None:
+        sys.stdout.flush()
+
+
+class FileWriter(OutputWriter):
+    """Writes a local UTF-8 file."""
+
+    def __init__(self, path: str | Path) -> None:
+        self.path = Path(path)
+        self._handle: IO[str] = open(self.path, "w", encoding="utf-8")
+
+    def write(self, text: str) -> None:
+        self._handle.write(text)
+
+    def close(self) -> None:
+        self._handle.close()
+
+
+class _SpooledWriter(OutputWriter):
+    """Buffers output and hands the bytes to :meth:`upload` on close."""
+
+    def __init__(self) -> None:
+        self._buffer: IO[bytes] = tempfile.SpooledTemporaryFile(
+            max_size=_SPOOL_MAX_SIZE
+        )
+        self._closed = False
+
+    def write(self, text: str) -> None:
+        self._buffer.write(text.encode("utf-8"))
+
+    @abstractmethod
+    def upload(self, body: IO[bytes], length: int) -> None:
+        """Send the spooled output."""
+
+    def close(self) -> None:
+        if self._closed:
+            return
+        self._closed = True
+        try:
+            length = self._buffer.tell()
+            self._buffer.seek(0)
+            self.upload(self._buffer, length)
+        finally:
+            self._buffer.close()
+
+    def abort(self) -> None:
+        self._closed = True
+        self._buffer.close()
+
+
+class HttpPutWriter(_SpooledWriter):
+    """Uploads the output with a single HTTP PUT request."""
+
+    def __init__(
+        self,
+        url: str,
+        *,
+        content_type: str = "text/plain; charset=utf-8",
+        headers: dict[str, str] | None = None,
+        timeout: float = 60.0,
+    ) -> None:
+        super().__init__()
+        self.url = url
+        self.headers = {"Content-Type": content_type, **(headers or {})}
+        self.timeout = timeout
+
+    def upload(self, body: IO[bytes], length: int) -> None:
+        request = urllib.request.Request(
+            self.url,
+            data=body,
+            method="PUT",
+            headers={**self.headers, "Content-Length": str(length)},
+        )
+        try:
+            with urllib.request.urlopen(request, timeout=self.timeout):
+                pass
+        except urllib.error.HTTPError as e:
+            raise WriterError(f"PUT {self.url} failed: HTTP {e.code} {e.reason}")
+        except urllib.error.URLError as e:
+            raise WriterError(f"PUT {self.url} failed: {e.reason}") from e
+
+
+class S3Writer(_SpooledWriter):
+    """Uploads the output to ``s3://bucket/key`` with boto3."""
+
+    def __init__(self, url: str, *, client: Any = None) -> None:
+        bucket, _, key = url[len("s3://") :].partition("/")
+        if not bucket or not key:
+            raise WriterError(f"expected s3://bucket/key, got {url!r}")
+        if client is None:
+            try:
+                import boto3
+            except ImportError as e:
+                raise WriterError(
+                    "s3:// output requires boto3. "
+                    "Install with: pip install 'durak-nlp[s3]'"
+                ) from e
+            client = boto3.client("s3")
+        super().__init__()
+        self.url = url
+        self.bucket = bucket
+        self.key = key
+        self._client = client
+
+    def upload(self, body: IO[bytes], length: int) -> None:
+        try:
+            self._client.upload_fileobj(body, self.bucket, self.key)
+        except Exception as e:  # botocore raises many unrelated types
+            raise WriterError(f"upload to {self.url} failed: {e}") from e
+
+
+def open_writer(target: str | None) -> OutputWriter:
+    """Open the writer for a target: stdout, URL or file path.
+
+    Raises:
+        WriterError: For a malformed ``s3://`` target or missing boto3
+    """
+    if target is None or target == "-":
+        return StdoutWriter()
+    if target.startswith(("http://", "https://")):
+        return HttpPutWriter(target)
+    if target.startswith("s3://"):
+        return S3Writer(target)
+    return FileWriter(target)
+
+
+__all__ = [
+    "FileWriter",
+    "HttpPutWriter",
+    "OutputWriter",
+    "S3Writer",
+    "StdoutWriter",
+    "WriterError",
+    "open_writer",
+]
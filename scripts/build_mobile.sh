@@ -0,0 +1,46 @@
+#!/usr/bin/env bash
+# Build the Durak core as a static library for mobile embedding.
+#
+# Usage:
+#   scripts/build_mobile.sh [TARGET...]   # default: aarch64-linux-android aarch64-apple-ios
+#   scripts/build_mobile.sh --check       # host build + C smoke test
+#
+# The library is built without Python (--no-default-features --features ffi)
+# using the size-optimized `mobile` profile. Static archives need no linker,
+# so cross-compiling only requires the Rust target (no NDK or Xcode); link the
+# archive into the app with its platform toolchain.
+#
+# Set DURAK_LEMMA_TOP_N to shrink the embedded dictionary (see build.rs).
+#
+# Output: target/<TARGET>/mobile/lib_durak_core.a, header: include/durak.h
+
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+build() {
+    cargo rustc --lib --profile mobile --no-default-features --features ffi \
+        --crate-type staticlib "$@"
+}
+
+if [[ "${1:-}" == "--check" ]]; then
+    build
+    out="$(mktemp -d)"
+    cc -std=c99 -Wall -Werror -Iinclude tests/ffi/smoke_test.c \
+        target/mobile/lib_durak_core.a -lpthread -ldl -lm -o "$out/smoke_test"
+    "$out/smoke_test"
+    exit 0
+fi
+
+targets=("$@")
+if [[ ${#targets[@]} -eq 0 ]]; then
+    targets=(aarch64-linux-android aarch64-apple-ios)
+fi
+
+for target in "${targets[@]}"; do
+    if command -v rustup >/dev/null; then
+        rustup target add "$target"
+    fi
+    build --target "$target"
+    echo "Built target/$target/mobile/lib_durak_core.a"
+done
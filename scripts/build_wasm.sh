@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+# Build the Durak core as a WebAssembly module for browsers.
+#
+# Usage:
+#   scripts/build_wasm.sh           # build the module and its bindings
+#   scripts/build_wasm.sh --check   # build + Node.js smoke test
+#
+# The module is built without Python (--no-default-features --features wasm)
+# for wasm32-unknown-unknown using the size-optimized `wasm` profile, then
+# wasm-bindgen generates the JavaScript bindings. The wasm-bindgen CLI must
+# match the crate version in Cargo.lock:
+#
+#   cargo install wasm-bindgen-cli --version <version>
+#
+# Set DURAK_LEMMA_TOP_N to shrink the embedded dictionary (see build.rs).
+#
+# Output: target/wasm32-unknown-unknown/wasm/pkg/ (ES module for browsers)
+
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+target=wasm32-unknown-unknown
+if command -v rustup >/dev/null; then
+    rustup target add "$target"
+fi
+cargo build --lib --profile wasm --no-default-features --features wasm --target "$target"
+module="target/$target/wasm/_durak_core.wasm"
+wasm-bindgen --target web --out-dir "target/$target/wasm/pkg" "$module"
+echo "Built target/$target/wasm/pkg"
+
+if [[ "${1:-}" == "--check" ]]; then
+    wasm-bindgen --target nodejs --out-dir "target/$target/wasm/node" "$module"
+    node tests/wasm/smoke_test.mjs "target/$target/wasm/node/_durak_core.js"
+fi
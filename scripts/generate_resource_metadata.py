@@ -105,6 +105,181 @@ def generate_metadata():
             "last_updated": "2026-01-26"
         }
     
+    # Confusion Pairs
+    confusion_pairs = resources_dir / "config/confusion_pairs.txt"
+    if confusion_pairs.exists():
+        metadata["resources"]["confusion_pairs"] = {
+            "name": "Turkish Confusion Pairs",
+            "version": "1.0.0",
+            "source": "Common misspellings in user-generated text (TDK spelling)",
+            "checksum": compute_checksum(confusion_pairs),
+            "item_count": count_items(confusion_pairs),
+            "last_updated": "2026-10-14"
+        }
+    
+    # Turkish Given Names (NER Gazetteer)
+    gazetteer_person_names = resources_dir / "gazetteers/person_names.txt"
+    if gazetteer_person_names.exists():
+        metadata["resources"]["gazetteer_person_names"] = {
+            "name": "Turkish Given Names (NER Gazetteer)",
+            "version": "1.0.0",
+            "source": "High-frequency Turkish given names",
+            "checksum": compute_checksum(gazetteer_person_names),
+            "item_count": count_items(gazetteer_person_names),
+            "last_updated": "2026-10-14"
+        }
+    
+    # Turkish Provinces (NER Gazetteer)
+    gazetteer_locations = resources_dir / "gazetteers/locations.txt"
+    if gazetteer_locations.exists():
+        metadata["resources"]["gazetteer_locations"] = {
+            "name": "Turkish Provinces (NER Gazetteer)",
+            "version": "1.0.0",
+            "source": "Official list of the 81 provinces of Turkey",
+            "checksum": compute_checksum(gazetteer_locations),
+            "item_count": count_items(gazetteer_locations),
+            "last_updated": "2026-10-14"
+        }
+    
+    # Turkish Organizations (NER Gazetteer)
+    gazetteer_organizations = resources_dir / "gazetteers/organizations.txt"
+    if gazetteer_organizations.exists():
+        metadata["resources"]["gazetteer_organizations"] = {
+            "name": "Turkish Organizations (NER Gazetteer)",
+            "version": "1.0.0",
+            "source": "Curated by Durak team",
+            "checksum": compute_checksum(gazetteer_organizations),
+            "item_count": count_items(gazetteer_organizations),
+            "last_updated": "2026-10-14"
+        }
+    
+    # Root Parts of Speech
+    root_pos = resources_dir / "lemmas/root_pos.txt"
+    if root_pos.exists():
+        metadata["resources"]["root_pos"] = {
+            "name": "Turkish Root Parts of Speech",
+            "version": "1.0.0",
+            "source": "Curated by Durak team",
+            "checksum": compute_checksum(root_pos),
+            "item_count": count_items(root_pos),
+            "last_updated": "2026-10-14"
+        }
+    
+    # Lemma Frequency Ranking
+    lemma_frequency = resources_dir / "lemmas/lemma_frequency.txt"
+    if lemma_frequency.exists():
+        metadata["resources"]["lemma_frequency"] = {
+            "name": "Turkish Lemma Frequency Ranking",
+            "version": "1.0.0",
+            "source": "Curated by Durak team",
+            "checksum": compute_checksum(lemma_frequency),
+            "item_count": count_items(lemma_frequency),
+            "last_updated": "2026-10-14"
+        }
+    
+    vowel_drop = resources_dir / "lemmas/vowel_drop.txt"
+    if vowel_drop.exists():
+        metadata["resources"]["vowel_drop"] = {
+            "name": "Turkish Vowel-Dropping Roots",
+            "version": "1.0.0",
+            "source": "Curated by Durak team",
+            "checksum": compute_checksum(vowel_drop),
+            "item_count": count_items(vowel_drop),
+            "last_updated": "2026-10-14"
+        }
+    
+    keyboard_layouts = resources_dir / "config/keyboard_layouts.txt"
+    if keyboard_layouts.exists():
+        metadata["resources"]["keyboard_layouts"] = {
+            "name": "Turkish Keyboard Layouts (Q and F)",
+            "version": "1.0.0",
+            "source": "Standard Turkish Q and F keyboard layouts",
+            "checksum": compute_checksum(keyboard_layouts),
+            "item_count": count_items(keyboard_layouts),
+            "last_updated": "2026-10-14"
+        }
+    
+    suffix_frequencies = resources_dir / "config/suffix_frequencies.txt"
+    if suffix_frequencies.exists():
+        metadata["resources"]["suffix_frequencies"] = {
+            "name": "Turkish Suffix Frequencies",
+            "version": "1.0.0",
+            "source": "Estimated suffix tag frequencies, curated by Durak team",
+            "checksum": compute_checksum(suffix_frequencies),
+            "item_count": count_items(suffix_frequencies),
+            "last_updated": "2026-10-14"
+        }
+    
+    grammar_rules = resources_dir / "config/grammar_rules.txt"
+    if grammar_rules.exists():
+        metadata["resources"]["grammar_rules"] = {
+            "name": "Turkish Grammar Rules",
+            "version": "1.0.0",
+            "source": (
+                "Frequent spelling and grammar errors (TDK spelling), "
+                "curated by Durak team"
+            ),
+            "checksum": compute_checksum(grammar_rules),
+            "item_count": count_items(grammar_rules),
+            "last_updated": "2026-10-14"
+        }
+    
+    slang = resources_dir / "config/slang.txt"
+    if slang.exists():
+        metadata["resources"]["slang"] = {
+            "name": "Turkish Informal Lexicon",
+            "version": "1.0.0",
+            "source": (
+                "Slang and chat abbreviations in user-generated text, "
+                "curated by Durak team"
+            ),
+            "checksum": compute_checksum(slang),
+            "item_count": count_items(slang),
+            "last_updated": "2026-10-14"
+        }
+    
+    dialect_cues = resources_dir / "config/dialect_cues.txt"
+    if dialect_cues.exists():
+        metadata["resources"]["dialect_cues"] = {
+            "name": "Turkish Dialect and Variant Cues",
+            "version": "1.0.0",
+            "source": (
+                "Dialectal and Azerbaijani-influenced spellings, "
+                "curated by Durak team"
+            ),
+            "checksum": compute_checksum(dialect_cues),
+            "item_count": count_items(dialect_cues),
+            "last_updated": "2026-10-14"
+        }
+    
+    etymology = resources_dir / "config/etymology.txt"
+    if etymology.exists():
+        metadata["resources"]["etymology"] = {
+            "name": "Turkish Loanword Origins",
+            "version": "1.0.0",
+            "source": (
+                "Common Arabic, Persian, French and English loanwords "
+                "(TDK dictionary), curated by Durak team"
+            ),
+            "checksum": compute_checksum(etymology),
+            "item_count": count_items(etymology),
+            "last_updated": "2026-10-14"
+        }
+    
+    language_profiles = resources_dir / "config/language_profiles.txt"
+    if language_profiles.exists():
+        metadata["resources"]["language_profiles"] = {
+            "name": "Language Identification Profiles",
+            "version": "1.0.0",
+            "source": (
+                "Character trigram ranks of general text per language, "
+                "curated by Durak team"
+            ),
+            "checksum": compute_checksum(language_profiles),
+            "item_count": count_items(language_profiles),
+            "last_updated": "2026-10-14"
+        }
+    
     # Write metadata
     output_path = Path("resources/metadata.json")
     with open(output_path, "w", encoding='utf-8') as f:
@@ -0,0 +1,320 @@
+//! Person and possessor agreement checking
+//!
+//! Flags two kinds of agreement errors with the morphological analyzer:
+//!
+//! - Possessor: a genitive possessor and the possessive suffix of its head
+//!   noun disagree ("benim arabası" for "benim arabam"). Possessors are the
+//!   genitive personal pronouns, proper nouns with a genitive after the
+//!   apostrophe ("Ali'nin") and nouns whose only reading is genitive; they
+//!   take a 3rd person possessive. The head is the next noun, skipping up
+//!   to three adjectives, numerals or adverbs.
+//! - Person: a subject pronoun and the person ending of the finite verb
+//!   closing its clause disagree ("ben geldin" for "ben geldim").
+//!
+//! Heads without a possessive suffix ("bizim ev") and clauses without a
+//! finite verb are not judged, and a word is only flagged when none of its
+//! analyses agrees. Clauses end at punctuation and before a coordinating
+//! conjunction.
+
+use crate::analyzer::{self, Analysis, Pos};
+use crate::fast_normalize;
+use crate::pos::{self, Tag};
+use regex::Regex;
+use std::sync::OnceLock;
+
+static WORD_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// Possessives agreeing with each genitive pronoun
+const GENITIVE_PRONOUNS: &[(&str, &[&str])] = &[
+    ("benim", &["P1sg"]),
+    ("senin", &["P2sg"]),
+    ("onun", &["P3sg", "P3pl"]),
+    ("bizim", &["P1pl"]),
+    ("sizin", &["P2pl"]),
+    ("onların", &["P3pl", "P3sg"]),
+];
+
+/// Possessives agreeing with a genitive noun
+const THIRD_PERSON: &[&str] = &["P3sg", "P3pl"];
+
+/// Person endings agreeing with each subject pronoun; 3rd person singular
+/// has no ending and is reported as "A3sg"
+const SUBJECT_PRONOUNS: &[(&str, &[&str])] = &[
+    ("ben", &["A1sg"]),
+    ("sen", &["A2sg"]),
+    ("o", &["A3sg"]),
+    ("biz", &["A1pl"]),
+    ("siz", &["A2pl"]),
+    ("onlar", &["A3pl", "A3sg"]),
+];
+
+const POSSESSIVES: &[&str] = &["P1sg", "P2sg", "P3sg", "P1pl", "P2pl", "P3pl"];
+const PERSONS: &[&str] = &["A1sg", "A2sg", "A1pl", "A2pl", "A3pl"];
+const TENSES: &[&str] = &["Past", "Cond", "Narr", "Fut", "Prog1", "Prog2", "Neces", "Aor"];
+
+/// Conjunctions that start a new clause
+const CONJUNCTIONS: &[&str] = &["ama", "ancak", "fakat", "veya", "ve", "yoksa", "çünkü"];
+
+/// Kind of agreement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Possessor,
+    Person,
+}
+
+impl Kind {
+    /// Label used by the Python API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Kind::Possessor => "POSSESSOR",
+            Kind::Person => "PERSON",
+        }
+    }
+}
+
+/// An agreement error spanning the controller and the disagreeing word,
+/// with character offsets into the input text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub text: String,
+    pub kind: Kind,
+    /// Tag the controller requires ("P1sg", "A1sg")
+    pub expected: &'static str,
+    /// Tag of the disagreeing word's preferred analysis
+    pub found: &'static str,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A word of the text as a byte range, lowercased
+pub(crate) struct Word {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    pub(crate) lower: String,
+    /// Last word of its clause
+    pub(crate) last: bool,
+}
+
+fn word_regex() -> &'static Regex {
+    WORD_REGEX.get_or_init(|| {
+        Regex::new(r"\p{L}+(?:['’]\p{L}+)?").expect("Invalid word regex")
+    })
+}
+
+/// Words of the text, with clauses ending at punctuation and before a
+/// coordinating conjunction
+pub(crate) fn words(text: &str) -> Vec<Word> {
+    let mut words: Vec<Word> = word_regex()
+        .find_iter(text)
+        .map(|m| Word {
+            start: m.start(),
+            end: m.end(),
+            lower: fast_normalize(m.as_str(), true, true),
+            last: false,
+        })
+        .collect();
+    for i in 0..words.len() {
+        words[i].last = match words.get(i + 1) {
+            None => true,
+            Some(next) => {
+                let between = &text[words[i].end..next.start];
+                CONJUNCTIONS.contains(&next.lower.as_str())
+                    || between.contains(['.', '!', '?', '…', ',', ';', ':'])
+            }
+        };
+    }
+    words
+}
+
+/// Possessives a possessor requires, None for words that are no possessor
+fn possessor(word: &str) -> Option<&'static [&'static str]> {
+    if let Some(&(_, tags)) = GENITIVE_PRONOUNS.iter().find(|&&(w, _)| w == word) {
+        return Some(tags);
+    }
+    if let Some((_, suffix)) = word.split_once(['\'', '’']) {
+        let genitive = suffix.strip_prefix('n').unwrap_or(suffix);
+        return matches!(genitive, "ın" | "in" | "un" | "ün").then_some(THIRD_PERSON);
+    }
+    let analyses = analyzer::analyze(word);
+    let genitive = !analyses.is_empty()
+        && analyses.iter().all(|a| a.morphemes.last().is_some_and(|m| m.tag == "Gen"));
+    genitive.then_some(THIRD_PERSON)
+}
+
+fn possessive(analysis: &Analysis) -> Option<&'static str> {
+    analysis.morphemes.iter().map(|m| m.tag).find(|tag| POSSESSIVES.contains(tag))
+}
+
+/// Whether an analysis is a finite verb: a verb with a tense or mood suffix
+/// and no derivation to another part of speech
+pub(crate) fn is_finite(analysis: &Analysis) -> bool {
+    let tags: Vec<&str> = analysis.morphemes.iter().map(|m| m.tag).collect();
+    analysis.pos == Pos::Verb
+        && analysis.final_pos() == Pos::Verb
+        && tags.iter().any(|tag| TENSES.contains(tag))
+        && !tags.contains(&"While")
+}
+
+/// Person ending of a finite verb analysis, None for other analyses
+pub(crate) fn person(analysis: &Analysis) -> Option<&'static str> {
+    is_finite(analysis).then(|| {
+        let mut tags = analysis.morphemes.iter().map(|m| m.tag);
+        tags.find(|tag| PERSONS.contains(tag)).unwrap_or("A3sg")
+    })
+}
+
+/// First tag of the analyses that is not in `expected`, when no tag is;
+/// None when the analyses agree or carry no tag to judge
+fn disagreement(
+    analyses: &[Analysis],
+    tag: fn(&Analysis) -> Option<&'static str>,
+    expected: &[&str],
+) -> Option<&'static str> {
+    let tags: Vec<&'static str> = analyses.iter().filter_map(tag).collect();
+    match tags.first() {
+        Some(&found) if !tags.iter().any(|t| expected.contains(t)) => Some(found),
+        _ => None,
+    }
+}
+
+fn issue(
+    text: &str,
+    kind: Kind,
+    from: &Word,
+    to: &Word,
+    expected: &'static str,
+    found: &'static str,
+) -> Issue {
+    let start = text[..from.start].chars().count();
+    Issue {
+        text: text[from.start..to.end].to_string(),
+        kind,
+        expected,
+        found,
+        start,
+        end: start + text[from.start..to.end].chars().count(),
+    }
+}
+
+fn check_possessors(text: &str, words: &[Word], issues: &mut Vec<Issue>) {
+    for (i, word) in words.iter().enumerate() {
+        if word.last {
+            continue;
+        }
+        let Some(expected) = possessor(&word.lower) else {
+            continue;
+        };
+        // Head noun after at most three modifiers in the same clause
+        let mut j = i + 1;
+        while j < words.len()
+            && j - i <= 3
+            && !words[j - 1].last
+            && matches!(pos::tag_token(&words[j].lower), Tag::Adj | Tag::Num | Tag::Adv)
+        {
+            j += 1;
+        }
+        if j >= words.len() || words[j - 1].last {
+            continue;
+        }
+        let analyses: Vec<Analysis> = analyzer::analyze(&words[j].lower)
+            .into_iter()
+            .filter(|a| a.pos == Pos::Noun)
+            .collect();
+        if let Some(found) = disagreement(&analyses, possessive, expected) {
+            issues.push(issue(text, Kind::Possessor, word, &words[j], expected[0], found));
+        }
+    }
+}
+
+fn check_persons(text: &str, words: &[Word], issues: &mut Vec<Issue>) {
+    let mut subject: Option<(usize, &'static [&'static str])> = None;
+    for (i, word) in words.iter().enumerate() {
+        if word.last {
+            if let Some((s, expected)) = subject.filter(|&(s, _)| s < i) {
+                let analyses = analyzer::analyze(&word.lower);
+                if let Some(found) = disagreement(&analyses, person, expected) {
+                    issues.push(issue(text, Kind::Person, &words[s], word, expected[0], found));
+                }
+            }
+            subject = None;
+            continue;
+        }
+        if let Some(&(_, expected)) = SUBJECT_PRONOUNS.iter().find(|&&(w, _)| w == word.lower) {
+            subject = Some((i, expected));
+        }
+    }
+}
+
+/// Agreement errors of `text` in order of appearance
+///
+/// # Examples
+/// ```text
+/// check("Benim arabası bozuldu. Ben dün geldin.")
+///     -> [("Benim arabası", Possessor, "P1sg", "P3sg", 0, 13),
+///         ("Ben dün geldin", Person, "A1sg", "A2sg", 23, 37)]
+/// ```
+pub fn check(text: &str) -> Vec<Issue> {
+    let words = words(text);
+    let mut issues = Vec::new();
+    check_possessors(text, &words, &mut issues);
+    check_persons(text, &words, &mut issues);
+    issues.sort_by_key(|issue| issue.start);
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found(text: &str) -> Vec<(String, &'static str, &'static str, &'static str)> {
+        check(text)
+            .into_iter()
+            .map(|i| (i.text, i.kind.as_str(), i.expected, i.found))
+            .collect()
+    }
+
+    #[test]
+    fn test_possessor_agreement() {
+        assert_eq!(
+            found("benim arabası bozuldu"),
+            vec![("benim arabası".to_string(), "POSSESSOR", "P1sg", "P3sg")]
+        );
+        assert_eq!(found("Senin güzel kitabım")[0].3, "P1sg");
+        assert_eq!(found("Ali'nin arabam")[0].2, "P3sg");
+        for correct in [
+            "benim arabam bozuldu",
+            "senin çok güzel kitabın",
+            "onların evleri",
+            "Ali'nin arabası",
+            "bizim ev",
+            "benim, arabası",
+        ] {
+            assert!(found(correct).is_empty(), "{}", correct);
+        }
+    }
+
+    #[test]
+    fn test_person_agreement() {
+        assert_eq!(
+            found("Ben dün geldin."),
+            vec![("Ben dün geldin".to_string(), "PERSON", "A1sg", "A2sg")]
+        );
+        assert_eq!(found("biz okula gitti")[0].3, "A3sg");
+        for correct in [
+            "ben dün geldim",
+            "sen gelince ben gittim",
+            "biz gittik",
+            "onlar geldi",
+            "ben de",
+            "ben evdeyim ve sen geldin",
+        ] {
+            assert!(found(correct).is_empty(), "{}", correct);
+        }
+    }
+
+    #[test]
+    fn test_char_offsets() {
+        let issues = check("Çok iyi. Benim arabası");
+        assert_eq!((issues[0].start, issues[0].end), (9, 22));
+    }
+}
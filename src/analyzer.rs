@@ -0,0 +1,1207 @@
+//! Turkish Morphological Analyzer
+//!
+//! Finds every segmentation of a word into a dictionary root followed by a
+//! morphotactically valid chain of suffixes, each labelled with an
+//! Oflazer-style tag (`kitaplarımdan` → `kitap+Noun+Pl+P1sg+Abl`).
+//!
+//! Suffixes are written as templates with archiphonemes:
+//!
+//! - `A` → a/e (two-way vowel harmony)
+//! - `I` → ı/i/u/ü (four-way vowel harmony)
+//! - `D` → d/t, `C` → c/ç (voicing assimilation after voiceless consonants)
+//! - `(y)`, `(s)`, `(n)` → buffer consonants, only used after a vowel
+//! - `(I)`, `(A)` → buffer vowels, only used after a consonant
+//!
+//! Analysis works by generation: starting from every root candidate, suffixes
+//! allowed by the morphotactic state machine are attached (applying harmony,
+//! buffer letters and consonant softening) and a path is kept only while its
+//! surface form is still a prefix of the word.
+//!
+//! `analyze_all` scores the competing analyses of an ambiguous word with
+//! suffix tag frequencies embedded from
+//! `resources/tr/config/suffix_frequencies.txt`.
+
+use crate::etymology;
+use crate::fast_normalize;
+use crate::is_blob;
+use crate::lemma_dict;
+use crate::vowel_harmony::{get_last_vowel_class, get_vowel_class, VowelClass};
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+/// Embedded root part-of-speech table (lemma<TAB>POS[,POS])
+static ROOT_POS_DATA: &str = include_str!("../resources/tr/lemmas/root_pos.txt");
+static ROOT_POS: OnceLock<HashMap<&'static str, Vec<Pos>>> = OnceLock::new();
+
+/// Embedded suffix tag frequencies (tag<TAB>count<TAB>final share)
+static SUFFIX_FREQUENCY_DATA: &str = include_str!("../resources/tr/config/suffix_frequencies.txt");
+static SUFFIX_FREQUENCIES: OnceLock<HashMap<&'static str, SuffixFrequency>> = OnceLock::new();
+
+/// Upper bound on suffixes per word, guarding against runaway derivation chains
+const MAX_MORPHEMES: usize = 10;
+
+/// Monosyllabic verbs taking the -Ir aorist instead of -Ar (gel-ir, al-ır)
+const IR_AORIST_VERBS: &[&str] = &[
+    "al", "bil", "bul", "dur", "gel", "gör", "kal", "ol", "öl", "san", "var", "ver", "vur",
+];
+
+/// Verbs whose final t softens before a vowel (git → gid-iyor)
+const SOFTENING_T_VERBS: &[&str] = &["dit", "et", "git", "güt", "tat"];
+
+/// Pronouns with irregular inflected forms: (form, root, tag)
+const IRREGULAR_PRONOUNS: &[(&str, &str, &str)] = &[
+    ("bana", "ben", "Dat"),
+    ("sana", "sen", "Dat"),
+    ("benim", "ben", "Gen"),
+    ("bizim", "biz", "Gen"),
+];
+
+/// Part of speech of a root or derived stem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pos {
+    Noun,
+    Adj,
+    Verb,
+    Pron,
+}
+
+impl Pos {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Pos::Noun => "Noun",
+            Pos::Adj => "Adj",
+            Pos::Verb => "Verb",
+            Pos::Pron => "Pron",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Pos> {
+        match s {
+            "Noun" => Some(Pos::Noun),
+            "Adj" => Some(Pos::Adj),
+            "Verb" => Some(Pos::Verb),
+            "Pron" => Some(Pos::Pron),
+            _ => None,
+        }
+    }
+}
+
+/// Morphotactic states
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Bare nominal stem: may take plural, possessive, case or derivation
+    Nominal,
+    /// After plural
+    Plural,
+    /// After 1st/2nd person possessive
+    Possessive,
+    /// After 3rd person possessive or a demonstrative pronoun (pronominal n)
+    Possessive3,
+    /// After a case that licenses the relativizer -ki (Loc, Gen)
+    CaseKi,
+    /// After any other case
+    Case,
+    /// Bare verb stem
+    Verb,
+    /// After voice (passive/causative)
+    Voice,
+    /// After ability
+    Ability,
+    /// After negation
+    Negation,
+    /// After past/conditional: k-paradigm person endings
+    TenseK,
+    /// After other tenses: z-paradigm person endings
+    TenseZ,
+    /// Nothing else may follow
+    Final,
+}
+
+/// A suffix: tag, surface template, target state and optional POS change
+struct Suffix {
+    tag: &'static str,
+    template: &'static str,
+    next: State,
+    pos: Option<Pos>,
+    /// Whether the suffix-final stop softens before a vowel (-DIk → -DIğ-)
+    softens: bool,
+}
+
+const fn sfx(tag: &'static str, template: &'static str, next: State) -> Suffix {
+    Suffix {
+        tag,
+        template,
+        next,
+        pos: None,
+        softens: false,
+    }
+}
+
+const fn soft(tag: &'static str, template: &'static str, next: State) -> Suffix {
+    Suffix {
+        tag,
+        template,
+        next,
+        pos: None,
+        softens: true,
+    }
+}
+
+const fn derive(
+    tag: &'static str,
+    template: &'static str,
+    next: State,
+    pos: Pos,
+    softens: bool,
+) -> Suffix {
+    Suffix {
+        tag,
+        template,
+        next,
+        pos: Some(pos),
+        softens,
+    }
+}
+
+const PLURAL: Suffix = sfx("Pl", "lAr", State::Plural);
+
+const POSSESSIVES: &[Suffix] = &[
+    sfx("P1sg", "(I)m", State::Possessive),
+    sfx("P2sg", "(I)n", State::Possessive),
+    sfx("P3sg", "(s)I", State::Possessive3),
+    sfx("P1pl", "(I)mIz", State::Possessive),
+    sfx("P2pl", "(I)nIz", State::Possessive),
+    sfx("P3pl", "lArI", State::Possessive3),
+];
+
+const CASES: &[Suffix] = &[
+    sfx("Acc", "(y)I", State::Case),
+    sfx("Dat", "(y)A", State::Case),
+    sfx("Loc", "DA", State::CaseKi),
+    sfx("Abl", "DAn", State::Case),
+    sfx("Gen", "(n)In", State::CaseKi),
+    sfx("Ins", "(y)lA", State::Case),
+];
+
+/// Case endings after a 3rd person possessive take a pronominal n
+const PRONOMINAL_CASES: &[Suffix] = &[
+    sfx("Acc", "nI", State::Case),
+    sfx("Dat", "nA", State::Case),
+    sfx("Loc", "nDA", State::CaseKi),
+    sfx("Abl", "nDAn", State::Case),
+    sfx("Gen", "nIn", State::CaseKi),
+    sfx("Ins", "(y)lA", State::Case),
+];
+
+const NOMINAL_DERIVATIONS: &[Suffix] = &[
+    derive("With", "lI", State::Nominal, Pos::Adj, false),
+    derive("Without", "sIz", State::Nominal, Pos::Adj, false),
+    derive("Ness", "lIk", State::Nominal, Pos::Noun, true),
+    derive("Agt", "CI", State::Nominal, Pos::Noun, false),
+];
+
+const COPULA: Suffix = sfx("Cop", "DIr", State::Final);
+const RELATIVIZER: Suffix = derive("Rel", "ki", State::Nominal, Pos::Adj, false);
+
+const VOICES: &[Suffix] = &[
+    sfx("Pass", "Il", State::Voice),
+    sfx("Caus", "DIr", State::Voice),
+];
+const ABILITY: Suffix = sfx("Able", "(y)Abil", State::Ability);
+const NEGATION: Suffix = sfx("Neg", "mA", State::Negation);
+
+const TENSES: &[Suffix] = &[
+    sfx("Past", "DI", State::TenseK),
+    sfx("Cond", "sA", State::TenseK),
+    sfx("Narr", "mIş", State::TenseZ),
+    soft("Fut", "(y)AcAk", State::TenseZ),
+    sfx("Prog1", "(I)yor", State::TenseZ),
+    sfx("Prog2", "mAktA", State::TenseZ),
+    sfx("Neces", "mAlI", State::TenseZ),
+];
+
+/// Positive aorist; the surface vowel depends on the stem (see `realize`)
+const AORIST: Suffix = sfx("Aor", "Ir", State::TenseZ);
+/// Negative aorist: gel-me-z
+const NEG_AORIST: Suffix = sfx("Aor", "z", State::TenseZ);
+
+const NONFINITES: &[Suffix] = &[
+    derive("Inf1", "mAk", State::Possessive, Pos::Noun, false),
+    derive("Inf2", "mA", State::Nominal, Pos::Noun, false),
+    derive("PastPart", "DIk", State::Nominal, Pos::Noun, true),
+    derive("PresPart", "(y)An", State::Nominal, Pos::Adj, false),
+    sfx("AfterDoingSo", "(y)Ip", State::Final),
+    sfx("ByDoingSo", "(y)ArAk", State::Final),
+    sfx("When", "(y)IncA", State::Final),
+];
+
+const PERSONS_K: &[Suffix] = &[
+    sfx("A1sg", "m", State::Final),
+    sfx("A2sg", "n", State::Final),
+    sfx("A1pl", "k", State::Final),
+    sfx("A2pl", "nIz", State::Final),
+    sfx("A3pl", "lAr", State::Final),
+];
+
+const PERSONS_Z: &[Suffix] = &[
+    sfx("A1sg", "(y)Im", State::Final),
+    sfx("A2sg", "sIn", State::Final),
+    sfx("A1pl", "(y)Iz", State::Final),
+    sfx("A2pl", "sInIz", State::Final),
+    sfx("A3pl", "lAr", State::Final),
+];
+
+/// Tense suffixes stacked on a z-paradigm tense (gel-iyor-du, gel-miş-se)
+const COPULAR_TENSES: &[Suffix] = &[
+    sfx("Past", "(y)DI", State::TenseK),
+    sfx("Cond", "(y)sA", State::TenseK),
+    sfx("While", "(y)ken", State::Final),
+];
+
+/// Suffixes allowed after each state
+fn transitions(state: State) -> Vec<&'static Suffix> {
+    let mut next: Vec<&'static Suffix> = Vec::new();
+    match state {
+        State::Nominal => {
+            next.push(&PLURAL);
+            next.extend(POSSESSIVES);
+            next.extend(CASES);
+            next.extend(NOMINAL_DERIVATIONS);
+            next.push(&COPULA);
+        }
+        State::Plural => {
+            next.extend(POSSESSIVES);
+            next.extend(CASES);
+            next.push(&COPULA);
+        }
+        State::Possessive => {
+            next.extend(CASES);
+            next.push(&COPULA);
+        }
+        State::Possessive3 => {
+            next.extend(PRONOMINAL_CASES);
+            next.push(&COPULA);
+        }
+        State::CaseKi => {
+            next.push(&RELATIVIZER);
+            next.push(&COPULA);
+        }
+        State::Case => next.push(&COPULA),
+        State::Verb | State::Voice | State::Ability | State::Negation => {
+            if state == State::Verb {
+                next.extend(VOICES);
+            } else if state == State::Voice {
+                // Causative before passive: yap-tır-ıl
+                next.push(&VOICES[0]);
+            }
+            if state != State::Negation {
+                next.push(&ABILITY);
+                next.push(&NEGATION);
+                next.push(&AORIST);
+            } else {
+                next.push(&NEG_AORIST);
+            }
+            next.extend(TENSES);
+            next.extend(NONFINITES);
+        }
+        State::TenseK => next.extend(PERSONS_K),
+        State::TenseZ => {
+            next.extend(PERSONS_Z);
+            next.extend(COPULAR_TENSES);
+            next.push(&COPULA);
+        }
+        State::Final => {}
+    }
+    next
+}
+
+fn root_pos_table() -> &'static HashMap<&'static str, Vec<Pos>> {
+    ROOT_POS.get_or_init(|| {
+        let mut table = HashMap::new();
+        for line in ROOT_POS_DATA.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some((lemma, tags)) = line.split_once('\t') {
+                let tags: Vec<Pos> = tags
+                    .split(',')
+                    .filter_map(|t| Pos::parse(t.trim()))
+                    .collect();
+                table.insert(lemma.trim(), tags);
+            }
+        }
+        table
+    })
+}
+
+/// Share of bare roots among the words a root starts
+const ROOT_FINAL: f64 = 0.5;
+
+/// Occurrence count and word-final share of a suffix tag
+#[derive(Debug, Clone, Copy)]
+struct SuffixFrequency {
+    count: f64,
+    final_share: f64,
+}
+
+/// Frequency of unlisted tags
+const UNLISTED: SuffixFrequency = SuffixFrequency { count: 1.0, final_share: 0.5 };
+
+fn suffix_frequencies() -> &'static HashMap<&'static str, SuffixFrequency> {
+    SUFFIX_FREQUENCIES.get_or_init(|| {
+        let mut table = HashMap::new();
+        for line in SUFFIX_FREQUENCY_DATA.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split('\t');
+            let (Some(tag), Some(count), Some(final_share)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                continue;
+            };
+            if let (Ok(count), Ok(final_share)) = (count.parse(), final_share.parse()) {
+                table.insert(tag, SuffixFrequency { count, final_share });
+            }
+        }
+        table
+    })
+}
+
+fn suffix_frequency(tag: &str) -> SuffixFrequency {
+    suffix_frequencies().get(tag).copied().unwrap_or(UNLISTED)
+}
+
+/// Probability of an analysis under the suffix frequencies
+///
+/// The morphotactic state machine is walked as a probabilistic automaton:
+/// after the root or a suffix the word ends with that suffix's final share,
+/// and otherwise continues with one of the suffixes the state allows, in
+/// proportion to their counts.
+fn analysis_probability(analysis: &Analysis) -> f64 {
+    let mut state = initial_state(&analysis.root, analysis.pos);
+    let mut probability = 1.0;
+    let mut final_share = ROOT_FINAL;
+    for morpheme in &analysis.morphemes {
+        let allowed = transitions(state);
+        let total: f64 = allowed.iter().map(|s| suffix_frequency(s.tag).count).sum();
+        let frequency = suffix_frequency(morpheme.tag);
+        probability *= (1.0 - final_share) * frequency.count / total;
+        final_share = frequency.final_share;
+        // Irregular pronoun forms carry a tag without a transition
+        match allowed.iter().find(|s| s.tag == morpheme.tag) {
+            Some(suffix) => state = suffix.next,
+            None => break,
+        }
+    }
+    probability * final_share
+}
+
+/// Morphotactic state of a bare root
+fn initial_state(root: &str, pos: Pos) -> State {
+    match pos {
+        Pos::Verb => State::Verb,
+        Pos::Pron if matches!(root, "o" | "bu" | "şu") => State::Possessive3,
+        _ => State::Nominal,
+    }
+}
+
+/// Parts of speech of a known root (nouns by default)
+fn root_pos(root: &str) -> Option<Vec<Pos>> {
+    if let Some(tags) = root_pos_table().get(root) {
+        return Some(tags.clone());
+    }
+    lemma_dict::is_lemma(root).then(|| vec![Pos::Noun])
+}
+
+fn is_vowel(c: char) -> bool {
+    get_vowel_class(c).is_some()
+}
+
+fn is_voiceless(c: char) -> bool {
+    matches!(c, 'ç' | 'f' | 'h' | 'k' | 'p' | 's' | 'ş' | 't')
+}
+
+fn syllable_count(word: &str) -> usize {
+    word.chars().filter(|&c| is_vowel(c)).count()
+}
+
+/// Two-way harmony vowel (a/e) after the last vowel of `stem`
+fn harmonize_a(stem: &str) -> char {
+    match get_last_vowel_class(stem) {
+        Some(class) if class.is_front() => 'e',
+        Some(_) => 'a',
+        None => 'e',
+    }
+}
+
+/// Four-way harmony vowel (ı/i/u/ü) after the last vowel of `stem`
+fn harmonize_i(stem: &str) -> char {
+    match get_last_vowel_class(stem) {
+        Some(VowelClass::FrontUnrounded) | None => 'i',
+        Some(VowelClass::FrontRounded) => 'ü',
+        Some(VowelClass::BackUnrounded) => 'ı',
+        Some(VowelClass::BackRounded) => 'u',
+    }
+}
+
+/// Front vowel with the same rounding, for roots that harmonize as front
+/// roots (saat-ler, gol-ü)
+fn front_vowel(vowel: char) -> char {
+    match vowel {
+        'a' => 'e',
+        'ı' => 'i',
+        'o' => 'ö',
+        'u' => 'ü',
+        other => other,
+    }
+}
+
+/// Softened form of a stem-final stop (p→b, ç→c, t→d, k→ğ, nk→ng)
+fn soften(stem: &str) -> Option<String> {
+    let mut chars: Vec<char> = stem.chars().collect();
+    let last = chars.pop()?;
+    let softened = match last {
+        'p' => 'b',
+        'ç' => 'c',
+        't' => 'd',
+        'k' if chars.last() == Some(&'n') => 'g',
+        'k' => 'ğ',
+        _ => return None,
+    };
+    chars.push(softened);
+    Some(chars.into_iter().collect())
+}
+
+/// Whether a root's final stop softens before a vowel-initial suffix
+///
+/// Polysyllabic nominal roots in p/ç/k soften (kitap → kitabı); monosyllabic
+/// ones and t-final nouns usually do not (top → topu, millet → milleti).
+/// Verbs only soften in a handful of t-final roots (git → gidiyor).
+fn root_softens(root: &str, pos: Pos) -> bool {
+    match pos {
+        Pos::Verb => SOFTENING_T_VERBS.contains(&root),
+        _ => syllable_count(root) > 1 && matches!(root.chars().last(), Some('p' | 'ç' | 'k')),
+    }
+}
+
+/// Surface form of `suffix` attached to `stem`
+///
+/// Returns the new word and the byte offset where the suffix starts (the
+/// stem itself may change through softening or vowel drop).
+fn realize(stem: &str, stem_softens: bool, suffix: &Suffix, from: State) -> (String, usize) {
+    let ends_in_vowel = stem.chars().last().is_some_and(is_vowel);
+
+    // Suffixes whose shape depends on the stem beyond plain harmony
+    let template: &str = match suffix.tag {
+        "Pass" if ends_in_vowel => "n",
+        "Pass" if stem.ends_with('l') => "In",
+        "Caus" if ends_in_vowel => "t",
+        "Aor" if from != State::Negation => {
+            if ends_in_vowel {
+                "r"
+            } else if syllable_count(stem) == 1 && !IR_AORIST_VERBS.contains(&stem) {
+                "Ar"
+            } else {
+                "Ir"
+            }
+        }
+        _ => suffix.template,
+    };
+
+    let mut base = stem.to_string();
+
+    // The progressive replaces a stem-final vowel: başla → başl-ıyor
+    if suffix.tag == "Prog1" && ends_in_vowel {
+        let dropped = base.pop().unwrap_or('e');
+        let vowel = if syllable_count(&base) > 0 {
+            harmonize_i(&base)
+        } else {
+            harmonize_i(&dropped.to_string())
+        };
+        let start = base.len();
+        base.push(vowel);
+        base.push_str("yor");
+        return (base, start);
+    }
+
+    // Expand the template against the growing word. The first vowel after
+    // a loanword root such as "saat" takes front harmony.
+    let front_stem = etymology::takes_front_harmony(&base);
+    let mut surface = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        let current = format!("{}{}", base, surface);
+        let last = current.chars().last();
+        let after_vowel = last.is_some_and(is_vowel);
+        let front = front_stem && !surface.chars().any(is_vowel);
+        let harmonize = |vowel: char| if front { front_vowel(vowel) } else { vowel };
+
+        if c == '(' {
+            let optional = chars.next().unwrap_or(')');
+            chars.next(); // closing parenthesis
+            let is_buffer_vowel = matches!(optional, 'A' | 'I');
+            if is_buffer_vowel && !after_vowel && last.is_some() {
+                surface.push(harmonize(if optional == 'A' {
+                    harmonize_a(&current)
+                } else {
+                    harmonize_i(&current)
+                }));
+            } else if !is_buffer_vowel && after_vowel {
+                surface.push(optional);
+            }
+            continue;
+        }
+
+        surface.push(match c {
+            'A' => harmonize(harmonize_a(&current)),
+            'I' => harmonize(harmonize_i(&current)),
+            'D' if last.is_some_and(is_voiceless) => 't',
+            'D' => 'd',
+            'C' if last.is_some_and(is_voiceless) => 'ç',
+            'C' => 'c',
+            other => other,
+        });
+    }
+
+    // Consonant softening before a vowel-initial suffix
+    if stem_softens && surface.chars().next().is_some_and(is_vowel) {
+        if let Some(softened) = soften(&base) {
+            base = softened;
+        }
+    }
+
+    let start = base.len();
+    base.push_str(&surface);
+    (base, start)
+}
+
+/// A single suffix in an analysis
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Morpheme {
+    pub tag: &'static str,
+    pub surface: String,
+    /// Part of speech after a derivational suffix
+    pub pos: Option<Pos>,
+}
+
+/// One morphological analysis of a word
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub root: String,
+    pub pos: Pos,
+    pub morphemes: Vec<Morpheme>,
+}
+
+impl Analysis {
+    /// Part of speech of the whole word after all derivations
+    pub fn final_pos(&self) -> Pos {
+        self.morphemes
+            .iter()
+            .rev()
+            .find_map(|m| m.pos)
+            .unwrap_or(self.pos)
+    }
+}
+
+/// Partial path explored during analysis
+struct Path<'a> {
+    word: &'a str,
+    root: &'a str,
+    pos: Pos,
+    /// (suffix, start offset) for each attached suffix
+    suffixes: Vec<(&'static Suffix, usize)>,
+}
+
+fn walk(path: &mut Path, surface: &str, state: State, softens: bool, out: &mut Vec<Analysis>) {
+    if surface == path.word {
+        out.push(build(path));
+    }
+    if path.suffixes.len() >= MAX_MORPHEMES || surface.len() >= path.word.len() {
+        return;
+    }
+
+    for suffix in transitions(state) {
+        let (next, start) = realize(surface, softens, suffix, state);
+        // A softening suffix may still change its final stop (gidecek → gideceğ-im),
+        // and a final vowel may still drop before -Iyor (gelme → gelm-iyor)
+        let viable = path.word.starts_with(&next)
+            || (suffix.softens && soften(&next).is_some_and(|s| path.word.starts_with(&s)))
+            || next
+                .strip_suffix(|c: char| is_vowel(c))
+                .is_some_and(|stem| path.word.starts_with(stem));
+        if !viable {
+            continue;
+        }
+        path.suffixes.push((suffix, start));
+        walk(path, &next, suffix.next, suffix.softens, out);
+        path.suffixes.pop();
+    }
+}
+
+fn build(path: &Path) -> Analysis {
+    let mut ends: Vec<usize> = path
+        .suffixes
+        .iter()
+        .skip(1)
+        .map(|&(_, start)| start)
+        .collect();
+    ends.push(path.word.len());
+
+    let morphemes = path
+        .suffixes
+        .iter()
+        .zip(ends)
+        .map(|(&(suffix, start), end)| Morpheme {
+            tag: suffix.tag,
+            surface: path.word[start..end].to_string(),
+            pos: suffix.pos,
+        })
+        .collect();
+
+    Analysis {
+        root: path.root.to_string(),
+        pos: path.pos,
+        morphemes,
+    }
+}
+
+/// Candidate roots that may start `word`: prefixes, un-softened prefixes
+/// (kitab- → kitap) and prefixes missing a dropped final vowel (başl- → başla)
+fn root_candidates(word: &str) -> Vec<String> {
+    let mut candidates = Vec::new();
+    let bounds = word
+        .char_indices()
+        .map(|(i, _)| i)
+        .skip(1)
+        .chain(std::iter::once(word.len()));
+
+    for end in bounds {
+        let prefix = &word[..end];
+        candidates.push(prefix.to_string());
+
+        let mut chars: Vec<char> = prefix.chars().collect();
+        if let Some(last) = chars.pop() {
+            let hard = match last {
+                'b' => Some('p'),
+                'c' => Some('ç'),
+                'd' => Some('t'),
+                'ğ' | 'g' => Some('k'),
+                _ => None,
+            };
+            if let Some(hard) = hard {
+                chars.push(hard);
+                candidates.push(chars.into_iter().collect());
+            }
+        }
+
+        if end < word.len() {
+            for vowel in ['a', 'e', 'ı', 'i', 'o', 'ö', 'u', 'ü'] {
+                candidates.push(format!("{}{}", prefix, vowel));
+            }
+        }
+    }
+    candidates
+}
+
+/// All morphological analyses of a word
+///
+/// Analyses are ordered by preference: longer roots first, then fewer
+/// suffixes. An empty result means the word could not be analyzed.
+///
+/// # Examples
+/// ```text
+/// analyze("kitaplarımdan") -> [kitap+Noun+Pl+P1sg+Abl]
+/// analyze("geliyorum")     -> [gel+Verb+Prog1+A1sg]
+/// ```
+pub fn analyze(word: &str) -> Vec<Analysis> {
+    if is_blob(word) {
+        return Vec::new();
+    }
+    let word = fast_normalize(word, true, true);
+    let mut analyses = Vec::new();
+
+    for &(form, root, tag) in IRREGULAR_PRONOUNS {
+        if form == word {
+            analyses.push(Analysis {
+                root: root.to_string(),
+                pos: Pos::Pron,
+                morphemes: vec![Morpheme {
+                    tag,
+                    surface: form.strip_prefix(root).unwrap_or(form).to_string(),
+                    pos: None,
+                }],
+            });
+        }
+    }
+
+    for root in root_candidates(&word) {
+        let Some(parts_of_speech) = root_pos(&root) else {
+            continue;
+        };
+        for pos in parts_of_speech {
+            let state = initial_state(&root, pos);
+            let mut path = Path {
+                word: &word,
+                root: &root,
+                pos,
+                suffixes: Vec::new(),
+            };
+            walk(
+                &mut path,
+                &root,
+                state,
+                root_softens(&root, pos),
+                &mut analyses,
+            );
+        }
+    }
+
+    analyses.sort_by_key(|a| (std::cmp::Reverse(a.root.chars().count()), a.morphemes.len()));
+    // Different root candidates can reach the same analysis
+    let mut unique: Vec<Analysis> = Vec::with_capacity(analyses.len());
+    for analysis in analyses {
+        if !unique.contains(&analysis) {
+            unique.push(analysis);
+        }
+    }
+    unique
+}
+
+/// All analyses of a word with their probabilities, most probable first
+///
+/// Each analysis is scored with the suffix frequencies (see
+/// `analysis_probability`) and the scores are normalized to sum to 1.
+/// Analyses with equal scores (the same tags on different readings of a
+/// root) keep the order of `analyze`.
+///
+/// # Examples
+/// ```text
+/// analyze_all("evin")   -> [(ev+Noun+Gen, 0.94), (ev+Noun+P2sg, 0.06)]
+/// analyze_all("geldik") -> [(gel+Verb+Past+A1pl, 0.84), (gel+Verb+PastPart, 0.16)]
+/// ```
+pub fn analyze_all(word: &str) -> Vec<(Analysis, f64)> {
+    let mut scored: Vec<(Analysis, f64)> = analyze(word)
+        .into_iter()
+        .map(|analysis| {
+            let score = analysis_probability(&analysis);
+            (analysis, score)
+        })
+        .collect();
+    let total: f64 = scored.iter().map(|(_, score)| score).sum();
+    for (_, score) in &mut scored {
+        *score /= total;
+    }
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+    scored
+}
+
+/// Every inflected surface form of every known root with at most
+/// `max_suffixes` suffixes. Derivational suffixes are not followed, which
+/// keeps the lexicon to inflections of dictionary words.
+pub(crate) fn surface_forms(max_suffixes: usize) -> Vec<String> {
+    fn expand(surface: &str, state: State, softens: bool, depth: usize, out: &mut HashSet<String>) {
+        if depth == 0 {
+            return;
+        }
+        for suffix in transitions(state) {
+            if suffix.pos.is_some() {
+                continue;
+            }
+            let (next, _) = realize(surface, softens, suffix, state);
+            expand(&next, suffix.next, suffix.softens, depth - 1, out);
+            out.insert(next);
+        }
+    }
+
+    let mut forms: HashSet<String> = IRREGULAR_PRONOUNS
+        .iter()
+        .map(|&(form, _, _)| form.to_string())
+        .collect();
+    for (&root, parts_of_speech) in root_pos_table() {
+        forms.insert(root.to_string());
+        for &pos in parts_of_speech {
+            let state = initial_state(root, pos);
+            expand(
+                root,
+                state,
+                root_softens(root, pos),
+                max_suffixes,
+                &mut forms,
+            );
+        }
+    }
+
+    let mut forms: Vec<String> = forms.into_iter().collect();
+    forms.sort();
+    forms
+}
+
+/// Why a tag sequence could not be generated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenerateError {
+    /// Not a tag of any suffix
+    UnknownTag(String),
+    /// A known tag that cannot follow the preceding ones
+    InvalidSequence { tag: String, position: usize },
+}
+
+impl std::fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GenerateError::UnknownTag(tag) => write!(f, "unknown suffix tag '{}'", tag),
+            GenerateError::InvalidSequence { tag, position } => write!(
+                f,
+                "suffix tag '{}' cannot appear at position {}",
+                tag, position
+            ),
+        }
+    }
+}
+
+/// Attach suffixes to a root as a single reading, or report the first tag
+/// that does not fit
+fn generate_as(root: &str, pos: Pos, tags: &[&str]) -> Result<String, GenerateError> {
+    if let [tag] = tags {
+        if let Some(&(form, _, _)) = IRREGULAR_PRONOUNS
+            .iter()
+            .find(|&&(_, r, t)| r == root && t == *tag && pos == Pos::Pron)
+        {
+            return Ok(form.to_string());
+        }
+    }
+
+    let mut surface = root.to_string();
+    let mut state = initial_state(root, pos);
+    let mut softens = root_softens(root, pos);
+    for (position, &tag) in tags.iter().enumerate() {
+        let Some(suffix) = transitions(state).into_iter().find(|s| s.tag == tag) else {
+            return Err(GenerateError::InvalidSequence {
+                tag: tag.to_string(),
+                position,
+            });
+        };
+        surface = realize(&surface, softens, suffix, state).0;
+        state = suffix.next;
+        softens = suffix.softens;
+    }
+    Ok(surface)
+}
+
+/// Generate the surface form of a root followed by suffix tags
+///
+/// The inverse of [`analyze`]: suffixes are attached with vowel harmony,
+/// buffer letters and consonant softening. Known roots are tried with each
+/// of their parts of speech; unknown roots use `pos`, defaulting to noun.
+/// `pos` also restricts homographs to one reading (yaz as noun or verb).
+///
+/// # Examples
+/// ```text
+/// generate("kitap", &["P1sg", "Loc"], None) -> kitabımda
+/// generate("git", &["Fut", "A1sg"], None)   -> gideceğim
+/// ```
+pub fn generate(root: &str, tags: &[&str], pos: Option<Pos>) -> Result<String, GenerateError> {
+    let root = fast_normalize(root, true, true);
+
+    let known: HashSet<&str> = [
+        State::Nominal,
+        State::Plural,
+        State::Possessive,
+        State::Possessive3,
+        State::CaseKi,
+        State::Case,
+        State::Verb,
+        State::Voice,
+        State::Ability,
+        State::Negation,
+        State::TenseK,
+        State::TenseZ,
+    ]
+    .into_iter()
+    .flat_map(transitions)
+    .map(|suffix| suffix.tag)
+    .chain(IRREGULAR_PRONOUNS.iter().map(|&(_, _, tag)| tag))
+    .collect();
+    if let Some(tag) = tags.iter().find(|tag| !known.contains(**tag)) {
+        return Err(GenerateError::UnknownTag(tag.to_string()));
+    }
+
+    let readings = match pos {
+        Some(pos) => vec![pos],
+        None => root_pos(&root).unwrap_or_else(|| vec![Pos::Noun]),
+    };
+
+    // Report the error of the reading that got furthest
+    let mut best_error: Option<GenerateError> = None;
+    for reading in readings {
+        match generate_as(&root, reading, tags) {
+            Ok(form) => return Ok(form),
+            Err(error) => {
+                let further = match (&best_error, &error) {
+                    (
+                        Some(GenerateError::InvalidSequence { position: best, .. }),
+                        GenerateError::InvalidSequence { position, .. },
+                    ) => position > best,
+                    (None, _) => true,
+                    _ => false,
+                };
+                if further {
+                    best_error = Some(error);
+                }
+            }
+        }
+    }
+    Err(best_error.expect("every root has at least one reading"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Render an analysis as root+POS+tags for compact assertions
+    fn render(analysis: &Analysis) -> String {
+        let mut out = format!("{}+{}", analysis.root, analysis.pos.as_str());
+        for m in &analysis.morphemes {
+            out.push('+');
+            out.push_str(m.tag);
+        }
+        out
+    }
+
+    fn parses(word: &str) -> Vec<String> {
+        analyze(word).iter().map(render).collect()
+    }
+
+    #[test]
+    fn test_root_pos_loaded() {
+        assert_eq!(root_pos("gel"), Some(vec![Pos::Verb]));
+        assert_eq!(root_pos("kitap"), Some(vec![Pos::Noun]));
+        assert_eq!(root_pos("yaz"), Some(vec![Pos::Noun, Pos::Verb]));
+        assert_eq!(root_pos("bilgisayar"), None);
+    }
+
+    #[test]
+    fn test_harmony_and_buffers() {
+        let (word, _) = realize("kitap", false, &POSSESSIVES[2], State::Nominal);
+        assert_eq!(word, "kitapı");
+        let (word, _) = realize("masa", false, &POSSESSIVES[2], State::Nominal);
+        assert_eq!(word, "masası");
+        let (word, _) = realize("göz", false, &CASES[5], State::Nominal);
+        assert_eq!(word, "gözle");
+        let (word, _) = realize("kitap", false, &CASES[2], State::Nominal);
+        assert_eq!(word, "kitapta");
+    }
+
+    #[test]
+    fn test_loanword_front_harmony() {
+        assert!(parses("saatler").contains(&"saat+Noun+Pl".to_string()));
+        assert!(parses("saatlar").is_empty());
+        assert_eq!(generate("saat", &["Pl", "P3sg"], None).unwrap(), "saatleri");
+        // Only the root's own harmony is fronted: kitap stays back
+        assert_eq!(generate("kitap", &["Pl"], None).unwrap(), "kitaplar");
+    }
+
+    #[test]
+    fn test_softening() {
+        let (word, start) = realize("kitap", true, &CASES[0], State::Nominal);
+        assert_eq!(word, "kitabı");
+        assert_eq!(&word[start..], "ı");
+        let (word, _) = realize("git", true, &TENSES[4], State::Verb);
+        assert_eq!(word, "gidiyor");
+    }
+
+    #[test]
+    fn test_nominal_analysis() {
+        assert_eq!(parses("kitaplarımdan")[0], "kitap+Noun+Pl+P1sg+Abl");
+        assert!(parses("kitabı").contains(&"kitap+Noun+Acc".to_string()));
+        assert!(parses("evin").contains(&"ev+Noun+Gen".to_string()));
+        assert!(parses("evin").contains(&"ev+Noun+P2sg".to_string()));
+        assert!(parses("evindeki").contains(&"ev+Noun+P3sg+Loc+Rel".to_string()));
+        assert!(parses("çocuğu").contains(&"çocuk+Noun+P3sg".to_string()));
+    }
+
+    #[test]
+    fn test_verbal_analysis() {
+        assert_eq!(parses("geliyorum")[0], "gel+Verb+Prog1+A1sg");
+        assert_eq!(parses("başlıyor")[0], "başla+Verb+Prog1");
+        assert_eq!(parses("gelmedim")[0], "gel+Verb+Neg+Past+A1sg");
+        assert_eq!(parses("gelmiyorum")[0], "gel+Verb+Neg+Prog1+A1sg");
+        assert!(parses("gideceğim").contains(&"git+Verb+Fut+A1sg".to_string()));
+        assert!(parses("yapılır").contains(&"yap+Verb+Pass+Aor".to_string()));
+        assert!(parses("gelir").contains(&"gel+Verb+Aor".to_string()));
+        assert!(parses("gelmez").contains(&"gel+Verb+Neg+Aor".to_string()));
+        assert!(parses("geliyordu").contains(&"gel+Verb+Prog1+Past".to_string()));
+    }
+
+    #[test]
+    fn test_derivation_changes_pos() {
+        let analyses = analyze("geldiğim");
+        let part = analyses
+            .iter()
+            .find(|a| render(a) == "gel+Verb+PastPart+P1sg")
+            .expect("participle reading");
+        assert_eq!(part.final_pos(), Pos::Noun);
+        assert_eq!(part.morphemes[0].surface, "diğ");
+        assert_eq!(part.morphemes[1].surface, "im");
+
+        let good = analyze("güzellik");
+        assert_eq!(good[0].final_pos(), Pos::Noun);
+    }
+
+    #[test]
+    fn test_pronouns() {
+        assert!(parses("onu").contains(&"o+Pron+Acc".to_string()));
+        assert!(parses("bana").contains(&"ben+Pron+Dat".to_string()));
+        assert!(parses("benim").contains(&"ben+Pron+Gen".to_string()));
+    }
+
+    #[test]
+    fn test_surfaces_cover_word() {
+        for word in ["kitaplarımdan", "geliyorum", "okullarda", "çalışıyorlar"] {
+            for analysis in analyze(word) {
+                let mut rebuilt = word[..word.len()
+                    - analysis
+                        .morphemes
+                        .iter()
+                        .map(|m| m.surface.len())
+                        .sum::<usize>()]
+                    .to_string();
+                for m in &analysis.morphemes {
+                    rebuilt.push_str(&m.surface);
+                }
+                assert_eq!(rebuilt, word);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_words() {
+        assert!(analyze("xyzabc").is_empty());
+        assert!(analyze("").is_empty());
+    }
+
+    #[test]
+    fn test_surface_forms() {
+        let forms = surface_forms(3);
+        for form in [
+            "kitap",
+            "kitabı",
+            "kitaplarımdan",
+            "gelmiyorum",
+            "gideceğim",
+            "bana",
+        ] {
+            assert!(
+                forms.binary_search(&form.to_string()).is_ok(),
+                "missing {}",
+                form
+            );
+        }
+        // Derivations are not generated
+        assert!(forms.binary_search(&"evli".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate("kitap", &["P1sg", "Loc"], None).unwrap(),
+            "kitabımda"
+        );
+        assert_eq!(
+            generate("kitap", &["Pl", "P1sg", "Abl"], None).unwrap(),
+            "kitaplarımdan"
+        );
+        assert_eq!(generate("ağaç", &["Acc"], None).unwrap(), "ağacı");
+        assert_eq!(
+            generate("masa", &["P3sg", "Loc"], None).unwrap(),
+            "masasında"
+        );
+        assert_eq!(
+            generate("git", &["Fut", "A1sg"], None).unwrap(),
+            "gideceğim"
+        );
+        assert_eq!(
+            generate("gel", &["Neg", "Prog1", "A1sg"], None).unwrap(),
+            "gelmiyorum"
+        );
+        assert_eq!(generate("ben", &["Dat"], None).unwrap(), "bana");
+        assert_eq!(generate("ev", &[], None).unwrap(), "ev");
+    }
+
+    #[test]
+    fn test_generate_readings() {
+        // Homograph: summer (noun) or write (verb)
+        assert_eq!(generate("yaz", &["Past", "A1sg"], None).unwrap(), "yazdım");
+        assert_eq!(generate("yaz", &["Loc"], None).unwrap(), "yazda");
+        assert_eq!(
+            generate("yaz", &["Loc"], Some(Pos::Verb)),
+            Err(GenerateError::InvalidSequence {
+                tag: "Loc".to_string(),
+                position: 0
+            })
+        );
+        // Unknown roots default to nouns
+        assert_eq!(
+            generate("bilgisayar", &["Pl", "Dat"], None).unwrap(),
+            "bilgisayarlara"
+        );
+    }
+
+    #[test]
+    fn test_generate_errors() {
+        assert_eq!(
+            generate("ev", &["Xyz"], None),
+            Err(GenerateError::UnknownTag("Xyz".to_string()))
+        );
+        assert_eq!(
+            generate("ev", &["Loc", "Pl"], None),
+            Err(GenerateError::InvalidSequence {
+                tag: "Pl".to_string(),
+                position: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_generate_analyze_roundtrip() {
+        for word in [
+            "kitaplarımdan",
+            "evindeki",
+            "gideceğim",
+            "gelmedim",
+            "çocuğu",
+        ] {
+            for analysis in analyze(word) {
+                let tags: Vec<&str> = analysis.morphemes.iter().map(|m| m.tag).collect();
+                let form = generate(&analysis.root, &tags, Some(analysis.pos)).unwrap();
+                assert_eq!(form, word, "{:?}", analysis);
+            }
+        }
+    }
+
+    #[test]
+    fn test_analyze_all_probabilities() {
+        let scored = analyze_all("evin");
+        let tags: Vec<String> = scored.iter().map(|(a, _)| render(a)).collect();
+        assert_eq!(tags, vec!["ev+Noun+Gen", "ev+Noun+P2sg"]);
+        assert!(scored[0].1 > 0.8 && scored[0].1 < 1.0);
+        let total: f64 = scored.iter().map(|(_, p)| p).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+
+        // A longer chain wins when its suffixes usually end the word
+        assert_eq!(render(&analyze_all("geldik")[0].0), "gel+Verb+Past+A1pl");
+        assert_eq!(analyze_all("kitaplarımdan")[0].1, 1.0);
+        assert!(analyze_all("xyzq").is_empty());
+    }
+
+    #[test]
+    fn test_suffix_frequencies_cover_tags() {
+        let table = suffix_frequencies();
+        for tag in ["Pl", "P2sg", "Gen", "Past", "A1sg", "Inf2", "While"] {
+            assert!(table.contains_key(tag), "{}", tag);
+        }
+        assert!(table.values().all(|f| f.count > 0.0 && (0.0..=1.0).contains(&f.final_share)));
+    }
+}
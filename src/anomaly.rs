@@ -0,0 +1,136 @@
+//! Line anomaly detection
+//!
+//! Scores each line of a text by the character statistics that set spam,
+//! tables and markup apart from running text:
+//!
+//! - Punctuation density: punctuation and non-emoji symbols per non-space
+//!   character ("|---|---|", "!!!!!!")
+//! - ALL-CAPS ratio: uppercase letters per letter, for lines with at least
+//!   `MIN_CAPS_LETTERS` letters so acronyms ("TBMM'de") pass
+//! - Digit ratio: digits per non-space character (tables, phone lists)
+//! - Whitespace ratio: whitespace per character (space-aligned columns)
+//!
+//! Each statistic is divided by its limit for running text; the anomaly score
+//! of a line is the largest of these, so a score above 1.0 flags the line.
+
+use regex::Regex;
+use std::sync::OnceLock;
+
+static PUNCTUATION_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// Letters a line needs before its ALL-CAPS ratio counts
+const MIN_CAPS_LETTERS: usize = 8;
+
+/// Limits for running text: punctuation, uppercase, digit, whitespace ratio
+const LIMITS: (f64, f64, f64, f64) = (0.2, 0.6, 0.3, 0.35);
+
+/// Character statistics and anomaly score of one line
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineScores {
+    /// Punctuation and non-emoji symbols per non-space character
+    pub punctuation: f64,
+    /// Uppercase letters per letter, 0.0 for lines with few letters
+    pub uppercase: f64,
+    /// Digits per non-space character
+    pub digits: f64,
+    /// Whitespace per character
+    pub whitespace: f64,
+    /// Largest statistic relative to its limit; above 1.0 is anomalous
+    pub anomaly: f64,
+}
+
+fn punctuation_regex() -> &'static Regex {
+    PUNCTUATION_REGEX.get_or_init(|| {
+        Regex::new(r"[\p{P}\p{Sm}\p{Sc}\p{Sk}]").expect("Invalid punctuation regex")
+    })
+}
+
+fn ratio(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64
+    }
+}
+
+/// Score a single line
+///
+/// # Examples
+/// ```text
+/// score_line("Yarın toplantı var.").anomaly      -> 0.30
+/// score_line("SÜPER FIRSAT!!! HEMEN ARA").anomaly -> 1.67
+/// ```
+pub fn score_line(line: &str) -> LineScores {
+    let (mut chars, mut spaces, mut letters, mut uppercase, mut digits) = (0, 0, 0, 0, 0);
+    for c in line.chars() {
+        chars += 1;
+        if c.is_whitespace() {
+            spaces += 1;
+        } else if c.is_alphabetic() {
+            letters += 1;
+            if c.is_uppercase() {
+                uppercase += 1;
+            }
+        } else if c.is_numeric() {
+            digits += 1;
+        }
+    }
+    let visible = chars - spaces;
+    let punctuation = ratio(punctuation_regex().find_iter(line).count(), visible);
+    let uppercase = if letters < MIN_CAPS_LETTERS {
+        0.0
+    } else {
+        ratio(uppercase, letters)
+    };
+    let digits = ratio(digits, visible);
+    let whitespace = ratio(spaces, chars);
+    let (punctuation_limit, uppercase_limit, digit_limit, whitespace_limit) = LIMITS;
+    let anomaly = [
+        punctuation / punctuation_limit,
+        uppercase / uppercase_limit,
+        digits / digit_limit,
+        whitespace / whitespace_limit,
+    ]
+    .into_iter()
+    .fold(0.0, f64::max);
+    LineScores { punctuation, uppercase, digits, whitespace, anomaly }
+}
+
+/// Scores of each line of `text`, in order
+pub fn score_lines(text: &str) -> Vec<LineScores> {
+    text.lines().map(score_line).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_running_text() {
+        for line in [
+            "Yarın saat onda toplantı yapılacak, lütfen geç kalmayın.",
+            "TBMM'de bugün 3 kanun teklifi görüşüldü.",
+            "Harika bir gün 😂😂",
+            "",
+        ] {
+            assert!(score_line(line).anomaly <= 1.0, "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_anomalous_lines() {
+        let caps = score_line("SÜPER FIRSAT HEMEN ARAYIN");
+        assert_eq!(caps.uppercase, 1.0);
+        assert!(caps.anomaly > 1.0);
+        assert!(score_line("|---|---|---|").punctuation > 0.9);
+        assert!(score_line("0212 555 12 34 / 0532 111 22 33").anomaly > 1.0);
+        assert!(score_line("Ad        Soyad        Yaş").whitespace > 0.5);
+    }
+
+    #[test]
+    fn test_score_lines() {
+        let scores = score_lines("Merhaba dünya.\n!!!!!!\n");
+        assert_eq!(scores.len(), 2);
+        assert!(scores[0].anomaly < 1.0 && scores[1].anomaly > 1.0);
+    }
+}
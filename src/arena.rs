@@ -0,0 +1,94 @@
+//! Bump-style string arena for batch results
+//!
+//! A batch of documents tokenized into one `String` per token makes one
+//! heap allocation per token, then frees them all again once the tokens are
+//! copied into Python strings; for short documents the allocator dominates
+//! the batch. A `StrArena` instead appends every string of a run of
+//! documents to one growing buffer and records where each string and each
+//! document ends, the same layout as an Arrow string column. Growth
+//! amortizes to a handful of allocations per arena, and the strings are
+//! read back as slices of the buffer while converting to Python objects.
+//!
+//! Strings are only ever appended; an arena is dropped as a whole once its
+//! strings have been copied out.
+
+use std::ops::Range;
+
+/// Strings of a sequence of documents, missing documents included
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrArena {
+    data: String,
+    /// Byte offset in `data` where each string ends, after a leading 0
+    bounds: Vec<usize>,
+    /// Per document: indices of its strings, or None for a missing document
+    documents: Vec<Option<Range<usize>>>,
+    /// Index of the first string of the current document
+    open: usize,
+}
+
+impl StrArena {
+    pub fn new() -> Self {
+        Self {
+            data: String::new(),
+            bounds: vec![0],
+            documents: Vec::new(),
+            open: 0,
+        }
+    }
+
+    /// Append a string to the current document
+    pub fn push(&mut self, text: &str) {
+        self.data.push_str(text);
+        self.bounds.push(self.data.len());
+    }
+
+    /// End the current document with the strings pushed since the previous
+    /// one
+    pub fn finish_document(&mut self) {
+        let end = self.bounds.len() - 1;
+        self.documents.push(Some(self.open..end));
+        self.open = end;
+    }
+
+    /// Record a missing document; strings pushed since the previous
+    /// document are dropped from view
+    pub fn push_missing(&mut self) {
+        self.documents.push(None);
+        self.open = self.bounds.len() - 1;
+    }
+
+    /// Number of documents, missing ones included
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    /// Strings of document `index`, None when it is missing
+    pub fn document(&self, index: usize) -> Option<impl ExactSizeIterator<Item = &str> + '_> {
+        let strings = self.documents[index].clone()?;
+        Some(strings.map(move |i| &self.data[self.bounds[i]..self.bounds[i + 1]]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_documents() {
+        let mut arena = StrArena::new();
+        arena.push("kitap");
+        arena.push("okudum");
+        arena.finish_document();
+        arena.push_missing();
+        arena.finish_document();
+        arena.push("ğ");
+        arena.finish_document();
+        let documents: Vec<Option<Vec<&str>>> = (0..arena.len())
+            .map(|i| arena.document(i).map(Iterator::collect))
+            .collect();
+        assert_eq!(
+            documents,
+            [Some(vec!["kitap", "okudum"]), None, Some(vec![]), Some(vec!["ğ"])]
+        );
+    }
+}
@@ -0,0 +1,199 @@
+//! Arrow columns for batch processing
+//!
+//! Columns are exchanged with pyarrow, polars and pandas through the Arrow
+//! PyCapsule interface (`__arrow_c_array__` / `__arrow_c_stream__`), which
+//! `pyo3-arrow` imports and exports; this module works on the `arrow-array`
+//! arrays on either side:
+//!
+//! - String columns (`string`, `large_string`, `string_view`) are read in
+//!   place: each value borrows from the imported buffers
+//! - Results are built as `large_string`, `int64` and `large_list` arrays,
+//!   which the consumer takes over without copying
+//!
+//! `tokenize` turns a string column into list columns of tokens and of their
+//! start and end character offsets, as `tokenize_with_offsets` computes them
+//! per string; null strings give null lists.
+
+use crate::cancel::{Cancelled, Token};
+use arrow_array::builder::{Int64Builder, LargeListBuilder, LargeStringBuilder};
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
+use arrow_array::{Array, ArrayRef, LargeStringArray};
+use arrow_schema::DataType;
+use std::sync::Arc;
+
+/// Values of a string column, borrowed from its buffers; None for nulls.
+/// Fails for other column types.
+pub fn strings(array: &dyn Array) -> Result<Vec<Option<&str>>, String> {
+    match array.data_type() {
+        DataType::Utf8 => Ok(array.as_string::<i32>().iter().collect()),
+        DataType::LargeUtf8 => Ok(array.as_string::<i64>().iter().collect()),
+        DataType::Utf8View => Ok(array.as_string_view().iter().collect()),
+        other => Err(format!(
+            "expected a string array, got Arrow type {}",
+            type_name(other)
+        )),
+    }
+}
+
+/// A `large_string` column of the given strings and nulls
+pub fn string_column<'a>(values: impl IntoIterator<Item = Option<&'a str>>) -> ArrayRef {
+    Arc::new(values.into_iter().collect::<LargeStringArray>())
+}
+
+/// Arrow type name, e.g. "large_list<large_string>"
+pub fn type_name(data_type: &DataType) -> String {
+    match data_type {
+        DataType::Utf8 => "string".to_string(),
+        DataType::LargeUtf8 => "large_string".to_string(),
+        DataType::Utf8View => "string_view".to_string(),
+        DataType::Int64 => "int64".to_string(),
+        DataType::LargeList(field) => format!("large_list<{}>", type_name(field.data_type())),
+        other => other.to_string(),
+    }
+}
+
+/// A value of a column as `value` reads it
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Str(String),
+    Int(i64),
+    List(Vec<Value>),
+}
+
+/// Value of row `i` of a `large_string`, `int64` or `large_list` column
+pub fn value(array: &dyn Array, i: usize) -> Value {
+    if array.is_null(i) {
+        return Value::Null;
+    }
+    match array.data_type() {
+        DataType::LargeUtf8 => Value::Str(array.as_string::<i64>().value(i).to_string()),
+        DataType::Int64 => Value::Int(array.as_primitive::<Int64Type>().value(i)),
+        DataType::LargeList(_) => {
+            let row = array.as_list::<i64>().value(i);
+            Value::List((0..row.len()).map(|j| value(row.as_ref(), j)).collect())
+        }
+        other => unreachable!("durak builds no {} columns", other),
+    }
+}
+
+/// List columns of the tokens of each string and of their start and end
+/// character offsets; null strings give null lists in all three. Stops
+/// with `Cancelled` once `token` is cancelled.
+pub fn tokenize(
+    values: &[Option<&str>],
+    threads: usize,
+    token: &Token,
+) -> Result<(ArrayRef, ArrayRef, ArrayRef), Cancelled> {
+    let spans = crate::parallel::map_cancellable(values, threads, token, |text| {
+        text.map(crate::spans_with_char_offsets)
+    })?;
+    let mut tokens = LargeListBuilder::new(LargeStringBuilder::new());
+    let mut starts = LargeListBuilder::new(Int64Builder::new());
+    let mut ends = LargeListBuilder::new(Int64Builder::new());
+    for (text, spans) in values.iter().zip(&spans) {
+        if let (Some(text), Some(spans)) = (text, spans) {
+            for (span, start, end) in spans {
+                tokens.values().append_value(&text[span.start..span.end]);
+                starts.values().append_value(*start as i64);
+                ends.values().append_value(*end as i64);
+            }
+        }
+        let valid = spans.is_some();
+        tokens.append(valid);
+        starts.append(valid);
+        ends.append(valid);
+    }
+    Ok((
+        Arc::new(tokens.finish()),
+        Arc::new(starts.finish()),
+        Arc::new(ends.finish()),
+    ))
+}
+
+/// A `large_string` column of `f` applied to each string; null strings
+/// stay null. Stops with `Cancelled` once `token` is cancelled.
+pub fn map_strings<F>(
+    values: &[Option<&str>],
+    threads: usize,
+    token: &Token,
+    f: F,
+) -> Result<ArrayRef, Cancelled>
+where
+    F: Fn(&str) -> String + Sync + Send,
+{
+    let mapped = crate::parallel::map_cancellable(values, threads, token, |text| text.map(&f))?;
+    Ok(string_column(mapped.iter().map(Option::as_deref)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::ffi::{from_ffi, to_ffi};
+    use arrow_array::{make_array, StringArray, StringViewArray};
+
+    /// Export a column through the C data interface and import it back, as
+    /// a consumer in another library would see it
+    fn through_ffi(array: &ArrayRef) -> ArrayRef {
+        let (array, schema) = to_ffi(&array.to_data()).unwrap();
+        make_array(unsafe { from_ffi(array, &schema) }.unwrap())
+    }
+
+    #[test]
+    fn test_string_layouts() {
+        let values = [Some("ev"), None, Some(""), Some("kitaplarımızdan")];
+        let columns: [ArrayRef; 3] = [
+            Arc::new(StringArray::from(values.to_vec())),
+            string_column(values),
+            Arc::new(StringViewArray::from(values.to_vec())),
+        ];
+        for column in &columns {
+            assert_eq!(strings(through_ffi(column).as_ref()).unwrap(), values);
+        }
+        let sliced = columns[1].slice(1, 3);
+        assert_eq!(
+            strings(through_ffi(&sliced).as_ref()).unwrap(),
+            &values[1..]
+        );
+
+        let ints: ArrayRef = Arc::new(arrow_array::Int64Array::from(vec![1]));
+        assert_eq!(
+            strings(ints.as_ref()).unwrap_err(),
+            "expected a string array, got Arrow type int64"
+        );
+    }
+
+    #[test]
+    fn test_tokenize() {
+        let values = [Some("Ankara'ya gittim."), None, Some("")];
+        let token = Token::new();
+        let (tokens, starts, ends) = tokenize(&values, 1, &token).unwrap();
+        let tokens = through_ffi(&tokens);
+        assert_eq!(type_name(tokens.data_type()), "large_list<large_string>");
+        assert_eq!(type_name(starts.data_type()), "large_list<int64>");
+        let strs = |values: &[&str]| {
+            Value::List(values.iter().map(|v| Value::Str(v.to_string())).collect())
+        };
+        assert_eq!(
+            value(tokens.as_ref(), 0),
+            strs(&["Ankara'ya", "gittim", "."])
+        );
+        let ints = |values: &[i64]| Value::List(values.iter().map(|&v| Value::Int(v)).collect());
+        assert_eq!(value(starts.as_ref(), 0), ints(&[0, 10, 16]));
+        assert_eq!(value(ends.as_ref(), 0), ints(&[9, 16, 17]));
+        assert_eq!(value(tokens.as_ref(), 1), Value::Null);
+        assert_eq!(value(ends.as_ref(), 2), Value::List(Vec::new()));
+        token.cancel();
+        assert!(tokenize(&values, 1, &token).is_err());
+    }
+
+    #[test]
+    fn test_map_strings() {
+        let token = Token::new();
+        let column = map_strings(&[Some("ev"), None], 1, &token, |s| s.repeat(2)).unwrap();
+        assert_eq!(type_name(column.data_type()), "large_string");
+        assert_eq!(value(column.as_ref(), 0), Value::Str("evev".to_string()));
+        assert_eq!(value(column.as_ref(), 1), Value::Null);
+    }
+}
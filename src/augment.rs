@@ -0,0 +1,339 @@
+//! Morphology-based text augmentation
+//!
+//! Generates variants of a sentence that keep its lemmas, for training
+//! classifiers on small Turkish datasets without a synonym lexicon. Each
+//! variant differs from the input by exactly one edit:
+//!
+//! - `Case`: a case-marked noun is re-inflected to another case
+//!   (evde → eve, evden); genitives are left alone since they mark a
+//!   possessor that needs its head
+//! - `Order`: two case-marked nouns or adverbs before the verb swap places.
+//!   Turkish is verb-final with free constituent order before the verb, so
+//!   only clauses whose last word is a verb are reordered, and words with a
+//!   preceding modifier (adjective, numeral, genitive) stay in place
+//! - `Clitic`: the clitic "da"/"de" ("also") is inserted after a noun or
+//!   personal pronoun, in harmony with its last vowel, or a standalone one
+//!   is removed
+//!
+//! Text is handled as whitespace-separated words with their surrounding
+//! punctuation attached, and variants are joined with single spaces. Clauses
+//! end at punctuation and before a coordinating conjunction ("ve", "ama").
+//! Words carrying punctuation, the first word of a clause and proper nouns
+//! with an apostrophe are never moved or re-inflected.
+
+use crate::analyzer::{self, Pos};
+use crate::pos::{self, Tag};
+use crate::sampling;
+use crate::vowel_harmony::get_last_vowel_class;
+use crate::{fast_normalize, fast_uppercase};
+
+/// Case tags a noun may be re-inflected to
+const CASES: &[&str] = &["Acc", "Dat", "Loc", "Abl", "Ins"];
+
+/// Conjunctions that start a new clause
+const CONJUNCTIONS: &[&str] = &["ama", "ancak", "fakat", "veya", "ve", "yoksa", "çünkü"];
+
+/// Pronouns the clitic may follow; demonstratives are left out since "bu"
+/// and "şu" usually modify the next word
+const PERSONAL_PRONOUNS: &[&str] = &["ben", "sen", "o", "biz", "siz", "onlar"];
+
+/// Kind of edit an augmented variant may contain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Case,
+    Order,
+    Clitic,
+}
+
+impl Operation {
+    pub const ALL: &'static [Operation] = &[Operation::Case, Operation::Order, Operation::Clitic];
+
+    pub fn parse(name: &str) -> Option<Operation> {
+        match name {
+            "case" => Some(Operation::Case),
+            "order" => Some(Operation::Order),
+            "clitic" => Some(Operation::Clitic),
+            _ => None,
+        }
+    }
+}
+
+/// A whitespace-separated word split into leading punctuation, the word
+/// itself and trailing punctuation
+#[derive(Debug, Clone, Copy)]
+struct Word<'a> {
+    prefix: &'a str,
+    core: &'a str,
+    suffix: &'a str,
+    /// First word of its clause
+    initial: bool,
+    /// Last word of its clause
+    last: bool,
+}
+
+impl<'a> Word<'a> {
+    fn split(text: &'a str) -> Word<'a> {
+        let trimmed = text.trim_start_matches(|c: char| !c.is_alphanumeric());
+        let core = trimmed.trim_end_matches(|c: char| !c.is_alphanumeric());
+        Word {
+            prefix: &text[..text.len() - trimmed.len()],
+            core,
+            suffix: &trimmed[core.len()..],
+            initial: false,
+            last: false,
+        }
+    }
+
+    /// A bare word that can be moved without carrying punctuation along
+    fn is_bare(&self) -> bool {
+        self.prefix.is_empty() && self.suffix.is_empty() && !self.core.is_empty()
+    }
+
+    fn is_clitic(&self) -> bool {
+        matches!(self.core, "da" | "de")
+    }
+}
+
+fn words(text: &str) -> Vec<Word<'_>> {
+    let mut words: Vec<Word> = text.split_whitespace().map(Word::split).collect();
+    let mut initial = true;
+    for i in 0..words.len() {
+        let conjunction = words.get(i + 1).map_or(true, |next| {
+            CONJUNCTIONS.contains(&fast_normalize(next.core, true, true).as_str())
+        });
+        let word = &mut words[i];
+        word.initial = initial;
+        word.last = conjunction || word.suffix.contains(['.', '!', '?', '…', ',', ';', ':']);
+        initial = word.last;
+    }
+    words
+}
+
+/// Preferred analysis of a case-marked noun and its case tag
+fn case_marked(word: &str) -> Option<(analyzer::Analysis, &'static str)> {
+    if word.contains('\'') {
+        return None;
+    }
+    let analysis = analyzer::analyze(word).into_iter().next()?;
+    let case = analysis.morphemes.last()?.tag;
+    let nominal = analysis.pos == Pos::Noun && analysis.morphemes.iter().all(|m| m.pos.is_none());
+    (nominal && CASES.contains(&case)).then_some((analysis, case))
+}
+
+/// Copy the capitalization of the first letter of `original` onto `form`
+fn match_case(original: &str, form: String) -> String {
+    match (original.chars().next(), form.chars().next()) {
+        (Some(first), Some(c)) if first.is_uppercase() => {
+            fast_uppercase(&c.to_string()) + &form[c.len_utf8()..]
+        }
+        _ => form,
+    }
+}
+
+/// The other case forms of a case-marked noun, in `CASES` order
+///
+/// # Examples
+/// ```text
+/// reinflect("evde")      -> ["evi", "eve", "evden", "evle"]
+/// reinflect("kitabımı")  -> ["kitabıma", "kitabımda", "kitabımdan", "kitabımla"]
+/// reinflect("güzel")     -> []
+/// ```
+pub fn reinflect(word: &str) -> Vec<String> {
+    let Some((analysis, case)) = case_marked(word) else {
+        return Vec::new();
+    };
+    let mut tags: Vec<&str> = analysis.morphemes.iter().map(|m| m.tag).collect();
+    CASES
+        .iter()
+        .filter(|&&other| other != case)
+        .filter_map(|&other| {
+            *tags.last_mut().expect("a case-marked noun has a case tag") = other;
+            analyzer::generate(&analysis.root, &tags, Some(Pos::Noun)).ok()
+        })
+        .map(|form| match_case(word, form))
+        .collect()
+}
+
+/// A single change to the word sequence
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Edit {
+    Replace(usize, String),
+    Swap(usize, usize),
+    Insert(usize, &'static str),
+    Remove(usize),
+}
+
+fn case_edits(words: &[Word], edits: &mut Vec<Edit>) {
+    for (i, word) in words.iter().enumerate() {
+        for form in reinflect(word.core) {
+            edits.push(Edit::Replace(i, form));
+        }
+    }
+}
+
+/// Words of one sentence that may trade places: bare case-marked nouns and
+/// adverbs before a final verb, without a modifier in front of them
+fn movable(words: &[Word], start: usize, end: usize) -> Vec<usize> {
+    let verb = &words[end - 1];
+    if pos::tag_token(verb.core) != Tag::Verb {
+        return Vec::new();
+    }
+    (start + 1..end - 1)
+        .filter(|&i| words[i].is_bare())
+        .filter(|&i| {
+            let core = words[i].core;
+            case_marked(core).is_some() || pos::tag_token(core) == Tag::Adv
+        })
+        .filter(|&i| {
+            let previous = words[i - 1].core;
+            let modified = matches!(pos::tag_token(previous), Tag::Adj | Tag::Num)
+                || case_marked(previous).is_some_and(|(_, case)| case == "Gen");
+            !modified || !words[i - 1].suffix.is_empty()
+        })
+        .collect()
+}
+
+fn order_edits(words: &[Word], edits: &mut Vec<Edit>) {
+    let mut start = 0;
+    for end in 1..=words.len() {
+        if !words[end - 1].last {
+            continue;
+        }
+        let movable = movable(words, start, end);
+        for (n, &i) in movable.iter().enumerate() {
+            for &j in &movable[n + 1..] {
+                if fast_normalize(words[i].core, true, true)
+                    != fast_normalize(words[j].core, true, true)
+                {
+                    edits.push(Edit::Swap(i, j));
+                }
+            }
+        }
+        start = end;
+    }
+}
+
+fn clitic_edits(words: &[Word], edits: &mut Vec<Edit>) {
+    for (i, word) in words.iter().enumerate() {
+        if word.is_clitic() && word.is_bare() && !word.initial && !word.last {
+            edits.push(Edit::Remove(i));
+            continue;
+        }
+        let followed = words.get(i + 1).is_some_and(|next| next.is_clitic());
+        if !word.suffix.is_empty() || word.last || followed || word.core.contains('\'') {
+            continue;
+        }
+        let lower = fast_normalize(word.core, true, true);
+        let noun = analyzer::analyze(&lower).first().is_some_and(|analysis| {
+            analysis.pos == Pos::Noun && analysis.morphemes.iter().all(|m| m.pos.is_none())
+        });
+        if !noun && !PERSONAL_PRONOUNS.contains(&lower.as_str()) {
+            continue;
+        }
+        if let Some(vowel) = get_last_vowel_class(&lower) {
+            edits.push(Edit::Insert(i, if vowel.is_front() { "de" } else { "da" }));
+        }
+    }
+}
+
+fn apply(words: &[Word], edit: &Edit) -> String {
+    let render = |word: &Word| format!("{}{}{}", word.prefix, word.core, word.suffix);
+    let mut out: Vec<String> = words.iter().map(render).collect();
+    match edit {
+        Edit::Replace(i, form) => {
+            out[*i] = format!("{}{}{}", words[*i].prefix, form, words[*i].suffix);
+        }
+        Edit::Swap(i, j) => out.swap(*i, *j),
+        Edit::Insert(i, clitic) => out.insert(i + 1, clitic.to_string()),
+        Edit::Remove(i) => {
+            out.remove(*i);
+        }
+    }
+    out.join(" ")
+}
+
+/// Up to `n` distinct variants of `text`, each one edit away from it
+///
+/// All possible edits of the requested operations are enumerated and a
+/// seeded uniform sample of `n` is applied, so a seed always selects the
+/// same variants. Fewer than `n` variants are returned when the text admits
+/// fewer edits.
+pub fn augment(text: &str, n: usize, operations: &[Operation], seed: u64) -> Vec<String> {
+    let words = words(text);
+    let mut edits = Vec::new();
+    for operation in Operation::ALL {
+        if !operations.contains(operation) {
+            continue;
+        }
+        match operation {
+            Operation::Case => case_edits(&words, &mut edits),
+            Operation::Order => order_edits(&words, &mut edits),
+            Operation::Clitic => clitic_edits(&words, &mut edits),
+        }
+    }
+    let mut variants: Vec<String> = Vec::new();
+    for edit in sampling::reservoir(edits, n, seed) {
+        let variant = apply(&words, &edit);
+        if !variants.contains(&variant) {
+            variants.push(variant);
+        }
+    }
+    variants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all(text: &str, operations: &[Operation]) -> Vec<String> {
+        augment(text, usize::MAX, operations, 0)
+    }
+
+    #[test]
+    fn test_reinflect() {
+        assert_eq!(reinflect("evde"), vec!["evi", "eve", "evden", "evle"]);
+        assert_eq!(reinflect("Kitabımı")[0], "Kitabıma");
+        assert!(reinflect("güzel").is_empty());
+        assert!(reinflect("İstanbul'da").is_empty());
+    }
+
+    #[test]
+    fn test_case_variants_keep_punctuation() {
+        let variants = all("Ali okula gitti.", &[Operation::Case]);
+        assert!(variants.contains(&"Ali okulda gitti.".to_string()));
+        assert!(variants
+            .iter()
+            .all(|v| v.starts_with("Ali ") && v.ends_with(" gitti.")));
+    }
+
+    #[test]
+    fn test_order_swaps_before_final_verb() {
+        assert_eq!(
+            all("Ali yarın okula gidecek.", &[Operation::Order]),
+            vec!["Ali okula yarın gidecek."]
+        );
+        // Not verb-final
+        assert!(all("Ali yarın okula gidecek mi acaba", &[Operation::Order]).is_empty());
+        // "büyük" modifies "eve"
+        assert!(all("Ali yarın büyük eve gidecek.", &[Operation::Order]).is_empty());
+    }
+
+    #[test]
+    fn test_clitic_insertion_and_removal() {
+        let variants = all("ben de kitabı okudum", &[Operation::Clitic]);
+        assert_eq!(
+            variants,
+            vec!["ben kitabı okudum", "ben de kitabı da okudum"]
+        );
+        assert!(all("okula", &[Operation::Clitic]).is_empty());
+    }
+
+    #[test]
+    fn test_augment_is_seeded() {
+        let text = "Ali yarın okula kitabı götürecek.";
+        let first = augment(text, 3, Operation::ALL, 7);
+        assert_eq!(first.len(), 3);
+        assert_eq!(first, augment(text, 3, Operation::ALL, 7));
+        assert!(!first.contains(&text.to_string()));
+    }
+}
@@ -0,0 +1,44 @@
+//! Entry points for the Criterion benchmarks
+//!
+//! `benches/core.rs` links the crate as an rlib and can only see public
+//! items; the pipeline functions stay private to the crate, so this module
+//! re-exports the hot paths the benchmarks measure. It is hidden from the
+//! docs and not part of any stable API.
+
+use crate::{fast_normalize, lemma_cache, lemmatize_word, parallel, token_spans, Strategy};
+
+/// Minimum root length of the default `durak.Lemmatizer`
+const MIN_ROOT_LENGTH: usize = 2;
+
+/// Tokens of `text` as byte spans, without building strings
+pub fn tokenize(text: &str) -> usize {
+    token_spans(text).len()
+}
+
+/// Lowercase with Turkish I/ı rules, as `durak.normalize_case`
+pub fn normalize(text: &str) -> String {
+    fast_normalize(text, true, true)
+}
+
+/// Hybrid lemma of every word of `text`, through the lemma cache
+pub fn lemmatize(text: &str) -> Vec<String> {
+    let normalized = fast_normalize(text, true, true);
+    token_spans(&normalized)
+        .iter()
+        .map(|span| {
+            let word = &normalized[span.start..span.end];
+            lemmatize_word(word, Strategy::Hybrid, false, false, MIN_ROOT_LENGTH)
+        })
+        .collect()
+}
+
+/// Normalize, tokenize and lemmatize documents on `threads` workers
+/// (0: one per core), returning the token count of each
+pub fn batch(texts: &[String], threads: usize) -> Vec<usize> {
+    parallel::map(texts, threads, |text| lemmatize(text).len())
+}
+
+/// Set the lemma cache capacity (0 disables it), emptying the cache
+pub fn set_lemma_cache_size(maxsize: usize) {
+    lemma_cache().resize(maxsize);
+}
@@ -0,0 +1,166 @@
+//! Bidirectional text
+//!
+//! Quoted Arabic or Hebrew (religious texts, names, citations) carries
+//! right-to-left runs and often invisible bidi controls: marks (LRM, RLM,
+//! ALM), embeddings and overrides (LRE … RLO) and isolates (LRI … PDI).
+//! The tokenizer skips the controls, so they never become tokens of their
+//! own or end up inside URL tokens, and token offsets keep pointing into
+//! the raw text.
+//!
+//! `rtl_spans` tags the right-to-left runs with character offsets and
+//! `isolate` rewrites a text for display: the original controls are
+//! dropped (an unterminated RLO reverses everything after it) and each
+//! run is wrapped in RLI … PDI, so it cannot reorder the Turkish around it.
+
+const RLI: char = '\u{2067}';
+const PDI: char = '\u{2069}';
+
+/// Regex character class body of the bidi controls, for excluding them
+/// from token patterns
+pub const CONTROL_CLASS: &str = r"\x{200E}\x{200F}\x{061C}\x{202A}-\x{202E}\x{2066}-\x{2069}";
+
+/// Bidi formatting characters: marks, embeddings, overrides and isolates
+pub fn is_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200E}' | '\u{200F}' | '\u{061C}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+/// Right-to-left script of a run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Script {
+    Arabic,
+    Hebrew,
+}
+
+impl Script {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Script::Arabic => "ARABIC",
+            Script::Hebrew => "HEBREW",
+        }
+    }
+
+    /// Script of a character, including its combining marks (harakat,
+    /// niqqud) and digits; None for other scripts and for the ALM control
+    pub fn of(c: char) -> Option<Self> {
+        match c {
+            '\u{061C}' => None,
+            '\u{0590}'..='\u{05FF}' | '\u{FB1D}'..='\u{FB4F}' => Some(Script::Hebrew),
+            '\u{0600}'..='\u{06FF}'
+            | '\u{0750}'..='\u{077F}'
+            | '\u{08A0}'..='\u{08FF}'
+            | '\u{FB50}'..='\u{FDFF}'
+            | '\u{FE70}'..='\u{FEFF}' => Some(Script::Arabic),
+            _ => None,
+        }
+    }
+}
+
+/// A right-to-left run with character offsets into the text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub text: String,
+    pub script: Script,
+    pub start: usize,
+    pub end: usize,
+    /// Byte offsets of the run
+    byte_start: usize,
+    byte_end: usize,
+}
+
+/// Right-to-left runs of a text. A run is a maximal sequence of characters
+/// of one script; spaces and bidi controls between two of its characters
+/// belong to the run, so a quoted phrase is one span.
+///
+/// # Examples
+/// ```text
+/// rtl_spans("Ayette «بسم الله» yazar")
+///     -> [Span { text: "بسم الله", script: Arabic, start: 8, end: 16 }]
+/// ```
+pub fn rtl_spans(text: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut current: Option<Span> = None;
+    for (char_index, (byte_index, c)) in text.char_indices().enumerate() {
+        let end = byte_index + c.len_utf8();
+        match (Script::of(c), current.as_mut()) {
+            (Some(script), Some(span)) if span.script == script => {
+                span.end = char_index + 1;
+                span.byte_end = end;
+            }
+            (None, Some(_)) if c.is_whitespace() || is_control(c) => {}
+            (script, _) => {
+                spans.extend(current.take());
+                current = script.map(|script| Span {
+                    text: String::new(),
+                    script,
+                    start: char_index,
+                    end: char_index + 1,
+                    byte_start: byte_index,
+                    byte_end: end,
+                });
+            }
+        }
+    }
+    spans.extend(current);
+    for span in &mut spans {
+        span.text = text[span.byte_start..span.byte_end].to_string();
+    }
+    spans
+}
+
+/// The text without bidi controls
+pub fn strip_controls(text: &str) -> String {
+    text.chars().filter(|&c| !is_control(c)).collect()
+}
+
+/// The text for display: bidi controls dropped and every right-to-left run
+/// wrapped in RLI … PDI
+pub fn isolate(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() + 8);
+    let mut position = 0;
+    for span in rtl_spans(text) {
+        result.push_str(&strip_controls(&text[position..span.byte_start]));
+        result.push(RLI);
+        result.push_str(&strip_controls(&span.text));
+        result.push(PDI);
+        position = span.byte_end;
+    }
+    result.push_str(&strip_controls(&text[position..]));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rtl_spans() {
+        let text = "Ayette «بسم الله» yazar, İbranice שָׁלוֹם der.";
+        let spans: Vec<_> = rtl_spans(text)
+            .into_iter()
+            .map(|s| (s.text, s.script.as_str(), s.start, s.end))
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                ("بسم الله".to_string(), "ARABIC", 8, 16),
+                ("שָׁלוֹם".to_string(), "HEBREW", 34, 41),
+            ]
+        );
+        let chars: Vec<char> = text.chars().collect();
+        assert_eq!(chars[8..16].iter().collect::<String>(), "بسم الله");
+        // Trailing spaces and controls stay outside the run
+        assert_eq!(rtl_spans("سلام \u{200F} ve")[0].end, 4);
+        assert!(rtl_spans("Merhaba dünya").is_empty());
+    }
+
+    #[test]
+    fn test_strip_and_isolate() {
+        let text = "\u{202E}abc\u{202C} ve \u{200F}سلام\u{200F}.";
+        assert_eq!(strip_controls(text), "abc ve سلام.");
+        assert_eq!(isolate(text), "abc ve \u{2067}سلام\u{2069}.");
+        assert_eq!(isolate("Merhaba"), "Merhaba");
+    }
+}
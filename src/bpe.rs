@@ -0,0 +1,575 @@
+//! Byte-pair encoding subword vocabularies (Sennrich et al. 2016)
+//!
+//! Words are durak-normalized and counted as for the unigram trainer
+//! (`unigram::count_words`), split into characters after a leading `▁`,
+//! and the most frequent adjacent pair of symbols is merged until
+//! `vocab_size` pieces exist. One thing differs from plain BPE: a pair
+//! occurrence that straddles a morpheme boundary of the analyzer's
+//! segmentation counts only `1 - boundary_penalty` times, so merges grow
+//! roots and suffixes ("▁kitap" + "lar") before pieces that cut across
+//! them ("▁kita" + "plar"). Encoding applies the merges in training order.
+//!
+//! Models are written as the `vocab.json` and `merges.txt` pair of GPT-2
+//! style BPE, which `tokenizers.models.BPE.from_file` loads with
+//! `unk_token="<unk>"`. Text must go through `fast_normalize` and have its
+//! words prefixed with `▁` before it is encoded there; `BpeModel::encode`
+//! does both itself.
+
+use crate::unigram::{self, ModelError, TrainError};
+use crate::{is_blob, parallel, subword};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::ops::Range;
+
+/// Header of `merges.txt`, as written by `tokenizers` and GPT-2
+const MERGES_HEADER: &str = "#version: 0.2";
+
+// ============================================================================
+// MODEL
+// ============================================================================
+
+/// A trained BPE vocabulary: pieces in id order and the merges that build
+/// them, by rank
+#[derive(Debug, Clone)]
+pub struct BpeModel {
+    pieces: Vec<String>,
+    index: HashMap<String, u32>,
+    unk_id: u32,
+    merges: Vec<(u32, u32)>,
+    /// Rank and merged id of each mergeable pair
+    ranks: HashMap<(u32, u32), (usize, u32)>,
+}
+
+impl BpeModel {
+    /// Build a model from its pieces in id order and its merges by rank.
+    /// Both parts of every merge and their concatenation must be pieces.
+    pub fn new(pieces: Vec<String>, merges: &[(String, String)]) -> Result<Self, ModelError> {
+        let mut index = HashMap::with_capacity(pieces.len());
+        for (id, piece) in pieces.iter().enumerate() {
+            index.entry(piece.clone()).or_insert(id as u32);
+        }
+        let unk_id = *index
+            .get(unigram::UNK_PIECE)
+            .ok_or(ModelError::MissingUnknownPiece)?;
+        let mut ids = Vec::with_capacity(merges.len());
+        let mut ranks = HashMap::with_capacity(merges.len());
+        for (left, right) in merges {
+            let lookup = |piece: &str| {
+                index
+                    .get(piece)
+                    .copied()
+                    .filter(|&id| id != unk_id)
+                    .ok_or(ModelError::Malformed("merge of an unknown piece"))
+            };
+            let pair = (lookup(left)?, lookup(right)?);
+            let merged = lookup(&format!("{}{}", left, right))?;
+            ranks.entry(pair).or_insert((ids.len(), merged));
+            ids.push(pair);
+        }
+        Ok(Self {
+            pieces,
+            index,
+            unk_id,
+            merges: ids,
+            ranks,
+        })
+    }
+
+    pub fn pieces(&self) -> &[String] {
+        &self.pieces
+    }
+
+    /// Merged pairs by rank
+    pub fn merges(&self) -> Vec<(&str, &str)> {
+        self.merges
+            .iter()
+            .map(|&(left, right)| {
+                (self.pieces[left as usize].as_str(), self.pieces[right as usize].as_str())
+            })
+            .collect()
+    }
+
+    pub fn piece_to_id(&self, piece: &str) -> u32 {
+        self.index.get(piece).copied().unwrap_or(self.unk_id)
+    }
+
+    pub fn id_to_piece(&self, id: u32) -> Option<&str> {
+        self.pieces.get(id as usize).map(String::as_str)
+    }
+
+    /// Segment normalized text into `(id, surface)` pairs. Runs of unknown
+    /// characters become a single unknown piece carrying their surface;
+    /// blobs (see `is_blob`) are left as characters.
+    pub fn encode(&self, text: &str) -> Vec<(u32, String)> {
+        let mut encoded: Vec<(u32, String)> = Vec::new();
+        for word in unigram::words(text) {
+            let mut previous_unknown = false;
+            for (id, range) in self.encode_word(&word) {
+                let surface = &word[range];
+                match id {
+                    Some(id) => encoded.push((id, surface.to_string())),
+                    None if previous_unknown => {
+                        if let Some(last) = encoded.last_mut() {
+                            last.1.push_str(surface);
+                        }
+                    }
+                    None => encoded.push((self.unk_id, surface.to_string())),
+                }
+                previous_unknown = id.is_none();
+            }
+        }
+        encoded
+    }
+
+    /// Symbols of one `▁`-prefixed word after applying the merges, lowest
+    /// rank first; `None` for unknown characters
+    fn encode_word(&self, word: &str) -> Vec<(Option<u32>, Range<usize>)> {
+        let mut symbols: Vec<(Option<u32>, Range<usize>)> = word
+            .char_indices()
+            .map(|(i, c)| {
+                let range = i..i + c.len_utf8();
+                (self.index.get(&word[range.clone()]).copied(), range)
+            })
+            .collect();
+        if is_blob(word) {
+            return symbols;
+        }
+        loop {
+            let best = symbols
+                .windows(2)
+                .enumerate()
+                .filter_map(|(i, pair)| {
+                    let rank = self.ranks.get(&(pair[0].0?, pair[1].0?))?;
+                    Some((*rank, i))
+                })
+                .min();
+            let Some(((_, merged), i)) = best else {
+                return symbols;
+            };
+            let (_, right) = symbols.remove(i + 1);
+            symbols[i] = (Some(merged), symbols[i].1.start..right.end);
+        }
+    }
+
+    /// Join pieces back into text
+    pub fn decode<S: AsRef<str>>(&self, pieces: &[S]) -> String {
+        subword::join_pieces(pieces)
+    }
+
+    /// Join piece ids back into text; unknown ids decode as "⁇"
+    pub fn decode_ids(&self, ids: &[u32]) -> String {
+        let pieces: Vec<&str> = ids
+            .iter()
+            .filter_map(|&id| self.id_to_piece(id).map(|piece| (id, piece)))
+            .map(|(id, piece)| if id == self.unk_id { unigram::UNK_SURFACE } else { piece })
+            .collect();
+        self.decode(&pieces)
+    }
+
+    /// `vocab.json`: a JSON object from piece to id, in id order
+    pub fn to_vocab_json(&self) -> String {
+        let entries: Vec<String> = self
+            .pieces
+            .iter()
+            .enumerate()
+            .map(|(id, piece)| {
+                let key = serde_json::to_string(piece).expect("strings serialize");
+                format!("{}:{}", key, id)
+            })
+            .collect();
+        format!("{{{}}}", entries.join(","))
+    }
+
+    /// `merges.txt`: a version header and one `left right` line per rank
+    pub fn to_merges_txt(&self) -> String {
+        let mut out = format!("{}\n", MERGES_HEADER);
+        for (left, right) in self.merges() {
+            out.push_str(&format!("{} {}\n", left, right));
+        }
+        out
+    }
+
+    /// Load the `vocab.json` and `merges.txt` pair written by `save` or by
+    /// `tokenizers`
+    pub fn from_files(vocab_json: &str, merges_txt: &str) -> Result<Self, ModelError> {
+        let vocab: HashMap<String, u32> = serde_json::from_str(vocab_json)
+            .map_err(|_| ModelError::Malformed("vocab is not a JSON object of ids"))?;
+        let mut pieces = vec![None; vocab.len()];
+        for (piece, id) in vocab {
+            match pieces.get_mut(id as usize) {
+                Some(slot @ None) => *slot = Some(piece),
+                _ => return Err(ModelError::Malformed("vocab ids are not 0..n")),
+            }
+        }
+        let pieces: Vec<String> = pieces.into_iter().map(Option::unwrap_or_default).collect();
+        let merges = merges_txt
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with("#version"))
+            .map(|line| {
+                line.split_once(' ')
+                    .map(|(left, right)| (left.to_string(), right.to_string()))
+                    .ok_or(ModelError::Malformed("merge line is not a pair"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(pieces, &merges)
+    }
+}
+
+// ============================================================================
+// TRAINING
+// ============================================================================
+
+/// Trainer settings
+#[derive(Debug, Clone)]
+pub struct TrainerConfig {
+    /// Final vocabulary size, including `<unk>` and the characters; training
+    /// stops earlier when no pair is frequent enough
+    pub vocab_size: usize,
+    /// Pairs weighing less than this are not merged
+    pub min_frequency: u64,
+    /// Weigh pair occurrences that cross the analyzer's morpheme boundaries
+    /// down by `boundary_penalty`
+    pub morpheme_boundaries: bool,
+    /// Share of a boundary-crossing occurrence's count that is discarded:
+    /// 0.0 is plain BPE, 1.0 never merges across a boundary
+    pub boundary_penalty: f64,
+    pub threads: usize,
+}
+
+impl Default for TrainerConfig {
+    fn default() -> Self {
+        Self {
+            vocab_size: 8000,
+            min_frequency: 2,
+            morpheme_boundaries: true,
+            boundary_penalty: 0.9,
+            threads: 1,
+        }
+    }
+}
+
+/// A distinct word of the training corpus as its current symbols
+struct Word {
+    symbols: Vec<u32>,
+    /// Length of each symbol in characters
+    lengths: Vec<usize>,
+    freq: f64,
+    /// Character offsets between the morphemes
+    boundaries: Vec<usize>,
+}
+
+impl Word {
+    /// Adjacent symbol pairs with their weights
+    fn pairs(&self, penalty: f64) -> Vec<((u32, u32), f64)> {
+        let mut offset = 0;
+        self.symbols
+            .windows(2)
+            .zip(&self.lengths)
+            .map(|(pair, length)| {
+                offset += length;
+                let weight = match self.boundaries.contains(&offset) {
+                    true => self.freq * (1.0 - penalty),
+                    false => self.freq,
+                };
+                ((pair[0], pair[1]), weight)
+            })
+            .collect()
+    }
+
+    /// Merge every occurrence of `pair`, left to right; false when the word
+    /// has none
+    fn merge(&mut self, pair: (u32, u32), merged: u32) -> bool {
+        let mut changed = false;
+        let mut i = 0;
+        while i + 1 < self.symbols.len() {
+            if (self.symbols[i], self.symbols[i + 1]) == pair {
+                self.symbols[i] = merged;
+                self.symbols.remove(i + 1);
+                self.lengths[i] += self.lengths.remove(i + 1);
+                changed = true;
+            }
+            i += 1;
+        }
+        changed
+    }
+}
+
+/// A pair and its weight when it was queued; ties go to the older pieces
+struct Candidate {
+    weight: f64,
+    pair: (u32, u32),
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.weight
+            .total_cmp(&other.weight)
+            .then_with(|| other.pair.cmp(&self.pair))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+/// Learn merges from normalized word frequencies (see
+/// `unigram::count_words`)
+pub fn train(
+    counts: &HashMap<String, u64>,
+    config: &TrainerConfig,
+) -> Result<BpeModel, TrainError> {
+    let mut sorted: Vec<(&String, u64)> =
+        counts.iter().map(|(word, &count)| (word, count)).collect();
+    if sorted.is_empty() {
+        return Err(TrainError::EmptyCorpus);
+    }
+    sorted.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    let morphemes = match config.morpheme_boundaries {
+        true => parallel::map(&sorted, config.threads, |(word, _)| {
+            unigram::morpheme_pieces(word)
+        }),
+        false => vec![Vec::new(); sorted.len()],
+    };
+
+    // Characters by frequency, after `<unk>`
+    let mut char_counts: HashMap<char, u64> = HashMap::new();
+    for (word, count) in &sorted {
+        for c in word.chars() {
+            *char_counts.entry(c).or_insert(0) += count;
+        }
+    }
+    let mut chars: Vec<(char, u64)> = char_counts.into_iter().collect();
+    chars.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    if chars.len() + 1 > config.vocab_size {
+        return Err(TrainError::VocabTooSmall {
+            requested: config.vocab_size,
+            required: chars.len() + 1,
+        });
+    }
+    let mut pieces = vec![unigram::UNK_PIECE.to_string()];
+    pieces.extend(chars.iter().map(|(c, _)| c.to_string()));
+    let mut index: HashMap<String, u32> = pieces
+        .iter()
+        .enumerate()
+        .map(|(id, piece)| (piece.clone(), id as u32))
+        .collect();
+
+    let mut words: Vec<Word> = sorted
+        .iter()
+        .zip(morphemes)
+        .map(|((word, count), morphemes)| {
+            let mut boundaries: Vec<usize> = morphemes
+                .iter()
+                .scan(0, |offset, m| {
+                    *offset += m.chars().count();
+                    Some(*offset)
+                })
+                .collect();
+            boundaries.pop();
+            Word {
+                symbols: word.chars().map(|c| index[&c.to_string()]).collect(),
+                lengths: vec![1; word.chars().count()],
+                freq: *count as f64,
+                boundaries,
+            }
+        })
+        .collect();
+
+    let penalty = config.boundary_penalty;
+    let mut weights: HashMap<(u32, u32), f64> = HashMap::new();
+    let mut occurrences: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+    for (w, word) in words.iter().enumerate() {
+        for (pair, weight) in word.pairs(penalty) {
+            *weights.entry(pair).or_insert(0.0) += weight;
+            occurrences.entry(pair).or_default().push(w);
+        }
+    }
+    let mut queue: BinaryHeap<Candidate> = weights
+        .iter()
+        .map(|(&pair, &weight)| Candidate { weight, pair })
+        .collect();
+
+    let mut merges = Vec::new();
+    while pieces.len() < config.vocab_size {
+        let Some(best) = queue.pop() else {
+            break;
+        };
+        // Entries are queued again whenever a weight changes; skip stale ones
+        if weights.get(&best.pair) != Some(&best.weight) {
+            continue;
+        }
+        if best.weight < config.min_frequency.max(1) as f64 {
+            break;
+        }
+        let (left, right) = best.pair;
+        let piece = format!("{}{}", pieces[left as usize], pieces[right as usize]);
+        let merged = *index.entry(piece.clone()).or_insert_with(|| {
+            pieces.push(piece);
+            pieces.len() as u32 - 1
+        });
+        merges.push((pieces[left as usize].clone(), pieces[right as usize].clone()));
+
+        let mut changed = HashSet::new();
+        for w in occurrences.remove(&best.pair).unwrap_or_default() {
+            let word = &mut words[w];
+            let before = word.pairs(penalty);
+            if !word.merge(best.pair, merged) {
+                continue;
+            }
+            for (pair, weight) in before {
+                *weights.entry(pair).or_insert(0.0) -= weight;
+                changed.insert(pair);
+            }
+            for (pair, weight) in word.pairs(penalty) {
+                *weights.entry(pair).or_insert(0.0) += weight;
+                occurrences.entry(pair).or_default().push(w);
+                changed.insert(pair);
+            }
+        }
+        weights.remove(&best.pair);
+        for pair in changed {
+            if let Some(&weight) = weights.get(&pair) {
+                queue.push(Candidate { weight, pair });
+            }
+        }
+    }
+    Ok(BpeModel::new(pieces, &merges).expect("trained merges build their pieces"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn corpus() -> HashMap<String, u64> {
+        let text = "kitaplar kitaplarım kitaplardan kitabım evler evlerim evden evde \
+                    geliyorum geliyorsun gidiyorum gidiyorsun okullar okulda okuldan \
+                    masalar masada masadan";
+        let mut counts = HashMap::new();
+        for _ in 0..20 {
+            unigram::count_words(&mut counts, text);
+        }
+        counts
+    }
+
+    fn config(vocab_size: usize) -> TrainerConfig {
+        TrainerConfig {
+            vocab_size,
+            ..TrainerConfig::default()
+        }
+    }
+
+    fn encode(model: &BpeModel, text: &str) -> Vec<String> {
+        model.encode(text).into_iter().map(|(_, piece)| piece).collect()
+    }
+
+    #[test]
+    fn test_train_vocab_size_and_order() {
+        let model = train(&corpus(), &config(60)).unwrap();
+        assert_eq!(model.pieces().len(), 60);
+        assert_eq!(model.id_to_piece(0), Some("<unk>"));
+        // Characters come first, then merged pieces
+        let pieces = &model.pieces()[1..];
+        let chars = pieces.iter().take_while(|p| p.chars().count() == 1).count();
+        assert!(pieces[chars..].iter().all(|p| p.chars().count() > 1));
+        assert!(pieces[..chars].contains(&"▁".to_string()));
+        // Every merge builds a piece from earlier ones
+        for (left, right) in model.merges() {
+            let merged = format!("{}{}", left, right);
+            assert!(model.piece_to_id(left) < model.piece_to_id(&merged));
+            assert!(model.piece_to_id(right) < model.piece_to_id(&merged));
+        }
+        // Deterministic, whatever the thread count
+        let parallel = TrainerConfig {
+            threads: 2,
+            ..config(60)
+        };
+        assert_eq!(train(&corpus(), &parallel).unwrap().pieces(), model.pieces());
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let model = train(&corpus(), &config(60)).unwrap();
+        let encoded = model.encode("KİTAPLARIM  evde 日本");
+        let surfaces: Vec<&str> = encoded.iter().map(|(_, s)| s.as_str()).collect();
+        assert_eq!(model.decode(&surfaces), "kitaplarım evde 日本");
+        // Unknown characters are merged into one piece
+        assert_eq!(encoded.last().unwrap(), &(0, "日本".to_string()));
+        let ids: Vec<u32> = encoded.iter().map(|(id, _)| *id).collect();
+        assert_eq!(model.decode_ids(&ids), "kitaplarım evde  \u{2047} ");
+    }
+
+    #[test]
+    fn test_merges_follow_morphemes() {
+        let encode_with = |morpheme_boundaries, boundary_penalty| {
+            let config = TrainerConfig {
+                morpheme_boundaries,
+                boundary_penalty,
+                ..config(200)
+            };
+            encode(&train(&corpus(), &config).unwrap(), "kitaplarım evlerim")
+        };
+        assert_eq!(encode_with(true, 1.0), ["▁kitap", "lar", "ım", "▁ev", "ler", "im"]);
+        assert_eq!(encode_with(false, 1.0), ["▁kitaplarım", "▁evlerim"]);
+        assert_eq!(encode_with(true, 0.0), encode_with(false, 0.0));
+    }
+
+    #[test]
+    fn test_min_frequency_stops_training() {
+        let config = TrainerConfig {
+            min_frequency: 1000,
+            ..config(200)
+        };
+        let model = train(&corpus(), &config).unwrap();
+        assert!(model.merges().is_empty());
+        assert_eq!(encode(&model, "ev"), ["▁", "e", "v"]);
+    }
+
+    #[test]
+    fn test_files_round_trip() {
+        let model = train(&corpus(), &config(60)).unwrap();
+        let vocab = model.to_vocab_json();
+        let merges = model.to_merges_txt();
+        assert!(merges.starts_with("#version: 0.2\n"));
+        let loaded = BpeModel::from_files(&vocab, &merges).unwrap();
+        assert_eq!(loaded.pieces(), model.pieces());
+        assert_eq!(loaded.merges(), model.merges());
+        let text = "Kitaplarımdan evlere geldim";
+        assert_eq!(loaded.encode(text), model.encode(text));
+    }
+
+    #[test]
+    fn test_model_errors() {
+        let pieces = |list: &[&str]| list.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+        let merge = vec![("a".to_string(), "b".to_string())];
+        assert_eq!(
+            BpeModel::new(pieces(&["a", "b", "ab"]), &merge).unwrap_err(),
+            ModelError::MissingUnknownPiece
+        );
+        assert!(BpeModel::new(pieces(&["<unk>", "a", "b"]), &merge).is_err());
+        assert!(BpeModel::new(pieces(&["<unk>", "a", "b", "ab"]), &merge).is_ok());
+        assert!(BpeModel::from_files(r#"{"<unk>": 0, "a": 2}"#, "").is_err());
+        assert!(BpeModel::from_files(r#"{"<unk>": 0}"#, "ab\n").is_err());
+    }
+
+    #[test]
+    fn test_train_errors() {
+        assert_eq!(
+            train(&HashMap::new(), &config(60)).unwrap_err(),
+            TrainError::EmptyCorpus
+        );
+        assert!(matches!(
+            train(&corpus(), &config(5)),
+            Err(TrainError::VocabTooSmall { .. })
+        ));
+    }
+}
@@ -0,0 +1,132 @@
+//! Cooperative cancellation of long-running calls
+//!
+//! Batch loops and file readers check a `Token` between items and stop with
+//! `Cancelled` once it is set; items already started run to completion.
+//!
+//! - `Token::child` links a per-call token to a caller's token: cancelling
+//!   the caller's token stops the call, cancelling the call's own token (on
+//!   Ctrl-C) leaves the caller's token usable for the next call
+//! - `Reader` wraps an `io::Read` so reads fail with `Cancelled` as the
+//!   inner error, which `is_cancelled_io` recognizes
+
+use std::fmt;
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A cancellation flag shared between the caller and the workers of a call
+#[derive(Debug, Clone, Default)]
+pub struct Token {
+    flag: Arc<AtomicBool>,
+    parent: Option<Arc<AtomicBool>>,
+}
+
+impl Token {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A token of its own that also counts as cancelled once `self` is
+    pub fn child(&self) -> Self {
+        Self {
+            flag: Arc::new(AtomicBool::new(false)),
+            parent: Some(Arc::clone(&self.flag)),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::Relaxed)
+            || self
+                .parent
+                .as_ref()
+                .is_some_and(|parent| parent.load(Ordering::Relaxed))
+    }
+
+    pub fn check(&self) -> Result<(), Cancelled> {
+        match self.is_cancelled() {
+            true => Err(Cancelled),
+            false => Ok(()),
+        }
+    }
+}
+
+/// Error of a call stopped through its token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("operation cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// A reader that fails once its token is cancelled
+pub struct Reader<'a, R> {
+    inner: R,
+    token: &'a Token,
+}
+
+impl<'a, R> Reader<'a, R> {
+    pub fn new(inner: R, token: &'a Token) -> Self {
+        Self { inner, token }
+    }
+}
+
+impl<R: io::Read> io::Read for Reader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.token
+            .check()
+            .map_err(|cancelled| io::Error::new(io::ErrorKind::Other, cancelled))?;
+        self.inner.read(buf)
+    }
+}
+
+/// Whether an I/O error comes from a cancelled `Reader`
+pub fn is_cancelled_io(error: &io::Error) -> bool {
+    error.get_ref().is_some_and(|inner| inner.is::<Cancelled>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn test_token() {
+        let token = Token::new();
+        assert_eq!(token.check(), Ok(()));
+        let clone = token.clone();
+        clone.cancel();
+        assert!(token.is_cancelled());
+        assert_eq!(token.check(), Err(Cancelled));
+    }
+
+    #[test]
+    fn test_child() {
+        let parent = Token::new();
+        let child = parent.child();
+        child.cancel();
+        assert!(child.is_cancelled() && !parent.is_cancelled());
+        let child = parent.child();
+        parent.cancel();
+        assert!(child.is_cancelled());
+    }
+
+    #[test]
+    fn test_reader() {
+        let token = Token::new();
+        let mut reader = Reader::new("kitap".as_bytes(), &token);
+        let mut buf = [0u8; 3];
+        assert_eq!(reader.read(&mut buf).unwrap(), 3);
+        token.cancel();
+        let error = reader.read(&mut buf).unwrap_err();
+        assert!(is_cancelled_io(&error));
+        assert!(!is_cancelled_io(&io::Error::new(io::ErrorKind::Other, "x")));
+    }
+}
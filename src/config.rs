@@ -0,0 +1,91 @@
+//! Process-wide defaults
+//!
+//! A few options are the same for every call in most programs: the minimum
+//! root length and vowel harmony check of suffix stripping, the unit of
+//! token offsets and the stopword domains added to the base list. They live
+//! in one `Config` behind a `RwLock`, so an application sets them once
+//! (`durak.set_config`, from `durak.defaults`) instead of passing them to
+//! every call.
+//!
+//! Explicit arguments still win: a function reads the config only for the
+//! options its caller left out. Readers hold the lock for the duration of a
+//! field read, so a change made on one thread is seen by the next call on
+//! every thread.
+
+use std::sync::{RwLock, RwLockReadGuard};
+
+/// Unit of the token offsets returned to callers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetMode {
+    /// Code points, as Python indexes `str`
+    Char,
+    /// UTF-8 bytes, as Rust and Arrow index strings
+    Byte,
+}
+
+impl OffsetMode {
+    pub fn parse(name: &str) -> Option<OffsetMode> {
+        match name {
+            "char" => Some(OffsetMode::Char),
+            "byte" => Some(OffsetMode::Byte),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OffsetMode::Char => "char",
+            OffsetMode::Byte => "byte",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Shortest root suffix stripping may leave, in characters
+    pub min_root_length: usize,
+    /// Check vowel harmony before stripping a suffix when validating roots
+    pub check_harmony: bool,
+    pub offsets: OffsetMode,
+    /// Stopword domains added to the base stopwords
+    pub stopword_domains: Vec<String>,
+}
+
+impl Config {
+    /// The defaults every option had before it was configurable
+    pub const DEFAULT: Config = Config {
+        min_root_length: 2,
+        check_harmony: true,
+        offsets: OffsetMode::Char,
+        stopword_domains: Vec::new(),
+    };
+}
+
+static CONFIG: RwLock<Config> = RwLock::new(Config::DEFAULT);
+
+/// The current defaults; keep the guard short-lived
+pub fn read() -> RwLockReadGuard<'static, Config> {
+    CONFIG.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Change the defaults in place, returning the previous ones; concurrent
+/// updates apply one after the other
+pub fn update(change: impl FnOnce(&mut Config)) -> Config {
+    let mut current = CONFIG.write().unwrap_or_else(|e| e.into_inner());
+    let previous = current.clone();
+    change(&mut current);
+    previous
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_mode_names() {
+        for mode in [OffsetMode::Char, OffsetMode::Byte] {
+            assert_eq!(OffsetMode::parse(mode.as_str()), Some(mode));
+        }
+        assert_eq!(OffsetMode::parse("utf16"), None);
+    }
+}
@@ -0,0 +1,169 @@
+//! Confusion-pair aware normalization for common Turkish misspellings
+//!
+//! User-generated Turkish text is dominated by a small set of recurring
+//! spelling errors ("herşey" for "her şey", "yanlız" for "yalnız",
+//! "şarz" for "şarj"). This module applies a curated list of such pairs,
+//! embedded from `resources/tr/config/confusion_pairs.txt`, word by word
+//! while leaving all other characters untouched.
+
+use crate::turkish_lowercase;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Embedded confusion pairs (misspelling<TAB>correction)
+static CONFUSION_PAIRS_DATA: &str = include_str!("../resources/tr/config/confusion_pairs.txt");
+static CONFUSION_PAIRS: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+
+fn get_pairs() -> &'static HashMap<&'static str, &'static str> {
+    CONFUSION_PAIRS.get_or_init(|| {
+        let mut pairs = HashMap::new();
+
+        for line in CONFUSION_PAIRS_DATA.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some((wrong, correct)) = line.split_once('\t') {
+                pairs.insert(wrong.trim(), correct.trim());
+            }
+        }
+
+        pairs
+    })
+}
+
+/// All confusion pairs as (misspelling, correction), sorted by misspelling
+pub fn pairs() -> Vec<(&'static str, &'static str)> {
+    let mut all: Vec<(&'static str, &'static str)> =
+        get_pairs().iter().map(|(&w, &c)| (w, c)).collect();
+    all.sort_unstable();
+    all
+}
+
+/// Look up the correction for a single lowercase word
+pub fn lookup(word: &str) -> Option<&'static str> {
+    get_pairs().get(word).copied()
+}
+
+/// Uppercase a single character with Turkish I/İ rules
+fn turkish_upper_char(c: char) -> String {
+    match c {
+        'i' => "İ".to_string(),
+        'ı' => "I".to_string(),
+        _ => c.to_uppercase().collect(),
+    }
+}
+
+/// Re-apply the casing pattern of `original` to `replacement`
+///
+/// ALL-CAPS words stay all caps, capitalized words keep an initial capital,
+/// everything else is returned as-is (corrections are stored lowercase).
+pub(crate) fn match_case(original: &str, replacement: &str) -> String {
+    let letters: Vec<char> = original.chars().filter(|c| c.is_alphabetic()).collect();
+    let all_upper = letters.len() > 1 && letters.iter().all(|c| c.is_uppercase());
+
+    if all_upper {
+        return replacement.chars().map(turkish_upper_char).collect();
+    }
+
+    if original.chars().next().is_some_and(|c| c.is_uppercase()) {
+        let mut chars = replacement.chars();
+        if let Some(first) = chars.next() {
+            return turkish_upper_char(first) + chars.as_str();
+        }
+    }
+
+    replacement.to_string()
+}
+
+/// Replace known misspellings in `text` with their corrections
+///
+/// Matching is case-insensitive (Turkish-aware) and whole-word only;
+/// the casing of the original word is carried over to the correction.
+///
+/// # Examples
+/// ```text
+/// correct("Herşey yanlız") -> "Her şey yalnız"
+/// ```
+pub fn correct(text: &str) -> String {
+    fn push_word(result: &mut String, word: &str) {
+        match lookup(&turkish_lowercase(word)) {
+            Some(replacement) => result.push_str(&match_case(word, replacement)),
+            None => result.push_str(word),
+        }
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut word_start: Option<usize> = None;
+
+    for (idx, c) in text.char_indices() {
+        if c.is_alphabetic() {
+            if word_start.is_none() {
+                word_start = Some(idx);
+            }
+        } else {
+            if let Some(start) = word_start.take() {
+                push_word(&mut result, &text[start..idx]);
+            }
+            result.push(c);
+        }
+    }
+    if let Some(start) = word_start {
+        push_word(&mut result, &text[start..]);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairs_loaded() {
+        let all = pairs();
+        assert!(all.len() >= 20);
+        assert!(all.contains(&("herşey", "her şey")));
+        assert!(all.contains(&("yanlız", "yalnız")));
+        assert!(all.contains(&("şarz", "şarj")));
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(lookup("herşey"), Some("her şey"));
+        assert_eq!(lookup("yalnız"), None);
+        assert_eq!(lookup("kitap"), None);
+    }
+
+    #[test]
+    fn test_correct_basic() {
+        assert_eq!(correct("herşey yolunda"), "her şey yolunda");
+        assert_eq!(
+            correct("telefonu şarz etmem lazım"),
+            "telefonu şarj etmem lazım"
+        );
+        assert_eq!(correct("yanlız kaldım"), "yalnız kaldım");
+    }
+
+    #[test]
+    fn test_correct_preserves_case() {
+        assert_eq!(correct("Herşey"), "Her şey");
+        assert_eq!(correct("HERŞEY"), "HER ŞEY");
+        assert_eq!(correct("Yanlız"), "Yalnız");
+    }
+
+    #[test]
+    fn test_correct_preserves_punctuation() {
+        assert_eq!(correct("Herşey, yanlız!"), "Her şey, yalnız!");
+        assert_eq!(correct("  deyil  "), "  değil  ");
+    }
+
+    #[test]
+    fn test_correct_whole_words_only() {
+        // "herşeyi" is not in the list and must not be partially rewritten
+        assert_eq!(correct("herşeyi"), "herşeyi");
+        assert_eq!(correct("kitap okudum"), "kitap okudum");
+        assert_eq!(correct(""), "");
+    }
+}
@@ -0,0 +1,493 @@
+//! CoNLL-U input and output
+//!
+//! Writes tokens in the ten-column format of Universal Dependencies (ID,
+//! FORM, LEMMA, UPOS, XPOS, FEATS, HEAD, DEPREL, DEPS, MISC), so durak
+//! output goes straight into UD tooling and evaluation scripts, and reads
+//! existing treebank files back.
+//!
+//! UPOS comes from the token type and the coarse tagger (`pos`), whose own
+//! tag is kept as XPOS. FEATS translates the analyzer's preferred parse:
+//! features of the last derivation only, since UD describes the word the
+//! token finally is ("evdeki" is an adjective without a case). Durak does
+//! not parse, so HEAD, DEPREL and DEPS are "_", and MISC records
+//! `SpaceAfter=No` for tokens directly followed by the next one.
+//!
+//! Parsed sentences keep every line as read, so a file can be re-annotated
+//! (`reannotate`) with only the chosen columns rewritten: comments, syntax
+//! columns, multiword token ranges ("1-2") and empty nodes ("1.1") come out
+//! unchanged.
+
+use crate::analyzer::{self, Analysis};
+use crate::pos::{self, Tag};
+use std::collections::BTreeMap;
+
+/// A token to write: its text, lemma, type (as in `tokenize_with_types`)
+/// and character offsets
+#[derive(Debug, Clone, Copy)]
+pub struct Token<'a> {
+    pub form: &'a str,
+    pub lemma: &'a str,
+    pub kind: &'a str,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Tense and mood suffixes of finite verbs
+const FINITE_TAGS: &[&str] = &[
+    "Past", "Narr", "Fut", "Aor", "Prog1", "Prog2", "Cond", "Neces",
+];
+
+/// UD features of one analyzer tag, as (feature, value)
+fn tag_features(tag: &str) -> &'static [(&'static str, &'static str)] {
+    match tag {
+        "Pl" => &[("Number", "Plur")],
+        "P1sg" => &[("Number[psor]", "Sing"), ("Person[psor]", "1")],
+        "P2sg" => &[("Number[psor]", "Sing"), ("Person[psor]", "2")],
+        "P3sg" => &[("Number[psor]", "Sing"), ("Person[psor]", "3")],
+        "P1pl" => &[("Number[psor]", "Plur"), ("Person[psor]", "1")],
+        "P2pl" => &[("Number[psor]", "Plur"), ("Person[psor]", "2")],
+        "P3pl" => &[("Number[psor]", "Plur"), ("Person[psor]", "3")],
+        "Acc" => &[("Case", "Acc")],
+        "Dat" => &[("Case", "Dat")],
+        "Loc" => &[("Case", "Loc")],
+        "Abl" => &[("Case", "Abl")],
+        "Gen" => &[("Case", "Gen")],
+        "Ins" => &[("Case", "Ins")],
+        "A1sg" => &[("Number", "Sing"), ("Person", "1")],
+        "A2sg" => &[("Number", "Sing"), ("Person", "2")],
+        "A3sg" => &[("Number", "Sing"), ("Person", "3")],
+        "A1pl" => &[("Number", "Plur"), ("Person", "1")],
+        "A2pl" => &[("Number", "Plur"), ("Person", "2")],
+        "A3pl" => &[("Number", "Plur"), ("Person", "3")],
+        "Neg" => &[("Polarity", "Neg")],
+        "Past" => &[("Evident", "Fh"), ("Tense", "Past")],
+        "Narr" => &[("Evident", "Nfh"), ("Tense", "Past")],
+        "Fut" => &[("Tense", "Fut")],
+        "Aor" => &[("Aspect", "Hab"), ("Tense", "Pres")],
+        "Prog1" | "Prog2" => &[("Aspect", "Prog"), ("Tense", "Pres")],
+        "Cond" => &[("Mood", "Cnd")],
+        "Neces" => &[("Mood", "Nec")],
+        "Able" => &[("Mood", "Pot")],
+        "Pass" => &[("Voice", "Pass")],
+        "Caus" => &[("Voice", "Cau")],
+        "Inf1" | "Inf2" => &[("VerbForm", "Vnoun")],
+        "PastPart" | "PresPart" => &[("VerbForm", "Part")],
+        "When" | "While" | "AfterDoingSo" | "ByDoingSo" => &[("VerbForm", "Conv")],
+        _ => &[],
+    }
+}
+
+/// UD features of an analysis joined as FEATS ("Case=Loc|Number=Sing"),
+/// "_" when there are none
+///
+/// # Examples
+/// ```text
+/// evlerimizden   -> Case=Abl|Number=Plur|Number[psor]=Plur|Person=3|Person[psor]=1
+/// gelmeyecekler  -> Number=Plur|Person=3|Polarity=Neg|Tense=Fut
+/// ```
+pub fn features(analysis: &Analysis) -> String {
+    // Only the morphemes after the last derivation describe the final word
+    let last_derivation = analysis.morphemes.iter().rposition(|m| m.pos.is_some());
+    let morphemes = match last_derivation {
+        Some(index) => &analysis.morphemes[index..],
+        None => &analysis.morphemes[..],
+    };
+    let mut features = BTreeMap::new();
+    for morpheme in morphemes {
+        for &(feature, value) in tag_features(morpheme.tag) {
+            features.insert(feature, value);
+        }
+    }
+
+    // Unmarked values: nominative singular third person nouns, positive
+    // third person singular finite verbs
+    let finite = morphemes.iter().any(|m| FINITE_TAGS.contains(&m.tag));
+    match analysis.final_pos() {
+        analyzer::Pos::Noun => {
+            features.entry("Case").or_insert("Nom");
+            features.entry("Number").or_insert("Sing");
+            features.entry("Person").or_insert("3");
+        }
+        analyzer::Pos::Verb if finite => {
+            features.entry("Number").or_insert("Sing");
+            features.entry("Person").or_insert("3");
+            features.entry("Polarity").or_insert("Pos");
+        }
+        _ => {}
+    }
+
+    if features.is_empty() {
+        return "_".to_string();
+    }
+    features
+        .iter()
+        .map(|(feature, value)| format!("{}={}", feature, value))
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+/// UPOS and XPOS of a token: its type decides for everything but words and
+/// numbers, which get the coarse tag. Capitalized words with an apostrophe
+/// suffix ("Ankara'dan") are proper nouns.
+fn tags(form: &str, kind: &str) -> (&'static str, &'static str) {
+    let upos = match kind {
+        "WORD" | "NUMBER" => None,
+        "PUNCT" => Some("PUNCT"),
+        "DATE" | "TIME" => Some("NUM"),
+        "EMOJI" | "EMOTICON" => Some("SYM"),
+        _ => Some("X"),
+    };
+    if let Some(upos) = upos {
+        return (upos, "_");
+    }
+    let tag = pos::tag_token(form);
+    let upos = match tag {
+        Tag::Noun | Tag::Adj
+            if form.contains(['\'', '’']) && form.starts_with(char::is_uppercase) =>
+        {
+            "PROPN"
+        }
+        Tag::Noun => "NOUN",
+        Tag::Verb => "VERB",
+        Tag::Adj => "ADJ",
+        Tag::Adv => "ADV",
+        Tag::Pron => "PRON",
+        Tag::Punc => "PUNCT",
+        Tag::Num => "NUM",
+    };
+    (upos, tag.as_str())
+}
+
+/// FEATS of a word token from its preferred parse
+fn word_features(form: &str, kind: &str) -> String {
+    if kind != "WORD" {
+        return "_".to_string();
+    }
+    match analyzer::analyze(form).first() {
+        Some(analysis) => features(analysis),
+        None => "_".to_string(),
+    }
+}
+
+/// Tabs and newlines would break the columns; UD writes tokens without them
+fn column_value(value: &str) -> String {
+    if value.is_empty() {
+        return "_".to_string();
+    }
+    value.replace(['\t', '\n', '\r'], " ")
+}
+
+/// Append one sentence: `# sent_id` (when given) and `# text` comments,
+/// a line per token, and the blank line that ends the sentence
+pub fn write_sentence(out: &mut String, sent_id: Option<&str>, text: &str, tokens: &[Token]) {
+    if let Some(sent_id) = sent_id {
+        out.push_str(&format!("# sent_id = {}\n", column_value(sent_id)));
+    }
+    out.push_str(&format!(
+        "# text = {}\n",
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    ));
+    for (i, token) in tokens.iter().enumerate() {
+        let (upos, xpos) = tags(token.form, token.kind);
+        let glued = tokens
+            .get(i + 1)
+            .is_some_and(|next| next.start == token.end);
+        let columns = [
+            (i + 1).to_string(),
+            column_value(token.form),
+            column_value(token.lemma),
+            upos.to_string(),
+            xpos.to_string(),
+            word_features(token.form, token.kind),
+            "_".to_string(),
+            "_".to_string(),
+            "_".to_string(),
+            if glued { "SpaceAfter=No" } else { "_" }.to_string(),
+        ];
+        out.push_str(&columns.join("\t"));
+        out.push('\n');
+    }
+    out.push('\n');
+}
+
+/// A column `reannotate` can rewrite
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Lemma,
+    Upos,
+    Xpos,
+    Feats,
+}
+
+impl Column {
+    pub const ALL: [Column; 4] = [Column::Lemma, Column::Upos, Column::Xpos, Column::Feats];
+
+    pub fn parse(name: &str) -> Option<Column> {
+        match name {
+            "lemma" => Some(Column::Lemma),
+            "upos" => Some(Column::Upos),
+            "xpos" => Some(Column::Xpos),
+            "feats" => Some(Column::Feats),
+            _ => None,
+        }
+    }
+
+    /// Position among the ten columns
+    fn index(self) -> usize {
+        match self {
+            Column::Lemma => 2,
+            Column::Upos => 3,
+            Column::Xpos => 4,
+            Column::Feats => 5,
+        }
+    }
+}
+
+/// A sentence as read: its comment lines and its rows of ten columns
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Sentence {
+    /// Comment lines, with the leading '#'
+    pub comments: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Range of a multiword token row ("3-4"), None for other rows
+fn range(id: &str) -> Option<(usize, usize)> {
+    let (first, last) = id.split_once('-')?;
+    Some((first.parse().ok()?, last.parse().ok()?))
+}
+
+fn space_after(row: &[String]) -> bool {
+    !row[9].split('|').any(|item| item == "SpaceAfter=No")
+}
+
+impl Sentence {
+    /// Rows of the surface tokens: multiword tokens instead of the words
+    /// they span, no empty nodes
+    pub fn surface_rows(&self) -> Vec<&[String]> {
+        let mut rows = Vec::new();
+        let mut covered = 0;
+        for row in &self.rows {
+            if let Some((_, last)) = range(&row[0]) {
+                covered = last;
+                rows.push(row.as_slice());
+            } else if let Ok(id) = row[0].parse::<usize>() {
+                if id > covered {
+                    rows.push(row.as_slice());
+                }
+            }
+        }
+        rows
+    }
+
+    /// The text of the surface tokens, one space between them except after
+    /// `SpaceAfter=No`, with each token's character offsets
+    pub fn text(&self) -> (String, Vec<(usize, usize)>) {
+        let rows = self.surface_rows();
+        let mut text = String::new();
+        let mut offsets = Vec::with_capacity(rows.len());
+        let mut chars = 0;
+        for (i, row) in rows.iter().enumerate() {
+            let start = chars;
+            text.push_str(&row[1]);
+            chars += row[1].chars().count();
+            offsets.push((start, chars));
+            if i + 1 < rows.len() && space_after(row) {
+                text.push(' ');
+                chars += 1;
+            }
+        }
+        (text, offsets)
+    }
+}
+
+/// Split CoNLL-U input into sentences; errors name the first line that is
+/// not a comment, a blank line or ten tab-separated columns
+pub fn parse(input: &str) -> Result<Vec<Sentence>, String> {
+    let mut sentences = Vec::new();
+    let mut sentence = Sentence::default();
+    for (number, line) in input.lines().enumerate() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if line.trim().is_empty() {
+            if !sentence.rows.is_empty() || !sentence.comments.is_empty() {
+                sentences.push(std::mem::take(&mut sentence));
+            }
+        } else if line.starts_with('#') {
+            sentence.comments.push(line.to_string());
+        } else {
+            let row: Vec<String> = line.split('\t').map(str::to_string).collect();
+            if row.len() != 10 {
+                return Err(format!(
+                    "line {}: expected 10 columns, got {}",
+                    number + 1,
+                    row.len()
+                ));
+            }
+            sentence.rows.push(row);
+        }
+    }
+    if !sentence.rows.is_empty() || !sentence.comments.is_empty() {
+        sentences.push(sentence);
+    }
+    Ok(sentences)
+}
+
+/// Append a parsed sentence as read, with the blank line that ends it
+pub fn write_parsed(out: &mut String, sentence: &Sentence) {
+    for comment in &sentence.comments {
+        out.push_str(comment);
+        out.push('\n');
+    }
+    for row in &sentence.rows {
+        out.push_str(&row.join("\t"));
+        out.push('\n');
+    }
+    out.push('\n');
+}
+
+/// Recompute `columns` for the words of a sentence from their FORM.
+/// Multiword tokens and the words they span keep their annotation, since
+/// durak analyzes whole surface words, as do empty nodes.
+pub fn reannotate(sentence: &mut Sentence, columns: &[Column]) {
+    let mut covered = 0;
+    for row in &mut sentence.rows {
+        if let Some((_, last)) = range(&row[0]) {
+            covered = last;
+            continue;
+        }
+        match row[0].parse::<usize>() {
+            Ok(id) if id > covered => {}
+            _ => continue,
+        }
+        let form = row[1].as_str();
+        // Forms the tokenizer would split further are analyzed as words
+        let kind = crate::token_kind(form).unwrap_or("WORD");
+        let (upos, xpos) = tags(form, kind);
+        let values: Vec<(usize, String)> = columns
+            .iter()
+            .map(|&column| {
+                let value = match column {
+                    Column::Lemma => column_value(&crate::token_lemma(
+                        form,
+                        kind,
+                        &crate::fast_normalize(form, true, true),
+                        true,
+                    )),
+                    Column::Upos => upos.to_string(),
+                    Column::Xpos => xpos.to_string(),
+                    Column::Feats => word_features(form, kind),
+                };
+                (column.index(), value)
+            })
+            .collect();
+        for (index, value) in values {
+            row[index] = value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feats(word: &str) -> String {
+        features(&analyzer::analyze(word)[0])
+    }
+
+    #[test]
+    fn test_features() {
+        assert_eq!(
+            feats("evlerimizden"),
+            "Case=Abl|Number=Plur|Number[psor]=Plur|Person=3|Person[psor]=1"
+        );
+        assert_eq!(
+            feats("gelmeyecekler"),
+            "Number=Plur|Person=3|Polarity=Neg|Tense=Fut"
+        );
+        assert_eq!(
+            feats("geldim"),
+            "Evident=Fh|Number=Sing|Person=1|Polarity=Pos|Tense=Past"
+        );
+        assert_eq!(feats("evdeki"), "_");
+        assert_eq!(feats("gelince"), "VerbForm=Conv");
+    }
+
+    #[test]
+    fn test_write_sentence() {
+        let tokens = [
+            Token {
+                form: "Ankara'dan",
+                lemma: "ankara",
+                kind: "WORD",
+                start: 0,
+                end: 10,
+            },
+            Token {
+                form: "geldim",
+                lemma: "gel",
+                kind: "WORD",
+                start: 11,
+                end: 17,
+            },
+            Token {
+                form: ".",
+                lemma: ".",
+                kind: "PUNCT",
+                start: 17,
+                end: 18,
+            },
+        ];
+        let mut out = String::new();
+        write_sentence(&mut out, Some("1"), "Ankara'dan  geldim.", &tokens);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], "# sent_id = 1");
+        assert_eq!(lines[1], "# text = Ankara'dan geldim.");
+        assert_eq!(
+            lines[2],
+            "1\tAnkara'dan\tankara\tPROPN\tNoun\t_\t_\t_\t_\t_"
+        );
+        assert!(lines[3].starts_with("2\tgeldim\tgel\tVERB\tVerb\tEvident=Fh|"));
+        assert!(lines[3].ends_with("\t_\t_\t_\tSpaceAfter=No"));
+        assert_eq!(lines[4], "3\t.\t.\tPUNCT\t_\t_\t_\t_\t_\t_");
+        assert!(out.ends_with("\n\n"));
+    }
+
+    const TREEBANK: &str = "# sent_id = 7\n# text = Evdeydim, geldim.\n\
+        1-2\tEvdeydim\t_\t_\t_\t_\t_\t_\t_\tSpaceAfter=No\n\
+        1\tEvde\tev\tNOUN\t_\tCase=Loc\t2\tobl\t_\t_\n\
+        2\tydim\ti\tAUX\t_\t_\t0\troot\t_\t_\n\
+        3\t,\t,\tPUNCT\t_\t_\t4\tpunct\t_\t_\n\
+        4\tgeldim\tgelmek\tNOUN\tNoun\t_\t2\tconj\t_\tSpaceAfter=No\n\
+        5\t.\t.\tPUNCT\t_\t_\t2\tpunct\t_\t_\n\n";
+
+    #[test]
+    fn test_parse_round_trip() {
+        let sentences = parse(TREEBANK).unwrap();
+        assert_eq!(sentences.len(), 1);
+        let mut out = String::new();
+        write_parsed(&mut out, &sentences[0]);
+        assert_eq!(out, TREEBANK);
+
+        let (text, offsets) = sentences[0].text();
+        assert_eq!(text, "Evdeydim, geldim.");
+        assert_eq!(offsets, [(0, 8), (8, 9), (10, 16), (16, 17)]);
+
+        assert_eq!(
+            parse("1\tev\n").unwrap_err(),
+            "line 1: expected 10 columns, got 2"
+        );
+        assert_eq!(parse("\r\n\n").unwrap(), []);
+    }
+
+    #[test]
+    fn test_reannotate() {
+        let mut sentence = parse(TREEBANK).unwrap().remove(0);
+        reannotate(&mut sentence, &[Column::Lemma, Column::Upos]);
+        let geldim = &sentence.rows[4];
+        assert_eq!(geldim[1..6], ["geldim", "gel", "VERB", "Noun", "_"]);
+        // Syntax columns, multiword tokens and their words are untouched
+        assert_eq!(geldim[6..], ["2", "conj", "_", "SpaceAfter=No"]);
+        assert_eq!(sentence.rows[1][2..4], ["ev", "NOUN"]);
+        assert_eq!(sentence.rows[3][3], "PUNCT");
+    }
+}
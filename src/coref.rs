@@ -0,0 +1,220 @@
+//! Heuristic pronoun and zero-subject linking
+//!
+//! Ties third person singular anaphors to the most recent person or
+//! organization name before them (from `ner`), at most `window` sentences
+//! back:
+//!
+//! - Pronouns: the case forms of "o" and of reflexive "kendisi" ("onu",
+//!   "ona", "kendisine"). An "o" followed by a noun or adjective in its
+//!   clause is a demonstrative ("o kitap") and is skipped.
+//! - Zero subjects: Turkish drops subject pronouns, so the finite 3rd person
+//!   singular verb closing a clause ("gitti") stands for its subject when
+//!   the clause has none. A clause has a subject when it contains a name
+//!   without an apostrophe suffix, a subject pronoun or a bare noun.
+//!
+//! Plural anaphors ("onlar", "kendileri") and first and second person forms
+//! are not linked. Links are candidates, not resolved chains: an anaphor
+//! always points at a name, never at another anaphor.
+
+use crate::agreement;
+use crate::analyzer;
+use crate::ner::{self, EntityLabel};
+use crate::pos::{self, Tag};
+
+/// Third person singular pronoun forms linked to a name
+const PRONOUNS: &[&str] = &[
+    "o", "onu", "ona", "onun", "ondan", "onda", "onunla", "onla", "kendisi", "kendisini",
+    "kendisine", "kendisinden", "kendisinde", "kendisiyle", "kendisinin",
+];
+
+/// Nominative pronouns that fill the subject of their clause
+const SUBJECT_PRONOUNS: &[&str] = &["ben", "sen", "o", "biz", "siz", "onlar", "kendisi"];
+
+/// Sentence-final punctuation
+const SENTENCE_ENDS: &[char] = &['.', '!', '?', '…'];
+
+/// Kind of anaphor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Pronoun,
+    /// A dropped subject, represented by its verb
+    Zero,
+}
+
+impl Kind {
+    /// Label used by the Python API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Kind::Pronoun => "PRONOUN",
+            Kind::Zero => "ZERO",
+        }
+    }
+}
+
+/// An anaphor and the name it is linked to, with character offsets into
+/// the input text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    pub text: String,
+    pub kind: Kind,
+    pub start: usize,
+    pub end: usize,
+    pub antecedent: String,
+    pub antecedent_start: usize,
+    pub antecedent_end: usize,
+}
+
+/// A person or organization name as a byte range
+struct Name {
+    start: usize,
+    end: usize,
+    /// Followed by an apostrophe suffix ("Ali'ye"), so not a subject
+    inflected: bool,
+}
+
+/// Person and organization names of the text as byte ranges
+fn names(text: &str) -> Vec<Name> {
+    let mut bytes: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+    bytes.push(text.len());
+    ner::extract_entities(text)
+        .into_iter()
+        .filter(|e| matches!(e.label, EntityLabel::Person | EntityLabel::Organization))
+        .map(|e| {
+            let (start, end) = (bytes[e.start], bytes[e.end]);
+            Name {
+                start,
+                end,
+                inflected: text[end..].starts_with(['\'', '’']),
+            }
+        })
+        .collect()
+}
+
+/// Whether a word is a bare (caseless) noun that can be a subject
+fn is_bare_noun(word: &str) -> bool {
+    pos::tag_token(word) == Tag::Noun
+        && analyzer::analyze(word).iter().any(|a| a.morphemes.is_empty())
+}
+
+/// Whether the preferred analysis of a word is a finite 3rd person singular verb
+fn is_third_singular_verb(word: &str) -> bool {
+    analyzer::analyze(word)
+        .first()
+        .is_some_and(|a| agreement::person(a) == Some("A3sg"))
+}
+
+/// Anaphors of `text` linked to the most recent compatible name at most
+/// `window` sentences back, in order of appearance
+///
+/// # Examples
+/// ```text
+/// link("Ayşe Kaya dün geldi. Bugün eve gitti. Onu çok özledik.", 2)
+///     -> [("gitti", Zero, 31, 36, "Ayşe Kaya", 0, 9),
+///         ("Onu", Pronoun, 38, 41, "Ayşe Kaya", 0, 9)]
+/// ```
+pub fn link(text: &str, window: usize) -> Vec<Link> {
+    let names = names(text);
+    let words = agreement::words(text);
+    let sentence_ends: Vec<usize> = text.match_indices(SENTENCE_ENDS).map(|(i, _)| i).collect();
+    let sentence = |byte: usize| sentence_ends.partition_point(|&end| end < byte);
+
+    // Anaphors as (byte start, byte end, kind)
+    let mut anaphors = Vec::new();
+    let mut has_subject = false;
+    for (i, word) in words.iter().enumerate() {
+        if let Some(name) = names.iter().find(|n| n.start <= word.start && word.start < n.end) {
+            has_subject |= !name.inflected;
+        } else if PRONOUNS.contains(&word.lower.as_str()) {
+            let demonstrative = word.lower == "o"
+                && !word.last
+                && words.get(i + 1).is_some_and(|next| {
+                    matches!(pos::tag_token(&next.lower), Tag::Noun | Tag::Adj)
+                });
+            if !demonstrative {
+                anaphors.push((word.start, word.end, Kind::Pronoun));
+            }
+            has_subject |= SUBJECT_PRONOUNS.contains(&word.lower.as_str());
+        } else if word.last {
+            if !has_subject && is_third_singular_verb(&word.lower) {
+                anaphors.push((word.start, word.end, Kind::Zero));
+            }
+        } else {
+            has_subject |= SUBJECT_PRONOUNS.contains(&word.lower.as_str())
+                || is_bare_noun(&word.lower);
+        }
+        if word.last {
+            has_subject = false;
+        }
+    }
+
+    let chars = |byte: usize| text[..byte].chars().count();
+    anaphors
+        .into_iter()
+        .filter_map(|(start, end, kind)| {
+            let first_sentence = sentence(start).saturating_sub(window);
+            let name = names
+                .iter()
+                .rev()
+                .find(|n| n.end <= start && sentence(n.start) >= first_sentence)?;
+            Some(Link {
+                text: text[start..end].to_string(),
+                kind,
+                start: chars(start),
+                end: chars(end),
+                antecedent: text[name.start..name.end].to_string(),
+                antecedent_start: chars(name.start),
+                antecedent_end: chars(name.end),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn links(text: &str, window: usize) -> Vec<(String, &'static str, String)> {
+        link(text, window)
+            .into_iter()
+            .map(|l| (l.text, l.kind.as_str(), l.antecedent))
+            .collect()
+    }
+
+    #[test]
+    fn test_pronouns_and_zero_subjects() {
+        assert_eq!(
+            links("Ayşe Kaya dün geldi. Bugün eve gitti. Onu çok özledik.", 2),
+            vec![
+                ("gitti".to_string(), "ZERO", "Ayşe Kaya".to_string()),
+                ("Onu".to_string(), "PRONOUN", "Ayşe Kaya".to_string()),
+            ]
+        );
+        // The most recent name wins
+        assert_eq!(links("Ali, Mehmet'i aradı. Kendisine kızgındı.", 2)[0].2, "Mehmet");
+    }
+
+    #[test]
+    fn test_skipped_anaphors() {
+        for text in [
+            // Demonstrative "o"
+            "Ali geldi. O kitabı okudum.",
+            // Explicit subjects
+            "Ali geldi. Kitap masaya düştü.",
+            "Ali geldi. Ben gittim.",
+            // No name before the anaphor
+            "O geldi. Ali gitti.",
+        ] {
+            assert!(link(text, 2).iter().all(|l| l.start < 5), "{}", text);
+        }
+        assert!(link("Ali geldi. Hava güzel. Yağmur yağdı. Onu gördüm.", 1).is_empty());
+    }
+
+    #[test]
+    fn test_char_offsets() {
+        let found = link("Şule Öztürk geldi. Onu gördük.", 2);
+        assert_eq!(
+            (found[0].start, found[0].end, found[0].antecedent_start, found[0].antecedent_end),
+            (19, 22, 0, 11)
+        );
+    }
+}
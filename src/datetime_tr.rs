@@ -0,0 +1,247 @@
+//! Turkish date and time expressions
+//!
+//! Recognizes the date and time formats of Turkish text and normalizes them
+//! to ISO 8601:
+//!
+//! - Dates: "2024-03-12", "12.03.2024", "12/03/2024", "12 Mart 2024",
+//!   "12 Mart" and "Mart 2024" (→ "2024-03-12", "--03-12", "2024-03")
+//! - Times: "14:30", "14:30:15" and "saat 14:30", "saat 14.30", "saat 9"
+//!   (→ "14:30", "14:30:15", "09:00")
+//!
+//! The patterns are shared with the tokenizer, which keeps each expression
+//! as a single DATE or TIME token, and with named entity recognition.
+//! Numeric dates are read day first. The tokenizer matches the patterns
+//! alone; `extract_dates` also checks the calendar, so "31.02.2024" is a
+//! DATE token but not an extracted date.
+
+use crate::{fast_normalize, fast_uppercase};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const MONTHS: &[&str] = &[
+    "ocak", "şubat", "mart", "nisan", "mayıs", "haziran", "temmuz", "ağustos", "eylül", "ekim",
+    "kasım", "aralık",
+];
+
+const DAY: &str = r"(?:0?[1-9]|[12]\d|3[01])";
+const MONTH_NUMBER: &str = r"(?:0?[1-9]|1[0-2])";
+const HOUR: &str = r"(?:[01]?\d|2[0-3])";
+
+static DATE_PATTERN: OnceLock<String> = OnceLock::new();
+static TIME_PATTERN: OnceLock<String> = OnceLock::new();
+static DATE_REGEX: OnceLock<Regex> = OnceLock::new();
+static TIME_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// Kind of expression
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Date,
+    Time,
+}
+
+impl Kind {
+    /// Label used by the tokenizer and the Python API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Kind::Date => "DATE",
+            Kind::Time => "TIME",
+        }
+    }
+}
+
+/// A recognized expression with character offsets into the input text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expression {
+    pub text: String,
+    pub kind: Kind,
+    /// ISO 8601 form: "2024-03-12", "--03-12" without a year, "2024-03"
+    /// without a day, "14:30" or "14:30:15"
+    pub iso: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Month names in lowercase, capitalized and uppercase spelling
+fn month_names() -> String {
+    MONTHS
+        .iter()
+        .flat_map(|month| {
+            let mut chars = month.chars();
+            let first = chars.next().map(|c| fast_uppercase(&c.to_string())).unwrap_or_default();
+            [month.to_string(), first + chars.as_str(), fast_uppercase(month)]
+        })
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+/// Date pattern without capture groups, usable in verbose mode
+pub fn date_pattern() -> &'static str {
+    DATE_PATTERN.get_or_init(|| {
+        let months = month_names();
+        [
+            r"\b\d{4}-(?:0[1-9]|1[0-2])-(?:0[1-9]|[12]\d|3[01])\b".to_string(),
+            format!(r"\b{DAY}\.{MONTH_NUMBER}\.\d{{4}}\b"),
+            format!(r"\b{DAY}/{MONTH_NUMBER}/\d{{4}}\b"),
+            format!(r"\b{DAY}\x20(?:{months})(?:\x20\d{{4}})?\b"),
+            format!(r"\b(?:{months})\x20\d{{4}}\b"),
+        ]
+        .join("|")
+    })
+}
+
+/// Time pattern without capture groups, usable in verbose mode
+pub fn time_pattern() -> &'static str {
+    TIME_PATTERN.get_or_init(|| {
+        [
+            format!(r"\b(?:[Ss]aat|SAAT)\x20{HOUR}(?:[:.][0-5]\d)?\b"),
+            format!(r"\b{HOUR}:[0-5]\d(?::[0-5]\d)?\b"),
+        ]
+        .join("|")
+    })
+}
+
+pub fn date_regex() -> &'static Regex {
+    DATE_REGEX.get_or_init(|| Regex::new(date_pattern()).expect("Invalid date regex"))
+}
+
+fn time_regex() -> &'static Regex {
+    TIME_REGEX.get_or_init(|| Regex::new(time_pattern()).expect("Invalid time regex"))
+}
+
+fn days_in_month(year: Option<u32>, month: u32) -> u32 {
+    match month {
+        2 => match year {
+            Some(y) if !(y % 4 == 0 && (y % 100 != 0 || y % 400 == 0)) => 28,
+            _ => 29,
+        },
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// ISO form of a matched date, or None for a day the month does not have
+fn date_iso(text: &str) -> Option<String> {
+    let lower = fast_normalize(text, true, true);
+    let parts: Vec<&str> = lower.split(['-', '.', '/', ' ']).collect();
+    let number = |part: &str| part.parse::<u32>().ok();
+    let month_of = |part: &str| MONTHS.iter().position(|&m| m == part).map(|i| i as u32 + 1);
+
+    let (year, month, day) = match parts.as_slice() {
+        [y, m, d] if y.len() == 4 => (number(y), number(m)?, number(d)),
+        [d, m, y] => (number(y), month_of(m).or_else(|| number(m))?, number(d)),
+        [d, m] if number(d).is_some() => (None, month_of(m)?, number(d)),
+        [m, y] => (number(y), month_of(m)?, None),
+        _ => return None,
+    };
+    match (year, day) {
+        (Some(y), None) => Some(format!("{:04}-{:02}", y, month)),
+        (_, Some(d)) if d > days_in_month(year, month) => None,
+        (Some(y), Some(d)) => Some(format!("{:04}-{:02}-{:02}", y, month, d)),
+        (None, Some(d)) => Some(format!("--{:02}-{:02}", month, d)),
+        (None, None) => None,
+    }
+}
+
+/// ISO form of a matched time
+fn time_iso(text: &str) -> Option<String> {
+    let clock = text.rsplit(' ').next()?;
+    let parts: Vec<u32> = clock.split([':', '.']).map(|p| p.parse().ok()).collect::<Option<_>>()?;
+    match parts.as_slice() {
+        [h] => Some(format!("{:02}:00", h)),
+        [h, m] => Some(format!("{:02}:{:02}", h, m)),
+        [h, m, s] => Some(format!("{:02}:{:02}:{:02}", h, m, s)),
+        _ => None,
+    }
+}
+
+/// ISO form of an expression matched by the date or time pattern
+pub fn normalize(text: &str, kind: Kind) -> Option<String> {
+    match kind {
+        Kind::Date => date_iso(text),
+        Kind::Time => time_iso(text),
+    }
+}
+
+/// Dates and times of `text` in order of appearance
+///
+/// # Examples
+/// ```text
+/// extract("3 Mart 2024 saat 14:30") -> [("3 Mart 2024", Date, "2024-03-03", 0, 11),
+///                                       ("saat 14:30", Time, "14:30", 12, 22)]
+/// ```
+pub fn extract(text: &str) -> Vec<Expression> {
+    let mut spans: Vec<(usize, usize, Kind)> = Vec::new();
+    for (regex, kind) in [(date_regex(), Kind::Date), (time_regex(), Kind::Time)] {
+        for mat in regex.find_iter(text) {
+            if !spans.iter().any(|&(s, e, _)| mat.start() < e && s < mat.end()) {
+                spans.push((mat.start(), mat.end(), kind));
+            }
+        }
+    }
+    spans.sort_unstable_by_key(|&(start, _, _)| start);
+
+    spans
+        .into_iter()
+        .filter_map(|(start, end, kind)| {
+            let iso = normalize(&text[start..end], kind)?;
+            let char_start = text[..start].chars().count();
+            Some(Expression {
+                text: text[start..end].to_string(),
+                kind,
+                iso,
+                start: char_start,
+                end: char_start + text[start..end].chars().count(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iso(text: &str) -> Vec<(String, &'static str, String)> {
+        extract(text).into_iter().map(|e| (e.text, e.kind.as_str(), e.iso)).collect()
+    }
+
+    #[test]
+    fn test_dates() {
+        let expected = |text: &str, iso: &str| vec![(text.to_string(), "DATE", iso.to_string())];
+        assert_eq!(iso("2024-03-12"), expected("2024-03-12", "2024-03-12"));
+        assert_eq!(iso("Tarih: 12.03.2024."), expected("12.03.2024", "2024-03-12"));
+        assert_eq!(iso("1/3/2024"), expected("1/3/2024", "2024-03-01"));
+        assert_eq!(iso("3 Mart 2024'te"), expected("3 Mart 2024", "2024-03-03"));
+        assert_eq!(iso("29 ŞUBAT günü"), expected("29 ŞUBAT", "--02-29"));
+        assert_eq!(iso("Ağustos 2023"), expected("Ağustos 2023", "2023-08"));
+    }
+
+    #[test]
+    fn test_invalid_dates() {
+        assert!(iso("31.02.2024").is_empty());
+        assert!(iso("29.02.2023").is_empty());
+        assert_eq!(iso("29.02.2024")[0].2, "2024-02-29");
+        assert!(iso("32.01.2024 13.13.2024 2024-13-01").is_empty());
+        assert!(iso("3 martı").is_empty());
+    }
+
+    #[test]
+    fn test_times() {
+        assert_eq!(
+            iso("Toplantı saat 14:30'da, mola 9:05:30"),
+            vec![
+                ("saat 14:30".to_string(), "TIME", "14:30".to_string()),
+                ("9:05:30".to_string(), "TIME", "09:05:30".to_string()),
+            ]
+        );
+        assert_eq!(iso("Saat 9")[0].2, "09:00");
+        assert_eq!(iso("saat 14.30")[0].2, "14:30");
+        assert!(iso("24:00 ve 12:60").is_empty());
+    }
+
+    #[test]
+    fn test_char_offsets() {
+        let found = extract("Çarşamba 3 Mart 2024 saat 14:30");
+        let spans: Vec<(usize, usize)> = found.iter().map(|e| (e.start, e.end)).collect();
+        assert_eq!(spans, vec![(9, 20), (21, 31)]);
+    }
+}
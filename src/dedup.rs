@@ -0,0 +1,225 @@
+//! Near-duplicate detection with MinHash and LSH
+//!
+//! A document is reduced to the set of its word shingles (`shingle_size`
+//! consecutive tokens) and summarized by a MinHash signature: the minimum
+//! of `num_perm` independent hashes over that set. The fraction of equal
+//! signature slots estimates the Jaccard similarity of two shingle sets.
+//!
+//! Signatures are split into bands of rows (locality-sensitive hashing);
+//! two documents become candidates when one band agrees, and candidates
+//! are kept when their estimated similarity reaches the threshold. The band
+//! layout is chosen so the LSH threshold `(1 / bands) ^ (1 / rows)` is
+//! closest to the requested one.
+//!
+//! Hashing is FNV-1a with SplitMix64-derived seeds, fixed here rather than
+//! taken from `std`, so signatures are the same on every platform and in
+//! every release for a given seed.
+
+use crate::sampling::SplitMix64;
+use std::collections::HashMap;
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// SplitMix64 finalizer, a cheap independent hash per seed
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Bands and rows per band for `num_perm` slots whose LSH threshold is
+/// closest to `threshold`
+fn band_layout(num_perm: usize, threshold: f64) -> (usize, usize) {
+    (1..=num_perm)
+        .filter(|rows| num_perm % rows == 0)
+        .map(|rows| (num_perm / rows, rows))
+        .min_by(|&(b1, r1), &(b2, r2)| {
+            let error =
+                |b: usize, r: usize| ((1.0 / b as f64).powf(1.0 / r as f64) - threshold).abs();
+            error(b1, r1).total_cmp(&error(b2, r2))
+        })
+        .unwrap_or((1, num_perm))
+}
+
+#[derive(Debug, Clone)]
+pub struct Deduplicator {
+    threshold: f64,
+    shingle_size: usize,
+    seeds: Vec<u64>,
+    rows: usize,
+    /// Signatures of the distinct documents, with their ids
+    kept: Vec<(usize, Vec<u64>)>,
+    /// Per band: band hash → positions in `kept`
+    buckets: Vec<HashMap<u64, Vec<usize>>>,
+    /// Documents added so far, duplicates included
+    added: usize,
+}
+
+impl Deduplicator {
+    /// Errors unless `threshold` is in (0, 1] and `num_perm` and
+    /// `shingle_size` are positive
+    pub fn new(
+        threshold: f64,
+        num_perm: usize,
+        shingle_size: usize,
+        seed: u64,
+    ) -> Result<Self, String> {
+        if !(threshold > 0.0 && threshold <= 1.0) {
+            return Err(format!("threshold must be in (0, 1], got {}", threshold));
+        }
+        if num_perm == 0 || shingle_size == 0 {
+            return Err("num_perm and shingle_size must be positive".to_string());
+        }
+        let mut rng = SplitMix64::new(seed);
+        let seeds = (0..num_perm).map(|_| rng.next_u64()).collect();
+        let (bands, rows) = band_layout(num_perm, threshold);
+        Ok(Self {
+            threshold,
+            shingle_size,
+            seeds,
+            rows,
+            kept: Vec::new(),
+            buckets: vec![HashMap::new(); bands],
+            added: 0,
+        })
+    }
+
+    /// MinHash signature of a token sequence; shorter sequences than a
+    /// shingle form one shingle, and no tokens give an all-`u64::MAX`
+    /// signature
+    pub fn signature<S: AsRef<str>>(&self, tokens: &[S]) -> Vec<u64> {
+        let width = self.shingle_size.min(tokens.len()).max(1);
+        let shingles: Vec<u64> = tokens
+            .windows(width)
+            .map(|window| {
+                let joined: Vec<&str> = window.iter().map(AsRef::as_ref).collect();
+                fnv1a(joined.join(" ").as_bytes())
+            })
+            .collect();
+        self.seeds
+            .iter()
+            .map(|&seed| {
+                shingles
+                    .iter()
+                    .map(|&s| mix(s ^ seed))
+                    .min()
+                    .unwrap_or(u64::MAX)
+            })
+            .collect()
+    }
+
+    /// Estimated Jaccard similarity of two signatures
+    pub fn similarity(a: &[u64], b: &[u64]) -> f64 {
+        if a.is_empty() {
+            return 0.0;
+        }
+        a.iter().zip(b).filter(|(x, y)| x == y).count() as f64 / a.len() as f64
+    }
+
+    fn band_hashes<'a>(&'a self, signature: &'a [u64]) -> impl Iterator<Item = u64> + 'a {
+        signature.chunks(self.rows).map(|band| {
+            let bytes: Vec<u8> = band.iter().flat_map(|v| v.to_le_bytes()).collect();
+            fnv1a(&bytes)
+        })
+    }
+
+    /// The most similar distinct document at or above the threshold, as
+    /// (id, estimated similarity); ties go to the earlier document
+    pub fn query(&self, signature: &[u64]) -> Option<(usize, f64)> {
+        let mut best: Option<(usize, f64)> = None;
+        let mut checked = std::collections::HashSet::new();
+        for (band, hash) in self.band_hashes(signature).enumerate() {
+            for &position in self.buckets[band].get(&hash).into_iter().flatten() {
+                if !checked.insert(position) {
+                    continue;
+                }
+                let (id, kept) = &self.kept[position];
+                let score = Self::similarity(signature, kept);
+                let better = match best {
+                    Some((best_id, best_score)) => {
+                        score > best_score || (score == best_score && *id < best_id)
+                    }
+                    None => true,
+                };
+                if score >= self.threshold && better {
+                    best = Some((*id, score));
+                }
+            }
+        }
+        best
+    }
+
+    /// Add the next document: returns the match of `query`, or indexes the
+    /// document as distinct and returns None. Ids count every added
+    /// document, duplicates included, from 0.
+    pub fn add(&mut self, signature: Vec<u64>) -> Option<(usize, f64)> {
+        let id = self.added;
+        self.added += 1;
+        let found = self.query(&signature);
+        if found.is_none() {
+            let position = self.kept.len();
+            let hashes: Vec<u64> = self.band_hashes(&signature).collect();
+            for (band, hash) in hashes.into_iter().enumerate() {
+                self.buckets[band].entry(hash).or_default().push(position);
+            }
+            self.kept.push((id, signature));
+        }
+        found
+    }
+
+    /// Documents added so far, duplicates included
+    pub fn added(&self) -> usize {
+        self.added
+    }
+
+    /// Distinct documents indexed so far
+    pub fn distinct(&self) -> usize {
+        self.kept.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(text: &str) -> Vec<&str> {
+        text.split(' ').collect()
+    }
+
+    #[test]
+    fn test_band_layout() {
+        assert_eq!(band_layout(128, 0.8), (8, 16));
+        assert_eq!(band_layout(128, 0.5), (32, 4));
+        assert_eq!(band_layout(1, 0.5), (1, 1));
+    }
+
+    #[test]
+    fn test_add() {
+        let mut dedup = Deduplicator::new(0.7, 128, 2, 0).unwrap();
+        let original = "bugün hava çok güzel ve biz parka gittik sonra eve döndük";
+        let near = "bugün hava çok güzel ve biz parka gittik sonra eve geldik";
+        let other = "yarın sabah erkenden toplantı var unutma lütfen";
+        assert_eq!(dedup.add(dedup.signature(&words(original))), None);
+        let (id, score) = dedup.add(dedup.signature(&words(near))).unwrap();
+        assert_eq!(id, 0);
+        assert!(score > 0.7 && score < 1.0);
+        assert_eq!(dedup.add(dedup.signature(&words(other))), None);
+        assert_eq!(dedup.add(dedup.signature(&words(original))), Some((0, 1.0)));
+        assert_eq!((dedup.added(), dedup.distinct()), (4, 2));
+        // Same seed, same signature
+        let again = Deduplicator::new(0.7, 128, 2, 0).unwrap();
+        assert_eq!(
+            again.signature(&words(other)),
+            dedup.signature(&words(other))
+        );
+        assert!(Deduplicator::new(0.0, 128, 2, 0).is_err());
+        assert!(Deduplicator::new(0.5, 0, 2, 0).is_err());
+    }
+}
@@ -0,0 +1,153 @@
+//! Detokenization
+//!
+//! - Exact: each token keeps the text between it and the next token
+//!   (normally whitespace), so joining tokens with their trailing text gives
+//!   back the input byte for byte, also after tokens are masked or removed
+//! - spaCy alignment: words and trailing-space flags as `spacy.tokens.Doc`
+//!   takes them; whitespace other than one trailing space becomes a word of
+//!   its own, as spaCy's tokenizer does
+//! - Heuristic: a plain token list is joined with single spaces except
+//!   before closing punctuation and suffix apostrophes ("ankara ' da" →
+//!   "ankara'da"), after opening brackets and the Turkish percent sign
+//!   ("% 20" → "%20"), and inside double quotes
+
+use crate::TokenSpan;
+use std::ops::Range;
+
+/// Tokens that attach to the token before them
+const NO_SPACE_BEFORE: &[&str] = &[
+    ".", ",", ";", ":", "!", "?", "…", ")", "]", "}", "»", "'", "’",
+];
+
+/// Tokens that attach to the token after them
+const NO_SPACE_AFTER: &[&str] = &["(", "[", "{", "«", "'", "’", "%"];
+
+/// Leading text before the first token, and each token with the text up to
+/// the next token (or the end of the input)
+pub fn with_trailing<'a>(
+    text: &'a str,
+    spans: &[TokenSpan],
+) -> (&'a str, Vec<(&'a str, &'a str)>) {
+    let prefix = &text[..spans.first().map_or(text.len(), |span| span.start)];
+    let tokens = spans
+        .iter()
+        .enumerate()
+        .map(|(i, span)| {
+            let next = spans.get(i + 1).map_or(text.len(), |next| next.start);
+            (&text[span.start..span.end], &text[span.end..next])
+        })
+        .collect();
+    (prefix, tokens)
+}
+
+/// Byte ranges of the words of a spaCy `Doc` and whether each is followed by
+/// a single space; the words and spaces rebuild `text` exactly
+///
+/// # Examples
+/// ```text
+/// "Merhaba  dünya!\n" -> Merhaba+space, " ", dünya, !, "\n"
+/// ```
+pub fn spacy_words(text: &str, spans: &[TokenSpan]) -> Vec<(Range<usize>, bool)> {
+    let (prefix, tokens) = with_trailing(text, spans);
+    let mut words = Vec::new();
+    if !prefix.is_empty() {
+        words.push((0..prefix.len(), false));
+    }
+    for (span, (_, trailing)) in spans.iter().zip(tokens) {
+        let space = trailing.starts_with(' ');
+        words.push((span.start..span.end, space));
+        let rest = span.end + usize::from(space);
+        if rest < span.end + trailing.len() {
+            words.push((rest..span.end + trailing.len(), false));
+        }
+    }
+    words
+}
+
+/// Join tokens into text with Turkish spacing conventions
+///
+/// # Examples
+/// ```text
+/// detokenize(["Merhaba", ",", "dünya", "!"])           -> "Merhaba, dünya!"
+/// detokenize(["(", "Ankara", "'", "da", ")"])          -> "(Ankara'da)"
+/// detokenize(["\"", "Evet", "\"", "dedi", "%", "20"])  -> "\"Evet\" dedi %20"
+/// ```
+pub fn detokenize<S: AsRef<str>>(tokens: &[S]) -> String {
+    let mut text = String::new();
+    let mut open_quote = false;
+    let mut attach_next = true;
+    for token in tokens {
+        let token = token.as_ref();
+        let is_quote = token == "\"";
+        let attach = if is_quote { open_quote } else { NO_SPACE_BEFORE.contains(&token) };
+        if !attach && !attach_next {
+            text.push(' ');
+        }
+        text.push_str(token);
+        attach_next = if is_quote { !open_quote } else { NO_SPACE_AFTER.contains(&token) };
+        if is_quote {
+            open_quote = !open_quote;
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token_spans;
+
+    #[test]
+    fn test_with_trailing_is_exact() {
+        let text = "  İstanbul'da  \"güzel\" bir gün!\n\tYeni satır ";
+        let spans = token_spans(text);
+        let (prefix, tokens) = with_trailing(text, &spans);
+        assert_eq!(prefix, "  ");
+        assert_eq!(tokens[0], ("İstanbul'da", "  "));
+        assert_eq!(tokens[tokens.len() - 1], ("satır", " "));
+        let mut rebuilt = prefix.to_string();
+        for (token, trailing) in &tokens {
+            rebuilt.push_str(token);
+            rebuilt.push_str(trailing);
+        }
+        assert_eq!(rebuilt, text);
+        assert_eq!(with_trailing("   ", &token_spans("   ")), ("   ", vec![]));
+    }
+
+    #[test]
+    fn test_spacy_words() {
+        let text = " Merhaba  dünya!\n";
+        let words: Vec<(&str, bool)> = spacy_words(text, &token_spans(text))
+            .into_iter()
+            .map(|(range, space)| (&text[range], space))
+            .collect();
+        assert_eq!(
+            words,
+            vec![
+                (" ", false),
+                ("Merhaba", true),
+                (" ", false),
+                ("dünya", false),
+                ("!", false),
+                ("\n", false),
+            ]
+        );
+        let rebuilt: String = words
+            .iter()
+            .map(|(word, space)| format!("{}{}", word, if *space { " " } else { "" }))
+            .collect();
+        assert_eq!(rebuilt, text);
+    }
+
+    #[test]
+    fn test_detokenize() {
+        assert_eq!(detokenize(&["Merhaba", ",", "dünya", "!"]), "Merhaba, dünya!");
+        assert_eq!(detokenize(&["(", "Ankara", "'", "da", ")"]), "(Ankara'da)");
+        assert_eq!(
+            detokenize(&["\"", "Evet", "\"", "dedi", "%", "20"]),
+            "\"Evet\" dedi %20"
+        );
+        assert_eq!(detokenize(&["Ne", "?", ".", ".", ".", "Tamam"]), "Ne?... Tamam");
+        assert_eq!(detokenize::<&str>(&[]), "");
+    }
+}
@@ -0,0 +1,197 @@
+//! Dialect and variant cue detection
+//!
+//! Flags tokens that mark a regional variety of Turkish with the cue lexicon
+//! of resources/tr/config/dialect_cues.txt:
+//!
+//! - Whole words in dialectal spelling ("gız" for "kız", "bööle" for "böyle")
+//!   or in their Azerbaijani-influenced form ("çox" for "çok")
+//! - Word-final dialectal suffixes ("gidecağam" for "gideceğim", Black Sea
+//!   "geliyrum" for "geliyorum"), longest suffix first
+//! - Words spelled with the Azerbaijani letter "ə"
+//!
+//! Each cue comes with the standard spelling when the lexicon gives one.
+//! Cues are evidence for a variety, not proof: corpus builders aggregate
+//! them per document to stratify or filter regional variants.
+
+use crate::confusion;
+use crate::turkish_lowercase;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+static DIALECT_CUES_DATA: &str = include_str!("../resources/tr/config/dialect_cues.txt");
+
+static WORDS: OnceLock<HashMap<&'static str, (&'static str, Variety)>> = OnceLock::new();
+static SUFFIXES: OnceLock<Vec<(&'static str, &'static str, Variety)>> = OnceLock::new();
+
+/// Shortest stem a dialectal suffix may attach to
+const MIN_STEM_CHARS: usize = 2;
+
+/// Regional variety a cue points to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variety {
+    Azerbaijani,
+    /// Spellings shared by many Anatolian dialects
+    Anatolian,
+    Eastern,
+    BlackSea,
+}
+
+impl Variety {
+    pub fn parse(name: &str) -> Option<Variety> {
+        match name {
+            "AZERBAIJANI" => Some(Variety::Azerbaijani),
+            "ANATOLIAN" => Some(Variety::Anatolian),
+            "EASTERN" => Some(Variety::Eastern),
+            "BLACK_SEA" => Some(Variety::BlackSea),
+            _ => None,
+        }
+    }
+
+    /// Label used by the lexicon and the Python API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Variety::Azerbaijani => "AZERBAIJANI",
+            Variety::Anatolian => "ANATOLIAN",
+            Variety::Eastern => "EASTERN",
+            Variety::BlackSea => "BLACK_SEA",
+        }
+    }
+}
+
+/// A dialect cue with character offsets into the input text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cue {
+    pub text: String,
+    pub variety: Variety,
+    /// Standard spelling in the casing of the text, None when unknown
+    pub standard: Option<String>,
+    pub start: usize,
+    pub end: usize,
+}
+
+fn entries() -> impl Iterator<Item = (&'static str, &'static str, Variety)> {
+    DIALECT_CUES_DATA
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let (form, standard, variety) = (fields.next()?, fields.next()?, fields.next()?);
+            Some((form, standard, Variety::parse(variety)?))
+        })
+}
+
+fn words() -> &'static HashMap<&'static str, (&'static str, Variety)> {
+    WORDS.get_or_init(|| {
+        entries()
+            .filter(|(form, _, _)| !form.starts_with('-'))
+            .map(|(form, standard, variety)| (form, (standard, variety)))
+            .collect()
+    })
+}
+
+/// Dialectal suffixes without their "-", longest first
+fn suffixes() -> &'static [(&'static str, &'static str, Variety)] {
+    SUFFIXES.get_or_init(|| {
+        let mut suffixes: Vec<_> = entries()
+            .filter_map(|(form, standard, variety)| {
+                Some((form.strip_prefix('-')?, standard.strip_prefix('-')?, variety))
+            })
+            .collect();
+        suffixes.sort_by_key(|&(suffix, _, _)| std::cmp::Reverse(suffix.chars().count()));
+        suffixes
+    })
+}
+
+/// Variety and standard spelling of a single token, None when it carries
+/// no dialect cue
+///
+/// # Examples
+/// ```text
+/// flag("Gız")       -> Some((Anatolian, Some("Kız")))
+/// flag("gidecağam") -> Some((Eastern, Some("gideceğim")))
+/// flag("kız")       -> None
+/// ```
+pub fn flag(token: &str) -> Option<(Variety, Option<String>)> {
+    let lower = turkish_lowercase(token);
+    if let Some(&(standard, variety)) = words().get(lower.as_str()) {
+        return Some((variety, Some(confusion::match_case(token, standard))));
+    }
+    for &(suffix, standard, variety) in suffixes() {
+        let Some(stem) = lower.strip_suffix(suffix) else {
+            continue;
+        };
+        if stem.chars().count() >= MIN_STEM_CHARS {
+            let word = format!("{}{}", stem, standard);
+            return Some((variety, Some(confusion::match_case(token, &word))));
+        }
+    }
+    lower.contains('ə').then_some((Variety::Azerbaijani, None))
+}
+
+/// Dialect cues of `text` in order of appearance
+pub fn detect(text: &str) -> Vec<Cue> {
+    let mut cues = Vec::new();
+    let mut char_position = 0;
+    let mut byte_position = 0;
+    for mat in crate::words(text) {
+        char_position += text[byte_position..mat.start()].chars().count();
+        byte_position = mat.start();
+        let Some((variety, standard)) = flag(mat.as_str()) else {
+            continue;
+        };
+        cues.push(Cue {
+            text: mat.as_str().to_string(),
+            variety,
+            standard,
+            start: char_position,
+            end: char_position + mat.as_str().chars().count(),
+        });
+    }
+    cues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flagged(token: &str) -> Option<(&'static str, Option<String>)> {
+        flag(token).map(|(variety, standard)| (variety.as_str(), standard))
+    }
+
+    #[test]
+    fn test_lexicon_parses() {
+        let lines = DIALECT_CUES_DATA
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .count();
+        assert_eq!(entries().count(), lines);
+    }
+
+    #[test]
+    fn test_word_cues() {
+        assert_eq!(flagged("Gız"), Some(("ANATOLIAN", Some("Kız".to_string()))));
+        assert_eq!(flagged("ÇOX"), Some(("AZERBAIJANI", Some("ÇOK".to_string()))));
+        assert_eq!(flagged("gəlirəm"), Some(("AZERBAIJANI", None)));
+        for standard in ["kız", "çok", "böyle", "gideceğim", "geliyorum", "Uşak"] {
+            assert_eq!(flagged(standard), None, "{}", standard);
+        }
+    }
+
+    #[test]
+    fn test_suffix_cues() {
+        assert_eq!(flagged("gidecağam"), Some(("EASTERN", Some("gideceğim".to_string()))));
+        assert_eq!(flagged("yapayrum"), Some(("BLACK_SEA", Some("yapıyorum".to_string()))));
+        assert_eq!(flagged("geliyrum").unwrap().1.as_deref(), Some("geliyorum"));
+        // The stem must be at least two letters long
+        assert_eq!(flagged("ayrum"), None);
+    }
+
+    #[test]
+    fn test_detect_offsets() {
+        let cues = detect("Çarşıda gız bööle dedi");
+        let spans: Vec<(&str, usize, usize)> =
+            cues.iter().map(|c| (c.text.as_str(), c.start, c.end)).collect();
+        assert_eq!(spans, vec![("gız", 8, 11), ("bööle", 12, 17)]);
+    }
+}
@@ -0,0 +1,95 @@
+//! Emoji grapheme clusters
+//!
+//! An emoji can be several code points: a base pictograph with skin tone
+//! modifiers and variation selectors, ZWJ sequences (👨‍👩‍👧), flags made of
+//! two regional indicators (🇹🇷) and keycaps (1️⃣). The tokenizer regex sees
+//! these code point by code point; `cluster_at` extends a token starting at
+//! an emoji to its whole extended grapheme cluster (UAX #29).
+//!
+//! A cluster is an emoji when it starts with a pictographic character, is a
+//! keycap, or is a text symbol followed by U+FE0F (emoji presentation, as in
+//! ❤️ or ©️). `durak.tokenizer.EMOJI_TOKEN` follows the same rules.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+const VARIATION_SELECTOR_16: char = '\u{FE0F}';
+const COMBINING_KEYCAP: char = '\u{20E3}';
+
+/// Characters drawn as emoji by default: the pictographic blocks (which
+/// include regional indicators and skin tone modifiers) and the emoji
+/// scattered through the symbol blocks
+pub fn is_pictographic(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1F000}'..='\u{1FAFF}'
+            | '\u{1FC00}'..='\u{1FFFD}'
+            | '\u{2600}'..='\u{27BF}'
+            | '\u{231A}'..='\u{231B}'
+            | '\u{23E9}'..='\u{23F3}'
+            | '\u{23F8}'..='\u{23FA}'
+            | '\u{2934}'..='\u{2935}'
+            | '\u{2B05}'..='\u{2B07}'
+            | '\u{2B1B}'..='\u{2B1C}'
+            | '\u{2B50}'
+            | '\u{2B55}'
+            | '\u{3030}'
+            | '\u{303D}'
+            | '\u{3297}'
+            | '\u{3299}'
+    )
+}
+
+fn is_emoji_cluster(cluster: &str) -> bool {
+    let mut chars = cluster.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    if is_pictographic(first) {
+        return true;
+    }
+    if matches!(first, '0'..='9' | '#' | '*') && cluster.contains(COMBINING_KEYCAP) {
+        return true;
+    }
+    !first.is_alphanumeric()
+        && first != '_'
+        && !first.is_whitespace()
+        && chars.next() == Some(VARIATION_SELECTOR_16)
+}
+
+/// Byte index just past the emoji cluster starting at `start`, if one does
+pub fn cluster_at(text: &str, start: usize) -> Option<usize> {
+    let cluster = text[start..].graphemes(true).next()?;
+    is_emoji_cluster(cluster).then(|| start + cluster.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cluster(text: &str) -> Option<&str> {
+        cluster_at(text, 0).map(|end| &text[..end])
+    }
+
+    #[test]
+    fn test_multi_code_point_emoji() {
+        let emoji = ["👍🏽", "👨‍👩‍👧", "🇹🇷", "❤️", "1️⃣", "🏳️‍🌈", "🧑🏻‍💻"];
+        for emoji in emoji {
+            let text = format!("{}!", emoji);
+            assert_eq!(cluster(&text), Some(emoji), "{}", emoji);
+        }
+    }
+
+    #[test]
+    fn test_adjacent_emoji_are_separate() {
+        assert_eq!(cluster("😀😀"), Some("😀"));
+        assert_eq!(cluster("🇹🇷🇩🇪"), Some("🇹🇷"));
+    }
+
+    #[test]
+    fn test_non_emoji() {
+        for text in ["a", "1", "12", "©", "?", "ğ", "g\u{306}", ""] {
+            assert_eq!(cluster(text), None, "{:?}", text);
+        }
+        assert_eq!(cluster("©\u{FE0F}"), Some("©\u{FE0F}"));
+    }
+}
@@ -0,0 +1,149 @@
+//! Loanword origin tags
+//!
+//! Tags common loanwords with the language they entered Turkish from, using
+//! the lexicon of resources/tr/config/etymology.txt:
+//!
+//! - Lemmas are looked up as they are ("kitap" → Arabic, "telefon" → French)
+//! - Inflected tokens are tagged through their root: the analyzer's roots
+//!   first, then the validated suffix stripper ("kitaplarımız" → "kitap")
+//!
+//! The lexicon also marks roots ending in a palatal consonant whose
+//! suffixes take front vowels against their last, back vowel (saat-ler,
+//! hal-i, gol-ü). The analyzer and the harmony check of the suffix stripper
+//! consult `takes_front_harmony` so these forms are not rejected.
+
+use crate::analyzer;
+use crate::turkish_lowercase;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+static ETYMOLOGY_DATA: &str = include_str!("../resources/tr/config/etymology.txt");
+
+static LEXICON: OnceLock<HashMap<&'static str, (Origin, bool)>> = OnceLock::new();
+
+/// Language a loanword entered Turkish from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Origin {
+    Arabic,
+    Persian,
+    French,
+    English,
+}
+
+impl Origin {
+    pub fn parse(name: &str) -> Option<Origin> {
+        match name {
+            "ARABIC" => Some(Origin::Arabic),
+            "PERSIAN" => Some(Origin::Persian),
+            "FRENCH" => Some(Origin::French),
+            "ENGLISH" => Some(Origin::English),
+            _ => None,
+        }
+    }
+
+    /// Label used by the lexicon and the Python API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Origin::Arabic => "ARABIC",
+            Origin::Persian => "PERSIAN",
+            Origin::French => "FRENCH",
+            Origin::English => "ENGLISH",
+        }
+    }
+}
+
+fn entries() -> impl Iterator<Item = (&'static str, Origin, bool)> {
+    ETYMOLOGY_DATA
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let (word, origin) = (fields.next()?, Origin::parse(fields.next()?)?);
+            Some((word, origin, fields.next() == Some("FRONT")))
+        })
+}
+
+fn lexicon() -> &'static HashMap<&'static str, (Origin, bool)> {
+    LEXICON.get_or_init(|| {
+        entries()
+            .map(|(word, origin, front)| (word, (origin, front)))
+            .collect()
+    })
+}
+
+/// Whether the suffixes of a lowercase root take front vowels although its
+/// last vowel is back ("saat" → "saatler")
+pub fn takes_front_harmony(root: &str) -> bool {
+    lexicon().get(root).is_some_and(|&(_, front)| front)
+}
+
+/// Origin of a token, looked up as a lemma and then through its root; None
+/// for native words and loanwords missing from the lexicon
+///
+/// # Examples
+/// ```text
+/// origin("Kitaplarımız") -> Some(Arabic)
+/// origin("otobüste")     -> Some(French)
+/// origin("ev")           -> None
+/// ```
+pub fn origin(token: &str) -> Option<Origin> {
+    let lower = turkish_lowercase(token);
+    let lookup = |word: &str| lexicon().get(word).map(|&(origin, _)| origin);
+    if let Some(origin) = lookup(&lower) {
+        return Some(origin);
+    }
+    if let Some(origin) = analyzer::analyze(&lower)
+        .iter()
+        .find_map(|analysis| lookup(&analysis.root))
+    {
+        return Some(origin);
+    }
+    lookup(&crate::strip_suffixes_validated(&lower, false, 2, true, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(token: &str) -> Option<&'static str> {
+        origin(token).map(|origin| origin.as_str())
+    }
+
+    #[test]
+    fn test_lexicon_parses() {
+        let lines = ETYMOLOGY_DATA
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .count();
+        assert_eq!(entries().count(), lines);
+        assert_eq!(lexicon().len(), lines);
+    }
+
+    #[test]
+    fn test_lemma_origins() {
+        assert_eq!(tag("kitap"), Some("ARABIC"));
+        assert_eq!(tag("Pazar"), Some("PERSIAN"));
+        assert_eq!(tag("TELEFON"), Some("FRENCH"));
+        assert_eq!(tag("futbol"), Some("ENGLISH"));
+        for native in ["ev", "göz", "gelmek", "", "123"] {
+            assert_eq!(tag(native), None, "{}", native);
+        }
+    }
+
+    #[test]
+    fn test_inflected_origins() {
+        assert_eq!(tag("Kitaplarımız"), Some("ARABIC"));
+        assert_eq!(tag("saatler"), Some("ARABIC"));
+        assert_eq!(tag("dostlar"), Some("PERSIAN"));
+        assert_eq!(tag("evlerimiz"), None);
+    }
+
+    #[test]
+    fn test_front_harmony_exceptions() {
+        assert!(takes_front_harmony("saat"));
+        assert!(takes_front_harmony("gol"));
+        assert!(!takes_front_harmony("kitap"));
+        assert!(!takes_front_harmony("ev"));
+    }
+}
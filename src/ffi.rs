@@ -0,0 +1,234 @@
+//! C ABI for embedding without Python
+//!
+//! Built as a static library for Android and iOS (see `scripts/build_mobile.sh`
+//! and `include/durak.h`) so keyboards and apps can run Turkish normalization
+//! and lemmatization natively, and as a shared library that Go (cgo), Java
+//! (JNI/JNA) and .NET (P/Invoke) services link against.
+//!
+//! Strings cross the boundary as NUL-terminated UTF-8. Every `char *` returned
+//! by a `durak_*` function is owned by the caller and must be released with
+//! `durak_string_free`; token arrays from `durak_tokenize` are released with
+//! `durak_tokens_free`. Invalid input (NULL or non-UTF-8) yields NULL.
+
+use crate::{chunk_tokens, fast_normalize, lemmatize_word, Strategy};
+use std::ffi::{c_char, CStr, CString};
+
+/// Minimum root length of the suffix stripper, as in the Python `Lemmatizer`
+const MIN_ROOT_LENGTH: usize = 2;
+
+static VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), "\0");
+
+/// Borrow a C string as UTF-8, or `None` for NULL / invalid UTF-8
+///
+/// # Safety
+/// `ptr` must be NULL or point to a NUL-terminated string that outlives the
+/// returned reference.
+unsafe fn borrow<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        return None;
+    }
+    CStr::from_ptr(ptr).to_str().ok()
+}
+
+/// Hand a Rust string to the caller (NULL if it contains an interior NUL)
+fn into_raw(s: String) -> *mut c_char {
+    CString::new(s).map_or(std::ptr::null_mut(), CString::into_raw)
+}
+
+/// Library version, e.g. "0.4.0". The returned string is static; do not free it.
+#[no_mangle]
+pub extern "C" fn durak_version() -> *const c_char {
+    VERSION.as_ptr().cast()
+}
+
+/// Lowercase text with Turkish I/ı and İ/i handling.
+///
+/// # Safety
+/// `text` must be NULL or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn durak_normalize(text: *const c_char) -> *mut c_char {
+    match borrow(text) {
+        Some(text) => into_raw(fast_normalize(text, true, true)),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Lemma of a word: dictionary lookup first, suffix stripping as fallback,
+/// with the defaults of the Python `Lemmatizer(strategy="hybrid")` (no root
+/// validation).
+///
+/// # Safety
+/// `word` must be NULL or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn durak_lemmatize(word: *const c_char) -> *mut c_char {
+    let Some(word) = borrow(word) else {
+        return std::ptr::null_mut();
+    };
+    let word = fast_normalize(word, true, true);
+    into_raw(lemmatize_word(&word, Strategy::Hybrid, false, false, MIN_ROOT_LENGTH))
+}
+
+/// Tokens of a text as a JSON array of strings, for bindings that already
+/// parse JSON.
+///
+/// # Safety
+/// `text` must be NULL or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn durak_tokenize_json(text: *const c_char) -> *mut c_char {
+    match borrow(text) {
+        Some(text) => {
+            let tokens = chunk_tokens(text, false);
+            into_raw(serde_json::to_string(&tokens).expect("Tokens serialize"))
+        }
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Tokens of a text as an array of `*count` strings. Release the array with
+/// `durak_tokens_free(tokens, *count)`. NULL (and `*count` 0) on invalid
+/// input; an empty text gives a non-NULL array of 0 tokens.
+///
+/// # Safety
+/// `text` must be NULL or a valid NUL-terminated string, and `count` NULL or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn durak_tokenize(
+    text: *const c_char,
+    count: *mut usize,
+) -> *mut *mut c_char {
+    if count.is_null() {
+        return std::ptr::null_mut();
+    }
+    *count = 0;
+    let Some(text) = borrow(text) else {
+        return std::ptr::null_mut();
+    };
+    // Tokens of a NUL-terminated string contain no NUL, so none is NULL
+    let tokens: Box<[*mut c_char]> = chunk_tokens(text, false)
+        .into_iter()
+        .map(into_raw)
+        .collect();
+    *count = tokens.len();
+    Box::into_raw(tokens).cast()
+}
+
+/// Release an array returned by `durak_tokenize` with its strings. NULL is
+/// ignored.
+///
+/// # Safety
+/// `tokens` must be NULL or an array returned by `durak_tokenize` that has
+/// not been freed yet, with `count` the count it returned.
+#[no_mangle]
+pub unsafe extern "C" fn durak_tokens_free(tokens: *mut *mut c_char, count: usize) {
+    if tokens.is_null() {
+        return;
+    }
+    let tokens = Box::from_raw(std::ptr::slice_from_raw_parts_mut(tokens, count));
+    for &token in tokens.iter() {
+        durak_string_free(token);
+    }
+}
+
+/// Release a string returned by a `durak_*` function. NULL is ignored.
+///
+/// # Safety
+/// `s` must be NULL or a pointer returned by this library that has not been
+/// freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn durak_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Call a string function through the C ABI and copy the result back
+    fn call(f: unsafe extern "C" fn(*const c_char) -> *mut c_char, input: &str) -> Option<String> {
+        let input = CString::new(input).unwrap();
+        unsafe {
+            let out = f(input.as_ptr());
+            if out.is_null() {
+                return None;
+            }
+            let result = CStr::from_ptr(out).to_str().unwrap().to_string();
+            durak_string_free(out);
+            Some(result)
+        }
+    }
+
+    #[test]
+    fn test_version() {
+        let version = unsafe { CStr::from_ptr(durak_version()) };
+        assert_eq!(version.to_str().unwrap(), env!("CARGO_PKG_VERSION"));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            call(durak_normalize, "İSTANBUL").as_deref(),
+            Some("istanbul")
+        );
+        assert_eq!(call(durak_normalize, "IRMAK").as_deref(), Some("ırmak"));
+    }
+
+    #[test]
+    fn test_lemmatize() {
+        assert_eq!(call(durak_lemmatize, "Kitaplar").as_deref(), Some("kitap"));
+        assert_eq!(call(durak_lemmatize, "geliyorum").as_deref(), Some("gel"));
+        // Same lemmas as the Python default, which does not validate roots
+        for word in ["evlerden", "oğlum", "burnu"] {
+            let expected = lemmatize_word(word, Strategy::Hybrid, false, false, MIN_ROOT_LENGTH);
+            assert_eq!(call(durak_lemmatize, word), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_tokenize_json() {
+        let json = call(durak_tokenize_json, "Ankara'ya gittim, çok güzel!").unwrap();
+        let tokens: Vec<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(tokens, ["Ankara'ya", "gittim", ",", "çok", "güzel", "!"]);
+        assert_eq!(call(durak_tokenize_json, "").as_deref(), Some("[]"));
+    }
+
+    #[test]
+    fn test_tokenize_array() {
+        let text = CString::new("Merhaba dünya!").unwrap();
+        let mut count = usize::MAX;
+        unsafe {
+            let tokens = durak_tokenize(text.as_ptr(), &mut count);
+            assert!(!tokens.is_null());
+            let read: Vec<&str> = (0..count)
+                .map(|i| CStr::from_ptr(*tokens.add(i)).to_str().unwrap())
+                .collect();
+            assert_eq!(read, ["Merhaba", "dünya", "!"]);
+            durak_tokens_free(tokens, count);
+
+            let empty = CString::new("").unwrap();
+            let tokens = durak_tokenize(empty.as_ptr(), &mut count);
+            assert!(!tokens.is_null() && count == 0);
+            durak_tokens_free(tokens, count);
+        }
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        unsafe {
+            assert!(durak_normalize(std::ptr::null()).is_null());
+            assert!(durak_lemmatize(std::ptr::null()).is_null());
+            durak_string_free(std::ptr::null_mut());
+            durak_tokens_free(std::ptr::null_mut(), 0);
+            assert!(durak_tokenize_json(std::ptr::null()).is_null());
+            let mut count = 1;
+            assert!(durak_tokenize(std::ptr::null(), &mut count).is_null());
+            assert_eq!(count, 0);
+            let text = CString::new("kitap").unwrap();
+            assert!(durak_tokenize(text.as_ptr(), std::ptr::null_mut()).is_null());
+
+            let invalid = [0xffu8, 0xfe, 0x00];
+            assert!(durak_normalize(invalid.as_ptr().cast()).is_null());
+        }
+    }
+}
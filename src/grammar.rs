@@ -0,0 +1,259 @@
+//! Rule-based grammar checking
+//!
+//! Flags frequent Turkish spelling and grammar errors with the rules of
+//! resources/tr/config/grammar_rules.txt:
+//!
+//! - The clitic "de/da" written together with its word ("bende geldim"),
+//!   hardened ("kitap ta") or against vowel harmony ("ev da")
+//! - The relative suffix "-ki" written apart ("evde ki") and the conjunction
+//!   "ki" written together ("dedimki")
+//! - The question particle "mi" written together with its verb ("geldinmi")
+//! - Double negation under "ne ... ne ..." ("ne çay ne kahve içmedim")
+//!
+//! A rule is a regex over the lowercased text with conditions on the
+//! morphological analyses and vowels of its groups, and a fix template that
+//! may regenerate a word without one of its suffixes.
+
+use crate::agreement;
+use crate::analyzer::{self, Analysis};
+use crate::confusion;
+use crate::fast_normalize;
+use crate::vowel_harmony;
+use regex::{Captures, Regex};
+use std::sync::OnceLock;
+
+static GRAMMAR_RULES_DATA: &str = include_str!("../resources/tr/config/grammar_rules.txt");
+
+static RULES: OnceLock<Vec<Rule>> = OnceLock::new();
+
+/// Condition on a capture group of a rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Condition {
+    /// Some analysis of the group has the suffix tag
+    Has(usize, &'static str),
+    /// No analysis of the group has the suffix tag
+    Lacks(usize, &'static str),
+    /// The last vowel of the group is front (true) or back (false)
+    Front(usize, bool),
+}
+
+#[derive(Debug)]
+struct Rule {
+    id: &'static str,
+    regex: Regex,
+    fix: &'static str,
+    message: &'static str,
+    conditions: Vec<Condition>,
+}
+
+/// A grammar error with character offsets into the input text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub text: String,
+    /// Identifier of the rule that flagged the error ("de-da-attached")
+    pub rule: &'static str,
+    pub message: &'static str,
+    /// Corrected text, None when the fix could not be generated
+    pub suggestion: Option<String>,
+    pub start: usize,
+    pub end: usize,
+}
+
+fn parse_condition(text: &'static str) -> Option<Condition> {
+    let at = text.find([':', '!'])?;
+    let group = text[..at].parse().ok()?;
+    let value = &text[at + 1..];
+    Some(match (&text[at..at + 1], value) {
+        (":", "front") => Condition::Front(group, true),
+        (":", "back") => Condition::Front(group, false),
+        (":", tag) => Condition::Has(group, tag),
+        (_, tag) => Condition::Lacks(group, tag),
+    })
+}
+
+fn parse_rule(line: &'static str) -> Option<Rule> {
+    let mut fields = line.split('\t');
+    let (id, pattern, fix, message) =
+        (fields.next()?, fields.next()?, fields.next()?, fields.next()?);
+    let conditions = match fields.next() {
+        Some(list) => list.split(',').map(parse_condition).collect::<Option<_>>()?,
+        None => Vec::new(),
+    };
+    Some(Rule {
+        id,
+        regex: Regex::new(pattern).ok()?,
+        fix,
+        message,
+        conditions,
+    })
+}
+
+fn rules() -> &'static [Rule] {
+    RULES.get_or_init(|| {
+        GRAMMAR_RULES_DATA
+            .lines()
+            .map(str::trim_end)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(parse_rule)
+            .collect()
+    })
+}
+
+fn has_tag(analysis: &Analysis, tag: &str) -> bool {
+    match tag {
+        "Finite" => agreement::is_finite(analysis),
+        _ => analysis.morphemes.iter().any(|m| m.tag == tag),
+    }
+}
+
+fn holds(condition: Condition, caps: &Captures) -> bool {
+    let word = |group: usize| caps.get(group).map_or("", |m| m.as_str());
+    match condition {
+        Condition::Has(group, tag) => {
+            analyzer::analyze(word(group)).iter().any(|a| has_tag(a, tag))
+        }
+        Condition::Lacks(group, tag) => {
+            !analyzer::analyze(word(group)).iter().any(|a| has_tag(a, tag))
+        }
+        Condition::Front(group, front) => vowel_harmony::get_last_vowel_class(word(group))
+            .is_some_and(|class| class.is_front() == front),
+    }
+}
+
+/// `word` generated again from its first analysis with `tag`, leaving the
+/// suffix out ("içmedim" without Neg → "içtim")
+fn without_tag(word: &str, tag: &str) -> Option<String> {
+    let analysis = analyzer::analyze(word).into_iter().find(|a| has_tag(a, tag))?;
+    let tags: Vec<&str> = analysis.morphemes.iter().map(|m| m.tag).filter(|&t| t != tag).collect();
+    analyzer::generate(&analysis.root, &tags, Some(analysis.pos)).ok()
+}
+
+/// Fill in the `$N` and `${N:-Tag}` references of a fix template
+fn render(template: &str, caps: &Captures) -> Option<String> {
+    let word = |group: &str| caps.get(group.parse().ok()?).map(|m| m.as_str());
+    let mut out = String::new();
+    let mut rest = template;
+    while let Some(at) = rest.find('$') {
+        out.push_str(&rest[..at]);
+        rest = &rest[at + 1..];
+        if let Some(inner) = rest.strip_prefix('{') {
+            let close = inner.find('}')?;
+            let (group, tag) = inner[..close].split_once(":-")?;
+            out.push_str(&without_tag(word(group)?, tag)?);
+            rest = &inner[close + 1..];
+        } else {
+            let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+            out.push_str(word(&rest[..digits]).unwrap_or(""));
+            rest = &rest[digits..];
+        }
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
+/// Grammar errors of `text` in order of appearance
+///
+/// # Examples
+/// ```text
+/// check("Bende geldim.")
+///     -> [("Bende geldim", "de-da-attached", ..., Some("Ben de geldim"), 0, 12)]
+/// ```
+pub fn check(text: &str) -> Vec<Issue> {
+    // Lowercasing keeps one character per character, so character offsets
+    // into the lowered text are offsets into `text`
+    let lower = fast_normalize(text, true, true);
+    let bytes: Vec<usize> = text.char_indices().map(|(i, _)| i).chain([text.len()]).collect();
+    let mut issues = Vec::new();
+    for rule in rules() {
+        for caps in rule.regex.captures_iter(&lower) {
+            if !rule.conditions.iter().all(|&condition| holds(condition, &caps)) {
+                continue;
+            }
+            let mat = caps.get(0).expect("group 0 is the match");
+            let start = lower[..mat.start()].chars().count();
+            let end = start + mat.as_str().chars().count();
+            let original = &text[bytes[start]..bytes[end]];
+            let suggestion =
+                render(rule.fix, &caps).map(|fix| confusion::match_case(original, &fix));
+            issues.push(Issue {
+                text: original.to_string(),
+                rule: rule.id,
+                message: rule.message,
+                suggestion,
+                start,
+                end,
+            });
+        }
+    }
+    issues.sort_by_key(|issue| issue.start);
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixes(text: &str) -> Vec<(&'static str, Option<String>)> {
+        check(text).into_iter().map(|i| (i.rule, i.suggestion)).collect()
+    }
+
+    fn fix(rule: &'static str, suggestion: &str) -> Vec<(&'static str, Option<String>)> {
+        vec![(rule, Some(suggestion.to_string()))]
+    }
+
+    #[test]
+    fn test_rules_parse() {
+        let lines = GRAMMAR_RULES_DATA
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .count();
+        assert_eq!(rules().len(), lines);
+    }
+
+    #[test]
+    fn test_clitic_de_da() {
+        assert_eq!(fixes("Bende geldim."), fix("de-da-attached", "Ben de geldim"));
+        assert_eq!(fixes("kitap ta var"), fix("de-da-hardened", "kitap da"));
+        assert_eq!(fixes("ev da güzel"), fix("de-da-harmony", "ev de"));
+        assert_eq!(fixes("okul de kapalı"), fix("de-da-harmony", "okul da"));
+        for correct in ["ben de geldim", "bende kalsın", "kitap da var", "ev de güzel"] {
+            assert!(fixes(correct).is_empty(), "{}", correct);
+        }
+    }
+
+    #[test]
+    fn test_ki() {
+        assert_eq!(fixes("evde ki kitap"), fix("ki-detached", "evdeki"));
+        assert_eq!(fixes("dedimki gel"), fix("ki-attached", "dedim ki"));
+        for correct in ["evdeki kitap", "dedim ki gel", "belki gelir", "sanki"] {
+            assert!(fixes(correct).is_empty(), "{}", correct);
+        }
+    }
+
+    #[test]
+    fn test_question_particle() {
+        assert_eq!(fixes("Geldinmi?"), fix("mi-attached", "Geldin mi"));
+        assert_eq!(fixes("gelecekmisin"), fix("mi-attached", "gelecek misin"));
+        for correct in ["geldin mi", "kimi", "geldiğimi", "gelmemiş"] {
+            assert!(fixes(correct).is_empty(), "{}", correct);
+        }
+    }
+
+    #[test]
+    fn test_double_negation() {
+        assert_eq!(
+            fixes("ne çay ne kahve içmedim"),
+            fix("ne-ne-negative", "ne çay ne kahve içtim")
+        );
+        assert!(fixes("ne çay ne kahve içtim").is_empty());
+    }
+
+    #[test]
+    fn test_char_offsets() {
+        let issues = check("Çok güzel, şendemi?");
+        assert!(issues.is_empty());
+        let issues = check("Çiçek ta açtı");
+        assert_eq!((issues[0].start, issues[0].end), (0, 8));
+        assert_eq!(issues[0].text, "Çiçek ta");
+    }
+}
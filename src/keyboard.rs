@@ -0,0 +1,210 @@
+//! Turkish keyboard geometry and typo costs
+//!
+//! Letter positions of the Turkish Q and F keyboards, embedded from
+//! `resources/tr/config/keyboard_layouts.txt`. Two keys are neighbours when
+//! they sit next to each other in a row or their centres in adjacent rows
+//! are less than one key width apart; a typo hitting a neighbour is the most
+//! likely substitution. The spell checker ranks suggestions with
+//! `edit_cost`, the noise injector draws typos from `neighbours`.
+
+use crate::turkish_lower_char;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Embedded layout rows (layout<TAB>offset<TAB>letters)
+static LAYOUT_DATA: &str = include_str!("../resources/tr/config/keyboard_layouts.txt");
+static LAYOUTS: OnceLock<HashMap<Layout, Vec<Key>>> = OnceLock::new();
+
+/// Cost of an insertion, deletion or non-adjacent substitution in
+/// `edit_cost`; substituting a neighbouring key costs 1
+pub const EDIT: usize = 2;
+
+/// Turkish keyboard layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layout {
+    Q,
+    F,
+}
+
+impl Layout {
+    pub fn parse(name: &str) -> Option<Layout> {
+        match name {
+            "q" => Some(Layout::Q),
+            "f" => Some(Layout::F),
+            _ => None,
+        }
+    }
+}
+
+/// A letter key: row from the top and horizontal centre in key widths
+#[derive(Debug, Clone, Copy)]
+struct Key {
+    letter: char,
+    row: usize,
+    x: f64,
+}
+
+fn layouts() -> &'static HashMap<Layout, Vec<Key>> {
+    LAYOUTS.get_or_init(|| {
+        let mut layouts: HashMap<Layout, Vec<Key>> = HashMap::new();
+        let mut rows: HashMap<Layout, usize> = HashMap::new();
+        for line in LAYOUT_DATA.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split('\t');
+            let (Some(layout), Some(offset), Some(letters)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                continue;
+            };
+            let (Some(layout), Ok(offset)) = (Layout::parse(layout), offset.parse::<f64>()) else {
+                continue;
+            };
+            let row = rows.entry(layout).or_insert(0);
+            let keys = layouts.entry(layout).or_default();
+            for (i, letter) in letters.chars().enumerate() {
+                keys.push(Key { letter, row: *row, x: offset / 4.0 + i as f64 + 0.5 });
+            }
+            *row += 1;
+        }
+        layouts
+    })
+}
+
+fn key(c: char, layout: Layout) -> Option<Key> {
+    let c = turkish_lower_char(c);
+    layouts().get(&layout)?.iter().find(|key| key.letter == c).copied()
+}
+
+fn adjacent(a: Key, b: Key) -> bool {
+    let dx = (a.x - b.x).abs();
+    match a.row.abs_diff(b.row) {
+        0 => (dx - 1.0).abs() < 1e-9,
+        1 => dx < 1.0,
+        _ => false,
+    }
+}
+
+/// Letter rows of a layout, top row first
+pub fn rows(layout: Layout) -> Vec<String> {
+    let mut rows: Vec<String> = Vec::new();
+    for key in layouts().get(&layout).into_iter().flatten() {
+        if rows.len() <= key.row {
+            rows.push(String::new());
+        }
+        rows[key.row].push(key.letter);
+    }
+    rows
+}
+
+/// Letters on the keys around `c`'s key, row by row; empty for characters
+/// that are not on the layout. Case is ignored.
+///
+/// # Examples
+/// ```text
+/// neighbours('g', Layout::Q) -> ['t', 'y', 'f', 'h', 'v', 'b']
+/// neighbours('a', Layout::F) -> ['ı', 'o', 'e', 'ü', 'v', 'c']
+/// ```
+pub fn neighbours(c: char, layout: Layout) -> Vec<char> {
+    let Some(from) = key(c, layout) else {
+        return Vec::new();
+    };
+    layouts()[&layout].iter().filter(|&&to| adjacent(from, to)).map(|to| to.letter).collect()
+}
+
+/// Distance between the centres of two keys in key widths
+pub fn distance(a: char, b: char, layout: Layout) -> Option<f64> {
+    let (a, b) = (key(a, layout)?, key(b, layout)?);
+    let dy = a.row.abs_diff(b.row) as f64;
+    Some(((a.x - b.x).powi(2) + dy * dy).sqrt())
+}
+
+/// Cost of typing `b` instead of `a`: 0 for the same letter, 1 for a
+/// neighbouring key, `EDIT` otherwise
+pub fn substitution_cost(a: char, b: char, layout: Layout) -> usize {
+    if turkish_lower_char(a) == turkish_lower_char(b) {
+        return 0;
+    }
+    match (key(a, layout), key(b, layout)) {
+        (Some(a), Some(b)) if adjacent(a, b) => 1,
+        _ => EDIT,
+    }
+}
+
+/// Keyboard-aware edit distance in half edits: Levenshtein distance where
+/// substituting a neighbouring key costs half an edit
+///
+/// # Examples
+/// ```text
+/// edit_cost("kitap", "kitao", Layout::Q) -> 1  (p and o are neighbours)
+/// edit_cost("kitap", "kitak", Layout::Q) -> 2
+/// ```
+pub fn edit_cost(a: &[char], b: &[char], layout: Layout) -> usize {
+    let mut prev: Vec<usize> = (0..=b.len()).map(|j| j * EDIT).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, &ca) in a.iter().enumerate() {
+        curr[0] = (i + 1) * EDIT;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = prev[j] + substitution_cost(ca, cb, layout);
+            curr[j + 1] = substitution.min(prev[j + 1] + EDIT).min(curr[j] + EDIT);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+/// Every pair of neighbouring letters of a layout as (letter, neighbour),
+/// in row order: the non-zero cells of a keyboard confusion matrix
+pub fn confusion_pairs(layout: Layout) -> Vec<(char, char)> {
+    layouts()
+        .get(&layout)
+        .into_iter()
+        .flatten()
+        .flat_map(|key| {
+            neighbours(key.letter, layout).into_iter().map(move |near| (key.letter, near))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layouts_loaded() {
+        assert_eq!(rows(Layout::Q), vec!["qwertyuıopğü", "asdfghjklşi", "zxcvbnmöç"]);
+        assert_eq!(rows(Layout::F)[1], "uieaütkmlyşx");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        assert_eq!(neighbours('g', Layout::Q), vec!['t', 'y', 'f', 'h', 'v', 'b']);
+        assert_eq!(neighbours('Q', Layout::Q), vec!['w', 'a']);
+        assert!(neighbours('ş', Layout::Q).contains(&'ğ'));
+        assert_eq!(neighbours('a', Layout::F), vec!['ı', 'o', 'e', 'ü', 'v', 'c']);
+        assert!(neighbours('1', Layout::Q).is_empty());
+    }
+
+    #[test]
+    fn test_costs() {
+        assert_eq!(distance('f', 'g', Layout::Q), Some(1.0));
+        assert_eq!(substitution_cost('p', 'o', Layout::Q), 1);
+        assert_eq!(substitution_cost('p', 'k', Layout::Q), EDIT);
+        assert_eq!(substitution_cost('I', 'ı', Layout::Q), 0);
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(edit_cost(&chars("kitap"), &chars("kitao"), Layout::Q), 1);
+        assert_eq!(edit_cost(&chars("kitap"), &chars("kitak"), Layout::Q), 2);
+        assert_eq!(edit_cost(&chars("ev"), &chars("evler"), Layout::Q), 6);
+    }
+
+    #[test]
+    fn test_confusion_pairs_are_symmetric() {
+        for layout in [Layout::Q, Layout::F] {
+            let pairs = confusion_pairs(layout);
+            assert!(pairs.iter().all(|&(a, b)| pairs.contains(&(b, a))));
+        }
+    }
+}
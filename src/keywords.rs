@@ -0,0 +1,192 @@
+//! Multi-pattern keyword spotting
+//!
+//! A `KeywordMatcher` compiles a phrase list (brands, products, gazetteer
+//! entries) into one Aho–Corasick automaton, so a text is scanned once no
+//! matter how many thousands of phrases there are. Phrases and text are
+//! optionally normalized with `fast_normalize` (lowercase, Turkish I/ı)
+//! first; it maps every character to exactly one character, so matches keep
+//! character offsets into the original text.
+//!
+//! With `whole_words` a match must not continue a word on either side:
+//! "Koç" matches in "Koç'un" but not in "Koçak". The check only applies at
+//! an edge where the phrase itself has a letter or digit, so "#tatil" or
+//! "c++" still match next to punctuation.
+
+use crate::fast_normalize;
+use aho_corasick::{AhoCorasick, MatchKind};
+
+/// A phrase found in a text, with character offsets
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// Index of the phrase in the list the matcher was built from
+    pub phrase: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct KeywordMatcher {
+    automaton: AhoCorasick,
+    /// Input index of each pattern of the automaton
+    indices: Vec<usize>,
+    normalize: bool,
+    whole_words: bool,
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric()
+}
+
+impl KeywordMatcher {
+    /// Compile `phrases`; duplicates (after normalization) keep their first
+    /// occurrence. Errors on an empty phrase, with its index.
+    pub fn new<S: AsRef<str>>(
+        phrases: &[S],
+        normalize: bool,
+        whole_words: bool,
+    ) -> Result<Self, String> {
+        let mut indices = Vec::new();
+        let mut patterns = Vec::new();
+        let mut seen = std::collections::HashSet::new();
+        for (index, phrase) in phrases.iter().enumerate() {
+            let phrase = phrase.as_ref();
+            if phrase.trim().is_empty() {
+                return Err(format!("empty keyword at index {}", index));
+            }
+            let pattern = if normalize {
+                fast_normalize(phrase, true, true)
+            } else {
+                phrase.to_string()
+            };
+            if seen.insert(pattern.clone()) {
+                indices.push(index);
+                patterns.push(pattern);
+            }
+        }
+        let automaton = AhoCorasick::builder()
+            .match_kind(MatchKind::Standard)
+            .build(&patterns)
+            .map_err(|e| e.to_string())?;
+        Ok(Self {
+            automaton,
+            indices,
+            normalize,
+            whole_words,
+        })
+    }
+
+    /// Number of distinct phrases
+    pub fn phrase_count(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Every match of every phrase, overlapping ones included, by start
+    /// then longest first. Without `overlapping` only the leftmost-longest
+    /// matches that do not overlap are kept.
+    pub fn find(&self, text: &str, overlapping: bool) -> Vec<Match> {
+        let normalized;
+        let haystack = if self.normalize {
+            normalized = fast_normalize(text, true, true);
+            normalized.as_str()
+        } else {
+            text
+        };
+        // Byte offset of every character start, and the end of the text
+        let offsets: Vec<usize> = haystack
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain([haystack.len()])
+            .collect();
+        let char_index = |byte: usize| offsets.binary_search(&byte).unwrap_or(0);
+
+        let mut matches: Vec<Match> = self
+            .automaton
+            .find_overlapping_iter(haystack)
+            .filter(|m| !self.whole_words || self.at_word_bounds(haystack, m.start(), m.end()))
+            .map(|m| Match {
+                phrase: self.indices[m.pattern().as_usize()],
+                start: char_index(m.start()),
+                end: char_index(m.end()),
+            })
+            .collect();
+        matches.sort_by_key(|m| (m.start, std::cmp::Reverse(m.end), m.phrase));
+        if !overlapping {
+            let mut end = 0;
+            matches.retain(|m| {
+                let keep = m.start >= end;
+                if keep {
+                    end = m.end;
+                }
+                keep
+            });
+        }
+        matches
+    }
+
+    fn at_word_bounds(&self, haystack: &str, start: usize, end: usize) -> bool {
+        let matched = &haystack[start..end];
+        let first = matched.chars().next().is_some_and(is_word_char);
+        let last = matched.chars().next_back().is_some_and(is_word_char);
+        let before = haystack[..start]
+            .chars()
+            .next_back()
+            .is_some_and(is_word_char);
+        let after = haystack[end..].chars().next().is_some_and(is_word_char);
+        let continues_word = (first && before) || (last && after);
+        !continues_word
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans(
+        matcher: &KeywordMatcher,
+        text: &str,
+        overlapping: bool,
+    ) -> Vec<(String, usize, usize)> {
+        let chars: Vec<char> = text.chars().collect();
+        matcher
+            .find(text, overlapping)
+            .into_iter()
+            .map(|m| (chars[m.start..m.end].iter().collect(), m.start, m.end))
+            .collect()
+    }
+
+    #[test]
+    fn test_find_normalized() {
+        let matcher =
+            KeywordMatcher::new(&["Türk Telekom", "Türk", "Koç", "#tatil"], true, true).unwrap();
+        let text = "İYİ Kİ TÜRK TELEKOM'u seçtim, Koçak değil Koç'un #tatil";
+        assert_eq!(
+            spans(&matcher, text, false),
+            [
+                ("TÜRK TELEKOM".to_string(), 7, 19),
+                ("Koç".to_string(), 42, 45),
+                ("#tatil".to_string(), 49, 55),
+            ]
+        );
+        let overlapping = spans(&matcher, text, true);
+        assert_eq!(overlapping.len(), 4);
+        assert_eq!(overlapping[1], ("TÜRK".to_string(), 7, 11));
+        assert_eq!(matcher.find(text, false)[0].phrase, 0);
+    }
+
+    #[test]
+    fn test_options() {
+        let exact = KeywordMatcher::new(&["ev", "Ev"], false, false).unwrap();
+        assert_eq!(exact.phrase_count(), 2);
+        assert_eq!(
+            spans(&exact, "Evet ev", false),
+            [("Ev".to_string(), 0, 2), ("ev".to_string(), 5, 7)]
+        );
+        let folded = KeywordMatcher::new(&["ev", "Ev"], true, true).unwrap();
+        assert_eq!(folded.phrase_count(), 1);
+        assert_eq!(spans(&folded, "Evet ev", false), [("ev".to_string(), 5, 7)]);
+        assert_eq!(
+            KeywordMatcher::new(&["a", " "], true, true).unwrap_err(),
+            "empty keyword at index 1"
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! Language identification with character trigram profiles
+//!
+//! A fast filter for corpus pipelines that drops non-Turkish lines before
+//! expensive processing. The profiles of resources/tr/config/language_profiles.txt
+//! rank the most frequent trigrams of each language:
+//!
+//! - Text is lowercased and split into letter runs padded with "_" ("_ev_")
+//! - Each trigram scores the log of its Zipf probability in a profile,
+//!   1/(rank+1); trigrams missing from a profile score as rank 3×size
+//! - Summed scores give a posterior over the languages with uniform priors
+//!
+//! Text without any profile trigram (digits only, other scripts) is left
+//! undetermined.
+
+use crate::{turkish_lower_char, words};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+static LANGUAGE_PROFILES_DATA: &str =
+    include_str!("../resources/tr/config/language_profiles.txt");
+
+static PROFILES: OnceLock<Vec<Profile>> = OnceLock::new();
+
+/// Rank of a trigram missing from a profile, in multiples of the profile size
+const MISSING_RANK_FACTOR: usize = 3;
+
+struct Profile {
+    language: &'static str,
+    ranks: HashMap<&'static str, usize>,
+}
+
+fn profiles() -> &'static [Profile] {
+    PROFILES.get_or_init(|| {
+        let mut profiles: Vec<Profile> = Vec::new();
+        let entries = LANGUAGE_PROFILES_DATA
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('\t'));
+        for (language, trigram) in entries {
+            if profiles.last().map_or(true, |p| p.language != language) {
+                profiles.push(Profile { language, ranks: HashMap::new() });
+            }
+            let ranks = &mut profiles.last_mut().expect("profile just pushed").ranks;
+            let rank = ranks.len();
+            ranks.entry(trigram).or_insert(rank);
+        }
+        profiles
+    })
+}
+
+/// Padded lowercase trigrams of the words of `text`
+fn trigrams(text: &str) -> Vec<String> {
+    let mut trigrams = Vec::new();
+    for mat in words(text) {
+        let mut chars = vec!['_'];
+        chars.extend(mat.as_str().chars().map(turkish_lower_char));
+        chars.push('_');
+        trigrams.extend(chars.windows(3).map(|w| w.iter().collect::<String>()));
+    }
+    trigrams
+}
+
+/// Posterior probability of each language for `text`, most probable first;
+/// empty when no trigram of the text occurs in any profile
+pub fn scores(text: &str) -> Vec<(&'static str, f64)> {
+    let trigrams = trigrams(text);
+    let profiles = profiles();
+    let known = |t: &String| profiles.iter().any(|p| p.ranks.contains_key(t.as_str()));
+    if !trigrams.iter().any(known) {
+        return Vec::new();
+    }
+    let log_likelihoods: Vec<f64> = profiles
+        .iter()
+        .map(|profile| {
+            let missing = (MISSING_RANK_FACTOR * profile.ranks.len()) as f64;
+            let rank = |t: &String| profile.ranks.get(t.as_str()).map(|&rank| (rank + 1) as f64);
+            trigrams.iter().map(|t| -rank(t).unwrap_or(missing).ln()).sum()
+        })
+        .collect();
+    let best = log_likelihoods.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let total: f64 = log_likelihoods.iter().map(|l| (l - best).exp()).sum();
+    let mut scores: Vec<(&'static str, f64)> = profiles
+        .iter()
+        .zip(&log_likelihoods)
+        .map(|(profile, l)| (profile.language, (l - best).exp() / total))
+        .collect();
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+    scores
+}
+
+/// Most probable language of `text` and its probability, None when the
+/// language cannot be determined
+///
+/// # Examples
+/// ```text
+/// detect("Bugün hava çok güzel") -> Some(("tr", 0.99..))
+/// detect("12345")                -> None
+/// ```
+pub fn detect(text: &str) -> Option<(&'static str, f64)> {
+    scores(text).into_iter().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn language(text: &str) -> Option<&'static str> {
+        detect(text).map(|(language, _)| language)
+    }
+
+    #[test]
+    fn test_profiles_parse() {
+        let languages: Vec<&str> = profiles().iter().map(|p| p.language).collect();
+        assert_eq!(languages, vec!["tr", "az", "ku", "en", "de", "fr", "es", "it"]);
+        assert!(profiles().iter().all(|p| p.ranks.len() == 300));
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(language("Toplantı yarın saat onda yapılacaktır."), Some("tr"));
+        assert_eq!(language("ALLAH RAZI OLSUN KARDEŞİM"), Some("tr"));
+        assert_eq!(language("The weather is nice today, shall we go out?"), Some("en"));
+        assert_eq!(language("Vielen Dank für Ihre Hilfe"), Some("de"));
+        assert_eq!(language("Mən sabah Bakıya gedirəm"), Some("az"));
+        assert_eq!(language("Ez ji te hez dikim"), Some("ku"));
+    }
+
+    #[test]
+    fn test_confidence() {
+        let (_, confidence) = detect("Bugün hava çok güzel, dışarı çıkalım mı?").unwrap();
+        assert!(confidence > 0.99);
+        let total: f64 = scores("merhaba").iter().map(|(_, p)| p).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_undetermined() {
+        assert_eq!(detect("12345 !!!"), None);
+        assert_eq!(detect("Привет, как дела?"), None);
+        assert_eq!(detect(""), None);
+    }
+}
@@ -0,0 +1,317 @@
+//! Sharded LRU cache for repeated lemmatization
+//!
+//! Corpus text is Zipfian: a few thousand inflected forms make up most
+//! tokens, so remembering recent lemmas skips the dictionary lookup and
+//! suffix stripping for almost every token. The cache is split into
+//! `SHARDS` independently locked LRU lists, keyed by hash, so batch workers
+//! on different threads rarely wait for each other.
+//!
+//! Each shard is a hash map into a slab of nodes linked in recency order;
+//! a hit moves its node to the front and an insert into a full shard reuses
+//! the slot of the least recently used node. A capacity of 0 disables the
+//! cache: lookups miss without counting and nothing is stored.
+//!
+//! Lookups take a borrowed view of the key (see `Key`): the map is keyed by
+//! the hash of the view and the node's key is compared with it in place, so a hit
+//! allocates nothing and the owned key is only built on insert. Two views
+//! with the same hash share a slot, the later one replacing the earlier.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Entries kept by default across all shards
+pub const DEFAULT_CAPACITY: usize = 100_000;
+
+const SHARDS: usize = 16;
+const NIL: usize = usize::MAX;
+
+/// A cache key probed through a borrowed view of itself
+pub trait Key {
+    type View<'a>: Hash + Copy
+    where
+        Self: 'a;
+
+    fn from_view(view: Self::View<'_>) -> Self;
+    /// Whether `view` is a view of this key
+    fn matches(&self, view: Self::View<'_>) -> bool;
+}
+
+impl Key for String {
+    type View<'a> = &'a str;
+
+    fn from_view(view: &str) -> Self {
+        view.to_string()
+    }
+
+    fn matches(&self, view: &str) -> bool {
+        self == view
+    }
+}
+
+/// A word with options that are cheap to copy
+impl<T: Hash + Eq + Copy> Key for (String, T) {
+    type View<'a>
+        = (&'a str, T)
+    where
+        T: 'a;
+
+    fn from_view((word, options): (&str, T)) -> Self {
+        (word.to_string(), options)
+    }
+
+    fn matches(&self, (word, options): (&str, T)) -> bool {
+        self.0 == word && self.1 == options
+    }
+}
+
+struct Node<K, V> {
+    hash: u64,
+    key: K,
+    value: V,
+    prev: usize,
+    next: usize,
+}
+
+/// One shard: least recently used at the tail
+struct Lru<K, V> {
+    /// Hash of a key's view → its node
+    map: HashMap<u64, usize>,
+    nodes: Vec<Node<K, V>>,
+    head: usize,
+    tail: usize,
+    capacity: usize,
+}
+
+impl<K: Key, V: Clone> Lru<K, V> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            map: HashMap::new(),
+            nodes: Vec::new(),
+            head: NIL,
+            tail: NIL,
+            capacity,
+        }
+    }
+
+    fn detach(&mut self, index: usize) {
+        let (prev, next) = (self.nodes[index].prev, self.nodes[index].next);
+        match prev {
+            NIL => self.head = next,
+            _ => self.nodes[prev].next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            _ => self.nodes[next].prev = prev,
+        }
+    }
+
+    fn push_front(&mut self, index: usize) {
+        self.nodes[index].prev = NIL;
+        self.nodes[index].next = self.head;
+        match self.head {
+            NIL => self.tail = index,
+            head => self.nodes[head].prev = index,
+        }
+        self.head = index;
+    }
+
+    fn get(&mut self, hash: u64, key: K::View<'_>) -> Option<V> {
+        let index = *self.map.get(&hash)?;
+        if !self.nodes[index].key.matches(key) {
+            return None;
+        }
+        self.detach(index);
+        self.push_front(index);
+        Some(self.nodes[index].value.clone())
+    }
+
+    fn insert(&mut self, hash: u64, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        if let Some(&index) = self.map.get(&hash) {
+            let node = &mut self.nodes[index];
+            node.key = key;
+            node.value = value;
+            self.detach(index);
+            self.push_front(index);
+            return;
+        }
+        let index = if self.nodes.len() < self.capacity {
+            self.nodes.push(Node {
+                hash,
+                key,
+                value,
+                prev: NIL,
+                next: NIL,
+            });
+            self.nodes.len() - 1
+        } else {
+            let index = self.tail;
+            self.detach(index);
+            let node = &mut self.nodes[index];
+            self.map.remove(&node.hash);
+            node.hash = hash;
+            node.key = key;
+            node.value = value;
+            index
+        };
+        self.map.insert(hash, index);
+        self.push_front(index);
+    }
+}
+
+/// Hit and miss counts, current entries and capacity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheInfo {
+    pub hits: u64,
+    pub misses: u64,
+    pub size: usize,
+    pub maxsize: usize,
+}
+
+pub struct ShardedLru<K, V> {
+    shards: Vec<Mutex<Lru<K, V>>>,
+    hasher: RandomState,
+    capacity: AtomicUsize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// Capacity of each shard for a total capacity, rounded up
+fn shard_capacity(capacity: usize) -> usize {
+    capacity / SHARDS + usize::from(capacity % SHARDS != 0)
+}
+
+impl<K: Key, V: Clone> ShardedLru<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            shards: (0..SHARDS)
+                .map(|_| Mutex::new(Lru::new(shard_capacity(capacity))))
+                .collect(),
+            hasher: RandomState::new(),
+            capacity: AtomicUsize::new(capacity),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    fn shard(&self, hash: u64) -> std::sync::MutexGuard<'_, Lru<K, V>> {
+        let shard = &self.shards[hash as usize % SHARDS];
+        shard.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.capacity.load(Ordering::Relaxed) > 0
+    }
+
+    /// The cached value of `key`, else `compute(key)`, cached under an
+    /// owned copy of `key`
+    pub fn get_or_insert_with<'q>(
+        &self,
+        key: K::View<'q>,
+        compute: impl FnOnce(K::View<'q>) -> V,
+    ) -> V {
+        if !self.enabled() {
+            return compute(key);
+        }
+        let mut hasher = self.hasher.build_hasher();
+        key.hash(&mut hasher);
+        let hash = hasher.finish();
+        if let Some(value) = self.shard(hash).get(hash, key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return value;
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        // Computed outside the lock; a concurrent miss on the same key
+        // computes the same value
+        let value = compute(key);
+        self.shard(hash).insert(hash, K::from_view(key), value.clone());
+        value
+    }
+
+    /// Drop every entry and reset the counters
+    pub fn clear(&self) {
+        self.resize(self.capacity.load(Ordering::Relaxed));
+    }
+
+    /// Set the capacity (0 disables the cache); drops every entry and
+    /// resets the counters
+    pub fn resize(&self, capacity: usize) {
+        self.capacity.store(capacity, Ordering::Relaxed);
+        for shard in &self.shards {
+            *shard.lock().unwrap_or_else(|e| e.into_inner()) = Lru::new(shard_capacity(capacity));
+        }
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+
+    pub fn info(&self) -> CacheInfo {
+        let size = self
+            .shards
+            .iter()
+            .map(|shard| shard.lock().unwrap_or_else(|e| e.into_inner()).map.len())
+            .sum();
+        CacheInfo {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            size,
+            maxsize: self.capacity.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lru_eviction() {
+        let mut lru = Lru::new(2);
+        lru.insert(1, "a".to_string(), 1);
+        lru.insert(2, "b".to_string(), 2);
+        assert_eq!(lru.get(1, "a"), Some(1));
+        // "b" is now the least recently used
+        lru.insert(3, "c".to_string(), 3);
+        assert_eq!(lru.get(2, "b"), None);
+        assert_eq!(lru.get(1, "a"), Some(1));
+        assert_eq!(lru.get(3, "c"), Some(3));
+        lru.insert(3, "c".to_string(), 4);
+        assert_eq!((lru.get(3, "c"), lru.map.len()), (Some(4), 2));
+        // A key with the hash of another misses, and replaces it on insert
+        assert_eq!(lru.get(3, "d"), None);
+        lru.insert(3, "d".to_string(), 5);
+        assert_eq!((lru.get(3, "c"), lru.get(3, "d")), (None, Some(5)));
+    }
+
+    #[test]
+    fn test_sharded_counts() {
+        let cache: ShardedLru<String, usize> = ShardedLru::new(64);
+        let mut computed = 0;
+        for word in ["ev", "ev", "okul", "ev"] {
+            cache.get_or_insert_with(word, |w| {
+                computed += 1;
+                w.len()
+            });
+        }
+        assert_eq!(computed, 2);
+        let info = cache.info();
+        assert_eq!((info.hits, info.misses, info.size, info.maxsize), (2, 2, 2, 64));
+
+        cache.resize(0);
+        assert_eq!(cache.get_or_insert_with("ev", |_| 7), 7);
+        assert_eq!(cache.info(), CacheInfo { hits: 0, misses: 0, size: 0, maxsize: 0 });
+    }
+
+    #[test]
+    fn test_options_in_key() {
+        let cache: ShardedLru<(String, usize), usize> = ShardedLru::new(64);
+        assert_eq!(cache.get_or_insert_with(("ev", 2), |(_, n)| n), 2);
+        assert_eq!(cache.get_or_insert_with(("ev", 3), |(_, n)| n), 3);
+        assert_eq!(cache.get_or_insert_with(("ev", 2), |_| 7), 2);
+        assert_eq!(cache.info().size, 2);
+    }
+}
@@ -0,0 +1,137 @@
+//! FST-backed Turkish lemma dictionary
+//!
+//! The dictionary is compiled by `build.rs` from
+//! `resources/tr/lemmas/turkish_lemma_dict.txt` into two finite-state
+//! automata that are embedded as bytes:
+//!
+//! - a map from inflected form to lemma (values point into a lemma blob)
+//! - a set of all lemmas (root forms)
+//!
+//! Opening an embedded FST only validates its header, so initialization is
+//! effectively free regardless of dictionary size, and lookups never allocate.
+//! The automata also support ordered prefix queries, which a `HashMap` cannot.
+//!
+//! A dictionary installed from a user directory (`resources`) replaces the
+//! embedded one for all queries.
+
+use fst::automaton::{Automaton, Str};
+use crate::resources;
+use fst::{IntoStreamer, Map, Set, Streamer};
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+static LEMMA_FORMS_FST: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/lemma_forms.fst"));
+static LEMMA_SET_FST: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/lemma_set.fst"));
+static LEMMA_BLOB: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/lemma_blob.bin"));
+
+static FORMS: OnceLock<Map<&'static [u8]>> = OnceLock::new();
+static LEMMAS: OnceLock<Set<&'static [u8]>> = OnceLock::new();
+
+fn forms() -> &'static Map<&'static [u8]> {
+    FORMS.get_or_init(|| Map::new(LEMMA_FORMS_FST).expect("Invalid embedded lemma FST"))
+}
+
+fn lemmas() -> &'static Set<&'static [u8]> {
+    LEMMAS.get_or_init(|| Set::new(LEMMA_SET_FST).expect("Invalid embedded lemma set FST"))
+}
+
+/// Resolve a packed FST value (offset << 32 | len) to the lemma it points to
+fn resolve(value: u64) -> &'static str {
+    let offset = (value >> 32) as usize;
+    let len = (value & 0xFFFF_FFFF) as usize;
+    std::str::from_utf8(&LEMMA_BLOB[offset..offset + len]).expect("Lemma blob is not UTF-8")
+}
+
+/// Exact lookup of an inflected form
+pub fn lookup(word: &str) -> Option<Cow<'static, str>> {
+    if let Some(table) = resources::lemmas() {
+        return table.forms.get(word).map(|lemma| Cow::Owned(lemma.clone()));
+    }
+    forms().get(word).map(resolve).map(Cow::Borrowed)
+}
+
+/// Check whether a word is a known lemma (appears as a root in the dictionary)
+pub fn is_lemma(word: &str) -> bool {
+    match resources::lemmas() {
+        Some(table) => table.lemmas.contains(word),
+        None => lemmas().contains(word),
+    }
+}
+
+/// All (inflected form, lemma) entries whose form starts with `prefix`,
+/// in lexicographic order. `limit` caps the number of returned entries.
+pub fn prefix_search(prefix: &str, limit: Option<usize>) -> Vec<(String, String)> {
+    if let Some(table) = resources::lemmas() {
+        return table
+            .forms
+            .range::<str, _>((std::ops::Bound::Included(prefix), std::ops::Bound::Unbounded))
+            .take_while(|(form, _)| form.starts_with(prefix))
+            .take(limit.unwrap_or(usize::MAX))
+            .map(|(form, lemma)| (form.clone(), lemma.clone()))
+            .collect();
+    }
+    let matcher = Str::new(prefix).starts_with();
+    let mut stream = forms().search(matcher).into_stream();
+    let mut results = Vec::new();
+
+    while let Some((key, value)) = stream.next() {
+        if limit.is_some_and(|max| results.len() >= max) {
+            break;
+        }
+        // Keys were inserted from a UTF-8 source, so this never fails
+        if let Ok(form) = std::str::from_utf8(key) {
+            results.push((form.to_string(), resolve(value).to_string()));
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(lookup("kitaplar").as_deref(), Some("kitap"));
+        assert_eq!(lookup("geliyorum").as_deref(), Some("gel"));
+        assert_eq!(lookup("bilgisayar"), None);
+        assert_eq!(lookup(""), None);
+    }
+
+    #[test]
+    fn test_is_lemma() {
+        assert!(is_lemma("kitap"));
+        assert!(is_lemma("ev"));
+        assert!(!is_lemma("kitaplar"));
+        assert!(!is_lemma("xyzabc"));
+    }
+
+    #[test]
+    fn test_counts() {
+        assert!(forms().len() > 100);
+        assert!(lemmas().len() > 10);
+        assert!(lemmas().len() <= forms().len());
+    }
+
+    #[test]
+    fn test_prefix_search() {
+        let results = prefix_search("kitap", None);
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|(form, _)| form.starts_with("kitap")));
+        assert!(results
+            .iter()
+            .any(|(form, lemma)| form == "kitaplar" && *lemma == "kitap"));
+
+        // Results are sorted lexicographically
+        let forms: Vec<&String> = results.iter().map(|(f, _)| f).collect();
+        let mut sorted = forms.clone();
+        sorted.sort();
+        assert_eq!(forms, sorted);
+    }
+
+    #[test]
+    fn test_prefix_search_limit() {
+        assert_eq!(prefix_search("", Some(5)).len(), 5);
+        assert!(prefix_search("qqq", None).is_empty());
+    }
+}
@@ -1,14 +1,78 @@
+// Without the Python bindings most helpers are only reachable through `ffi` and `wasm`
+#![cfg_attr(not(feature = "python"), allow(dead_code))]
+
+mod agreement;
+mod anomaly;
+mod analyzer;
+mod arena;
+#[cfg(feature = "python")]
+mod arrow;
+mod augment;
+#[doc(hidden)]
+pub mod bench;
+mod bidi;
+mod bpe;
+mod cancel;
+mod config;
+mod confusion;
+mod conllu;
+mod coref;
+mod datetime_tr;
+mod dedup;
+mod detokenizer;
+mod dialect;
+mod emoji;
+mod etymology;
+#[cfg(any(feature = "ffi", test))]
+mod ffi;
+mod grammar;
+mod keyboard;
+mod keywords;
+mod language;
+mod lemma_cache;
+mod lemma_dict;
 mod morphotactics;
+mod ndjson;
+mod noise;
+mod ner;
+mod numbers;
+mod parallel;
+mod pdf;
+mod phrases;
+mod pos;
+mod readability;
+mod register;
+mod resources;
+mod rewrite;
 mod root_validator;
+mod sampling;
+mod segmentation;
+mod similarity;
+mod spill;
+mod spell;
+mod stats;
+mod stemmer;
+mod stream;
+mod subword;
+mod suggestion;
+mod token_regex;
+mod unigram;
+mod vowel_drop;
 mod vowel_harmony;
+#[cfg(feature = "wasm")]
+mod wasm;
 
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
-use regex::Regex;
 // will be keeping for backward compatability
 use serde::{Deserialize, Serialize};
 use root_validator::RootValidator;
+use config::OffsetMode;
+use token_regex::{ClassSet, TokenClass};
+use std::cmp::Reverse;
 use std::collections::HashMap;
 use std::sync::OnceLock;
+use unicode_normalization::UnicodeNormalization;
 
 
 // Embedded resources using include_str! for zero-overhead loading
@@ -18,66 +82,325 @@ static STOPWORDS_TR_DATA: &str = include_str!("../resources/tr/stopwords/base/tu
 static STOPWORDS_METADATA_DATA: &str = include_str!("../resources/tr/stopwords/metadata.json");
 static STOPWORDS_SOCIAL_MEDIA_DATA: &str = include_str!("../resources/tr/stopwords/domains/social_media.txt");
 static RESOURCE_METADATA: &str = include_str!("../resources/metadata.json");
-// incoming change will be moved into bayesian clustering in later updates
-static LEMMA_DICT_DATA: &str = include_str!("../resources/tr/lemmas/turkish_lemma_dict.txt");
+// The lemma dictionary is precompiled into an FST by build.rs (see lemma_dict.rs)
 
-static LEMMA_DICT: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
-static TOKEN_REGEX: OnceLock<Regex> = OnceLock::new();
 static DETACHED_SUFFIXES: OnceLock<Vec<&'static str>> = OnceLock::new();
 static STOPWORDS_BASE: OnceLock<Vec<&'static str>> = OnceLock::new();
 
-fn get_lemma_dict() -> &'static HashMap<&'static str, &'static str> {
-    LEMMA_DICT.get_or_init(|| {
-        // Load Turkish lemma dictionary from embedded TSV resource
-        // Format: inflected_form<TAB>lemma
-        let mut m = HashMap::new();
+/// Check if a word is a known lemma (root form) in the dictionary
+fn is_known_lemma(word: &str) -> bool {
+    // If the word is itself an inflected entry, it is a lemma only if it maps to itself
+    if let Some(lemma) = lemma_dict::lookup(word) {
+        return lemma == word;
+    }
 
-        for line in LEMMA_DICT_DATA.lines() {
-            let line = line.trim();
-            if line.is_empty() || line.starts_with('#') {
-                continue;
-            }
+    // Otherwise check whether any entry has this as its lemma
+    lemma_dict::is_lemma(word)
+}
 
-            if let Some((inflected, lemma)) = line.split_once('\t') {
-                m.insert(inflected.trim(), lemma.trim());
-            }
+/// Reverse consonant softening on a stem left by stripping a vowel-initial
+/// suffix (kitab-ı → kitap, ağac-ı → ağaç, çocuğ-u → çocuk, reng-i → renk).
+///
+/// The hardened stem is accepted when it is a known lemma. Without dictionary
+/// support it is only accepted for b, c and ğ, which rarely end a
+/// polysyllabic Turkish word, and only if it passes root validation; final d
+/// and ng are common enough (ad, kod, ping) to require the dictionary.
+fn restore_softened_root(stem: &str, suffix: &str, validator: &RootValidator) -> Option<String> {
+    let vowel_initial = suffix
+        .chars()
+        .next()
+        .is_some_and(|c| vowel_harmony::get_vowel_class(c).is_some());
+    if !vowel_initial {
+        return None;
+    }
+
+    let mut chars: Vec<char> = stem.chars().collect();
+    let last = chars.pop()?;
+    let hard = match last {
+        'b' => 'p',
+        'c' => 'ç',
+        'd' => 't',
+        'ğ' => 'k',
+        'g' if chars.last() == Some(&'n') => 'k',
+        _ => return None,
+    };
+    chars.push(hard);
+    let hardened: String = chars.into_iter().collect();
+
+    if is_known_lemma(&hardened) {
+        return Some(hardened);
+    }
+
+    let syllables = stem
+        .chars()
+        .filter(|&c| vowel_harmony::get_vowel_class(c).is_some())
+        .count();
+    let rarely_final = matches!(last, 'b' | 'c' | 'ğ');
+    (rarely_final && syllables > 1 && validator.is_valid_root(&hardened)).then_some(hardened)
+}
+
+/// Full root for a stem left by stripping `suffix`, undoing vowel drop
+/// (burn-u → burun) or consonant softening (kitab-ı → kitap)
+fn restore_root(stem: &str, suffix: &str, validator: &RootValidator) -> Option<String> {
+    vowel_drop::restore(stem, suffix).or_else(|| restore_softened_root(stem, suffix, validator))
+}
+
+/// Known lemma, or a vowel-dropping root that the dictionary may lack
+fn is_known_root(word: &str) -> bool {
+    is_known_lemma(word) || vowel_drop::is_listed_root(word)
+}
+
+/// Tokens longer than this many characters are BLOB tokens (base64 data,
+/// minified code, runaway "words"); the longest Turkish words have about 70
+const MAX_WORD_CHARS: usize = 128;
+
+/// Whether a token is too long to be a word. Lemmatization, analysis and
+/// spelling pass such tokens through unchanged instead of scanning them.
+fn is_blob(token: &str) -> bool {
+    token.len() > MAX_WORD_CHARS && token.chars().nth(MAX_WORD_CHARS).is_some()
+}
+
+/// The first `MAX_WORD_CHARS` characters of a token
+fn truncate_blob(token: &str) -> &str {
+    let end = token.char_indices().nth(MAX_WORD_CHARS).map_or(token.len(), |(i, _)| i);
+    &token[..end]
+}
+
+/// Token class reported by `tokenize_with_types`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Url,
+    Email,
+    Phone,
+    Iban,
+    Date,
+    Time,
+    Hashtag,
+    Mention,
+    Emoticon,
+    Emoji,
+    Word,
+    Number,
+    Punct,
+    Blob,
+}
+
+impl TokenKind {
+    const ALL: [TokenKind; 14] = [
+        TokenKind::Url,
+        TokenKind::Email,
+        TokenKind::Phone,
+        TokenKind::Iban,
+        TokenKind::Date,
+        TokenKind::Time,
+        TokenKind::Hashtag,
+        TokenKind::Mention,
+        TokenKind::Emoticon,
+        TokenKind::Emoji,
+        TokenKind::Word,
+        TokenKind::Number,
+        TokenKind::Punct,
+        TokenKind::Blob,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            TokenKind::Url => "URL",
+            TokenKind::Email => "EMAIL",
+            TokenKind::Phone => "PHONE",
+            TokenKind::Iban => "IBAN",
+            TokenKind::Date => "DATE",
+            TokenKind::Time => "TIME",
+            TokenKind::Hashtag => "HASHTAG",
+            TokenKind::Mention => "MENTION",
+            TokenKind::Emoticon => "EMOTICON",
+            TokenKind::Emoji => "EMOJI",
+            TokenKind::Word => "WORD",
+            TokenKind::Number => "NUMBER",
+            TokenKind::Punct => "PUNCT",
+            TokenKind::Blob => "BLOB",
+        }
+    }
+}
+
+/// A token as a byte range of the input, with the class that matched it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TokenSpan {
+    start: usize,
+    end: usize,
+    class: TokenClass,
+    emoji: bool,
+}
+
+impl TokenSpan {
+    fn kind(&self, text: &str) -> TokenKind {
+        if self.emoji {
+            return TokenKind::Emoji;
         }
+        let token = &text[self.start..self.end];
+        if is_blob(token) {
+            // Classified by a prefix; only URLs are legitimately this long
+            let prefix = token_regex::Scanner::new(truncate_blob(token), ClassSet::ALL).find_at(0);
+            return match prefix.map(|m| m.class) {
+                Some(TokenClass::Url) => TokenKind::Url,
+                _ => TokenKind::Blob,
+            };
+        }
+        match self.class {
+            TokenClass::Url => TokenKind::Url,
+            TokenClass::Email => TokenKind::Email,
+            TokenClass::Iban => TokenKind::Iban,
+            TokenClass::Phone => TokenKind::Phone,
+            TokenClass::Date => TokenKind::Date,
+            TokenClass::Time => TokenKind::Time,
+            TokenClass::Hashtag => TokenKind::Hashtag,
+            TokenClass::Mention => TokenKind::Mention,
+            TokenClass::Emoticon => TokenKind::Emoticon,
+            TokenClass::Apostrophe | TokenClass::Word => TokenKind::Word,
+            TokenClass::Number => TokenKind::Number,
+            TokenClass::Punct => TokenKind::Punct,
+        }
+    }
 
-        m
-    })
+    /// A lone non-word character (same rule as the Python tokenizer)
+    fn is_punct(&self, text: &str) -> bool {
+        let mut chars = text[self.start..self.end].chars();
+        !self.emoji
+            && matches!(
+                (chars.next(), chars.next()),
+                (Some(c), None) if !c.is_alphanumeric() && c != '_'
+            )
+    }
 }
 
-/// Check if a word is a known lemma (root form) in the dictionary
-fn is_known_lemma(word: &str) -> bool {
-    let dict = get_lemma_dict();
+/// Token spans of a text, found one at a time. A match that starts an emoji
+/// grapheme cluster is widened to the whole cluster, so ZWJ sequences,
+/// flags, keycaps and emoji with modifiers are single tokens instead of one
+/// token per code point.
+struct TokenSpans<'a> {
+    text: &'a str,
+    scanner: token_regex::Scanner<'a>,
+    /// Byte offset where the search for the next token starts
+    position: usize,
+}
 
-    // Check if word is a lemma (appears as value in dictionary)
-    // OR if word maps to itself (is both inflected form and lemma)
-    if let Some(&lemma) = dict.get(word) {
-        // Word is in dictionary, check if it's the lemma form
-        return lemma == word;
+impl<'a> TokenSpans<'a> {
+    fn new(text: &'a str) -> Self {
+        Self::with_classes(text, ClassSet::ALL)
     }
 
-    // Also check if any entry has this as its lemma
-    dict.values().any(|&lemma| lemma == word)
+    /// Token spans with only `classes` kept whole (see `token_regex`)
+    fn with_classes(text: &'a str, classes: ClassSet) -> Self {
+        Self { text, scanner: token_regex::Scanner::new(text, classes), position: 0 }
+    }
 }
 
-fn get_token_regex() -> &'static Regex {
-    TOKEN_REGEX.get_or_init(|| {
-        // Regex patterns tuned for Turkish tokenization (ported from Python)
-        // URL, Emoticon, Apostrophe, Number, Word, Punctuation
-        let pattern = r"(?x)
-            (https?://[^\s]+|www\.[^\s]+) |          # URL
-            ([:;=8][-^']?[)DPOo(\[/\\]) |            # Emoticon
-            ([A-Za-zÇĞİÖŞÜçğıöşü]+(?:'[A-Za-zÇĞİÖŞÜçğıöşü]+)?) | # Apostrophe
-            (\d+(?:[.,]\d+)*(?:[-–]\d+)?) |          # Number
-            ([A-Za-zÇĞİÖŞÜçğıöşü]+(?:-[A-Za-zÇĞİÖŞÜçğıöşü]+)*) | # Word
-            ([^\w\s])                                # Punctuation
-        ";
-        Regex::new(pattern).expect("Invalid regex pattern")
-    })
+impl Iterator for TokenSpans<'_> {
+    type Item = TokenSpan;
+
+    fn next(&mut self) -> Option<TokenSpan> {
+        let mat = self.scanner.find_at(self.position)?;
+        let (end, emoji) = match emoji::cluster_at(self.text, mat.start) {
+            Some(end) => (end, true),
+            None => (mat.end, false),
+        };
+        let span = TokenSpan { start: mat.start, end, class: mat.class, emoji };
+        self.position = span.end;
+        Some(span)
+    }
+}
+
+/// Token spans of `text` (see `TokenSpans`)
+fn token_spans(text: &str) -> Vec<TokenSpan> {
+    TokenSpans::new(text).collect()
+}
+
+/// Tokens of a text as (token, start, end) with character offsets; tokens
+/// are slices of the text, so nothing is allocated per token until the
+/// caller copies them (once, into Python strings, at the PyO3 boundary).
+/// Regex matches give byte offsets; Python indexes strings by character.
+struct TokenIter<'a> {
+    spans: TokenSpans<'a>,
+    /// Byte offset of the end of the previous token
+    byte_position: usize,
+    /// Character offset of `byte_position`
+    char_position: usize,
+}
+
+impl<'a> TokenIter<'a> {
+    fn new(text: &'a str) -> Self {
+        Self::with_classes(text, ClassSet::ALL)
+    }
+
+    fn with_classes(text: &'a str, classes: ClassSet) -> Self {
+        Self {
+            spans: TokenSpans::with_classes(text, classes),
+            byte_position: 0,
+            char_position: 0,
+        }
+    }
+
+    /// The next token span with its start and end character offsets
+    fn next_span(&mut self) -> Option<(TokenSpan, usize, usize)> {
+        let span = self.spans.next()?;
+        let text = self.spans.text;
+        let char_start = self.char_position + text[self.byte_position..span.start].chars().count();
+        let char_end = char_start + text[span.start..span.end].chars().count();
+        self.byte_position = span.end;
+        self.char_position = char_end;
+        Some((span, char_start, char_end))
+    }
+
+    /// The remaining tokens with their spans, for callers that need the
+    /// token kind
+    fn with_spans(mut self) -> impl Iterator<Item = (&'a str, TokenSpan, usize, usize)> {
+        std::iter::from_fn(move || {
+            let text = self.spans.text;
+            self.next_span()
+                .map(|(span, start, end)| (&text[span.start..span.end], span, start, end))
+        })
+    }
+
+    /// `with_spans` with offsets in `mode` units
+    fn with_offsets(
+        self,
+        mode: OffsetMode,
+    ) -> impl Iterator<Item = (&'a str, TokenSpan, usize, usize)> {
+        self.with_spans().map(move |(token, span, start, end)| match mode {
+            OffsetMode::Char => (token, span, start, end),
+            OffsetMode::Byte => (token, span, span.start, span.end),
+        })
+    }
+}
+
+impl<'a> Iterator for TokenIter<'a> {
+    type Item = (&'a str, usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.spans.text;
+        self.next_span()
+            .map(|(span, start, end)| (&text[span.start..span.end], start, end))
+    }
+}
+
+/// Type name of a single token as in `tokenize_with_types` ("WORD",
+/// "DATE"), None when the text is not exactly one token
+pub(crate) fn token_kind(token: &str) -> Option<&'static str> {
+    match token_spans(token).as_slice() {
+        [span] if span.start == 0 && span.end == token.len() => Some(span.kind(token).as_str()),
+        _ => None,
+    }
+}
+
+/// The type name for a `tokenize_with_types` label, None for unknown labels
+pub(crate) fn token_kind_name(label: &str) -> Option<&'static str> {
+    TokenKind::ALL
+        .iter()
+        .map(|kind| kind.as_str())
+        .find(|&name| name == label)
 }
 
+
 /// Fast normalization for Turkish text.
 /// Handles I/ı and İ/i conversion correctly and optionally lowercases the rest.
 /// 
@@ -85,7 +408,6 @@ fn get_token_regex() -> &'static Regex {
 /// * `text` - Input text to normalize
 /// * `lowercase` - If true, convert text to lowercase
 /// * `handle_turkish_i` - If true, handle Turkish İ/I conversion (İ→i, I→ı)
-#[pyfunction]
 fn fast_normalize(text: &str, lowercase: bool, handle_turkish_i: bool) -> String {
     // Rust handles Turkish I/ı conversion correctly and instantly
     // "Single Pass" allocation for maximum speed
@@ -110,415 +432,4337 @@ fn fast_normalize(text: &str, lowercase: bool, handle_turkish_i: bool) -> String
     }).collect()
 }
 
-/// Tokenize text and return tokens with their start and end character offsets.
-/// Returns a list of (token, start, end).
-#[pyfunction]
-fn tokenize_with_offsets(text: &str) -> Vec<(String, usize, usize)> {
-    let re = get_token_regex();
-    let mut results = Vec::new();
-
-    for caps in re.captures_iter(text) {
-        if let Some(mat) = caps.get(0) {
-            let token = mat.as_str().to_string();
-            // In Rust regex, `mat.start()` and `mat.end()` return byte indices.
-            // Python expects character indices. We must convert carefully.
-            // However, typical NLP tools often work with byte offsets or char offsets.
-            // Here we want char offsets strictly for Python compatibility if possible,
-            // OR we just return byte offsets and let Python handle it?
-            // "The Fix: Your Rust tokenizer must return Offset Mappings (start/end indices pointing back to the original raw text)"
-            // Usually Python users expect char indices.
-
-            // Converting byte offset to char offset is O(N) scan unless we map it.
-            // For now, let's just return what Regex gives us, which is byte offsets,
-            // BUT for this PoC we can do a quick char count up to that point if we want absolute correctness,
-            // or just note that these are byte offsets (Rust UTF-8).
-            // Let's implement char offset conversion for correctness.
-            let byte_start = mat.start();
-            let byte_end = mat.end();
+/// Uppercase Turkish text: i→İ and ı→I, everything else by Unicode rules.
+#[cfg_attr(feature = "python", pyfunction)]
+fn fast_uppercase(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            'i' => result.push('İ'),
+            'ı' => result.push('I'),
+            // Some characters expand when uppercased (ß → SS)
+            _ => result.extend(c.to_uppercase()),
+        }
+    }
+    result
+}
 
-            let char_start = text[..byte_start].chars().count();
-            let char_len = text[byte_start..byte_end].chars().count();
-            let char_end = char_start + char_len;
+/// Case conversion applied by `turkish_casefold`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CaseMode {
+    Lower,
+    Upper,
+    /// Lowercase plus Unicode full case folding (ß → ss, ς → σ): a key for
+    /// case-insensitive comparison, not for display
+    Fold,
+}
 
-            results.push((token, char_start, char_end));
+impl CaseMode {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "lower" => Some(CaseMode::Lower),
+            "upper" => Some(CaseMode::Upper),
+            "fold" => Some(CaseMode::Fold),
+            _ => None,
         }
     }
-    results
 }
 
-/// Tokenize text and return normalized tokens with offsets pointing to original text.
-/// This is the NER-friendly version: tokens are normalized but offsets reference the raw input.
-/// 
-/// # Example
-/// ```rust
-/// let text = "İstanbul'a gittim";
-/// let tokens = tokenize_with_normalized_offsets(text);
-/// // Returns: [("istanbul'a", 0, 10), ("gittim", 11, 17)]
-/// // Note: tokens are lowercased but offsets still point to "İstanbul'a" in original
-/// ```
-#[pyfunction]
-fn tokenize_with_normalized_offsets(text: &str) -> Vec<(String, usize, usize)> {
-    let re = get_token_regex();
-    let mut results = Vec::new();
+/// Lowercase a character with Turkish I rules: İ→i and I→ı, everything else
+/// by Unicode rules (İ is the only letter whose lowercase is longer than one
+/// character)
+pub(crate) fn turkish_lower_char(c: char) -> char {
+    match c {
+        'İ' => 'i',
+        'I' => 'ı',
+        _ => c.to_lowercase().next().unwrap_or(c),
+    }
+}
+
+/// Lowercase Turkish text with `turkish_lower_char`
+pub(crate) fn turkish_lowercase(text: &str) -> String {
+    text.chars().map(turkish_lower_char).collect()
+}
 
-    for caps in re.captures_iter(text) {
-        if let Some(mat) = caps.get(0) {
-            let token = mat.as_str();
-            let normalized_token = fast_normalize(token);
-            
-            let byte_start = mat.start();
-            let byte_end = mat.end();
-            
-            let char_start = text[..byte_start].chars().count();
-            let char_len = text[byte_start..byte_end].chars().count();
-            let char_end = char_start + char_len;
-            
-            results.push((normalized_token, char_start, char_end));
+/// Words of `text` as runs of letters, with their byte offsets
+pub(crate) fn words(text: &str) -> regex::Matches<'static, '_> {
+    static WORD_REGEX: OnceLock<regex::Regex> = OnceLock::new();
+    WORD_REGEX
+        .get_or_init(|| regex::Regex::new(r"\p{L}+").expect("Invalid word regex"))
+        .find_iter(text)
+}
+
+fn turkish_casefold_with(text: &str, mode: CaseMode) -> String {
+    match mode {
+        CaseMode::Lower => turkish_lowercase(text),
+        CaseMode::Upper => fast_uppercase(text),
+        CaseMode::Fold => {
+            let mut result = String::with_capacity(text.len());
+            for c in turkish_lowercase(text).chars() {
+                match c {
+                    'ß' => result.push_str("ss"),
+                    'ς' => result.push('σ'),
+                    _ => result.push(c),
+                }
+            }
+            result
         }
     }
-    results
 }
 
-/// Tier 1: Exact Lookup
+/// Turkish-aware case conversion, `mode` = "lower", "upper" or "fold".
+/// "fold" gives keys for case-insensitive dictionaries and deduplication:
+/// `turkish_casefold(a, "fold") == turkish_casefold(b, "fold")` when a and b
+/// differ only in case ("IŞIK" and "ışık", but not "ISIK" and "isik").
+#[cfg(feature = "python")]
 #[pyfunction]
-fn lookup_lemma(word: &str) -> Option<String> {
-    let dict = get_lemma_dict();
-    dict.get(word).map(|s| s.to_string())
+#[pyo3(signature = (text, mode="fold"))]
+fn turkish_casefold(text: &str, mode: &str) -> PyResult<String> {
+    let mode = CaseMode::parse(mode).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "unknown case mode '{}', expected 'lower', 'upper' or 'fold'",
+            mode
+        ))
+    })?;
+    Ok(turkish_casefold_with(text, mode))
 }
 
-/// Turkish suffix categories for morphological analysis
-///
-/// Turkish is an agglutinative language with complex suffix chains.
-/// Suffixes must be applied in a specific order (slot system).
-mod suffixes {
-    /// Compound suffixes that should be stripped as a unit before individual suffixes
-    /// These are morphological combinations that occur frequently
-    pub const COMPOUND_SUFFIXES: &[&str] = &[
-        // Without doing (negation + ablative): gel-meden = without coming
-        "meden",
-        "madan",
-        // Plural + Possessive combinations
-        "larımız",
-        "lerimiz",
-        "ların",
-        "lerin",
-        "larımızdan",
-        "lerimizden",
-        // Plural + Case combinations
-        "lardan",
-        "lerden",
-        "lara",
-        "lere",
-        // Possessive + Case combinations
-        "ımızdan",
-        "imizden",
-        "ındanan",
-        "indenan",
-        // Verbal compound: gel-me-di-m = didn't come
-        "medim",
-        "madım",
-        "medin",
-        "madın",
-        "medi",
-        "madı",
-        "medik",
-        "madık",
-        "mişim",
-        "mışım",
-        "mişiz",
-        "mışız",
-        // Continuous + person: geliyorum
-        "yorum",
-        "yorsun",
-        "yor",
-        "yoruz",
-        "yorsunuz",
-        "yorlar",
-        // Future + person
-        "eceğim",
-        "acağım",
-        "eceğiz",
-        "acağız",
-        "eceksin",
-        "acaksın",
-        // Ability
-        "yebilmek",
-        "yabilmek",
-        "ebilirim",
-        "abilirim",
-    ];
+/// Unicode normalization form (UAX #15)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UnicodeForm {
+    Nfc,
+    Nfkc,
+    Nfd,
+    Nfkd,
+}
 
-    /// Nominal suffixes (attached to nouns, adjectives)
-    /// Order: Plural → Possessive → Case
-    pub const NOMINAL_SUFFIXES: &[&str] = &[
-        // Plural (Slot 1)
-        "lar", "ler", // Possessive (Slot 2) - 1st person
-        "ım", "im", "um", "üm", "ımız", "imiz", "umuz", "ümüz",
-        // Possessive (Slot 2) - 2nd person
-        "ın", "in", "un", "ün", "ınız", "iniz", "unuz", "ünüz",
-        // Possessive (Slot 2) - 3rd person
-        "ı", "i", "u", "ü", "sı", "si", "su", "sü", // Case (Slot 3) - Accusative
-        "ı", "i", "u", "ü", // Case (Slot 3) - Dative
-        "a", "e", "ya", "ye", // Case (Slot 3) - Locative
-        "da", "de", "ta", "te", // Case (Slot 3) - Ablative
-        "dan", "den", "tan", "ten", // Case (Slot 3) - Genitive
-        "ın", "in", "un", "ün", "nın", "nin", "nun", "nün",
-    ];
+impl UnicodeForm {
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "NFC" => Some(UnicodeForm::Nfc),
+            "NFKC" => Some(UnicodeForm::Nfkc),
+            "NFD" => Some(UnicodeForm::Nfd),
+            "NFKD" => Some(UnicodeForm::Nfkd),
+            _ => None,
+        }
+    }
 
-    /// Verbal suffixes (attached to verbs)
-    /// Order: Voice → Negation → Tense/Aspect → Person
-    pub const VERBAL_SUFFIXES: &[&str] = &[
-        // Voice (Slot 1) - Passive, Causative, Reflexive, Reciprocal
-        "ıl", "il", "ul", "ül", "n", "ın", "in", "un", "ün", "dir", "dır", "dur", "dür", "tir",
-        "tır", "tur", "tür", "ş", "ış", "iş", "uş", "üş", // Negation (Slot 2)
-        "me", "ma", // Tense/Aspect (Slot 3)
-        "di", "dı", "du", "dü", "ti", "tı", "tu", "tü", // Definite past
-        "miş", "mış", "muş", "müş", // Evidential/Indefinite past
-        "ecek", "acak", // Future
-        "iyor", // Present continuous (fixed form - always "iyor")
-        "er", "ar", // Aorist
-        "mekte", "makta", // Progressive
-        // Person (Slot 4)
-        "m", "n", "k", "z", "ım", "im", "um", "üm", "ın", "in", "un", "ün", "ız", "iz", "uz", "üz",
-        "sın", "sin", "sun", "sün", "sınız", "siniz", "sunuz", "sünüz", "lar",
-        "ler", // 3rd person plural
-        // Infinitive
-        "mek", "mak",
-    ];
+    fn apply(self, text: &str) -> String {
+        match self {
+            UnicodeForm::Nfc => text.nfc().collect(),
+            UnicodeForm::Nfkc => text.nfkc().collect(),
+            UnicodeForm::Nfd => text.nfd().collect(),
+            UnicodeForm::Nfkd => text.nfkd().collect(),
+        }
+    }
 
-    /// Fixed morphemes that don't follow standard vowel harmony
-    /// These should be handled specially in harmony checking
-    pub const FIXED_MORPHEMES: &[&str] = &[
-        "iyor",  // Present continuous - always "iyor" regardless of root
-        "ken",   // While - always "ken"
-        "ki",    // Relative - always "ki"
-        "leyin", // Time - always "leyin"
-    ];
+    /// Composed form with the same compatibility mapping (NFD → NFC, NFKD → NFKC)
+    fn composed(self) -> Self {
+        match self {
+            UnicodeForm::Nfc | UnicodeForm::Nfd => UnicodeForm::Nfc,
+            UnicodeForm::Nfkc | UnicodeForm::Nfkd => UnicodeForm::Nfkc,
+        }
+    }
 }
 
-/// Tier 2: Heuristic Suffix Stripping
-/// Simple rule-based stripper for demonstration.
-/// In production, this would use a more complex state machine and vowel harmony checks.
+#[cfg(feature = "python")]
+fn parse_unicode_form(name: &str) -> PyResult<UnicodeForm> {
+    UnicodeForm::parse(name).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "unknown normalization form '{}', expected NFC, NFKC, NFD or NFKD",
+            name
+        ))
+    })
+}
+
+/// `fast_normalize` preceded by Unicode normalization.
+/// The Turkish I rules match precomposed characters (a decomposed "İ" is
+/// "I" + U+0307), so text is composed first and decomposed forms are only
+/// produced after casing.
+fn normalize_with_form(
+    text: &str,
+    lowercase: bool,
+    handle_turkish_i: bool,
+    form: UnicodeForm,
+) -> String {
+    let composed = form.composed().apply(text);
+    let normalized = fast_normalize(&composed, lowercase, handle_turkish_i);
+    if form == form.composed() {
+        normalized
+    } else {
+        form.apply(&normalized)
+    }
+}
+
+/// Fast normalization for Turkish text, with optional Unicode normalization
+/// (`unicode_form` = "NFC", "NFKC", "NFD" or "NFKD") for text copied from
+/// PDFs and the web, where "ğ" may arrive as "g" + combining breve.
+#[cfg(feature = "python")]
 #[pyfunction]
-fn strip_suffixes(word: &str) -> String {
-    let mut current = word.to_string();
+#[pyo3(
+    name = "fast_normalize",
+    signature = (text, lowercase=true, handle_turkish_i=true, unicode_form=None)
+)]
+fn fast_normalize_py(
+    text: &str,
+    lowercase: bool,
+    handle_turkish_i: bool,
+    unicode_form: Option<&str>,
+) -> PyResult<String> {
+    let Some(name) = unicode_form else {
+        return Ok(fast_normalize(text, lowercase, handle_turkish_i));
+    };
+    let form = parse_unicode_form(name)?;
+    Ok(normalize_with_form(text, lowercase, handle_turkish_i, form))
+}
 
-    // Use compound suffixes first (longest match)
-    let all_suffixes: Vec<&str> = suffixes::COMPOUND_SUFFIXES
-        .iter()
-        .chain(suffixes::NOMINAL_SUFFIXES.iter())
-        .chain(suffixes::VERBAL_SUFFIXES.iter())
-        .cloned()
-        .collect();
+/// Apply Unicode normalization ("NFC", "NFKC", "NFD" or "NFKD") to text.
+/// Raises ValueError for an unknown form.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (text, form="NFC"))]
+fn unicode_normalize(text: &str, form: &str) -> PyResult<String> {
+    Ok(parse_unicode_form(form)?.apply(text))
+}
 
-    let mut changed = true;
-    while changed {
-        changed = false;
-        // Sort by length descending for greedy matching
-        let mut sorted: Vec<&&str> = all_suffixes.iter().collect();
-        sorted.sort_by(|a, b| b.len().cmp(&a.len()));
+/// Replace high-frequency Turkish misspellings with their correct spelling.
+/// Uses the embedded confusion pairs from resources/tr/config/confusion_pairs.txt
+/// ("herşey" → "her şey", "yanlız" → "yalnız", "şarz" → "şarj").
+/// Matching is whole-word and case-insensitive; original casing is preserved.
+#[cfg_attr(feature = "python", pyfunction)]
+fn correct_confusions(text: &str) -> String {
+    confusion::correct(text)
+}
 
-        for suffix in sorted {
-            if current.ends_with(suffix) && current.chars().count() > suffix.chars().count() + 2 {
-                current = current[..current.len() - suffix.len()].to_string();
-                changed = true;
-                break;
-            }
-        }
-    }
-    current
+/// Repair the line wrapping of PDF-extracted text: remove soft hyphens and join
+/// words broken across lines after a hyphen ("kitap-\nları" → "kitapları") or
+/// mid-word when the joined form is a word and the halves are not.
+#[cfg_attr(feature = "python", pyfunction)]
+fn fix_pdf_artifacts(text: &str) -> String {
+    pdf::fix_artifacts(text)
 }
 
-/// Strip suffixes with root validity checking, vowel harmony, and morphotactic validation
-/// Prevents over-stripping by validating candidate roots, checking vowel harmony,
-/// and ensuring morphologically valid suffix ordering
+/// Arabic and Hebrew runs of a text as (text, script, start, end) with
+/// character offsets; script is "ARABIC" or "HEBREW".
+#[cfg_attr(feature = "python", pyfunction)]
+fn rtl_spans(text: &str) -> Vec<(String, &'static str, usize, usize)> {
+    bidi::rtl_spans(text)
+        .into_iter()
+        .map(|span| (span.text, span.script.as_str(), span.start, span.end))
+        .collect()
+}
+
+/// Text for display: bidi controls removed and Arabic/Hebrew runs wrapped in
+/// RLI … PDI so they cannot reorder the text around them.
+#[cfg_attr(feature = "python", pyfunction)]
+fn isolate_rtl(text: &str) -> String {
+    bidi::isolate(text)
+}
+
+type GrammarTuple = (String, &'static str, &'static str, Option<String>, usize, usize);
+
+/// Check text against the grammar rules of resources/tr/config/grammar_rules.txt
+/// ("bende geldim" → "ben de geldim", "geldinmi" → "geldin mi").
+/// Returns a list of (text, rule, message, suggestion, start, end) with character
+/// offsets into the input; suggestion is None when no fix could be generated.
+#[cfg_attr(feature = "python", pyfunction)]
+fn check_grammar(text: &str) -> Vec<GrammarTuple> {
+    grammar::check(text)
+        .into_iter()
+        .map(|i| (i.text, i.rule, i.message, i.suggestion, i.start, i.end))
+        .collect()
+}
+
+/// Split an accidentally concatenated token into its words.
+/// Uses dictionary-guided DP segmentation over the lemma dictionary, stopwords
+/// and question particles ("çokgüzel" → ["çok", "güzel"]).
+/// Returns the token unchanged (as a single element) when no full split exists.
+#[cfg_attr(feature = "python", pyfunction)]
+fn split_joined(token: &str) -> Vec<String> {
+    segmentation::split_joined(token)
+}
+
+/// Split a hashtag into lowercase words at camelCase humps, underscores and
+/// digits, de-concatenating each part ("#GünaydınTürkiye" → ["günaydın", "türkiye"]).
+#[cfg_attr(feature = "python", pyfunction)]
+fn split_hashtag(tag: &str) -> Vec<String> {
+    segmentation::split_hashtag(tag)
+}
+
+/// Extract named entities (rule + gazetteer based).
+/// Recognizes person names, provinces, organizations, dates, money amounts and URLs.
+/// Returns a list of (text, label, start, end) with character offsets into the input;
+/// labels are PER, LOC, ORG, DATE, MONEY and URL.
+#[cfg_attr(feature = "python", pyfunction)]
+fn extract_entities(text: &str) -> Vec<(String, String, usize, usize)> {
+    ner::extract_entities(text)
+        .into_iter()
+        .map(|e| (e.text, e.label.as_str().to_string(), e.start, e.end))
+        .collect()
+}
+
+/// Extract dates and times in Turkish formats ("12.03.2024", "3 Mart 2024",
+/// "saat 14:30") with their ISO 8601 forms.
+/// Returns a list of (text, label, iso, start, end) with character offsets into the input;
+/// labels are DATE and TIME. Days the month does not have are skipped.
+#[cfg_attr(feature = "python", pyfunction)]
+fn extract_dates(text: &str) -> Vec<(String, String, String, usize, usize)> {
+    datetime_tr::extract(text)
+        .into_iter()
+        .map(|e| (e.text, e.kind.as_str().to_string(), e.iso, e.start, e.end))
+        .collect()
+}
+
+type DialectTuple = (String, &'static str, Option<String>, usize, usize);
+
+/// Detect dialectal spellings and Azerbaijani-influenced forms ("gız", "çox",
+/// "gidecağam") with the cue lexicon of resources/tr/config/dialect_cues.txt.
+/// Returns a list of (text, variety, standard, start, end) with character offsets
+/// into the input; varieties are AZERBAIJANI, ANATOLIAN, EASTERN and BLACK_SEA.
+#[cfg_attr(feature = "python", pyfunction)]
+fn detect_dialect(text: &str) -> Vec<DialectTuple> {
+    dialect::detect(text)
+        .into_iter()
+        .map(|c| (c.text, c.variety.as_str(), c.standard, c.start, c.end))
+        .collect()
+}
+
+/// Dialect variety flag of each token, None for tokens without a cue.
+#[cfg_attr(feature = "python", pyfunction)]
+fn dialect_flags(tokens: Vec<String>) -> Vec<Option<&'static str>> {
+    tokens
+        .iter()
+        .map(|token| dialect::flag(token).map(|(variety, _)| variety.as_str()))
+        .collect()
+}
+
+/// Origin of a loanword ("ARABIC", "PERSIAN", "FRENCH" or "ENGLISH") with the
+/// lexicon of resources/tr/config/etymology.txt; inflected words are tagged
+/// through their root. None for native words and unlisted loanwords.
+#[cfg_attr(feature = "python", pyfunction)]
+fn word_origin(word: &str) -> Option<&'static str> {
+    etymology::origin(word).map(|origin| origin.as_str())
+}
+
+/// Loanword origin of each token, None for tokens without one.
+#[cfg_attr(feature = "python", pyfunction)]
+fn etymology_tags(tokens: Vec<String>) -> Vec<Option<&'static str>> {
+    tokens.iter().map(|token| word_origin(token)).collect()
+}
+
+/// Whether the suffixes of a lowercase root take front vowels although its
+/// last vowel is back, as for loanwords with a palatal final consonant
+/// ("saat" → "saatler", "gol" → "golü").
+#[cfg_attr(feature = "python", pyfunction)]
+fn takes_front_harmony(root: &str) -> bool {
+    etymology::takes_front_harmony(root)
+}
+
+/// Detect the language of a text with the character trigram profiles of
+/// resources/tr/config/language_profiles.txt. Returns (language, confidence)
+/// with an ISO 639-1 code and the posterior probability of that language, or
+/// ("und", 0.0) when no profile trigram occurs in the text.
+#[cfg_attr(feature = "python", pyfunction)]
+fn detect_language(text: &str) -> (&'static str, f64) {
+    language::detect(text).unwrap_or(("und", 0.0))
+}
+
+/// Whether a text is Turkish with at least `threshold` confidence; a fast
+/// filter to drop non-Turkish lines before expensive processing.
+#[cfg_attr(feature = "python", pyfunction)]
+#[cfg_attr(feature = "python", pyo3(signature = (text, threshold=0.5)))]
+fn is_turkish(text: &str, threshold: f64) -> bool {
+    language::detect(text).is_some_and(|(language, confidence)| {
+        language == "tr" && confidence >= threshold
+    })
+}
+
+/// Spell out a non-negative integer in Turkish words.
+/// 1250 → "bin iki yüz elli"; "bir" is dropped before "yüz" and "bin" only.
+#[cfg_attr(feature = "python", pyfunction)]
+fn number_to_words(n: u64) -> String {
+    numbers::to_words(n)
+}
+
+/// Read a number spelled in Turkish words ("üç yüz kırk iki" → 342).
+/// Solid spellings ("binikiyüzelli") are accepted; other input raises ValueError.
+#[cfg(feature = "python")]
+#[pyfunction]
+fn words_to_number(text: &str) -> PyResult<u64> {
+    numbers::from_words(text).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "'{}' is not a Turkish number in words",
+            text
+        ))
+    })
+}
+
+/// Extract spelled-out numbers ("üç yüz kırk iki") with their values.
+/// Returns a list of (text, value, start, end) with character offsets into the input;
+/// a lone word that is also an everyday word ("bir", "yüz") is skipped.
+#[cfg_attr(feature = "python", pyfunction)]
+fn extract_numbers(text: &str) -> Vec<(String, u64, usize, usize)> {
+    numbers::extract(text).into_iter().map(|n| (n.text, n.value, n.start, n.end)).collect()
+}
+
+type LinkTuple = (String, &'static str, usize, usize, String, usize, usize);
+
+/// Link 3rd person singular pronouns ("o", "kendisi" and their case forms)
+/// and dropped subjects to the most recent person or organization name at
+/// most `window` sentences back.
+/// Returns a list of (text, kind, start, end, antecedent, antecedent_start,
+/// antecedent_end) with character offsets into the input; kinds are PRONOUN
+/// and ZERO, whose text is the verb of a clause without a subject.
+#[cfg_attr(feature = "python", pyfunction)]
+#[cfg_attr(feature = "python", pyo3(signature = (text, window=2)))]
+fn link_pronouns(text: &str, window: usize) -> Vec<LinkTuple> {
+    coref::link(text, window)
+        .into_iter()
+        .map(|l| {
+            let (start, end) = (l.antecedent_start, l.antecedent_end);
+            (l.text, l.kind.as_str(), l.start, l.end, l.antecedent, start, end)
+        })
+        .collect()
+}
+
+type AgreementTuple = (String, &'static str, &'static str, &'static str, usize, usize);
+
+/// Check person and possessor agreement ("benim arabası", "ben geldin").
+/// Returns a list of (text, kind, expected, found, start, end) with character offsets
+/// into the input; kinds are POSSESSOR and PERSON, expected and found are suffix tags.
+#[cfg_attr(feature = "python", pyfunction)]
+fn check_agreement(text: &str) -> Vec<AgreementTuple> {
+    agreement::check(text)
+        .into_iter()
+        .map(|i| (i.text, i.kind.as_str(), i.expected, i.found, i.start, i.end))
+        .collect()
+}
+
+/// Token spans with their start and end character offsets
+fn spans_with_char_offsets(text: &str) -> Vec<(TokenSpan, usize, usize)> {
+    let mut tokens = TokenIter::new(text);
+    std::iter::from_fn(|| tokens.next_span()).collect()
+}
+
+/// Tokenize text and return tokens with their start and end offsets in
+/// `mode` units. Returns a list of (token, start, end).
+fn tokenize_with_offsets(text: &str, mode: OffsetMode) -> Vec<(&str, usize, usize)> {
+    TokenIter::new(text)
+        .with_offsets(mode)
+        .map(|(token, _, start, end)| (token, start, end))
+        .collect()
+}
+
+/// Tokenize text and return tokens with their type and offsets in `mode`
+/// units. Returns a list of (token, type, start, end); types are URL,
+/// EMOTICON, EMOJI, WORD, NUMBER and PUNCT.
+fn tokenize_with_types(text: &str, mode: OffsetMode) -> Vec<(&str, &'static str, usize, usize)> {
+    TokenIter::new(text)
+        .with_offsets(mode)
+        .map(|(token, span, start, end)| (token, span.kind(text).as_str(), start, end))
+        .collect()
+}
+
+/// The offset unit named by `offsets` ("char" or "byte"), else the
+/// configured one (see config.rs)
+#[cfg(feature = "python")]
+fn offset_mode(offsets: Option<&str>) -> PyResult<OffsetMode> {
+    let Some(name) = offsets else {
+        return Ok(config::read().offsets);
+    };
+    OffsetMode::parse(name).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "unknown offset mode '{}', expected 'char' or 'byte'",
+            name
+        ))
+    })
+}
+
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "tokenize_with_offsets", signature = (text, *, offsets=None))]
+fn tokenize_with_offsets_py<'a>(
+    text: &'a str,
+    offsets: Option<&str>,
+) -> PyResult<Vec<(&'a str, usize, usize)>> {
+    Ok(tokenize_with_offsets(text, offset_mode(offsets)?))
+}
+
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "tokenize_with_types", signature = (text, *, offsets=None))]
+fn tokenize_with_types_py<'a>(
+    text: &'a str,
+    offsets: Option<&str>,
+) -> PyResult<Vec<(&'a str, &'static str, usize, usize)>> {
+    Ok(tokenize_with_types(text, offset_mode(offsets)?))
+}
+
+/// Columnar form of `tokenize_with_types`: parallel lists of token texts,
+/// start offsets, end offsets and types. Four lists build and convert to
+/// NumPy or Polars columns faster than one tuple per token.
+#[cfg_attr(feature = "python", pyfunction)]
+fn tokenize_columns(text: &str) -> (Vec<&str>, Vec<usize>, Vec<usize>, Vec<&'static str>) {
+    let mut texts = Vec::new();
+    let mut starts = Vec::new();
+    let mut ends = Vec::new();
+    let mut kinds = Vec::new();
+    for (token, span, start, end) in TokenIter::new(text).with_spans() {
+        texts.push(token);
+        starts.push(start);
+        ends.push(end);
+        kinds.push(span.kind(text).as_str());
+    }
+    (texts, starts, ends, kinds)
+}
+
+/// The class set without the optional classes named in `disable`
+fn parse_disabled_classes<S: AsRef<str>>(disable: &[S]) -> Result<ClassSet, String> {
+    let mut classes = ClassSet::ALL;
+    for name in disable {
+        let name = name.as_ref();
+        let class = TokenClass::parse_optional(name).ok_or_else(|| {
+            let names: Vec<&str> = TokenClass::OPTIONAL.iter().map(|c| c.name()).collect();
+            format!("unknown token class '{}', expected one of: {}", name, names.join(", "))
+        })?;
+        classes = classes.without(class);
+    }
+    Ok(classes)
+}
+
+/// Regex tokenizer that keeps only some token classes whole. Classes named
+/// in `disable` (url, email, iban, phone, date, time, hashtag, mention,
+/// emoticon) fall apart into word, number and punctuation tokens; the
+/// per-class patterns are shared, so an instance compiles nothing.
+#[cfg(feature = "python")]
+#[pyclass(module = "durak._durak_core", name = "RegexTokenizer", frozen)]
+struct PyRegexTokenizer {
+    classes: ClassSet,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyRegexTokenizer {
+    #[new]
+    #[pyo3(signature = (disable=Vec::new()))]
+    fn new(disable: Vec<String>) -> PyResult<Self> {
+        let classes = parse_disabled_classes(&disable)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        Ok(Self { classes })
+    }
+
+    /// Names of the optional classes kept whole, in priority order
+    #[getter]
+    fn classes(&self) -> Vec<&'static str> {
+        TokenClass::OPTIONAL
+            .iter()
+            .filter(|&&class| self.classes.contains(class))
+            .map(|class| class.name())
+            .collect()
+    }
+
+    fn tokenize<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        TokenIter::with_classes(text, self.classes)
+            .map(|(token, _, _)| token)
+            .collect()
+    }
+
+    /// Tokens as (token, start, end), offsets as in `tokenize_with_offsets`
+    #[pyo3(signature = (text, *, offsets=None))]
+    fn tokenize_with_offsets<'a>(
+        &self,
+        text: &'a str,
+        offsets: Option<&str>,
+    ) -> PyResult<Vec<(&'a str, usize, usize)>> {
+        Ok(TokenIter::with_classes(text, self.classes)
+            .with_offsets(offset_mode(offsets)?)
+            .map(|(token, _, start, end)| (token, start, end))
+            .collect())
+    }
+
+    /// Tokens as (token, type, start, end), types as in `tokenize_with_types`
+    #[pyo3(signature = (text, *, offsets=None))]
+    fn tokenize_with_types<'a>(
+        &self,
+        text: &'a str,
+        offsets: Option<&str>,
+    ) -> PyResult<Vec<(&'a str, &'static str, usize, usize)>> {
+        Ok(TokenIter::with_classes(text, self.classes)
+            .with_offsets(offset_mode(offsets)?)
+            .map(|(token, span, start, end)| (token, span.kind(text).as_str(), start, end))
+            .collect())
+    }
+
+    /// Tokenize a batch of texts without the GIL, as `tokenize_batch`
+    #[pyo3(signature = (texts, threads=1, *, errors="raise", cancel=None))]
+    fn tokenize_batch(
+        &self,
+        py: Python<'_>,
+        texts: &Bound<'_, PyAny>,
+        threads: usize,
+        errors: &str,
+        cancel: Option<&PyCancellationToken>,
+    ) -> PyResult<Py<PyAny>> {
+        let (texts, failures) = batch_texts(texts, "tokenize_batch", errors)?;
+        let classes = self.classes;
+        let arenas = run_cancellable(py, cancel, |token| {
+            tokenize_batch(texts, classes, threads, token)
+        })?;
+        batch_output(py, arena_lists(py, &arenas)?, failures)
+    }
+
+    fn __repr__(&self) -> String {
+        let disabled: Vec<String> = TokenClass::OPTIONAL
+            .iter()
+            .filter(|&&class| !self.classes.contains(class))
+            .map(|class| format!("'{}'", class.name()))
+            .collect();
+        format!("RegexTokenizer(disable=[{}])", disabled.join(", "))
+    }
+}
+
+/// Tokenize text keeping the text between tokens, for exact reconstruction.
+/// Returns the text before the first token and a list of (token, trailing),
+/// where trailing is the whitespace up to the next token.
+#[cfg_attr(feature = "python", pyfunction)]
+fn tokenize_with_whitespace(text: &str) -> (String, Vec<(String, String)>) {
+    let spans = token_spans(text);
+    let (prefix, tokens) = detokenizer::with_trailing(text, &spans);
+    let tokens = tokens
+        .into_iter()
+        .map(|(token, trailing)| (token.to_string(), trailing.to_string()))
+        .collect();
+    (prefix.to_string(), tokens)
+}
+
+/// Tokenize text for a `spacy.tokens.Doc`: returns (words, spaces, spans),
+/// where spaces[i] tells whether words[i] is followed by one space and spans
+/// are character offsets. Other whitespace becomes words of its own, so the
+/// Doc text equals the input.
+#[cfg_attr(feature = "python", pyfunction)]
+fn tokenize_for_spacy(text: &str) -> (Vec<String>, Vec<bool>, Vec<(usize, usize)>) {
+    let words = detokenizer::spacy_words(text, &token_spans(text));
+    let mut texts = Vec::with_capacity(words.len());
+    let mut spaces = Vec::with_capacity(words.len());
+    let mut offsets = Vec::with_capacity(words.len());
+    let (mut byte_position, mut char_position) = (0, 0);
+    for (range, space) in words {
+        let start = char_position + text[byte_position..range.start].chars().count();
+        let end = start + text[range.clone()].chars().count();
+        texts.push(text[range.clone()].to_string());
+        spaces.push(space);
+        offsets.push((start, end));
+        byte_position = range.end;
+        char_position = end;
+    }
+    (texts, spaces, offsets)
+}
+
+/// `fast_normalize` (or `normalize_with_form` with a form) plus the
+/// character index in `text` of every normalized character, and
+/// `text.chars().count()` as a final entry for span ends.
+/// Text is normalized one base character and its combining marks at a
+/// time; characters of a segment whose length does not change map one to
+/// one, otherwise all to the segment start ("g" + U+0306 → "ğ" maps to the
+/// "g", NFKC "ﬁ" → "fi" maps both to the ligature).
+fn normalize_aligned(
+    text: &str,
+    lowercase: bool,
+    handle_turkish_i: bool,
+    form: Option<UnicodeForm>,
+) -> (String, Vec<usize>) {
+    let mut normalized = String::with_capacity(text.len());
+    let mut alignment = Vec::with_capacity(text.len() + 1);
+    let chars: Vec<char> = text.chars().collect();
+    let mut start = 0;
+    while start < chars.len() {
+        let end = (start + 1..chars.len())
+            .find(|&i| unicode_normalization::char::canonical_combining_class(chars[i]) == 0)
+            .unwrap_or(chars.len());
+        let segment: String = chars[start..end].iter().collect();
+        let output = match form {
+            Some(form) => normalize_with_form(&segment, lowercase, handle_turkish_i, form),
+            None => fast_normalize(&segment, lowercase, handle_turkish_i),
+        };
+        let count = output.chars().count();
+        if count == end - start {
+            alignment.extend(start..end);
+        } else {
+            alignment.extend(std::iter::repeat(start).take(count));
+        }
+        normalized.push_str(&output);
+        start = end;
+    }
+    alignment.push(chars.len());
+    (normalized, alignment)
+}
+
+/// Normalize as `fast_normalize` and return the normalized text with the
+/// index in `text` of every normalized character, plus `len(text)` as a
+/// final entry, so spans found in the normalized text map back to the raw
+/// input: `alignment[start]` to `alignment[end]`.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (text, lowercase=true, handle_turkish_i=true, unicode_form=None))]
+fn normalize_with_alignment(
+    text: &str,
+    lowercase: bool,
+    handle_turkish_i: bool,
+    unicode_form: Option<&str>,
+) -> PyResult<(String, Vec<usize>)> {
+    let form = unicode_form.map(parse_unicode_form).transpose()?;
+    Ok(normalize_aligned(text, lowercase, handle_turkish_i, form))
+}
+
+/// Tokenize text into (token, (byte_start, byte_end)) pairs, the offsets
+/// format of HuggingFace `tokenizers` pre-tokenizers.
+#[cfg_attr(feature = "python", pyfunction)]
+fn pre_tokenize(text: &str) -> Vec<(&str, (usize, usize))> {
+    TokenSpans::new(text)
+        .map(|span| (&text[span.start..span.end], (span.start, span.end)))
+        .collect()
+}
+
+/// Join tokens into text with Turkish spacing: no space before closing
+/// punctuation and suffix apostrophes, after opening brackets and "%", or
+/// inside double quotes.
+#[cfg_attr(feature = "python", pyfunction)]
+fn detokenize(tokens: Vec<String>) -> String {
+    detokenizer::detokenize(&tokens)
+}
+
+/// Tokenize text and return normalized tokens with offsets pointing to original text.
+/// This is the NER-friendly version: tokens are normalized but offsets reference the raw input.
+/// 
+/// # Example
+/// ```text
+/// let text = "İstanbul'a gittim";
+/// let tokens = tokenize_with_normalized_offsets(text);
+/// // Returns: [("istanbul'a", 0, 10), ("gittim", 11, 17)]
+/// // Note: tokens are lowercased but offsets still point to "İstanbul'a" in original
+/// ```
+#[cfg_attr(feature = "python", pyfunction)]
+fn tokenize_with_normalized_offsets(text: &str) -> Vec<(String, usize, usize)> {
+    TokenIter::new(text)
+        .map(|(token, start, end)| (fast_normalize(token, true, true), start, end))
+        .collect()
+}
+
+/// Whether a normalized token is in the base stopword list, embedded or
+/// installed by `set_resource_dirs`
+fn is_base_stopword(norm: &str) -> bool {
+    if let Some(stopwords) = resources::stopwords() {
+        return stopwords.contains(norm);
+    }
+    static SET: OnceLock<std::collections::HashSet<String>> = OnceLock::new();
+    SET.get_or_init(|| get_stopwords_base().into_iter().collect())
+        .contains(norm)
+}
+
+/// Lemma of a token given its normalized form: the hybrid lemma
+/// (`Lemmatizer()` defaults) for words, the base of an apostrophe-marked
+/// proper noun ("İstanbul'a" → "istanbul"), and `norm` itself for tokens
+/// other than words
+fn token_lemma(text: &str, kind: &str, norm: &str, normalize: bool) -> String {
+    if kind != "WORD" {
+        return norm.to_string();
+    }
+    match split_clitics(text) {
+        (base, suffix) if !suffix.is_empty() && normalize => fast_normalize(&base, true, true),
+        (base, suffix) if !suffix.is_empty() => base,
+        _ => lemmatize_word(norm, Strategy::Hybrid, false, false, 2),
+    }
+}
+
+/// Stages `process` runs after tokenization
+#[derive(Debug, Clone, Copy)]
+struct ProcessStages {
+    normalize: bool,
+    stopwords: bool,
+    lemmatize: bool,
+}
+
+/// A token with the `process` stages applied. Disabled stages leave the
+/// text as `norm`, no stopword flag and `norm` as the lemma.
+#[derive(Debug, Clone, PartialEq)]
+struct ProcessedToken {
+    text: String,
+    start: usize,
+    end: usize,
+    kind: &'static str,
+    norm: String,
+    is_stop: bool,
+    lemma: String,
+}
+
+/// Tokenize text and annotate every token with the enabled stages:
+/// normalize → stopword flagging → lemmatize
+fn process_tokens(text: &str, stages: ProcessStages) -> Vec<ProcessedToken> {
+    spans_with_char_offsets(text)
+        .into_iter()
+        .map(|(span, start, end)| {
+            let token = &text[span.start..span.end];
+            let kind = span.kind(text).as_str();
+            let norm = match stages.normalize {
+                true => fast_normalize(token, true, true),
+                false => token.to_string(),
+            };
+            let is_stop = stages.stopwords && is_base_stopword(&norm);
+            let lemma = match stages.lemmatize {
+                true => token_lemma(token, kind, &norm, stages.normalize),
+                false => norm.clone(),
+            };
+            ProcessedToken {
+                text: token.to_string(),
+                start,
+                end,
+                kind,
+                norm,
+                is_stop,
+                lemma,
+            }
+        })
+        .collect()
+}
+
+/// A token of a `Doc`: its text, character offsets and type as in
+/// `tokenize_with_types`. For a `Doc(text)` the `norm`, `is_stop` and
+/// `lemma` fields are computed on first access and kept; `process` fills
+/// them in up front.
+#[cfg(feature = "python")]
+#[pyclass(module = "durak._durak_core", name = "Token", frozen)]
+struct PyToken {
+    text: String,
+    start: usize,
+    end: usize,
+    kind: &'static str,
+    norm: OnceLock<String>,
+    is_stop: OnceLock<bool>,
+    lemma: OnceLock<String>,
+}
+
+#[cfg(feature = "python")]
+impl PyToken {
+    fn norm_str(&self) -> &str {
+        self.norm.get_or_init(|| fast_normalize(&self.text, true, true))
+    }
+}
+
+#[cfg(feature = "python")]
+impl From<ProcessedToken> for PyToken {
+    fn from(token: ProcessedToken) -> Self {
+        Self {
+            text: token.text,
+            start: token.start,
+            end: token.end,
+            kind: token.kind,
+            norm: OnceLock::from(token.norm),
+            is_stop: OnceLock::from(token.is_stop),
+            lemma: OnceLock::from(token.lemma),
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyToken {
+    #[getter]
+    fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Character offset of the first character in the `Doc` text
+    #[getter]
+    fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Character offset just past the last character in the `Doc` text
+    #[getter]
+    fn end(&self) -> usize {
+        self.end
+    }
+
+    #[getter]
+    fn kind(&self) -> &'static str {
+        self.kind
+    }
+
+    /// The text lowercased with Turkish I/ı handling
+    #[getter]
+    fn norm(&self) -> &str {
+        self.norm_str()
+    }
+
+    /// Whether `norm` is a base Turkish stopword
+    #[getter]
+    fn is_stop(&self) -> bool {
+        *self.is_stop.get_or_init(|| is_base_stopword(self.norm_str()))
+    }
+
+    /// Lemma of `norm` (see `token_lemma`)
+    #[getter]
+    fn lemma(&self) -> &str {
+        self.lemma
+            .get_or_init(|| token_lemma(&self.text, self.kind, self.norm_str(), true))
+    }
+
+    fn __len__(&self) -> usize {
+        self.end - self.start
+    }
+
+    fn __str__(&self) -> &str {
+        &self.text
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Token({:?}, {}, {}, {})", self.text, self.start, self.end, self.kind)
+    }
+}
+
+/// Tokenized text as `Token` objects, the structured form of
+/// `tokenize_with_types`. Indexing, `len()` and iteration go over the tokens.
+#[cfg(feature = "python")]
+#[pyclass(module = "durak._durak_core", name = "Doc", frozen, sequence)]
+struct PyDoc {
+    text: String,
+    tokens: Vec<Py<PyToken>>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyDoc {
+    #[new]
+    fn new(py: Python<'_>, text: String) -> PyResult<Self> {
+        let spans = spans_with_char_offsets(&text);
+        let mut tokens = Vec::with_capacity(spans.len());
+        for (span, start, end) in spans {
+            let token = PyToken {
+                text: text[span.start..span.end].to_string(),
+                start,
+                end,
+                kind: span.kind(&text).as_str(),
+                norm: OnceLock::new(),
+                is_stop: OnceLock::new(),
+                lemma: OnceLock::new(),
+            };
+            tokens.push(Py::new(py, token)?);
+        }
+        Ok(Self { text, tokens })
+    }
+
+    #[getter]
+    fn text(&self) -> &str {
+        &self.text
+    }
+
+    #[getter]
+    fn tokens(&self, py: Python<'_>) -> Vec<Py<PyToken>> {
+        self.tokens.iter().map(|token| token.clone_ref(py)).collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.tokens.len()
+    }
+
+    fn __getitem__(&self, py: Python<'_>, index: isize) -> PyResult<Py<PyToken>> {
+        let len = self.tokens.len() as isize;
+        let position = if index < 0 { index + len } else { index };
+        if !(0..len).contains(&position) {
+            return Err(pyo3::exceptions::PyIndexError::new_err("Doc index out of range"));
+        }
+        Ok(self.tokens[position as usize].clone_ref(py))
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, pyo3::types::PyIterator>> {
+        pyo3::types::PyList::new(py, self.tokens(py))?.try_iter()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Doc({:?}, {} tokens)", self.text, self.tokens.len())
+    }
+}
+
+/// Run normalize → tokenize → stopword flagging → lemmatize over a text in
+/// one call without the GIL and return the annotated `Doc`. Each stage after
+/// tokenization can be turned off: without `normalize` the later stages see
+/// the token text, without `stopwords` no token is flagged, and without
+/// `lemmatize` the lemma is `norm`.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (text, *, normalize=true, stopwords=true, lemmatize=true))]
+fn process(
+    py: Python<'_>,
+    text: String,
+    normalize: bool,
+    stopwords: bool,
+    lemmatize: bool,
+) -> PyResult<PyDoc> {
+    let stages = ProcessStages {
+        normalize,
+        stopwords,
+        lemmatize,
+    };
+    let tokens = py.detach(|| process_tokens(&text, stages));
+    let tokens = tokens
+        .into_iter()
+        .map(|token| Py::new(py, PyToken::from(token)))
+        .collect::<PyResult<_>>()?;
+    Ok(PyDoc { text, tokens })
+}
+
+/// A `Doc` as one CoNLL-U sentence (see `conllu`): a line per token with
+/// ID, FORM, LEMMA, UPOS, XPOS and FEATS filled in, ending with the blank
+/// line that closes the sentence
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (doc, sent_id=None))]
+fn to_conllu(py: Python<'_>, doc: &Bound<'_, PyDoc>, sent_id: Option<&str>) -> String {
+    let doc = doc.get();
+    let tokens: Vec<&PyToken> = doc.tokens.iter().map(|token| token.get()).collect();
+    py.detach(|| {
+        let tokens: Vec<conllu::Token> = tokens
+            .iter()
+            .map(|token| conllu::Token {
+                form: &token.text,
+                lemma: token.lemma(),
+                kind: token.kind,
+                start: token.start,
+                end: token.end,
+            })
+            .collect();
+        let mut out = String::new();
+        conllu::write_sentence(&mut out, sent_id, &doc.text, &tokens);
+        out
+    })
+}
+
+/// Read a CoNLL-U file into one `Doc` per sentence. The text is rebuilt
+/// from the surface tokens and `SpaceAfter=No`, multiword tokens count as
+/// one token, and tokens keep the file's LEMMA unless it is "_". A malformed
+/// line raises ValueError naming it.
+#[cfg(feature = "python")]
+#[pyfunction]
+fn read_conllu(py: Python<'_>, path: std::path::PathBuf) -> PyResult<Vec<PyDoc>> {
+    let input = std::fs::read_to_string(&path)?;
+    let sentences = conllu::parse(&input).map_err(|e| {
+        pyo3::exceptions::PyValueError::new_err(format!("{}: {}", path.display(), e))
+    })?;
+    let mut docs = Vec::with_capacity(sentences.len());
+    for sentence in &sentences {
+        let (text, offsets) = sentence.text();
+        let mut tokens = Vec::with_capacity(offsets.len());
+        for (row, (start, end)) in sentence.surface_rows().into_iter().zip(offsets) {
+            let lemma = match row[2].as_str() {
+                "_" => OnceLock::new(),
+                lemma => OnceLock::from(lemma.to_string()),
+            };
+            let token = PyToken {
+                text: row[1].clone(),
+                start,
+                end,
+                kind: token_kind(&row[1]).unwrap_or("WORD"),
+                norm: OnceLock::new(),
+                is_stop: OnceLock::new(),
+                lemma,
+            };
+            tokens.push(Py::new(py, token)?);
+        }
+        docs.push(PyDoc { text, tokens });
+    }
+    Ok(docs)
+}
+
+/// Recompute the LEMMA, UPOS, XPOS or FEATS columns of CoNLL-U text from
+/// each word's FORM (all four by default), leaving every other line and
+/// column as it was (see `conllu::reannotate`)
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (text, *, columns=None))]
+fn reannotate_conllu(py: Python<'_>, text: &str, columns: Option<Vec<String>>) -> PyResult<String> {
+    let columns = match columns {
+        None => conllu::Column::ALL.to_vec(),
+        Some(names) => names
+            .iter()
+            .map(|name| {
+                conllu::Column::parse(name).ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err(format!(
+                        "unknown column '{}', expected lemma, upos, xpos or feats",
+                        name
+                    ))
+                })
+            })
+            .collect::<PyResult<_>>()?,
+    };
+    let mut sentences =
+        conllu::parse(text).map_err(pyo3::exceptions::PyValueError::new_err)?;
+    Ok(py.detach(|| {
+        let mut out = String::new();
+        for sentence in &mut sentences {
+            conllu::reannotate(sentence, &columns);
+            conllu::write_parsed(&mut out, sentence);
+        }
+        out
+    }))
+}
+
+/// Rewrite rules over token lists ("TL => lira", "<NUMBER> TL => $1 lira");
+/// see `rewrite` for the rule syntax. A malformed rule raises ValueError
+/// naming its line.
+#[cfg(feature = "python")]
+#[pyclass(module = "durak._durak_core", name = "RewriteRules", frozen)]
+struct PyRewriteRules {
+    rules: rewrite::Rules,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyRewriteRules {
+    #[new]
+    fn new(rules: &str) -> PyResult<Self> {
+        let rules = rewrite::Rules::parse(rules)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(Self { rules })
+    }
+
+    /// Read a UTF-8 rules file
+    #[staticmethod]
+    fn from_file(path: std::path::PathBuf) -> PyResult<Self> {
+        let rules = std::fs::read_to_string(&path)?;
+        rewrite::Rules::parse(&rules)
+            .map(|rules| Self { rules })
+            .map_err(|e| {
+                pyo3::exceptions::PyValueError::new_err(format!("{}: {}", path.display(), e))
+            })
+    }
+
+    fn apply(&self, tokens: Vec<String>) -> Vec<String> {
+        self.rules.apply(&tokens)
+    }
+
+    fn __call__(&self, tokens: Vec<String>) -> Vec<String> {
+        self.rules.apply(&tokens)
+    }
+
+    fn __len__(&self) -> usize {
+        self.rules.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("RewriteRules({} rules)", self.rules.len())
+    }
+}
+
+/// Aho–Corasick matcher over many phrases (see `keywords`). Built from a
+/// list of phrases, each its own label, or a mapping of phrase to label.
+#[cfg(feature = "python")]
+#[pyclass(module = "durak._durak_core", name = "KeywordMatcher", frozen)]
+struct PyKeywordMatcher {
+    matcher: keywords::KeywordMatcher,
+    labels: Vec<String>,
+}
+
+/// (matched text, label, start, end) with character offsets
+#[cfg(feature = "python")]
+type KeywordTuple = (String, String, usize, usize);
+
+/// Phrases and their labels from a list (each phrase its own label) or a
+/// mapping of phrase to label
+#[cfg(feature = "python")]
+fn labeled_phrases(keywords: &Bound<'_, PyAny>) -> PyResult<(Vec<String>, Vec<String>)> {
+    if let Ok(mapping) = keywords.cast::<pyo3::types::PyMapping>() {
+        return Ok(mapping.items()?.extract::<Vec<(String, String)>>()?.into_iter().unzip());
+    }
+    let phrases: Vec<String> = keywords
+        .try_iter()?
+        .map(|phrase| phrase?.extract::<String>())
+        .collect::<PyResult<_>>()?;
+    Ok((phrases.clone(), phrases))
+}
+
+/// Phrases and their labels from a UTF-8 file of one phrase per line,
+/// optionally followed by a tab and its label. Blank lines and "# "
+/// comments are skipped, so hashtags can be phrases.
+#[cfg(feature = "python")]
+fn read_labeled_phrases(path: &std::path::Path) -> PyResult<(Vec<String>, Vec<String>)> {
+    let text = std::fs::read_to_string(path)?;
+    Ok(text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && *line != "#" && !line.starts_with("# "))
+        .map(|line| match line.split_once('\t') {
+            Some((phrase, label)) => (phrase.trim().to_string(), label.trim().to_string()),
+            None => (line.to_string(), line.to_string()),
+        })
+        .unzip())
+}
+
+/// Byte offset of every character start of `text`, and its length
+#[cfg(feature = "python")]
+fn char_byte_offsets(text: &str) -> Vec<usize> {
+    text.char_indices().map(|(i, _)| i).chain([text.len()]).collect()
+}
+
+#[cfg(feature = "python")]
+impl PyKeywordMatcher {
+    fn build(
+        phrases: &[String],
+        labels: Vec<String>,
+        normalize: bool,
+        whole_words: bool,
+    ) -> PyResult<Self> {
+        let matcher = keywords::KeywordMatcher::new(phrases, normalize, whole_words)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        Ok(Self { matcher, labels })
+    }
+
+    fn matches(&self, text: &str, overlapping: bool) -> Vec<KeywordTuple> {
+        let bytes = char_byte_offsets(text);
+        self.matcher
+            .find(text, overlapping)
+            .into_iter()
+            .map(|m| {
+                let span = text[bytes[m.start]..bytes[m.end]].to_string();
+                (span, self.labels[m.phrase].clone(), m.start, m.end)
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyKeywordMatcher {
+    #[new]
+    #[pyo3(signature = (keywords, *, normalize=true, whole_words=true))]
+    fn new(keywords: &Bound<'_, PyAny>, normalize: bool, whole_words: bool) -> PyResult<Self> {
+        let (phrases, labels) = labeled_phrases(keywords)?;
+        Self::build(&phrases, labels, normalize, whole_words)
+    }
+
+    /// Read a UTF-8 file of one phrase per line, optionally followed by a
+    /// tab and its label; blank lines and "# " comments are skipped, so
+    /// hashtags can be phrases
+    #[staticmethod]
+    #[pyo3(signature = (path, *, normalize=true, whole_words=true))]
+    fn from_file(path: std::path::PathBuf, normalize: bool, whole_words: bool) -> PyResult<Self> {
+        let (phrases, labels) = read_labeled_phrases(&path)?;
+        Self::build(&phrases, labels, normalize, whole_words)
+    }
+
+    /// (matched text, label, start, end) of the phrases in text, by start;
+    /// overlapping matches are kept only with overlapping=True
+    #[pyo3(signature = (text, overlapping=false))]
+    fn find(&self, text: &str, overlapping: bool) -> Vec<KeywordTuple> {
+        self.matches(text, overlapping)
+    }
+
+    /// `find` over many texts, across threads workers (0: one per core)
+    #[pyo3(signature = (texts, overlapping=false, threads=1))]
+    fn find_batch(
+        &self,
+        py: Python<'_>,
+        texts: Vec<String>,
+        overlapping: bool,
+        threads: usize,
+    ) -> Vec<Vec<KeywordTuple>> {
+        py.detach(|| parallel::map(&texts, threads, |text| self.matches(text, overlapping)))
+    }
+
+    fn __len__(&self) -> usize {
+        self.matcher.phrase_count()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("KeywordMatcher({} keywords)", self.matcher.phrase_count())
+    }
+}
+
+/// Phrase matcher over lemmas (see `phrases`): "kitap oku" finds "kitabı
+/// okudum". Patterns and texts are tokenized, normalized and lemmatized as
+/// by `process`. Built from a list of patterns, each its own label, or a
+/// mapping of pattern to label.
+#[cfg(feature = "python")]
+#[pyclass(module = "durak._durak_core", name = "PhraseMatcher", frozen)]
+struct PyPhraseMatcher {
+    matcher: phrases::PhraseMatcher,
+    labels: Vec<String>,
+}
+
+#[cfg(feature = "python")]
+const PHRASE_STAGES: ProcessStages = ProcessStages {
+    normalize: true,
+    stopwords: false,
+    lemmatize: true,
+};
+
+#[cfg(feature = "python")]
+impl PyPhraseMatcher {
+    fn build(patterns: &[String], labels: Vec<String>) -> PyResult<Self> {
+        let lemma_sequence = |pattern: &String| -> Vec<String> {
+            process_tokens(pattern, PHRASE_STAGES).into_iter().map(|t| t.lemma).collect()
+        };
+        let lemmas = patterns.iter().map(lemma_sequence).collect();
+        let matcher =
+            phrases::PhraseMatcher::new(lemmas).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        Ok(Self { matcher, labels })
+    }
+
+    fn matches(&self, text: &str, overlapping: bool) -> Vec<KeywordTuple> {
+        let tokens = process_tokens(text, PHRASE_STAGES);
+        let lemmas: Vec<&str> = tokens.iter().map(|t| t.lemma.as_str()).collect();
+        let bytes = char_byte_offsets(text);
+        self.matcher
+            .find(&lemmas, overlapping)
+            .into_iter()
+            .map(|m| {
+                let (start, end) = (tokens[m.start].start, tokens[m.end - 1].end);
+                let span = text[bytes[start]..bytes[end]].to_string();
+                (span, self.labels[m.pattern].clone(), start, end)
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyPhraseMatcher {
+    #[new]
+    fn new(patterns: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let (patterns, labels) = labeled_phrases(patterns)?;
+        Self::build(&patterns, labels)
+    }
+
+    /// Read a UTF-8 file of one pattern per line, optionally followed by a
+    /// tab and its label; blank lines and "# " comments are skipped
+    #[staticmethod]
+    fn from_file(path: std::path::PathBuf) -> PyResult<Self> {
+        let (patterns, labels) = read_labeled_phrases(&path)?;
+        Self::build(&patterns, labels)
+    }
+
+    /// (matched text, label, start, end) of the patterns in text, by start;
+    /// overlapping matches are kept only with overlapping=True
+    #[pyo3(signature = (text, overlapping=false))]
+    fn find(&self, text: &str, overlapping: bool) -> Vec<KeywordTuple> {
+        self.matches(text, overlapping)
+    }
+
+    /// `find` over many texts, across threads workers (0: one per core)
+    #[pyo3(signature = (texts, overlapping=false, threads=1))]
+    fn find_batch(
+        &self,
+        py: Python<'_>,
+        texts: Vec<String>,
+        overlapping: bool,
+        threads: usize,
+    ) -> Vec<Vec<KeywordTuple>> {
+        py.detach(|| parallel::map(&texts, threads, |text| self.matches(text, overlapping)))
+    }
+
+    fn __len__(&self) -> usize {
+        self.matcher.pattern_count()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PhraseMatcher({} patterns)", self.matcher.pattern_count())
+    }
+}
+
+/// Tier 1: Exact Lookup
+#[cfg_attr(feature = "python", pyfunction)]
+fn lookup_lemma(word: &str) -> Option<String> {
+    lemma_dict::lookup(word).map(|s| s.into_owned())
+}
+
+/// Prefix query over the lemma dictionary
+/// Returns (inflected_form, lemma) pairs whose inflected form starts with `prefix`,
+/// in lexicographic order, optionally capped at `limit` entries.
+#[cfg_attr(feature = "python", pyfunction)]
+#[cfg_attr(feature = "python", pyo3(signature = (prefix, limit=None)))]
+fn lookup_lemma_prefix(prefix: &str, limit: Option<usize>) -> Vec<(String, String)> {
+    lemma_dict::prefix_search(prefix, limit)
+}
+
+/// Turkish suffix categories for morphological analysis
+///
+/// Turkish is an agglutinative language with complex suffix chains.
+/// Suffixes must be applied in a specific order (slot system).
+mod suffixes {
+    /// Compound suffixes that should be stripped as a unit before individual suffixes
+    /// These are morphological combinations that occur frequently
+    pub const COMPOUND_SUFFIXES: &[&str] = &[
+        // Without doing (negation + ablative): gel-meden = without coming
+        "meden",
+        "madan",
+        // Plural + Possessive combinations
+        "larımız",
+        "lerimiz",
+        "ların",
+        "lerin",
+        "larımızdan",
+        "lerimizden",
+        // Plural + Case combinations
+        "lardan",
+        "lerden",
+        "lara",
+        "lere",
+        // Possessive + Case combinations
+        "ımızdan",
+        "imizden",
+        "ındanan",
+        "indenan",
+        // Verbal compound: gel-me-di-m = didn't come
+        "medim",
+        "madım",
+        "medin",
+        "madın",
+        "medi",
+        "madı",
+        "medik",
+        "madık",
+        "mişim",
+        "mışım",
+        "mişiz",
+        "mışız",
+        // Continuous + person: geliyorum
+        "yorum",
+        "yorsun",
+        "yor",
+        "yoruz",
+        "yorsunuz",
+        "yorlar",
+        // Future + person
+        "eceğim",
+        "acağım",
+        "eceğiz",
+        "acağız",
+        "eceksin",
+        "acaksın",
+        // Ability
+        "yebilmek",
+        "yabilmek",
+        "ebilirim",
+        "abilirim",
+    ];
+
+    /// Nominal suffixes (attached to nouns, adjectives)
+    /// Order: Plural → Possessive → Case
+    pub const NOMINAL_SUFFIXES: &[&str] = &[
+        // Plural (Slot 1)
+        "lar", "ler", // Possessive (Slot 2) - 1st person
+        "ım", "im", "um", "üm", "ımız", "imiz", "umuz", "ümüz",
+        // Possessive (Slot 2) - 2nd person
+        "ın", "in", "un", "ün", "ınız", "iniz", "unuz", "ünüz",
+        // Possessive (Slot 2) - 3rd person
+        "ı", "i", "u", "ü", "sı", "si", "su", "sü", // Case (Slot 3) - Accusative
+        "ı", "i", "u", "ü", // Case (Slot 3) - Dative
+        "a", "e", "ya", "ye", // Case (Slot 3) - Locative
+        "da", "de", "ta", "te", // Case (Slot 3) - Ablative
+        "dan", "den", "tan", "ten", // Case (Slot 3) - Genitive
+        "ın", "in", "un", "ün", "nın", "nin", "nun", "nün",
+    ];
+
+    /// Verbal suffixes (attached to verbs)
+    /// Order: Voice → Negation → Tense/Aspect → Person
+    pub const VERBAL_SUFFIXES: &[&str] = &[
+        // Voice (Slot 1) - Passive, Causative, Reflexive, Reciprocal
+        "ıl", "il", "ul", "ül", "n", "ın", "in", "un", "ün", "dir", "dır", "dur", "dür", "tir",
+        "tır", "tur", "tür", "ş", "ış", "iş", "uş", "üş", // Negation (Slot 2)
+        "me", "ma", // Tense/Aspect (Slot 3)
+        "di", "dı", "du", "dü", "ti", "tı", "tu", "tü", // Definite past
+        "miş", "mış", "muş", "müş", // Evidential/Indefinite past
+        "ecek", "acak", // Future
+        "iyor", // Present continuous (fixed form - always "iyor")
+        "er", "ar", // Aorist
+        "mekte", "makta", // Progressive
+        // Person (Slot 4)
+        "m", "n", "k", "z", "ım", "im", "um", "üm", "ın", "in", "un", "ün", "ız", "iz", "uz", "üz",
+        "sın", "sin", "sun", "sün", "sınız", "siniz", "sunuz", "sünüz", "lar",
+        "ler", // 3rd person plural
+        // Infinitive
+        "mek", "mak",
+    ];
+
+    /// Fixed morphemes that don't follow standard vowel harmony
+    /// These should be handled specially in harmony checking
+    pub const FIXED_MORPHEMES: &[&str] = &[
+        "iyor",  // Present continuous - always "iyor" regardless of root
+        "ken",   // While - always "ken"
+        "ki",    // Relative - always "ki"
+        "leyin", // Time - always "leyin"
+    ];
+}
+
+/// Tier 2: Heuristic Suffix Stripping
+/// Simple rule-based stripper for demonstration.
+/// In production, this would use a more complex state machine and vowel harmony checks.
+#[cfg_attr(feature = "python", pyfunction)]
+fn strip_suffixes(word: &str) -> String {
+    if is_blob(word) {
+        return word.to_string();
+    }
+    let mut current = word.to_string();
+
+    // Use compound suffixes first (longest match)
+    let all_suffixes: Vec<&str> = suffixes::COMPOUND_SUFFIXES
+        .iter()
+        .chain(suffixes::NOMINAL_SUFFIXES.iter())
+        .chain(suffixes::VERBAL_SUFFIXES.iter())
+        .cloned()
+        .collect();
+
+    // Sort by length descending for greedy matching
+    let mut sorted: Vec<&&str> = all_suffixes.iter().collect();
+    sorted.sort_by_key(|s| Reverse(s.len()));
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        // Stop as soon as we reach a known root instead of eroding it further
+        if is_known_lemma(&current) {
+            break;
+        }
+
+        for &suffix in &sorted {
+            if current.ends_with(suffix) && current.chars().count() > suffix.chars().count() + 2 {
+                current = current[..current.len() - suffix.len()].to_string();
+                changed = true;
+                break;
+            }
+        }
+    }
+    current
+}
+
+/// Strip suffixes with root validity checking, vowel harmony, and morphotactic validation
+/// Prevents over-stripping by validating candidate roots, checking vowel harmony,
+/// and ensuring morphologically valid suffix ordering
+///
+/// # Arguments
+/// * `word` - The word to process
+/// * `strict` - If true, check dictionary first, then validate; if false, use phonotactic rules only
+/// * `min_root_length` - Minimum acceptable root length
+/// * `check_harmony` - If true, validate vowel harmony before stripping
+/// * `preserve_proper_nouns` - If true, a word with an apostrophe (Ankara'dan) only loses
+///   what follows the apostrophe and keeps its casing
+///
+/// # Returns
+/// The word with suffixes stripped, validated to prevent over-stripping
+fn strip_suffixes_validated(
+    word: &str,
+    strict: bool,
+    min_root_length: usize,
+    check_harmony: bool,
+    preserve_proper_nouns: bool,
+) -> String {
+    if is_blob(word) {
+        return word.to_string();
+    }
+    // Proper nouns keep their suffixes behind an apostrophe, which marks the
+    // root boundary exactly
+    if preserve_proper_nouns {
+        let (base, suffix) = split_clitics(word);
+        if !suffix.is_empty() {
+            return base;
+        }
+    }
+
+    // In strict mode, first check if the word is in the lemma dictionary
+    if strict {
+        if let Some(lemma) = lookup_lemma(word) {
+            return lemma;
+        }
+    }
+
+    let validator = RootValidator::new(min_root_length, strict);
+    let morphotactics = morphotactics::MorphotacticClassifier::new();
+    let mut current = word.to_string();
+    let mut best_result = word.to_string(); // Track best valid result
+    let mut stripped_suffixes: Vec<&str> = Vec::new();
+
+    // Phase 1: Try compound suffixes first (longest match)
+    for suffix in suffixes::COMPOUND_SUFFIXES {
+        if current.ends_with(suffix) {
+            let stem = &current[..current.len() - suffix.len()];
+            let restored = restore_root(stem, suffix, &validator);
+            let candidate = restored.as_deref().unwrap_or(stem);
+            let is_valid_root = validator.is_valid_root(candidate);
+            let has_harmony =
+                !check_harmony || vowel_harmony::check_vowel_harmony(candidate, suffix);
+            let valid_morphotactics = morphotactics.validate_sequence(&[suffix]);
+
+            if is_valid_root && has_harmony && valid_morphotactics && !candidate.is_empty() {
+                current = candidate.to_string();
+                stripped_suffixes.push(suffix);
+                best_result = current.clone();
+                break;
+            }
+        }
+    }
+
+    // Phase 2: Strip individual suffixes with validation
+    // Combine nominal and verbal suffixes, sorted by length (longest first)
+    let mut all_single_suffixes: Vec<&str> = suffixes::NOMINAL_SUFFIXES
+        .iter()
+        .chain(suffixes::VERBAL_SUFFIXES.iter())
+        .cloned()
+        .collect();
+    all_single_suffixes.sort_by_key(|s| Reverse(s.len()));
+    all_single_suffixes.dedup();
+
+    // Candidates are checked against known lemmas (root forms) in the dictionary
+
+    let mut changed = true;
+    let mut iterations = 0;
+    const MAX_ITERATIONS: usize = 10;
+
+    while changed && iterations < MAX_ITERATIONS {
+        changed = false;
+        iterations += 1;
+
+        // Check if current is in dictionary - if so, stop stripping
+        if is_known_root(&current) {
+            break;
+        }
+
+        for suffix in &all_single_suffixes {
+            if current.ends_with(suffix) {
+                let stem = &current[..current.len() - suffix.len()];
+                // Undo vowel drop and consonant softening before the suffix
+                // (burn-u → burun, kitab-ı → kitap)
+                let restored = restore_root(stem, suffix, &validator);
+                let candidate = restored.as_deref().unwrap_or(stem);
+
+                // Skip if candidate would be too short
+                if candidate.chars().count() < min_root_length {
+                    continue;
+                }
+
+                // Build hypothetical suffix sequence for morphotactic validation
+                let mut test_sequence = vec![*suffix];
+                test_sequence.extend(stripped_suffixes.iter().rev());
+                test_sequence.reverse();
+
+                // Validate all conditions
+                let is_valid_root = validator.is_valid_root(candidate);
+                let has_harmony = !check_harmony || {
+                    if suffixes::FIXED_MORPHEMES.contains(suffix) {
+                        true
+                    } else {
+                        vowel_harmony::check_vowel_harmony(candidate, suffix)
+                    }
+                };
+                let valid_morphotactics = morphotactics.validate_sequence(&test_sequence);
+
+                // Only strip if ALL conditions are met
+                if is_valid_root && has_harmony && valid_morphotactics {
+                    // If candidate is in dictionary, this is our answer - stop here
+                    if is_known_root(candidate) {
+                        return candidate.to_string();
+                    }
+
+                    current = candidate.to_string();
+                    stripped_suffixes.push(suffix);
+                    best_result = current.clone();
+                    changed = true;
+                    break;
+                }
+            }
+        }
+    }
+
+    // Final check: if current is in dictionary, prefer it
+    if is_known_root(&current) {
+        return current;
+    }
+
+    // Otherwise return the best valid result found
+    if validator.is_valid_root(&current) {
+        current
+    } else {
+        best_result
+    }
+}
+
+/// `strip_suffixes_validated` for Python; `min_root_length` and
+/// `check_harmony` default to the process-wide config (see config.rs)
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "strip_suffixes_validated",
+    signature = (
+        word, strict=false, min_root_length=None, check_harmony=None, preserve_proper_nouns=true
+    )
+)]
+fn strip_suffixes_validated_py(
+    word: &str,
+    strict: bool,
+    min_root_length: Option<usize>,
+    check_harmony: Option<bool>,
+    preserve_proper_nouns: bool,
+) -> String {
+    let (min_root_length, check_harmony) = {
+        let config = config::read();
+        (
+            min_root_length.unwrap_or(config.min_root_length),
+            check_harmony.unwrap_or(config.check_harmony),
+        )
+    };
+    strip_suffixes_validated(word, strict, min_root_length, check_harmony, preserve_proper_nouns)
+}
+
+/// Aggressive stem of a word for search indexing (see `stemmer`); not a
+/// lemma and never checked against the dictionary
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "stem",
+    signature = (
+        word, max_suffixes=None, *, method="cascade",
+        prefix_length=stemmer::DEFAULT_PREFIX_LENGTH
+    )
+)]
+fn stem_py(
+    word: &str,
+    max_suffixes: Option<usize>,
+    method: &str,
+    prefix_length: usize,
+) -> PyResult<String> {
+    let method = match method {
+        "cascade" => stemmer::Method::Cascade,
+        "prefix" => stemmer::Method::Prefix(prefix_length),
+        _ => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "unknown method '{}', expected 'cascade' or 'prefix'",
+                method
+            )))
+        }
+    };
+    Ok(stemmer::stem(word, method, max_suffixes))
+}
+
+/// Get embedded detached suffixes list
+/// Returns suffixes compiled into the binary from resources/tr/labels/DETACHED_SUFFIXES.txt,
+/// or the list installed by `set_resource_dirs`
+#[cfg_attr(feature = "python", pyfunction)]
+fn get_detached_suffixes() -> Vec<String> {
+    if let Some(suffixes) = resources::detached_suffixes() {
+        return suffixes.to_vec();
+    }
+    let suffixes = DETACHED_SUFFIXES.get_or_init(|| {
+        DETACHED_SUFFIXES_DATA
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .collect()
+    });
+    suffixes.iter().map(|s| s.to_string()).collect()
+}
+
+/// Split a token at the apostrophe that separates a proper noun from its suffixes:
+/// "İstanbul'a" → ("İstanbul", "'a"), "Ali’nin" → ("Ali", "’nin").
+/// Only the last ' or ’ is considered, and only when letters follow it in lowercase
+/// (or the whole token is uppercase, "ANKARA'DA"); other tokens come back whole with
+/// an empty suffix ("O'Neill", "kitap").
+#[cfg_attr(feature = "python", pyfunction)]
+fn split_clitics(token: &str) -> (String, String) {
+    let apostrophe = token.char_indices().rev().find(|&(_, c)| matches!(c, '\'' | '’'));
+    let split = apostrophe.filter(|&(i, c)| {
+        let (base, suffix) = (&token[..i], &token[i + c.len_utf8()..]);
+        let uppercase = !token.chars().any(char::is_lowercase);
+        base.chars().next_back().is_some_and(char::is_alphabetic)
+            && !suffix.is_empty()
+            && suffix.chars().all(|c| c.is_alphabetic() && (uppercase || c.is_lowercase()))
+    });
+    match split {
+        Some((i, _)) => (token[..i].to_string(), token[i..].to_string()),
+        None => (token.to_string(), String::new()),
+    }
+}
+
+/// Get embedded Turkish stopwords list
+/// Returns base Turkish stopwords compiled into the binary from resources/tr/stopwords/base/turkish.txt,
+/// or the list installed by `set_resource_dirs`
+#[cfg_attr(feature = "python", pyfunction)]
+fn get_stopwords_base() -> Vec<String> {
+    if let Some(stopwords) = resources::stopwords() {
+        return stopwords.iter().cloned().collect();
+    }
+    let stopwords = STOPWORDS_BASE.get_or_init(|| {
+        STOPWORDS_TR_DATA
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect()
+    });
+    stopwords.iter().map(|s| s.to_string()).collect()
+}
+
+/// Layer resource directories over the embedded lemma dictionary, base
+/// stopwords and detached suffixes; earlier directories take precedence and
+/// an empty list restores the embedded data. Files use the layout of
+/// resources/tr. Returns the (resource, path) pairs now overridden.
+#[cfg(feature = "python")]
+#[pyfunction]
+fn set_resource_dirs(
+    py: Python<'_>,
+    directories: Vec<std::path::PathBuf>,
+) -> PyResult<Vec<(&'static str, std::path::PathBuf)>> {
+    let overrides = py.detach(|| resources::load(&directories))?;
+    let sources = overrides.sources.clone();
+    resources::install(overrides);
+    // Cached lemmas may come from the previous dictionary
+    lemma_cache().clear();
+    Ok(sources)
+}
+
+/// The (resource, path) pairs installed by `set_resource_dirs`
+#[cfg(feature = "python")]
+#[pyfunction]
+fn resource_overrides() -> Vec<(&'static str, std::path::PathBuf)> {
+    resources::current().map_or_else(Vec::new, |overrides| overrides.sources.clone())
+}
+
+/// Process-wide defaults as a dict (see config.rs)
+#[cfg(feature = "python")]
+fn config_dict<'py>(
+    py: Python<'py>,
+    config: &config::Config,
+) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("min_root_length", config.min_root_length)?;
+    dict.set_item("check_harmony", config.check_harmony)?;
+    dict.set_item("offsets", config.offsets.as_str())?;
+    dict.set_item("stopword_domains", &config.stopword_domains)?;
+    Ok(dict)
+}
+
+/// The process-wide defaults: min_root_length, check_harmony, offsets and
+/// stopword_domains
+#[cfg(feature = "python")]
+#[pyfunction]
+fn get_config(py: Python<'_>) -> PyResult<Bound<'_, pyo3::types::PyDict>> {
+    let config = config::read().clone();
+    config_dict(py, &config)
+}
+
+/// Change process-wide defaults for every thread; options left as None keep
+/// their value. Returns the previous defaults. Raises ValueError for a
+/// min_root_length below 1 or an unknown offset mode.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (
+    *, min_root_length=None, check_harmony=None, offsets=None, stopword_domains=None
+))]
+fn set_config<'py>(
+    py: Python<'py>,
+    min_root_length: Option<usize>,
+    check_harmony: Option<bool>,
+    offsets: Option<&str>,
+    stopword_domains: Option<Vec<String>>,
+) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+    if min_root_length == Some(0) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "min_root_length must be at least 1",
+        ));
+    }
+    let offsets = match offsets {
+        Some(name) => Some(offset_mode(Some(name))?),
+        None => None,
+    };
+    let previous = config::update(|config| {
+        if let Some(min_root_length) = min_root_length {
+            config.min_root_length = min_root_length;
+        }
+        if let Some(check_harmony) = check_harmony {
+            config.check_harmony = check_harmony;
+        }
+        if let Some(offsets) = offsets {
+            config.offsets = offsets;
+        }
+        if let Some(domains) = stopword_domains {
+            config.stopword_domains = domains;
+        }
+    });
+    config_dict(py, &previous)
+}
+
+/// Get embedded stopwords metadata JSON
+/// Returns metadata compiled into the binary from resources/tr/stopwords/metadata.json
+#[cfg_attr(feature = "python", pyfunction)]
+fn get_stopwords_metadata() -> String {
+    STOPWORDS_METADATA_DATA.to_string()
+}
+
+/// Get embedded social media stopwords
+/// Returns social media stopwords compiled into the binary from resources/tr/stopwords/domains/social_media.txt
+#[cfg_attr(feature = "python", pyfunction)]
+fn get_stopwords_social_media() -> Vec<String> {
+    STOPWORDS_SOCIAL_MEDIA_DATA
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// Get embedded confusion pairs
+/// Returns (misspelling, correction) pairs compiled into the binary from resources/tr/config/confusion_pairs.txt
+#[cfg_attr(feature = "python", pyfunction)]
+fn get_confusion_pairs() -> Vec<(String, String)> {
+    confusion::pairs()
+        .into_iter()
+        .map(|(wrong, correct)| (wrong.to_string(), correct.to_string()))
+        .collect()
+}
+
+/// Check if a suffix harmonizes with a root (Python binding)
 ///
 /// # Arguments
-/// * `word` - The word to process
-/// * `strict` - If true, check dictionary first, then validate; if false, use phonotactic rules only
-/// * `min_root_length` - Minimum acceptable root length (default: 2)
-/// * `check_harmony` - If true, validate vowel harmony before stripping (default: true)
+/// * `root` - The root word
+/// * `suffix` - The suffix to check
 ///
 /// # Returns
-/// The word with suffixes stripped, validated to prevent over-stripping
+/// True if the suffix harmonizes with the root, False otherwise
+#[cfg_attr(feature = "python", pyfunction)]
+fn check_vowel_harmony_py(root: &str, suffix: &str) -> bool {
+    vowel_harmony::check_vowel_harmony(root, suffix)
+}
+
+// ============================================================================
+// MORPHOLOGICAL ANALYSIS, POS TAGGING & STATISTICS
+// ============================================================================
+
+/// Word tokens of a text for morphological processing.
+/// Punctuation, numbers, dates, times, URLs, e-mail addresses, IBANs and emoticons are
+/// dropped; apostrophe suffixes are cut off ("İstanbul'da" → "İstanbul").
+fn word_tokens(text: &str) -> Vec<&str> {
+    token_regex::find_iter(text, ClassSet::ALL)
+        .map(|mat| &text[mat.start..mat.end])
+        .filter(|token| token.chars().next().is_some_and(char::is_alphabetic))
+        .filter(|token| !token.starts_with("www."))
+        .filter(|token| !token.contains(|c: char| c == '@' || c.is_ascii_digit()))
+        .map(|token| token.split('\'').next().unwrap_or(token))
+        .collect()
+}
+
+/// One parse as returned to Python: (root, root_pos, [(tag, surface), ...])
+type ParseTuple = (String, String, Vec<(String, String)>);
+type RankedParseTuple = (String, String, Vec<(String, String)>, f64);
+
+/// Morphological analysis of a word.
+/// Returns every parse as (root, root_pos, [(tag, surface), ...]), preferred parse first.
+/// Tags follow Oflazer-style conventions: kitaplarımdan → kitap Noun [Pl, P1sg, Abl].
+#[cfg_attr(feature = "python", pyfunction)]
+fn analyze(word: &str) -> Vec<ParseTuple> {
+    analyzer::analyze(word).into_iter().map(parse_tuple).collect()
+}
+
+fn parse_tuple(analysis: analyzer::Analysis) -> ParseTuple {
+    let morphemes = analysis
+        .morphemes
+        .into_iter()
+        .map(|m| (m.tag.to_string(), m.surface))
+        .collect();
+    (analysis.root, analysis.pos.as_str().to_string(), morphemes)
+}
+
+/// Every morphological analysis of a word with its probability.
+/// Returns (root, root_pos, [(tag, surface), ...], probability), most probable first;
+/// probabilities come from embedded suffix frequencies and sum to 1.
+/// evin → ev [Gen] 0.94, ev [P2sg] 0.06.
+#[cfg_attr(feature = "python", pyfunction)]
+fn analyze_all(word: &str) -> Vec<RankedParseTuple> {
+    analyzer::analyze_all(word)
+        .into_iter()
+        .map(|(analysis, probability)| {
+            let (root, pos, morphemes) = parse_tuple(analysis);
+            (root, pos, morphemes, probability)
+        })
+        .collect()
+}
+
+/// Generate a surface form from a root and suffix tags (inverse of analyze).
+/// Applies vowel harmony, buffer letters and consonant softening:
+/// kitap + [P1sg, Loc] → kitabımda. Raises ValueError for unknown tags, tag
+/// sequences the morphotactics do not allow, or an unknown `pos`.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (root, suffix_tags, pos=None))]
+fn generate(root: &str, suffix_tags: Vec<String>, pos: Option<&str>) -> PyResult<String> {
+    let pos = match pos {
+        Some(name) => Some(analyzer::Pos::parse(name).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "unknown part of speech '{}', expected Noun, Adj, Verb or Pron",
+                name
+            ))
+        })?),
+        None => None,
+    };
+    let tags: Vec<&str> = suffix_tags.iter().map(String::as_str).collect();
+    analyzer::generate(root, &tags, pos)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+}
+
+/// Morphological complexity metrics of a document.
+/// Returns mean morphemes per word, suffix-type entropy (bits), verb/noun ratio,
+/// and analyzer coverage, computed from the preferred parse of each word.
+#[cfg_attr(feature = "python", pyfunction)]
+fn morphological_complexity(text: &str) -> HashMap<String, f64> {
+    let stats = stats::morphological_complexity(word_tokens(text));
+
+    let mut result = HashMap::new();
+    result.insert("words".to_string(), stats.words as f64);
+    result.insert("analyzed".to_string(), stats.analyzed as f64);
+    result.insert("coverage".to_string(), stats.coverage());
+    result.insert(
+        "mean_morphemes_per_word".to_string(),
+        stats.mean_morphemes_per_word,
+    );
+    result.insert("suffix_entropy".to_string(), stats.suffix_entropy);
+    result.insert("verb_noun_ratio".to_string(), stats.verb_noun_ratio);
+    result
+}
+
+/// Formal vs. informal register of a document from lexical cues: slang and chat
+/// abbreviations, 2nd person singular pronouns and verbs, contracted verb forms
+/// ("geliyom", "gelcem") and emoji. Returns the cue counts, emoji per word, and
+/// a formality score from 1.0 (no informal cues) towards 0.0.
+#[cfg_attr(feature = "python", pyfunction)]
+fn formality_score(text: &str) -> HashMap<String, f64> {
+    let emoji = token_spans(text).iter().filter(|span| span.emoji).count();
+    let register = register::register(word_tokens(text), emoji);
+
+    let mut result = HashMap::new();
+    result.insert("words".to_string(), register.words as f64);
+    result.insert("slang".to_string(), register.slang as f64);
+    result.insert("second_person".to_string(), register.second_person as f64);
+    result.insert("contractions".to_string(), register.contractions as f64);
+    result.insert("emoji".to_string(), register.emoji as f64);
+    result.insert("emoji_density".to_string(), register.emoji_density());
+    result.insert("formality".to_string(), register.formality);
+    result
+}
+
+/// Size and readability statistics of a document: token, word, type and
+/// sentence counts, mean word length in characters, mean syllables per word,
+/// mean words per sentence and the Ateşman readability score (about 90–100
+/// very easy, below 30 very difficult). Types are distinct lowercased words.
+#[cfg_attr(feature = "python", pyfunction)]
+fn text_stats(text: &str) -> HashMap<String, f64> {
+    let words: Vec<String> =
+        word_tokens(text).into_iter().map(|w| fast_normalize(w, true, true)).collect();
+    let sentences = sampling::split_sentences(text).len();
+    let stats = readability::text_stats(
+        token_spans(text).len(),
+        words.iter().map(String::as_str),
+        sentences,
+    );
+
+    let mut result = HashMap::new();
+    result.insert("tokens".to_string(), stats.tokens as f64);
+    result.insert("words".to_string(), stats.words as f64);
+    result.insert("types".to_string(), stats.types as f64);
+    result.insert("sentences".to_string(), stats.sentences as f64);
+    result.insert("avg_word_length".to_string(), stats.avg_word_length);
+    result.insert("avg_syllables_per_word".to_string(), stats.avg_syllables_per_word);
+    result.insert("avg_sentence_length".to_string(), stats.avg_sentence_length);
+    result.insert("atesman".to_string(), stats.atesman);
+    result
+}
+
+/// Character statistics of each line of a text, for dropping spam, tables and
+/// markup: punctuation density, ALL-CAPS ratio, digit ratio and whitespace
+/// ratio, and an anomaly score relative to the limits for running text where
+/// above 1.0 flags the line.
+#[cfg_attr(feature = "python", pyfunction)]
+fn line_anomalies(text: &str) -> Vec<HashMap<String, f64>> {
+    anomaly::score_lines(text)
+        .into_iter()
+        .map(|scores| {
+            let mut result = HashMap::new();
+            result.insert("punctuation".to_string(), scores.punctuation);
+            result.insert("uppercase".to_string(), scores.uppercase);
+            result.insert("digits".to_string(), scores.digits);
+            result.insert("whitespace".to_string(), scores.whitespace);
+            result.insert("anomaly".to_string(), scores.anomaly);
+            result
+        })
+        .collect()
+}
+
+/// Split a word into syllables ("kitaplar" → ["ki", "tap", "lar"]); each
+/// syllable holds one vowel and a single consonant before a vowel opens the
+/// next syllable.
+#[cfg_attr(feature = "python", pyfunction)]
+fn syllabify(word: &str) -> Vec<&str> {
+    readability::syllabify(word)
+}
+
+#[cfg(feature = "python")]
+fn check_ngram_size(n: usize) -> PyResult<()> {
+    if n == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err("n must be positive"));
+    }
+    Ok(())
+}
+
+/// Word n-grams of a token sequence as tuples, in order.
+/// With `counts=True` returns a dict of n-gram → count instead, most frequent
+/// first and ties in order of first appearance. Counting runs without the GIL.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (tokens, n=2, *, counts=false))]
+fn ngrams(py: Python<'_>, tokens: Vec<String>, n: usize, counts: bool) -> PyResult<Py<PyAny>> {
+    check_ngram_size(n)?;
+    if !counts {
+        let grams = stats::ngrams(&tokens, n)
+            .map(|gram| pyo3::types::PyTuple::new(py, gram))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(grams.into_pyobject(py)?.into_any().unbind());
+    }
+    let counted = py.detach(|| stats::count(stats::ngrams(&tokens, n)));
+    let dict = pyo3::types::PyDict::new(py);
+    for (gram, count) in counted {
+        dict.set_item(pyo3::types::PyTuple::new(py, gram)?, count)?;
+    }
+    Ok(dict.into_any().unbind())
+}
+
+/// Character n-grams of a text as strings, in order; whitespace and
+/// punctuation are kept. With `counts=True` returns a dict of n-gram → count
+/// instead, most frequent first and ties in order of first appearance.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (text, n=2, *, counts=false))]
+fn char_ngrams(py: Python<'_>, text: &str, n: usize, counts: bool) -> PyResult<Py<PyAny>> {
+    check_ngram_size(n)?;
+    if !counts {
+        return Ok(stats::char_ngrams(text, n).into_pyobject(py)?.into_any().unbind());
+    }
+    let counted = py.detach(|| stats::count(stats::char_ngrams(text, n)));
+    let dict = pyo3::types::PyDict::new(py);
+    for (gram, count) in counted {
+        dict.set_item(gram, count)?;
+    }
+    Ok(dict.into_any().unbind())
+}
+
+/// Corpus-level term, document and word pair counts for TF-IDF keywords and
+/// PMI collocations (see `stats::CorpusStats`). Documents are split into word
+/// tokens as for `morphological_complexity` and lowercased.
+#[cfg(feature = "python")]
+#[pyclass(module = "durak._durak_core", name = "CorpusStats")]
+struct PyCorpusStats {
+    stats: stats::CorpusStats,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyCorpusStats {
+    #[new]
+    #[pyo3(signature = (documents=None))]
+    fn new(documents: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let mut corpus = Self {
+            stats: stats::CorpusStats::new(),
+        };
+        if let Some(documents) = documents {
+            for document in documents.try_iter()? {
+                corpus.add_document(&document?.extract::<String>()?);
+            }
+        }
+        Ok(corpus)
+    }
+
+    fn add_document(&mut self, text: &str) {
+        let lower = fast_normalize(text, true, true);
+        self.stats.add_document(word_tokens(&lower));
+    }
+
+    /// Occurrences of a term in the corpus, case-insensitive
+    fn term_frequency(&self, term: &str) -> usize {
+        self.stats.term_frequency(&fast_normalize(term, true, true))
+    }
+
+    /// Documents containing a term, case-insensitive
+    fn document_frequency(&self, term: &str) -> usize {
+        self.stats.document_frequency(&fast_normalize(term, true, true))
+    }
+
+    /// Relative corpus frequency of a term times its smoothed IDF
+    fn tfidf(&self, term: &str) -> f64 {
+        self.stats.tfidf(&fast_normalize(term, true, true))
+    }
+
+    /// `(term, tfidf)` pairs of the k highest scoring terms
+    #[pyo3(signature = (k=10))]
+    fn top_keywords(&self, k: usize) -> Vec<(String, f64)> {
+        self.stats.top_keywords(k)
+    }
+
+    /// `(term, score)` pairs of the k likeliest corpus-specific stopwords
+    /// among terms in at least `min_df` documents
+    #[pyo3(signature = (k=50, min_df=2))]
+    fn stopword_candidates(&self, k: usize, min_df: usize) -> Vec<(String, f64)> {
+        self.stats.stopword_candidates(k, min_df)
+    }
+
+    /// `(first, second, pmi, count)` for the k adjacent word pairs with the
+    /// highest PMI among pairs seen at least `min_count` times
+    #[pyo3(signature = (k=10, min_count=2))]
+    fn top_collocations(&self, k: usize, min_count: usize) -> Vec<(String, String, f64, usize)> {
+        self.stats
+            .top_collocations(k, min_count)
+            .into_iter()
+            .map(|c| (c.first, c.second, c.pmi, c.count))
+            .collect()
+    }
+
+    #[getter]
+    fn documents(&self) -> usize {
+        self.stats.documents()
+    }
+
+    #[getter]
+    fn tokens(&self) -> usize {
+        self.stats.tokens()
+    }
+
+    fn __len__(&self) -> usize {
+        self.stats.documents()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "CorpusStats(documents={}, tokens={})",
+            self.stats.documents(),
+            self.stats.tokens()
+        )
+    }
+}
+
+/// Corpus-specific stopwords: the top_k terms in at least min_df documents,
+/// most frequent and evenly spread first (see `CorpusStats.stopword_candidates`).
+/// With merge_base the base stopwords not among them follow, alphabetically.
+#[cfg(feature = "python")]
 #[pyfunction]
-#[pyo3(signature = (word, strict=false, min_root_length=2, check_harmony=true))]
-fn strip_suffixes_validated(
+#[pyo3(signature = (documents, top_k=50, min_df=2, merge_base=false))]
+fn discover_stopwords(
+    documents: &Bound<'_, PyAny>,
+    top_k: usize,
+    min_df: usize,
+    merge_base: bool,
+) -> PyResult<Vec<String>> {
+    let corpus = PyCorpusStats::new(Some(documents))?;
+    let mut words: Vec<String> = corpus
+        .stats
+        .stopword_candidates(top_k, min_df)
+        .into_iter()
+        .map(|(term, _)| term)
+        .collect();
+    if merge_base {
+        let mut base = get_stopwords_base();
+        base.sort();
+        base.dedup();
+        base.retain(|word| !words.contains(word));
+        words.extend(base);
+    }
+    Ok(words)
+}
+
+/// Coarse part-of-speech tags for a sequence of tokens.
+/// Returns (token, tag) pairs with tags Noun, Verb, Adj, Adv, Pron, Punc or Num,
+/// derived from morphological analysis with a suffix-based fallback for unknown words.
+#[cfg_attr(feature = "python", pyfunction)]
+fn pos_tag(tokens: Vec<String>) -> Vec<(String, String)> {
+    let tags = pos::pos_tag(tokens.iter().map(String::as_str));
+    tokens
+        .into_iter()
+        .zip(tags)
+        .map(|(token, tag)| (token, tag.as_str().to_string()))
+        .collect()
+}
+
+// ============================================================================
+// SPELL CHECKING
+// ============================================================================
+
+/// Check whether a word is spelled correctly.
+/// A word is correct when the morphological analyzer can parse it or it is a
+/// known standalone word; numbers and apostrophe-marked proper nouns are accepted.
+#[cfg_attr(feature = "python", pyfunction)]
+fn is_correct(word: &str) -> bool {
+    spell::is_correct(word)
+}
+
+/// Spelling suggestions for a word, best first.
+/// Candidates are dictionary forms and generated inflections within `max_edits`
+/// edits; missing diacritics (ç/c, ğ/g, ı/i, ö/o, ş/s, ü/u) cost no edit.
+#[cfg_attr(feature = "python", pyfunction)]
+#[cfg_attr(feature = "python", pyo3(signature = (word, max_edits=2, k=5)))]
+fn suggest(word: &str, max_edits: usize, k: usize) -> Vec<String> {
+    spell::suggest(word, max_edits, k)
+        .into_iter()
+        .map(|suggestion| suggestion.word)
+        .collect()
+}
+
+/// Ranked correction candidates from spelling, diacritic restoration and
+/// confusion pairs, as (text, score, source) tuples, best first.
+/// Scores combine the frequency of the root with the likelihood of the
+/// edits and are normalized over all candidates, so they sum to at most 1.0.
+#[cfg_attr(feature = "python", pyfunction)]
+#[cfg_attr(feature = "python", pyo3(signature = (word, max_edits=2, k=5)))]
+fn ranked_suggestions(word: &str, max_edits: usize, k: usize) -> Vec<(String, f64, &'static str)> {
+    suggestion::suggest(word, max_edits, k)
+        .into_iter()
+        .map(|s| (s.text, s.score, s.source.as_str()))
+        .collect()
+}
+
+// ============================================================================
+// BATCH PROCESSING (DATAFRAME INTEGRATIONS)
+// ============================================================================
+// One call per column chunk instead of one Python call per cell. Nulls in the
+// input come back as nulls so dataframe validity masks are preserved. The
+// `threads` argument spreads a batch over rayon workers (see parallel.rs).
+// Long calls stop early on Ctrl-C or a cancelled `CancellationToken` (see
+// cancel.rs and `run_cancellable`).
+
+/// Lemmatization strategy, mirroring `durak.Lemmatizer`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Strategy {
+    Lookup,
+    Heuristic,
+    Hybrid,
+}
+
+impl Strategy {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "lookup" => Some(Strategy::Lookup),
+            "heuristic" => Some(Strategy::Heuristic),
+            "hybrid" => Some(Strategy::Hybrid),
+            _ => None,
+        }
+    }
+}
+
+/// A word and the `lemmatize_word` options it was lemmatized with, the
+/// configured harmony check last
+type LemmaKey = (String, (Strategy, bool, bool, usize, bool));
+
+static LEMMA_CACHE: OnceLock<lemma_cache::ShardedLru<LemmaKey, String>> = OnceLock::new();
+
+/// Cache of `lemmatize_word` results, emptied when resources change
+fn lemma_cache() -> &'static lemma_cache::ShardedLru<LemmaKey, String> {
+    LEMMA_CACHE.get_or_init(|| lemma_cache::ShardedLru::new(lemma_cache::DEFAULT_CAPACITY))
+}
+
+/// Lemma of a word under a `durak.Lemmatizer` configuration, from the
+/// lemma cache when the word was seen with the same options. Root
+/// validation checks vowel harmony as configured (see config.rs).
+fn lemmatize_word(
     word: &str,
-    strict: bool,
+    strategy: Strategy,
+    validate_roots: bool,
+    strict_validation: bool,
     min_root_length: usize,
-    check_harmony: bool,
 ) -> String {
-    // In strict mode, first check if the word is in the lemma dictionary
-    if strict {
+    if word.is_empty() {
+        return String::new();
+    }
+    let check_harmony = config::read().check_harmony;
+    let options = (
+        strategy,
+        validate_roots,
+        strict_validation,
+        min_root_length,
+        check_harmony,
+    );
+    // Probed with the borrowed word; the key is only allocated on a miss
+    lemma_cache().get_or_insert_with((word, options), |(word, _)| {
+        let options = (validate_roots, strict_validation, min_root_length, check_harmony);
+        lemmatize_word_uncached(word, strategy, options)
+    })
+}
+
+/// `lemmatize_word` without the cache; `options` are (validate_roots,
+/// strict_validation, min_root_length, check_harmony)
+fn lemmatize_word_uncached(
+    word: &str,
+    strategy: Strategy,
+    options: (bool, bool, usize, bool),
+) -> String {
+    let (validate_roots, strict_validation, min_root_length, check_harmony) = options;
+    if strategy != Strategy::Heuristic {
         if let Some(lemma) = lookup_lemma(word) {
             return lemma;
         }
+        if strategy == Strategy::Lookup {
+            return word.to_string();
+        }
+    }
+    if validate_roots {
+        strip_suffixes_validated(word, strict_validation, min_root_length, check_harmony, true)
+    } else {
+        strip_suffixes(word)
+    }
+}
+
+/// How `lemma_candidates` orders the lemmas of a word when the dictionary,
+/// the other dictionary readings of the word and suffix stripping disagree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TieBreak {
+    /// The lemma `lemmatize_word` picks: the dictionary entry, else the
+    /// stripped root
+    PreferDictionary,
+    /// The candidate with the fewest characters
+    PreferShortest,
+    /// The candidate ranked highest in lemma_frequency.txt; unranked
+    /// candidates come last
+    PreferMostFrequent,
+    /// Every candidate, in the `PreferDictionary` order
+    ReturnAll,
+}
+
+impl TieBreak {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "prefer-dictionary" => Some(TieBreak::PreferDictionary),
+            "prefer-shortest" => Some(TieBreak::PreferShortest),
+            "prefer-most-frequent" => Some(TieBreak::PreferMostFrequent),
+            "return-all" => Some(TieBreak::ReturnAll),
+            _ => None,
+        }
+    }
+}
+
+/// Candidate lemmas of a word, best first under `tie_break`
+///
+/// The candidates are the `lemmatize_word` result, then the roots of the
+/// morphological analyses (the other dictionary readings, unless the
+/// strategy is heuristic), then the stripped root when the dictionary had
+/// an entry. Ties keep that order. `ReturnAll` keeps every candidate, the
+/// other policies only the best one.
+///
+/// # Examples
+/// ```text
+/// lemma_candidates("gelir", Hybrid, .., ReturnAll)              -> [gel, gelir]
+/// lemma_candidates("gözlükçü", Hybrid, .., PreferDictionary)     -> [gözlükç]
+/// lemma_candidates("gözlükçü", Hybrid, .., PreferMostFrequent)   -> [göz]
+/// ```
+fn lemma_candidates(
+    word: &str,
+    strategy: Strategy,
+    validate_roots: bool,
+    strict_validation: bool,
+    min_root_length: usize,
+    tie_break: TieBreak,
+) -> Vec<String> {
+    let first = lemmatize_word(word, strategy, validate_roots, strict_validation, min_root_length);
+    if word.is_empty() || tie_break == TieBreak::PreferDictionary {
+        return vec![first];
+    }
+    let mut candidates = vec![first];
+    if strategy != Strategy::Heuristic {
+        candidates.extend(analyzer::analyze(word).into_iter().map(|a| a.root));
+        if strategy == Strategy::Hybrid && lemma_dict::lookup(word).is_some() {
+            candidates.push(match validate_roots {
+                true => {
+                    let check_harmony = config::read().check_harmony;
+                    strip_suffixes_validated(
+                        word,
+                        strict_validation,
+                        min_root_length,
+                        check_harmony,
+                        true,
+                    )
+                }
+                false => strip_suffixes(word),
+            });
+        }
+    }
+    let mut unique: Vec<String> = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        if !unique.contains(&candidate) {
+            unique.push(candidate);
+        }
+    }
+    // Stable sorts, so ties keep the dictionary-first order
+    match tie_break {
+        TieBreak::PreferShortest => unique.sort_by_key(|lemma| lemma.chars().count()),
+        TieBreak::PreferMostFrequent => unique.sort_by_key(|lemma| {
+            suggestion::frequency_rank(lemma).unwrap_or(usize::MAX)
+        }),
+        TieBreak::PreferDictionary | TieBreak::ReturnAll => {}
+    }
+    if tie_break != TieBreak::ReturnAll {
+        unique.truncate(1);
+    }
+    unique
+}
+
+/// How a batch function handles malformed items: values that are neither
+/// strings nor missing, and strings that are not valid UTF-8 (lone
+/// surrogates)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BatchErrors {
+    /// Fail the whole batch on the first malformed item
+    Raise,
+    /// Return a `BatchResult` per item, so one malformed document does not
+    /// discard the work done on the others
+    Isolate,
+}
+
+impl BatchErrors {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "raise" => Some(BatchErrors::Raise),
+            "isolate" => Some(BatchErrors::Isolate),
+            _ => None,
+        }
+    }
+}
+
+/// Outcome of one item of a batch run with `errors="isolate"`: the value
+/// the batch function returns for it, or the reason it failed
+#[cfg(feature = "python")]
+#[pyclass(module = "durak._durak_core", name = "BatchResult", frozen)]
+struct PyBatchResult {
+    value: Py<PyAny>,
+    error: Option<String>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyBatchResult {
+    /// Whether the item was processed
+    #[getter]
+    fn ok(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// The result for the item, None when it failed
+    #[getter]
+    fn value(&self, py: Python<'_>) -> Py<PyAny> {
+        self.value.clone_ref(py)
+    }
+
+    /// Why the item failed, None when it was processed
+    #[getter]
+    fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+
+    /// The result for the item; raises ValueError with the reason when it
+    /// failed
+    fn unwrap(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        match &self.error {
+            None => Ok(self.value.clone_ref(py)),
+            Some(error) => Err(pyo3::exceptions::PyValueError::new_err(error.clone())),
+        }
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        match &self.error {
+            None => Ok(format!("Ok({})", self.value.bind(py).repr()?)),
+            Some(error) => Ok(format!("Err({:?})", error)),
+        }
+    }
+}
+
+/// Positions and errors of the malformed items of a batch
+#[cfg(feature = "python")]
+type BatchFailures = Vec<(usize, PyErr)>;
+
+/// Strings of a batch, missing values (None, NaN, pd.NA) as None. Under
+/// `errors="raise"` the first malformed item raises; under "isolate" it
+/// becomes None and its error is returned along with its position.
+#[cfg(feature = "python")]
+fn batch_texts(
+    values: &Bound<'_, PyAny>,
+    function: &str,
+    errors: &str,
+) -> PyResult<(Vec<Option<String>>, Option<BatchFailures>)> {
+    let mode = BatchErrors::parse(errors).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "unknown errors mode '{}', expected raise or isolate",
+            errors
+        ))
+    })?;
+    if values.is_instance_of::<pyo3::types::PyString>() {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} expects an iterable of strings, got a single str",
+            function
+        )));
+    }
+    let mut texts = Vec::new();
+    let mut failures = Vec::new();
+    for (index, value) in values.try_iter()?.enumerate() {
+        let value = value?;
+        let text = match value.cast::<pyo3::types::PyString>() {
+            Ok(text) => text.to_str().map(|text| Some(text.to_string())),
+            Err(_) if is_missing(&value)? => Ok(None),
+            Err(_) => Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "{} expects strings or missing values, got {} at position {}",
+                function,
+                value.get_type().name()?,
+                index
+            ))),
+        };
+        match text {
+            Ok(text) => texts.push(text),
+            Err(error) if mode == BatchErrors::Raise => return Err(error),
+            Err(error) => {
+                texts.push(None);
+                failures.push((index, error));
+            }
+        }
+    }
+    Ok((texts, (mode == BatchErrors::Isolate).then_some(failures)))
+}
+
+/// The outputs of a batch as a list, or a list of `BatchResult`s when the
+/// batch isolates errors (`failures` from `batch_texts`)
+#[cfg(feature = "python")]
+fn batch_output<'py, T>(
+    py: Python<'py>,
+    outputs: Vec<T>,
+    failures: Option<BatchFailures>,
+) -> PyResult<Py<PyAny>>
+where
+    T: IntoPyObject<'py>,
+{
+    let Some(failures) = failures else {
+        return pyo3::IntoPyObjectExt::into_py_any(outputs, py);
+    };
+    let mut results = Vec::with_capacity(outputs.len());
+    for output in outputs {
+        let value = pyo3::IntoPyObjectExt::into_py_any(output, py)?;
+        results.push(PyBatchResult { value, error: None });
+    }
+    for (index, error) in failures {
+        results[index] = PyBatchResult {
+            value: py.None(),
+            error: Some(error.to_string()),
+        };
+    }
+    Ok(results.into_pyobject(py)?.into_any().unbind())
+}
+
+#[cfg(feature = "python")]
+pyo3::import_exception!(durak.exceptions, CancelledError);
+
+/// How often a cancellable call checks for Ctrl-C while it runs
+#[cfg(feature = "python")]
+const INTERRUPT_POLL: std::time::Duration = std::time::Duration::from_millis(50);
+
+/// A flag that stops batch and sampling calls running on other threads.
+/// Pass it as `cancel=`; after `cancel()` the current and every later call
+/// raises `durak.exceptions.CancelledError`, so use a new token per job.
+#[cfg(feature = "python")]
+#[pyclass(name = "CancellationToken", module = "durak._durak_core", frozen)]
+struct PyCancellationToken {
+    token: cancel::Token,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyCancellationToken {
+    #[new]
+    fn new() -> Self {
+        Self {
+            token: cancel::Token::new(),
+        }
+    }
+
+    /// Stop the calls using this token at their next item
+    fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    #[getter]
+    fn cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    fn __repr__(&self) -> String {
+        let cancelled = if self.cancelled() { "True" } else { "False" };
+        format!("CancellationToken(cancelled={})", cancelled)
+    }
+}
+
+/// Run `f` without the GIL on a worker thread while this thread checks for
+/// Ctrl-C. On an interrupt the worker is stopped through its token and
+/// KeyboardInterrupt is raised; a cancelled `cancel` token raises
+/// CancelledError. The Python thread never blocks for longer than
+/// `INTERRUPT_POLL`, so notebooks stay responsive to the interrupt button.
+#[cfg(feature = "python")]
+fn run_cancellable<T, F>(
+    py: Python<'_>,
+    cancel: Option<&PyCancellationToken>,
+    f: F,
+) -> PyResult<T>
+where
+    T: Send,
+    F: FnOnce(&cancel::Token) -> Result<T, cancel::Cancelled> + Send,
+{
+    let token = match cancel {
+        Some(cancel) => cancel.token.child(),
+        None => cancel::Token::new(),
+    };
+    let caller = std::thread::current();
+    std::thread::scope(|scope| {
+        let token = &token;
+        let worker = scope.spawn(move || {
+            let result = f(token);
+            caller.unpark();
+            result
+        });
+        let mut interrupt = None;
+        while !worker.is_finished() {
+            py.detach(|| std::thread::park_timeout(INTERRUPT_POLL));
+            if interrupt.is_none() {
+                if let Err(error) = py.check_signals() {
+                    token.cancel();
+                    interrupt = Some(error);
+                }
+            }
+        }
+        let result = worker
+            .join()
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+        if let Some(error) = interrupt {
+            return Err(error);
+        }
+        result.map_err(|cancelled| CancelledError::new_err(cancelled.to_string()))
+    })
+}
+
+#[cfg(feature = "python")]
+fn parse_lemma_options(strategy: &str, tie_break: &str) -> PyResult<(Strategy, TieBreak)> {
+    let strategy = Strategy::parse(strategy).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "unknown strategy '{}', expected lookup, heuristic or hybrid",
+            strategy
+        ))
+    })?;
+    let tie_break = TieBreak::parse(tie_break).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "unknown tie_break '{}', expected prefer-dictionary, prefer-shortest, \
+             prefer-most-frequent or return-all",
+            tie_break
+        ))
+    })?;
+    Ok((strategy, tie_break))
+}
+
+/// `min_root_length`, else the configured one (see config.rs)
+#[cfg(feature = "python")]
+fn configured_min_root_length(min_root_length: Option<usize>) -> usize {
+    min_root_length.unwrap_or_else(|| config::read().min_root_length)
+}
+
+/// Candidate lemmas of a word under a `Lemmatizer` configuration, best
+/// first under `tie_break` (see `TieBreak`); a single lemma unless
+/// `tie_break="return-all"`
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "lemma_candidates")]
+#[pyo3(signature = (
+    word,
+    strategy="hybrid",
+    *,
+    validate_roots=false,
+    strict_validation=false,
+    min_root_length=None,
+    tie_break="prefer-dictionary",
+))]
+fn lemma_candidates_py(
+    word: &str,
+    strategy: &str,
+    validate_roots: bool,
+    strict_validation: bool,
+    min_root_length: Option<usize>,
+    tie_break: &str,
+) -> PyResult<Vec<String>> {
+    let (strategy, tie_break) = parse_lemma_options(strategy, tie_break)?;
+    Ok(lemma_candidates(
+        word,
+        strategy,
+        validate_roots,
+        strict_validation,
+        configured_min_root_length(min_root_length),
+        tie_break,
+    ))
+}
+
+/// Hits, misses, current size and maximum size of the lemma cache
+#[cfg(feature = "python")]
+#[pyfunction]
+fn lemma_cache_info() -> std::collections::HashMap<&'static str, u64> {
+    let info = lemma_cache().info();
+    std::collections::HashMap::from([
+        ("hits", info.hits),
+        ("misses", info.misses),
+        ("size", info.size as u64),
+        ("maxsize", info.maxsize as u64),
+    ])
+}
+
+/// Set the number of lemmas the cache keeps (0 disables it); drops the
+/// cached lemmas and resets the counters
+#[cfg(feature = "python")]
+#[pyfunction]
+fn set_lemma_cache_size(maxsize: usize) {
+    lemma_cache().resize(maxsize);
+}
+
+/// Drop the cached lemmas and reset the counters
+#[cfg(feature = "python")]
+#[pyfunction]
+fn clear_lemma_cache() {
+    lemma_cache().clear();
+}
+
+/// Lemmatize a batch of words with the same options as `Lemmatizer`.
+/// Runs without the GIL; raises ValueError for an unknown strategy or
+/// tie-break policy. With `tie_break="return-all"` each word maps to its
+/// list of candidates.
+/// With `errors="isolate"` each item comes back as a `BatchResult`. Stops
+/// on Ctrl-C (KeyboardInterrupt) or once `cancel` is cancelled.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (
+    words,
+    strategy="hybrid",
+    validate_roots=false,
+    strict_validation=false,
+    min_root_length=None,
+    threads=1,
+    *,
+    errors="raise",
+    tie_break="prefer-dictionary",
+    cancel=None,
+))]
+#[allow(clippy::too_many_arguments)]
+fn lemmatize_batch(
+    py: Python<'_>,
+    words: &Bound<'_, PyAny>,
+    strategy: &str,
+    validate_roots: bool,
+    strict_validation: bool,
+    min_root_length: Option<usize>,
+    threads: usize,
+    errors: &str,
+    tie_break: &str,
+    cancel: Option<&PyCancellationToken>,
+) -> PyResult<Py<PyAny>> {
+    let min_root_length = configured_min_root_length(min_root_length);
+    let (strategy, tie_break) = parse_lemma_options(strategy, tie_break)?;
+    let (words, failures) = batch_texts(words, "lemmatize_batch", errors)?;
+    let candidates = run_cancellable(py, cancel, |token| {
+        parallel::map_cancellable(&words, threads, token, |word| {
+            word.as_deref().map(|word| {
+                lemma_candidates(
+                    word,
+                    strategy,
+                    validate_roots,
+                    strict_validation,
+                    min_root_length,
+                    tie_break,
+                )
+            })
+        })
+    })?;
+    if tie_break == TieBreak::ReturnAll {
+        return batch_output(py, candidates, failures);
+    }
+    let lemmas: Vec<Option<String>> = candidates
+        .into_iter()
+        .map(|lemmas| lemmas.and_then(|lemmas| lemmas.into_iter().next()))
+        .collect();
+    batch_output(py, lemmas, failures)
+}
+
+/// Normalize a batch of texts (see `fast_normalize`)
+fn normalize_batch(
+    texts: Vec<Option<String>>,
+    lowercase: bool,
+    handle_turkish_i: bool,
+    threads: usize,
+    token: &cancel::Token,
+) -> Result<Vec<Option<String>>, cancel::Cancelled> {
+    parallel::map_cancellable(&texts, threads, token, |text| {
+        text.as_deref()
+            .map(|text| fast_normalize(text, lowercase, handle_turkish_i))
+    })
+}
+
+/// Normalize a batch of texts (see `fast_normalize`).
+/// With `errors="isolate"` each item comes back as a `BatchResult`. Stops
+/// on Ctrl-C (KeyboardInterrupt) or once `cancel` is cancelled.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "normalize_batch",
+    signature = (
+        texts, lowercase=true, handle_turkish_i=true, threads=1, *, errors="raise", cancel=None
+    )
+)]
+fn normalize_batch_py(
+    py: Python<'_>,
+    texts: &Bound<'_, PyAny>,
+    lowercase: bool,
+    handle_turkish_i: bool,
+    threads: usize,
+    errors: &str,
+    cancel: Option<&PyCancellationToken>,
+) -> PyResult<Py<PyAny>> {
+    let (texts, failures) = batch_texts(texts, "normalize_batch", errors)?;
+    let normalized = run_cancellable(py, cancel, |token| {
+        normalize_batch(texts, lowercase, handle_turkish_i, threads, token)
+    })?;
+    batch_output(py, normalized, failures)
+}
+
+/// Tokenize a batch of texts into lists of tokens, kept in one arena per
+/// chunk of texts instead of one `String` per token (see `arena`)
+fn tokenize_batch(
+    texts: Vec<Option<String>>,
+    classes: ClassSet,
+    threads: usize,
+    token: &cancel::Token,
+) -> Result<Vec<arena::StrArena>, cancel::Cancelled> {
+    let chunks: Vec<&[Option<String>]> =
+        texts.chunks(parallel::chunk_len(texts.len(), threads)).collect();
+    parallel::map_cancellable(&chunks, threads, token, |chunk| {
+        let mut arena = arena::StrArena::new();
+        for text in chunk.iter() {
+            let Some(text) = text else {
+                arena.push_missing();
+                continue;
+            };
+            for span in TokenSpans::with_classes(text, classes) {
+                arena.push(&text[span.start..span.end]);
+            }
+            arena.finish_document();
+        }
+        arena
+    })
+}
+
+/// One list of strings per document of a run of arenas, None for missing
+/// documents, converted straight from the arena buffers
+#[cfg(feature = "python")]
+fn arena_lists<'py>(
+    py: Python<'py>,
+    arenas: &[arena::StrArena],
+) -> PyResult<Vec<Option<Bound<'py, pyo3::types::PyList>>>> {
+    let mut lists = Vec::with_capacity(arenas.iter().map(arena::StrArena::len).sum());
+    for arena in arenas {
+        for index in 0..arena.len() {
+            let list = arena
+                .document(index)
+                .map(|strings| pyo3::types::PyList::new(py, strings))
+                .transpose()?;
+            lists.push(list);
+        }
+    }
+    Ok(lists)
+}
+
+/// Tokenize a batch of texts into lists of tokens.
+/// With `errors="isolate"` each item comes back as a `BatchResult`. Stops
+/// on Ctrl-C (KeyboardInterrupt) or once `cancel` is cancelled.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "tokenize_batch",
+    signature = (texts, threads=1, *, errors="raise", cancel=None)
+)]
+fn tokenize_batch_py(
+    py: Python<'_>,
+    texts: &Bound<'_, PyAny>,
+    threads: usize,
+    errors: &str,
+    cancel: Option<&PyCancellationToken>,
+) -> PyResult<Py<PyAny>> {
+    let (texts, failures) = batch_texts(texts, "tokenize_batch", errors)?;
+    let arenas = run_cancellable(py, cancel, |token| {
+        tokenize_batch(texts, ClassSet::ALL, threads, token)
+    })?;
+    batch_output(py, arena_lists(py, &arenas)?, failures)
+}
+
+/// Element-wise stage applied by `process_series`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SeriesStage {
+    Normalize,
+    CorrectConfusions,
+    Tokenize,
+    Lemmatize,
+}
+
+impl SeriesStage {
+    const NAMES: &'static [&'static str] =
+        &["normalize", "correct_confusions", "tokenize", "lemmatize"];
+
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "normalize" => Some(SeriesStage::Normalize),
+            "correct_confusions" => Some(SeriesStage::CorrectConfusions),
+            "tokenize" => Some(SeriesStage::Tokenize),
+            "lemmatize" => Some(SeriesStage::Lemmatize),
+            _ => None,
+        }
+    }
+
+    fn apply(self, value: &str) -> StageOutput {
+        match self {
+            SeriesStage::Normalize => StageOutput::Text(fast_normalize(value, true, true)),
+            SeriesStage::CorrectConfusions => StageOutput::Text(correct_confusions(value)),
+            SeriesStage::Tokenize => StageOutput::Tokens(chunk_tokens(value, false)),
+            SeriesStage::Lemmatize => {
+                StageOutput::Text(lemmatize_word(value, Strategy::Hybrid, false, false, 2))
+            }
+        }
+    }
+}
+
+/// Result of a stage on one value: a string, or a token list for `tokenize`
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "python", derive(IntoPyObject))]
+enum StageOutput {
+    Text(String),
+    Tokens(Vec<String>),
+}
+
+/// Missing values as pandas represents them: None, float NaN and pd.NA
+#[cfg(feature = "python")]
+fn is_missing(value: &Bound<'_, PyAny>) -> PyResult<bool> {
+    if value.is_none() {
+        return Ok(true);
+    }
+    if let Ok(number) = value.cast::<pyo3::types::PyFloat>() {
+        return Ok(number.value().is_nan());
+    }
+    Ok(value.get_type().name()? == "NAType")
+}
+
+/// Apply a processing stage to every element of a column.
+/// Accepts any iterable (list, pandas Series, numpy array); missing values
+/// (None, NaN, pd.NA) come back as None at the same position. The stage runs
+/// without the GIL. Raises ValueError for an unknown stage and TypeError for
+/// non-string values, unless `errors="isolate"` returns a `BatchResult` per
+/// item instead. Stops on Ctrl-C (KeyboardInterrupt) or once `cancel` is
+/// cancelled.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (values, stage, *, errors="raise", cancel=None))]
+fn process_series(
+    py: Python<'_>,
+    values: &Bound<'_, PyAny>,
+    stage: &str,
+    errors: &str,
+    cancel: Option<&PyCancellationToken>,
+) -> PyResult<Py<PyAny>> {
+    let stage = SeriesStage::parse(stage).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "unknown stage '{}', expected one of: {}",
+            stage,
+            SeriesStage::NAMES.join(", ")
+        ))
+    })?;
+    let (texts, failures) = batch_texts(values, "process_series", errors)?;
+    let outputs = run_cancellable(py, cancel, |token| {
+        parallel::map_cancellable(&texts, 1, token, |text| {
+            text.as_deref().map(|text| stage.apply(text))
+        })
+    })?;
+    batch_output(py, outputs, failures)
+}
+
+/// A column built by durak, exported through the Arrow PyCapsule interface
+/// (`pyarrow.array(column)`, `polars.Series(column)`)
+#[cfg(feature = "python")]
+#[pyclass(module = "durak._durak_core", name = "ArrowArray", frozen)]
+struct PyArrowArray {
+    array: arrow_array::ArrayRef,
+}
+
+#[cfg(feature = "python")]
+fn arrow_value(py: Python<'_>, value: arrow::Value) -> PyResult<Py<PyAny>> {
+    use pyo3::IntoPyObjectExt;
+    match value {
+        arrow::Value::Null => Ok(py.None()),
+        arrow::Value::Str(text) => text.into_py_any(py),
+        arrow::Value::Int(number) => number.into_py_any(py),
+        arrow::Value::List(values) => values
+            .into_iter()
+            .map(|value| arrow_value(py, value))
+            .collect::<PyResult<Vec<_>>>()?
+            .into_py_any(py),
+    }
+}
+
+#[cfg(feature = "python")]
+impl PyArrowArray {
+    fn field(&self) -> arrow_schema::FieldRef {
+        std::sync::Arc::new(arrow_schema::Field::new("", self.array.data_type().clone(), true))
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyArrowArray {
+    /// A `large_string` column of the given strings and Nones
+    #[staticmethod]
+    fn from_pylist(values: Vec<Option<String>>) -> Self {
+        arrow_column(arrow::string_column(values.iter().map(Option::as_deref)))
+    }
+
+    /// Arrow type name, e.g. "large_list<large_string>"
+    #[getter]
+    fn r#type(&self) -> String {
+        arrow::type_name(self.array.data_type())
+    }
+
+    /// Values as Python objects: strings, ints, lists and Nones
+    fn to_pylist(&self, py: Python<'_>) -> PyResult<Vec<Py<PyAny>>> {
+        (0..self.array.len())
+            .map(|i| arrow_value(py, arrow::value(self.array.as_ref(), i)))
+            .collect()
+    }
+
+    /// Export as (schema, array) capsules, cast to `requested_schema` when
+    /// the column can be
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_array__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<Bound<'py, pyo3::types::PyCapsule>>,
+    ) -> PyResult<Bound<'py, pyo3::types::PyTuple>> {
+        let capsules = pyo3_arrow::ffi::to_array_pycapsules(
+            py,
+            self.field(),
+            self.array.as_ref(),
+            requested_schema,
+        )?;
+        Ok(capsules)
+    }
+
+    /// Export as a stream capsule of one array
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<Bound<'py, pyo3::types::PyCapsule>>,
+    ) -> PyResult<Bound<'py, pyo3::types::PyCapsule>> {
+        let reader =
+            pyo3_arrow::ffi::ArrayIterator::new([Ok(self.array.clone())], self.field());
+        let capsule =
+            pyo3_arrow::ffi::to_stream_pycapsule(py, Box::new(reader), requested_schema)?;
+        Ok(capsule)
+    }
+
+    fn __len__(&self) -> usize {
+        self.array.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ArrowArray(type={}, length={})", self.r#type(), self.array.len())
+    }
+}
+
+/// Run `f` on the strings of an Arrow column passed through the PyCapsule
+/// interface; they are borrowed from the imported arrays, which outlive the
+/// call. Raises TypeError for objects without the interface and ValueError
+/// for non-string columns.
+#[cfg(feature = "python")]
+fn with_arrow_strings<T>(
+    function: &str,
+    array: &Bound<'_, PyAny>,
+    f: impl FnOnce(&[Option<&str>]) -> PyResult<T>,
+) -> PyResult<T> {
+    let value_error = pyo3::exceptions::PyValueError::new_err;
+    if !(array.hasattr("__arrow_c_array__")? || array.hasattr("__arrow_c_stream__")?) {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} expects an Arrow array with __arrow_c_array__ or \
+             __arrow_c_stream__, got {}",
+            function,
+            array.get_type().name()?
+        )));
+    }
+    // A stream is read to its end; every chunk stays alive until `f` returns
+    let input: pyo3_arrow::input::AnyArray = array.extract()?;
+    let chunks = input
+        .into_reader()?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| value_error(e.to_string()))?;
+    let mut values = Vec::new();
+    for chunk in &chunks {
+        values.extend(arrow::strings(chunk.as_ref()).map_err(value_error)?);
+    }
+    f(&values)
+}
+
+/// Wrap a column built in Rust for export to Arrow consumers
+#[cfg(feature = "python")]
+fn arrow_column(array: arrow_array::ArrayRef) -> PyArrowArray {
+    PyArrowArray { array }
+}
+
+/// Tokenize a string column passed through the Arrow PyCapsule interface
+/// (pyarrow arrays and chunked arrays, polars series, or anything with
+/// `__arrow_c_array__` or `__arrow_c_stream__`) without copying its strings.
+/// Returns (tokens, starts, ends) as `large_list` columns; starts and ends
+/// are character offsets and null strings give null lists. Raises TypeError
+/// for other objects and ValueError for non-string columns. Stops on Ctrl-C
+/// (KeyboardInterrupt) or once `cancel` is cancelled.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (array, *, threads=1, cancel=None))]
+fn tokenize_arrow(
+    py: Python<'_>,
+    array: &Bound<'_, PyAny>,
+    threads: usize,
+    cancel: Option<&PyCancellationToken>,
+) -> PyResult<(PyArrowArray, PyArrowArray, PyArrowArray)> {
+    let (tokens, starts, ends) = with_arrow_strings("tokenize_arrow", array, |values| {
+        run_cancellable(py, cancel, |token| arrow::tokenize(values, threads, token))
+    })?;
+    Ok((arrow_column(tokens), arrow_column(starts), arrow_column(ends)))
+}
+
+/// Normalize a string column passed through the Arrow PyCapsule interface
+/// (see `tokenize_arrow` and `fast_normalize`) into a `large_string`
+/// column; nulls stay null
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (array, *, lowercase=true, handle_turkish_i=true, threads=1, cancel=None))]
+fn normalize_arrow(
+    py: Python<'_>,
+    array: &Bound<'_, PyAny>,
+    lowercase: bool,
+    handle_turkish_i: bool,
+    threads: usize,
+    cancel: Option<&PyCancellationToken>,
+) -> PyResult<PyArrowArray> {
+    let column = with_arrow_strings("normalize_arrow", array, |values| {
+        run_cancellable(py, cancel, |token| {
+            arrow::map_strings(values, threads, token, |text| {
+                fast_normalize(text, lowercase, handle_turkish_i)
+            })
+        })
+    })?;
+    Ok(arrow_column(column))
+}
+
+/// Lemmatize a string column of words passed through the Arrow PyCapsule
+/// interface (see `tokenize_arrow`) with the options of `lemmatize_batch`
+/// into a `large_string` column; nulls stay null
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (
+    array,
+    strategy="hybrid",
+    *,
+    validate_roots=false,
+    strict_validation=false,
+    min_root_length=None,
+    threads=1,
+    cancel=None,
+))]
+#[allow(clippy::too_many_arguments)]
+fn lemmatize_arrow(
+    py: Python<'_>,
+    array: &Bound<'_, PyAny>,
+    strategy: &str,
+    validate_roots: bool,
+    strict_validation: bool,
+    min_root_length: Option<usize>,
+    threads: usize,
+    cancel: Option<&PyCancellationToken>,
+) -> PyResult<PyArrowArray> {
+    let min_root_length = configured_min_root_length(min_root_length);
+    let strategy = Strategy::parse(strategy).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "unknown strategy '{}', expected lookup, heuristic or hybrid",
+            strategy
+        ))
+    })?;
+    let column = with_arrow_strings("lemmatize_arrow", array, |values| {
+        run_cancellable(py, cancel, |token| {
+            arrow::map_strings(values, threads, token, |word| {
+                lemmatize_word(
+                    word,
+                    strategy,
+                    validate_roots,
+                    strict_validation,
+                    min_root_length,
+                )
+            })
+        })
+    })?;
+    Ok(arrow_column(column))
+}
+
+// ============================================================================
+// STREAMING TOKENIZATION
+// ============================================================================
+
+/// Tokens of one chunk of text, optionally without punctuation tokens
+fn chunk_tokens(text: &str, strip_punct: bool) -> Vec<String> {
+    token_spans(text)
+        .into_iter()
+        .filter(|span| !(strip_punct && span.is_punct(text)))
+        .map(|span| text[span.start..span.end].to_string())
+        .collect()
+}
+
+/// Reads a Python file-like object (text or binary mode) as UTF-8 bytes
+#[cfg(feature = "python")]
+struct PyFileReader {
+    file: Py<PyAny>,
+    /// Bytes returned by `read()` that did not fit the caller's buffer
+    pending: Vec<u8>,
+}
+
+#[cfg(feature = "python")]
+impl std::io::Read for PyFileReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.pending.is_empty() {
+            self.pending = Python::attach(|py| -> PyResult<Vec<u8>> {
+                let data = self.file.bind(py).call_method1("read", (buf.len(),))?;
+                match data.extract::<String>() {
+                    Ok(text) => Ok(text.into_bytes()),
+                    Err(_) => Ok(data.extract::<&[u8]>()?.to_vec()),
+                }
+            })
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))?;
+        }
+        let n = buf.len().min(self.pending.len());
+        buf[..n].copy_from_slice(&self.pending[..n]);
+        self.pending.drain(..n);
+        Ok(n)
     }
+}
 
-    let validator = RootValidator::new(min_root_length, strict);
-    let morphotactics = morphotactics::MorphotacticClassifier::new();
-    let mut current = word.to_string();
-    let mut best_result = word.to_string(); // Track best valid result
-    let mut stripped_suffixes: Vec<&str> = Vec::new();
+/// Convert a stream error, re-raising exceptions from the Python file object
+#[cfg(feature = "python")]
+fn stream_error(error: std::io::Error) -> PyErr {
+    if error.get_ref().is_some_and(|inner| inner.is::<PyErr>()) {
+        if let Some(Ok(err)) = error.into_inner().map(|inner| inner.downcast::<PyErr>()) {
+            return *err;
+        }
+        unreachable!("checked that the error wraps a PyErr");
+    }
+    if error.kind() == std::io::ErrorKind::InvalidData {
+        return pyo3::exceptions::PyValueError::new_err(format!(
+            "input is not valid UTF-8: {}",
+            error
+        ));
+    }
+    error.into()
+}
 
-    // Phase 1: Try compound suffixes first (longest match)
-    for suffix in suffixes::COMPOUND_SUFFIXES {
-        if current.ends_with(suffix) {
-            let candidate = &current[..current.len() - suffix.len()];
-            let is_valid_root = validator.is_valid_root(candidate);
-            let has_harmony =
-                !check_harmony || vowel_harmony::check_vowel_harmony(candidate, suffix);
-            let valid_morphotactics = morphotactics.validate_sequence(&[suffix]);
+/// Lazy tokenizer over a file path or file-like object.
+/// Reads `chunk_size` bytes at a time and tokenizes chunk by chunk, cutting
+/// on sentence boundaries, so memory use does not grow with the input.
+#[cfg(feature = "python")]
+#[pyclass(module = "durak._durak_core")]
+struct TokenStream {
+    chunks: stream::SentenceChunks<Box<dyn std::io::Read + Send + Sync>>,
+    tokens: std::collections::VecDeque<String>,
+    strip_punct: bool,
+}
 
-            if is_valid_root && has_harmony && valid_morphotactics && !candidate.is_empty() {
-                current = candidate.to_string();
-                stripped_suffixes.push(suffix);
-                best_result = current.clone();
-                break;
+#[cfg(feature = "python")]
+#[pymethods]
+impl TokenStream {
+    #[new]
+    #[pyo3(signature = (source, chunk_size=stream::DEFAULT_CHUNK_SIZE, strip_punct=false))]
+    fn new(source: &Bound<'_, PyAny>, chunk_size: usize, strip_punct: bool) -> PyResult<Self> {
+        if chunk_size == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "chunk_size must be positive",
+            ));
+        }
+        let reader: Box<dyn std::io::Read + Send + Sync> = if source.hasattr("read")? {
+            Box::new(PyFileReader {
+                file: source.clone().unbind(),
+                pending: Vec::new(),
+            })
+        } else {
+            let path: std::path::PathBuf = source.extract()?;
+            Box::new(std::fs::File::open(path)?)
+        };
+        Ok(Self {
+            chunks: stream::SentenceChunks::new(reader, chunk_size),
+            tokens: std::collections::VecDeque::new(),
+            strip_punct,
+        })
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<String>> {
+        loop {
+            if let Some(token) = self.tokens.pop_front() {
+                return Ok(Some(token));
+            }
+            let strip_punct = self.strip_punct;
+            let chunks = &mut self.chunks;
+            let tokens = py
+                .detach(|| {
+                    let chunk = chunks.next_chunk()?;
+                    Ok(chunk.map(|chunk| chunk_tokens(&chunk, strip_punct)))
+                })
+                .map_err(stream_error)?;
+            match tokens {
+                Some(tokens) => self.tokens.extend(tokens),
+                None => return Ok(None),
             }
         }
     }
+}
 
-    // Phase 2: Strip individual suffixes with validation
-    // Combine nominal and verbal suffixes, sorted by length (longest first)
-    let mut all_single_suffixes: Vec<&str> = suffixes::NOMINAL_SUFFIXES
-        .iter()
-        .chain(suffixes::VERBAL_SUFFIXES.iter())
-        .cloned()
-        .collect();
-    all_single_suffixes.sort_by(|a, b| b.len().cmp(&a.len()));
-    all_single_suffixes.dedup();
+/// Streaming reader of ndjson (JSON lines) dumps from a file path or file-like
+/// object. Yields (line_no, text) with the string at the dotted `field` path
+/// of each JSON object line ("text", "user.description"), selected without
+/// building the record; lines not starting with "{" are plain-text records.
+/// Blank lines and records without a string at `field` are skipped and
+/// counted in `skipped`. With `raw=True` the input line is yielded as well.
+#[cfg(feature = "python")]
+#[pyclass(module = "durak._durak_core")]
+struct NdjsonReader {
+    lines: ndjson::Lines<Box<dyn std::io::Read + Send + Sync>>,
+    field: String,
+    raw: bool,
+    skipped: usize,
+}
 
-    // Get dictionary reference for checking known words
-    // We check if candidates are known lemmas (root forms)
+#[cfg(feature = "python")]
+#[pymethods]
+impl NdjsonReader {
+    #[new]
+    #[pyo3(signature = (
+        source, field="text", *, raw=false, block_size=ndjson::DEFAULT_BLOCK_SIZE
+    ))]
+    fn new(
+        source: &Bound<'_, PyAny>,
+        field: &str,
+        raw: bool,
+        block_size: usize,
+    ) -> PyResult<Self> {
+        if block_size == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "block_size must be positive",
+            ));
+        }
+        if field.split('.').any(str::is_empty) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "invalid field path '{}'",
+                field
+            )));
+        }
+        let reader: Box<dyn std::io::Read + Send + Sync> = if source.hasattr("read")? {
+            Box::new(PyFileReader {
+                file: source.clone().unbind(),
+                pending: Vec::new(),
+            })
+        } else {
+            let path: std::path::PathBuf = source.extract()?;
+            Box::new(std::fs::File::open(path)?)
+        };
+        Ok(Self {
+            lines: ndjson::Lines::new(reader, block_size),
+            field: field.to_string(),
+            raw,
+            skipped: 0,
+        })
+    }
 
-    let mut changed = true;
-    let mut iterations = 0;
-    const MAX_ITERATIONS: usize = 10;
+    /// Non-blank lines skipped because they had no string at `field`
+    #[getter]
+    fn skipped(&self) -> usize {
+        self.skipped
+    }
 
-    while changed && iterations < MAX_ITERATIONS {
-        changed = false;
-        iterations += 1;
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
 
-        // Check if current is in dictionary - if so, stop stripping
-        if dictionary.contains(&current.as_str()) {
-            break;
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        let field = self.field.as_str();
+        let lines = &mut self.lines;
+        let mut skipped = 0;
+        // A borrowed field is kept as its byte range in the line so the line
+        // buffer is not borrowed across loop iterations
+        let record = py.detach(|| -> PyResult<_> {
+            while let Some((line_no, line)) = lines.next_line().map_err(stream_error)? {
+                let text = ndjson::select(line, field).map_err(|e| {
+                    pyo3::exceptions::PyValueError::new_err(format!(
+                        "line {}: invalid JSON ({})",
+                        line_no, e
+                    ))
+                })?;
+                match text {
+                    Some(std::borrow::Cow::Borrowed(text)) => {
+                        let start = text.as_ptr() as usize - line.as_ptr() as usize;
+                        return Ok(Some((line_no, Err(start..start + text.len()))));
+                    }
+                    Some(std::borrow::Cow::Owned(text)) => {
+                        return Ok(Some((line_no, Ok(text))));
+                    }
+                    None if line.iter().all(u8::is_ascii_whitespace) => {}
+                    None => skipped += 1,
+                }
+            }
+            Ok(None)
+        });
+        self.skipped += skipped;
+        let Some((line_no, text)) = record? else {
+            return Ok(None);
+        };
+        // Lines with a selected field are valid UTF-8
+        let line = std::str::from_utf8(self.lines.line()).unwrap_or_default();
+        let text = match &text {
+            Ok(text) => text.as_str(),
+            Err(range) => &line[range.clone()],
+        };
+        let record = if self.raw {
+            (line_no, text, line).into_pyobject(py)?.into_any()
+        } else {
+            (line_no, text).into_pyobject(py)?.into_any()
+        };
+        Ok(Some(record.unbind()))
+    }
+}
+
+// ============================================================================
+// SAMPLING
+// ============================================================================
+
+/// Seeded reservoir sample of `n` non-blank lines from a file path or
+/// file-like object, in input order; with `line_numbers=True` each line
+/// comes with its 1-based line number. Stops on Ctrl-C (KeyboardInterrupt) or
+/// once `cancel` is cancelled.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (source, n, seed=0, *, line_numbers=false, cancel=None))]
+fn sample_lines(
+    py: Python<'_>,
+    source: &Bound<'_, PyAny>,
+    n: usize,
+    seed: u64,
+    line_numbers: bool,
+    cancel: Option<&PyCancellationToken>,
+) -> PyResult<Py<PyAny>> {
+    let reader = source_reader(source)?;
+    let sample = run_cancellable(py, cancel, |token| {
+        let reader = std::io::BufReader::new(cancel::Reader::new(reader, token));
+        match sampling::sample_lines(reader, n, seed) {
+            Err(error) if cancel::is_cancelled_io(&error) => Err(cancel::Cancelled),
+            result => Ok(result),
         }
+    })?
+    .map_err(stream_error)?;
+    if line_numbers {
+        return Ok(sample.into_pyobject(py)?.into_any().unbind());
+    }
+    let lines: Vec<String> = sample.into_iter().map(|(_, line)| line).collect();
+    Ok(lines.into_pyobject(py)?.into_any().unbind())
+}
 
-        for suffix in &all_single_suffixes {
-            if current.ends_with(suffix) {
-                let candidate = &current[..current.len() - suffix.len()];
+/// Seeded reservoir sample of `n` sentences of `text`, in text order.
+/// Sentences are split as by `durak.split_sentences`.
+#[cfg_attr(feature = "python", pyfunction)]
+#[cfg_attr(feature = "python", pyo3(signature = (text, n, seed=0)))]
+fn sample_sentences(text: &str, n: usize, seed: u64) -> Vec<String> {
+    sampling::sample_sentences(text, n, seed)
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+}
 
-                // Skip if candidate would be too short
-                if candidate.chars().count() < min_root_length {
-                    continue;
-                }
+// ============================================================================
+// MEMORY-CAPPED CORPUS PASSES
+// ============================================================================
+// Counting and deduplication over whole files. Their tables grow with the
+// vocabulary, so `max_memory_mb` caps them and spills the overflow to
+// temporary run files (see spill.rs).
 
-                // Build hypothetical suffix sequence for morphotactic validation
-                let mut test_sequence = vec![*suffix];
-                test_sequence.extend(stripped_suffixes.iter().rev());
-                test_sequence.reverse();
+/// Word n-gram counts of the lines of a reader, one document per line,
+/// seen at least `min_count` times; most frequent first, ties in
+/// alphabetical order, and only the first `top` when given
+fn count_terms<R: std::io::BufRead>(
+    reader: R,
+    n: usize,
+    min_count: u64,
+    top: Option<usize>,
+    budget: usize,
+) -> std::io::Result<Vec<(String, u64)>> {
+    use std::cmp::Reverse;
+    let mut table = spill::Table::new(spill::Combine::Sum, budget);
+    for line in reader.lines() {
+        let lower = fast_normalize(&line?, true, true);
+        let words = word_tokens(&lower);
+        for gram in stats::ngrams(&words, n) {
+            table.add(&gram.join(" "), 1)?;
+        }
+    }
+    let counts = table
+        .finish()?
+        .filter(|entry| !matches!(entry, Ok((_, count)) if *count < min_count));
+    let Some(top) = top else {
+        let mut counted = counts.collect::<std::io::Result<Vec<_>>>()?;
+        counted.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        return Ok(counted);
+    };
+    // Only the best `top` are kept, so the result stays small as well
+    let mut best = std::collections::BinaryHeap::new();
+    for entry in counts {
+        let (term, count) = entry?;
+        best.push(Reverse((count, Reverse(term))));
+        if best.len() > top {
+            best.pop();
+        }
+    }
+    Ok(best
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((count, Reverse(term)))| (term, count))
+        .collect())
+}
 
-                // Validate all conditions
-                let is_valid_root = validator.is_valid_root(candidate);
-                let has_harmony = !check_harmony || {
-                    if suffixes::FIXED_MORPHEMES.contains(suffix) {
-                        true
-                    } else {
-                        vowel_harmony::check_vowel_harmony(candidate, suffix)
-                    }
-                };
-                let valid_morphotactics = morphotactics.validate_sequence(&test_sequence);
+/// A file path or file-like object (text or binary mode) as a byte reader
+#[cfg(feature = "python")]
+fn source_reader(source: &Bound<'_, PyAny>) -> PyResult<Box<dyn std::io::Read + Send>> {
+    if source.hasattr("read")? {
+        return Ok(Box::new(PyFileReader {
+            file: source.clone().unbind(),
+            pending: Vec::new(),
+        }));
+    }
+    let path: std::path::PathBuf = source.extract()?;
+    Ok(Box::new(std::fs::File::open(path)?))
+}
 
-                // Only strip if ALL conditions are met
-                if is_valid_root && has_harmony && valid_morphotactics {
-                    // If candidate is in dictionary, this is our answer - stop here
-                    if dictionary.contains(&candidate) {
-                        return candidate.to_string();
-                    }
+/// Word n-gram counts of a file path or file-like object, one document per
+/// line, lowercased and tokenized as for `CorpusStats`. Returns (term,
+/// count) pairs seen at least `min_count` times, most frequent first with
+/// ties in alphabetical order; `top` keeps only the first `top`. With
+/// `max_memory_mb` the counts that do not fit spill to temporary files.
+/// Stops on Ctrl-C (KeyboardInterrupt) or once `cancel` is cancelled.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (source, n=1, *, min_count=1, top=None, max_memory_mb=None, cancel=None))]
+fn term_counts(
+    py: Python<'_>,
+    source: &Bound<'_, PyAny>,
+    n: usize,
+    min_count: u64,
+    top: Option<usize>,
+    max_memory_mb: Option<usize>,
+    cancel: Option<&PyCancellationToken>,
+) -> PyResult<Vec<(String, u64)>> {
+    if n == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err("n must be positive"));
+    }
+    let reader = source_reader(source)?;
+    let budget = spill::budget(max_memory_mb);
+    run_cancellable(py, cancel, |token| {
+        let reader = std::io::BufReader::new(cancel::Reader::new(reader, token));
+        match count_terms(reader, n, min_count, top, budget) {
+            Err(error) if cancel::is_cancelled_io(&error) => Err(cancel::Cancelled),
+            result => Ok(result),
+        }
+    })?
+    .map_err(stream_error)
+}
 
-                    current = candidate.to_string();
-                    stripped_suffixes.push(suffix);
-                    best_result = current.clone();
-                    changed = true;
-                    break;
-                }
-            }
+/// Write the first occurrence of every distinct line of the file at
+/// `source` to the file at `destination`, in input order, and return the
+/// number of lines written. Reads the source twice. With `max_memory_mb`
+/// the seen lines that do not fit spill to temporary files. Stops on Ctrl-C
+/// (KeyboardInterrupt) or once `cancel` is cancelled.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (source, destination, *, max_memory_mb=None, cancel=None))]
+fn dedup_lines(
+    py: Python<'_>,
+    source: std::path::PathBuf,
+    destination: std::path::PathBuf,
+    max_memory_mb: Option<usize>,
+    cancel: Option<&PyCancellationToken>,
+) -> PyResult<usize> {
+    let same_file = std::fs::canonicalize(&destination)
+        .is_ok_and(|destination| std::fs::canonicalize(&source).is_ok_and(|s| s == destination));
+    if same_file {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "destination must differ from source",
+        ));
+    }
+    // Opened before the first pass so a missing source does not truncate it
+    std::fs::File::open(&source)?;
+    let mut writer = std::io::BufWriter::new(std::fs::File::create(&destination)?);
+    let budget = spill::budget(max_memory_mb);
+    run_cancellable(py, cancel, |token| {
+        let open = || {
+            let file = std::fs::File::open(&source)?;
+            Ok(std::io::BufReader::new(cancel::Reader::new(file, token)))
+        };
+        match spill::dedup_lines(open, &mut writer, budget) {
+            Err(error) if cancel::is_cancelled_io(&error) => Err(cancel::Cancelled),
+            result => Ok(result),
         }
+    })?
+    .map_err(stream_error)
+}
+
+// ============================================================================
+// NEAR-DUPLICATE DETECTION
+// ============================================================================
+
+/// MinHash signature of a document's lowercased word tokens
+fn dedup_signature(dedup: &dedup::Deduplicator, text: &str) -> Vec<u64> {
+    let lower = fast_normalize(text, true, true);
+    dedup.signature(&word_tokens(&lower))
+}
+
+/// Near-duplicate detection with MinHash signatures and LSH (see dedup.rs).
+/// Documents are lowercased and split into word tokens as for
+/// `CorpusStats`; a document is a near duplicate when the estimated Jaccard
+/// similarity of its word shingles to an earlier distinct document reaches
+/// `threshold`.
+#[cfg(feature = "python")]
+#[pyclass(module = "durak._durak_core", name = "Deduplicator")]
+struct PyDeduplicator {
+    dedup: dedup::Deduplicator,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyDeduplicator {
+    #[new]
+    #[pyo3(signature = (threshold=0.8, *, num_perm=128, shingle_size=3, seed=0))]
+    fn new(threshold: f64, num_perm: usize, shingle_size: usize, seed: u64) -> PyResult<Self> {
+        let dedup = dedup::Deduplicator::new(threshold, num_perm, shingle_size, seed)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        Ok(Self { dedup })
+    }
+
+    /// Add the next document; returns the id of the earlier document it
+    /// near-duplicates, or None for a distinct document. Ids count every
+    /// added document from 0.
+    fn add(&mut self, text: &str) -> Option<usize> {
+        let signature = dedup_signature(&self.dedup, text);
+        self.dedup.add(signature).map(|(id, _)| id)
+    }
+
+    /// `add` for many documents in order, computing the signatures across
+    /// threads workers (0: one per core)
+    #[pyo3(signature = (texts, threads=1))]
+    fn add_batch(
+        &mut self,
+        py: Python<'_>,
+        texts: Vec<String>,
+        threads: usize,
+    ) -> Vec<Option<usize>> {
+        let dedup = &self.dedup;
+        let signatures =
+            py.detach(|| parallel::map(&texts, threads, |text| dedup_signature(dedup, text)));
+        signatures
+            .into_iter()
+            .map(|signature| self.dedup.add(signature).map(|(id, _)| id))
+            .collect()
+    }
+
+    /// (id, estimated similarity) of the most similar distinct document at
+    /// or above the threshold, without adding the text
+    fn query(&self, text: &str) -> Option<(usize, f64)> {
+        self.dedup.query(&dedup_signature(&self.dedup, text))
+    }
+
+    /// Estimated Jaccard similarity of the word shingles of two texts
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        dedup::Deduplicator::similarity(
+            &dedup_signature(&self.dedup, a),
+            &dedup_signature(&self.dedup, b),
+        )
+    }
+
+    /// Distinct documents indexed so far
+    #[getter]
+    fn distinct(&self) -> usize {
+        self.dedup.distinct()
+    }
+
+    fn __len__(&self) -> usize {
+        self.dedup.added()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Deduplicator({} documents, {} distinct)",
+            self.dedup.added(),
+            self.dedup.distinct()
+        )
+    }
+}
+
+// ============================================================================
+// AUGMENTATION & NOISE
+// ============================================================================
+
+/// Other case forms of a case-marked noun ("evde" → ["evi", "eve", "evden", "evle"]);
+/// empty for words that are not case-marked nouns.
+#[cfg_attr(feature = "python", pyfunction)]
+fn reinflect_cases(word: &str) -> Vec<String> {
+    augment::reinflect(word)
+}
+
+/// Up to `n` distinct variants of `text`, each one case, word order or clitic
+/// edit away from it; the seed selects the same variants on every run.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "augment", signature = (text, n=1, *, operations=None, seed=0))]
+fn augment_py(
+    text: &str,
+    n: usize,
+    operations: Option<Vec<String>>,
+    seed: u64,
+) -> PyResult<Vec<String>> {
+    let operations = match operations {
+        None => augment::Operation::ALL.to_vec(),
+        Some(names) => names
+            .iter()
+            .map(|name| {
+                augment::Operation::parse(name).ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err(format!(
+                        "unknown augmentation operation '{}', expected 'case', 'order' or 'clitic'",
+                        name
+                    ))
+                })
+            })
+            .collect::<PyResult<_>>()?,
+    };
+    Ok(augment::augment(text, n, &operations, seed))
+}
+
+/// Corrupt text with realistic Turkish noise (missing diacritics, keyboard
+/// typos, reversed confusion pairs, clitic spacing errors), each word with
+/// probability `rate`; the seed makes the noise reproducible.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (text, profile="social", rate=0.1, seed=0, *, keyboard="q"))]
+fn add_noise(text: &str, profile: &str, rate: f64, seed: u64, keyboard: &str) -> PyResult<String> {
+    let profile = noise::Profile::parse(profile).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "unknown noise profile '{}', expected 'social', 'keyboard' or 'ascii'",
+            profile
+        ))
+    })?;
+    if !(0.0..=1.0).contains(&rate) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "rate must be between 0 and 1, got {}",
+            rate
+        )));
+    }
+    Ok(noise::add_noise(text, profile, rate, seed, parse_layout(keyboard)?))
+}
+
+// ============================================================================
+// KEYBOARD LAYOUTS
+// ============================================================================
+
+#[cfg(feature = "python")]
+fn parse_layout(name: &str) -> PyResult<keyboard::Layout> {
+    keyboard::Layout::parse(name).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "unknown keyboard layout '{}', expected 'q' or 'f'",
+            name
+        ))
+    })
+}
+
+/// Letter rows of the Turkish Q or F keyboard, top row first.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (layout="q"))]
+fn keyboard_layout(layout: &str) -> PyResult<Vec<String>> {
+    Ok(keyboard::rows(parse_layout(layout)?))
+}
+
+/// Letters on the keys around a letter's key (case-insensitive); empty for
+/// characters that are not on the layout.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (letter, layout="q"))]
+fn keyboard_neighbors(letter: char, layout: &str) -> PyResult<Vec<char>> {
+    Ok(keyboard::neighbours(letter, parse_layout(layout)?))
+}
+
+/// Distance between two keys in key widths, or None when either letter is
+/// not on the layout.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (a, b, layout="q"))]
+fn keyboard_distance(a: char, b: char, layout: &str) -> PyResult<Option<f64>> {
+    Ok(keyboard::distance(a, b, parse_layout(layout)?))
+}
+
+/// Levenshtein distance where substituting a neighbouring key costs 0.5.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (a, b, layout="q"))]
+fn keyboard_edit_distance(a: &str, b: &str, layout: &str) -> PyResult<f64> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let cost = keyboard::edit_cost(&a, &b, parse_layout(layout)?);
+    Ok(cost as f64 / keyboard::EDIT as f64)
+}
+
+/// Every (letter, neighbouring letter) pair of a layout: the likely
+/// substitutions of a keyboard confusion matrix.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (layout="q"))]
+fn keyboard_confusion_pairs(layout: &str) -> PyResult<Vec<(char, char)>> {
+    Ok(keyboard::confusion_pairs(parse_layout(layout)?))
+}
+
+// ============================================================================
+// FUZZY MATCHING
+// ============================================================================
+
+#[cfg(feature = "python")]
+fn parse_metric(name: &str) -> PyResult<similarity::Metric> {
+    similarity::Metric::parse(name).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "unknown metric '{}', expected 'levenshtein', 'jaro' or 'jaro_winkler'",
+            name
+        ))
+    })
+}
+
+/// Similarity of two strings in [0, 1] where a Turkish letter and its ASCII
+/// counterpart (ı/i, ş/s, ç/c, ğ/g, ö/o, ü/u) are a cheap substitution.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "similarity", signature = (a, b, metric="jaro_winkler", *, ignore_case=true))]
+fn similarity_py(a: &str, b: &str, metric: &str, ignore_case: bool) -> PyResult<f64> {
+    Ok(similarity::similarity(a, b, parse_metric(metric)?, ignore_case))
+}
+
+/// The `k` candidates most similar to `query` as (candidate, score), best
+/// first, skipping those scoring below `min_score`.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (
+    query, candidates, k=5, metric="jaro_winkler", *, ignore_case=true, min_score=0.0
+))]
+fn closest_matches(
+    query: &str,
+    candidates: Vec<String>,
+    k: usize,
+    metric: &str,
+    ignore_case: bool,
+    min_score: f64,
+) -> PyResult<Vec<(String, f64)>> {
+    let best = similarity::closest(query, &candidates, k, parse_metric(metric)?, ignore_case);
+    Ok(best
+        .into_iter()
+        .filter(|&(_, score)| score >= min_score)
+        .map(|(i, score)| (candidates[i].clone(), score))
+        .collect())
+}
+
+// ============================================================================
+// SUBWORD VOCABULARIES
+// ============================================================================
+
+#[cfg(feature = "python")]
+fn parse_fallback(name: &str) -> PyResult<subword::Fallback> {
+    subword::Fallback::parse(name).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "unknown fallback '{}', expected 'char' or 'byte'",
+            name
+        ))
+    })
+}
+
+/// Split a token into `▁`-marked root and suffix pieces; tokens the analyzer
+/// cannot segment are split into characters or `<0xNN>` byte pieces.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (token, fallback="char"))]
+fn segment_word(token: &str, fallback: &str) -> PyResult<Vec<String>> {
+    Ok(subword::segment_word(token, parse_fallback(fallback)?).0)
+}
+
+/// Normalize and tokenize text and segment every token as `segment_word`
+/// does, giving a segmentation that covers the whole text
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (text, fallback="char"))]
+fn segment_morphemes(text: &str, fallback: &str) -> PyResult<Vec<String>> {
+    Ok(subword::segment_text(text, parse_fallback(fallback)?))
+}
+
+/// Unigram subword model in SentencePiece format (see `unigram`)
+#[cfg(feature = "python")]
+#[pyclass(module = "durak._durak_core", name = "UnigramModel")]
+struct PyUnigramModel {
+    model: unigram::UnigramModel,
+}
+
+#[cfg(feature = "python")]
+fn model_error(error: unigram::ModelError) -> PyErr {
+    pyo3::exceptions::PyValueError::new_err(error.to_string())
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyUnigramModel {
+    /// Load a `.model` file written by `save` or by SentencePiece
+    #[staticmethod]
+    fn load(path: std::path::PathBuf) -> PyResult<Self> {
+        let data = std::fs::read(path)?;
+        Self::from_bytes(&data)
+    }
+
+    #[staticmethod]
+    fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        let model = unigram::UnigramModel::from_proto(data).map_err(model_error)?;
+        Ok(Self { model })
+    }
+
+    fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, pyo3::types::PyBytes> {
+        pyo3::types::PyBytes::new(py, &self.model.to_proto())
+    }
+
+    /// Write `<prefix>.model` and `<prefix>.vocab`, like `spm_train`
+    fn save(&self, prefix: &str) -> PyResult<()> {
+        std::fs::write(format!("{}.model", prefix), self.model.to_proto())?;
+        std::fs::write(format!("{}.vocab", prefix), self.model.to_vocab_tsv())?;
+        Ok(())
+    }
+
+    /// Normalize and segment text into pieces
+    fn encode(&self, text: &str) -> Vec<String> {
+        self.model.encode(text).into_iter().map(|(_, piece)| piece).collect()
+    }
+
+    fn encode_ids(&self, text: &str) -> Vec<u32> {
+        self.model.encode(text).into_iter().map(|(id, _)| id).collect()
+    }
+
+    fn decode(&self, pieces: Vec<String>) -> String {
+        self.model.decode(&pieces)
+    }
+
+    fn decode_ids(&self, ids: Vec<u32>) -> String {
+        self.model.decode_ids(&ids)
+    }
+
+    /// Id of a piece; unknown pieces map to the `<unk>` id
+    fn piece_to_id(&self, piece: &str) -> u32 {
+        self.model.piece_to_id(piece)
+    }
+
+    fn id_to_piece(&self, id: u32) -> PyResult<String> {
+        self.model.id_to_piece(id).map(str::to_string).ok_or_else(|| {
+            pyo3::exceptions::PyIndexError::new_err(format!("piece id {} out of range", id))
+        })
+    }
+
+    /// `(piece, score)` pairs in id order
+    #[getter]
+    fn pieces(&self) -> Vec<(String, f32)> {
+        self.model
+            .pieces()
+            .iter()
+            .map(|p| (p.piece.clone(), p.score))
+            .collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.model.pieces().len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("UnigramModel(vocab_size={})", self.model.pieces().len())
+    }
+}
+
+/// Train a unigram subword model on an iterable of texts (an open file
+/// works: it yields lines). Texts are normalized with `fast_normalize`;
+/// with `seed_morphemes` the analyzer's root/suffix segmentation guides the
+/// vocabulary towards morpheme boundaries, and with `byte_fallback` unknown
+/// characters are encoded as byte pieces instead of `<unk>`.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (
+    texts,
+    vocab_size=8000,
+    *,
+    max_piece_length=16,
+    character_coverage=0.9995,
+    seed_morphemes=true,
+    byte_fallback=false,
+    threads=1
+))]
+fn train_unigram(
+    texts: &Bound<'_, PyAny>,
+    vocab_size: usize,
+    max_piece_length: usize,
+    character_coverage: f64,
+    seed_morphemes: bool,
+    byte_fallback: bool,
+    threads: usize,
+) -> PyResult<PyUnigramModel> {
+    if max_piece_length == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "max_piece_length must be positive",
+        ));
+    }
+    if !(character_coverage > 0.0 && character_coverage <= 1.0) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "character_coverage must be in (0, 1]",
+        ));
+    }
+    let mut counts = HashMap::new();
+    for text in texts.try_iter()? {
+        unigram::count_words(&mut counts, &text?.extract::<String>()?);
+    }
+    let config = unigram::TrainerConfig {
+        vocab_size,
+        max_piece_length,
+        character_coverage,
+        seed_morphemes,
+        byte_fallback,
+        threads,
+        ..unigram::TrainerConfig::default()
+    };
+    let model = texts
+        .py()
+        .detach(|| unigram::train(&counts, &config))
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    Ok(PyUnigramModel { model })
+}
+
+/// Byte-pair encoding model with `vocab.json`/`merges.txt` files (see `bpe`)
+#[cfg(feature = "python")]
+#[pyclass(module = "durak._durak_core", name = "BpeModel")]
+struct PyBpeModel {
+    model: bpe::BpeModel,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyBpeModel {
+    /// Load `<prefix>-vocab.json` and `<prefix>-merges.txt`, as written by
+    /// `save` or by `tokenizers`
+    #[staticmethod]
+    fn load(prefix: &str) -> PyResult<Self> {
+        let vocab = std::fs::read_to_string(format!("{}-vocab.json", prefix))?;
+        let merges = std::fs::read_to_string(format!("{}-merges.txt", prefix))?;
+        let model = bpe::BpeModel::from_files(&vocab, &merges).map_err(model_error)?;
+        Ok(Self { model })
+    }
+
+    /// Write `<prefix>-vocab.json` and `<prefix>-merges.txt`, like
+    /// `tokenizers.models.BPE.save`
+    fn save(&self, prefix: &str) -> PyResult<()> {
+        std::fs::write(format!("{}-vocab.json", prefix), self.model.to_vocab_json())?;
+        std::fs::write(format!("{}-merges.txt", prefix), self.model.to_merges_txt())?;
+        Ok(())
+    }
+
+    /// Normalize and segment text into pieces
+    fn encode(&self, text: &str) -> Vec<String> {
+        self.model.encode(text).into_iter().map(|(_, piece)| piece).collect()
+    }
+
+    fn encode_ids(&self, text: &str) -> Vec<u32> {
+        self.model.encode(text).into_iter().map(|(id, _)| id).collect()
     }
 
-    // Final check: if current is in dictionary, prefer it
-    if dictionary.contains(&current.as_str()) {
-        return current;
+    fn decode(&self, pieces: Vec<String>) -> String {
+        self.model.decode(&pieces)
     }
 
-    // Otherwise return the best valid result found
-    if validator.is_valid_root(&current) {
-        current
-    } else {
-        best_result
+    fn decode_ids(&self, ids: Vec<u32>) -> String {
+        self.model.decode_ids(&ids)
     }
-}
 
-/// Get embedded detached suffixes list
-/// Returns suffixes compiled into the binary from resources/tr/labels/DETACHED_SUFFIXES.txt
-#[pyfunction]
-fn get_detached_suffixes() -> Vec<String> {
-    let suffixes = DETACHED_SUFFIXES.get_or_init(|| {
-        DETACHED_SUFFIXES_DATA
-            .lines()
-            .map(|line| line.trim())
-            .filter(|line| !line.is_empty())
-            .collect()
-    });
-    suffixes.iter().map(|s| s.to_string()).collect()
-}
+    /// Id of a piece; unknown pieces map to the `<unk>` id
+    fn piece_to_id(&self, piece: &str) -> u32 {
+        self.model.piece_to_id(piece)
+    }
 
-/// Get embedded Turkish stopwords list
-/// Returns base Turkish stopwords compiled into the binary from resources/tr/stopwords/base/turkish.txt
-#[pyfunction]
-fn get_stopwords_base() -> Vec<String> {
-    let stopwords = STOPWORDS_BASE.get_or_init(|| {
-        STOPWORDS_TR_DATA
-            .lines()
-            .map(|line| line.trim())
-            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+    fn id_to_piece(&self, id: u32) -> PyResult<String> {
+        self.model.id_to_piece(id).map(str::to_string).ok_or_else(|| {
+            pyo3::exceptions::PyIndexError::new_err(format!("piece id {} out of range", id))
+        })
+    }
+
+    /// Pieces in id order
+    #[getter]
+    fn pieces(&self) -> Vec<String> {
+        self.model.pieces().to_vec()
+    }
+
+    /// Merged `(left, right)` pairs by rank
+    #[getter]
+    fn merges(&self) -> Vec<(String, String)> {
+        self.model
+            .merges()
+            .into_iter()
+            .map(|(left, right)| (left.to_string(), right.to_string()))
             .collect()
-    });
-    stopwords.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.model.pieces().len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("BpeModel(vocab_size={})", self.model.pieces().len())
+    }
 }
 
-/// Get embedded stopwords metadata JSON
-/// Returns metadata compiled into the binary from resources/tr/stopwords/metadata.json
+/// Train a byte-pair encoding model on an iterable of texts. Texts are
+/// normalized with `fast_normalize`; with `morpheme_boundaries` pair
+/// occurrences crossing the analyzer's root/suffix boundaries count only
+/// `1 - boundary_penalty` times, so merges follow morphemes.
+#[cfg(feature = "python")]
 #[pyfunction]
-fn get_stopwords_metadata() -> String {
-    STOPWORDS_METADATA_DATA.to_string()
+#[pyo3(signature = (
+    texts,
+    vocab_size=8000,
+    *,
+    min_frequency=2,
+    morpheme_boundaries=true,
+    boundary_penalty=0.9,
+    threads=1
+))]
+fn train_bpe(
+    texts: &Bound<'_, PyAny>,
+    vocab_size: usize,
+    min_frequency: u64,
+    morpheme_boundaries: bool,
+    boundary_penalty: f64,
+    threads: usize,
+) -> PyResult<PyBpeModel> {
+    if !(0.0..=1.0).contains(&boundary_penalty) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "boundary_penalty must be in [0, 1]",
+        ));
+    }
+    let mut counts = HashMap::new();
+    for text in texts.try_iter()? {
+        unigram::count_words(&mut counts, &text?.extract::<String>()?);
+    }
+    let config = bpe::TrainerConfig {
+        vocab_size,
+        min_frequency,
+        morpheme_boundaries,
+        boundary_penalty,
+        threads,
+    };
+    let model = texts
+        .py()
+        .detach(|| bpe::train(&counts, &config))
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    Ok(PyBpeModel { model })
 }
 
-/// Get embedded social media stopwords
-/// Returns social media stopwords compiled into the binary from resources/tr/stopwords/domains/social_media.txt
-#[pyfunction]
-fn get_stopwords_social_media() -> Vec<String> {
-    STOPWORDS_SOCIAL_MEDIA_DATA
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty() && !line.starts_with('#'))
-        .map(|s| s.to_string())
+// ============================================================================
+// WARM-UP
+// ============================================================================
+// Dictionaries, gazetteers and regexes are built lazily on first use. Services
+// call `warm_up` at start-up so that cost does not land on the first request.
+
+/// Lazily initialized components and a call that forces each one
+const WARM_UP_COMPONENTS: &[(&str, fn())] = &[
+    ("lemma_dict", || {
+        lemma_dict::lookup("kitaplar");
+        lemma_dict::is_lemma("kitap");
+    }),
+    ("tokenizer", token_regex::warm_up),
+    ("detached_suffixes", || {
+        get_detached_suffixes();
+    }),
+    ("stopwords", || {
+        get_stopwords_base();
+        segmentation::is_function_word("ve");
+    }),
+    ("vowel_drop", || {
+        vowel_drop::is_listed_root("burun");
+    }),
+    ("confusion_pairs", || {
+        confusion::lookup("herşey");
+    }),
+    ("keyboard_layouts", || {
+        keyboard::neighbours('g', keyboard::Layout::Q);
+    }),
+    ("language_profiles", || {
+        language::detect("merhaba");
+    }),
+    ("grammar_rules", || {
+        check_grammar("ben de");
+    }),
+    ("segmentation", || {
+        segmentation::split_joined("çokgüzel");
+    }),
+    ("analyzer", || {
+        analyzer::analyze("kitaplarımdan");
+    }),
+    ("ner", || {
+        ner::extract_entities("Ahmet 1 Ocak 2024'te 5 TL ödedi: www.example.com");
+    }),
+];
+
+/// Spell checking builds a BK-tree over generated inflections, by far the
+/// slowest component, so it is only warmed up on request
+const WARM_UP_SPELL: (&str, fn()) = ("spell", spell::build_lexicon);
+
+/// Initialize every lazily built component, returning seconds spent per
+/// component (near zero for components that were already initialized)
+fn run_warm_up(include_spell: bool) -> HashMap<String, f64> {
+    let spell = include_spell.then_some(&WARM_UP_SPELL);
+    WARM_UP_COMPONENTS
+        .iter()
+        .chain(spell)
+        .map(|(name, init)| {
+            let start = std::time::Instant::now();
+            init();
+            (name.to_string(), start.elapsed().as_secs_f64())
+        })
         .collect()
 }
 
-/// Check if a suffix harmonizes with a root (Python binding)
-///
-/// # Arguments
-/// * `root` - The root word
-/// * `suffix` - The suffix to check
+/// Force initialization of the embedded dictionaries, gazetteers and regexes.
+/// Runs without the GIL.
 ///
-/// # Returns
-/// True if the suffix harmonizes with the root, False otherwise
+/// # Example
+/// ```python
+/// from durak import warm_up
+/// timings = warm_up()
+/// print(sorted(timings))  # ['analyzer', 'confusion_pairs', ...]
+/// ```
+#[cfg(feature = "python")]
 #[pyfunction]
-fn check_vowel_harmony_py(root: &str, suffix: &str) -> bool {
-    vowel_harmony::check_vowel_harmony(root, suffix)
+#[pyo3(signature = (include_spell=false))]
+fn warm_up(py: Python<'_>, include_spell: bool) -> HashMap<String, f64> {
+    py.detach(|| run_warm_up(include_spell))
 }
 
 // ============================================================================
@@ -551,7 +4795,7 @@ struct ResourceMetadata {
 /// info = get_build_info()
 /// print(info['durak_version'])  # '0.4.0'
 /// ```
-#[pyfunction]
+#[cfg_attr(feature = "python", pyfunction)]
 fn get_build_info() -> HashMap<String, String> {
     let mut info = HashMap::new();
     info.insert("durak_version".to_string(), env!("CARGO_PKG_VERSION").to_string());
@@ -566,6 +4810,10 @@ fn get_build_info() -> HashMap<String, String> {
     // Rust version - use option_env! with fallback for robustness
     let rust_version = option_env!("CARGO_PKG_RUST_VERSION").unwrap_or("1.70");
     info.insert("rust_version".to_string(), rust_version.to_string());
+
+    // Lemma dictionary pruning applied at build time (see build.rs)
+    info.insert("lemma_dict_top_n".to_string(), env!("DURAK_LEMMA_TOP_N").to_string());
+    info.insert("lemma_dict_forms".to_string(), env!("DURAK_LEMMA_FORMS").to_string());
     
     info
 }
@@ -580,6 +4828,7 @@ fn get_build_info() -> HashMap<String, String> {
 /// print(resources['stopwords_base']['checksum'])  # 'a3f5b8c9d2e1f4a7...'
 /// print(resources['stopwords_base']['item_count'])  # 442
 /// ```
+#[cfg(feature = "python")]
 #[pyfunction]
 fn get_resource_info(py: Python) -> PyResult<HashMap<String, Py<pyo3::types::PyAny>>> {
     let metadata: ResourceMetadata = serde_json::from_str(RESOURCE_METADATA)
@@ -602,26 +4851,152 @@ fn get_resource_info(py: Python) -> PyResult<HashMap<String, Py<pyo3::types::PyA
 
 /// The internal Rust part of the Durak library.
 /// High-performance Turkish NLP operations with embedded resources.
+#[cfg(feature = "python")]
 #[pymodule]
 fn _durak_core(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Core text processing functions
-    m.add_function(wrap_pyfunction!(fast_normalize, m)?)?;
-    m.add_function(wrap_pyfunction!(tokenize_with_offsets, m)?)?;
+    m.add_function(wrap_pyfunction!(fast_normalize_py, m)?)?;
+    m.add_function(wrap_pyfunction!(unicode_normalize, m)?)?;
+    m.add_function(wrap_pyfunction!(fast_uppercase, m)?)?;
+    m.add_function(wrap_pyfunction!(turkish_casefold, m)?)?;
+    m.add_function(wrap_pyfunction!(correct_confusions, m)?)?;
+    m.add_function(wrap_pyfunction!(check_grammar, m)?)?;
+    m.add_function(wrap_pyfunction!(fix_pdf_artifacts, m)?)?;
+    m.add_function(wrap_pyfunction!(rtl_spans, m)?)?;
+    m.add_function(wrap_pyfunction!(isolate_rtl, m)?)?;
+    m.add_function(wrap_pyfunction!(split_joined, m)?)?;
+    m.add_function(wrap_pyfunction!(split_hashtag, m)?)?;
+    m.add_function(wrap_pyfunction!(tokenize_with_offsets_py, m)?)?;
+    m.add_function(wrap_pyfunction!(tokenize_with_types_py, m)?)?;
+    m.add_function(wrap_pyfunction!(tokenize_columns, m)?)?;
+    m.add_function(wrap_pyfunction!(tokenize_with_whitespace, m)?)?;
+    m.add_function(wrap_pyfunction!(detokenize, m)?)?;
+    m.add_function(wrap_pyfunction!(tokenize_for_spacy, m)?)?;
+    m.add_function(wrap_pyfunction!(pre_tokenize, m)?)?;
     m.add_function(wrap_pyfunction!(tokenize_with_normalized_offsets, m)?)?;
+    m.add_function(wrap_pyfunction!(normalize_with_alignment, m)?)?;
+    m.add_class::<PyToken>()?;
+    m.add_class::<PyDoc>()?;
+    m.add_class::<PyRewriteRules>()?;
+    m.add_class::<PyKeywordMatcher>()?;
+    m.add_class::<PyPhraseMatcher>()?;
+    m.add_function(wrap_pyfunction!(process, m)?)?;
+    m.add_function(wrap_pyfunction!(to_conllu, m)?)?;
+    m.add_function(wrap_pyfunction!(read_conllu, m)?)?;
+    m.add_function(wrap_pyfunction!(reannotate_conllu, m)?)?;
+
+    // Named entity recognition
+    m.add_function(wrap_pyfunction!(extract_entities, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_dates, m)?)?;
+    m.add_function(wrap_pyfunction!(link_pronouns, m)?)?;
+    m.add_function(wrap_pyfunction!(detect_dialect, m)?)?;
+    m.add_function(wrap_pyfunction!(dialect_flags, m)?)?;
+    m.add_function(wrap_pyfunction!(word_origin, m)?)?;
+    m.add_function(wrap_pyfunction!(etymology_tags, m)?)?;
+    m.add_function(wrap_pyfunction!(takes_front_harmony, m)?)?;
+    m.add_function(wrap_pyfunction!(detect_language, m)?)?;
+    m.add_function(wrap_pyfunction!(is_turkish, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_numbers, m)?)?;
+    m.add_function(wrap_pyfunction!(number_to_words, m)?)?;
+    m.add_function(wrap_pyfunction!(words_to_number, m)?)?;
 
     // Lemmatization functions
     m.add_function(wrap_pyfunction!(lookup_lemma, m)?)?;
+    m.add_function(wrap_pyfunction!(lookup_lemma_prefix, m)?)?;
     m.add_function(wrap_pyfunction!(strip_suffixes, m)?)?;
-    m.add_function(wrap_pyfunction!(strip_suffixes_validated, m)?)?;
+    m.add_function(wrap_pyfunction!(strip_suffixes_validated_py, m)?)?;
+    m.add_function(wrap_pyfunction!(stem_py, m)?)?;
 
     // Vowel harmony checker
     m.add_function(wrap_pyfunction!(check_vowel_harmony_py, m)?)?;
 
+    // Morphological analysis & statistics
+    m.add_function(wrap_pyfunction!(analyze, m)?)?;
+    m.add_function(wrap_pyfunction!(analyze_all, m)?)?;
+    m.add_function(wrap_pyfunction!(check_agreement, m)?)?;
+    m.add_function(wrap_pyfunction!(generate, m)?)?;
+    m.add_function(wrap_pyfunction!(morphological_complexity, m)?)?;
+    m.add_function(wrap_pyfunction!(formality_score, m)?)?;
+    m.add_function(wrap_pyfunction!(text_stats, m)?)?;
+    m.add_function(wrap_pyfunction!(syllabify, m)?)?;
+    m.add_function(wrap_pyfunction!(line_anomalies, m)?)?;
+    m.add_function(wrap_pyfunction!(ngrams, m)?)?;
+    m.add_function(wrap_pyfunction!(char_ngrams, m)?)?;
+    m.add_class::<PyCorpusStats>()?;
+    m.add_function(wrap_pyfunction!(pos_tag, m)?)?;
+
+    // Spell checking
+    m.add_function(wrap_pyfunction!(is_correct, m)?)?;
+    m.add_function(wrap_pyfunction!(suggest, m)?)?;
+    m.add_function(wrap_pyfunction!(ranked_suggestions, m)?)?;
+
+    // Streaming tokenization
+    m.add_class::<PyRegexTokenizer>()?;
+    m.add_class::<TokenStream>()?;
+    m.add_class::<NdjsonReader>()?;
+
+    // Batch processing (dataframe integrations)
+    m.add_function(wrap_pyfunction!(lemmatize_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(lemma_candidates_py, m)?)?;
+    m.add_function(wrap_pyfunction!(lemma_cache_info, m)?)?;
+    m.add_function(wrap_pyfunction!(set_lemma_cache_size, m)?)?;
+    m.add_function(wrap_pyfunction!(clear_lemma_cache, m)?)?;
+    m.add_function(wrap_pyfunction!(normalize_batch_py, m)?)?;
+    m.add_function(wrap_pyfunction!(tokenize_batch_py, m)?)?;
+    m.add_class::<PyBatchResult>()?;
+    m.add_class::<PyCancellationToken>()?;
+    m.add_class::<PyArrowArray>()?;
+    m.add_function(wrap_pyfunction!(tokenize_arrow, m)?)?;
+    m.add_function(wrap_pyfunction!(normalize_arrow, m)?)?;
+    m.add_function(wrap_pyfunction!(lemmatize_arrow, m)?)?;
+    m.add_function(wrap_pyfunction!(process_series, m)?)?;
+
     // Embedded resource accessors
     m.add_function(wrap_pyfunction!(get_detached_suffixes, m)?)?;
+    m.add_function(wrap_pyfunction!(split_clitics, m)?)?;
     m.add_function(wrap_pyfunction!(get_stopwords_base, m)?)?;
+    m.add_function(wrap_pyfunction!(set_resource_dirs, m)?)?;
+    m.add_function(wrap_pyfunction!(resource_overrides, m)?)?;
+    m.add_function(wrap_pyfunction!(get_config, m)?)?;
+    m.add_function(wrap_pyfunction!(set_config, m)?)?;
     m.add_function(wrap_pyfunction!(get_stopwords_metadata, m)?)?;
     m.add_function(wrap_pyfunction!(get_stopwords_social_media, m)?)?;
+    m.add_function(wrap_pyfunction!(get_confusion_pairs, m)?)?;
+
+    // Sampling
+    m.add_function(wrap_pyfunction!(sample_lines, m)?)?;
+    m.add_function(wrap_pyfunction!(term_counts, m)?)?;
+    m.add_function(wrap_pyfunction!(discover_stopwords, m)?)?;
+    m.add_function(wrap_pyfunction!(dedup_lines, m)?)?;
+    m.add_class::<PyDeduplicator>()?;
+    m.add_function(wrap_pyfunction!(sample_sentences, m)?)?;
+
+    // Augmentation and noise
+    m.add_function(wrap_pyfunction!(reinflect_cases, m)?)?;
+    m.add_function(wrap_pyfunction!(augment_py, m)?)?;
+    m.add_function(wrap_pyfunction!(add_noise, m)?)?;
+
+    // Keyboard layouts
+    m.add_function(wrap_pyfunction!(keyboard_layout, m)?)?;
+    m.add_function(wrap_pyfunction!(keyboard_neighbors, m)?)?;
+    m.add_function(wrap_pyfunction!(keyboard_distance, m)?)?;
+    m.add_function(wrap_pyfunction!(keyboard_edit_distance, m)?)?;
+    m.add_function(wrap_pyfunction!(keyboard_confusion_pairs, m)?)?;
+
+    // Fuzzy matching
+    m.add_function(wrap_pyfunction!(similarity_py, m)?)?;
+    m.add_function(wrap_pyfunction!(closest_matches, m)?)?;
+
+    // Subword vocabularies
+    m.add_class::<PyUnigramModel>()?;
+    m.add_function(wrap_pyfunction!(train_unigram, m)?)?;
+    m.add_class::<PyBpeModel>()?;
+    m.add_function(wrap_pyfunction!(train_bpe, m)?)?;
+    m.add_function(wrap_pyfunction!(segment_word, m)?)?;
+    m.add_function(wrap_pyfunction!(segment_morphemes, m)?)?;
+
+    // Warm-up
+    m.add_function(wrap_pyfunction!(warm_up, m)?)?;
 
     // Reproducibility & versioning API
     m.add_function(wrap_pyfunction!(get_build_info, m)?)?;
@@ -638,21 +5013,98 @@ fn _durak_core(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fast_uppercase_turkish_i() {
+        assert_eq!(fast_uppercase("istanbul"), "İSTANBUL");
+        assert_eq!(fast_uppercase("ışık"), "IŞIK");
+        assert_eq!(fast_uppercase("çiğdem öğün"), "ÇİĞDEM ÖĞÜN");
+        assert_eq!(fast_uppercase("straße"), "STRASSE");
+    }
+
+    #[test]
+    fn test_turkish_casefold_modes() {
+        assert_eq!(turkish_casefold_with("İSTANBUL", CaseMode::Lower), "istanbul");
+        assert_eq!(turkish_casefold_with("ışık", CaseMode::Upper), "IŞIK");
+        assert_eq!(turkish_casefold_with("Straße", CaseMode::Fold), "strasse");
+        assert_eq!(CaseMode::parse("title"), None);
+    }
+
+    #[test]
+    fn test_turkish_casefold_round_trip() {
+        for word in ["IŞIK", "İğne", "kırmızı", "Iğdır"] {
+            let folded = turkish_casefold_with(word, CaseMode::Fold);
+            assert_eq!(turkish_casefold_with(&fast_uppercase(word), CaseMode::Fold), folded);
+        }
+        // Dotted and dotless i are different letters, not case variants
+        assert_ne!(
+            turkish_casefold_with("ISIK", CaseMode::Fold),
+            turkish_casefold_with("isik", CaseMode::Fold)
+        );
+    }
+
+    #[test]
+    fn test_unicode_form_parse() {
+        assert_eq!(UnicodeForm::parse("nfc"), Some(UnicodeForm::Nfc));
+        assert_eq!(UnicodeForm::parse("NFKD"), Some(UnicodeForm::Nfkd));
+        assert_eq!(UnicodeForm::parse("NFX"), None);
+    }
+
+    #[test]
+    fn test_normalize_with_form_composes_decomposed_input() {
+        // "g" + combining breve, "I" + combining dot above
+        assert_eq!(
+            normalize_with_form("Dag\u{306}", true, true, UnicodeForm::Nfc),
+            "dağ"
+        );
+        assert_eq!(
+            normalize_with_form("I\u{307}STANBUL", true, true, UnicodeForm::Nfc),
+            "istanbul"
+        );
+        // Compatibility forms fold ligatures and full-width letters
+        assert_eq!(
+            normalize_with_form("ﬁLM", true, true, UnicodeForm::Nfkc),
+            "film"
+        );
+    }
+
+    #[test]
+    fn test_normalize_with_form_decomposes_after_casing() {
+        let result = normalize_with_form("DAĞ", true, true, UnicodeForm::Nfd);
+        assert_eq!(result, "dag\u{306}");
+        assert_eq!(UnicodeForm::Nfc.apply(&result), "dağ");
+    }
+
+    #[test]
+    fn test_normalize_aligned() {
+        let (text, alignment) = normalize_aligned("İSTANBUL'a", true, true, None);
+        assert_eq!(text, "istanbul'a");
+        assert_eq!(alignment, (0..=10).collect::<Vec<_>>());
+        // "g" + U+0306 composes into one character aligned to the "g"
+        let (text, alignment) =
+            normalize_aligned("Dag\u{306} ﬁLM", true, true, Some(UnicodeForm::Nfkc));
+        assert_eq!(text, "dağ film");
+        assert_eq!(alignment, [0, 1, 2, 4, 5, 5, 6, 7, 8]);
+        let (text, alignment) = normalize_aligned("DAĞ", true, true, Some(UnicodeForm::Nfd));
+        assert_eq!(text, "dag\u{306}");
+        assert_eq!(alignment, [0, 1, 2, 2, 3]);
+        assert_eq!(normalize_aligned("", true, true, None), (String::new(), vec![0]));
+    }
+
     #[test]
     fn test_lemma_dict_loading() {
-        let dict = get_lemma_dict();
+        let count = lemma_dict::prefix_search("", None).len();
 
         // Verify dictionary is not empty
-        assert!(!dict.is_empty(), "Lemma dictionary should not be empty");
+        assert!(count > 0, "Lemma dictionary should not be empty");
 
         // Verify we have more than mock data (original had 3 entries)
         assert!(
-            dict.len() > 100,
+            count > 100,
             "Dictionary should contain more than 100 entries, got {}",
-            dict.len()
+            count
         );
 
-        println!("✓ Loaded {} lemma entries", dict.len());
+        println!("✓ Loaded {} lemma entries", count);
     }
 
     #[test]
@@ -738,10 +5190,8 @@ mod tests {
 
     #[test]
     fn test_lemma_dict_format_validation() {
-        let dict = get_lemma_dict();
-
         // Check a few entries to ensure proper format
-        for (inflected, lemma) in dict.iter().take(10) {
+        for (inflected, lemma) in lemma_dict::prefix_search("", Some(10)) {
             assert!(!inflected.is_empty(), "Inflected form should not be empty");
             assert!(!lemma.is_empty(), "Lemma should not be empty");
             assert!(
@@ -773,6 +5223,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_strip_suffixes_stops_at_known_root() {
+        // "insan" ends in the suffix "an", which would be stripped to "ins"
+        assert_eq!(strip_suffixes("insanlar"), "insan");
+        assert_eq!(strip_suffixes("insan"), "insan");
+    }
+
     #[test]
     fn test_strip_suffixes_validated_lenient() {
         // Test validated stripping with lenient mode (phonotactic rules only)
@@ -783,7 +5240,7 @@ mod tests {
         ];
 
         for (word, expected) in test_cases {
-            let result = strip_suffixes_validated(word, false, 2, true);
+            let result = strip_suffixes_validated(word, false, 2, true, true);
             assert_eq!(
                 result, expected,
                 "strip_suffixes_validated({}, lenient) should be '{}'",
@@ -803,7 +5260,7 @@ mod tests {
         ];
 
         for (word, expected) in test_cases {
-            let result = strip_suffixes_validated(word, true, 2, true);
+            let result = strip_suffixes_validated(word, true, 2, true, true);
             assert_eq!(
                 result, expected,
                 "strip_suffixes_validated({}, strict) should be '{}'",
@@ -812,6 +5269,418 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_strip_suffixes_validated_consonant_mutation() {
+        // Softened final consonants are restored after stripping (kitab-ı)
+        let test_cases = vec![
+            ("kitabı", "kitap"),
+            ("ağacı", "ağaç"),
+            ("çocuğu", "çocuk"),
+            ("sokağı", "sokak"),
+            ("mektubu", "mektup"),
+            // Monosyllabic roots keep their voiced consonant
+            ("dağı", "dağ"),
+            ("adı", "ad"),
+        ];
+
+        for (word, expected) in test_cases {
+            let result = strip_suffixes_validated(word, false, 2, true, true);
+            assert_eq!(
+                result, expected,
+                "strip_suffixes_validated({}, lenient) should be '{}'",
+                word, expected
+            );
+        }
+        assert_eq!(strip_suffixes_validated("kitabı", true, 2, true, true), "kitap");
+    }
+
+    #[test]
+    fn test_strip_suffixes_validated_vowel_drop() {
+        // Roots that lose their last vowel before vowel-initial suffixes
+        let test_cases = vec![
+            ("burnu", "burun"),
+            ("oğlum", "oğul"),
+            ("ağzımız", "ağız"),
+            ("şehri", "şehir"),
+            ("vakti", "vakit"),
+            ("kaybı", "kayıp"),
+            // Consonant-initial suffixes keep the full root
+            ("burunda", "burun"),
+        ];
+
+        for (word, expected) in test_cases {
+            let result = strip_suffixes_validated(word, false, 2, true, true);
+            assert_eq!(
+                result, expected,
+                "strip_suffixes_validated({}, lenient) should be '{}'",
+                word, expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_lemmatize_word_strategies() {
+        assert_eq!(Strategy::parse("hybrid"), Some(Strategy::Hybrid));
+        assert_eq!(Strategy::parse("fuzzy"), None);
+
+        // Dictionary hit
+        assert_eq!(lemmatize_word("kitaplar", Strategy::Lookup, false, false, 2), "kitap");
+        // OOV words are returned unchanged by lookup, stripped otherwise
+        assert_eq!(lemmatize_word("kalemler", Strategy::Lookup, false, false, 2), "kalemler");
+        assert_eq!(lemmatize_word("kalemler", Strategy::Hybrid, true, false, 2), "kalem");
+        assert_eq!(lemmatize_word("", Strategy::Hybrid, false, false, 2), "");
+    }
+
+    #[test]
+    fn test_is_base_stopword() {
+        assert!(is_base_stopword("ve"));
+        assert!(!is_base_stopword("kitap"));
+        // Tokens are normalized before the lookup
+        assert!(!is_base_stopword("Ve"));
+    }
+
+    #[test]
+    fn test_process_tokens_stages() {
+        let all = ProcessStages {
+            normalize: true,
+            stopwords: true,
+            lemmatize: true,
+        };
+        let tokens = process_tokens("Ve İstanbul'a kitapları okudum.", all);
+        let fields: Vec<_> = tokens
+            .iter()
+            .map(|t| (t.norm.as_str(), t.is_stop, t.lemma.as_str()))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("ve", true, "ve"),
+                ("istanbul'a", false, "istanbul"),
+                ("kitapları", false, "kitap"),
+                ("okudum", false, "oku"),
+                (".", false, "."),
+            ]
+        );
+        assert_eq!((tokens[1].start, tokens[1].end, tokens[1].kind), (3, 13, "WORD"));
+
+        // Disabled stages leave the token text as it is
+        let raw = process_tokens(
+            "Ve kitapları",
+            ProcessStages {
+                normalize: false,
+                stopwords: true,
+                lemmatize: false,
+            },
+        );
+        assert_eq!((raw[0].norm.as_str(), raw[0].is_stop), ("Ve", false));
+        assert_eq!(raw[1].lemma, "kitapları");
+        let unflagged = ProcessStages {
+            stopwords: false,
+            ..all
+        };
+        assert!(!process_tokens("ve", unflagged)[0].is_stop);
+    }
+
+    #[test]
+    fn test_lemma_candidates_tie_break() {
+        assert_eq!(TieBreak::parse("return-all"), Some(TieBreak::ReturnAll));
+        assert_eq!(TieBreak::parse("prefer_shortest"), None);
+
+        let candidates = |word, tie_break| {
+            lemma_candidates(word, Strategy::Hybrid, false, false, 2, tie_break)
+        };
+        // The dictionary entry and the noun reading of "gelir" (income)
+        assert_eq!(candidates("gelir", TieBreak::ReturnAll), ["gel", "gelir"]);
+        assert_eq!(candidates("gelir", TieBreak::PreferDictionary), ["gel"]);
+        // Stripping disagrees with the analyzer for an unlisted word
+        assert_eq!(candidates("gözlükçü", TieBreak::PreferDictionary), ["gözlükç"]);
+        assert_eq!(candidates("gözlükçü", TieBreak::PreferShortest), ["göz"]);
+        assert_eq!(candidates("gözlükçü", TieBreak::PreferMostFrequent), ["göz"]);
+        // Heuristic lemmas come from suffix stripping alone
+        assert_eq!(
+            lemma_candidates("kitaplar", Strategy::Heuristic, false, false, 2, TieBreak::ReturnAll),
+            ["kitap"]
+        );
+        assert_eq!(candidates("", TieBreak::ReturnAll), [""]);
+    }
+
+    #[test]
+    fn test_series_stages() {
+        assert_eq!(SeriesStage::parse("stem"), None);
+        for name in SeriesStage::NAMES {
+            assert!(SeriesStage::parse(name).is_some(), "{}", name);
+        }
+        assert_eq!(
+            SeriesStage::Normalize.apply("İSTANBUL"),
+            StageOutput::Text("istanbul".to_string())
+        );
+        assert_eq!(
+            SeriesStage::Lemmatize.apply("kitaplar"),
+            StageOutput::Text("kitap".to_string())
+        );
+        assert_eq!(
+            SeriesStage::Tokenize.apply("Merhaba dünya!"),
+            StageOutput::Tokens(vec!["Merhaba".into(), "dünya".into(), "!".into()])
+        );
+    }
+
+    #[test]
+    fn test_chunk_tokens() {
+        let text = "Merhaba, dünya! İstanbul'a gittim.";
+        assert_eq!(
+            chunk_tokens(text, false),
+            vec!["Merhaba", ",", "dünya", "!", "İstanbul'a", "gittim", "."]
+        );
+        assert_eq!(
+            chunk_tokens(text, true),
+            vec!["Merhaba", "dünya", "İstanbul'a", "gittim"]
+        );
+    }
+
+    #[test]
+    fn test_emoji_clusters_are_single_tokens() {
+        let text = "Harika 👍🏽 aile 👨‍👩‍👧 🇹🇷 ❤️ 1️⃣ :) 😀😀";
+        assert_eq!(
+            chunk_tokens(text, false),
+            vec!["Harika", "👍🏽", "aile", "👨‍👩‍👧", "🇹🇷", "❤️", "1️⃣", ":)", "😀", "😀"]
+        );
+        // Emoji are not punctuation
+        assert_eq!(chunk_tokens("Süper! 🔥", true), vec!["Süper", "🔥"]);
+    }
+
+    #[test]
+    fn test_tokenize_with_types() {
+        let text = "İyi 👨‍👩‍👧 :) 3,5 www.x.com !";
+        let types: Vec<(String, &str)> = tokenize_with_types(text, OffsetMode::Char)
+            .into_iter()
+            .map(|(token, kind, _, _)| (token.to_string(), kind))
+            .collect();
+        let expected = [
+            ("İyi", "WORD"),
+            ("👨‍👩‍👧", "EMOJI"),
+            (":)", "EMOTICON"),
+            ("3,5", "NUMBER"),
+            ("www.x.com", "URL"),
+            ("!", "PUNCT"),
+        ];
+        let expected: Vec<(String, &str)> =
+            expected.iter().map(|(token, kind)| (token.to_string(), *kind)).collect();
+        assert_eq!(types, expected);
+    }
+
+    #[test]
+    fn test_bidi_controls_are_skipped() {
+        // RLM after a URL, an override around a word, an isolated Arabic quote
+        let text = "bkz. https://x.com/a\u{200F} \u{202E}evet\u{202C} \u{2067}سلام\u{2069}!";
+        let tokens = tokenize_with_offsets(text, OffsetMode::Char);
+        let chars: Vec<char> = text.chars().collect();
+        for (token, start, end) in &tokens {
+            assert_eq!(chars[*start..*end].iter().collect::<String>(), *token);
+        }
+        let tokens: Vec<&str> = tokens.iter().map(|(token, _, _)| *token).collect();
+        assert_eq!(tokens, ["bkz", ".", "https://x.com/a", "evet", "!"]);
+    }
+
+    #[test]
+    fn test_blob_tokens() {
+        let blob = "a".repeat(10_000);
+        let url = format!("https://durak.dev/?q={}", "x".repeat(200));
+        let text = format!("kitap {} {}", blob, url);
+        let kinds: Vec<&str> =
+            tokenize_with_types(&text, OffsetMode::Char).into_iter().map(|t| t.1).collect();
+        assert_eq!(kinds, vec!["WORD", "BLOB", "URL"]);
+        let word = "ler".repeat(5_000);
+        assert_eq!(strip_suffixes(&word), word);
+        assert_eq!(strip_suffixes_validated(&word, false, 2, true, true), word);
+        assert!(analyzer::analyze(&word).is_empty());
+        assert!(!spell::is_correct(&word) && spell::suggest(&word, 2, 5).is_empty());
+        assert!(!is_blob(&"ç".repeat(MAX_WORD_CHARS)));
+        assert!(is_blob(&"ç".repeat(MAX_WORD_CHARS + 1)));
+    }
+
+    #[test]
+    fn test_pre_tokenize_byte_offsets() {
+        let text = "Çok güzel 👍🏽!";
+        let tokens = pre_tokenize(text);
+        assert_eq!(tokens[1], ("güzel", (5, 11)));
+        for (token, (start, end)) in tokens {
+            assert_eq!(&text[start..end], token);
+        }
+    }
+
+    #[test]
+    fn test_tokenize_columns_match_rows() {
+        let text = "Harika 👍🏽 :) https://durak.dev";
+        let (texts, starts, ends, kinds) = tokenize_columns(text);
+        let rows: Vec<(&str, &str, usize, usize)> = (0..texts.len())
+            .map(|i| (texts[i], kinds[i], starts[i], ends[i]))
+            .collect();
+        assert_eq!(rows, tokenize_with_types(text, OffsetMode::Char));
+        assert_eq!(kinds, vec!["WORD", "EMOJI", "EMOTICON", "URL"]);
+    }
+
+    #[test]
+    fn test_hashtag_and_mention_types() {
+        let text = "@ali_veli #GünaydınTürkiye x@ #️⃣ bu#değil";
+        let types: Vec<(String, &str)> = tokenize_with_types(text, OffsetMode::Char)
+            .into_iter()
+            .map(|(token, kind, _, _)| (token.to_string(), kind))
+            .collect();
+        let expected = [
+            ("@ali_veli", "MENTION"),
+            ("#GünaydınTürkiye", "HASHTAG"),
+            ("x", "WORD"),
+            ("@", "PUNCT"),
+            ("#️⃣", "EMOJI"),
+            ("bu", "WORD"),
+            ("#", "PUNCT"),
+            ("değil", "WORD"),
+        ];
+        let expected: Vec<(String, &str)> =
+            expected.iter().map(|(token, kind)| (token.to_string(), *kind)).collect();
+        assert_eq!(types, expected);
+    }
+
+    #[test]
+    fn test_contact_tokens_are_whole() {
+        let text = "Yaz: ali.veli@ornek.com.tr. Tel 0532 123 45 67, +90 (212) 123-45-67 \
+                    (0312) 1234567; IBAN TR33 0006 1005 1978 6457 8413 26 ya da \
+                    TR330006100519786457841326";
+        let types: Vec<(String, &str)> = tokenize_with_types(text, OffsetMode::Char)
+            .into_iter()
+            .map(|(token, kind, _, _)| (token.to_string(), kind))
+            .filter(|(_, kind)| matches!(*kind, "EMAIL" | "PHONE" | "IBAN"))
+            .collect();
+        let expected = [
+            ("ali.veli@ornek.com.tr", "EMAIL"),
+            ("0532 123 45 67", "PHONE"),
+            ("+90 (212) 123-45-67", "PHONE"),
+            ("(0312) 1234567", "PHONE"),
+            ("TR33 0006 1005 1978 6457 8413 26", "IBAN"),
+            ("TR330006100519786457841326", "IBAN"),
+        ];
+        let expected: Vec<(String, &str)> =
+            expected.iter().map(|(token, kind)| (token.to_string(), *kind)).collect();
+        assert_eq!(types, expected);
+        // Plain numbers stay numbers
+        assert_eq!(chunk_tokens("1500 2024 05321", false), vec!["1500", "2024", "05321"]);
+    }
+
+    #[test]
+    fn test_date_and_time_tokens_are_whole() {
+        let text = "3 Mart 2024 saat 14:30'da, 12.03.2024 ve 2024-03-12 arası 9:05. 3.5 Mart";
+        let types: Vec<(String, &str)> = tokenize_with_types(text, OffsetMode::Char)
+            .into_iter()
+            .map(|(token, kind, _, _)| (token.to_string(), kind))
+            .filter(|(_, kind)| matches!(*kind, "DATE" | "TIME" | "NUMBER"))
+            .collect();
+        let expected = [
+            ("3 Mart 2024", "DATE"),
+            ("saat 14:30", "TIME"),
+            ("12.03.2024", "DATE"),
+            ("2024-03-12", "DATE"),
+            ("9:05", "TIME"),
+            ("3.5", "NUMBER"),
+        ];
+        let expected: Vec<(String, &str)> =
+            expected.iter().map(|(token, kind)| (token.to_string(), *kind)).collect();
+        assert_eq!(types, expected);
+        assert_eq!(word_tokens("Saat 9 ve Mart 2024 toplantısı"), vec!["ve", "toplantısı"]);
+    }
+
+    #[test]
+    fn test_offsets_count_characters() {
+        // The family emoji is 5 code points
+        let offsets = tokenize_with_offsets("ğ 👨‍👩‍👧 son", OffsetMode::Char);
+        assert_eq!(offsets[1], ("👨‍👩‍👧", 2, 7));
+        assert_eq!(offsets[2], ("son", 8, 11));
+        let bytes = tokenize_with_offsets("ğ 👨‍👩‍👧 son", OffsetMode::Byte);
+        assert_eq!(bytes[1], ("👨‍👩‍👧", 3, 21));
+        assert_eq!(bytes[2], ("son", 22, 25));
+    }
+
+    #[test]
+    fn test_token_iter_borrows_input() {
+        let text = "Çok güzel 👍🏽! https://durak.dev";
+        let tokens: Vec<(&str, usize, usize)> = TokenIter::new(text).collect();
+        assert_eq!(tokens.len(), token_spans(text).len());
+        let range = text.as_bytes().as_ptr_range();
+        for (token, _, _) in &tokens {
+            assert!(range.contains(&token.as_ptr()));
+        }
+        assert_eq!(TokenIter::new(text).nth(2), Some(("👍🏽", 10, 12)));
+        assert_eq!(TokenIter::new("  ").next(), None);
+    }
+
+    /// Tokens of each document of a `tokenize_batch` result
+    fn batch_tokens(arenas: Vec<arena::StrArena>) -> Vec<Option<Vec<String>>> {
+        arenas
+            .iter()
+            .flat_map(|arena| {
+                (0..arena.len())
+                    .map(|i| arena.document(i).map(|tokens| tokens.map(str::to_string).collect()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_batch_helpers_preserve_nulls() {
+        let texts = vec![Some("İstanbul'a GİT".to_string()), None];
+        let token = cancel::Token::new();
+        assert_eq!(
+            normalize_batch(texts.clone(), true, true, 1, &token),
+            Ok(vec![Some("istanbul'a git".to_string()), None])
+        );
+        assert_eq!(
+            tokenize_batch(texts.clone(), ClassSet::ALL, 1, &token).map(batch_tokens),
+            Ok(vec![
+                Some(vec!["İstanbul'a".to_string(), "GİT".to_string()]),
+                None
+            ])
+        );
+        token.cancel();
+        assert_eq!(tokenize_batch(texts, ClassSet::ALL, 1, &token), Err(cancel::Cancelled));
+    }
+
+    #[test]
+    fn test_count_terms_under_a_memory_cap() {
+        let text = "Kitap okudum.\nKİTAP okudum, dergi okudum\n\ndergi aldım\n";
+        let counted = count_terms(text.as_bytes(), 1, 1, None, usize::MAX).unwrap();
+        assert_eq!(counted[0], ("okudum".to_string(), 3));
+        assert_eq!(counted[1], ("dergi".to_string(), 2));
+        for budget in [0, 100] {
+            assert_eq!(count_terms(text.as_bytes(), 1, 1, None, budget).unwrap(), counted);
+        }
+        let top = count_terms(text.as_bytes(), 1, 2, Some(2), 0).unwrap();
+        assert_eq!(top, &counted[..2]);
+        let bigrams = count_terms(text.as_bytes(), 2, 2, None, 0).unwrap();
+        assert_eq!(bigrams, [("kitap okudum".to_string(), 2)]);
+    }
+
+    #[test]
+    fn test_warm_up_components() {
+        let timings = run_warm_up(false);
+        assert_eq!(timings.len(), WARM_UP_COMPONENTS.len());
+        assert!(timings.contains_key("lemma_dict"));
+        assert!(!timings.contains_key("spell"));
+        assert!(timings.values().all(|&seconds| seconds >= 0.0));
+    }
+
+    #[test]
+    fn test_batch_helpers_threads_preserve_order() {
+        let texts: Vec<Option<String>> = (0..500)
+            .map(|i| (i % 7 != 0).then(|| format!("KİTAP {}", i)))
+            .collect();
+        let token = cancel::Token::new();
+        let normalized = normalize_batch(texts.clone(), true, true, 1, &token).unwrap();
+        let sequential = tokenize_batch(normalized, ClassSet::ALL, 1, &token).map(batch_tokens);
+        for threads in [0, 4] {
+            let normalized = normalize_batch(texts.clone(), true, true, threads, &token);
+            let tokens = tokenize_batch(normalized.unwrap(), ClassSet::ALL, threads, &token).map(batch_tokens);
+            assert_eq!(tokens, sequential);
+        }
+    }
+
     #[test]
     fn test_validated_prevents_overstripping() {
         // Demonstrate that validated stripping prevents over-stripping
@@ -820,7 +5689,7 @@ mod tests {
         // Example: "kitaplardan" -> naive might strip to "ki" or "k"
         // but validated should stop at "kitap"
         let word = "kitaplardan";
-        let validated_result = strip_suffixes_validated(word, true, 2, true);
+        let validated_result = strip_suffixes_validated(word, true, 2, true, true);
 
         // Should be a valid root
         assert!(
@@ -858,7 +5727,7 @@ mod tests {
         ];
 
         for (word, expected_root) in valid_cases {
-            let result = strip_suffixes_validated(word, false, 2, true);
+            let result = strip_suffixes_validated(word, false, 2, true, true);
             assert!(
                 result.contains(expected_root),
                 "Valid sequence: {} should lemmatize to contain '{}', got '{}'",
@@ -886,7 +5755,7 @@ mod tests {
 
         for word in test_words {
             let naive = strip_suffixes(word);
-            let validated = strip_suffixes_validated(word, true, 2, true);
+            let validated = strip_suffixes_validated(word, true, 2, true, true);
 
             println!(
                 "Word: {} | Naive: {} | Validated: {}",
@@ -918,7 +5787,7 @@ mod tests {
         ];
 
         for (word, expected) in valid_cases {
-            let result = strip_suffixes_validated(word, false, 2, true);
+            let result = strip_suffixes_validated(word, false, 2, true, true);
             assert_eq!(
                 result, expected,
                 "Vowel harmony should allow {} -> {}",
@@ -937,10 +5806,10 @@ mod tests {
         let word = "kitaplar";
 
         // With harmony check (default)
-        let with_harmony = strip_suffixes_validated(word, false, 2, true);
+        let with_harmony = strip_suffixes_validated(word, false, 2, true, true);
 
         // Without harmony check
-        let without_harmony = strip_suffixes_validated(word, false, 2, false);
+        let without_harmony = strip_suffixes_validated(word, false, 2, false, true);
 
         // Both should work for valid Turkish words
         assert_eq!(with_harmony, "kitap");
@@ -960,7 +5829,7 @@ mod tests {
         let test_word = "kitaplardan"; // book-PLUR-ABL
 
         // With harmony checking
-        let with_harmony = strip_suffixes_validated(test_word, false, 2, true);
+        let with_harmony = strip_suffixes_validated(test_word, false, 2, true, true);
 
         // Should strip to valid root
         assert!(with_harmony.len() >= 2);
@@ -968,4 +5837,24 @@ mod tests {
 
         println!("Harmony validation: {} -> {}", test_word, with_harmony);
     }
+
+    #[test]
+    fn test_split_clitics() {
+        assert_eq!(split_clitics("İstanbul'a"), ("İstanbul".to_string(), "'a".to_string()));
+        assert_eq!(split_clitics("Ali’nin"), ("Ali".to_string(), "’nin".to_string()));
+        assert_eq!(split_clitics("ANKARA'DA"), ("ANKARA".to_string(), "'DA".to_string()));
+        assert_eq!(split_clitics("O'Neill'ın").1, "'ın");
+        for whole in ["O'Neill", "kitap", "'a", "Ali'", "saat'14"] {
+            assert_eq!(split_clitics(whole), (whole.to_string(), String::new()));
+        }
+    }
+
+    #[test]
+    fn test_proper_noun_suffixes_after_apostrophe() {
+        assert_eq!(strip_suffixes_validated("Ankara'dan", false, 2, true, true), "Ankara");
+        assert_eq!(strip_suffixes_validated("İstanbul’daki", true, 2, true, true), "İstanbul");
+        assert_eq!(strip_suffixes_validated("ANKARA'DAN", false, 2, true, true), "ANKARA");
+        assert_eq!(strip_suffixes_validated("kitaplardan", false, 2, true, true), "kitap");
+        assert_ne!(strip_suffixes_validated("Ankara'dan", false, 2, true, false), "Ankara");
+    }
 }
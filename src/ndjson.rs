@@ -0,0 +1,286 @@
+//! Streaming ndjson (JSON lines) reading for large dumps
+//!
+//! - Lines are split with memchr over a reusable block buffer, so a
+//!   multi-hundred-GB dump is read with memory bounded by the longest line
+//! - A field is selected by a dotted path ("text", "user.description")
+//!   without building the record: the other fields are skipped by the JSON
+//!   parser and a string without escapes is borrowed from the line buffer
+//!
+//! Lines that do not start with "{" are plain-text records, as in the JSONL
+//! input of the CLI.
+
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use std::borrow::Cow;
+use std::fmt;
+use std::io::{self, Read};
+use std::ops::Range;
+
+/// Default read block size in bytes
+pub const DEFAULT_BLOCK_SIZE: usize = 1 << 20;
+
+/// Splits a byte stream into lines without copying them out of the buffer
+pub struct Lines<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    /// Start of the unread part of the buffer
+    start: usize,
+    block_size: usize,
+    eof: bool,
+    line_no: usize,
+    /// Buffer range of the last line returned
+    line: Range<usize>,
+}
+
+impl<R: Read> Lines<R> {
+    pub fn new(reader: R, block_size: usize) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            start: 0,
+            block_size: block_size.max(1),
+            eof: false,
+            line_no: 0,
+            line: 0..0,
+        }
+    }
+
+    /// Move the unread bytes to the front and read one more block after them
+    fn fill(&mut self) -> io::Result<()> {
+        self.buffer.drain(..self.start);
+        self.start = 0;
+        let end = self.buffer.len();
+        self.buffer.resize(end + self.block_size, 0);
+        let read = loop {
+            match self.reader.read(&mut self.buffer[end..]) {
+                Ok(read) => break read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    self.buffer.truncate(end);
+                    return Err(e);
+                }
+            }
+        };
+        self.buffer.truncate(end + read);
+        self.eof = read == 0;
+        Ok(())
+    }
+
+    /// Next line without its line ending and its 1-based number, or `None`
+    /// at the end of the input
+    pub fn next_line(&mut self) -> io::Result<Option<(usize, &[u8])>> {
+        let mut searched = 0;
+        let end = loop {
+            let unread = &self.buffer[self.start..];
+            if let Some(offset) = memchr::memchr(b'\n', &unread[searched..]) {
+                break self.start + searched + offset;
+            }
+            if self.eof {
+                if unread.is_empty() {
+                    return Ok(None);
+                }
+                break self.buffer.len();
+            }
+            searched = unread.len();
+            self.fill()?;
+        };
+        let line_start = self.start;
+        self.start = (end + 1).min(self.buffer.len());
+        self.line_no += 1;
+        let line = &self.buffer[line_start..end];
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        self.line = line_start..line_start + line.len();
+        Ok(Some((self.line_no, line)))
+    }
+
+    /// The line last returned by `next_line`, empty before the first line
+    pub fn line(&self) -> &[u8] {
+        &self.buffer[self.line.clone()]
+    }
+}
+
+/// Whether a map key equals the next path segment, compared without
+/// allocating for keys without escapes
+struct KeyMatches<'p>(&'p str);
+
+impl<'de> DeserializeSeed<'de> for KeyMatches<'_> {
+    type Value = bool;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<bool, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for KeyMatches<'_> {
+    type Value = bool;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string key")
+    }
+
+    fn visit_str<E: de::Error>(self, key: &str) -> Result<bool, E> {
+        Ok(key == self.0)
+    }
+}
+
+/// The string at `path` below the current value, `None` when the path is
+/// missing or does not end on a string
+struct Field<'p> {
+    path: &'p [&'p str],
+}
+
+impl<'de> DeserializeSeed<'de> for Field<'_> {
+    type Value = Option<Cow<'de, str>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Field<'_> {
+    type Value = Option<Cow<'de, str>>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON value")
+    }
+
+    fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E> {
+        Ok(self.path.is_empty().then_some(Cow::Borrowed(value)))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(self.path.is_empty().then(|| Cow::Owned(value.to_string())))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Self::Value, E> {
+        Ok(self.path.is_empty().then_some(Cow::Owned(value)))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut found = None;
+        while let Some(matches) = match self.path.split_first() {
+            Some((key, _)) if found.is_none() => map.next_key_seed(KeyMatches(key))?,
+            _ => map.next_key::<IgnoredAny>()?.map(|_| false),
+        } {
+            if matches {
+                found = map.next_value_seed(Field { path: &self.path[1..] })?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(found)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(None)
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+}
+
+/// The text of one record: the string at the dotted `path` of a JSON object
+/// line (borrowed when it has no escapes), or a plain-text line as is.
+/// `None` for blank lines and records without a string at `path`.
+///
+/// # Examples
+/// ```text
+/// select(br#"{"user": {"bio": "Merhaba"}}"#, "user.bio") -> Some("Merhaba")
+/// select(br#"{"id": 1}"#, "text")                       -> None
+/// select(b"Merhaba dunya", "text")                      -> Some("Merhaba dunya")
+/// ```
+pub fn select<'a>(
+    line: &'a [u8],
+    path: &str,
+) -> Result<Option<Cow<'a, str>>, serde_json::Error> {
+    let indent = line.iter().take_while(|b| b.is_ascii_whitespace()).count();
+    let trimmed = &line[indent..];
+    if !trimmed.starts_with(b"{") {
+        let text = std::str::from_utf8(line).map_err(de::Error::custom)?;
+        return Ok((!text.trim().is_empty()).then_some(Cow::Borrowed(text)));
+    }
+    let segments: Vec<&str> = path.split('.').collect();
+    let mut deserializer = serde_json::Deserializer::from_slice(trimmed);
+    let field = Field { path: &segments }.deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(field)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(input: &str, block_size: usize) -> Vec<(usize, String)> {
+        let mut reader = Lines::new(input.as_bytes(), block_size);
+        let mut lines = Vec::new();
+        while let Some((line_no, line)) = reader.next_line().unwrap() {
+            lines.push((line_no, String::from_utf8(line.to_vec()).unwrap()));
+        }
+        lines
+    }
+
+    #[test]
+    fn test_lines_across_blocks() {
+        let input = "bir\r\niki üç\n\ndört";
+        let expected = vec![
+            (1, "bir".to_string()),
+            (2, "iki üç".to_string()),
+            (3, String::new()),
+            (4, "dört".to_string()),
+        ];
+        let mut reader = Lines::new("bir\r\niki".as_bytes(), 2);
+        assert_eq!(reader.line(), b"");
+        reader.next_line().unwrap();
+        assert_eq!(reader.line(), b"bir");
+        for block_size in [1, 3, 64] {
+            assert_eq!(lines(input, block_size), expected, "block size {}", block_size);
+        }
+        assert_eq!(lines("bir\n", 2), vec![(1, "bir".to_string())]);
+        assert!(lines("", 8).is_empty());
+    }
+
+    #[test]
+    fn test_select_fields() {
+        let line = concat!(
+            r#"{"id": 7, "tags": [1, {"text": "x"}], "#,
+            r#""user": {"bio": "Selam"}, "text": "Merhaba"}"#,
+        )
+        .as_bytes();
+        assert_eq!(select(line, "text").unwrap().as_deref(), Some("Merhaba"));
+        assert_eq!(select(line, "user.bio").unwrap().as_deref(), Some("Selam"));
+        assert_eq!(select(line, "id").unwrap(), None);
+        assert_eq!(select(line, "user.name").unwrap(), None);
+        assert!(matches!(select(line, "text").unwrap(), Some(Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn test_select_escapes_and_plain_text() {
+        let escaped = select(r#"{"text": "çay\nsu"}"#.as_bytes(), "text").unwrap();
+        assert_eq!(escaped.as_deref(), Some("çay\nsu"));
+        let plain = select("düz metin".as_bytes(), "text").unwrap();
+        assert_eq!(plain.as_deref(), Some("düz metin"));
+        assert_eq!(select(b"   ", "text").unwrap(), None);
+        assert!(select(br#"{"text": "yar"#, "text").is_err());
+        assert!(select(br#"{"text": "a"} {}"#, "text").is_err());
+    }
+}
@@ -0,0 +1,443 @@
+//! Rule and gazetteer based named entity recognition
+//!
+//! Entities are found in three passes, earlier passes taking precedence over
+//! later ones for overlapping spans:
+//!
+//! 1. Patterns: URLs, dates ("12 Mart 2024", "12.03.2024", the patterns of
+//!    `datetime_tr`) and money amounts ("1.250 TL", "₺100", "5 milyon dolar")
+//! 2. Organizations by rule: a capitalized phrase ending in a head word such
+//!    as "Üniversitesi", "Bakanlığı" or "Holding"
+//! 3. Gazetteers embedded from `resources/tr/gazetteers/`: given names
+//!    (extended with following capitalized surnames), provinces and
+//!    well-known organizations
+//!
+//! Gazetteer matches require capitalization, and Turkish case suffixes after
+//! an apostrophe are excluded from the span ("İstanbul'a" → "İstanbul").
+
+use crate::datetime_tr;
+use crate::fast_normalize;
+use crate::segmentation;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+static PERSON_NAMES_DATA: &str = include_str!("../resources/tr/gazetteers/person_names.txt");
+static LOCATIONS_DATA: &str = include_str!("../resources/tr/gazetteers/locations.txt");
+static ORGANIZATIONS_DATA: &str = include_str!("../resources/tr/gazetteers/organizations.txt");
+
+/// Head words that close an organization name
+const ORG_HEADS: &[&str] = &[
+    "ajansı",
+    "bakanlığı",
+    "bankası",
+    "başkanlığı",
+    "belediyesi",
+    "derneği",
+    "enstitüsü",
+    "federasyonu",
+    "hastanesi",
+    "holding",
+    "kulübü",
+    "kurulu",
+    "kurumu",
+    "lisesi",
+    "meclisi",
+    "müdürlüğü",
+    "partisi",
+    "şirketi",
+    "üniversitesi",
+    "vakfı",
+    "valiliği",
+];
+
+/// Longest organization name (in words) recognized by the head-word rule
+const MAX_ORG_WORDS: usize = 6;
+
+/// Surnames / middle names attached to a recognized given name
+const MAX_NAME_EXTENSION: usize = 2;
+
+/// Entity type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityLabel {
+    Person,
+    Location,
+    Organization,
+    Date,
+    Money,
+    Url,
+}
+
+impl EntityLabel {
+    /// Short label used in the Python API and CLI output
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EntityLabel::Person => "PER",
+            EntityLabel::Location => "LOC",
+            EntityLabel::Organization => "ORG",
+            EntityLabel::Date => "DATE",
+            EntityLabel::Money => "MONEY",
+            EntityLabel::Url => "URL",
+        }
+    }
+}
+
+/// A recognized entity with character offsets into the input text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entity {
+    pub text: String,
+    pub label: EntityLabel,
+    pub start: usize,
+    pub end: usize,
+}
+
+struct Gazetteer {
+    names: HashSet<&'static str>,
+    /// Locations and organizations keyed by their lowercase, space-joined words
+    phrases: HashMap<&'static str, EntityLabel>,
+    max_phrase_words: usize,
+}
+
+static GAZETTEER: OnceLock<Gazetteer> = OnceLock::new();
+static URL_REGEX: OnceLock<Regex> = OnceLock::new();
+static MONEY_REGEX: OnceLock<Regex> = OnceLock::new();
+static WORD_REGEX: OnceLock<Regex> = OnceLock::new();
+
+fn entries(data: &'static str) -> impl Iterator<Item = &'static str> {
+    data.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+fn gazetteer() -> &'static Gazetteer {
+    GAZETTEER.get_or_init(|| {
+        let names = entries(PERSON_NAMES_DATA).collect();
+
+        let mut phrases = HashMap::new();
+        for location in entries(LOCATIONS_DATA) {
+            phrases.insert(location, EntityLabel::Location);
+        }
+        for organization in entries(ORGANIZATIONS_DATA) {
+            phrases.insert(organization, EntityLabel::Organization);
+        }
+
+        let max_phrase_words = phrases
+            .keys()
+            .map(|phrase| phrase.split(' ').count())
+            .max()
+            .unwrap_or(1);
+
+        Gazetteer {
+            names,
+            phrases,
+            max_phrase_words,
+        }
+    })
+}
+
+fn get_url_regex() -> &'static Regex {
+    URL_REGEX.get_or_init(|| Regex::new(r"https?://[^\s]+|www\.[^\s]+").expect("Invalid URL regex"))
+}
+
+fn get_money_regex() -> &'static Regex {
+    MONEY_REGEX.get_or_init(|| {
+        let pattern = r"(?x)
+            [₺$€£]\s?\d(?:[\d.,]*\d)? |                     # ₺100, $5.99
+            \b\d(?:[\d.,]*\d)?(?:\s(?:bin|milyon|milyar))?\s?
+            (?:
+                [₺$€£] |
+                (?:TL|USD|EUR|GBP|lira(?:sı)?|dolar(?:ı)?|avro|euro|sterlin|kuruş)\b
+            )                                               # 1.250 TL, 5 milyon dolar
+        ";
+        Regex::new(pattern).expect("Invalid money regex")
+    })
+}
+
+fn get_word_regex() -> &'static Regex {
+    WORD_REGEX.get_or_init(|| Regex::new(r"[\p{L}\p{N}]+").expect("Invalid word regex"))
+}
+
+/// A word of the input with byte offsets
+struct Word {
+    start: usize,
+    end: usize,
+    lower: String,
+    capitalized: bool,
+}
+
+/// Byte span (start, end, label) found by one of the passes
+type Span = (usize, usize, EntityLabel);
+
+fn overlaps(spans: &[Span], start: usize, end: usize) -> bool {
+    spans.iter().any(|&(s, e, _)| start < e && s < end)
+}
+
+fn find_patterns(text: &str, spans: &mut Vec<Span>) {
+    for mat in get_url_regex().find_iter(text) {
+        // Trailing sentence punctuation is not part of the URL
+        let url = mat
+            .as_str()
+            .trim_end_matches(['.', ',', ';', ':', '!', '?', ')', '"', '\'']);
+        spans.push((mat.start(), mat.start() + url.len(), EntityLabel::Url));
+    }
+
+    for (regex, label) in [
+        (datetime_tr::date_regex(), EntityLabel::Date),
+        (get_money_regex(), EntityLabel::Money),
+    ] {
+        for mat in regex.find_iter(text) {
+            if !overlaps(spans, mat.start(), mat.end()) {
+                spans.push((mat.start(), mat.end(), label));
+            }
+        }
+    }
+}
+
+/// Index one past the end of the capitalized run starting at `start`
+///
+/// A run is a sequence of capitalized words separated only by whitespace.
+fn run_end(text: &str, words: &[Word], start: usize) -> usize {
+    let mut end = start + 1;
+    while end < words.len()
+        && words[end].capitalized
+        && text[words[end - 1].end..words[end].start]
+            .chars()
+            .all(char::is_whitespace)
+    {
+        end += 1;
+    }
+    end
+}
+
+/// Longest gazetteer phrase starting at word `start` within the run
+fn match_phrase(words: &[Word], start: usize, end: usize) -> Option<(usize, EntityLabel)> {
+    let gaz = gazetteer();
+    let longest = gaz.max_phrase_words.min(end - start);
+
+    (1..=longest).rev().find_map(|len| {
+        let phrase = words[start..start + len]
+            .iter()
+            .map(|w| w.lower.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        gaz.phrases.get(phrase.as_str()).map(|&label| (len, label))
+    })
+}
+
+fn find_words(text: &str, spans: &mut Vec<Span>) {
+    let gaz = gazetteer();
+    let words: Vec<Word> = get_word_regex()
+        .find_iter(text)
+        .filter(|mat| !overlaps(spans, mat.start(), mat.end()))
+        .map(|mat| Word {
+            start: mat.start(),
+            end: mat.end(),
+            lower: fast_normalize(mat.as_str(), true, true),
+            capitalized: mat.as_str().chars().next().is_some_and(char::is_uppercase),
+        })
+        .collect();
+
+    let is_head = |w: &Word| ORG_HEADS.contains(&w.lower.as_str());
+
+    let mut i = 0;
+    while i < words.len() {
+        if !words[i].capitalized {
+            i += 1;
+            continue;
+        }
+        let end = run_end(text, &words, i);
+
+        let mut p = i;
+        while p < end {
+            // Given name, extended with following capitalized surnames
+            if gaz.names.contains(words[p].lower.as_str()) {
+                let mut last = p;
+                while last + 1 < end
+                    && last - p < MAX_NAME_EXTENSION
+                    && !is_head(&words[last + 1])
+                    && match_phrase(&words, last + 1, end).is_none()
+                {
+                    last += 1;
+                }
+                spans.push((words[p].start, words[last].end, EntityLabel::Person));
+                p = last + 1;
+                continue;
+            }
+
+            // Organization closed by a head word ("Ankara Üniversitesi")
+            let head = (p + 1..end.min(p + MAX_ORG_WORDS)).find(|&q| is_head(&words[q]));
+            if let Some(q) = head {
+                // Sentence-initial common words are not part of the name
+                let mut first = p;
+                while first < q && segmentation::is_word(&words[first].lower) {
+                    first += 1;
+                }
+                if first < q {
+                    spans.push((words[first].start, words[q].end, EntityLabel::Organization));
+                    p = q + 1;
+                    continue;
+                }
+            }
+
+            if let Some((len, label)) = match_phrase(&words, p, end) {
+                spans.push((words[p].start, words[p + len - 1].end, label));
+                p += len;
+                continue;
+            }
+
+            p += 1;
+        }
+
+        i = end;
+    }
+}
+
+/// Extract named entities from text
+///
+/// Returns entities sorted by position, with character (not byte) offsets.
+///
+/// # Examples
+/// ```text
+/// extract_entities("Ahmet Yılmaz 12 Mart 2024'te Ankara'ya gitti.")
+/// -> [("Ahmet Yılmaz", PER, 0, 12), ("12 Mart 2024", DATE, 13, 25),
+///     ("Ankara", LOC, 29, 35)]
+/// ```
+pub fn extract_entities(text: &str) -> Vec<Entity> {
+    let mut spans = Vec::new();
+    find_patterns(text, &mut spans);
+    find_words(text, &mut spans);
+    spans.sort_by_key(|&(start, _, _)| start);
+
+    spans
+        .into_iter()
+        .map(|(start, end, label)| {
+            let char_start = text[..start].chars().count();
+            let char_end = char_start + text[start..end].chars().count();
+            Entity {
+                text: text[start..end].to_string(),
+                label,
+                start: char_start,
+                end: char_end,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(text: &str) -> Vec<(String, &'static str)> {
+        extract_entities(text)
+            .into_iter()
+            .map(|e| (e.text, e.label.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_gazetteers_loaded() {
+        let gaz = gazetteer();
+        assert!(gaz.names.contains("ahmet"));
+        assert_eq!(gaz.phrases.get("istanbul"), Some(&EntityLabel::Location));
+        assert_eq!(
+            gaz.phrases.get("türk hava yolları"),
+            Some(&EntityLabel::Organization)
+        );
+        assert!(gaz.max_phrase_words >= 3);
+    }
+
+    #[test]
+    fn test_person_with_surname() {
+        assert_eq!(
+            labels("Dün Ahmet Yılmaz ile görüştük."),
+            vec![("Ahmet Yılmaz".to_string(), "PER")]
+        );
+    }
+
+    #[test]
+    fn test_location_excludes_suffix() {
+        let entities = extract_entities("İstanbul'a gittim");
+        assert_eq!(entities.len(), 1);
+        assert_eq!(entities[0].text, "İstanbul");
+        assert_eq!(entities[0].label, EntityLabel::Location);
+        assert_eq!((entities[0].start, entities[0].end), (0, 8));
+    }
+
+    #[test]
+    fn test_requires_capitalization() {
+        assert!(extract_entities("ahmet ankara'ya gitti").is_empty());
+    }
+
+    #[test]
+    fn test_organizations() {
+        assert_eq!(
+            labels("Türk Hava Yolları yeni uçak aldı"),
+            vec![("Türk Hava Yolları".to_string(), "ORG")]
+        );
+        // Not in the gazetteer, recognized by head word
+        assert_eq!(
+            labels("Ama Ege Üniversitesi'nde ders var"),
+            vec![("Ege Üniversitesi".to_string(), "ORG")]
+        );
+        assert_eq!(
+            labels("Mehmet Demir Ankara Üniversitesi'nde okuyor"),
+            vec![
+                ("Mehmet Demir".to_string(), "PER"),
+                ("Ankara Üniversitesi".to_string(), "ORG"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(
+            labels("12 Mart 2024"),
+            vec![("12 Mart 2024".to_string(), "DATE")]
+        );
+        assert_eq!(
+            labels("Tarih: 12.03.2024"),
+            vec![("12.03.2024".to_string(), "DATE")]
+        );
+        assert_eq!(
+            labels("2024-03-12"),
+            vec![("2024-03-12".to_string(), "DATE")]
+        );
+        assert_eq!(
+            labels("Ocak 2023'te"),
+            vec![("Ocak 2023".to_string(), "DATE")]
+        );
+    }
+
+    #[test]
+    fn test_money() {
+        assert_eq!(
+            labels("Fiyatı 1.250,50 TL oldu"),
+            vec![("1.250,50 TL".to_string(), "MONEY")]
+        );
+        assert_eq!(labels("₺100 verdim"), vec![("₺100".to_string(), "MONEY")]);
+        assert_eq!(
+            labels("5 milyon dolar"),
+            vec![("5 milyon dolar".to_string(), "MONEY")]
+        );
+        assert!(labels("5 kitap").is_empty());
+    }
+
+    #[test]
+    fn test_urls() {
+        assert_eq!(
+            labels("Detaylar https://example.com/a.html."),
+            vec![("https://example.com/a.html".to_string(), "URL")]
+        );
+    }
+
+    #[test]
+    fn test_char_offsets() {
+        let text = "Şükrü İzmir'de";
+        let entities = extract_entities(text);
+        assert_eq!(entities.len(), 2);
+        let chars: Vec<char> = text.chars().collect();
+        for entity in entities {
+            let slice: String = chars[entity.start..entity.end].iter().collect();
+            assert_eq!(slice, entity.text);
+        }
+    }
+}
@@ -0,0 +1,326 @@
+//! Realistic noise injection for robustness testing
+//!
+//! Corrupts clean Turkish text with the errors found in user-generated
+//! content, reusing the tables the correction features undo:
+//!
+//! - Deasciification reversal: Turkish letters typed as their ASCII look-alike
+//!   (çocuğu → cocugu), the folding table of the spell checker
+//! - Keyboard typos: a neighbouring key on the Turkish Q or F keyboard
+//!   substituted or inserted, a letter dropped, doubled or swapped with the
+//!   next one (`keyboard` adjacency)
+//! - Confusion pairs in reverse: a correction from the confusion-pair list
+//!   replaced by its misspelling (yalnız → yanlız, her şey → herşey)
+//! - Clitic splitting errors: the clitic "de"/"da" written together with its
+//!   host (ben de → bende), or the locative suffix written apart (evde → ev de)
+//!
+//! Each word is corrupted with probability `rate` by one error drawn from the
+//! profile's mix. A seed always produces the same noise.
+
+use crate::analyzer;
+use crate::confusion;
+use crate::turkish_lowercase;
+use crate::keyboard::{self, Layout};
+use crate::sampling::SplitMix64;
+use crate::spell;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+static MISSPELLINGS: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+
+/// Kind of error injected into a word
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Error {
+    Ascii,
+    Typo,
+    Confusion,
+    Clitic,
+}
+
+/// Mix of errors to inject
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// All error kinds, weighted towards missing diacritics
+    Social,
+    /// Keyboard typos only
+    Keyboard,
+    /// Missing diacritics only
+    Ascii,
+}
+
+impl Profile {
+    pub fn parse(name: &str) -> Option<Profile> {
+        match name {
+            "social" => Some(Profile::Social),
+            "keyboard" => Some(Profile::Keyboard),
+            "ascii" => Some(Profile::Ascii),
+            _ => None,
+        }
+    }
+
+    /// Relative weight of each error kind
+    fn weights(self) -> &'static [(Error, u64)] {
+        match self {
+            Profile::Social => &[
+                (Error::Ascii, 4),
+                (Error::Typo, 3),
+                (Error::Confusion, 2),
+                (Error::Clitic, 2),
+            ],
+            Profile::Keyboard => &[(Error::Typo, 1)],
+            Profile::Ascii => &[(Error::Ascii, 1)],
+        }
+    }
+}
+
+/// Misspelling for each correction of the confusion-pair list
+fn misspellings() -> &'static HashMap<&'static str, &'static str> {
+    MISSPELLINGS.get_or_init(|| {
+        let mut map = HashMap::new();
+        for (wrong, correct) in confusion::pairs() {
+            map.entry(correct).or_insert(wrong);
+        }
+        map
+    })
+}
+
+/// Fold Turkish letters to ASCII, keeping case (Çiçek → Cicek)
+fn asciify(word: &str) -> String {
+    word.chars()
+        .map(|c| match c {
+            'İ' => 'I',
+            _ if c.is_uppercase() => {
+                let lower = c.to_lowercase().next().unwrap_or(c);
+                spell::fold(lower).to_uppercase().next().unwrap_or(c)
+            }
+            _ => spell::fold(c),
+        })
+        .collect()
+}
+
+fn typo(word: &str, rng: &mut SplitMix64, layout: Layout) -> String {
+    let mut chars: Vec<char> = word.chars().collect();
+    let i = rng.below(chars.len() as u64) as usize;
+    let lower = chars[i].to_lowercase().next().unwrap_or(chars[i]);
+    let near = keyboard::neighbours(lower, layout);
+    match rng.below(5) {
+        0 | 1 if !near.is_empty() => {
+            let key = near[rng.below(near.len() as u64) as usize];
+            if rng.below(2) == 0 {
+                chars[i] = key;
+            } else {
+                chars.insert(i + 1, key);
+            }
+        }
+        2 if chars.len() > 2 => {
+            chars.remove(i);
+        }
+        3 if i + 1 < chars.len() => chars.swap(i, i + 1),
+        _ => chars.insert(i, chars[i]),
+    }
+    chars.into_iter().collect()
+}
+
+/// Stem and locative suffix of a word whose preferred analysis ends in Loc
+fn split_locative(word: &str) -> Option<(String, String)> {
+    let lower = turkish_lowercase(word);
+    let analysis = analyzer::analyze(&lower).into_iter().next()?;
+    let last = analysis.morphemes.last()?;
+    if last.tag != "Loc" || last.surface.is_empty() {
+        return None;
+    }
+    let cut = word
+        .chars()
+        .count()
+        .checked_sub(last.surface.chars().count())?;
+    let at = word.char_indices().nth(cut)?.0;
+    (at > 0).then(|| (word[..at].to_string(), word[at..].to_string()))
+}
+
+/// A run of letters or of other characters
+#[derive(Debug, Clone)]
+struct Segment {
+    text: String,
+    word: bool,
+}
+
+fn segments(text: &str) -> Vec<Segment> {
+    let mut segments: Vec<Segment> = Vec::new();
+    for c in text.chars() {
+        let word = c.is_alphabetic();
+        match segments.last_mut() {
+            Some(last) if last.word == word => last.text.push(c),
+            _ => segments.push(Segment {
+                text: c.to_string(),
+                word,
+            }),
+        }
+    }
+    segments
+}
+
+/// Index of the word after the word at `i` when only a single space lies
+/// between them
+fn next_word(segments: &[Segment], i: usize) -> Option<usize> {
+    (segments.get(i + 1)?.text == " " && segments.get(i + 2)?.word).then_some(i + 2)
+}
+
+/// Errors applicable to the word at `i`
+fn applicable(segments: &[Segment], i: usize, error: Error) -> bool {
+    let word = &segments[i].text;
+    let lower = turkish_lowercase(word);
+    match error {
+        Error::Ascii => asciify(word) != *word,
+        Error::Typo => true,
+        Error::Confusion => {
+            misspellings().contains_key(lower.as_str())
+                || next_word(segments, i).is_some_and(|j| {
+                    let phrase =
+                        format!("{} {}", lower, turkish_lowercase(&segments[j].text));
+                    misspellings().contains_key(phrase.as_str())
+                })
+        }
+        Error::Clitic => {
+            next_word(segments, i).is_some_and(|j| matches!(segments[j].text.as_str(), "de" | "da"))
+                || split_locative(word).is_some()
+        }
+    }
+}
+
+/// Inject `error` at word `i`, returning the index of the last segment used
+fn inject(
+    segments: &mut [Segment],
+    i: usize,
+    error: Error,
+    rng: &mut SplitMix64,
+    layout: Layout,
+) -> usize {
+    let word = segments[i].text.clone();
+    let lower = turkish_lowercase(&word);
+    match error {
+        Error::Ascii => segments[i].text = asciify(&word),
+        Error::Typo => segments[i].text = typo(&word, rng, layout),
+        Error::Confusion => {
+            if let Some(wrong) = misspellings().get(lower.as_str()) {
+                segments[i].text = confusion::match_case(&word, wrong);
+                return i;
+            }
+            let j = next_word(segments, i).expect("phrase has a second word");
+            let phrase = format!("{} {}", lower, turkish_lowercase(&segments[j].text));
+            segments[i].text = confusion::match_case(&word, misspellings()[phrase.as_str()]);
+            segments[i + 1].text.clear();
+            segments[j].text.clear();
+            return j;
+        }
+        Error::Clitic => {
+            if let Some(j) =
+                next_word(segments, i).filter(|&j| matches!(segments[j].text.as_str(), "de" | "da"))
+            {
+                segments[i].text = word + &segments[j].text;
+                segments[i + 1].text.clear();
+                segments[j].text.clear();
+                return j;
+            }
+            let (stem, suffix) = split_locative(&word).expect("word has a locative");
+            segments[i].text = format!("{} {}", stem, suffix);
+        }
+    }
+    i
+}
+
+/// Corrupt `text` with the errors of `profile`, each word with probability
+/// `rate`; typos follow the key adjacency of `layout`
+///
+/// # Examples
+/// ```text
+/// add_noise("Çocuğu evde gördüm", Profile::Ascii, 1.0, 0, Layout::Q) -> "Cocugu evde gordum"
+/// ```
+pub fn add_noise(text: &str, profile: Profile, rate: f64, seed: u64, layout: Layout) -> String {
+    let mut rng = SplitMix64::new(seed);
+    let mut segments = segments(text);
+    let mut i = 0;
+    while i < segments.len() {
+        if !segments[i].word || !rng.chance(rate) {
+            i += 1;
+            continue;
+        }
+        let choices: Vec<(Error, u64)> = profile
+            .weights()
+            .iter()
+            .copied()
+            .filter(|&(error, _)| applicable(&segments, i, error))
+            .collect();
+        let total: u64 = choices.iter().map(|&(_, weight)| weight).sum();
+        if total == 0 {
+            i += 1;
+            continue;
+        }
+        let mut pick = rng.below(total);
+        let error = choices
+            .iter()
+            .find(|&&(_, weight)| {
+                let found = pick < weight;
+                pick = pick.saturating_sub(weight);
+                found
+            })
+            .map(|&(error, _)| error)
+            .expect("pick is below the total weight");
+        i = inject(&mut segments, i, error, &mut rng, layout) + 1;
+    }
+    segments.into_iter().map(|segment| segment.text).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_profile() {
+        assert_eq!(
+            add_noise(
+                "Çocuğu İstanbul'da gördüm, ŞÜPHE yok.",
+                Profile::Ascii,
+                1.0,
+                0,
+                Layout::Q
+            ),
+            "Cocugu Istanbul'da gordum, SUPHE yok."
+        );
+    }
+
+    #[test]
+    fn test_zero_rate_keeps_text() {
+        let text = "Her şey yolunda, ben de geldim.";
+        assert_eq!(add_noise(text, Profile::Social, 0.0, 3, Layout::Q), text);
+    }
+
+    #[test]
+    fn test_reverse_confusions_and_clitics() {
+        let mut parts = segments("Her şey yalnız");
+        inject(&mut parts, 0, Error::Confusion, &mut SplitMix64::new(0), Layout::Q);
+        inject(&mut parts, 4, Error::Confusion, &mut SplitMix64::new(0), Layout::Q);
+        let text: String = parts.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(text, "Herşey yanlız");
+
+        let mut parts = segments("ben de evde");
+        inject(&mut parts, 0, Error::Clitic, &mut SplitMix64::new(0), Layout::Q);
+        inject(&mut parts, 4, Error::Clitic, &mut SplitMix64::new(0), Layout::Q);
+        let text: String = parts.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(text, "bende ev de");
+    }
+
+    #[test]
+    fn test_f_keyboard_typos() {
+        // On the F keyboard only ı, o, e, ü, v and c surround a
+        let noisy = add_noise("a", Profile::Keyboard, 1.0, 2, Layout::F);
+        assert!(noisy.chars().all(|c| "aıoeüvc".contains(c)), "{}", noisy);
+    }
+
+    #[test]
+    fn test_keyboard_typos_are_seeded() {
+        let text = "Yarın sabah erkenden toplantıya gideceğim";
+        let noisy = add_noise(text, Profile::Keyboard, 0.5, 11, Layout::Q);
+        assert_eq!(noisy, add_noise(text, Profile::Keyboard, 0.5, 11, Layout::Q));
+        assert_ne!(noisy, text);
+        assert_eq!(noisy.split(' ').count(), text.split(' ').count());
+    }
+}
@@ -0,0 +1,300 @@
+//! Turkish cardinal numbers in words
+//!
+//! Converts between integers and their Turkish spelling (1250 ↔ "bin iki yüz
+//! elli") and finds spelled-out numbers in running text. Turkish leaves out
+//! "bir" before "yüz" and "bin" (yüz, bin, iki bin) but keeps it before the
+//! larger scales (bir milyon). Reading accepts words separated by spaces and
+//! the solid spelling used on cheques ("binikiyüzelli"), and rejects word
+//! sequences that do not spell a number ("iki iki", "bir yüz").
+
+use crate::{fast_normalize, words};
+
+const ONES: [&str; 10] =
+    ["", "bir", "iki", "üç", "dört", "beş", "altı", "yedi", "sekiz", "dokuz"];
+const TENS: [&str; 10] =
+    ["", "on", "yirmi", "otuz", "kırk", "elli", "altmış", "yetmiş", "seksen", "doksan"];
+
+/// Scale words, largest first
+const SCALES: [(u64, &str); 6] = [
+    (1_000_000_000_000_000_000, "kentilyon"),
+    (1_000_000_000_000_000, "katrilyon"),
+    (1_000_000_000_000, "trilyon"),
+    (1_000_000_000, "milyar"),
+    (1_000_000, "milyon"),
+    (1_000, "bin"),
+];
+
+/// Number words that are also everyday words (the article "bir", "altı"
+/// "underside", "yüz" "face", "bin-" "ride", "on" "front"); on their own
+/// they are not reported by `extract`
+const AMBIGUOUS: &[&str] = &["bir", "on", "altı", "yüz", "bin"];
+
+/// A spelled-out number with character offsets into the input text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub text: String,
+    pub value: u64,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// One word of a spelled-out number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Word {
+    Zero,
+    One(u64),
+    Ten(u64),
+    Hundred,
+    Scale(u64),
+}
+
+fn word(text: &str) -> Option<Word> {
+    if text == "sıfır" {
+        return Some(Word::Zero);
+    }
+    if text == "yüz" {
+        return Some(Word::Hundred);
+    }
+    let position = |table: &[&str]| table.iter().skip(1).position(|&w| w == text);
+    if let Some(i) = position(&ONES) {
+        return Some(Word::One(i as u64 + 1));
+    }
+    if let Some(i) = position(&TENS) {
+        return Some(Word::Ten(i as u64 + 1));
+    }
+    SCALES.iter().find(|&&(_, name)| name == text).map(|&(scale, _)| Word::Scale(scale))
+}
+
+/// Split a solidly written number into its words, longest word first
+/// ("üçyüzkırkiki" → üç, yüz, kırk, iki)
+fn split_solid(text: &str) -> Option<Vec<&str>> {
+    let vocabulary = ONES[1..]
+        .iter()
+        .chain(&TENS[1..])
+        .chain(SCALES.iter().map(|(_, name)| name))
+        .chain(&["yüz", "sıfır"]);
+    let mut words = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let next = vocabulary.clone().filter(|w| rest.starts_with(**w)).max_by_key(|w| w.len())?;
+        words.push(&rest[..next.len()]);
+        rest = &rest[next.len()..];
+    }
+    Some(words)
+}
+
+/// Words of a group below one thousand
+fn push_group(words: &mut Vec<&'static str>, n: u64) {
+    let (hundreds, tens, ones) = (n / 100, n / 10 % 10, n % 10);
+    if hundreds > 1 {
+        words.push(ONES[hundreds as usize]);
+    }
+    if hundreds > 0 {
+        words.push("yüz");
+    }
+    if tens > 0 {
+        words.push(TENS[tens as usize]);
+    }
+    if ones > 0 {
+        words.push(ONES[ones as usize]);
+    }
+}
+
+/// Turkish spelling of `n`
+///
+/// # Examples
+/// ```text
+/// to_words(1250)      -> "bin iki yüz elli"
+/// to_words(1_000_001) -> "bir milyon bir"
+/// ```
+pub fn to_words(n: u64) -> String {
+    if n == 0 {
+        return "sıfır".to_string();
+    }
+    let mut words = Vec::new();
+    let mut rest = n;
+    for (scale, name) in SCALES {
+        let group = rest / scale;
+        if group > 0 {
+            if !(scale == 1_000 && group == 1) {
+                push_group(&mut words, group);
+            }
+            words.push(name);
+        }
+        rest %= scale;
+    }
+    push_group(&mut words, rest);
+    words.join(" ")
+}
+
+/// Value of a number spelled in Turkish words, or None when the words do
+/// not spell a number. Case is ignored.
+///
+/// # Examples
+/// ```text
+/// from_words("üç yüz kırk iki") -> Some(342)
+/// from_words("İKİBİN")          -> Some(2000)
+/// from_words("bir yüz")         -> None
+/// ```
+pub fn from_words(text: &str) -> Option<u64> {
+    let lower = fast_normalize(text, true, true);
+    let mut words = Vec::new();
+    for part in lower.split_whitespace() {
+        for w in split_solid(part)? {
+            words.push(word(w)?);
+        }
+    }
+    match words.as_slice() {
+        [] => return None,
+        [Word::Zero] => return Some(0),
+        _ => {}
+    }
+
+    let mut total: u64 = 0;
+    let mut last_scale = u64::MAX;
+    // Hundreds, tens and ones of the group being read
+    let (mut hundreds, mut tens, mut ones) = (None, None, None);
+    for w in words {
+        match w {
+            Word::Zero => return None,
+            Word::One(d) if ones.is_none() => ones = Some(d),
+            Word::Ten(t) if tens.is_none() && ones.is_none() => tens = Some(t),
+            Word::Hundred if hundreds.is_none() && tens.is_none() && ones != Some(1) => {
+                hundreds = Some(ones.take().unwrap_or(1));
+            }
+            Word::Scale(scale) if scale < last_scale => {
+                let empty = hundreds.is_none() && tens.is_none() && ones.is_none();
+                let group = match (empty, scale) {
+                    (true, 1_000) => 1,
+                    (true, _) => return None,
+                    (false, 1_000) if (hundreds, tens, ones) == (None, None, Some(1)) => {
+                        return None
+                    }
+                    (false, _) => {
+                        hundreds.unwrap_or(0) * 100 + tens.unwrap_or(0) * 10 + ones.unwrap_or(0)
+                    }
+                };
+                total = total.checked_add(group.checked_mul(scale)?)?;
+                (hundreds, tens, ones) = (None, None, None);
+                last_scale = scale;
+            }
+            _ => return None,
+        }
+    }
+    total.checked_add(hundreds.unwrap_or(0) * 100 + tens.unwrap_or(0) * 10 + ones.unwrap_or(0))
+}
+
+/// Spelled-out numbers of `text` in order of appearance
+///
+/// Runs of number words separated by whitespace are read as the longest
+/// numbers they spell, so "iki üç" gives two numbers. A lone ambiguous word
+/// ("bir", "yüz", ...) is skipped.
+///
+/// # Examples
+/// ```text
+/// extract("Üç yüz kırk iki kişi geldi") -> [("Üç yüz kırk iki", 342, 0, 15)]
+/// ```
+pub fn extract(text: &str) -> Vec<Number> {
+    // Byte ranges of consecutive number words
+    let mut runs: Vec<Vec<(usize, usize)>> = Vec::new();
+    let mut previous_end = None;
+    for mat in words(text) {
+        let lower = fast_normalize(mat.as_str(), true, true);
+        let is_number = split_solid(&lower).is_some_and(|ws| ws.iter().all(|w| word(w).is_some()));
+        if !is_number {
+            previous_end = None;
+            continue;
+        }
+        let joined = previous_end.is_some_and(|end: usize| {
+            let gap = &text[end..mat.start()];
+            !gap.is_empty() && gap.chars().all(char::is_whitespace)
+        });
+        match runs.last_mut() {
+            Some(run) if joined => run.push((mat.start(), mat.end())),
+            _ => runs.push(vec![(mat.start(), mat.end())]),
+        }
+        previous_end = Some(mat.end());
+    }
+
+    let mut numbers = Vec::new();
+    for run in runs {
+        let mut i = 0;
+        while i < run.len() {
+            let found = (i + 1..=run.len()).rev().find_map(|j| {
+                let (start, end) = (run[i].0, run[j - 1].1);
+                from_words(&text[start..end]).map(|value| (j, start, end, value))
+            });
+            let Some((j, start, end, value)) = found else {
+                i += 1;
+                continue;
+            };
+            let lone = j == i + 1
+                && AMBIGUOUS.contains(&fast_normalize(&text[start..end], true, true).as_str());
+            if !lone {
+                let char_start = text[..start].chars().count();
+                numbers.push(Number {
+                    text: text[start..end].to_string(),
+                    value,
+                    start: char_start,
+                    end: char_start + text[start..end].chars().count(),
+                });
+            }
+            i = j;
+        }
+    }
+    numbers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_words() {
+        assert_eq!(to_words(0), "sıfır");
+        assert_eq!(to_words(1250), "bin iki yüz elli");
+        assert_eq!(to_words(100), "yüz");
+        assert_eq!(to_words(1_000_000), "bir milyon");
+        assert_eq!(to_words(101_011), "yüz bir bin on bir");
+        assert_eq!(to_words(2_000_000_300), "iki milyar üç yüz");
+    }
+
+    #[test]
+    fn test_from_words() {
+        assert_eq!(from_words("üç yüz kırk iki"), Some(342));
+        assert_eq!(from_words("Bin Dokuz Yüz Seksen Dört"), Some(1984));
+        assert_eq!(from_words("binikiyüzelli"), Some(1250));
+        assert_eq!(from_words("İKİ BİN"), Some(2000));
+        assert_eq!(from_words("bir milyon bir"), Some(1_000_001));
+        for invalid in ["", "bir yüz", "bir bin", "iki iki", "on yirmi", "bin milyon", "ev"] {
+            assert_eq!(from_words(invalid), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for n in (0..3000).chain([u64::MAX, 1_000_000_000_000, 900_000_009]) {
+            assert_eq!(from_words(&to_words(n)), Some(n), "{}", to_words(n));
+        }
+    }
+
+    #[test]
+    fn test_extract() {
+        let values = |text: &str| -> Vec<(String, u64)> {
+            extract(text).into_iter().map(|n| (n.text, n.value)).collect()
+        };
+        assert_eq!(
+            values("Üç yüz kırk iki kişi ve iki üç çocuk geldi"),
+            vec![
+                ("Üç yüz kırk iki".to_string(), 342),
+                ("iki".to_string(), 2),
+                ("üç".to_string(), 3)
+            ]
+        );
+        assert!(values("Bir gün masanın altı boştu").is_empty());
+        assert_eq!(values("altı yüz"), vec![("altı yüz".to_string(), 600)]);
+        assert_eq!(values("yüz elli")[0].1, 150);
+        let found = extract("Çarşı: beş bin");
+        assert_eq!((found[0].start, found[0].end), (7, 14));
+    }
+}
@@ -0,0 +1,148 @@
+//! Order-preserving parallel map for batch functions
+//!
+//! Batch functions take a `threads` argument: `1` runs on the calling
+//! thread, `0` uses rayon's global pool (one worker per core) and any other
+//! value runs on a dedicated pool of that size. Pools are built once per
+//! size and reused, so repeated batch calls do not respawn threads. Results
+//! always come back in input order; `map_cancellable` stops early once its
+//! `cancel::Token` is cancelled.
+
+use crate::cancel::{Cancelled, Token};
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Dedicated pools by thread count
+static POOLS: OnceLock<Mutex<HashMap<usize, Arc<ThreadPool>>>> = OnceLock::new();
+
+fn pool(threads: usize) -> Option<Arc<ThreadPool>> {
+    let mut pools = POOLS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(pool) = pools.get(&threads) {
+        return Some(Arc::clone(pool));
+    }
+    // If the OS refuses the threads, callers fall back to the global pool
+    let pool = Arc::new(ThreadPoolBuilder::new().num_threads(threads).build().ok()?);
+    pools.insert(threads, Arc::clone(&pool));
+    Some(pool)
+}
+
+/// Apply `f` to every item across `threads` workers, preserving order
+pub fn map<T, U, F>(items: &[T], threads: usize, f: F) -> Vec<U>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&T) -> U + Sync + Send,
+{
+    if threads == 1 || items.len() < 2 {
+        return items.iter().map(f).collect();
+    }
+    let run = || items.par_iter().map(&f).collect();
+    match (threads > 1).then(|| pool(threads)).flatten() {
+        Some(pool) => pool.install(run),
+        None => run(),
+    }
+}
+
+/// Largest run of items a chunked batch hands to one worker at a time
+const MAX_CHUNK: usize = 256;
+
+/// Items per chunk when a batch is split into chunks for `threads` workers:
+/// about four chunks per worker, so uneven items still balance, and at
+/// most `MAX_CHUNK` so cancellation is noticed quickly
+pub fn chunk_len(items: usize, threads: usize) -> usize {
+    let workers = match threads {
+        0 => rayon::current_num_threads(),
+        threads => threads,
+    };
+    let chunks = workers * 4;
+    (items / chunks + usize::from(items % chunks != 0)).clamp(1, MAX_CHUNK)
+}
+
+/// `map` that skips the remaining items and returns `Cancelled` once
+/// `token` is cancelled
+pub fn map_cancellable<T, U, F>(
+    items: &[T],
+    threads: usize,
+    token: &Token,
+    f: F,
+) -> Result<Vec<U>, Cancelled>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&T) -> U + Sync + Send,
+{
+    let f = |item: &T| (!token.is_cancelled()).then(|| f(item));
+    let results: Option<Vec<U>> = if threads == 1 || items.len() < 2 {
+        items.iter().map(f).collect()
+    } else {
+        let run = || items.par_iter().map(&f).collect();
+        match (threads > 1).then(|| pool(threads)).flatten() {
+            Some(pool) => pool.install(run),
+            None => run(),
+        }
+    };
+    results.ok_or(Cancelled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_preserves_order() {
+        let items: Vec<usize> = (0..1000).collect();
+        let expected: Vec<usize> = items.iter().map(|i| i * 2).collect();
+        for threads in [0, 1, 2, 4] {
+            assert_eq!(map(&items, threads, |i| i * 2), expected);
+        }
+    }
+
+    #[test]
+    fn test_pools_are_reused() {
+        let first = pool(3).unwrap();
+        let second = pool(3).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(first.current_num_threads(), 3);
+    }
+
+    #[test]
+    fn test_chunk_len() {
+        assert_eq!(chunk_len(0, 2), 1);
+        assert_eq!(chunk_len(10, 1), 3);
+        assert_eq!(chunk_len(1000, 2), 125);
+        assert_eq!(chunk_len(1_000_000, 2), MAX_CHUNK);
+    }
+
+    #[test]
+    fn test_map_empty() {
+        let items: Vec<u8> = Vec::new();
+        assert!(map(&items, 0, |b| *b).is_empty());
+    }
+
+    #[test]
+    fn test_map_cancellable() {
+        let items: Vec<usize> = (0..1000).collect();
+        let token = Token::new();
+        for threads in [0, 1, 2] {
+            let doubled = map_cancellable(&items, threads, &token, |i| i * 2);
+            assert_eq!(doubled, Ok(map(&items, 1, |i| i * 2)));
+        }
+        // Cancelling from inside the loop stops the remaining items
+        for threads in [0, 1, 2] {
+            let token = Token::new();
+            let calls = std::sync::atomic::AtomicUsize::new(0);
+            let result = map_cancellable(&items, threads, &token, |&i| {
+                calls.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                if i == 10 {
+                    token.cancel();
+                }
+            });
+            assert_eq!(result, Err(Cancelled));
+            assert!(calls.into_inner() < items.len());
+        }
+    }
+}
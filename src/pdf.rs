@@ -0,0 +1,91 @@
+//! Line-break artifacts of PDF text extraction
+//!
+//! Text extracted from PDFs keeps the hard line wrapping of the page, so
+//! words are broken across lines:
+//!
+//! - Soft hyphens (U+00AD) mark where the typesetter may break a word; they
+//!   are always removed and the word joined
+//! - A hyphen before a line break is a break unless the second half is
+//!   capitalized ("Türk-\nAlman") or both halves are words and the joined
+//!   form is not ("öğretmen-\nöğrenci"); those keep their hyphen
+//! - A line break inside a word without a hyphen is only joined when the
+//!   joined form is a word and the halves are not both words ("kitap\nları"
+//!   joins, "ev\nde" stays)
+//!
+//! Words are checked with the spell checker's morphological analysis.
+
+use crate::spell;
+use regex::{Captures, Regex};
+use std::sync::OnceLock;
+
+const SOFT_HYPHEN: char = '\u{AD}';
+
+static BREAK_REGEX: OnceLock<Regex> = OnceLock::new();
+
+fn break_regex() -> &'static Regex {
+    BREAK_REGEX.get_or_init(|| {
+        Regex::new(r"(\p{L}+)([\x{AD}\-\x{2010}]?)[ \t]*\r?\n[ \t]*(\p{L}+)")
+            .expect("Invalid line break regex")
+    })
+}
+
+fn join(caps: &Captures) -> String {
+    let (left, hyphen, right) = (&caps[1], &caps[2], &caps[3]);
+    let joined = format!("{}{}", left, right);
+    if hyphen.starts_with(SOFT_HYPHEN) {
+        return joined;
+    }
+    if !hyphen.is_empty() && right.chars().next().is_some_and(char::is_uppercase) {
+        return format!("{}{}{}", left, hyphen, right);
+    }
+    let halves_are_words = spell::is_correct(left) && spell::is_correct(right);
+    let joined_is_word = spell::is_correct(&joined);
+    match (hyphen.is_empty(), joined_is_word, halves_are_words) {
+        (false, false, true) => format!("{}{}{}", left, hyphen, right),
+        (false, _, _) => joined,
+        (true, true, false) => joined,
+        (true, _, _) => caps[0].to_string(),
+    }
+}
+
+/// Join words broken across lines and remove soft hyphens
+///
+/// # Examples
+/// ```text
+/// fix_artifacts("kitap-\nları okudum") -> "kitapları okudum"
+/// fix_artifacts("Türk-\nAlman işbirliği") -> "Türk-Alman işbirliği"
+/// fix_artifacts("oku\u{AD}dum") -> "okudum"
+/// ```
+pub fn fix_artifacts(text: &str) -> String {
+    let joined = break_regex().replace_all(text, |caps: &Captures| join(caps));
+    joined.replace(SOFT_HYPHEN, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hyphen_breaks() {
+        assert_eq!(fix_artifacts("kitap-\nları okudum"), "kitapları okudum");
+        assert_eq!(fix_artifacts("Geliyor-  \r\n  lardı"), "Geliyorlardı");
+        assert_eq!(fix_artifacts("Türk-\nAlman işbirliği"), "Türk-Alman işbirliği");
+        assert_eq!(fix_artifacts("öğretmen-\nöğrenci"), "öğretmen-öğrenci");
+        // Unknown words are joined: a hyphen at the end of a line is a break
+        assert_eq!(fix_artifacts("Kılıç-\ndaroğlu"), "Kılıçdaroğlu");
+    }
+
+    #[test]
+    fn test_soft_hyphens() {
+        assert_eq!(fix_artifacts("oku\u{AD}dum"), "okudum");
+        assert_eq!(fix_artifacts("ev\u{AD}\nde"), "evde");
+    }
+
+    #[test]
+    fn test_breaks_without_hyphen() {
+        assert_eq!(fix_artifacts("güzel kitap\nları okudum"), "güzel kitapları okudum");
+        for kept in ["ev\nde", "okudum.\nSonra", "bir\nşey"] {
+            assert_eq!(fix_artifacts(kept), kept);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Lemma-level phrase matching
+//!
+//! A `PhraseMatcher` matches patterns against the lemmas of a token
+//! sequence instead of its surface forms, so "kitap oku" finds "kitabı
+//! okudum" and "kitapları okuyorlar". Patterns are lemmatized like the
+//! text, so the inflection used in a pattern does not matter; the caller
+//! lemmatizes both (see `PhraseMatcher` in lib.rs).
+//!
+//! Pattern tokens must match consecutive tokens. Candidates are found
+//! through an index of first lemmas, so a scan costs one lookup per token
+//! plus the patterns that start there.
+
+use std::collections::HashMap;
+
+/// A pattern found in a token sequence, by token index (end exclusive)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// Index of the pattern in the list the matcher was built from
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PhraseMatcher {
+    /// Lemmas of each distinct pattern, with its input index
+    patterns: Vec<(usize, Vec<String>)>,
+    /// First lemma → positions in `patterns`
+    by_first: HashMap<String, Vec<usize>>,
+}
+
+impl PhraseMatcher {
+    /// Build from the lemma sequences of the patterns; duplicate sequences
+    /// keep their first occurrence. Errors on an empty pattern, with its
+    /// index.
+    pub fn new(patterns: Vec<Vec<String>>) -> Result<Self, String> {
+        let mut matcher = Self::default();
+        let mut seen = std::collections::HashSet::new();
+        for (index, lemmas) in patterns.into_iter().enumerate() {
+            let Some(first) = lemmas.first() else {
+                return Err(format!("empty pattern at index {}", index));
+            };
+            if !seen.insert(lemmas.clone()) {
+                continue;
+            }
+            matcher
+                .by_first
+                .entry(first.clone())
+                .or_default()
+                .push(matcher.patterns.len());
+            matcher.patterns.push((index, lemmas));
+        }
+        Ok(matcher)
+    }
+
+    /// Number of distinct patterns
+    pub fn pattern_count(&self) -> usize {
+        self.patterns.len()
+    }
+
+    /// Matches in a sequence of lemmas, by start then longest first.
+    /// Without `overlapping` only the leftmost-longest matches that do not
+    /// overlap are kept.
+    pub fn find<S: AsRef<str>>(&self, lemmas: &[S], overlapping: bool) -> Vec<Match> {
+        let mut matches = Vec::new();
+        for start in 0..lemmas.len() {
+            let Some(candidates) = self.by_first.get(lemmas[start].as_ref()) else {
+                continue;
+            };
+            for &position in candidates {
+                let (pattern, sequence) = &self.patterns[position];
+                let end = start + sequence.len();
+                if end <= lemmas.len()
+                    && sequence
+                        .iter()
+                        .zip(&lemmas[start..end])
+                        .all(|(a, b)| a == b.as_ref())
+                {
+                    matches.push(Match {
+                        pattern: *pattern,
+                        start,
+                        end,
+                    });
+                }
+            }
+        }
+        matches.sort_by_key(|m| (m.start, std::cmp::Reverse(m.end), m.pattern));
+        if !overlapping {
+            let mut end = 0;
+            matches.retain(|m| {
+                let keep = m.start >= end;
+                if keep {
+                    end = m.end;
+                }
+                keep
+            });
+        }
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lemmas(text: &str) -> Vec<String> {
+        text.split(' ').map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_find() {
+        let matcher = PhraseMatcher::new(vec![
+            lemmas("kitap oku"),
+            lemmas("kitap"),
+            lemmas("oku"),
+            lemmas("kitap oku"),
+        ])
+        .unwrap();
+        assert_eq!(matcher.pattern_count(), 3);
+        let text = lemmas("ben kitap oku ve oku");
+        let found: Vec<(usize, usize, usize)> = matcher
+            .find(&text, false)
+            .into_iter()
+            .map(|m| (m.pattern, m.start, m.end))
+            .collect();
+        assert_eq!(found, [(0, 1, 3), (2, 4, 5)]);
+        assert_eq!(matcher.find(&text, true).len(), 4);
+        assert!(matcher.find(&lemmas("kitap"), false)[0].pattern == 1);
+        assert!(matcher.find::<&str>(&[], false).is_empty());
+        assert_eq!(
+            PhraseMatcher::new(vec![vec![]]).unwrap_err(),
+            "empty pattern at index 0"
+        );
+    }
+}
@@ -0,0 +1,292 @@
+//! Coarse part-of-speech tagging
+//!
+//! Each token is tagged independently, in three steps:
+//!
+//! 1. Surface shape: punctuation and numerals ("3", "1.500", "5'te")
+//! 2. Closed classes: a small lexicon of adverbs, pronouns and number words
+//! 3. Morphology: the final part of speech of the analyzer's preferred parse,
+//!    with adverbial converbs (gel-ip, gel-ince, gel-erek) tagged as adverbs
+//!
+//! Tokens the analyzer cannot parse fall back to a suffix model: the longest
+//! known word ending decides the tag, and anything else is a noun.
+
+use crate::analyzer::{self, Pos};
+use crate::fast_normalize;
+
+/// Coarse part-of-speech tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tag {
+    Noun,
+    Verb,
+    Adj,
+    Adv,
+    Pron,
+    Punc,
+    Num,
+}
+
+impl Tag {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Tag::Noun => "Noun",
+            Tag::Verb => "Verb",
+            Tag::Adj => "Adj",
+            Tag::Adv => "Adv",
+            Tag::Pron => "Pron",
+            Tag::Punc => "Punc",
+            Tag::Num => "Num",
+        }
+    }
+}
+
+impl From<Pos> for Tag {
+    fn from(pos: Pos) -> Self {
+        match pos {
+            Pos::Noun => Tag::Noun,
+            Pos::Adj => Tag::Adj,
+            Pos::Verb => Tag::Verb,
+            Pos::Pron => Tag::Pron,
+        }
+    }
+}
+
+/// Frequent adverbs, which carry no distinguishing morphology
+const ADVERBS: &[&str] = &[
+    "artık",
+    "asla",
+    "az",
+    "bazen",
+    "belki",
+    "birden",
+    "böyle",
+    "bugün",
+    "çabuk",
+    "çok",
+    "daha",
+    "dün",
+    "en",
+    "epey",
+    "gene",
+    "hemen",
+    "henüz",
+    "hep",
+    "hiç",
+    "hâlâ",
+    "hala",
+    "yalnızca",
+    "öyle",
+    "sadece",
+    "şimdi",
+    "şöyle",
+    "tekrar",
+    "yarın",
+    "yine",
+    "zaten",
+];
+
+/// Pronouns beyond the personal and demonstrative ones in the root table
+const PRONOUNS: &[&str] = &[
+    "bazı", "birbiri", "hangi", "hepsi", "herkes", "hiçbiri", "kendi", "kim", "ne", "neler",
+    "nere", "birisi", "şey",
+];
+
+/// Cardinal number words
+const NUMBER_WORDS: &[&str] = &[
+    "sıfır", "bir", "iki", "üç", "dört", "beş", "altı", "yedi", "sekiz", "dokuz", "on", "yirmi",
+    "otuz", "kırk", "elli", "altmış", "yetmiş", "seksen", "doksan", "yüz", "bin", "milyon",
+    "milyar",
+];
+
+/// Converb suffixes that turn a verb into an adverbial clause
+const ADVERBIAL_TAGS: &[&str] = &["AfterDoingSo", "ByDoingSo", "When", "While"];
+
+/// Word endings for unanalyzable tokens, longest match wins
+const SUFFIX_MODEL: &[(&str, Tag)] = &[
+    // Tense and person endings
+    ("yor", Tag::Verb),
+    ("yorum", Tag::Verb),
+    ("yorsun", Tag::Verb),
+    ("yoruz", Tag::Verb),
+    ("yorlar", Tag::Verb),
+    ("acak", Tag::Verb),
+    ("ecek", Tag::Verb),
+    ("acağım", Tag::Verb),
+    ("eceğim", Tag::Verb),
+    ("mış", Tag::Verb),
+    ("miş", Tag::Verb),
+    ("muş", Tag::Verb),
+    ("müş", Tag::Verb),
+    ("malı", Tag::Verb),
+    ("meli", Tag::Verb),
+    ("dım", Tag::Verb),
+    ("dim", Tag::Verb),
+    ("dum", Tag::Verb),
+    ("düm", Tag::Verb),
+    ("tım", Tag::Verb),
+    ("tim", Tag::Verb),
+    ("tum", Tag::Verb),
+    ("tüm", Tag::Verb),
+    ("dık", Tag::Verb),
+    ("dik", Tag::Verb),
+    ("duk", Tag::Verb),
+    ("dük", Tag::Verb),
+    ("mak", Tag::Verb),
+    ("mek", Tag::Verb),
+    // Adjective-forming derivations
+    ("lı", Tag::Adj),
+    ("li", Tag::Adj),
+    ("lu", Tag::Adj),
+    ("lü", Tag::Adj),
+    ("sız", Tag::Adj),
+    ("siz", Tag::Adj),
+    ("suz", Tag::Adj),
+    ("süz", Tag::Adj),
+    ("sal", Tag::Adj),
+    ("sel", Tag::Adj),
+    ("ımsı", Tag::Adj),
+    ("imsi", Tag::Adj),
+    // Adverb-forming derivations
+    ("ca", Tag::Adv),
+    ("ce", Tag::Adv),
+    ("ça", Tag::Adv),
+    ("çe", Tag::Adv),
+    ("casına", Tag::Adv),
+    ("cesine", Tag::Adv),
+    ("ken", Tag::Adv),
+    // Nominal inflection that would otherwise match a shorter ending above
+    ("ları", Tag::Noun),
+    ("leri", Tag::Noun),
+    ("lık", Tag::Noun),
+    ("lik", Tag::Noun),
+    ("luk", Tag::Noun),
+    ("lük", Tag::Noun),
+];
+
+/// Shortest stem left before a suffix-model ending
+const MIN_STEM_CHARS: usize = 2;
+
+/// Numerals: digits with optional separators and an apostrophe suffix
+fn is_number(token: &str) -> bool {
+    let numeral = token.split('\'').next().unwrap_or(token);
+    let numeral = numeral.trim_start_matches('%').trim_end_matches(['.', '%']);
+    numeral.chars().next().is_some_and(|c| c.is_ascii_digit())
+        && numeral
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | ',' | ':' | '/'))
+}
+
+fn is_punctuation(token: &str) -> bool {
+    !token.is_empty() && token.chars().all(|c| !c.is_alphanumeric())
+}
+
+/// Tag from the longest matching ending, if any
+fn suffix_model(word: &str) -> Option<Tag> {
+    let chars = word.chars().count();
+    SUFFIX_MODEL
+        .iter()
+        .filter(|(ending, _)| {
+            word.ends_with(ending) && chars >= ending.chars().count() + MIN_STEM_CHARS
+        })
+        .max_by_key(|(ending, _)| ending.len())
+        .map(|&(_, tag)| tag)
+}
+
+/// Tag a single token
+///
+/// # Examples
+/// ```text
+/// tag_token("kitaplarımdan") -> Noun
+/// tag_token("gelince")       -> Adv
+/// tag_token("1.500")         -> Num
+/// ```
+pub fn tag_token(token: &str) -> Tag {
+    if is_punctuation(token) {
+        return Tag::Punc;
+    }
+    if is_number(token) {
+        return Tag::Num;
+    }
+
+    // Proper nouns keep their suffixes behind an apostrophe (İstanbul'da)
+    let word = fast_normalize(token.split('\'').next().unwrap_or(token), true, true);
+    if ADVERBS.contains(&word.as_str()) {
+        return Tag::Adv;
+    }
+    if PRONOUNS.contains(&word.as_str()) {
+        return Tag::Pron;
+    }
+    if NUMBER_WORDS.contains(&word.as_str()) {
+        return Tag::Num;
+    }
+
+    if let Some(best) = analyzer::analyze(&word).into_iter().next() {
+        let adverbial = best
+            .morphemes
+            .last()
+            .is_some_and(|m| ADVERBIAL_TAGS.contains(&m.tag));
+        return if adverbial {
+            Tag::Adv
+        } else {
+            best.final_pos().into()
+        };
+    }
+
+    suffix_model(&word).unwrap_or(Tag::Noun)
+}
+
+/// Tag a sequence of tokens, one tag per token
+pub fn pos_tag<'a>(tokens: impl IntoIterator<Item = &'a str>) -> Vec<Tag> {
+    tokens.into_iter().map(tag_token).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_surface_classes() {
+        assert_eq!(tag_token("."), Tag::Punc);
+        assert_eq!(tag_token("..."), Tag::Punc);
+        assert_eq!(tag_token("3"), Tag::Num);
+        assert_eq!(tag_token("1.500"), Tag::Num);
+        assert_eq!(tag_token("5'te"), Tag::Num);
+        assert_eq!(tag_token("%50"), Tag::Num);
+    }
+
+    #[test]
+    fn test_closed_classes() {
+        assert_eq!(tag_token("çok"), Tag::Adv);
+        assert_eq!(tag_token("Hemen"), Tag::Adv);
+        assert_eq!(tag_token("kim"), Tag::Pron);
+        assert_eq!(tag_token("iki"), Tag::Num);
+    }
+
+    #[test]
+    fn test_morphology() {
+        assert_eq!(tag_token("kitaplarımdan"), Tag::Noun);
+        assert_eq!(tag_token("geliyorum"), Tag::Verb);
+        assert_eq!(tag_token("güzel"), Tag::Adj);
+        assert_eq!(tag_token("evli"), Tag::Adj);
+        assert_eq!(tag_token("bana"), Tag::Pron);
+        assert_eq!(tag_token("gelince"), Tag::Adv);
+        assert_eq!(tag_token("gelen"), Tag::Adj);
+    }
+
+    #[test]
+    fn test_oov_fallback() {
+        assert_eq!(tag_token("koşturuyor"), Tag::Verb);
+        assert_eq!(tag_token("uluslararası"), Tag::Noun);
+        assert_eq!(tag_token("kokulu"), Tag::Adj);
+        assert_eq!(tag_token("sessizce"), Tag::Adv);
+        assert_eq!(tag_token("bilgisayar"), Tag::Noun);
+    }
+
+    #[test]
+    fn test_pos_tag() {
+        let tags = pos_tag(["Ben", "İstanbul'a", "yarın", "gideceğim", "."]);
+        assert_eq!(
+            tags,
+            vec![Tag::Pron, Tag::Noun, Tag::Adv, Tag::Verb, Tag::Punc]
+        );
+    }
+}
@@ -0,0 +1,144 @@
+//! Text statistics and readability
+//!
+//! - Syllabification by the Turkish rule that every syllable holds one vowel
+//!   and a single consonant before a vowel opens the next syllable
+//!   (ki-tap, ars-lan, kork-tu); clusters at the start of loanwords stay in
+//!   the first syllable (tren, spor)
+//! - Counts of tokens, word types and sentences with mean word and sentence
+//!   lengths
+//! - Ateşman's readability formula for Turkish (1997), an adaptation of the
+//!   Flesch reading ease:
+//!   198.825 − 40.175 × syllables per word − 2.610 × words per sentence.
+//!   Scores run from about 90–100 (very easy) to below 30 (very difficult).
+
+use crate::vowel_harmony;
+use std::collections::HashSet;
+
+/// Ateşman's coefficients: constant, syllables per word, words per sentence
+const ATESMAN: (f64, f64, f64) = (198.825, 40.175, 2.610);
+
+fn is_vowel(c: char) -> bool {
+    vowel_harmony::get_vowel_class(c).is_some() || matches!(c, 'â' | 'î' | 'û' | 'Â' | 'Î' | 'Û')
+}
+
+/// Split a word into syllables; a word without vowels is one syllable
+///
+/// # Examples
+/// ```text
+/// syllabify("kitaplar") -> ["ki", "tap", "lar"]
+/// syllabify("Türkçe")   -> ["Türk", "çe"]
+/// syllabify("saat")     -> ["sa", "at"]
+/// ```
+pub fn syllabify(word: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = word.char_indices().collect();
+    let vowels: Vec<usize> = (0..chars.len()).filter(|&i| is_vowel(chars[i].1)).collect();
+    let mut syllables = Vec::new();
+    let mut start = 0;
+    for pair in vowels.windows(2) {
+        let (previous, next) = (pair[0], pair[1]);
+        // One consonant moves to the next syllable; adjacent vowels split
+        let boundary = if next - previous > 1 { next - 1 } else { next };
+        let byte = chars[boundary].0;
+        syllables.push(&word[start..byte]);
+        start = byte;
+    }
+    if start < word.len() {
+        syllables.push(&word[start..]);
+    }
+    syllables
+}
+
+/// Number of syllables of a word, at least one
+pub fn syllable_count(word: &str) -> usize {
+    word.chars().filter(|&c| is_vowel(c)).count().max(1)
+}
+
+/// Size and readability statistics of a text
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextStats {
+    /// Tokens of the tokenizer, punctuation and emoji included
+    pub tokens: usize,
+    /// Word tokens
+    pub words: usize,
+    /// Distinct lowercased words
+    pub types: usize,
+    pub sentences: usize,
+    /// Mean characters per word
+    pub avg_word_length: f64,
+    pub avg_syllables_per_word: f64,
+    /// Mean words per sentence
+    pub avg_sentence_length: f64,
+    /// Ateşman readability score, 0.0 for text without words
+    pub atesman: f64,
+}
+
+/// Statistics of a text given its token count, word tokens and number of
+/// sentences; words are lowercased by the caller for the type count
+pub fn text_stats<'a>(
+    tokens: usize,
+    words: impl IntoIterator<Item = &'a str>,
+    sentences: usize,
+) -> TextStats {
+    let mut types = HashSet::new();
+    let (mut total, mut chars, mut syllables) = (0, 0, 0);
+    for word in words {
+        total += 1;
+        chars += word.chars().count();
+        syllables += syllable_count(word);
+        types.insert(word);
+    }
+    let mean = |count: usize, per: usize| if per == 0 { 0.0 } else { count as f64 / per as f64 };
+    let avg_syllables_per_word = mean(syllables, total);
+    let avg_sentence_length = mean(total, sentences);
+    let (constant, syllable_weight, sentence_weight) = ATESMAN;
+    let atesman = if total == 0 {
+        0.0
+    } else {
+        constant - syllable_weight * avg_syllables_per_word - sentence_weight * avg_sentence_length
+    };
+    TextStats {
+        tokens,
+        words: total,
+        types: types.len(),
+        sentences,
+        avg_word_length: mean(chars, total),
+        avg_syllables_per_word,
+        avg_sentence_length,
+        atesman,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_syllabify() {
+        assert_eq!(syllabify("kitaplar"), vec!["ki", "tap", "lar"]);
+        assert_eq!(syllabify("arslan"), vec!["ars", "lan"]);
+        assert_eq!(syllabify("korktu"), vec!["kork", "tu"]);
+        assert_eq!(syllabify("Türkçe"), vec!["Türk", "çe"]);
+        assert_eq!(syllabify("saat"), vec!["sa", "at"]);
+        assert_eq!(syllabify("tren"), vec!["tren"]);
+        assert_eq!(syllabify("İstanbul"), vec!["İs", "tan", "bul"]);
+        assert_eq!(syllabify("tmm"), vec!["tmm"]);
+        assert_eq!(syllable_count("tmm"), 1);
+    }
+
+    #[test]
+    fn test_text_stats() {
+        let words = ["ali", "okula", "gitti", "ali", "eve", "geldi"];
+        let stats = text_stats(8, words, 2);
+        assert_eq!((stats.tokens, stats.words, stats.types), (8, 6, 5));
+        assert_eq!(stats.avg_syllables_per_word, 13.0 / 6.0);
+        assert_eq!(stats.avg_sentence_length, 3.0);
+        let expected = 198.825 - 40.175 * 13.0 / 6.0 - 2.610 * 3.0;
+        assert!((stats.atesman - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_empty_text() {
+        let stats = text_stats(0, std::iter::empty(), 0);
+        assert_eq!((stats.words, stats.avg_word_length, stats.atesman), (0, 0.0, 0.0));
+    }
+}
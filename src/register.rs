@@ -0,0 +1,164 @@
+//! Formal vs. informal register scoring
+//!
+//! Scores how formal a text reads from lexical cues of informal register:
+//!
+//! - Slang, interjections and chat abbreviations listed in
+//!   resources/tr/config/slang.txt (kanka, valla, tmm, slm)
+//! - 2nd person singular address: the pronoun "sen" and finite verbs with
+//!   the A2sg ending (geldin, gidiyorsun); formal Turkish addresses the
+//!   reader with the plural
+//! - Colloquial contractions of verb suffixes: progressive "-yo" for "-yor"
+//!   (geliyom, napıyon) and the future without its vowel (gelcem, yapcaz)
+//! - Emoji, counted by the caller from the token spans
+//!
+//! Each word counts as at most one cue. The formality score decays with the
+//! share of cues per word, from 1.0 for text without cues towards 0.0.
+
+use crate::agreement;
+use crate::analyzer;
+use crate::fast_normalize;
+use regex::Regex;
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+static SLANG_DATA: &str = include_str!("../resources/tr/config/slang.txt");
+
+static SLANG: OnceLock<HashSet<&'static str>> = OnceLock::new();
+static CONTRACTION_REGEX: OnceLock<Regex> = OnceLock::new();
+
+const SECOND_PERSON_PRONOUNS: &[&str] =
+    &["sen", "seni", "sana", "sende", "senden", "senin", "seninle", "senle"];
+
+/// Score decay per cue and word: one cue every ten words gives about 0.61,
+/// one every five words about 0.37
+const DECAY: f64 = 5.0;
+
+/// Informal cues of a text and its formality score
+#[derive(Debug, Clone, PartialEq)]
+pub struct Register {
+    /// Number of words considered
+    pub words: usize,
+    /// Words of the informal lexicon
+    pub slang: usize,
+    /// 2nd person singular pronouns and verb forms
+    pub second_person: usize,
+    /// Colloquially contracted verb forms
+    pub contractions: usize,
+    /// Emoji, as counted by the caller
+    pub emoji: usize,
+    /// 1.0 for text without informal cues, approaching 0.0 as they dominate
+    pub formality: f64,
+}
+
+impl Register {
+    /// Emoji per word
+    pub fn emoji_density(&self) -> f64 {
+        if self.words == 0 {
+            0.0
+        } else {
+            self.emoji as f64 / self.words as f64
+        }
+    }
+}
+
+fn slang() -> &'static HashSet<&'static str> {
+    SLANG.get_or_init(|| {
+        SLANG_DATA
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect()
+    })
+}
+
+fn contraction_regex() -> &'static Regex {
+    CONTRACTION_REGEX.get_or_init(|| {
+        Regex::new(concat!(
+            // Progressive "-yo" for "-yor": geliyom, napıyon, geliyosunuz
+            r"^(?:\p{L}+[ıiuü]yo(?:[mnz]|k|sun|sunuz|nuz|lar)?",
+            // Future without its vowel: gelcem, yapcaz, gelicem
+            r"|\p{L}+(?:[^aeıioöuü]|[ıiuü])c[ae][mz])$",
+        ))
+        .expect("Invalid contraction regex")
+    })
+}
+
+fn is_second_person(word: &str) -> bool {
+    SECOND_PERSON_PRONOUNS.contains(&word)
+        || analyzer::analyze(word)
+            .iter()
+            .find(|a| agreement::is_finite(a))
+            .is_some_and(|a| a.morphemes.iter().any(|m| m.tag == "A2sg"))
+}
+
+/// Score the register of `words`, with `emoji` emoji in the same text
+///
+/// # Examples
+/// ```text
+/// register(["Toplantı", "yarın", "yapılacaktır"], 0).formality -> 1.0
+/// register(["kanka", "gelcem", "bekle"], 1).formality          -> 0.0067
+/// ```
+pub fn register<'a>(words: impl IntoIterator<Item = &'a str>, emoji: usize) -> Register {
+    let (mut total, mut slang_count, mut second_person, mut contractions) = (0, 0, 0, 0);
+    for word in words {
+        total += 1;
+        let lower = fast_normalize(word, true, true);
+        if slang().contains(lower.as_str()) {
+            slang_count += 1;
+        } else if contraction_regex().is_match(&lower) {
+            contractions += 1;
+        } else if is_second_person(&lower) {
+            second_person += 1;
+        }
+    }
+    let cues = slang_count + second_person + contractions + emoji;
+    let rate = if total == 0 {
+        cues as f64
+    } else {
+        cues as f64 / total as f64
+    };
+    Register {
+        words: total,
+        slang: slang_count,
+        second_person,
+        contractions,
+        emoji,
+        formality: (-DECAY * rate).exp(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cues(text: &str) -> (usize, usize, usize) {
+        let r = register(text.split_whitespace(), 0);
+        (r.slang, r.second_person, r.contractions)
+    }
+
+    #[test]
+    fn test_cues() {
+        assert_eq!(cues("Kanka valla TMM"), (3, 0, 0));
+        assert_eq!(cues("sen dün geldin mi"), (0, 2, 0));
+        assert_eq!(cues("geliyom napıyon gelcem yapcaz"), (0, 0, 4));
+        assert_eq!(cues("geliyorum radyo gelecek ilçem gittiniz"), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_formality() {
+        let formal = register("Toplantı yarın saat onda yapılacaktır".split(' '), 0);
+        assert_eq!(formal.formality, 1.0);
+        let informal = register("kanka geliyom bekle".split(' '), 1);
+        assert!(informal.formality < 0.01);
+        assert!((informal.emoji_density() - 1.0 / 3.0).abs() < 1e-9);
+        let mixed = register("Yarın toplantıya katılacağız ama sen".split(' '), 0);
+        assert!(mixed.formality > 0.3 && mixed.formality < 0.5);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let empty = register(std::iter::empty(), 0);
+        assert_eq!((empty.words, empty.formality), (0, 1.0));
+        assert_eq!(empty.emoji_density(), 0.0);
+    }
+}
@@ -0,0 +1,168 @@
+//! Resource overrides
+//!
+//! Deployments can swap linguistic data without rebuilding: a list of user
+//! directories is layered over the resources embedded at build time. Each
+//! overridable resource is a file at the same relative path as under
+//! `resources/tr` (see `RESOURCES`); the first directory that has the file
+//! wins and replaces the embedded data as a whole, and resources no
+//! directory has keep the embedded data.
+//!
+//! Only the resources in `RESOURCES` can be overridden, and every lookup of
+//! them goes through this module: the lemma dictionary (lemmatization,
+//! `Doc`, segmentation), the base stopwords (`Token.is_stop`, segmentation,
+//! spell checking) and the detached suffixes `attach_detached_suffixes`
+//! joins. Everything else is compiled in, notably the suffix inventory of
+//! the suffix stripper, the analyzer's roots (`lemmas/root_pos.txt`, from
+//! which the spelling suggestions are generated) and the other word lists.
+//!
+//! `install` swaps the active set atomically, so lookups running on other
+//! threads see either the old or the new data, never a mix.
+
+use std::collections::{BTreeMap, HashSet};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Overridable resources as (name, path relative to a resource directory)
+pub const RESOURCES: [(&str, &str); 3] = [
+    ("lemmas", "lemmas/turkish_lemma_dict.txt"),
+    ("stopwords", "stopwords/base/turkish.txt"),
+    ("detached_suffixes", "labels/DETACHED_SUFFIXES.txt"),
+];
+
+/// Lemma dictionary read from a user directory
+#[derive(Debug, Default)]
+pub struct LemmaTable {
+    /// Inflected form → lemma
+    pub forms: BTreeMap<String, String>,
+    pub lemmas: HashSet<String>,
+}
+
+/// Data replacing embedded resources, with the file each came from
+#[derive(Debug, Default)]
+pub struct Overrides {
+    pub lemmas: Option<Arc<LemmaTable>>,
+    pub stopwords: Option<Arc<HashSet<String>>>,
+    pub detached_suffixes: Option<Arc<Vec<String>>>,
+    pub sources: Vec<(&'static str, PathBuf)>,
+}
+
+static ACTIVE: OnceLock<RwLock<Arc<Overrides>>> = OnceLock::new();
+// Lets lookups skip the lock while nothing is overridden
+static ANY: AtomicBool = AtomicBool::new(false);
+
+fn active() -> &'static RwLock<Arc<Overrides>> {
+    ACTIVE.get_or_init(|| RwLock::new(Arc::new(Overrides::default())))
+}
+
+/// The active overrides, None when every resource is embedded
+pub fn current() -> Option<Arc<Overrides>> {
+    if !ANY.load(Ordering::Acquire) {
+        return None;
+    }
+    let overrides = active().read().unwrap_or_else(|e| e.into_inner());
+    Some(Arc::clone(&overrides))
+}
+
+/// The installed lemma dictionary, None when the embedded one is used
+pub fn lemmas() -> Option<Arc<LemmaTable>> {
+    current()?.lemmas.clone()
+}
+
+/// The installed base stopwords, None when the embedded list is used
+pub fn stopwords() -> Option<Arc<HashSet<String>>> {
+    current()?.stopwords.clone()
+}
+
+/// The installed detached suffixes, None when the embedded list is used
+pub fn detached_suffixes() -> Option<Arc<Vec<String>>> {
+    current()?.detached_suffixes.clone()
+}
+
+/// Make `overrides` the active set
+pub fn install(overrides: Overrides) {
+    let any = !overrides.sources.is_empty();
+    *active().write().unwrap_or_else(|e| e.into_inner()) = Arc::new(overrides);
+    ANY.store(any, Ordering::Release);
+}
+
+/// Non-empty, non-comment lines of a resource file, trimmed
+fn entries(text: &str) -> impl Iterator<Item = &str> {
+    text.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+fn parse_lemmas(text: &str, path: &Path) -> io::Result<LemmaTable> {
+    let mut table = LemmaTable::default();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((form, lemma)) = line.split_once('\t') else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}:{}: expected 'form<TAB>lemma'", path.display(), number + 1),
+            ));
+        };
+        table.lemmas.insert(lemma.trim().to_string());
+        table.forms.insert(form.trim().to_string(), lemma.trim().to_string());
+    }
+    Ok(table)
+}
+
+/// Read the overrides of `directories`, earlier directories first
+pub fn load(directories: &[PathBuf]) -> io::Result<Overrides> {
+    let mut overrides = Overrides::default();
+    for (name, relative) in RESOURCES {
+        let Some(path) = directories.iter().map(|dir| dir.join(relative)).find(|p| p.is_file())
+        else {
+            continue;
+        };
+        let text = std::fs::read_to_string(&path)?;
+        match name {
+            "lemmas" => overrides.lemmas = Some(Arc::new(parse_lemmas(&text, &path)?)),
+            "stopwords" => {
+                overrides.stopwords = Some(Arc::new(entries(&text).map(str::to_string).collect()))
+            }
+            _ => {
+                overrides.detached_suffixes =
+                    Some(Arc::new(entries(&text).map(str::to_string).collect()))
+            }
+        }
+        overrides.sources.push((name, path));
+    }
+    Ok(overrides)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_precedence() {
+        let root = std::env::temp_dir().join(format!("durak-resources-{}", std::process::id()));
+        let (first, second) = (root.join("first"), root.join("second"));
+        std::fs::create_dir_all(first.join("stopwords/base")).unwrap();
+        std::fs::create_dir_all(second.join("stopwords/base")).unwrap();
+        std::fs::create_dir_all(second.join("lemmas")).unwrap();
+        std::fs::write(first.join("stopwords/base/turkish.txt"), "# ours\nve\nama\n").unwrap();
+        std::fs::write(second.join("stopwords/base/turkish.txt"), "hiç\n").unwrap();
+        std::fs::write(second.join("lemmas/turkish_lemma_dict.txt"), "gözlükçü\tgözlük\n").unwrap();
+
+        let overrides = load(&[first.clone(), second.clone()]).unwrap();
+        let stopwords = overrides.stopwords.unwrap();
+        assert_eq!(*stopwords, ["ve", "ama"].iter().map(|s| s.to_string()).collect());
+        let lemmas = overrides.lemmas.unwrap();
+        assert_eq!(lemmas.forms["gözlükçü"], "gözlük");
+        assert!(lemmas.lemmas.contains("gözlük"));
+        assert!(overrides.detached_suffixes.is_none());
+        let names: Vec<&str> = overrides.sources.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, ["lemmas", "stopwords"]);
+
+        std::fs::write(second.join("lemmas/turkish_lemma_dict.txt"), "ok\nbad line\n").unwrap();
+        let error = load(&[second]).unwrap_err();
+        assert!(error.to_string().ends_with("turkish_lemma_dict.txt:1: expected 'form<TAB>lemma'"));
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,312 @@
+//! Token rewrite rules
+//!
+//! A small transducer over token lists for house-style normalizations. A
+//! rules file has one rule per line, `pattern => replacement`, with `#`
+//! comments:
+//!
+//! ```text
+//! TL => lira
+//! <NUMBER> TL => $1 lira
+//! bkz . => bakınız
+//! <DATE> => $1:iso
+//! yani =>
+//! ```
+//!
+//! A pattern is a sequence of tokens: literals match case-insensitively
+//! (Turkish I/ı aware) and `<TYPE>` matches any token of a
+//! `tokenize_with_types` type (`<NUMBER>`, `<DATE>`, `<URL>`). The
+//! replacement is a sequence of tokens, possibly empty: literals are copied,
+//! `$n` copies the n-th matched token, `$n:lower` lowercases it and `$n:iso`
+//! turns a DATE or TIME token into its ISO 8601 form. A leading backslash
+//! makes `<`, `$` or `\` literal.
+//!
+//! Rules are applied left to right in one pass: at each position the
+//! longest matching pattern wins, earlier rules break ties, and rewritten
+//! tokens are not matched again.
+
+use crate::datetime_tr;
+use crate::{fast_normalize, token_kind, token_kind_name};
+use std::cell::OnceCell;
+use std::collections::HashMap;
+use std::fmt;
+
+/// One element of a pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Element {
+    /// A token, normalized for matching
+    Literal(String),
+    /// Any token of a type
+    Kind(&'static str),
+}
+
+/// How a captured token is copied into the replacement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Transform {
+    Copy,
+    Lower,
+    Iso,
+}
+
+/// One element of a replacement
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Output {
+    Literal(String),
+    /// Matched token by 0-based pattern position
+    Capture(usize, Transform),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Rule {
+    pattern: Vec<Element>,
+    replacement: Vec<Output>,
+}
+
+/// A malformed rule, with its 1-based line number
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// A compiled rule set
+#[derive(Debug, Clone, Default)]
+pub struct Rules {
+    rules: Vec<Rule>,
+    /// Rules by their first literal
+    by_literal: HashMap<String, Vec<usize>>,
+    /// Rules starting with a token type
+    by_kind: Vec<usize>,
+}
+
+fn unescape(token: &str) -> &str {
+    token.strip_prefix('\\').unwrap_or(token)
+}
+
+fn parse_element(token: &str) -> Result<Element, String> {
+    match token
+        .strip_prefix('<')
+        .and_then(|rest| rest.strip_suffix('>'))
+    {
+        Some(label) => token_kind_name(label)
+            .map(Element::Kind)
+            .ok_or_else(|| format!("unknown token type <{}>", label)),
+        None => Ok(Element::Literal(fast_normalize(
+            unescape(token),
+            true,
+            true,
+        ))),
+    }
+}
+
+fn parse_output(token: &str, pattern_len: usize) -> Result<Output, String> {
+    let Some(capture) = token.strip_prefix('$') else {
+        return Ok(Output::Literal(unescape(token).to_string()));
+    };
+    let (number, transform) = match capture.split_once(':') {
+        None => (capture, Transform::Copy),
+        Some((number, "lower")) => (number, Transform::Lower),
+        Some((number, "iso")) => (number, Transform::Iso),
+        Some((_, other)) => return Err(format!("unknown transform ':{}'", other)),
+    };
+    match number.parse::<usize>() {
+        Ok(n) if (1..=pattern_len).contains(&n) => Ok(Output::Capture(n - 1, transform)),
+        _ => Err(format!(
+            "'{}' does not name a pattern token (1 to {})",
+            token, pattern_len
+        )),
+    }
+}
+
+fn parse_rule(line: &str) -> Result<Rule, String> {
+    let (pattern, replacement) = line
+        .split_once("=>")
+        .ok_or_else(|| "expected 'pattern => replacement'".to_string())?;
+    let pattern = pattern
+        .split_whitespace()
+        .map(parse_element)
+        .collect::<Result<Vec<_>, _>>()?;
+    if pattern.is_empty() {
+        return Err("empty pattern".to_string());
+    }
+    let replacement = replacement
+        .split_whitespace()
+        .map(|token| parse_output(token, pattern.len()))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Rule {
+        pattern,
+        replacement,
+    })
+}
+
+impl Rules {
+    /// Compile the rules of a rules file
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut rules = Rules::default();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let rule = parse_rule(line).map_err(|message| ParseError {
+                line: number + 1,
+                message,
+            })?;
+            let index = rules.rules.len();
+            match &rule.pattern[0] {
+                Element::Literal(literal) => rules
+                    .by_literal
+                    .entry(literal.clone())
+                    .or_default()
+                    .push(index),
+                Element::Kind(_) => rules.by_kind.push(index),
+            }
+            rules.rules.push(rule);
+        }
+        Ok(rules)
+    }
+
+    /// Number of rules
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Rewrite a token list
+    ///
+    /// # Examples
+    /// ```text
+    /// Rules::parse("<NUMBER> TL => $1 lira").apply(["50", "TL", "verdim"])
+    ///     -> ["50", "lira", "verdim"]
+    /// ```
+    pub fn apply<S: AsRef<str>>(&self, tokens: &[S]) -> Vec<String> {
+        let norms: Vec<String> = tokens
+            .iter()
+            .map(|t| fast_normalize(t.as_ref(), true, true))
+            .collect();
+        // Types are only computed for tokens a <TYPE> element is tried on
+        let kinds: Vec<OnceCell<Option<&'static str>>> =
+            tokens.iter().map(|_| OnceCell::new()).collect();
+        let kind = |i: usize| *kinds[i].get_or_init(|| token_kind(tokens[i].as_ref()));
+        let matches = |rule: &Rule, at: usize| {
+            at + rule.pattern.len() <= tokens.len()
+                && rule
+                    .pattern
+                    .iter()
+                    .enumerate()
+                    .all(|(offset, element)| match element {
+                        Element::Literal(literal) => norms[at + offset] == *literal,
+                        Element::Kind(name) => kind(at + offset) == Some(*name),
+                    })
+        };
+
+        let mut output = Vec::with_capacity(tokens.len());
+        let mut i = 0;
+        while i < tokens.len() {
+            let literal_rules = self.by_literal.get(&norms[i]).map(Vec::as_slice);
+            let best = literal_rules
+                .unwrap_or_default()
+                .iter()
+                .chain(&self.by_kind)
+                .map(|&index| (index, &self.rules[index]))
+                .filter(|(_, rule)| matches(rule, i))
+                .max_by_key(|&(index, rule)| (rule.pattern.len(), std::cmp::Reverse(index)));
+            let Some((_, rule)) = best else {
+                output.push(tokens[i].as_ref().to_string());
+                i += 1;
+                continue;
+            };
+            for element in &rule.replacement {
+                output.push(match *element {
+                    Output::Literal(ref literal) => literal.clone(),
+                    Output::Capture(offset, transform) => {
+                        let token = tokens[i + offset].as_ref();
+                        match transform {
+                            Transform::Copy => token.to_string(),
+                            Transform::Lower => norms[i + offset].clone(),
+                            Transform::Iso => iso(token, kind(i + offset)),
+                        }
+                    }
+                });
+            }
+            i += rule.pattern.len();
+        }
+        output
+    }
+}
+
+/// ISO 8601 form of a DATE or TIME token; other tokens are kept
+fn iso(token: &str, kind: Option<&str>) -> String {
+    let kind = match kind {
+        Some("DATE") => datetime_tr::Kind::Date,
+        Some("TIME") => datetime_tr::Kind::Time,
+        _ => return token.to_string(),
+    };
+    datetime_tr::normalize(token, kind).unwrap_or_else(|| token.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rewrite(rules: &str, tokens: &[&str]) -> Vec<String> {
+        Rules::parse(rules).unwrap().apply(tokens)
+    }
+
+    #[test]
+    fn test_literal_and_type_patterns() {
+        let rules = "# house style\nTL => lira\n<NUMBER> TL => $1 lira\nbkz . => bakınız\n";
+        assert_eq!(
+            rewrite(rules, &["50", "TL", "ödedim"]),
+            ["50", "lira", "ödedim"]
+        );
+        assert_eq!(rewrite(rules, &["tl", "ile"]), ["lira", "ile"]);
+        assert_eq!(rewrite(rules, &["Bkz", ".", "ek"]), ["bakınız", "ek"]);
+        // A partial match leaves the tokens alone
+        assert_eq!(rewrite(rules, &["bkz", "ek"]), ["bkz", "ek"]);
+    }
+
+    #[test]
+    fn test_longest_match_and_rule_order() {
+        let rules = "yeni => NEW\nyeni york => New_York\nyeni => new\n";
+        assert_eq!(
+            rewrite(rules, &["Yeni", "York", "yeni"]),
+            ["New_York", "NEW"]
+        );
+        // Rewritten tokens are not matched again
+        assert_eq!(rewrite("a => b\nb => c", &["a"]), ["b"]);
+    }
+
+    #[test]
+    fn test_replacements() {
+        assert_eq!(rewrite("<DATE> => $1:iso", &["12.03.2024"]), ["2024-03-12"]);
+        assert_eq!(rewrite("<TIME> => $1:iso", &["saat 9"]), ["09:00"]);
+        assert_eq!(
+            rewrite("<WORD> ! => $1:lower", &["HARİKA", "!"]),
+            ["harika"]
+        );
+        assert_eq!(rewrite("yani =>", &["yani", "geldi"]), ["geldi"]);
+        assert_eq!(rewrite(r"\<br> => \$", &["<br>"]), ["$"]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |rules: &str| Rules::parse(rules).unwrap_err().to_string();
+        assert_eq!(
+            error("a => b\nno arrow"),
+            "line 2: expected 'pattern => replacement'"
+        );
+        assert_eq!(error("=> b"), "line 1: empty pattern");
+        assert_eq!(error("<NUM> => x"), "line 1: unknown token type <NUM>");
+        assert_eq!(
+            error("a b => $3"),
+            "line 1: '$3' does not name a pattern token (1 to 2)"
+        );
+        assert_eq!(error("a => $1:upper"), "line 1: unknown transform ':upper'");
+        assert_eq!(Rules::parse("# only comments\n\n").unwrap().len(), 0);
+    }
+}
@@ -3,8 +3,7 @@
 //! Validates candidate roots after suffix stripping to prevent
 //! over-stripping and ensure linguistically valid outputs.
 
-use std::collections::HashSet;
-use std::sync::OnceLock;
+use crate::lemma_dict;
 
 /// Turkish vowels (both lowercase and uppercase)
 const TURKISH_VOWELS: &[char] = &[
@@ -29,30 +28,6 @@ const BOUND_STEMS: &[&str] = &[
     "görü", "bilü",
 ];
 
-/// Embedded valid roots from lemma dictionary
-static LEMMA_DICT_DATA: &str = include_str!("../resources/tr/lemmas/turkish_lemma_dict.txt");
-static VALID_ROOTS: OnceLock<HashSet<String>> = OnceLock::new();
-
-/// Get valid root words from lemma dictionary
-pub fn get_valid_roots() -> &'static HashSet<String> {
-    VALID_ROOTS.get_or_init(|| {
-        let mut roots = HashSet::new();
-
-        for line in LEMMA_DICT_DATA.lines() {
-            let line = line.trim();
-            if line.is_empty() || line.starts_with('#') {
-                continue;
-            }
-
-            if let Some((_, lemma)) = line.split_once('\t') {
-                roots.insert(lemma.trim().to_string());
-            }
-        }
-
-        roots
-    })
-}
-
 /// Root validity checker for Turkish morphology
 pub struct RootValidator {
     /// Minimum acceptable root length (characters)
@@ -96,7 +71,7 @@ impl RootValidator {
 
         // 3. Strict mode: must be in known roots dictionary
         if self.strict {
-            return get_valid_roots().contains(candidate);
+            return lemma_dict::is_lemma(candidate);
         }
 
         // 4. Lenient mode: check linguistic constraints
@@ -138,7 +113,7 @@ impl RootValidator {
 
         // Voiceless stops at word end are valid in Turkish
         if VOICELESS_STOPS.contains(last_char) {
-            return lower_chars.len() >= 3 && self.has_valid_syllable_structure(&lower_chars);
+            return self.has_valid_syllable_structure(&lower_chars);
         }
 
         true
@@ -161,7 +136,7 @@ impl RootValidator {
         }
 
         let vowel_ratio = vowel_count as f32 / chars.len() as f32;
-        vowel_ratio >= 0.2 && vowel_ratio <= 0.7
+        (0.2..=0.7).contains(&vowel_ratio)
     }
 }
 
@@ -210,6 +185,14 @@ mod tests {
         assert!(validator.is_valid_root("at"));
     }
 
+    #[test]
+    fn test_two_letter_stop_final_roots() {
+        let validator = RootValidator::default();
+        for root in ["at", "et", "ok", "üç"] {
+            assert!(validator.is_valid_root(root), "{} should be valid", root);
+        }
+    }
+
     #[test]
     fn test_strict_mode() {
         let validator = RootValidator::new(2, true);
@@ -220,10 +203,9 @@ mod tests {
 
     #[test]
     fn test_known_roots() {
-        let roots = get_valid_roots();
-        assert!(roots.contains("kitap"));
-        assert!(roots.contains("ev"));
-        assert!(roots.contains("gel"));
-        assert!(roots.contains("git"));
+        assert!(lemma_dict::is_lemma("kitap"));
+        assert!(lemma_dict::is_lemma("ev"));
+        assert!(lemma_dict::is_lemma("gel"));
+        assert!(lemma_dict::is_lemma("git"));
     }
 }
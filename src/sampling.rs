@@ -0,0 +1,217 @@
+//! Seeded reservoir sampling of lines and sentences
+//!
+//! Samples are drawn with Algorithm R over a SplitMix64 generator. Both are
+//! fixed here rather than taken from a crate, so a seed selects the same
+//! sample on every platform and in every release: experiments can publish
+//! `(n, seed)` instead of the sample itself. The input is read once and only
+//! the `n` kept items are held in memory. Sampled items are returned in
+//! input order.
+
+use regex::Regex;
+use std::io::{self, BufRead};
+use std::sync::OnceLock;
+
+/// SplitMix64 (Steele, Lea & Flood 2014)
+pub(crate) struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// True with probability `p`
+    pub(crate) fn chance(&mut self, p: f64) -> bool {
+        // 53 random bits give every representable probability step
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    /// Uniform value in `0..bound` (rejection sampling, no modulo bias)
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        let zone = u64::MAX - (u64::MAX - bound + 1) % bound;
+        loop {
+            let value = self.next_u64();
+            if value <= zone {
+                return value % bound;
+            }
+        }
+    }
+}
+
+/// Keep a uniform sample of `n` items, returned in input order
+pub fn reservoir<T, I>(items: I, n: usize, seed: u64) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+{
+    let mut rng = SplitMix64::new(seed);
+    let mut kept: Vec<(usize, T)> = Vec::with_capacity(n.min(1024));
+    for (index, item) in items.into_iter().enumerate() {
+        if kept.len() < n {
+            kept.push((index, item));
+            continue;
+        }
+        let slot = rng.below(index as u64 + 1) as usize;
+        if slot < n {
+            kept[slot] = (index, item);
+        }
+    }
+    kept.sort_unstable_by_key(|(index, _)| *index);
+    kept.into_iter().map(|(_, item)| item).collect()
+}
+
+/// Sample `n` non-blank lines as `(line number, line)`, numbered from 1.
+/// Line endings are removed.
+pub fn sample_lines<R: BufRead>(
+    reader: R,
+    n: usize,
+    seed: u64,
+) -> io::Result<Vec<(usize, String)>> {
+    let mut error = None;
+    let lines = reader
+        .lines()
+        .enumerate()
+        .map_while(|(index, line)| match line {
+            Ok(line) => Some((index + 1, line)),
+            Err(e) => {
+                error = Some(e);
+                None
+            }
+        })
+        .map(|(line_no, line)| (line_no, line.trim_end_matches('\r').to_string()))
+        .filter(|(_, line)| !line.trim().is_empty());
+    let sample = reservoir(lines, n, seed);
+    match error {
+        Some(e) => Err(e),
+        None => Ok(sample),
+    }
+}
+
+/// Abbreviations that do not end a sentence (same as `durak.tokenizer`)
+const ABBREVIATIONS: &[&str] = &["dr.", "prof.", "doç.", "alb.", "sn.", "mr.", "mrs.", "ms."];
+
+fn sentence_end_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"([.!?…]+)(\s+|$)").expect("valid sentence regex"))
+}
+
+/// Split text into sentences; same rules as `durak.split_sentences`
+pub fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    for m in sentence_end_regex().find_iter(text) {
+        let end = m.end();
+        let candidate = text[start..end].trim();
+        if candidate.is_empty() {
+            start = end;
+            continue;
+        }
+        let last_word = candidate.split_whitespace().last().unwrap_or_default();
+        if ABBREVIATIONS.contains(&last_word.to_lowercase().as_str()) {
+            continue;
+        }
+        sentences.push(candidate);
+        start = end;
+    }
+    let remainder = text[start..].trim();
+    if !remainder.is_empty() {
+        sentences.push(remainder);
+    }
+    sentences
+}
+
+/// Sample `n` sentences of `text`
+pub fn sample_sentences(text: &str, n: usize, seed: u64) -> Vec<&str> {
+    reservoir(split_sentences(text), n, seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_splitmix64_reference_values() {
+        // First outputs for seed 0 from the reference implementation
+        let mut rng = SplitMix64::new(0);
+        assert_eq!(rng.next_u64(), 0xE220_A839_7B1D_CDAF);
+        assert_eq!(rng.next_u64(), 0x6E78_9E6A_A1B9_65F4);
+    }
+
+    #[test]
+    fn test_reservoir_is_seeded_and_ordered() {
+        let first = reservoir(0..1000, 10, 42);
+        assert_eq!(first, reservoir(0..1000, 10, 42));
+        assert_ne!(first, reservoir(0..1000, 10, 7));
+        assert_eq!(first.len(), 10);
+        assert!(first.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_reservoir_smaller_input() {
+        assert_eq!(reservoir(0..3, 10, 1), vec![0, 1, 2]);
+        assert!(reservoir(0..3, 0, 1).is_empty());
+    }
+
+    #[test]
+    fn test_reservoir_is_uniform() {
+        let mut counts = [0u32; 10];
+        for seed in 0..2000 {
+            for item in reservoir(0..10, 3, seed) {
+                counts[item] += 1;
+            }
+        }
+        // Each item is expected 600 times
+        assert!(
+            counts.iter().all(|&count| (500..700).contains(&count)),
+            "{:?}",
+            counts
+        );
+    }
+
+    #[test]
+    fn test_sample_lines_skips_blank_lines() {
+        let input = "bir\r\n\n  \niki\nüç\n";
+        let all = sample_lines(input.as_bytes(), 10, 0).unwrap();
+        assert_eq!(
+            all,
+            vec![
+                (1, "bir".to_string()),
+                (4, "iki".to_string()),
+                (5, "üç".to_string())
+            ]
+        );
+        assert_eq!(sample_lines(input.as_bytes(), 2, 3).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_sample_lines_invalid_utf8() {
+        let input: &[u8] = b"ok\n\xff\n";
+        let error = sample_lines(input, 1, 0).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_split_sentences() {
+        assert_eq!(
+            split_sentences("Dr. Ahmet geldi. Nasılsın? Çok iyiyim…  Sonra"),
+            vec!["Dr. Ahmet geldi.", "Nasılsın?", "Çok iyiyim…", "Sonra"]
+        );
+        assert!(split_sentences("   ").is_empty());
+    }
+
+    #[test]
+    fn test_sample_sentences() {
+        let text = "Bir. İki. Üç. Dört. Beş.";
+        assert_eq!(sample_sentences(text, 2, 5), sample_sentences(text, 2, 5));
+        assert_eq!(sample_sentences(text, 2, 5).len(), 2);
+        assert_eq!(sample_sentences(text, 9, 5).len(), 5);
+    }
+}
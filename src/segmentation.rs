@@ -0,0 +1,241 @@
+//! Splitting of accidentally concatenated words
+//!
+//! Noisy text often drops the space between words ("çokgüzel",
+//! "geliyorummusun"). A token that is not itself a known word is segmented
+//! with dynamic programming into the fewest pieces such that every piece is a
+//! known word: an entry of the lemma dictionary, a stopword, or a question
+//! particle (mı/mi/mu/mü and their personal forms).
+//!
+//! Splitting is deliberately conservative. Pieces shorter than
+//! `MIN_PIECE_CHARS` are only accepted when they are question particles, so
+//! case endings such as "-ta"/"-de" are never mistaken for separate words.
+
+use crate::lemma_dict;
+use crate::{is_base_stopword, turkish_lower_char, turkish_lowercase};
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+/// Shortest piece (in characters) accepted unless it is a question particle
+const MIN_PIECE_CHARS: usize = 3;
+
+/// Longer tokens are left untouched to keep the quadratic DP bounded
+const MAX_TOKEN_CHARS: usize = 64;
+
+static QUESTION_PARTICLES: OnceLock<HashSet<String>> = OnceLock::new();
+
+/// Question particle forms: mı, mıyım, mısın, mıyız, mısınız, mıdır, ...
+fn question_particles() -> &'static HashSet<String> {
+    QUESTION_PARTICLES.get_or_init(|| {
+        // `V` stands for the harmonized high vowel (ı, i, u, ü)
+        const TEMPLATES: [&str; 7] = ["mV", "mVyVm", "mVsVn", "mVyVz", "mVsVnVz", "mVdVr", "mVydV"];
+
+        ["ı", "i", "u", "ü"]
+            .iter()
+            .flat_map(|vowel| TEMPLATES.iter().map(move |t| t.replace('V', vowel)))
+            .collect()
+    })
+}
+
+/// Check whether a lowercase string is a stopword or question particle.
+/// Stopwords are the base list, or the one installed by `set_resource_dirs`;
+/// suffix entries such as "'da" are not words.
+pub(crate) fn is_function_word(piece: &str) -> bool {
+    (!piece.starts_with('\'') && is_base_stopword(piece)) || question_particles().contains(piece)
+}
+
+/// Check whether a lowercase string is a known standalone word
+pub(crate) fn is_word(piece: &str) -> bool {
+    lemma_dict::lookup(piece).is_some() || lemma_dict::is_lemma(piece) || is_function_word(piece)
+}
+
+fn is_piece(piece: &str, chars: usize) -> bool {
+    if chars < MIN_PIECE_CHARS {
+        return question_particles().contains(piece);
+    }
+    is_word(piece)
+}
+
+/// Split an accidentally concatenated token into its words
+///
+/// Returns the original token as a single element when it is already a
+/// known word or cannot be fully covered by known words. The returned pieces
+/// are slices of the original token, so casing is preserved.
+///
+/// # Examples
+/// ```text
+/// split_joined("çokgüzel")       -> ["çok", "güzel"]
+/// split_joined("geliyorummusun") -> ["geliyorum", "musun"]
+/// split_joined("kitapta")        -> ["kitapta"]
+/// ```
+pub fn split_joined(token: &str) -> Vec<String> {
+    let unsplit = || vec![token.to_string()];
+
+    let lower = turkish_lowercase(token);
+    // Byte offset of every char boundary in the lowercased token
+    let bounds: Vec<usize> = lower
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(lower.len()))
+        .collect();
+    let n = bounds.len() - 1;
+
+    // The shortest splittable token is a full word plus a two-letter particle
+    let splittable = (MIN_PIECE_CHARS + 2..=MAX_TOKEN_CHARS).contains(&n)
+        && lower.chars().all(char::is_alphabetic)
+        && !is_word(&lower);
+    if !splittable {
+        return unsplit();
+    }
+
+    // best[i] = (pieces, sum of squared piece lengths, previous boundary) for
+    // the prefix of i chars. Fewer pieces win, ties prefer longer pieces.
+    let mut best: Vec<Option<(usize, usize, usize)>> = vec![None; n + 1];
+    best[0] = Some((0, 0, 0));
+
+    for end in 1..=n {
+        for start in 0..end {
+            let Some((pieces, score, _)) = best[start] else {
+                continue;
+            };
+            let len = end - start;
+            if !is_piece(&lower[bounds[start]..bounds[end]], len) {
+                continue;
+            }
+
+            let candidate = (pieces + 1, score + len * len, start);
+            let better = match best[end] {
+                None => true,
+                Some((p, s, _)) => candidate.0 < p || (candidate.0 == p && candidate.1 > s),
+            };
+            if better {
+                best[end] = Some(candidate);
+            }
+        }
+    }
+
+    let Some((pieces, _, _)) = best[n] else {
+        return unsplit();
+    };
+    if pieces < 2 {
+        return unsplit();
+    }
+
+    // Walk the back-pointers and cut the original token at the same char offsets
+    let mut cuts = vec![n];
+    let mut pos = n;
+    while pos > 0 {
+        let (_, _, prev) = best[pos].expect("reachable boundary has a back-pointer");
+        cuts.push(prev);
+        pos = prev;
+    }
+    cuts.reverse();
+
+    let original: Vec<usize> = token
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(token.len()))
+        .collect();
+    cuts.windows(2)
+        .map(|w| token[original[w[0]]..original[w[1]]].to_string())
+        .collect()
+}
+
+/// Split the contents of a hashtag into lowercase words
+///
+/// The leading `#` is dropped and the tag is cut at underscores, at
+/// camelCase humps ("GünaydınTürkiye"), after an acronym ("HDPKongresi")
+/// and between letters and digits. Every part is then lowercased with
+/// Turkish I rules and run through `split_joined`, so all-lowercase tags
+/// are de-concatenated as well.
+///
+/// # Examples
+/// ```text
+/// split_hashtag("#GünaydınTürkiye") -> ["günaydın", "türkiye"]
+/// split_hashtag("#çokgüzel")        -> ["çok", "güzel"]
+/// split_hashtag("#HDPKongresi")     -> ["hdp", "kongresi"]
+/// ```
+pub fn split_hashtag(tag: &str) -> Vec<String> {
+    let body = tag.strip_prefix('#').unwrap_or(tag);
+    let chars: Vec<char> = body.chars().collect();
+    let mut parts: Vec<String> = Vec::new();
+    let mut part = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' {
+            parts.push(std::mem::take(&mut part));
+            continue;
+        }
+        if let Some(&prev) = i.checked_sub(1).and_then(|j| chars.get(j)) {
+            let next = chars.get(i + 1).copied();
+            let hump = prev.is_lowercase() && c.is_uppercase();
+            let acronym_end = prev.is_uppercase()
+                && c.is_uppercase()
+                && next.is_some_and(char::is_lowercase);
+            let digits = prev.is_numeric() != c.is_numeric();
+            if hump || acronym_end || digits {
+                parts.push(std::mem::take(&mut part));
+            }
+        }
+        part.push(turkish_lower_char(c));
+    }
+    parts.push(part);
+    parts
+        .iter()
+        .filter(|part| !part.is_empty())
+        .flat_map(|part| split_joined(part))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_question_particles() {
+        let particles = question_particles();
+        for form in ["mı", "mi", "mu", "mü", "musun", "miyim", "mısınız", "müdür"] {
+            assert!(particles.contains(form), "missing particle {}", form);
+        }
+    }
+
+    #[test]
+    fn test_split_joined_basic() {
+        assert_eq!(split_joined("çokgüzel"), vec!["çok", "güzel"]);
+        assert_eq!(split_joined("geliyorummusun"), vec!["geliyorum", "musun"]);
+        assert_eq!(split_joined("kitapmı"), vec!["kitap", "mı"]);
+    }
+
+    #[test]
+    fn test_split_joined_preserves_case() {
+        assert_eq!(split_joined("ÇokGüzel"), vec!["Çok", "Güzel"]);
+        assert_eq!(split_joined("İYİMİ"), vec!["İYİ", "Mİ"]);
+    }
+
+    #[test]
+    fn test_split_joined_leaves_known_words() {
+        assert_eq!(split_joined("geliyorum"), vec!["geliyorum"]);
+        assert_eq!(split_joined("kitap"), vec!["kitap"]);
+    }
+
+    #[test]
+    fn test_split_joined_does_not_split_case_endings() {
+        // "-ta" and "-de" are suffixes, not words
+        assert_eq!(split_joined("kitapta"), vec!["kitapta"]);
+        assert_eq!(split_joined("evde"), vec!["evde"]);
+    }
+
+    #[test]
+    fn test_split_joined_unknown() {
+        assert_eq!(split_joined("bilgisayarlar"), vec!["bilgisayarlar"]);
+        assert_eq!(split_joined(""), vec![""]);
+        assert_eq!(split_joined("çok-güzel"), vec!["çok-güzel"]);
+    }
+
+    #[test]
+    fn test_split_hashtag() {
+        assert_eq!(split_hashtag("#GünaydınTürkiye"), vec!["günaydın", "türkiye"]);
+        assert_eq!(split_hashtag("#çokgüzel"), vec!["çok", "güzel"]);
+        assert_eq!(split_hashtag("#HDPKongresi"), vec!["hdp", "kongresi"]);
+        assert_eq!(split_hashtag("#İyi_Bayramlar2024"), vec!["iyi", "bayramlar", "2024"]);
+        assert_eq!(split_hashtag("#__"), Vec::<String>::new());
+    }
+}
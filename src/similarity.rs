@@ -0,0 +1,224 @@
+//! Turkish-aware fuzzy string similarity
+//!
+//! Levenshtein and Jaro-Winkler similarities where a Turkish letter and the
+//! ASCII letter it is typed as on a non-Turkish keyboard (ı/i, ş/s, ç/c,
+//! ğ/g, ö/o, ü/u, and the circumflex vowels) are nearly the same letter: the
+//! substitution costs `DIACRITIC_COST` instead of a full edit. Deasciified
+//! user input ("Eskisehir", "cigkofte") thus scores close to the canonical
+//! name ("Eskişehir", "çiğköfte") and well above names that really differ.
+//!
+//! Scores are in [0, 1], 1 for equal strings. Case is folded with Turkish
+//! I rules first unless disabled, so "ISTANBUL" is one cheap substitution
+//! away from "İstanbul".
+
+use crate::fast_normalize;
+use crate::spell::fold;
+
+/// Cost of substituting a letter with its diacritic-less counterpart, in
+/// edits
+pub const DIACRITIC_COST: f64 = 0.2;
+
+/// Winkler's prefix scale and the longest prefix it rewards
+const PREFIX_SCALE: f64 = 0.1;
+const MAX_PREFIX: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    Levenshtein,
+    Jaro,
+    JaroWinkler,
+}
+
+impl Metric {
+    pub fn parse(name: &str) -> Option<Metric> {
+        match name {
+            "levenshtein" => Some(Metric::Levenshtein),
+            "jaro" => Some(Metric::Jaro),
+            "jaro_winkler" => Some(Metric::JaroWinkler),
+            _ => None,
+        }
+    }
+}
+
+/// Cost of substituting `b` for `a`: 0, `DIACRITIC_COST` or 1
+fn substitution_cost(a: char, b: char) -> f64 {
+    if a == b {
+        0.0
+    } else if fold(a) == fold(b) {
+        DIACRITIC_COST
+    } else {
+        1.0
+    }
+}
+
+/// Levenshtein distance where diacritic substitutions cost `DIACRITIC_COST`
+///
+/// # Examples
+/// ```text
+/// edit_distance("cigkofte", "çiğköfte") -> 0.6
+/// edit_distance("kitap", "kitaplar") -> 3.0
+/// ```
+pub fn edit_distance(a: &[char], b: &[char]) -> f64 {
+    let mut prev: Vec<f64> = (0..=b.len()).map(|j| j as f64).collect();
+    let mut curr = vec![0.0; b.len() + 1];
+
+    for (i, &ca) in a.iter().enumerate() {
+        curr[0] = (i + 1) as f64;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = prev[j] + substitution_cost(ca, cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1.0).min(curr[j] + 1.0);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+fn levenshtein(a: &[char], b: &[char]) -> f64 {
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - edit_distance(a, b) / longest as f64
+}
+
+/// Jaro similarity where characters match when they fold to the same
+/// letter; a diacritic match counts as `1 - DIACRITIC_COST` of a match
+fn jaro(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut taken = vec![false; b.len()];
+    let mut matched_a = Vec::new();
+    let mut weight = 0.0;
+    for (i, &ca) in a.iter().enumerate() {
+        let low = i.saturating_sub(window);
+        let high = (i + window + 1).min(b.len());
+        let found = (low..high).find(|&j| !taken[j] && fold(b[j]) == fold(ca));
+        if let Some(j) = found {
+            taken[j] = true;
+            matched_a.push(ca);
+            weight += 1.0 - substitution_cost(ca, b[j]);
+        }
+    }
+    if matched_a.is_empty() {
+        return 0.0;
+    }
+    let matched_b = b.iter().zip(&taken).filter(|(_, &t)| t).map(|(&c, _)| c);
+    let transpositions = matched_a
+        .iter()
+        .zip(matched_b)
+        .filter(|(&x, y)| fold(x) != fold(*y))
+        .count() as f64
+        / 2.0;
+    let matches = matched_a.len() as f64;
+    (weight / a.len() as f64 + weight / b.len() as f64 + (matches - transpositions) / matches)
+        / 3.0
+}
+
+fn jaro_winkler(a: &[char], b: &[char]) -> f64 {
+    let score = jaro(a, b);
+    let prefix = a
+        .iter()
+        .zip(b)
+        .take(MAX_PREFIX)
+        .take_while(|(&x, &y)| fold(x) == fold(y))
+        .count();
+    score + prefix as f64 * PREFIX_SCALE * (1.0 - score)
+}
+
+fn chars(text: &str, ignore_case: bool) -> Vec<char> {
+    if ignore_case {
+        fast_normalize(text, true, true).chars().collect()
+    } else {
+        text.chars().collect()
+    }
+}
+
+fn score(a: &[char], b: &[char], metric: Metric) -> f64 {
+    match metric {
+        Metric::Levenshtein => levenshtein(a, b),
+        Metric::Jaro => jaro(a, b),
+        Metric::JaroWinkler => jaro_winkler(a, b),
+    }
+}
+
+/// Similarity of two strings in [0, 1]
+///
+/// # Examples
+/// ```text
+/// similarity("Eskisehir", "Eskişehir", Metric::Levenshtein, true) -> 0.98
+/// similarity("Eskisehir", "Eskimeler", Metric::Levenshtein, true) -> 0.67
+/// ```
+pub fn similarity(a: &str, b: &str, metric: Metric, ignore_case: bool) -> f64 {
+    score(&chars(a, ignore_case), &chars(b, ignore_case), metric)
+}
+
+/// The `k` candidates most similar to `query` as (candidate index, score),
+/// best first; ties keep the candidate order
+pub fn closest<S: AsRef<str>>(
+    query: &str,
+    candidates: &[S],
+    k: usize,
+    metric: Metric,
+    ignore_case: bool,
+) -> Vec<(usize, f64)> {
+    let query = chars(query, ignore_case);
+    let mut scored: Vec<(usize, f64)> = candidates
+        .iter()
+        .enumerate()
+        .map(|(i, candidate)| (i, score(&query, &chars(candidate.as_ref(), ignore_case), metric)))
+        .collect();
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    scored.truncate(k);
+    scored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert!(close(edit_distance(&chars("cigkofte"), &chars("çiğköfte")), 0.6));
+        assert!(close(edit_distance(&chars("kitap"), &chars("kitaplar")), 3.0));
+        assert!(close(edit_distance(&chars(""), &chars("ev")), 2.0));
+    }
+
+    #[test]
+    fn test_similarity() {
+        let lev = Metric::Levenshtein;
+        assert!(close(similarity("Eskisehir", "Eskişehir", lev, true), 1.0 - 0.2 / 9.0));
+        assert!(close(similarity("ISTANBUL", "İstanbul", lev, true), 1.0 - 0.2 / 8.0));
+        assert!(close(similarity("ISTANBUL", "İstanbul", lev, false), 0.0));
+        assert!(close(similarity("", "", lev, true), 1.0));
+        for metric in [Metric::Levenshtein, Metric::Jaro, Metric::JaroWinkler] {
+            let near = similarity("Eskisehir", "Eskişehir", metric, true);
+            let far = similarity("Eskisehir", "Eskimeler", metric, true);
+            assert!(near > 0.95 && far < 0.9 && near < 1.0, "{:?}", metric);
+            assert!(close(similarity("ev", "ev", metric, true), 1.0));
+            assert!(close(similarity("ev", "", metric, true), 0.0));
+        }
+        // Classic Jaro-Winkler values without diacritics
+        assert!((similarity("martha", "marhta", Metric::Jaro, true) - 0.9444).abs() < 1e-4);
+        assert!((similarity("martha", "marhta", Metric::JaroWinkler, true) - 0.9611).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_closest() {
+        let names = ["Eskişehir", "Kırşehir", "Kırıkkale", "Eskişehir"];
+        let best = closest("kirsehir", &names, 2, Metric::JaroWinkler, true);
+        assert_eq!(best[0].0, 1);
+        assert_eq!(best[1].0, 0);
+        let ties = closest("eskisehir", &names, 5, Metric::Levenshtein, true);
+        assert_eq!(ties.iter().map(|m| m.0).collect::<Vec<_>>(), [0, 3, 1, 2]);
+    }
+}
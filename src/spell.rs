@@ -0,0 +1,360 @@
+//! Spell checking and correction suggestions
+//!
+//! A word is correct when the morphological analyzer can parse it or it is a
+//! stopword or question particle. Inflected forms of the lemma dictionary are
+//! deliberately not consulted: the dictionary also lists diacritic-less
+//! variants ("okudi") so the lemmatizer can handle them.
+//!
+//! Suggestions are drawn from a lexicon of every inflection the analyzer can
+//! generate from a known root (up to `LEXICON_SUFFIXES` suffixes), so
+//! candidates always obey vowel harmony and suffix order. The lexicon is
+//! indexed in a BK-tree keyed on diacritic-folded forms: a missing diacritic
+//! ("cocugu" for "çocuğu") costs no edit, the most common error in Turkish
+//! text typed on non-Turkish keyboards.
+//!
+//! The lexicon only depends on the analyzer's roots, which are compiled in,
+//! so it is built once per process. `set_resource_dirs` still changes which
+//! words are correct: stopwords come from the installed list and analysis
+//! falls back to the installed lemma dictionary.
+
+use crate::analyzer;
+use crate::fast_normalize;
+use crate::is_blob;
+use crate::keyboard::{self, Layout};
+use crate::segmentation;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Longest suffix chain generated for the suggestion lexicon
+const LEXICON_SUFFIXES: usize = 3;
+
+static LEXICON: OnceLock<BkTree> = OnceLock::new();
+
+/// Map Turkish letters to the ASCII letter they are most often typed as
+pub(crate) fn fold(c: char) -> char {
+    match c {
+        'ç' => 'c',
+        'ğ' => 'g',
+        'ı' => 'i',
+        'ö' => 'o',
+        'ş' => 's',
+        'ü' => 'u',
+        'â' => 'a',
+        'î' => 'i',
+        'û' => 'u',
+        _ => c,
+    }
+}
+
+/// Levenshtein distance between two char sequences
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, &ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+/// A BK-tree node: one folded key and the lexicon forms that fold to it
+struct BkNode {
+    key: Vec<char>,
+    forms: Vec<String>,
+    /// (distance to this node, child index)
+    children: Vec<(usize, usize)>,
+}
+
+/// Burkhard-Keller tree over folded forms, searched by Levenshtein distance
+struct BkTree {
+    nodes: Vec<BkNode>,
+}
+
+impl BkTree {
+    fn build(forms: impl IntoIterator<Item = String>) -> Self {
+        let mut grouped: HashMap<Vec<char>, Vec<String>> = HashMap::new();
+        for form in forms {
+            grouped
+                .entry(form.chars().map(fold).collect())
+                .or_default()
+                .push(form);
+        }
+
+        // Insert in a fixed order so the tree shape is reproducible
+        let mut grouped: Vec<_> = grouped.into_iter().collect();
+        grouped.sort();
+
+        let mut tree = BkTree { nodes: Vec::new() };
+        for (key, mut forms) in grouped {
+            forms.sort();
+            forms.dedup();
+            tree.insert(key, forms);
+        }
+        tree
+    }
+
+    fn insert(&mut self, key: Vec<char>, forms: Vec<String>) {
+        let index = self.nodes.len();
+        if index > 0 {
+            let mut current = 0;
+            loop {
+                let distance = levenshtein(&key, &self.nodes[current].key);
+                let child = self.nodes[current]
+                    .children
+                    .iter()
+                    .find(|&&(d, _)| d == distance)
+                    .map(|&(_, child)| child);
+                match child {
+                    Some(child) => current = child,
+                    None => {
+                        self.nodes[current].children.push((distance, index));
+                        break;
+                    }
+                }
+            }
+        }
+        self.nodes.push(BkNode {
+            key,
+            forms,
+            children: Vec::new(),
+        });
+    }
+
+    /// Nodes within `max_distance` of `query`, with their distances
+    fn search(&self, query: &[char], max_distance: usize) -> Vec<(&BkNode, usize)> {
+        let mut found = Vec::new();
+        if self.nodes.is_empty() {
+            return found;
+        }
+
+        let mut stack = vec![0];
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            let distance = levenshtein(query, &node.key);
+            if distance <= max_distance {
+                found.push((node, distance));
+            }
+            // Triangle inequality: only children at distance ± max can match
+            let low = distance.saturating_sub(max_distance);
+            let high = distance + max_distance;
+            stack.extend(
+                node.children
+                    .iter()
+                    .filter(|&&(d, _)| d >= low && d <= high)
+                    .map(|&(_, child)| child),
+            );
+        }
+        found
+    }
+}
+
+fn lexicon() -> &'static BkTree {
+    LEXICON.get_or_init(|| BkTree::build(analyzer::surface_forms(LEXICON_SUFFIXES)))
+}
+
+/// Build the suggestion index now instead of on the first `suggest` call
+pub(crate) fn build_lexicon() {
+    lexicon();
+}
+
+/// Lowercased word with any apostrophe suffix removed
+pub(crate) fn stem(word: &str) -> String {
+    fast_normalize(word.split('\'').next().unwrap_or(word), true, true)
+}
+
+/// Check whether a word is spelled correctly
+///
+/// Tokens without letters (numbers, punctuation) and capitalized tokens with
+/// an apostrophe suffix (proper nouns such as "Ankara'da") are accepted;
+/// BLOB tokens (see `tokenize_with_types`) are not.
+pub fn is_correct(word: &str) -> bool {
+    if is_blob(word) {
+        return false;
+    }
+    if !word.chars().any(char::is_alphabetic) {
+        return true;
+    }
+    if word.contains('\'') && word.chars().next().is_some_and(char::is_uppercase) {
+        return true;
+    }
+    let word = stem(word);
+    segmentation::is_function_word(&word) || !analyzer::analyze(&word).is_empty()
+}
+
+/// Lexicon forms that differ from a normalized word only in diacritics,
+/// the word itself included when the lexicon has it
+pub fn diacritic_variants(word: &str) -> Vec<String> {
+    if word.is_empty() || is_blob(word) {
+        return Vec::new();
+    }
+    let folded: Vec<char> = word.chars().map(fold).collect();
+    let mut variants: Vec<String> = lexicon()
+        .search(&folded, 0)
+        .into_iter()
+        .flat_map(|(node, _)| node.forms.iter().cloned())
+        .collect();
+    variants.sort();
+    variants
+}
+
+/// A spelling suggestion
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub word: String,
+    /// Edits ignoring diacritics (ç/c, ğ/g, ı/i, ö/o, ş/s, ü/u)
+    pub edits: usize,
+    /// Edits counting diacritics, used as a tie-breaker
+    pub raw_edits: usize,
+    /// Half edits on the Turkish Q keyboard, where hitting a neighbouring key
+    /// costs half an edit (`keyboard::edit_cost`); the next tie-breaker
+    pub keyboard_cost: usize,
+}
+
+/// Up to `k` spelling suggestions within `max_edits` edits
+///
+/// Suggestions are lowercase and ordered by edits ignoring diacritics, then
+/// by edits counting them, then by keyboard-aware cost (a typo on a
+/// neighbouring key is the likelier one), then alphabetically. A correctly
+/// spelled word is returned as its own only suggestion.
+///
+/// # Examples
+/// ```text
+/// suggest("kitaplarimdan", 2, 5)[0] -> kitaplarımdan (0 edits, 1 raw)
+/// suggest("geliyorm", 2, 3)         -> [geliyor, geliyorum, geliyordu]
+/// ```
+pub fn suggest(word: &str, max_edits: usize, k: usize) -> Vec<Suggestion> {
+    let word = stem(word);
+    if word.is_empty() || k == 0 || is_blob(&word) {
+        return Vec::new();
+    }
+    if is_correct(&word) {
+        return vec![Suggestion {
+            word,
+            edits: 0,
+            raw_edits: 0,
+            keyboard_cost: 0,
+        }];
+    }
+
+    let chars: Vec<char> = word.chars().collect();
+    let folded: Vec<char> = chars.iter().copied().map(fold).collect();
+
+    let mut suggestions: Vec<Suggestion> = lexicon()
+        .search(&folded, max_edits)
+        .into_iter()
+        .flat_map(|(node, edits)| {
+            let chars = &chars;
+            node.forms.iter().map(move |form| {
+                let form_chars: Vec<char> = form.chars().collect();
+                Suggestion {
+                    word: form.clone(),
+                    edits,
+                    raw_edits: levenshtein(chars, &form_chars),
+                    keyboard_cost: keyboard::edit_cost(chars, &form_chars, Layout::Q),
+                }
+            })
+        })
+        .collect();
+
+    suggestions.sort_by(|a, b| {
+        (a.edits, a.raw_edits, a.keyboard_cost, &a.word)
+            .cmp(&(b.edits, b.raw_edits, b.keyboard_cost, &b.word))
+    });
+    suggestions.truncate(k);
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(suggestions: &[Suggestion]) -> Vec<&str> {
+        suggestions.iter().map(|s| s.word.as_str()).collect()
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(levenshtein(&chars("kitap"), &chars("kitap")), 0);
+        assert_eq!(levenshtein(&chars("kitap"), &chars("kitab")), 1);
+        assert_eq!(levenshtein(&chars("ev"), &chars("evler")), 3);
+        assert_eq!(levenshtein(&chars(""), &chars("göz")), 3);
+    }
+
+    #[test]
+    fn test_bk_tree_search() {
+        let tree = BkTree::build(["kitap", "kitabı", "kitaplar", "ev"].map(String::from));
+        let query: Vec<char> = "kitab".chars().collect();
+        let mut found: Vec<_> = tree
+            .search(&query, 1)
+            .into_iter()
+            .map(|(node, d)| (node.forms[0].as_str(), d))
+            .collect();
+        found.sort();
+        assert_eq!(found, vec![("kitabı", 1), ("kitap", 1)]);
+    }
+
+    #[test]
+    fn test_is_correct() {
+        assert!(is_correct("kitaplarımdan"));
+        assert!(is_correct("Geliyorum"));
+        assert!(is_correct("ve"));
+        assert!(is_correct("Ankara'da"));
+        assert!(is_correct("1990"));
+        assert!(!is_correct("kitaplarımdna"));
+        assert!(!is_correct("geliyorm"));
+        // Diacritic-less dictionary variants are misspellings
+        assert!(!is_correct("okudi"));
+    }
+
+    #[test]
+    fn test_suggest_missing_diacritics() {
+        let suggestions = suggest("kitaplarimdan", 2, 5);
+        assert_eq!(suggestions[0].word, "kitaplarımdan");
+        assert_eq!(suggestions[0].edits, 0);
+        assert_eq!(suggestions[0].raw_edits, 1);
+        assert_eq!(suggest("cocugu", 1, 1)[0].word, "çocuğu");
+    }
+
+    #[test]
+    fn test_suggest_typos() {
+        let suggestions = suggest("geliyorm", 2, 5);
+        assert_eq!(suggestions[0].edits, 1);
+        assert!(words(&suggestions).contains(&"geliyorum"));
+        // A transposition counts as two edits
+        let suggestions = suggest("kitaplarımdna", 2, 5);
+        assert_eq!(suggestions[0].word, "kitaplarımda");
+        assert!(words(&suggestions).contains(&"kitaplarımdan"));
+        assert!(words(&suggest("evlerde", 2, 5)).contains(&"evlerde"));
+    }
+
+    #[test]
+    fn test_suggest_prefers_neighbouring_keys() {
+        // "oku", "okul", "okun" and "okur" are all 1 edit from "okuk"; only
+        // l sits next to k
+        let suggestions = suggest("okuk", 1, 5);
+        assert_eq!(suggestions[0].word, "okul", "{:?}", words(&suggestions));
+        assert!(words(&suggestions).contains(&"oku"));
+    }
+
+    #[test]
+    fn test_diacritic_variants() {
+        assert_eq!(diacritic_variants("ic"), vec!["iç"]);
+        assert!(diacritic_variants("cocugu").contains(&"çocuğu".to_string()));
+        assert!(diacritic_variants("çocuğu").contains(&"çocuğu".to_string()));
+        assert!(diacritic_variants("xyzqwvbn").is_empty());
+    }
+
+    #[test]
+    fn test_suggest_limits() {
+        assert!(suggest("geliyorm", 2, 3).len() <= 3);
+        assert!(suggest("xyzqwvbn", 1, 5).is_empty());
+        assert!(suggest("geliyorm", 2, 0).is_empty());
+        assert!(suggest("", 2, 5).is_empty());
+    }
+}
@@ -0,0 +1,456 @@
+//! Memory-capped counting and deduplication
+//!
+//! Corpus-wide term counts and duplicate checks grow with the vocabulary
+//! rather than the input, so on large dumps they can outgrow a shared
+//! machine. Under a memory budget (`max_memory_mb` in the Python API) they
+//! keep at most that much in memory and spill the rest to sorted run files
+//! in a temporary directory that is removed afterwards:
+//!
+//! - `Sorter`: external merge sort of (key, value) entries; a run is written
+//!   whenever the buffered entries pass the budget, and `finish` merges the
+//!   runs k-way
+//! - `Table`: one value per key, combined in memory (sum for counts, min for
+//!   first occurrences) and spilled through a `Sorter` when it is full; the
+//!   merge combines the values from different runs
+//! - `first_lines`: indexes of the first occurrence of each distinct line,
+//!   for order-preserving deduplication in two passes over a file
+//!
+//! Without a budget nothing touches disk. Results are the same either way;
+//! only the memory high-water mark and the speed differ.
+
+use std::collections::{BinaryHeap, HashMap};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Estimated bookkeeping bytes per in-memory entry besides the key bytes
+const ENTRY_OVERHEAD: usize = 64;
+
+/// Distinguishes spill directories created by one process
+static DIRECTORIES: AtomicUsize = AtomicUsize::new(0);
+
+/// Byte budget for a `max_memory_mb` hint; unlimited for None
+pub fn budget(max_memory_mb: Option<usize>) -> usize {
+    max_memory_mb.map_or(usize::MAX, |mb| mb.saturating_mul(1 << 20))
+}
+
+/// Temporary directory of run files, removed on drop
+struct SpillDir {
+    path: PathBuf,
+    runs: usize,
+}
+
+impl SpillDir {
+    fn create() -> io::Result<Self> {
+        let id = DIRECTORIES.fetch_add(1, Ordering::Relaxed);
+        let name = format!("durak-spill-{}-{}", std::process::id(), id);
+        let path = std::env::temp_dir().join(name);
+        std::fs::create_dir_all(&path)?;
+        Ok(Self { path, runs: 0 })
+    }
+
+    fn next_run(&mut self) -> PathBuf {
+        self.runs += 1;
+        self.path.join(format!("run-{}", self.runs))
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Entry of a run file: u32 key length, key bytes, u64 value (little endian)
+fn write_entry(writer: &mut impl Write, key: &str, value: u64) -> io::Result<()> {
+    writer.write_all(&(key.len() as u32).to_le_bytes())?;
+    writer.write_all(key.as_bytes())?;
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn read_entry(reader: &mut impl Read) -> io::Result<Option<(String, u64)>> {
+    let mut len = [0u8; 4];
+    match reader.read_exact(&mut len) {
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        result => result?,
+    }
+    let mut key = vec![0u8; u32::from_le_bytes(len) as usize];
+    reader.read_exact(&mut key)?;
+    let mut value = [0u8; 8];
+    reader.read_exact(&mut value)?;
+    let key = String::from_utf8(key).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(Some((key, u64::from_le_bytes(value))))
+}
+
+/// Run files merged at once; more would risk the open file limit
+const MAX_FAN_IN: usize = 16;
+
+/// External merge sort of (key, value) entries by key, then value
+///
+/// Runs are compacted in tiers: whenever `MAX_FAN_IN` runs of one tier
+/// exist they are merged into a single run of the next tier, so at most
+/// `MAX_FAN_IN` files are open at a time and every entry is rewritten only
+/// a logarithmic number of times.
+pub struct Sorter {
+    budget: usize,
+    /// Combines the values of equal keys while merging, if set
+    combine: Option<Combine>,
+    buffer: Vec<(String, u64)>,
+    size: usize,
+    dir: Option<SpillDir>,
+    /// Run files with their tier, tiers non-increasing
+    runs: Vec<(PathBuf, usize)>,
+}
+
+impl Sorter {
+    pub fn new(budget: usize) -> Self {
+        Self {
+            budget,
+            combine: None,
+            buffer: Vec::new(),
+            size: 0,
+            dir: None,
+            runs: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, key: String, value: u64) -> io::Result<()> {
+        self.size += key.len() + ENTRY_OVERHEAD;
+        self.buffer.push((key, value));
+        if self.size > self.budget {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    /// Write the buffered entries to a sorted run file
+    fn spill(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        self.buffer.sort_unstable();
+        let entries = std::mem::take(&mut self.buffer).into_iter().map(Ok);
+        let path = self.write_run(entries)?;
+        self.runs.push((path, 0));
+        self.size = 0;
+        self.compact()
+    }
+
+    fn write_run(
+        &mut self,
+        entries: impl Iterator<Item = io::Result<(String, u64)>>,
+    ) -> io::Result<PathBuf> {
+        let dir = match &mut self.dir {
+            Some(dir) => dir,
+            None => self.dir.insert(SpillDir::create()?),
+        };
+        let path = dir.next_run();
+        let mut writer = BufWriter::new(File::create(&path)?);
+        for entry in entries {
+            let (key, value) = entry?;
+            write_entry(&mut writer, &key, value)?;
+        }
+        writer.flush()?;
+        Ok(path)
+    }
+
+    /// Merge the last `MAX_FAN_IN` runs while they share a tier
+    fn compact(&mut self) -> io::Result<()> {
+        while self.runs.len() >= MAX_FAN_IN {
+            let tail = &self.runs[self.runs.len() - MAX_FAN_IN..];
+            let tier = tail[0].1;
+            if tail.iter().any(|&(_, t)| t != tier) {
+                break;
+            }
+            let paths: Vec<PathBuf> = self
+                .runs
+                .drain(self.runs.len() - MAX_FAN_IN..)
+                .map(|(path, _)| path)
+                .collect();
+            let merge = Merge::open(Vec::new(), &paths, self.combine, None)?;
+            let path = self.write_run(merge)?;
+            for old in &paths {
+                std::fs::remove_file(old)?;
+            }
+            self.runs.push((path, tier + 1));
+        }
+        Ok(())
+    }
+
+    /// All entries in order, merged from the buffer and the run files
+    pub fn finish(mut self) -> io::Result<Merge> {
+        self.buffer.sort_unstable();
+        let paths: Vec<PathBuf> = self.runs.iter().map(|(path, _)| path.clone()).collect();
+        let buffer = std::mem::take(&mut self.buffer);
+        Merge::open(buffer, &paths, self.combine, self.dir.take())
+    }
+}
+
+enum Source {
+    Memory(std::vec::IntoIter<(String, u64)>),
+    Run(BufReader<File>),
+}
+
+impl Source {
+    fn next(&mut self) -> io::Result<Option<(String, u64)>> {
+        match self {
+            Source::Memory(entries) => Ok(entries.next()),
+            Source::Run(reader) => read_entry(reader),
+        }
+    }
+}
+
+/// Sorted entries of a `Sorter`; the run files are removed when it drops
+pub struct Merge {
+    sources: Vec<Source>,
+    heap: BinaryHeap<std::cmp::Reverse<(String, u64, usize)>>,
+    combine: Option<Combine>,
+    _dir: Option<SpillDir>,
+}
+
+impl Merge {
+    fn open(
+        buffer: Vec<(String, u64)>,
+        runs: &[PathBuf],
+        combine: Option<Combine>,
+        dir: Option<SpillDir>,
+    ) -> io::Result<Self> {
+        let mut sources = vec![Source::Memory(buffer.into_iter())];
+        for path in runs {
+            sources.push(Source::Run(BufReader::new(File::open(path)?)));
+        }
+        let mut merge = Merge {
+            sources,
+            heap: BinaryHeap::new(),
+            combine,
+            _dir: dir,
+        };
+        for index in 0..merge.sources.len() {
+            merge.refill(index)?;
+        }
+        Ok(merge)
+    }
+
+    fn refill(&mut self, index: usize) -> io::Result<()> {
+        if let Some((key, value)) = self.sources[index].next()? {
+            self.heap.push(std::cmp::Reverse((key, value, index)));
+        }
+        Ok(())
+    }
+
+    fn pop(&mut self) -> io::Result<Option<(String, u64)>> {
+        let Some(std::cmp::Reverse((key, mut value, index))) = self.heap.pop() else {
+            return Ok(None);
+        };
+        self.refill(index)?;
+        if let Some(combine) = self.combine {
+            while self.heap.peek().is_some_and(|next| next.0 .0 == key) {
+                let std::cmp::Reverse((_, other, index)) = self.heap.pop().expect("peeked");
+                value = combine.apply(value, other);
+                self.refill(index)?;
+            }
+        }
+        Ok(Some((key, value)))
+    }
+}
+
+impl Iterator for Merge {
+    type Item = io::Result<(String, u64)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pop().transpose()
+    }
+}
+
+/// How a `Table` combines two values of the same key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combine {
+    Sum,
+    Min,
+}
+
+impl Combine {
+    fn apply(self, a: u64, b: u64) -> u64 {
+        match self {
+            Combine::Sum => a + b,
+            Combine::Min => a.min(b),
+        }
+    }
+}
+
+/// One value per key under a memory budget
+pub struct Table {
+    combine: Combine,
+    budget: usize,
+    entries: HashMap<String, u64>,
+    size: usize,
+    sorter: Sorter,
+}
+
+impl Table {
+    pub fn new(combine: Combine, budget: usize) -> Self {
+        // Entries only reach the sorter when the table is full, so it
+        // writes each batch straight to a run
+        let mut sorter = Sorter::new(0);
+        sorter.combine = Some(combine);
+        Self {
+            combine,
+            budget,
+            entries: HashMap::new(),
+            size: 0,
+            sorter,
+        }
+    }
+
+    pub fn add(&mut self, key: &str, value: u64) -> io::Result<()> {
+        if let Some(current) = self.entries.get_mut(key) {
+            *current = self.combine.apply(*current, value);
+            return Ok(());
+        }
+        self.size += key.len() + ENTRY_OVERHEAD;
+        self.entries.insert(key.to_string(), value);
+        if self.size > self.budget {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        self.sorter.buffer.extend(self.entries.drain());
+        self.size = 0;
+        self.sorter.spill()
+    }
+
+    /// Every key with its combined value, in key order
+    pub fn finish(mut self) -> io::Result<Merge> {
+        self.sorter.buffer.extend(self.entries.drain());
+        self.sorter.finish()
+    }
+}
+
+/// 0-based indexes of the lines that are the first occurrence of their
+/// text, ascending. Lines are compared without their line ending ("\n" or
+/// "\r\n"); a final line without one counts as a line.
+pub fn first_lines<R: BufRead>(reader: R, budget: usize) -> io::Result<Merge> {
+    let mut table = Table::new(Combine::Min, budget);
+    for (index, line) in reader.lines().enumerate() {
+        table.add(&line?, index as u64)?;
+    }
+    let mut firsts = Sorter::new(budget);
+    for entry in table.finish()? {
+        let (_, index) = entry?;
+        firsts.push(String::new(), index)?;
+    }
+    firsts.finish()
+}
+
+/// Copy the first occurrence of every distinct line of a file to `writer`,
+/// in input order. `open` is called once per pass, so it must return the
+/// same content both times. Lines are written with "\n" endings; returns
+/// the number of lines written.
+pub fn dedup_lines<R, W, F>(open: F, writer: &mut W, budget: usize) -> io::Result<usize>
+where
+    R: BufRead,
+    W: Write,
+    F: Fn() -> io::Result<R>,
+{
+    let mut firsts = first_lines(open()?, budget)?;
+    let mut next = firsts.next().transpose()?.map(|(_, index)| index);
+    let mut written = 0;
+    for (index, line) in open()?.lines().enumerate() {
+        let line = line?;
+        if next == Some(index as u64) {
+            writer.write_all(line.as_bytes())?;
+            writer.write_all(b"\n")?;
+            written += 1;
+            next = firsts.next().transpose()?.map(|(_, index)| index);
+        }
+    }
+    writer.flush()?;
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(iter: impl Iterator<Item = io::Result<(String, u64)>>) -> Vec<(String, u64)> {
+        iter.collect::<io::Result<_>>().unwrap()
+    }
+
+    #[test]
+    fn test_budget() {
+        assert_eq!(budget(None), usize::MAX);
+        assert_eq!(budget(Some(2)), 2 << 20);
+    }
+
+    #[test]
+    fn test_sorter_spills_and_merges() {
+        let keys = ["ev", "kitap", "ağaç", "ev", "çay", "bir", "kitap"];
+        for budget in [usize::MAX, 0, 150] {
+            let mut sorter = Sorter::new(budget);
+            for (i, key) in keys.iter().enumerate() {
+                sorter.push(key.to_string(), i as u64).unwrap();
+            }
+            assert_eq!(!sorter.runs.is_empty(), budget != usize::MAX);
+            let dir = sorter.dir.as_ref().map(|dir| dir.path.clone());
+            let sorted = collect(sorter.finish().unwrap());
+            let mut expected: Vec<(String, u64)> =
+                keys.iter().enumerate().map(|(i, k)| (k.to_string(), i as u64)).collect();
+            expected.sort();
+            assert_eq!(sorted, expected);
+            // Run files are removed once the merge is done
+            assert!(!dir.is_some_and(|dir| dir.exists()));
+        }
+    }
+
+    #[test]
+    fn test_runs_are_compacted() {
+        let mut sorter = Sorter::new(0);
+        for i in (0..1000u64).rev() {
+            sorter.push(format!("{:04}", i % 300), i).unwrap();
+        }
+        // 1000 runs of one entry became a few tiers of merged runs
+        assert!(sorter.runs.len() < 2 * MAX_FAN_IN);
+        let sorted = collect(sorter.finish().unwrap());
+        assert_eq!(sorted.len(), 1000);
+        assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_table_counts() {
+        let words = "bir iki bir üç iki bir".split(' ');
+        for budget in [usize::MAX, 0] {
+            let mut table = Table::new(Combine::Sum, budget);
+            for word in words.clone() {
+                table.add(word, 1).unwrap();
+            }
+            let counts = collect(table.finish().unwrap());
+            let counts: Vec<(&str, u64)> = counts.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+            assert_eq!(counts, [("bir", 3), ("iki", 2), ("üç", 1)]);
+        }
+    }
+
+    #[test]
+    fn test_dedup_lines() {
+        let text = "merhaba\ndünya\nmerhaba\n\nselam\r\ndünya\n\nselam";
+        for budget in [usize::MAX, 0, 200] {
+            let mut output = Vec::new();
+            let written = dedup_lines(|| Ok(text.as_bytes()), &mut output, budget).unwrap();
+            assert_eq!(written, 4);
+            assert_eq!(String::from_utf8(output).unwrap(), "merhaba\ndünya\n\nselam\n");
+        }
+    }
+
+    #[test]
+    fn test_run_entries_round_trip() {
+        let mut bytes = Vec::new();
+        write_entry(&mut bytes, "kitap", 7).unwrap();
+        write_entry(&mut bytes, "", u64::MAX).unwrap();
+        let mut reader = bytes.as_slice();
+        assert_eq!(read_entry(&mut reader).unwrap(), Some(("kitap".to_string(), 7)));
+        assert_eq!(read_entry(&mut reader).unwrap(), Some((String::new(), u64::MAX)));
+        assert_eq!(read_entry(&mut reader).unwrap(), None);
+    }
+}
@@ -0,0 +1,402 @@
+//! Document-level statistics
+//!
+//! - Morphological complexity metrics computed from the analyzer's preferred
+//!   parse of each word. Turkish genres differ sharply in how much they lean
+//!   on suffixation (legal and academic prose vs. chat), which makes these
+//!   useful features for genre classification and readability research.
+//! - Word and character n-grams with their counts
+//! - Corpus statistics: term and document frequencies for TF-IDF keywords
+//!   and corpus-specific stopword candidates, and adjacent word pairs scored
+//!   by pointwise mutual information (PMI) for collocations
+
+use crate::analyzer::{self, Pos};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Morphological complexity of a sequence of words
+#[derive(Debug, Clone, PartialEq)]
+pub struct MorphComplexity {
+    /// Number of words considered
+    pub words: usize,
+    /// Words the analyzer could parse
+    pub analyzed: usize,
+    /// Mean morphemes per analyzed word (root + suffixes)
+    pub mean_morphemes_per_word: f64,
+    /// Shannon entropy (bits) of the suffix tag distribution
+    pub suffix_entropy: f64,
+    /// Verbs / nouns by the final (post-derivation) part of speech
+    pub verb_noun_ratio: f64,
+}
+
+impl MorphComplexity {
+    /// Share of words the analyzer could parse
+    pub fn coverage(&self) -> f64 {
+        if self.words == 0 {
+            0.0
+        } else {
+            self.analyzed as f64 / self.words as f64
+        }
+    }
+}
+
+/// Shannon entropy (bits) of a frequency table
+fn entropy(counts: &HashMap<&str, usize>) -> f64 {
+    let total: usize = counts.values().sum();
+    if total == 0 {
+        return 0.0;
+    }
+    counts
+        .values()
+        .map(|&count| {
+            let p = count as f64 / total as f64;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Compute morphological complexity over words
+///
+/// Words the analyzer cannot parse are counted in `words` but excluded from
+/// all other metrics.
+pub fn morphological_complexity<'a>(words: impl IntoIterator<Item = &'a str>) -> MorphComplexity {
+    let mut total = 0;
+    let mut analyzed = 0;
+    let mut morphemes = 0;
+    let mut verbs = 0;
+    let mut nouns = 0;
+    let mut tag_counts: HashMap<&str, usize> = HashMap::new();
+
+    for word in words {
+        total += 1;
+        let Some(best) = analyzer::analyze(word).into_iter().next() else {
+            continue;
+        };
+
+        analyzed += 1;
+        morphemes += 1 + best.morphemes.len();
+        for morpheme in &best.morphemes {
+            *tag_counts.entry(morpheme.tag).or_insert(0) += 1;
+        }
+        match best.final_pos() {
+            Pos::Verb => verbs += 1,
+            Pos::Noun => nouns += 1,
+            _ => {}
+        }
+    }
+
+    MorphComplexity {
+        words: total,
+        analyzed,
+        mean_morphemes_per_word: if analyzed == 0 {
+            0.0
+        } else {
+            morphemes as f64 / analyzed as f64
+        },
+        suffix_entropy: entropy(&tag_counts),
+        verb_noun_ratio: if nouns == 0 {
+            0.0
+        } else {
+            verbs as f64 / nouns as f64
+        },
+    }
+}
+
+/// Every run of `n` consecutive tokens, in order; none when `n` is 0 or
+/// longer than the sequence
+pub fn ngrams<T>(tokens: &[T], n: usize) -> impl Iterator<Item = &[T]> {
+    let n = if n == 0 { tokens.len() + 1 } else { n };
+    tokens.windows(n)
+}
+
+/// Every run of `n` consecutive characters of `text`, in order
+///
+/// # Examples
+/// ```text
+/// char_ngrams("kitap", 3) -> ["kit", "ita", "tap"]
+/// ```
+pub fn char_ngrams(text: &str, n: usize) -> Vec<&str> {
+    if n == 0 {
+        return Vec::new();
+    }
+    let bounds: Vec<usize> = text.char_indices().map(|(i, _)| i).chain([text.len()]).collect();
+    bounds.windows(n + 1).map(|w| &text[w[0]..w[n]]).collect()
+}
+
+/// Distinct items with their counts, most frequent first and ties in order
+/// of first appearance
+pub fn count<T: Hash + Eq>(items: impl IntoIterator<Item = T>) -> Vec<(T, usize)> {
+    let mut index: HashMap<T, usize> = HashMap::new();
+    let mut counts: Vec<usize> = Vec::new();
+    for item in items {
+        let i = *index.entry(item).or_insert_with(|| {
+            counts.push(0);
+            counts.len() - 1
+        });
+        counts[i] += 1;
+    }
+    let mut counted: Vec<(T, usize)> = index.into_iter().collect();
+    counted.sort_by_key(|&(_, i)| (std::cmp::Reverse(counts[i]), i));
+    counted.into_iter().map(|(item, i)| (item, counts[i])).collect()
+}
+
+/// Term, document and word pair counts of a corpus, added one document at
+/// a time
+#[derive(Debug, Clone, Default)]
+pub struct CorpusStats {
+    documents: usize,
+    tokens: usize,
+    bigrams: usize,
+    term_counts: HashMap<String, usize>,
+    document_counts: HashMap<String, usize>,
+    bigram_counts: HashMap<(String, String), usize>,
+}
+
+/// Adjacent word pair with its count and PMI (bits)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Collocation {
+    pub first: String,
+    pub second: String,
+    pub count: usize,
+    pub pmi: f64,
+}
+
+impl CorpusStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count the words of one document
+    pub fn add_document<'a>(&mut self, words: impl IntoIterator<Item = &'a str>) {
+        let words: Vec<&str> = words.into_iter().collect();
+        self.documents += 1;
+        self.tokens += words.len();
+        let mut seen: Vec<&str> = Vec::new();
+        for &word in &words {
+            *self.term_counts.entry(word.to_string()).or_insert(0) += 1;
+            if !seen.contains(&word) {
+                seen.push(word);
+                *self.document_counts.entry(word.to_string()).or_insert(0) += 1;
+            }
+        }
+        for pair in words.windows(2) {
+            self.bigrams += 1;
+            let key = (pair[0].to_string(), pair[1].to_string());
+            *self.bigram_counts.entry(key).or_insert(0) += 1;
+        }
+    }
+
+    pub fn documents(&self) -> usize {
+        self.documents
+    }
+
+    pub fn tokens(&self) -> usize {
+        self.tokens
+    }
+
+    /// Occurrences of `term` in the corpus
+    pub fn term_frequency(&self, term: &str) -> usize {
+        self.term_counts.get(term).copied().unwrap_or(0)
+    }
+
+    /// Documents containing `term`
+    pub fn document_frequency(&self, term: &str) -> usize {
+        self.document_counts.get(term).copied().unwrap_or(0)
+    }
+
+    /// Smoothed inverse document frequency, ln((1 + N) / (1 + df)) + 1, as
+    /// in scikit-learn; always positive, 1.0 for a term in every document
+    pub fn idf(&self, term: &str) -> f64 {
+        let n = self.documents as f64;
+        ((1.0 + n) / (1.0 + self.document_frequency(term) as f64)).ln() + 1.0
+    }
+
+    /// Relative corpus frequency of `term` times its IDF; 0.0 for terms not
+    /// in the corpus
+    pub fn tfidf(&self, term: &str) -> f64 {
+        match self.term_frequency(term) {
+            0 => 0.0,
+            tf => tf as f64 / self.tokens as f64 * self.idf(term),
+        }
+    }
+
+    /// The `k` terms with the highest TF-IDF, ties in alphabetical order
+    pub fn top_keywords(&self, k: usize) -> Vec<(String, f64)> {
+        let mut scored: Vec<(String, f64)> =
+            self.term_counts.keys().map(|term| (term.clone(), self.tfidf(term))).collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        scored.truncate(k);
+        scored
+    }
+
+    /// The `k` likeliest corpus-specific stopwords among terms in at least
+    /// `min_df` documents, scored by relative corpus frequency over IDF so
+    /// frequent terms spread across many documents come first; ties in
+    /// alphabetical order
+    pub fn stopword_candidates(&self, k: usize, min_df: usize) -> Vec<(String, f64)> {
+        let mut scored: Vec<(String, f64)> = self
+            .document_counts
+            .iter()
+            .filter(|&(_, &df)| df >= min_df)
+            .map(|(term, _)| {
+                let frequency = self.term_frequency(term) as f64 / self.tokens as f64;
+                (term.clone(), frequency / self.idf(term))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        scored.truncate(k);
+        scored
+    }
+
+    /// The `k` adjacent word pairs seen at least `min_count` times with the
+    /// highest PMI, log2(p(xy) / (p(x) p(y))); ties go to the more frequent
+    /// pair, then alphabetical order
+    pub fn top_collocations(&self, k: usize, min_count: usize) -> Vec<Collocation> {
+        let probability = |count: usize, total: usize| count as f64 / total as f64;
+        let mut scored: Vec<Collocation> = self
+            .bigram_counts
+            .iter()
+            .filter(|&(_, &count)| count >= min_count.max(1))
+            .map(|((first, second), &count)| {
+                let joint = probability(count, self.bigrams);
+                let p_first = probability(self.term_frequency(first), self.tokens);
+                let p_second = probability(self.term_frequency(second), self.tokens);
+                Collocation {
+                    first: first.clone(),
+                    second: second.clone(),
+                    count,
+                    pmi: (joint / (p_first * p_second)).log2(),
+                }
+            })
+            .collect();
+        scored.sort_by(|a, b| {
+            b.pmi
+                .total_cmp(&a.pmi)
+                .then_with(|| b.count.cmp(&a.count))
+                .then_with(|| (&a.first, &a.second).cmp(&(&b.first, &b.second)))
+        });
+        scored.truncate(k);
+        scored
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entropy() {
+        let mut counts = HashMap::new();
+        assert_eq!(entropy(&counts), 0.0);
+        counts.insert("Pl", 2);
+        assert_eq!(entropy(&counts), 0.0);
+        counts.insert("Loc", 2);
+        assert!((entropy(&counts) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_morphological_complexity() {
+        let stats = morphological_complexity(["kitaplarımdan", "geliyorum", "ev", "xyzabc"]);
+        assert_eq!(stats.words, 4);
+        assert_eq!(stats.analyzed, 3);
+        // (1 + 3) + (1 + 2) + 1 morphemes over 3 words
+        assert!((stats.mean_morphemes_per_word - 8.0 / 3.0).abs() < 1e-9);
+        // One verb (geliyorum) against two nouns (kitaplarımdan, ev)
+        assert!((stats.verb_noun_ratio - 0.5).abs() < 1e-9);
+        assert!(stats.suffix_entropy > 2.0);
+        assert!((stats.coverage() - 0.75).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let stats = morphological_complexity(std::iter::empty());
+        assert_eq!(stats.words, 0);
+        assert_eq!(stats.mean_morphemes_per_word, 0.0);
+        assert_eq!(stats.coverage(), 0.0);
+    }
+
+    #[test]
+    fn test_ngrams() {
+        let tokens = ["ben", "de", "geldim"];
+        let bigrams: Vec<&[&str]> = ngrams(&tokens, 2).collect();
+        assert_eq!(bigrams, vec![&["ben", "de"][..], &["de", "geldim"][..]]);
+        assert_eq!(ngrams(&tokens, 3).count(), 1);
+        assert_eq!(ngrams(&tokens, 4).count(), 0);
+        assert_eq!(ngrams(&tokens, 0).count(), 0);
+    }
+
+    #[test]
+    fn test_char_ngrams() {
+        assert_eq!(char_ngrams("kitap", 3), vec!["kit", "ita", "tap"]);
+        assert_eq!(char_ngrams("çiğ", 2), vec!["çi", "iğ"]);
+        assert!(char_ngrams("ev", 3).is_empty());
+        assert!(char_ngrams("ev", 0).is_empty());
+    }
+
+    #[test]
+    fn test_count() {
+        let counted = count(["b", "a", "b", "c", "a", "b"]);
+        assert_eq!(counted, vec![("b", 3), ("a", 2), ("c", 1)]);
+        assert_eq!(count(["y", "x"]), vec![("y", 1), ("x", 1)]);
+    }
+
+    fn corpus() -> CorpusStats {
+        let mut stats = CorpusStats::new();
+        for document in [
+            "türk kahvesi içtim ve çay içtim",
+            "türk kahvesi ve çay",
+            "bugün çay içtim",
+        ] {
+            stats.add_document(document.split(' '));
+        }
+        stats
+    }
+
+    #[test]
+    fn test_corpus_counts() {
+        let stats = corpus();
+        assert_eq!((stats.documents(), stats.tokens()), (3, 13));
+        assert_eq!(stats.term_frequency("içtim"), 3);
+        assert_eq!(stats.document_frequency("içtim"), 2);
+        assert_eq!(stats.document_frequency("yok"), 0);
+    }
+
+    #[test]
+    fn test_tfidf() {
+        let stats = corpus();
+        assert_eq!(stats.idf("çay"), 1.0);
+        assert_eq!(stats.tfidf("yok"), 0.0);
+        // 2 of 13 tokens, in 2 of 3 documents
+        let expected = 2.0 / 13.0 * ((4.0f64 / 3.0).ln() + 1.0);
+        assert!((stats.tfidf("türk") - expected).abs() < 1e-12);
+        assert!(stats.tfidf("bugün") > stats.tfidf("ve") / 2.0);
+        let keywords = stats.top_keywords(2);
+        assert_eq!(keywords[0].0, "içtim");
+        assert_eq!(keywords.len(), 2);
+    }
+
+    #[test]
+    fn test_stopword_candidates() {
+        let stats = corpus();
+        let terms: Vec<String> =
+            stats.stopword_candidates(5, 2).into_iter().map(|(term, _)| term).collect();
+        assert_eq!(terms, ["çay", "içtim", "kahvesi", "türk", "ve"]);
+        // çay: 3 of 13 tokens, in every document
+        let top = stats.stopword_candidates(1, 3);
+        assert_eq!(top, vec![("çay".to_string(), 3.0 / 13.0)]);
+        assert!(stats.stopword_candidates(10, 4).is_empty());
+        assert_eq!(stats.stopword_candidates(100, 1).len(), 6);
+    }
+
+    #[test]
+    fn test_collocations() {
+        let stats = corpus();
+        let top = stats.top_collocations(1, 2);
+        assert_eq!((top[0].first.as_str(), top[0].second.as_str()), ("türk", "kahvesi"));
+        assert_eq!(top[0].count, 2);
+        // 2/10 bigrams against 2/13 and 2/13 tokens
+        let expected = (0.2f64 / (2.0 / 13.0 * 2.0 / 13.0)).log2();
+        assert!((top[0].pmi - expected).abs() < 1e-12);
+        assert!(stats.top_collocations(10, 3).is_empty());
+        assert_eq!(stats.top_collocations(10, 1).len(), 7);
+    }
+}
@@ -0,0 +1,127 @@
+//! Aggressive stemming for retrieval
+//!
+//! Stems are not lemmas. Search indexes only need the forms of a word to
+//! collapse to the same key, and a stemmer that overshoots ("okul" → "ok")
+//! costs less there than a lemmatizer that misses a dictionary entry. Two
+//! methods, neither of which consults the lemma dictionary:
+//!
+//! - `Cascade` strips known suffixes, longest first, until none fits or the
+//!   stem would lose its last vowel or drop below `MIN_STEM` characters.
+//! - `Prefix` keeps the first characters of the word, the fixed-prefix
+//!   stemming that works well for Turkish retrieval at five characters.
+//!
+//! Both lowercase with Turkish I rules first. Proper nouns are cut at the
+//! apostrophe and nothing more ("Ankara'dan" → "ankara").
+
+use crate::{fast_normalize, is_blob, split_clitics, suffixes};
+use std::cmp::Reverse;
+use std::sync::OnceLock;
+
+/// Shortest stem `Cascade` leaves, in characters
+pub const MIN_STEM: usize = 2;
+
+/// Characters `Prefix` keeps by default
+pub const DEFAULT_PREFIX_LENGTH: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Cascade,
+    /// Keep this many characters
+    Prefix(usize),
+}
+
+/// Single nominal and verbal suffixes, longest first. Compound suffixes are
+/// left out so `max_suffixes` counts morphemes, and one-letter suffixes
+/// because nearly every word ends in one ("araba", "kalem").
+fn sorted_suffixes() -> &'static [&'static str] {
+    static SORTED: OnceLock<Vec<&'static str>> = OnceLock::new();
+    SORTED.get_or_init(|| {
+        let mut all: Vec<&'static str> = suffixes::NOMINAL_SUFFIXES
+            .iter()
+            .chain(suffixes::VERBAL_SUFFIXES)
+            .copied()
+            .filter(|suffix| suffix.chars().count() > 1)
+            .collect();
+        all.sort_by_key(|suffix| Reverse(suffix.chars().count()));
+        all.dedup();
+        all
+    })
+}
+
+fn has_vowel(text: &str) -> bool {
+    text.chars().any(|c| "aeıioöuüâîû".contains(c))
+}
+
+/// Strip up to `max_suffixes` suffixes (all that fit when None)
+fn cascade(word: &str, max_suffixes: Option<usize>) -> &str {
+    let mut stem = word;
+    let mut stripped = 0;
+    while max_suffixes.map_or(true, |max| stripped < max) {
+        let next = sorted_suffixes().iter().find_map(|suffix| {
+            let rest = stem.strip_suffix(suffix)?;
+            (rest.chars().count() >= MIN_STEM && has_vowel(rest)).then_some(rest)
+        });
+        match next {
+            Some(rest) => stem = rest,
+            None => break,
+        }
+        stripped += 1;
+    }
+    stem
+}
+
+/// Stem of a word for indexing; `max_suffixes` only limits `Cascade`
+///
+/// # Examples
+/// ```text
+/// stem("kitaplarımızdan", Method::Cascade, None)    -> "kitap"
+/// stem("kitaplarımızdan", Method::Cascade, Some(1)) -> "kitaplarımız"
+/// stem("kitaplarımızdan", Method::Prefix(5), None)  -> "kitap"
+/// stem("Ankara'dan", Method::Cascade, None)         -> "ankara"
+/// ```
+pub fn stem(word: &str, method: Method, max_suffixes: Option<usize>) -> String {
+    if is_blob(word) {
+        return word.to_string();
+    }
+    // The apostrophe marks the root boundary exactly
+    let (base, clitic) = split_clitics(word);
+    let word = fast_normalize(&base, true, true);
+    match method {
+        Method::Cascade if !clitic.is_empty() => word,
+        Method::Cascade => cascade(&word, max_suffixes).to_string(),
+        Method::Prefix(length) => word.chars().take(length).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cascade() {
+        for (word, expected) in [
+            ("kitaplarımızdan", "kitap"),
+            ("evlerde", "ev"),
+            ("geliyorum", "gel"),
+            ("İstanbul'da", "istanbul"),
+            ("ev", "ev"),
+        ] {
+            assert_eq!(stem(word, Method::Cascade, None), expected, "{}", word);
+        }
+        assert_eq!(
+            stem("kitaplarımızdan", Method::Cascade, Some(1)),
+            "kitaplarımız"
+        );
+        assert_eq!(
+            stem("kitaplarımızdan", Method::Cascade, Some(0)),
+            "kitaplarımızdan"
+        );
+    }
+
+    #[test]
+    fn test_prefix() {
+        assert_eq!(stem("Kitaplarımızdan", Method::Prefix(5), None), "kitap");
+        assert_eq!(stem("ev", Method::Prefix(5), None), "ev");
+        assert_eq!(stem("ISPARTA'ya", Method::Prefix(4), None), "ıspa");
+    }
+}
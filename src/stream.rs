@@ -0,0 +1,189 @@
+//! Streaming tokenization of large inputs
+//!
+//! Input is read in fixed-size blocks and handed to the tokenizer one chunk
+//! at a time, so memory use is bounded by the chunk size rather than the
+//! file size. Chunks end on a sentence boundary where the block contains
+//! one, otherwise on whitespace; tokens never span whitespace, so the token
+//! sequence is identical to tokenizing the whole input at once.
+
+use std::io::{self, Read};
+
+/// Default read block size in bytes
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Blocks buffered without any whitespace before a chunk is cut anyway
+const MAX_BLOCKS_WITHOUT_BREAK: usize = 4;
+
+/// Byte index just past the last sentence end (`.`, `!`, `?`, `…` followed by
+/// whitespace) in `text`, or past the last whitespace if there is none
+fn last_boundary(text: &str) -> Option<usize> {
+    let mut sentence_end = None;
+    let mut whitespace_end = None;
+    let mut previous = None;
+
+    for (index, c) in text.char_indices() {
+        if c.is_whitespace() {
+            let end = index + c.len_utf8();
+            whitespace_end = Some(end);
+            if matches!(previous, Some('.' | '!' | '?' | '…')) {
+                sentence_end = Some(end);
+            }
+        }
+        previous = Some(c);
+    }
+    sentence_end.or(whitespace_end)
+}
+
+/// Longest prefix of `bytes` that ends on a UTF-8 character boundary
+fn valid_prefix_len(bytes: &[u8]) -> io::Result<usize> {
+    match std::str::from_utf8(bytes) {
+        Ok(_) => Ok(bytes.len()),
+        // An incomplete character at the end is completed by the next read
+        Err(e) if e.error_len().is_none() => Ok(e.valid_up_to()),
+        Err(e) => Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+    }
+}
+
+/// Splits a UTF-8 byte stream into text chunks on sentence boundaries
+pub struct SentenceChunks<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    chunk_size: usize,
+    eof: bool,
+}
+
+impl<R: Read> SentenceChunks<R> {
+    pub fn new(reader: R, chunk_size: usize) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            chunk_size: chunk_size.max(1),
+            eof: false,
+        }
+    }
+
+    /// Read one block into the buffer, returning false at end of input
+    fn fill(&mut self) -> io::Result<bool> {
+        let start = self.buffer.len();
+        self.buffer.resize(start + self.chunk_size, 0);
+        let read = loop {
+            match self.reader.read(&mut self.buffer[start..]) {
+                Ok(read) => break read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    self.buffer.truncate(start);
+                    return Err(e);
+                }
+            }
+        };
+        self.buffer.truncate(start + read);
+        Ok(read > 0)
+    }
+
+    /// Remove and return the first `end` bytes of the buffer as text
+    fn take(&mut self, end: usize) -> String {
+        let rest = self.buffer.split_off(end);
+        let chunk = std::mem::replace(&mut self.buffer, rest);
+        // Callers only cut at validated character boundaries
+        String::from_utf8(chunk).expect("chunk ends on a character boundary")
+    }
+
+    /// Next chunk of text, or `None` once the input is exhausted
+    pub fn next_chunk(&mut self) -> io::Result<Option<String>> {
+        loop {
+            if !self.eof && self.buffer.len() < self.chunk_size {
+                self.eof = !self.fill()?;
+                continue;
+            }
+
+            let valid = valid_prefix_len(&self.buffer)?;
+            if self.eof {
+                if valid < self.buffer.len() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "stream ends inside a UTF-8 character",
+                    ));
+                }
+                if self.buffer.is_empty() {
+                    return Ok(None);
+                }
+                return Ok(Some(self.take(valid)));
+            }
+
+            // The prefix is valid UTF-8: the check above succeeded
+            let text = std::str::from_utf8(&self.buffer[..valid]).unwrap_or_default();
+            if let Some(end) = last_boundary(text) {
+                return Ok(Some(self.take(end)));
+            }
+            if self.buffer.len() >= self.chunk_size * MAX_BLOCKS_WITHOUT_BREAK && valid > 0 {
+                return Ok(Some(self.take(valid)));
+            }
+            self.eof = !self.fill()?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(text: &str, chunk_size: usize) -> Vec<String> {
+        let mut stream = SentenceChunks::new(text.as_bytes(), chunk_size);
+        let mut chunks = Vec::new();
+        while let Some(chunk) = stream.next_chunk().unwrap() {
+            chunks.push(chunk);
+        }
+        chunks
+    }
+
+    #[test]
+    fn test_last_boundary() {
+        assert_eq!(last_boundary("Bir. İki"), Some(5));
+        assert_eq!(last_boundary("Bir. İki üç"), Some(5));
+        assert_eq!(last_boundary("bir iki"), Some(4));
+        assert_eq!(last_boundary("biriki"), None);
+    }
+
+    #[test]
+    fn test_chunks_end_on_sentences() {
+        let text = "Merhaba dünya. Nasılsın? İyiyim!\nTeşekkürler.";
+        let result = chunks(text, 16);
+        assert_eq!(result.concat(), text);
+        assert!(result.len() > 1);
+        for chunk in &result[..result.len() - 1] {
+            assert!(chunk.ends_with(char::is_whitespace), "{:?}", chunk);
+        }
+    }
+
+    #[test]
+    fn test_multibyte_characters_across_reads() {
+        // Every read of 3 bytes cuts a two-byte character in half somewhere
+        let text = "çğıöşü ÇĞİÖŞÜ çğıöşü";
+        assert_eq!(chunks(text, 3).concat(), text);
+    }
+
+    #[test]
+    fn test_text_without_whitespace() {
+        let text = "a".repeat(100);
+        let result = chunks(&text, 8);
+        assert_eq!(result.concat(), text);
+        assert!(result
+            .iter()
+            .all(|chunk| chunk.len() <= 8 * MAX_BLOCKS_WITHOUT_BREAK));
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let mut stream = SentenceChunks::new(&[b'a', 0xff, b' '][..], 16);
+        let error = stream.next_chunk().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        let mut truncated = SentenceChunks::new(&[b'a', 0xc3][..], 16);
+        assert!(truncated.next_chunk().is_err());
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert!(chunks("", 16).is_empty());
+    }
+}
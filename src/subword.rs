@@ -0,0 +1,184 @@
+//! Total morpheme segmentation for subword exports
+//!
+//! Language model training needs every token split into pieces, but the
+//! analyzer only segments words it can parse. `segment_word` returns the
+//! root and suffixes of the first analysis when there is one and otherwise
+//! falls back to a deterministic segmentation covering the whole token: one
+//! piece per character, or one `<0xNN>` piece per UTF-8 byte (SentencePiece's
+//! byte-fallback notation). The first piece of a word carries the `▁` word
+//! marker (in byte mode `▁` is a piece of its own), so joining the pieces and
+//! turning `▁` into spaces gives back the normalized text.
+
+use crate::analyzer;
+use crate::fast_normalize;
+use crate::unigram::SPACE;
+
+/// Segmentation for tokens the analyzer cannot segment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fallback {
+    Char,
+    Byte,
+}
+
+impl Fallback {
+    pub fn parse(name: &str) -> Option<Fallback> {
+        match name {
+            "char" => Some(Fallback::Char),
+            "byte" => Some(Fallback::Byte),
+            _ => None,
+        }
+    }
+}
+
+/// Piece for a single byte: `<0xE2>`
+pub fn byte_piece(byte: u8) -> String {
+    format!("<0x{:02X}>", byte)
+}
+
+/// Byte of a `<0xNN>` piece
+pub fn parse_byte_piece(piece: &str) -> Option<u8> {
+    let hex = piece.strip_prefix("<0x")?.strip_suffix('>')?;
+    if hex.len() != 2 {
+        return None;
+    }
+    u8::from_str_radix(hex, 16).ok()
+}
+
+/// Join pieces into text, decoding runs of byte pieces as UTF-8 (invalid
+/// sequences become U+FFFD) and `▁` as a space between words
+pub fn join_pieces<S: AsRef<str>>(pieces: &[S]) -> String {
+    let mut text = String::new();
+    let mut bytes = Vec::new();
+    for piece in pieces {
+        match parse_byte_piece(piece.as_ref()) {
+            Some(byte) => bytes.push(byte),
+            None => {
+                text.push_str(&String::from_utf8_lossy(&bytes));
+                bytes.clear();
+                text.push_str(piece.as_ref());
+            }
+        }
+    }
+    text.push_str(&String::from_utf8_lossy(&bytes));
+    let text = text.replace(SPACE, " ");
+    text.strip_prefix(' ').unwrap_or(&text).to_string()
+}
+
+/// Root and suffix surfaces of the first analysis of a normalized word,
+/// if they spell the word: "kitaplarım" -> ["kitap", "lar", "ım"]
+pub fn analyzer_pieces(word: &str) -> Option<Vec<String>> {
+    let analyses = analyzer::analyze(word);
+    let analysis = analyses.first()?;
+    let suffixes: String = analysis
+        .morphemes
+        .iter()
+        .map(|m| m.surface.as_str())
+        .collect();
+    let root = word.strip_suffix(suffixes.as_str())?;
+    if root.is_empty() {
+        return None;
+    }
+    let suffixes = analysis
+        .morphemes
+        .iter()
+        .filter(|m| !m.surface.is_empty())
+        .map(|m| m.surface.clone());
+    Some(std::iter::once(root.to_string()).chain(suffixes).collect())
+}
+
+fn fallback_pieces(word: &str, fallback: Fallback) -> Vec<String> {
+    match fallback {
+        Fallback::Char => word
+            .chars()
+            .enumerate()
+            .map(|(i, c)| match i {
+                0 => format!("{}{}", SPACE, c),
+                _ => c.to_string(),
+            })
+            .collect(),
+        Fallback::Byte => std::iter::once(SPACE.to_string())
+            .chain(word.bytes().map(byte_piece))
+            .collect(),
+    }
+}
+
+fn segment_normalized(word: &str, fallback: Fallback) -> (Vec<String>, bool) {
+    match analyzer_pieces(word) {
+        Some(mut pieces) => {
+            pieces[0].insert(0, SPACE);
+            (pieces, true)
+        }
+        None => (fallback_pieces(word, fallback), false),
+    }
+}
+
+/// Segment one token; the flag tells whether the analyzer segmented it
+pub fn segment_word(token: &str, fallback: Fallback) -> (Vec<String>, bool) {
+    segment_normalized(&fast_normalize(token, true, true), fallback)
+}
+
+/// Normalize and tokenize text, then segment every token. Tokens with
+/// inner spaces (phone numbers, grouped IBANs) are segmented per part.
+pub fn segment_text(text: &str, fallback: Fallback) -> Vec<String> {
+    let normalized = fast_normalize(text, true, true);
+    crate::chunk_tokens(&normalized, false)
+        .iter()
+        .flat_map(|token| token.split_whitespace())
+        .flat_map(|part| segment_normalized(part, fallback).0)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analyzed_word() {
+        assert_eq!(
+            segment_word("Kitaplarımdan", Fallback::Byte),
+            (
+                vec![
+                    "▁kitap".to_string(),
+                    "lar".to_string(),
+                    "ım".to_string(),
+                    "dan".to_string()
+                ],
+                true
+            )
+        );
+    }
+
+    #[test]
+    fn test_fallback() {
+        assert_eq!(
+            segment_word("xğ", Fallback::Char),
+            (vec!["▁x".to_string(), "ğ".to_string()], false)
+        );
+        assert_eq!(
+            segment_word("xğ", Fallback::Byte).0,
+            vec!["▁", "<0x78>", "<0xC4>", "<0x9F>"]
+        );
+    }
+
+    #[test]
+    fn test_segment_text_is_total() {
+        let text = "Kitaplarımdan birini 3 günde okudum 😀 www.example.com";
+        for fallback in [Fallback::Char, Fallback::Byte] {
+            let pieces = segment_text(text, fallback);
+            assert_eq!(
+                join_pieces(&pieces),
+                "kitaplarımdan birini 3 günde okudum 😀 www.example.com"
+            );
+        }
+    }
+
+    #[test]
+    fn test_byte_pieces() {
+        assert_eq!(byte_piece(0x0A), "<0x0A>");
+        assert_eq!(parse_byte_piece("<0xff>"), Some(0xFF));
+        assert_eq!(parse_byte_piece("<0x1>"), None);
+        assert_eq!(parse_byte_piece("<unk>"), None);
+        // A cut multi-byte character decodes to U+FFFD
+        assert_eq!(join_pieces(&["▁", "<0xC4>", "a"]), "\u{FFFD}a");
+    }
+}
@@ -0,0 +1,217 @@
+//! Ranked correction candidates shared by the correction features
+//!
+//! Spelling suggestions, diacritic restoration ("deascii") and confusion
+//! pairs each produce candidates for a word; `suggest` pools them into one
+//! list of `Suggestion`s scored with a noisy-channel model:
+//!
+//! - Prior: Zipf probability of the candidate's root in
+//!   resources/tr/lemmas/lemma_frequency.txt, 1/(rank+1); roots missing from
+//!   the ranking score as rank 3×size
+//! - Channel: `EDIT_PROB` per edit ignoring diacritics, `DIACRITIC_PROB` per
+//!   missing diacritic, and `NEIGHBOUR_GAIN` for each edit on a neighbouring
+//!   key of the Turkish Q keyboard
+//! - Confusion pairs are unambiguous by construction and weigh `1.0`
+//!
+//! Scores are normalized over the candidate pool, so they sum to at most
+//! 1.0 over the returned list and are comparable across sources.
+
+use crate::analyzer;
+use crate::confusion;
+use crate::spell;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+static LEMMA_FREQUENCY_DATA: &str = include_str!("../resources/tr/lemmas/lemma_frequency.txt");
+
+static RANKS: OnceLock<HashMap<&'static str, usize>> = OnceLock::new();
+
+/// Rank of a root missing from the frequency ranking, in multiples of its size
+const MISSING_RANK_FACTOR: usize = 3;
+
+/// Probability of one edit ignoring diacritics
+const EDIT_PROB: f64 = 0.01;
+
+/// Probability of one missing or wrong diacritic (c for ç, i for ı)
+const DIACRITIC_PROB: f64 = 0.5;
+
+/// How much likelier a typo on a neighbouring key is than on any other
+const NEIGHBOUR_GAIN: f64 = 4.0;
+
+/// Spelling candidates drawn per requested suggestion, so that the
+/// frequency prior can reorder the spelling checker's ranking
+const CANDIDATE_FACTOR: usize = 4;
+
+/// The feature that proposed a candidate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// Spelling correction within an edit distance
+    Spell,
+    /// The same letters with diacritics restored ("cocugu" → "çocuğu")
+    Deascii,
+    /// A curated confusion pair ("yanlız" → "yalnız")
+    Confusion,
+}
+
+impl Source {
+    /// Label used by the Python API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Source::Spell => "spell",
+            Source::Deascii => "deascii",
+            Source::Confusion => "confusion",
+        }
+    }
+}
+
+/// A correction candidate with its score in (0, 1]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub text: String,
+    pub score: f64,
+    pub source: Source,
+}
+
+fn ranks() -> &'static HashMap<&'static str, usize> {
+    RANKS.get_or_init(|| {
+        let mut ranks = HashMap::new();
+        let lemmas = LEMMA_FREQUENCY_DATA
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+        for lemma in lemmas {
+            let rank = ranks.len();
+            ranks.entry(lemma).or_insert(rank);
+        }
+        ranks
+    })
+}
+
+/// Rank of a lemma in the frequency ranking, 0 for the most frequent
+pub fn frequency_rank(lemma: &str) -> Option<usize> {
+    ranks().get(lemma).copied()
+}
+
+/// Zipf probability of the root of a candidate
+fn prior(candidate: &str) -> f64 {
+    let ranks = ranks();
+    let root = analyzer::analyze(candidate).into_iter().next().map(|a| a.root);
+    let rank = root
+        .and_then(|root| ranks.get(root.as_str()).copied())
+        .unwrap_or(MISSING_RANK_FACTOR * ranks.len());
+    1.0 / (rank + 1) as f64
+}
+
+/// Up to `k` correction candidates for a word from every source, best
+/// first. A correctly spelled word is a candidate of its own, so it comes
+/// first unless a diacritic variant or confusion pair is more likely.
+///
+/// # Examples
+/// ```text
+/// suggest("cocugu", 2, 3)[0]  -> çocuğu (deascii)
+/// suggest("yanlız", 2, 3)[0]  -> yalnız (confusion)
+/// suggest("geliyorm", 2, 3)   -> [geliyor, geliyorum, ...] (spell)
+/// ```
+pub fn suggest(word: &str, max_edits: usize, k: usize) -> Vec<Suggestion> {
+    let word = spell::stem(word);
+    if word.is_empty() || k == 0 {
+        return Vec::new();
+    }
+    let mut pool: Vec<(String, f64, Source)> = Vec::new();
+    if let Some(correction) = confusion::lookup(&word) {
+        pool.push((correction.to_string(), 1.0, Source::Confusion));
+    }
+    let word_chars = word.chars().count();
+    for variant in spell::diacritic_variants(&word) {
+        let changed = variant.chars().zip(word.chars()).filter(|(a, b)| a != b).count();
+        if changed > 0 && variant.chars().count() == word_chars {
+            let weight = prior(&variant) * DIACRITIC_PROB.powi(changed as i32);
+            pool.push((variant, weight, Source::Deascii));
+        }
+    }
+    for candidate in spell::suggest(&word, max_edits, k * CANDIDATE_FACTOR) {
+        let diacritics = candidate.raw_edits.saturating_sub(candidate.edits);
+        let neighbours = (2 * candidate.raw_edits).saturating_sub(candidate.keyboard_cost);
+        let channel = EDIT_PROB.powi(candidate.edits as i32)
+            * DIACRITIC_PROB.powi(diacritics as i32)
+            * NEIGHBOUR_GAIN.powi(neighbours.min(candidate.edits) as i32);
+        let source = match candidate.edits == 0 && candidate.word != word {
+            true => Source::Deascii,
+            false => Source::Spell,
+        };
+        let weight = prior(&candidate.word) * channel;
+        pool.push((candidate.word, weight, source));
+    }
+
+    // One entry per text, with its best weight and the source that gave it
+    let mut best: Vec<(String, f64, Source)> = Vec::new();
+    for (text, weight, source) in pool {
+        match best.iter_mut().find(|(t, _, _)| *t == text) {
+            Some(entry) if entry.1 < weight => *entry = (text, weight, source),
+            Some(_) => {}
+            None => best.push((text, weight, source)),
+        }
+    }
+    let total: f64 = best.iter().map(|(_, weight, _)| weight).sum();
+    let mut suggestions: Vec<Suggestion> = best
+        .into_iter()
+        .map(|(text, weight, source)| Suggestion {
+            text,
+            score: weight / total,
+            source,
+        })
+        .collect();
+    suggestions.sort_by(|a, b| b.score.total_cmp(&a.score));
+    suggestions.truncate(k);
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(suggestions: &[Suggestion]) -> Vec<&str> {
+        suggestions.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_ranks_parse() {
+        assert_eq!(ranks().get("bu"), Some(&0));
+        assert!(prior("kitaplar") > prior("xyzqwvbn"));
+    }
+
+    #[test]
+    fn test_sources() {
+        let deascii = suggest("cocugu", 2, 3);
+        assert_eq!(deascii[0].text, "çocuğu");
+        assert_eq!(deascii[0].source, Source::Deascii);
+        let confusion = suggest("Yanlız", 2, 3);
+        assert_eq!(confusion[0].text, "yalnız");
+        assert_eq!(confusion[0].source, Source::Confusion);
+        let spell = suggest("geliyorm", 2, 5);
+        assert!(spell.iter().all(|s| s.source == Source::Spell));
+        assert!(texts(&spell).contains(&"geliyorum"));
+    }
+
+    #[test]
+    fn test_scores() {
+        let suggestions = suggest("kitaplarimdan", 2, 5);
+        assert_eq!(suggestions[0].text, "kitaplarımdan");
+        assert!(suggestions.windows(2).all(|w| w[0].score >= w[1].score));
+        let total: f64 = suggestions.iter().map(|s| s.score).sum();
+        assert!(total <= 1.0 + 1e-9 && suggestions.iter().all(|s| s.score > 0.0));
+        // A correct word is its own best candidate
+        let correct = suggest("kitaplarımdan", 2, 5);
+        assert_eq!(correct[0].text, "kitaplarımdan");
+        assert_eq!(correct[0].source, Source::Spell);
+        // A neighbouring key outweighs the frequency of the root
+        assert_eq!(suggest("okuk", 1, 5)[0].text, "okul");
+    }
+
+    #[test]
+    fn test_limits() {
+        assert!(suggest("geliyorm", 2, 0).is_empty());
+        assert!(suggest("", 2, 5).is_empty());
+        assert!(suggest("xyzqwvbn", 1, 5).is_empty());
+        assert!(suggest("geliyorm", 2, 2).len() <= 2);
+    }
+}
@@ -0,0 +1,355 @@
+//! Token patterns, one regex per token class
+//!
+//! The tokenizer used to be one alternation of every class. Here each class
+//! is compiled on its own, and a `RegexSet` of cheap prefilters (a literal
+//! or character every match of the class must contain) tells in one pass
+//! which classes can occur in a text at all, so the scan only runs the
+//! regexes that can match. A `Scanner` merges the per-class matches with the
+//! semantics of the old alternation: the leftmost match wins, and at the
+//! same start the class listed first in `TokenClass::ALL` wins.
+//!
+//! Every class but the base ones (apostrophe words, numbers, words and
+//! punctuation) can be left out through a `ClassSet`, per tokenizer and
+//! without compiling anything: its text then falls apart into base tokens.
+//! Each class keeps the last match it found and only searches again once the
+//! scan has moved past it, so a text is searched about once per class.
+
+use crate::{bidi, datetime_tr};
+use regex::{Regex, RegexSet};
+use std::sync::OnceLock;
+
+/// Token classes in priority order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenClass {
+    Url,
+    Email,
+    Iban,
+    Phone,
+    Date,
+    Time,
+    Hashtag,
+    Mention,
+    Emoticon,
+    /// A word with an apostrophe suffix ("İstanbul'a")
+    Apostrophe,
+    Number,
+    Word,
+    Punct,
+}
+
+impl TokenClass {
+    pub const ALL: [TokenClass; 13] = [
+        TokenClass::Url,
+        TokenClass::Email,
+        TokenClass::Iban,
+        TokenClass::Phone,
+        TokenClass::Date,
+        TokenClass::Time,
+        TokenClass::Hashtag,
+        TokenClass::Mention,
+        TokenClass::Emoticon,
+        TokenClass::Apostrophe,
+        TokenClass::Number,
+        TokenClass::Word,
+        TokenClass::Punct,
+    ];
+
+    /// Classes a tokenizer can leave out
+    pub const OPTIONAL: [TokenClass; 9] = [
+        TokenClass::Url,
+        TokenClass::Email,
+        TokenClass::Iban,
+        TokenClass::Phone,
+        TokenClass::Date,
+        TokenClass::Time,
+        TokenClass::Hashtag,
+        TokenClass::Mention,
+        TokenClass::Emoticon,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            TokenClass::Url => "url",
+            TokenClass::Email => "email",
+            TokenClass::Iban => "iban",
+            TokenClass::Phone => "phone",
+            TokenClass::Date => "date",
+            TokenClass::Time => "time",
+            TokenClass::Hashtag => "hashtag",
+            TokenClass::Mention => "mention",
+            TokenClass::Emoticon => "emoticon",
+            TokenClass::Apostrophe => "apostrophe",
+            TokenClass::Number => "number",
+            TokenClass::Word => "word",
+            TokenClass::Punct => "punct",
+        }
+    }
+
+    /// The optional class of a name, None for unknown and base classes
+    pub fn parse_optional(name: &str) -> Option<TokenClass> {
+        Self::OPTIONAL.into_iter().find(|class| class.name() == name)
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// What every match of an optional class contains; it has no word
+    /// boundaries, so the whole prefilter set runs as one DFA
+    fn prefilter(self) -> &'static str {
+        match self {
+            TokenClass::Url => r"https?://|www\.",
+            TokenClass::Email | TokenClass::Mention => "@",
+            TokenClass::Iban => r"TR\d",
+            TokenClass::Phone | TokenClass::Date | TokenClass::Time => r"\d",
+            TokenClass::Hashtag => "#",
+            TokenClass::Emoticon => r"[:;=8]",
+            _ => "",
+        }
+    }
+
+    fn pattern(self) -> String {
+        let bidi = bidi::CONTROL_CLASS;
+        let letters = "A-Za-zÇĞİÖŞÜçğıöşü";
+        match self {
+            TokenClass::Url => format!(r"https?://[^\s{bidi}]+|www\.[^\s{bidi}]+"),
+            TokenClass::Email => {
+                r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}".to_string()
+            }
+            TokenClass::Iban => r"TR\d{2}(?:\x20?\d{4}){5}\x20?\d{2}\b".to_string(),
+            TokenClass::Phone => concat!(
+                r"(?:\+90[\x20-]?|0[\x20-]?)(?:\([2-5]\d{2}\)|[2-5]\d{2})",
+                r"[\x20-]?\d{3}[\x20-]?\d{2}[\x20-]?\d{2}\b",
+                r"|\(0[2-5]\d{2}\)[\x20-]?\d{3}[\x20-]?\d{2}[\x20-]?\d{2}\b",
+            )
+            .to_string(),
+            TokenClass::Date => datetime_tr::date_pattern().to_string(),
+            TokenClass::Time => datetime_tr::time_pattern().to_string(),
+            TokenClass::Hashtag => r"\B\#\w+".to_string(),
+            TokenClass::Mention => r"\B@\w+".to_string(),
+            TokenClass::Emoticon => r"[:;=8][-^']?[)DPOo(\[/\\]".to_string(),
+            TokenClass::Apostrophe => format!(r"[{letters}]+(?:['’][{letters}]+)?"),
+            TokenClass::Number => r"\d+(?:[.,]\d+)*(?:[-–]\d+)?".to_string(),
+            TokenClass::Word => format!(r"[{letters}]+(?:-[{letters}]+)*"),
+            TokenClass::Punct => format!(r"[^\w\s{bidi}]"),
+        }
+    }
+}
+
+/// A set of token classes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClassSet(u16);
+
+impl ClassSet {
+    /// Every class: the default tokenizer
+    pub const ALL: ClassSet = ClassSet((1 << TokenClass::ALL.len()) - 1);
+
+    pub fn contains(self, class: TokenClass) -> bool {
+        self.0 & (1 << class.index()) != 0
+    }
+
+    /// This set without `class`; base classes cannot be left out
+    pub fn without(self, class: TokenClass) -> ClassSet {
+        match TokenClass::OPTIONAL.contains(&class) {
+            true => ClassSet(self.0 & !(1 << class.index())),
+            false => self,
+        }
+    }
+
+    /// The classes of this set, in priority order
+    pub fn classes(self) -> impl Iterator<Item = TokenClass> {
+        TokenClass::ALL.into_iter().filter(move |&class| self.contains(class))
+    }
+}
+
+struct Patterns {
+    /// One regex per optional class, indexed by `TokenClass::index`
+    optional: Vec<Regex>,
+    /// Prefilters of the optional classes: which can occur in a text
+    set: RegexSet,
+    /// The base classes as one alternation: they start with disjoint
+    /// characters, so one search finds the next base token
+    base: Regex,
+    digit: Regex,
+}
+
+static PATTERNS: OnceLock<Patterns> = OnceLock::new();
+
+fn source(class: TokenClass) -> String {
+    format!("(?x:{})", class.pattern())
+}
+
+fn patterns() -> &'static Patterns {
+    PATTERNS.get_or_init(|| {
+        let optional: Vec<String> = TokenClass::OPTIONAL.into_iter().map(source).collect();
+        let base: Vec<String> = TokenClass::ALL[TokenClass::OPTIONAL.len()..]
+            .iter()
+            .map(|&class| source(class))
+            .collect();
+        Patterns {
+            optional: optional
+                .iter()
+                .map(|source| Regex::new(source).expect("Invalid token pattern"))
+                .collect(),
+            set: RegexSet::new(TokenClass::OPTIONAL.map(TokenClass::prefilter))
+                .expect("Invalid token pattern"),
+            base: Regex::new(&base.join("|")).expect("Invalid token pattern"),
+            digit: Regex::new(r"^\d").expect("Invalid token pattern"),
+        }
+    })
+}
+
+/// Compile the token patterns now instead of on first use
+pub fn warm_up() {
+    patterns();
+}
+
+/// Class of a base token: words start with a letter, numbers with a digit,
+/// and punctuation is anything else
+fn base_class(token: &str) -> TokenClass {
+    match token.chars().next() {
+        Some('A'..='Z' | 'a'..='z' | 'Ç' | 'Ğ' | 'İ' | 'Ö' | 'Ş' | 'Ü' | 'ç' | 'ğ' | 'ı' | 'ö' | 'ş' | 'ü') => {
+            // The apostrophe pattern comes first and matches every word
+            TokenClass::Apostrophe
+        }
+        Some('0'..='9') => TokenClass::Number,
+        _ if patterns().digit.is_match(token) => TokenClass::Number,
+        _ => TokenClass::Punct,
+    }
+}
+
+/// A token found by a `Scanner`, as a byte range with its class
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub class: TokenClass,
+}
+
+/// Next-match search over a text for a set of classes
+pub struct Scanner<'t> {
+    text: &'t str,
+    /// Per enabled optional class that can occur in the text: its last match,
+    /// None once it has no more
+    next: Vec<(TokenClass, Option<(usize, usize)>)>,
+    /// Last base token found, None once there are no more
+    base: Option<(usize, usize)>,
+    /// Position the cached matches were searched from
+    searched: usize,
+}
+
+impl<'t> Scanner<'t> {
+    pub fn new(text: &'t str, classes: ClassSet) -> Self {
+        let patterns = patterns();
+        let present = patterns.set.matches(text);
+        let next = classes
+            .classes()
+            .filter(|class| class.index() < present.len() && present.matched(class.index()))
+            .map(|class| {
+                let found = patterns.optional[class.index()].find(text);
+                (class, found.map(|m| (m.start(), m.end())))
+            })
+            .collect();
+        let base = patterns.base.find(text).map(|m| (m.start(), m.end()));
+        Scanner { text, next, base, searched: 0 }
+    }
+
+    /// The leftmost token starting at or after byte `position`; at the same
+    /// start, the class first in priority order. Positions must not
+    /// decrease from one call to the next.
+    pub fn find_at(&mut self, position: usize) -> Option<Match> {
+        debug_assert!(position >= self.searched);
+        self.searched = position;
+        let patterns = patterns();
+        let mut best: Option<Match> = None;
+        for (class, next) in &mut self.next {
+            if matches!(*next, Some((start, _)) if start < position) {
+                *next = patterns.optional[class.index()]
+                    .find_at(self.text, position)
+                    .map(|m| (m.start(), m.end()));
+            }
+            let Some((start, end)) = *next else {
+                continue;
+            };
+            // Classes come in priority order, so only a strictly earlier
+            // start replaces the best match
+            if best.map_or(true, |best| start < best.start) {
+                best = Some(Match { start, end, class: *class });
+            }
+        }
+        if matches!(self.base, Some((start, _)) if start < position) {
+            self.base = patterns.base.find_at(self.text, position).map(|m| (m.start(), m.end()));
+        }
+        if let Some((start, end)) = self.base {
+            if best.map_or(true, |best| start < best.start) {
+                let class = base_class(&self.text[start..end]);
+                best = Some(Match { start, end, class });
+            }
+        }
+        best
+    }
+}
+
+/// Every token of `text` for `classes`, without the emoji handling of the
+/// tokenizer
+pub fn find_iter(text: &str, classes: ClassSet) -> impl Iterator<Item = Match> + '_ {
+    let mut scanner = Scanner::new(text, classes);
+    let mut position = 0;
+    std::iter::from_fn(move || {
+        let found = scanner.find_at(position)?;
+        position = found.end;
+        Some(found)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The single alternation the per-class patterns replace
+    fn alternation() -> Regex {
+        let pattern: Vec<String> = TokenClass::ALL
+            .iter()
+            .map(|class| format!("(?x:{})", class.pattern()))
+            .collect();
+        Regex::new(&pattern.join("|")).unwrap()
+    }
+
+    #[test]
+    fn test_matches_alternation() {
+        let texts = [
+            "Yaz: ali.veli@ornek.com.tr. Tel 0532 123 45 67, IBAN TR33 0006 1005 1978 6457 8413 26",
+            "3 Mart 2024 saat 14:30'da #GünaydınTürkiye @durak :) www.x.com/a?b=1 İstanbul'a",
+            "bu#değil x@ 3,5-4 kırk-iki ‹›…  ",
+            "",
+        ];
+        let regex = alternation();
+        for text in texts {
+            let expected: Vec<(usize, usize)> =
+                regex.find_iter(text).map(|m| (m.start(), m.end())).collect();
+            let found: Vec<(usize, usize)> = find_iter(text, ClassSet::ALL)
+                .map(|m| (m.start, m.end))
+                .collect();
+            assert_eq!(found, expected, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_class_set() {
+        let text = "ali@x.com 14:30";
+        let classes = |set: ClassSet| -> Vec<&str> {
+            find_iter(text, set).map(|m| m.class.name()).collect()
+        };
+        assert_eq!(classes(ClassSet::ALL), ["email", "time"]);
+        let set = ClassSet::ALL.without(TokenClass::Email).without(TokenClass::Time);
+        let word = "apostrophe";
+        assert_eq!(
+            classes(set),
+            [word, "punct", word, "punct", word, "number", "punct", "number"]
+        );
+        // Base classes stay
+        assert_eq!(ClassSet::ALL.without(TokenClass::Word), ClassSet::ALL);
+        assert_eq!(TokenClass::parse_optional("email"), Some(TokenClass::Email));
+        assert_eq!(TokenClass::parse_optional("word"), None);
+    }
+}
@@ -0,0 +1,1302 @@
+//! Unigram language model subword vocabularies (Kudo 2018)
+//!
+//! The trainer follows SentencePiece's unigram trainer: a large seed
+//! vocabulary of frequent substrings is refined with EM and pruned by the
+//! likelihood lost when each piece is removed, until `vocab_size` pieces
+//! remain. Two things differ. Words are durak-normalized (`fast_normalize`,
+//! so Turkish İ/I casing is handled before counting), and the analyzer's
+//! segmentation of each word guides training: its roots and suffixes are
+//! added to the seed vocabulary, and pieces crossing a morpheme boundary
+//! are penalized in the training lattices. Pieces then follow morpheme
+//! boundaries ("▁kitap" + "lar" + "ım" rather than "▁kita" + "plar" +
+//! "ım"), while inference is plain unigram Viterbi.
+//!
+//! Models are written as SentencePiece `ModelProto` files with an identity
+//! normalizer, so they load in `sentencepiece.SentencePieceProcessor`. Text
+//! must go through `fast_normalize` before it is encoded there;
+//! `UnigramModel::encode` does this itself. With `byte_fallback` ids 3-258
+//! hold the byte pieces `<0x00>`..`<0xFF>` and unknown characters are
+//! encoded as their UTF-8 bytes, as with SentencePiece's `--byte_fallback`,
+//! so every text has a segmentation without `<unk>`.
+
+use crate::subword::{self, byte_piece};
+use crate::{fast_normalize, parallel};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Word boundary marker, as in SentencePiece
+pub const SPACE: char = '\u{2581}';
+
+/// Special pieces with ids 0, 1 and 2
+pub(crate) const UNK_PIECE: &str = "<unk>";
+const BOS_PIECE: &str = "<s>";
+const EOS_PIECE: &str = "</s>";
+const SPECIAL_PIECES: usize = 3;
+
+/// Unknown characters score this much below the rarest piece
+const UNK_PENALTY: f64 = 10.0;
+
+/// Decoded text of an unknown piece (SentencePiece uses the same)
+pub(crate) const UNK_SURFACE: &str = " \u{2047} ";
+
+/// Pieces expected less often than this after an EM step are dropped
+const MIN_EXPECTED_COUNT: f64 = 0.5;
+
+/// Words per work unit in the parallel E-step
+const CHUNK_WORDS: usize = 4096;
+
+// ============================================================================
+// MODEL
+// ============================================================================
+
+/// Piece types of the SentencePiece model format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PieceType {
+    Normal,
+    Unknown,
+    Control,
+    UserDefined,
+    Unused,
+    Byte,
+}
+
+impl PieceType {
+    fn to_proto(self) -> u64 {
+        match self {
+            PieceType::Normal => 1,
+            PieceType::Unknown => 2,
+            PieceType::Control => 3,
+            PieceType::UserDefined => 4,
+            PieceType::Unused => 5,
+            PieceType::Byte => 6,
+        }
+    }
+
+    fn from_proto(value: u64) -> Option<PieceType> {
+        Some(match value {
+            1 => PieceType::Normal,
+            2 => PieceType::Unknown,
+            3 => PieceType::Control,
+            4 => PieceType::UserDefined,
+            5 => PieceType::Unused,
+            6 => PieceType::Byte,
+            _ => return None,
+        })
+    }
+
+    /// Whether the piece can appear in a segmentation
+    fn is_segment(self) -> bool {
+        matches!(self, PieceType::Normal | PieceType::UserDefined)
+    }
+}
+
+/// A vocabulary entry: surface, log probability and type
+#[derive(Debug, Clone, PartialEq)]
+pub struct Piece {
+    pub piece: String,
+    pub score: f32,
+    pub kind: PieceType,
+}
+
+/// Error loading a model file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModelError {
+    Malformed(&'static str),
+    NotUnigram(u64),
+    MissingUnknownPiece,
+}
+
+impl fmt::Display for ModelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModelError::Malformed(what) => write!(f, "malformed model file: {}", what),
+            ModelError::NotUnigram(model_type) => {
+                write!(f, "not a unigram model (model_type {})", model_type)
+            }
+            ModelError::MissingUnknownPiece => write!(f, "model has no unknown piece"),
+        }
+    }
+}
+
+/// A trained unigram vocabulary, used for Viterbi segmentation
+#[derive(Debug, Clone)]
+pub struct UnigramModel {
+    pieces: Vec<Piece>,
+    index: HashMap<String, u32>,
+    unk_id: u32,
+    max_piece_chars: usize,
+    unk_score: f64,
+    /// Unknown characters are encoded as `<0xNN>` byte pieces
+    byte_fallback: bool,
+}
+
+impl UnigramModel {
+    pub fn new(pieces: Vec<Piece>) -> Result<Self, ModelError> {
+        let unk_id = pieces
+            .iter()
+            .position(|p| p.kind == PieceType::Unknown)
+            .ok_or(ModelError::MissingUnknownPiece)? as u32;
+        let mut index = HashMap::with_capacity(pieces.len());
+        for (id, piece) in pieces.iter().enumerate() {
+            index.entry(piece.piece.clone()).or_insert(id as u32);
+        }
+        let segments = pieces.iter().filter(|p| p.kind.is_segment());
+        let max_piece_chars = segments.clone().map(|p| p.piece.chars().count()).max();
+        let min_score = segments.map(|p| p.score as f64).fold(0.0, f64::min);
+        Ok(Self {
+            index,
+            unk_id,
+            max_piece_chars: max_piece_chars.unwrap_or(1),
+            unk_score: min_score - UNK_PENALTY,
+            byte_fallback: pieces.iter().any(|p| p.kind == PieceType::Byte),
+            pieces,
+        })
+    }
+
+    pub fn pieces(&self) -> &[Piece] {
+        &self.pieces
+    }
+
+    pub fn piece_to_id(&self, piece: &str) -> u32 {
+        self.index.get(piece).copied().unwrap_or(self.unk_id)
+    }
+
+    pub fn id_to_piece(&self, id: u32) -> Option<&str> {
+        self.pieces.get(id as usize).map(|p| p.piece.as_str())
+    }
+
+    fn lookup(&self, piece: &str) -> Option<(usize, f64)> {
+        let id = *self.index.get(piece)? as usize;
+        let entry = &self.pieces[id];
+        entry.kind.is_segment().then_some((id, entry.score as f64))
+    }
+
+    /// Segment normalized text into `(id, surface)` pairs. With byte
+    /// fallback an unknown character becomes its UTF-8 byte pieces;
+    /// otherwise runs of unknown characters become a single unknown piece
+    /// carrying their surface.
+    pub fn encode(&self, text: &str) -> Vec<(u32, String)> {
+        let mut encoded: Vec<(u32, String)> = Vec::new();
+        for word in words(text) {
+            let lattice = Lattice::new(
+                &word,
+                (&[], 0.0),
+                self.max_piece_chars,
+                self.unk_score,
+                |p| self.lookup(p),
+            );
+            let mut previous_unknown = false;
+            for (start, end, id) in lattice.viterbi() {
+                let surface = lattice.surface(&word, start, end);
+                match id {
+                    Some(id) => encoded.push((id as u32, surface.to_string())),
+                    None if self.byte_fallback => {
+                        for byte in surface.bytes() {
+                            let piece = byte_piece(byte);
+                            encoded.push((self.piece_to_id(&piece), piece));
+                        }
+                    }
+                    None if previous_unknown => {
+                        if let Some(last) = encoded.last_mut() {
+                            last.1.push_str(surface);
+                        }
+                    }
+                    None => encoded.push((self.unk_id, surface.to_string())),
+                }
+                previous_unknown = id.is_none();
+            }
+        }
+        encoded
+    }
+
+    /// Join pieces back into text, decoding byte pieces
+    pub fn decode<S: AsRef<str>>(&self, pieces: &[S]) -> String {
+        subword::join_pieces(pieces)
+    }
+
+    /// Join piece ids back into text; control pieces are dropped
+    pub fn decode_ids(&self, ids: &[u32]) -> String {
+        let pieces: Vec<&str> = ids
+            .iter()
+            .filter_map(|&id| self.pieces.get(id as usize))
+            .filter_map(|p| match p.kind {
+                PieceType::Unknown => Some(UNK_SURFACE),
+                PieceType::Control | PieceType::Unused => None,
+                _ => Some(p.piece.as_str()),
+            })
+            .collect();
+        self.decode(&pieces)
+    }
+
+    /// Serialize as a SentencePiece `ModelProto`
+    pub fn to_proto(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        for piece in &self.pieces {
+            let mut entry = Vec::new();
+            proto::put_bytes(&mut entry, 1, piece.piece.as_bytes());
+            proto::put_float(&mut entry, 2, piece.score);
+            proto::put_varint(&mut entry, 3, piece.kind.to_proto());
+            proto::put_bytes(&mut out, 1, &entry);
+        }
+
+        let special_id = |name: &str| -> i64 {
+            self.index
+                .get(name)
+                .filter(|&&id| self.pieces[id as usize].kind != PieceType::Normal)
+                .map_or(-1, |&id| id as i64)
+        };
+        let mut trainer = Vec::new();
+        proto::put_varint(&mut trainer, 3, 1); // model_type: UNIGRAM
+        proto::put_varint(&mut trainer, 4, self.pieces.len() as u64);
+        proto::put_varint(&mut trainer, 20, self.max_piece_chars as u64);
+        proto::put_varint(&mut trainer, 22, 1); // split_by_whitespace
+        proto::put_varint(&mut trainer, 40, self.unk_id as u64);
+        proto::put_int(&mut trainer, 41, special_id(BOS_PIECE));
+        proto::put_int(&mut trainer, 42, special_id(EOS_PIECE));
+        proto::put_int(&mut trainer, 43, -1); // pad_id
+        if self.byte_fallback {
+            proto::put_varint(&mut trainer, 35, 1); // byte_fallback
+        }
+        proto::put_bytes(&mut out, 2, &trainer);
+
+        let mut normalizer = Vec::new();
+        proto::put_bytes(&mut normalizer, 1, b"identity");
+        proto::put_varint(&mut normalizer, 3, 1); // add_dummy_prefix
+        proto::put_varint(&mut normalizer, 4, 1); // remove_extra_whitespaces
+        proto::put_varint(&mut normalizer, 5, 1); // escape_whitespaces
+        proto::put_bytes(&mut out, 3, &normalizer);
+        out
+    }
+
+    /// Load a SentencePiece `ModelProto`
+    pub fn from_proto(data: &[u8]) -> Result<Self, ModelError> {
+        let mut pieces = Vec::new();
+        let mut fields = proto::Reader::new(data);
+        while let Some((field, value)) = fields.next_field()? {
+            match (field, value) {
+                (1, proto::Value::Bytes(entry)) => pieces.push(read_piece(entry)?),
+                (2, proto::Value::Bytes(trainer)) => {
+                    let mut fields = proto::Reader::new(trainer);
+                    while let Some((field, value)) = fields.next_field()? {
+                        if let (3, proto::Value::Varint(model_type)) = (field, value) {
+                            if model_type != 1 {
+                                return Err(ModelError::NotUnigram(model_type));
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Self::new(pieces)
+    }
+
+    /// The `.vocab` file SentencePiece writes next to a model: one
+    /// `piece<TAB>score` line per id
+    pub fn to_vocab_tsv(&self) -> String {
+        self.pieces
+            .iter()
+            .map(|p| format!("{}\t{}\n", p.piece, p.score))
+            .collect()
+    }
+}
+
+fn read_piece(data: &[u8]) -> Result<Piece, ModelError> {
+    let mut piece = Piece {
+        piece: String::new(),
+        score: 0.0,
+        kind: PieceType::Normal,
+    };
+    let mut fields = proto::Reader::new(data);
+    while let Some((field, value)) = fields.next_field()? {
+        match (field, value) {
+            (1, proto::Value::Bytes(bytes)) => {
+                piece.piece = String::from_utf8(bytes.to_vec())
+                    .map_err(|_| ModelError::Malformed("piece is not UTF-8"))?;
+            }
+            (2, proto::Value::Fixed32(bits)) => piece.score = f32::from_bits(bits),
+            (3, proto::Value::Varint(kind)) => {
+                piece.kind = PieceType::from_proto(kind)
+                    .ok_or(ModelError::Malformed("unknown piece type"))?;
+            }
+            _ => {}
+        }
+    }
+    Ok(piece)
+}
+
+/// Normalized words of a text, each with a leading `▁`
+pub(crate) fn words(text: &str) -> Vec<String> {
+    fast_normalize(text, true, true)
+        .split_whitespace()
+        .map(|word| format!("{}{}", SPACE, word))
+        .collect()
+}
+
+/// Add the words of a text to a frequency table
+pub fn count_words(counts: &mut HashMap<String, u64>, text: &str) {
+    for word in words(text) {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+}
+
+// ============================================================================
+// LATTICE
+// ============================================================================
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    end: usize,
+    /// `None` for an unknown character
+    id: Option<usize>,
+    score: f64,
+}
+
+/// All vocabulary pieces of a word, by start character. A character that
+/// is not a piece gets an unknown node so every word has a segmentation.
+/// Pieces crossing one of the `boundaries` (character offsets) lose
+/// `penalty` from their score.
+struct Lattice {
+    bounds: Vec<usize>,
+    nodes: Vec<Vec<Node>>,
+}
+
+impl Lattice {
+    fn new<F>(
+        word: &str,
+        (boundaries, penalty): (&[usize], f64),
+        max_chars: usize,
+        unk_score: f64,
+        lookup: F,
+    ) -> Self
+    where
+        F: Fn(&str) -> Option<(usize, f64)>,
+    {
+        let bounds: Vec<usize> = word
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain([word.len()])
+            .collect();
+        let len = bounds.len() - 1;
+        let mut nodes = vec![Vec::new(); len];
+        for (start, starting) in nodes.iter_mut().enumerate() {
+            let mut has_char = false;
+            for end in start + 1..=(start + max_chars).min(len) {
+                if let Some((id, score)) = lookup(&word[bounds[start]..bounds[end]]) {
+                    has_char |= end == start + 1;
+                    let crossed = boundaries.iter().filter(|&&b| start < b && b < end).count();
+                    starting.push(Node {
+                        end,
+                        id: Some(id),
+                        score: score - penalty * crossed as f64,
+                    });
+                }
+            }
+            if !has_char {
+                starting.push(Node {
+                    end: start + 1,
+                    id: None,
+                    score: unk_score,
+                });
+            }
+        }
+        Self { bounds, nodes }
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn surface<'a>(&self, word: &'a str, start: usize, end: usize) -> &'a str {
+        &word[self.bounds[start]..self.bounds[end]]
+    }
+
+    /// Best segmentation as `(start, end, id)` triples
+    fn viterbi(&self) -> Vec<(usize, usize, Option<usize>)> {
+        let len = self.len();
+        let mut best = vec![f64::NEG_INFINITY; len + 1];
+        let mut back: Vec<Option<(usize, Option<usize>)>> = vec![None; len + 1];
+        best[0] = 0.0;
+        for (start, starting) in self.nodes.iter().enumerate() {
+            if best[start] == f64::NEG_INFINITY {
+                continue;
+            }
+            for node in starting {
+                let score = best[start] + node.score;
+                if score > best[node.end] {
+                    best[node.end] = score;
+                    back[node.end] = Some((start, node.id));
+                }
+            }
+        }
+        let mut path = Vec::new();
+        let mut end = len;
+        while let Some((start, id)) = back[end] {
+            path.push((start, end, id));
+            end = start;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Add the expected count of every piece (forward-backward) times
+    /// `freq` to `expected`
+    fn add_expected(&self, freq: f64, expected: &mut HashMap<usize, f64>) {
+        let len = self.len();
+        let mut alpha = vec![f64::NEG_INFINITY; len + 1];
+        let mut beta = vec![f64::NEG_INFINITY; len + 1];
+        alpha[0] = 0.0;
+        beta[len] = 0.0;
+        for (start, starting) in self.nodes.iter().enumerate() {
+            for node in starting {
+                alpha[node.end] = log_add(alpha[node.end], alpha[start] + node.score);
+            }
+        }
+        for (start, starting) in self.nodes.iter().enumerate().rev() {
+            for node in starting {
+                beta[start] = log_add(beta[start], node.score + beta[node.end]);
+            }
+        }
+        let log_z = alpha[len];
+        for (start, starting) in self.nodes.iter().enumerate() {
+            for node in starting {
+                if let Some(id) = node.id {
+                    let posterior = (alpha[start] + node.score + beta[node.end] - log_z).exp();
+                    *expected.entry(id).or_insert(0.0) += freq * posterior;
+                }
+            }
+        }
+    }
+}
+
+fn log_add(a: f64, b: f64) -> f64 {
+    if a == f64::NEG_INFINITY {
+        return b;
+    }
+    if b == f64::NEG_INFINITY {
+        return a;
+    }
+    let (high, low) = if a > b { (a, b) } else { (b, a) };
+    high + (low - high).exp().ln_1p()
+}
+
+/// Digamma function, as used by SentencePiece for the Bayesian M-step
+fn digamma(mut x: f64) -> f64 {
+    let mut result = 0.0;
+    while x < 7.0 {
+        result -= 1.0 / x;
+        x += 1.0;
+    }
+    x -= 0.5;
+    let xx = 1.0 / x;
+    let xx2 = xx * xx;
+    let xx4 = xx2 * xx2;
+    result + x.ln() + xx2 / 24.0 - 7.0 / 960.0 * xx4 + 31.0 / 8064.0 * xx4 * xx2
+        - 127.0 / 30720.0 * xx4 * xx4
+}
+
+// ============================================================================
+// TRAINER
+// ============================================================================
+
+/// Trainer settings; the defaults follow SentencePiece
+#[derive(Debug, Clone)]
+pub struct TrainerConfig {
+    /// Final vocabulary size, including `<unk>`, `<s>` and `</s>`
+    pub vocab_size: usize,
+    /// Longest piece, in characters
+    pub max_piece_length: usize,
+    /// Share of character occurrences the vocabulary must cover; rarer
+    /// characters are left to `<unk>`
+    pub character_coverage: f64,
+    /// Substrings kept in the seed vocabulary
+    pub seed_size: usize,
+    /// Seed the vocabulary with analyzer roots and suffixes and penalize
+    /// pieces that cross their boundaries
+    pub seed_morphemes: bool,
+    /// Log probability a piece loses for each morpheme boundary it
+    /// crosses during training
+    pub boundary_penalty: f64,
+    /// EM steps between pruning steps
+    pub num_sub_iterations: usize,
+    /// Share of pieces kept by each pruning step
+    pub shrinking_factor: f64,
+    /// Reserve ids 3-258 for `<0x00>`..`<0xFF>` so that unknown characters
+    /// are encoded as bytes instead of `<unk>`
+    pub byte_fallback: bool,
+    pub threads: usize,
+}
+
+impl Default for TrainerConfig {
+    fn default() -> Self {
+        Self {
+            vocab_size: 8000,
+            max_piece_length: 16,
+            character_coverage: 0.9995,
+            seed_size: 1_000_000,
+            seed_morphemes: true,
+            boundary_penalty: 5.0,
+            num_sub_iterations: 2,
+            shrinking_factor: 0.75,
+            byte_fallback: false,
+            threads: 1,
+        }
+    }
+}
+
+impl TrainerConfig {
+    /// Ids taken by the special pieces and the byte pieces
+    fn reserved_ids(&self) -> usize {
+        SPECIAL_PIECES + if self.byte_fallback { 256 } else { 0 }
+    }
+}
+
+/// Error training a model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrainError {
+    EmptyCorpus,
+    /// Fewer ids than the special pieces plus the required characters
+    VocabTooSmall {
+        requested: usize,
+        required: usize,
+    },
+    /// The corpus does not have enough distinct pieces
+    VocabTooLarge {
+        requested: usize,
+        available: usize,
+    },
+}
+
+impl fmt::Display for TrainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrainError::EmptyCorpus => write!(f, "training corpus has no words"),
+            TrainError::VocabTooSmall {
+                requested,
+                required,
+            } => write!(
+                f,
+                "vocab_size {} is too small: the special pieces and characters need {} ids",
+                requested, required
+            ),
+            TrainError::VocabTooLarge {
+                requested,
+                available,
+            } => write!(
+                f,
+                "vocab_size {} is too large: the corpus yields only {} pieces",
+                requested, available
+            ),
+        }
+    }
+}
+
+/// A distinct word of the training corpus
+struct Word {
+    text: String,
+    freq: f64,
+    /// Root and suffix surfaces from the analyzer, if it found any
+    morphemes: Vec<String>,
+    /// Character offsets between the morphemes
+    boundaries: Vec<usize>,
+}
+
+impl Word {
+    fn new(text: String, freq: f64, morphemes: Vec<String>) -> Self {
+        let mut boundaries: Vec<usize> = morphemes
+            .iter()
+            .scan(0, |offset, m| {
+                *offset += m.chars().count();
+                Some(*offset)
+            })
+            .collect();
+        boundaries.pop();
+        Self {
+            text,
+            freq,
+            morphemes,
+            boundaries,
+        }
+    }
+
+    fn boundaries(&self, config: &TrainerConfig) -> (&[usize], f64) {
+        (&self.boundaries, config.boundary_penalty)
+    }
+}
+
+/// Vocabulary being trained. Required pieces are the covered characters,
+/// which are never pruned.
+struct Vocab {
+    pieces: Vec<String>,
+    scores: Vec<f64>,
+    required: Vec<bool>,
+    index: HashMap<String, usize>,
+}
+
+impl Vocab {
+    fn new(entries: Vec<(String, f64, bool)>) -> Self {
+        let mut vocab = Self {
+            pieces: Vec::with_capacity(entries.len()),
+            scores: Vec::with_capacity(entries.len()),
+            required: Vec::with_capacity(entries.len()),
+            index: HashMap::with_capacity(entries.len()),
+        };
+        for (id, (piece, score, required)) in entries.into_iter().enumerate() {
+            vocab.index.insert(piece.clone(), id);
+            vocab.pieces.push(piece);
+            vocab.scores.push(score);
+            vocab.required.push(required);
+        }
+        vocab
+    }
+
+    /// Seed vocabulary from raw counts
+    fn from_counts(entries: Vec<(String, f64, bool)>) -> Self {
+        let log_total = entries.iter().map(|e| e.1).sum::<f64>().ln();
+        let entries = entries
+            .into_iter()
+            .map(|(piece, count, required)| (piece, count.ln() - log_total, required))
+            .collect();
+        Self::new(entries)
+    }
+
+    fn len(&self) -> usize {
+        self.pieces.len()
+    }
+
+    fn lookup(&self, piece: &str) -> Option<(usize, f64)> {
+        self.index.get(piece).map(|&id| (id, self.scores[id]))
+    }
+
+    fn unk_score(&self) -> f64 {
+        self.scores.iter().copied().fold(0.0, f64::min) - UNK_PENALTY
+    }
+
+    fn lattice(&self, word: &str, boundaries: (&[usize], f64), max_chars: usize) -> Lattice {
+        Lattice::new(word, boundaries, max_chars, self.unk_score(), |p| {
+            self.lookup(p)
+        })
+    }
+}
+
+/// Learn a vocabulary from normalized word frequencies (see `count_words`)
+pub fn train(
+    counts: &HashMap<String, u64>,
+    config: &TrainerConfig,
+) -> Result<UnigramModel, TrainError> {
+    let mut sorted: Vec<(&String, u64)> =
+        counts.iter().map(|(word, &count)| (word, count)).collect();
+    if sorted.is_empty() {
+        return Err(TrainError::EmptyCorpus);
+    }
+    sorted.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    let morphemes = match config.seed_morphemes {
+        true => parallel::map(&sorted, config.threads, |(word, _)| morpheme_pieces(word)),
+        false => vec![Vec::new(); sorted.len()],
+    };
+    let words: Vec<Word> = sorted
+        .into_iter()
+        .zip(morphemes)
+        .map(|((word, count), morphemes)| Word::new(word.clone(), count as f64, morphemes))
+        .collect();
+
+    let chars = required_chars(&words, config.character_coverage);
+    if chars.len() + config.reserved_ids() > config.vocab_size {
+        return Err(TrainError::VocabTooSmall {
+            requested: config.vocab_size,
+            required: chars.len() + config.reserved_ids(),
+        });
+    }
+    let mut vocab = seed_vocab(&words, &chars, config);
+    let pieces = config.vocab_size - config.reserved_ids();
+    let desired = pieces + pieces / 10;
+    loop {
+        for _ in 0..config.num_sub_iterations {
+            let expected = expected_counts(&vocab, &words, config);
+            vocab = maximize(&vocab, &expected);
+        }
+        if vocab.len() <= desired {
+            break;
+        }
+        let size = vocab.len();
+        vocab = prune(&vocab, &words, desired, config);
+        if vocab.len() == size {
+            break;
+        }
+    }
+    finalize(&vocab, config)
+}
+
+/// Covered characters with their frequencies, most frequent first
+fn required_chars(words: &[Word], coverage: f64) -> Vec<(char, f64)> {
+    let mut counts: HashMap<char, f64> = HashMap::new();
+    for word in words {
+        for c in word.text.chars() {
+            *counts.entry(c).or_insert(0.0) += word.freq;
+        }
+    }
+    let total: f64 = counts.values().sum();
+    let mut chars: Vec<(char, f64)> = counts.into_iter().collect();
+    chars.sort_unstable_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    let mut covered = 0.0;
+    let mut kept = 0;
+    for &(_, freq) in &chars {
+        if kept > 0 && covered / total >= coverage {
+            break;
+        }
+        covered += freq;
+        kept += 1;
+    }
+    chars.truncate(kept);
+    chars
+}
+
+/// Letters, digits and other characters are not mixed within a piece
+fn char_class(c: char) -> u8 {
+    if c.is_alphabetic() {
+        0
+    } else if c.is_numeric() {
+        1
+    } else {
+        2
+    }
+}
+
+fn is_seed_piece(piece: &str, chars: &HashMap<char, f64>) -> bool {
+    let body = piece.strip_prefix(SPACE).unwrap_or(piece);
+    let mut body_chars = body.chars();
+    let Some(first) = body_chars.next() else {
+        return false;
+    };
+    piece.chars().count() >= 2
+        && piece.chars().all(|c| chars.contains_key(&c))
+        && !body.contains(SPACE)
+        && body_chars.all(|c| char_class(c) == char_class(first))
+}
+
+/// Root and suffix surfaces of the first analysis of a word:
+/// "▁kitaplarım" -> ["▁kitap", "lar", "ım"]
+pub(crate) fn morpheme_pieces(word: &str) -> Vec<String> {
+    let analyzed = word.strip_prefix(SPACE).and_then(subword::analyzer_pieces);
+    let Some(mut pieces) = analyzed else {
+        return Vec::new();
+    };
+    pieces[0].insert(0, SPACE);
+    pieces
+}
+
+/// Characters plus the most frequent substrings (by frequency times
+/// length, as in SentencePiece) and, optionally, analyzer morphemes
+fn seed_vocab(words: &[Word], chars: &[(char, f64)], config: &TrainerConfig) -> Vocab {
+    let char_counts: HashMap<char, f64> = chars.iter().copied().collect();
+    let mut substrings: HashMap<&str, f64> = HashMap::new();
+    for word in words {
+        let text = word.text.as_str();
+        let bounds: Vec<usize> = text
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain([text.len()])
+            .collect();
+        for start in 0..bounds.len() - 1 {
+            for end in start + 2..=(start + config.max_piece_length).min(bounds.len() - 1) {
+                *substrings
+                    .entry(&text[bounds[start]..bounds[end]])
+                    .or_insert(0.0) += word.freq;
+            }
+        }
+    }
+    let mut seeds: Vec<(String, f64)> = substrings
+        .into_iter()
+        .filter(|(piece, _)| is_seed_piece(piece, &char_counts))
+        .map(|(piece, count)| (piece.to_string(), count))
+        .collect();
+    let weight = |(piece, count): &(String, f64)| count * piece.chars().count() as f64;
+    seeds.sort_unstable_by(|a, b| weight(b).total_cmp(&weight(a)).then_with(|| a.0.cmp(&b.0)));
+    seeds.truncate(config.seed_size);
+
+    let mut boosts: HashMap<&str, f64> = HashMap::new();
+    for word in words {
+        for piece in &word.morphemes {
+            let fits = piece.chars().count() <= config.max_piece_length;
+            if fits && is_seed_piece(piece, &char_counts) {
+                *boosts.entry(piece).or_insert(0.0) += word.freq;
+            }
+        }
+    }
+    for (piece, count) in seeds.iter_mut() {
+        if let Some(boost) = boosts.remove(piece.as_str()) {
+            *count += boost;
+        }
+    }
+    let mut extra: Vec<(String, f64)> = boosts
+        .into_iter()
+        .map(|(piece, count)| (piece.to_string(), count))
+        .collect();
+    extra.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    seeds.extend(extra);
+
+    let entries = chars
+        .iter()
+        .map(|&(c, count)| (c.to_string(), count, true))
+        .chain(
+            seeds
+                .into_iter()
+                .map(|(piece, count)| (piece, count, false)),
+        )
+        .collect();
+    Vocab::from_counts(entries)
+}
+
+/// E-step: expected count of every piece over the corpus
+fn expected_counts(vocab: &Vocab, words: &[Word], config: &TrainerConfig) -> Vec<f64> {
+    let chunks: Vec<&[Word]> = words.chunks(CHUNK_WORDS).collect();
+    let partial = parallel::map(&chunks, config.threads, |chunk| {
+        let mut expected = HashMap::new();
+        for word in chunk.iter() {
+            vocab
+                .lattice(&word.text, word.boundaries(config), config.max_piece_length)
+                .add_expected(word.freq, &mut expected);
+        }
+        expected
+    });
+    let mut expected = vec![0.0; vocab.len()];
+    for chunk in partial {
+        for (id, count) in chunk {
+            expected[id] += count;
+        }
+    }
+    expected
+}
+
+/// M-step: drop rarely used pieces and re-estimate log probabilities
+/// with the digamma correction, which favours frequent pieces
+fn maximize(vocab: &Vocab, expected: &[f64]) -> Vocab {
+    let kept: Vec<(usize, f64)> = expected
+        .iter()
+        .enumerate()
+        .filter(|&(id, &count)| vocab.required[id] || count >= MIN_EXPECTED_COUNT)
+        .map(|(id, &count)| (id, count.max(MIN_EXPECTED_COUNT)))
+        .collect();
+    let log_total = digamma(kept.iter().map(|&(_, count)| count).sum());
+    let entries = kept
+        .into_iter()
+        .map(|(id, count)| {
+            (
+                vocab.pieces[id].clone(),
+                digamma(count) - log_total,
+                vocab.required[id],
+            )
+        })
+        .collect();
+    Vocab::new(entries)
+}
+
+/// Keep the pieces whose removal would cost the most likelihood. A piece
+/// that never wins its own Viterbi segmentation, or is never used, goes
+/// first; for the others the loss is estimated by handing their frequency
+/// to the segmentation they would be replaced with.
+fn prune(vocab: &Vocab, words: &[Word], desired: usize, config: &TrainerConfig) -> Vocab {
+    let max_chars = config.max_piece_length;
+    let candidates: Vec<usize> = (0..vocab.len()).filter(|&id| !vocab.required[id]).collect();
+    let alternatives = parallel::map(&candidates, config.threads, |&id| {
+        let piece = &vocab.pieces[id];
+        if vocab.lattice(piece, (&[], 0.0), max_chars).viterbi().len() > 1 {
+            return None;
+        }
+        let unk_score = vocab.unk_score();
+        let without = Lattice::new(piece, (&[], 0.0), max_chars, unk_score, |p| {
+            vocab.lookup(p).filter(|&(other, _)| other != id)
+        });
+        Some(
+            without
+                .viterbi()
+                .into_iter()
+                .filter_map(|(_, _, other)| other)
+                .collect::<Vec<usize>>(),
+        )
+    });
+
+    let chunks: Vec<&[Word]> = words.chunks(CHUNK_WORDS).collect();
+    let partial = parallel::map(&chunks, config.threads, |chunk| {
+        let mut freq: HashMap<usize, f64> = HashMap::new();
+        for word in chunk.iter() {
+            let lattice = vocab.lattice(&word.text, word.boundaries(config), max_chars);
+            for (_, _, id) in lattice.viterbi() {
+                if let Some(id) = id {
+                    *freq.entry(id).or_insert(0.0) += word.freq;
+                }
+            }
+        }
+        freq
+    });
+    let mut freq = vec![0.0; vocab.len()];
+    for chunk in partial {
+        for (id, count) in chunk {
+            freq[id] += count;
+        }
+    }
+    let total: f64 = freq.iter().sum();
+    let log_total = total.ln();
+    let corpus_size: f64 = words.iter().map(|word| word.freq).sum();
+
+    let mut kept: Vec<usize> = (0..vocab.len()).filter(|&id| vocab.required[id]).collect();
+    let mut losses: Vec<(usize, f64)> = Vec::new();
+    for (&id, alternative) in candidates.iter().zip(alternatives) {
+        let Some(alternative) = alternative else {
+            continue;
+        };
+        if freq[id] == 0.0 {
+            continue;
+        }
+        if alternative.is_empty() {
+            kept.push(id);
+            continue;
+        }
+        let log_prob = freq[id].ln() - log_total;
+        let log_total_alt = (total + freq[id] * (alternative.len() as f64 - 1.0)).ln();
+        let log_prob_alt: f64 = alternative
+            .iter()
+            .map(|&other| (freq[other] + freq[id]).ln() - log_total_alt)
+            .sum();
+        losses.push((id, freq[id] / corpus_size * (log_prob - log_prob_alt)));
+    }
+    losses.sort_unstable_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    let pruned_size = desired.max((config.shrinking_factor * vocab.len() as f64) as usize);
+    for (id, _) in losses {
+        if kept.len() >= pruned_size {
+            break;
+        }
+        kept.push(id);
+    }
+    kept.sort_unstable();
+    let entries = kept
+        .into_iter()
+        .map(|id| {
+            (
+                vocab.pieces[id].clone(),
+                vocab.scores[id],
+                vocab.required[id],
+            )
+        })
+        .collect();
+    Vocab::new(entries)
+}
+
+/// Special pieces (and byte pieces), then the required characters and the best scoring
+/// pieces up to `vocab_size`, ordered by score
+fn finalize(vocab: &Vocab, config: &TrainerConfig) -> Result<UnigramModel, TrainError> {
+    let wanted = config.vocab_size - config.reserved_ids();
+    let by_score = |a: &usize, b: &usize| {
+        vocab.scores[*b]
+            .total_cmp(&vocab.scores[*a])
+            .then_with(|| vocab.pieces[*a].cmp(&vocab.pieces[*b]))
+    };
+    let mut chosen: Vec<usize> = (0..vocab.len()).filter(|&id| vocab.required[id]).collect();
+    let mut others: Vec<usize> = (0..vocab.len()).filter(|&id| !vocab.required[id]).collect();
+    others.sort_unstable_by(by_score);
+    chosen.extend(others.into_iter().take(wanted.saturating_sub(chosen.len())));
+    if chosen.len() < wanted {
+        return Err(TrainError::VocabTooLarge {
+            requested: config.vocab_size,
+            available: chosen.len() + config.reserved_ids(),
+        });
+    }
+    chosen.sort_unstable_by(by_score);
+
+    let special = |piece: &str, kind| Piece {
+        piece: piece.to_string(),
+        score: 0.0,
+        kind,
+    };
+    let mut pieces = vec![
+        special(UNK_PIECE, PieceType::Unknown),
+        special(BOS_PIECE, PieceType::Control),
+        special(EOS_PIECE, PieceType::Control),
+    ];
+    if config.byte_fallback {
+        pieces.extend((0..=u8::MAX).map(|byte| special(&byte_piece(byte), PieceType::Byte)));
+    }
+    pieces.extend(chosen.into_iter().map(|id| Piece {
+        piece: vocab.pieces[id].clone(),
+        score: vocab.scores[id] as f32,
+        kind: PieceType::Normal,
+    }));
+    Ok(UnigramModel::new(pieces).expect("vocabulary has an unknown piece"))
+}
+
+// ============================================================================
+// PROTOCOL BUFFERS
+// ============================================================================
+// Just enough of the wire format for `sentencepiece_model.proto`
+
+mod proto {
+    use super::ModelError;
+
+    const VARINT: u64 = 0;
+    const FIXED64: u64 = 1;
+    const BYTES: u64 = 2;
+    const FIXED32: u64 = 5;
+
+    fn put_raw_varint(out: &mut Vec<u8>, mut value: u64) {
+        while value >= 0x80 {
+            out.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        out.push(value as u8);
+    }
+
+    fn put_key(out: &mut Vec<u8>, field: u64, wire_type: u64) {
+        put_raw_varint(out, (field << 3) | wire_type);
+    }
+
+    pub fn put_varint(out: &mut Vec<u8>, field: u64, value: u64) {
+        put_key(out, field, VARINT);
+        put_raw_varint(out, value);
+    }
+
+    /// `int32` field; negative values take ten bytes
+    pub fn put_int(out: &mut Vec<u8>, field: u64, value: i64) {
+        put_varint(out, field, value as u64);
+    }
+
+    pub fn put_float(out: &mut Vec<u8>, field: u64, value: f32) {
+        put_key(out, field, FIXED32);
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn put_bytes(out: &mut Vec<u8>, field: u64, value: &[u8]) {
+        put_key(out, field, BYTES);
+        put_raw_varint(out, value.len() as u64);
+        out.extend_from_slice(value);
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    pub enum Value<'a> {
+        Varint(u64),
+        /// Skipped: no field of the model format uses it
+        Fixed64,
+        Bytes(&'a [u8]),
+        Fixed32(u32),
+    }
+
+    pub struct Reader<'a> {
+        data: &'a [u8],
+        pos: usize,
+    }
+
+    impl<'a> Reader<'a> {
+        pub fn new(data: &'a [u8]) -> Self {
+            Self { data, pos: 0 }
+        }
+
+        fn take(&mut self, n: usize) -> Result<&'a [u8], ModelError> {
+            let end = self
+                .pos
+                .checked_add(n)
+                .filter(|&end| end <= self.data.len())
+                .ok_or(ModelError::Malformed("truncated field"))?;
+            let bytes = &self.data[self.pos..end];
+            self.pos = end;
+            Ok(bytes)
+        }
+
+        fn varint(&mut self) -> Result<u64, ModelError> {
+            let mut value = 0u64;
+            for shift in (0..64).step_by(7) {
+                let byte = self.take(1)?[0];
+                value |= ((byte & 0x7F) as u64) << shift;
+                if byte < 0x80 {
+                    return Ok(value);
+                }
+            }
+            Err(ModelError::Malformed("varint too long"))
+        }
+
+        pub fn next_field(&mut self) -> Result<Option<(u64, Value<'a>)>, ModelError> {
+            if self.pos == self.data.len() {
+                return Ok(None);
+            }
+            let key = self.varint()?;
+            let value = match key & 7 {
+                VARINT => Value::Varint(self.varint()?),
+                FIXED64 => {
+                    self.take(8)?;
+                    Value::Fixed64
+                }
+                BYTES => {
+                    let len = self.varint()? as usize;
+                    Value::Bytes(self.take(len)?)
+                }
+                FIXED32 => {
+                    let bytes = self.take(4)?;
+                    Value::Fixed32(u32::from_le_bytes(bytes.try_into().expect("4 bytes")))
+                }
+                _ => return Err(ModelError::Malformed("unsupported wire type")),
+            };
+            Ok(Some((key >> 3, value)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn corpus() -> HashMap<String, u64> {
+        let text = "kitaplar kitaplarım kitaplardan kitabım evler evlerim evden evde \
+                    geliyorum geliyorsun gidiyorum gidiyorsun okullar okulda okuldan \
+                    masalar masada masadan";
+        let mut counts = HashMap::new();
+        for _ in 0..20 {
+            count_words(&mut counts, text);
+        }
+        counts
+    }
+
+    fn config(vocab_size: usize) -> TrainerConfig {
+        TrainerConfig {
+            vocab_size,
+            ..TrainerConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_digamma() {
+        assert!((digamma(1.0) + 0.577_215_664_9).abs() < 1e-9);
+        assert!((digamma(10.0) - 2.251_752_589_1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_morpheme_pieces() {
+        assert_eq!(
+            morpheme_pieces("▁kitaplarımdan"),
+            vec!["▁kitap", "lar", "ım", "dan"]
+        );
+        assert!(morpheme_pieces("▁123").is_empty());
+    }
+
+    #[test]
+    fn test_train_vocab_size_and_order() {
+        let model = train(&corpus(), &config(36)).unwrap();
+        let pieces = model.pieces();
+        assert_eq!(pieces.len(), 36);
+        assert_eq!(pieces[0].kind, PieceType::Unknown);
+        assert_eq!(pieces[1].piece, "<s>");
+        assert_eq!(pieces[2].piece, "</s>");
+        assert!(pieces[3..].windows(2).all(|w| w[0].score >= w[1].score));
+        // Deterministic, whatever the thread count
+        let parallel = TrainerConfig {
+            threads: 2,
+            ..config(36)
+        };
+        assert_eq!(train(&corpus(), &parallel).unwrap().pieces(), pieces);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let model = train(&corpus(), &config(36)).unwrap();
+        let encoded = model.encode("KİTAPLARIM  evde 日本");
+        let surfaces: Vec<&str> = encoded.iter().map(|(_, s)| s.as_str()).collect();
+        assert_eq!(model.decode(&surfaces), "kitaplarım evde 日本");
+        // Unknown characters are merged into one piece
+        assert_eq!(encoded.last().unwrap(), &(0, "日本".to_string()));
+        let ids: Vec<u32> = encoded.iter().map(|(id, _)| *id).collect();
+        assert_eq!(model.decode_ids(&ids), "kitaplarım evde  \u{2047} ");
+    }
+
+    #[test]
+    fn test_pieces_follow_morphemes() {
+        let encode = |seed_morphemes| {
+            let config = TrainerConfig {
+                seed_morphemes,
+                ..config(36)
+            };
+            let model = train(&corpus(), &config).unwrap();
+            let pieces: Vec<String> = model
+                .encode("kitaplarım")
+                .into_iter()
+                .map(|p| p.1)
+                .collect();
+            pieces
+        };
+        assert_eq!(encode(true)[..2], ["▁kitap", "lar"]);
+        assert_eq!(encode(false)[0], "▁kitaplar");
+    }
+
+    #[test]
+    fn test_byte_fallback() {
+        let bytes = TrainerConfig {
+            byte_fallback: true,
+            ..config(290)
+        };
+        let model = train(&corpus(), &bytes).unwrap();
+        assert_eq!(model.pieces().len(), 290);
+        assert_eq!(model.id_to_piece(3), Some("<0x00>"));
+        assert_eq!(model.pieces()[258].kind, PieceType::Byte);
+        let encoded = model.encode("evde 日");
+        let pieces: Vec<&str> = encoded.iter().map(|(_, p)| p.as_str()).collect();
+        assert_eq!(pieces[pieces.len() - 3..], ["<0xE6>", "<0x97>", "<0xA5>"]);
+        assert!(encoded.iter().all(|&(id, _)| id != 0));
+        let ids: Vec<u32> = encoded.iter().map(|(id, _)| *id).collect();
+        assert_eq!(model.decode_ids(&ids), "evde 日");
+        let loaded = UnigramModel::from_proto(&model.to_proto()).unwrap();
+        assert_eq!(loaded.encode("evde 日"), encoded);
+        let too_small = TrainerConfig {
+            byte_fallback: true,
+            ..config(200)
+        };
+        assert!(matches!(
+            train(&corpus(), &too_small),
+            Err(TrainError::VocabTooSmall { .. })
+        ));
+    }
+
+    #[test]
+    fn test_vocab_size_errors() {
+        assert_eq!(
+            train(&HashMap::new(), &config(36)).unwrap_err(),
+            TrainError::EmptyCorpus
+        );
+        assert!(matches!(
+            train(&corpus(), &config(10)),
+            Err(TrainError::VocabTooSmall { .. })
+        ));
+        assert!(matches!(
+            train(&corpus(), &config(100_000)),
+            Err(TrainError::VocabTooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn test_proto_round_trip() {
+        let model = train(&corpus(), &config(36)).unwrap();
+        let bytes = model.to_proto();
+        let loaded = UnigramModel::from_proto(&bytes).unwrap();
+        assert_eq!(loaded.pieces(), model.pieces());
+        assert_eq!(loaded.encode("kitaplarım"), model.encode("kitaplarım"));
+        assert!(UnigramModel::from_proto(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_proto_wire_format() {
+        let model = UnigramModel::new(vec![Piece {
+            piece: "<unk>".to_string(),
+            score: 0.0,
+            kind: PieceType::Unknown,
+        }])
+        .unwrap();
+        let bytes = model.to_proto();
+        // pieces { piece: "<unk>" score: 0 type: UNKNOWN }
+        assert_eq!(
+            &bytes[..16],
+            b"\x0a\x0e\x0a\x05<unk>\x15\x00\x00\x00\x00\x18\x02"
+        );
+        // A BPE model is rejected
+        let mut bpe = Vec::new();
+        proto::put_bytes(&mut bpe, 2, b"\x18\x02");
+        assert_eq!(
+            UnigramModel::from_proto(&bpe).unwrap_err(),
+            ModelError::NotUnigram(2)
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Vowel drop (ünlü düşmesi) recovery for suffix stripping
+//!
+//! A closed class of mostly two-syllable roots loses its last vowel before a
+//! vowel-initial suffix: burun → burn-u, oğul → oğl-um, şehir → şehr-i.
+//! Stripping the suffix leaves a truncated stem that is not a word, so the
+//! stripper asks this module for the full root.
+//!
+//! Roots listed in `resources/tr/lemmas/vowel_drop.txt` are restored
+//! directly. Other stems ending in a consonant cluster get the high vowel
+//! that vowel harmony predicts inserted before their last consonant, and the
+//! result is kept only if it is a known lemma.
+
+use crate::is_known_lemma;
+use crate::vowel_harmony::{get_vowel_class, VowelClass};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Embedded vowel-dropping roots, one per line
+static VOWEL_DROP_DATA: &str = include_str!("../resources/tr/lemmas/vowel_drop.txt");
+/// Truncated stem → full root (burn → burun, kayb → kayıp)
+static TRUNCATED_STEMS: OnceLock<HashMap<String, &'static str>> = OnceLock::new();
+
+fn is_vowel(c: char) -> bool {
+    get_vowel_class(c).is_some()
+}
+
+/// Voiced counterpart of a final voiceless stop (kayıp → kayb-ı)
+fn soften(c: char) -> Option<char> {
+    match c {
+        'p' => Some('b'),
+        'ç' => Some('c'),
+        't' => Some('d'),
+        'k' => Some('ğ'),
+        _ => None,
+    }
+}
+
+fn truncated_stems() -> &'static HashMap<String, &'static str> {
+    TRUNCATED_STEMS.get_or_init(|| {
+        let mut stems = HashMap::new();
+
+        for line in VOWEL_DROP_DATA.lines() {
+            let root = line.trim();
+            if root.is_empty() || root.starts_with('#') {
+                continue;
+            }
+
+            let mut chars: Vec<char> = root.chars().collect();
+            let Some(last_vowel) = chars.iter().rposition(|&c| is_vowel(c)) else {
+                continue;
+            };
+            chars.remove(last_vowel);
+            stems.insert(chars.iter().collect(), root);
+
+            // The final stop may soften as well as the vowel dropping
+            if let Some(soft) = chars.last().copied().and_then(soften) {
+                *chars.last_mut().unwrap() = soft;
+                stems.insert(chars.into_iter().collect(), root);
+            }
+        }
+
+        stems
+    })
+}
+
+/// Check whether `word` is a listed vowel-dropping root
+///
+/// Listed roots are known words even when the lemma dictionary lacks them,
+/// so the stripper stops at them instead of eroding them further
+/// (burun must not lose "-un").
+pub fn is_listed_root(word: &str) -> bool {
+    truncated_stems().values().any(|&root| root == word)
+}
+
+/// High vowel that harmonizes with `vowel` (a/ı → ı, e/i → i, o/u → u, ö/ü → ü)
+fn high_vowel(vowel: char) -> Option<char> {
+    let high = match get_vowel_class(vowel)? {
+        VowelClass::BackUnrounded => 'ı',
+        VowelClass::FrontUnrounded => 'i',
+        VowelClass::BackRounded => 'u',
+        VowelClass::FrontRounded => 'ü',
+    };
+    Some(high)
+}
+
+/// Stem with the harmonizing high vowel reinserted before its last consonant
+/// (burn → burun), if it ends in a consonant cluster
+fn reinsert_vowel(stem: &str) -> Option<String> {
+    let mut chars: Vec<char> = stem.chars().collect();
+    let n = chars.len();
+    if n < 3 || is_vowel(chars[n - 1]) || is_vowel(chars[n - 2]) {
+        return None;
+    }
+    let vowel = high_vowel(*chars.iter().rev().find(|&&c| is_vowel(c))?)?;
+    chars.insert(n - 1, vowel);
+    Some(chars.into_iter().collect())
+}
+
+/// Full root for a stem left by stripping `suffix`, if the root's last vowel
+/// dropped before it
+///
+/// # Examples
+/// ```text
+/// restore("burn", "u")  -> Some("burun")
+/// restore("oğl", "um")  -> Some("oğul")
+/// restore("kayb", "ı")  -> Some("kayıp")
+/// restore("kurt", "u")  -> None
+/// ```
+pub fn restore(stem: &str, suffix: &str) -> Option<String> {
+    if !suffix.chars().next().is_some_and(is_vowel) {
+        return None;
+    }
+
+    if let Some(&root) = truncated_stems().get(stem) {
+        return Some(root.to_string());
+    }
+
+    if is_known_lemma(stem) {
+        return None;
+    }
+    reinsert_vowel(stem).filter(|root| is_known_lemma(root))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_listed_roots() {
+        assert_eq!(restore("burn", "u").as_deref(), Some("burun"));
+        assert_eq!(restore("oğl", "um").as_deref(), Some("oğul"));
+        assert_eq!(restore("ağz", "ı").as_deref(), Some("ağız"));
+        assert_eq!(restore("vakt", "i").as_deref(), Some("vakit"));
+        // Vowel drop combined with consonant softening
+        assert_eq!(restore("kayb", "ı").as_deref(), Some("kayıp"));
+        assert_eq!(restore("kayd", "ı").as_deref(), Some("kayıt"));
+        assert!(is_listed_root("burun"));
+        assert!(!is_listed_root("burn"));
+    }
+
+    #[test]
+    fn test_reinsert_vowel() {
+        assert_eq!(reinsert_vowel("şehr").as_deref(), Some("şehir"));
+        assert_eq!(reinsert_vowel("ağz").as_deref(), Some("ağız"));
+        assert_eq!(reinsert_vowel("göğs").as_deref(), Some("göğüs"));
+        assert_eq!(reinsert_vowel("ev"), None);
+        assert_eq!(reinsert_vowel("kitab"), None);
+    }
+
+    #[test]
+    fn test_no_restoration() {
+        // Consonant-initial suffixes never trigger vowel drop
+        assert_eq!(restore("burn", "da"), None);
+        // Unlisted roots are not guessed without dictionary support
+        assert_eq!(restore("kurt", "u"), None);
+        assert_eq!(restore("kitap", "ı"), None);
+    }
+}
@@ -5,6 +5,8 @@
 /// - Rounded/Unrounded harmony: Rounded (o,ö,u,ü) vs Unrounded (a,e,ı,i)
 ///
 /// Used to validate suffix attachment in lemmatization and morphological analysis.
+/// Loanword roots with a palatal final consonant (saat → saatler) harmonize
+/// as front roots; see `etymology::takes_front_harmony`.
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VowelClass {
@@ -33,6 +35,15 @@ impl VowelClass {
     pub fn is_rounded(&self) -> bool {
         matches!(self, VowelClass::FrontRounded | VowelClass::BackRounded)
     }
+
+    /// The front vowel class with the same rounding (a → e, u → ü)
+    pub fn fronted(self) -> VowelClass {
+        match (self.is_back(), self.is_rounded()) {
+            (false, _) => self,
+            (true, true) => VowelClass::FrontRounded,
+            (true, false) => VowelClass::FrontUnrounded,
+        }
+    }
 }
 
 /// Get the vowel class of a character
@@ -97,6 +108,7 @@ pub fn check_harmony(root_vowel: VowelClass, suffix_vowel: VowelClass) -> bool {
 pub fn check_vowel_harmony(root: &str, suffix: &str) -> bool {
     // Get the last vowel in the root
     let root_vowel = match get_last_vowel_class(root) {
+        Some(v) if crate::etymology::takes_front_harmony(root) => v.fronted(),
         Some(v) => v,
         None => return false, // No vowels in root = cannot validate
     };
@@ -179,6 +191,16 @@ mod tests {
         assert!(!check_vowel_harmony("şehir", "dan")); // *şehirdan ✗
     }
 
+    #[test]
+    fn test_loanword_exceptions() {
+        assert!(check_vowel_harmony("saat", "ler"));
+        assert!(!check_vowel_harmony("saat", "lar"));
+        assert!(check_vowel_harmony("gol", "ü"));
+        assert_eq!(VowelClass::BackRounded.fronted(), VowelClass::FrontRounded);
+        assert_eq!(VowelClass::BackUnrounded.fronted(), VowelClass::FrontUnrounded);
+        assert_eq!(VowelClass::FrontRounded.fronted(), VowelClass::FrontRounded);
+    }
+
     #[test]
     fn test_harmony_with_multi_vowel_suffixes() {
         // Test suffixes with multiple vowels
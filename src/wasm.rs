@@ -0,0 +1,68 @@
+//! WebAssembly exports for browsers
+//!
+//! Built for `wasm32-unknown-unknown` without Python (see
+//! `scripts/build_wasm.sh`), which runs `wasm-bindgen` to generate the
+//! JavaScript bindings: `tokenize`, `normalize` and `lemmatize` on plain
+//! JavaScript strings, so web apps preprocess Turkish client-side exactly
+//! like the Python package.
+
+use crate::{fast_normalize, lemmatize_word, token_spans, Strategy};
+use wasm_bindgen::prelude::*;
+
+/// Minimum root length of the suffix stripper, as in the Python `Lemmatizer`
+const MIN_ROOT_LENGTH: usize = 2;
+
+/// Lowercase text with Turkish I/ı and İ/i handling
+#[wasm_bindgen]
+pub fn normalize(text: &str) -> String {
+    fast_normalize(text, true, true)
+}
+
+/// Tokens of a text, as `durak.tokenize_with_offsets` returns them without
+/// offsets
+#[wasm_bindgen]
+pub fn tokenize(text: &str) -> Vec<String> {
+    token_spans(text)
+        .iter()
+        .map(|span| text[span.start..span.end].to_string())
+        .collect()
+}
+
+/// Lemma of a normalized word with the defaults of the Python
+/// `Lemmatizer(strategy="hybrid")` (no root validation)
+#[wasm_bindgen]
+pub fn lemmatize(word: &str) -> String {
+    let word = fast_normalize(word, true, true);
+    lemmatize_word(&word, Strategy::Hybrid, false, false, MIN_ROOT_LENGTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("İSTANBUL"), "istanbul");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize("Ankara'ya gittim, \"güzel\"!"),
+            ["Ankara'ya", "gittim", ",", "\"", "güzel", "\"", "!"]
+        );
+        assert!(tokenize("").is_empty());
+    }
+
+    #[test]
+    fn test_lemmatize() {
+        assert_eq!(lemmatize("Kitaplar"), "kitap");
+        assert_eq!(lemmatize("geliyorum"), "gel");
+        // Same lemmas as the Python default, which does not validate roots
+        for word in ["evlerden", "oğlum", "burnu"] {
+            let expected = lemmatize_word(word, Strategy::Hybrid, false, false, MIN_ROOT_LENGTH);
+            assert_eq!(lemmatize(word), expected);
+        }
+    }
+}
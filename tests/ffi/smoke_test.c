@@ -0,0 +1,40 @@
+/*
+ * Smoke test for the C interface; built and run by scripts/build_mobile.sh --check
+ */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "durak.h"
+
+static int expect(const char *label, char *actual, const char *expected) {
+    int ok = actual != NULL && strcmp(actual, expected) == 0;
+    if (!ok) {
+        fprintf(stderr, "%s: expected \"%s\", got \"%s\"\n", label, expected,
+                actual ? actual : "(null)");
+    }
+    durak_string_free(actual);
+    return ok;
+}
+
+int main(void) {
+    int ok = 1;
+    ok &= expect("normalize", durak_normalize("İSTANBUL IRMAK"), "istanbul ırmak");
+    ok &= expect("lemmatize", durak_lemmatize("Kitaplar"), "kitap");
+    ok &= durak_normalize(NULL) == NULL;
+    ok &= expect("tokenize_json", durak_tokenize_json("Merhaba dünya!"),
+                 "[\"Merhaba\",\"dünya\",\"!\"]");
+
+    size_t count = 0;
+    char **tokens = durak_tokenize("Ankara'ya gittim.", &count);
+    ok &= tokens != NULL && count == 3 && strcmp(tokens[0], "Ankara'ya") == 0 &&
+          strcmp(tokens[2], ".") == 0;
+    durak_tokens_free(tokens, count);
+
+    if (!ok) {
+        return EXIT_FAILURE;
+    }
+    printf("durak %s: C interface OK\n", durak_version());
+    return EXIT_SUCCESS;
+}
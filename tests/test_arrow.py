@@ -0,0 +1,116 @@
+"""Tests for the Arrow columnar interface."""
+
+from __future__ import annotations
+
+import pytest
+
+from durak import (
+    ArrowArray,
+    Lemmatizer,
+    lemmatize_arrow,
+    normalize_arrow,
+    tokenize_arrow,
+    tokenize_with_offsets,
+)
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+TEXTS = ["Ankara'ya gittim.", None, "", "Çok güzel 😊"]
+
+
+class StreamOnly:
+    """Exposes only the stream protocol, like a chunked array."""
+
+    def __init__(self, array: ArrowArray) -> None:
+        self.array = array
+
+    def __arrow_c_stream__(self, requested_schema: object = None) -> object:
+        return self.array.__arrow_c_stream__()
+
+
+def expected() -> tuple[list, list, list]:
+    tokens, starts, ends = [], [], []
+    for text in TEXTS:
+        spans = None if text is None else tokenize_with_offsets(text)
+        tokens.append(None if spans is None else [t for t, _, _ in spans])
+        starts.append(None if spans is None else [s for _, s, _ in spans])
+        ends.append(None if spans is None else [e for _, _, e in spans])
+    return tokens, starts, ends
+
+
+def test_from_pylist():
+    column = ArrowArray.from_pylist(["kitap", None])
+    assert column.type == "large_string"
+    assert len(column) == 2
+    assert column.to_pylist() == ["kitap", None]
+    assert repr(column) == "ArrowArray(type=large_string, length=2)"
+
+
+@pytest.mark.parametrize("threads", [1, 2])
+def test_tokenize_matches_offsets(threads: int):
+    columns = tokenize_arrow(ArrowArray.from_pylist(TEXTS), threads=threads)
+    assert [column.type for column in columns] == [
+        "large_list<large_string>",
+        "large_list<int64>",
+        "large_list<int64>",
+    ]
+    assert tuple(column.to_pylist() for column in columns) == expected()
+
+
+def test_stream_input():
+    tokens, _, _ = tokenize_arrow(StreamOnly(ArrowArray.from_pylist(TEXTS)))
+    assert tokens.to_pylist() == expected()[0]
+
+
+def test_errors():
+    tokens, _, _ = tokenize_arrow(ArrowArray.from_pylist(["a b"]))
+    with pytest.raises(ValueError, match="string array"):
+        tokenize_arrow(tokens)
+    with pytest.raises(TypeError, match="Arrow array"):
+        tokenize_arrow(["a b"])
+
+
+def test_pyarrow_round_trip():
+    pa = pytest.importorskip("pyarrow")
+
+    array = pa.chunked_array([pa.array(TEXTS[:2]), pa.array(TEXTS[2:])])
+    tokens, starts, ends = tokenize_arrow(array)
+    assert pa.array(tokens).to_pylist() == expected()[0]
+    assert pa.array(ends).to_pylist() == expected()[2]
+    sliced = pa.array(TEXTS, type=pa.large_string()).slice(1)
+    assert tokenize_arrow(sliced)[0].to_pylist() == expected()[0][1:]
+
+
+def test_polars_round_trip():
+    pl = pytest.importorskip("polars")
+
+    tokens, starts, _ = tokenize_arrow(pl.Series("text", TEXTS))
+    assert pl.Series(tokens).to_list() == expected()[0]
+    assert pl.Series(starts).to_list() == expected()[1]
+
+
+@pytest.mark.parametrize("threads", [1, 2])
+def test_normalize_and_lemmatize_columns(threads: int):
+    words = ArrowArray.from_pylist(["KİTAPLAR", None, "Geliyorum"])
+    normalized = normalize_arrow(words, threads=threads)
+    assert normalized.type == "large_string"
+    assert normalized.to_pylist() == ["kitaplar", None, "geliyorum"]
+    lemmas = lemmatize_arrow(StreamOnly(normalized), threads=threads)
+    lemmatizer = Lemmatizer()
+    assert lemmas.to_pylist() == [lemmatizer("kitaplar"), None, lemmatizer("geliyorum")]
+    unchanged = normalize_arrow(words, lowercase=False, handle_turkish_i=False)
+    assert unchanged.to_pylist()[0] == "KİTAPLAR"
+    with pytest.raises(ValueError, match="unknown strategy"):
+        lemmatize_arrow(words, "fuzzy")
+    with pytest.raises(TypeError, match="normalize_arrow expects an Arrow array"):
+        normalize_arrow(["kitap"])
+
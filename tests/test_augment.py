@@ -0,0 +1,91 @@
+"""Tests for morphology-based text augmentation."""
+
+from __future__ import annotations
+
+import pytest
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+from durak import add_noise, augment, reinflect_cases
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+TEXT = "Ali yarın okula kitabı götürecek."
+
+
+def test_reinflect_cases():
+    assert reinflect_cases("evde") == ["evi", "eve", "evden", "evle"]
+    assert reinflect_cases("Okula")[:2] == ["Okulu", "Okulda"]
+    assert reinflect_cases("güzel") == []
+
+
+def test_augment_is_reproducible():
+    variants = augment(TEXT, 3, seed=5)
+    assert len(variants) == 3
+    assert variants == augment(TEXT, 3, seed=5)
+    assert TEXT not in variants
+    assert len(set(variants)) == 3
+
+
+def test_augment_operations():
+    assert augment("Ali yarın okula gidecek.", 5, operations=["order"]) == [
+        "Ali okula yarın gidecek."
+    ]
+    cases = augment(TEXT, 10, operations=["case"])
+    assert "Ali yarın okulda kitabı götürecek." in cases
+    clitics = augment("ben de geldim", 10, operations=["clitic"])
+    assert clitics == ["ben geldim"]
+
+
+def test_augment_keeps_lemmas_and_punctuation():
+    for variant in augment(TEXT, 20):
+        assert variant.startswith("Ali ")
+        assert variant.endswith(" götürecek.")
+
+
+def test_augment_rejects_unknown_operation():
+    with pytest.raises(ValueError, match="unknown augmentation operation"):
+        augment(TEXT, operations=["synonym"])
+
+
+NOISE_TEXT = "Bugün hava çok güzel, ben de dışarıda yürüyüş yapmak istiyorum."
+
+
+def test_add_noise_ascii_profile():
+    assert add_noise("Çocuğu İstanbul'da gördüm", "ascii", 1.0) == (
+        "Cocugu Istanbul'da gordum"
+    )
+
+
+def test_add_noise_is_reproducible():
+    noisy = add_noise(NOISE_TEXT, rate=0.5, seed=3)
+    assert noisy == add_noise(NOISE_TEXT, rate=0.5, seed=3)
+    assert noisy != NOISE_TEXT
+    assert add_noise(NOISE_TEXT, rate=0.0) == NOISE_TEXT
+
+
+def test_add_noise_keyboard_keeps_word_count():
+    noisy = add_noise(NOISE_TEXT, profile="keyboard", rate=1.0, seed=1)
+    assert len(noisy.split()) == len(NOISE_TEXT.split())
+    assert noisy != NOISE_TEXT
+
+
+def test_add_noise_f_keyboard():
+    noisy = add_noise("a", profile="keyboard", rate=1.0, seed=2, keyboard="f")
+    assert set(noisy) <= set("aıoeüvc")
+
+
+def test_add_noise_rejects_bad_arguments():
+    with pytest.raises(ValueError, match="unknown noise profile"):
+        add_noise(NOISE_TEXT, profile="ocr")
+    with pytest.raises(ValueError, match="rate"):
+        add_noise(NOISE_TEXT, rate=1.5)
+    with pytest.raises(ValueError, match="unknown keyboard layout"):
+        add_noise(NOISE_TEXT, keyboard="dvorak")
@@ -0,0 +1,171 @@
+"""Tests for vectorized column helpers."""
+
+from __future__ import annotations
+
+import io
+import os
+import signal
+
+import pytest
+
+from durak import (
+    BatchResult,
+    CancellationToken,
+    CancelledError,
+    Lemmatizer,
+    process_series,
+    sample_lines,
+)
+from durak.batch import BATCH_ERROR_MODES, SERIES_STAGES
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+def test_missing_values_stay_aligned():
+    values = ["kitaplar", None, float("nan"), "geliyorum"]
+    assert process_series(values, "lemmatize") == ["kitap", None, None, "gel"]
+
+
+def test_stages():
+    assert process_series(["İSTANBUL"], "normalize") == ["istanbul"]
+    assert process_series(["herşey yolunda"], "correct_confusions") == [
+        "her şey yolunda"
+    ]
+    assert process_series(["Merhaba dünya!", None], "tokenize") == [
+        ["Merhaba", "dünya", "!"],
+        None,
+    ]
+
+
+def test_lemmatize_matches_lemmatizer():
+    words = ["kitaplar", "evlerde", "masalar", "geliyorum"]
+    lemmatizer = Lemmatizer()
+    assert process_series(words, "lemmatize") == [lemmatizer(w) for w in words]
+
+
+def test_accepts_any_iterable():
+    assert process_series(iter(["kitaplar"]), "lemmatize") == ["kitap"]
+    assert process_series((), "normalize") == []
+
+
+def test_every_stage_is_supported():
+    for stage in SERIES_STAGES:
+        assert len(process_series(["kitaplar", None], stage)) == 2
+
+
+def test_errors():
+    with pytest.raises(ValueError, match="unknown stage"):
+        process_series(["kitap"], "stem")
+    with pytest.raises(TypeError, match="position 1"):
+        process_series(["kitap", 42], "normalize")
+    with pytest.raises(ValueError, match="unknown errors mode"):
+        process_series(["kitap"], "normalize", errors="ignore")
+    with pytest.raises(TypeError, match="single str"):
+        process_series("kitap", "normalize")
+
+
+def test_isolated_errors_keep_the_rest_of_the_batch():
+    values = ["kitaplar", 42, None, "\ud800", "geliyorum"]
+    results = process_series(values, "lemmatize", errors="isolate")
+    assert all(isinstance(result, BatchResult) for result in results)
+    assert [result.ok for result in results] == [True, False, True, False, True]
+    assert [result.value for result in results] == ["kitap", None, None, None, "gel"]
+    assert results[1].error is not None and "position 1" in results[1].error
+    assert results[3].error is not None and "UnicodeEncodeError" in results[3].error
+    assert results[0].unwrap() == "kitap"
+    with pytest.raises(ValueError, match="got int"):
+        results[1].unwrap()
+    assert repr(results[0]) == "Ok('kitap')"
+    assert repr(results[1]).startswith("Err(")
+
+
+def test_batch_helpers_isolate_errors():
+    from durak._durak_core import lemmatize_batch, normalize_batch, tokenize_batch
+
+    assert "isolate" in BATCH_ERROR_MODES
+    lemmas = lemmatize_batch(["kitaplar", b"evler"], threads=2, errors="isolate")
+    assert [(r.ok, r.value) for r in lemmas] == [(True, "kitap"), (False, None)]
+    texts = normalize_batch([1.5, "İSTANBUL"], errors="isolate")
+    assert [r.value for r in texts] == [None, "istanbul"]
+    tokens = tokenize_batch(["Merhaba dünya!", {}], errors="isolate")
+    assert tokens[0].unwrap() == ["Merhaba", "dünya", "!"]
+    assert not tokens[1].ok
+    with pytest.raises(TypeError, match="position 1"):
+        tokenize_batch(["Merhaba", 7])
+    assert normalize_batch(["İSTANBUL", float("nan")]) == ["istanbul", None]
+
+
+class EndlessFile(io.RawIOBase):
+    """A file-like object of repeated lines that runs `on_read` per read."""
+
+    def __init__(self, on_read):
+        self.reads = 0
+        self.on_read = on_read
+
+    def read(self, size=-1):
+        self.reads += 1
+        self.on_read(self.reads)
+        return b"kitaplar okundu\n" * 64
+
+
+def test_cancellation_token():
+    from durak._durak_core import lemmatize_batch, normalize_batch, tokenize_batch
+
+    token = CancellationToken()
+    assert not token.cancelled
+    assert normalize_batch(["İSTANBUL"], cancel=token) == ["istanbul"]
+    token.cancel()
+    assert token.cancelled
+    assert repr(token) == "CancellationToken(cancelled=True)"
+    words = ["kitaplar"] * 2000
+    for run in (
+        lambda: lemmatize_batch(words, threads=2, cancel=token),
+        lambda: normalize_batch(words, cancel=token),
+        lambda: tokenize_batch(words, errors="isolate", cancel=token),
+        lambda: process_series(words, "lemmatize", cancel=token),
+    ):
+        with pytest.raises(CancelledError, match="cancelled"):
+            run()
+
+
+def test_cancel_stops_file_reading():
+    token = CancellationToken()
+
+    def cancel_after_ten(reads):
+        if reads == 10:
+            token.cancel()
+
+    source = EndlessFile(cancel_after_ten)
+    with pytest.raises(CancelledError):
+        sample_lines(source, 5, cancel=token)
+    assert source.reads == 10
+
+
+@pytest.mark.skipif(not hasattr(signal, "SIGINT"), reason="needs SIGINT")
+def test_ctrl_c_interrupts_without_killing_the_interpreter():
+    def interrupt_once(reads):
+        if reads == 10:
+            os.kill(os.getpid(), signal.SIGINT)
+
+    source = EndlessFile(interrupt_once)
+    with pytest.raises(KeyboardInterrupt):
+        sample_lines(source, 5)
+    # The reader stopped soon after the interrupt and later calls still work
+    assert source.reads < 10_000
+    assert sample_lines(io.StringIO("bir\niki\n"), 5) == ["bir", "iki"]
+
+
+def test_pandas_series():
+    pd = pytest.importorskip("pandas")
+
+    series = pd.Series(["kitaplar", None, pd.NA, "evler"])
+    assert process_series(series, "lemmatize") == ["kitap", None, None, "ev"]
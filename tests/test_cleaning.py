@@ -1,6 +1,13 @@
 import pytest
 from durak import cleaning
 
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
 
 def test_normalize_unicode_handles_typographic_variants() -> None:
     raw = "“İstanbul’da—efsane!”"
@@ -221,3 +228,84 @@ def test_emoji_integration_with_social_media_cleaning() -> None:
     assert len(extracted_emojis) >= 5
     assert "harıka" in cleaned_with_extract or "harika" in cleaned_with_extract
     assert "😍" not in cleaned_with_extract
+
+
+@pytest.mark.skipif(not RUST_AVAILABLE, reason="Rust extension not installed")
+def test_clean_text_pdf_artifacts_joins_broken_words() -> None:
+    text = "Güzel kitap-\nları oku\u00addum.\nTürk-\nAlman işbirliği"
+    assert cleaning.clean_text(text, pdf_artifacts=True) == (
+        "güzel kitapları okudum. türk-alman işbirliği"
+    )
+    assert "kitap- ları" in cleaning.clean_text(text)
+
+
+def test_bidi_controls_are_stripped() -> None:
+    text = "\u202eevet\u202c ve \u200fsalam\u2069"
+    assert cleaning.strip_bidi_controls(text) == "evet ve salam"
+    assert cleaning.normalize_unicode(text) == "evet ve salam"
+    assert cleaning.clean_text("\u202bMerhaba\u202c dünya") == "merhaba dünya"
+
+
+def test_bidi_controls_are_not_tokens() -> None:
+    from durak.tokenizer import tokenize_text
+
+    text = "bkz. https://x.com/a\u200f \u202eevet\u202c!"
+    assert tokenize_text(text) == ["bkz", ".", "https://x.com/a", "evet", "!"]
+
+
+@pytest.mark.skipif(not RUST_AVAILABLE, reason="Rust extension not installed")
+def test_rtl_spans_and_isolation() -> None:
+    from durak import tokenize_with_offsets
+
+    text = "Ayette \u202e«بسم الله» yazar, İbranice שָׁלוֹם der."
+    spans = cleaning.rtl_spans(text)
+    assert spans == [
+        ("بسم الله", "ARABIC", 9, 17),
+        ("שָׁלוֹם", "HEBREW", 35, 42),
+    ]
+    for span, _, start, end in spans:
+        assert text[start:end] == span
+    for token, start, end in tokenize_with_offsets(text):
+        assert text[start:end] == token
+    assert cleaning.isolate_rtl(text) == (
+        "Ayette «\u2067بسم الله\u2069» yazar, İbranice \u2067שָׁלוֹם\u2069 der."
+    )
+
+
+@pytest.mark.skipif(not RUST_AVAILABLE, reason="Rust extension not installed")
+def test_dedup_lines_keeps_first_occurrences(tmp_path) -> None:
+    source = tmp_path / "dump.txt"
+    source.write_text("merhaba\ndünya\nmerhaba\n\nselam\r\ndünya\n\nselam", "utf-8")
+    for cap in (None, 0):
+        destination = tmp_path / f"unique-{cap}.txt"
+        assert cleaning.dedup_lines(source, destination, max_memory_mb=cap) == 4
+        assert destination.read_text("utf-8") == "merhaba\ndünya\n\nselam\n"
+    with pytest.raises(ValueError, match="differ"):
+        cleaning.dedup_lines(source, source)
+    with pytest.raises(FileNotFoundError):
+        cleaning.dedup_lines(tmp_path / "missing.txt", tmp_path / "out.txt")
+    assert not (tmp_path / "out.txt").exists()
+
+
+@pytest.mark.skipif(not RUST_AVAILABLE, reason="Rust extension not installed")
+def test_deduplicator_flags_near_duplicates() -> None:
+    documents = [
+        "Bugün hava çok güzel, biz de parka gittik ve akşam eve döndük.",
+        "Yarın sabah erkenden toplantı var, lütfen unutmayın.",
+        "BUGÜN HAVA ÇOK GÜZEL, BİZ DE PARKA GİTTİK VE AKŞAM EVE DÖNDÜK!",
+        "Bugün hava çok güzel, biz de parka gittik ve akşam eve geldik.",
+        "Kedim kanepenin altına saklanmış.",
+    ]
+    dedup = cleaning.Deduplicator(threshold=0.6, shingle_size=2)
+    assert dedup.add_batch(documents, threads=2) == [None, None, 0, 0, None]
+    assert (len(dedup), dedup.distinct) == (5, 3)
+    assert dedup.add("yarın sabah erkenden toplantı var lütfen unutmayın") == 1
+    assert dedup.query("Tamamen farklı bir cümle yazdım.") is None
+    assert dedup.similarity(documents[0], documents[2]) == 1.0
+    assert 0.6 < dedup.similarity(documents[0], documents[3]) < 1.0
+
+    # Same seed, same decisions
+    again = cleaning.Deduplicator(threshold=0.6, shingle_size=2)
+    assert [again.add(doc) for doc in documents] == [None, None, 0, 0, None]
+    with pytest.raises(ValueError, match="threshold"):
+        cleaning.Deduplicator(threshold=1.5)
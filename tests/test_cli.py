@@ -2,11 +2,14 @@
 
 from __future__ import annotations
 
+import hashlib
 import json
 import subprocess
 import sys
 from pathlib import Path
 
+import pytest
+
 
 def test_cli_exists():
     """Test that CLI module exists and is importable."""
@@ -46,6 +49,19 @@ def test_cli_clean_with_json_format():
     assert "char_count" in data
 
 
+def test_cli_clean_pdf_artifacts():
+    """Test clean command joining words broken by PDF line wrapping."""
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "clean", "-", "--pdf-artifacts"],
+        input="kitap-\nları okudum",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    assert result.stdout.strip() == "kitapları okudum"
+
+
 def test_cli_tokenize_command():
     """Test tokenize command via subprocess."""
     test_text = "Merhaba dünya"
@@ -78,6 +94,29 @@ def test_cli_tokenize_with_json_format():
     assert data["count"] == 2
 
 
+def test_cli_tokenize_profile():
+    """Test --profile runs the steps and tokenizer options of a profile."""
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "tokenize", "-", "--profile", "social"],
+        input="#GünaydınTürkiye arkadaşlar! RT",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    assert result.stdout.split() == ["günaydın", "türkiye", "arkadaşlar"]
+
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "tokenize", "-", "-p", "legal", "-s"],
+        input="Madde 5",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode != 0
+    assert "cannot be combined" in result.stderr
+
+
 def test_cli_lemmatize_command():
     """Test lemmatize command via subprocess."""
     result = subprocess.run(
@@ -91,6 +130,79 @@ def test_cli_lemmatize_command():
     assert "ev" in result.stdout
 
 
+def test_cli_eval_command(tmp_path):
+    """Test eval reports tier accuracy for a gold TSV file."""
+    gold = tmp_path / "gold.tsv"
+    gold.write_text(
+        "# word\tlemma\nkitaplar\tkitap\nevler\tev\tNoun\n", encoding="utf-8"
+    )
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "eval", "--gold", str(gold), "-f", "json"],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    report = json.loads(result.stdout)
+    assert report["total"] == 2
+    assert report["tiers"]["lookup_lemma"]["correct"] == 2
+    assert report["per_pos"]["Noun"]["strip_suffixes"]["total"] == 2
+
+    gold.write_text("kitaplar\n", encoding="utf-8")
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "eval", "--gold", str(gold)],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode != 0
+    assert "expected word<TAB>lemma" in result.stderr
+
+
+def test_cli_diff_tokenize(tmp_path):
+    """Test diff-tokenize scores durak tokens against a reference."""
+    text = tmp_path / "text.txt"
+    text.write_text("3 Mart 2024 geldi.\n", encoding="utf-8")
+    reference = tmp_path / "gold.txt"
+    reference.write_text("3 Mart 2024 geldi .\n", encoding="utf-8")
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "diff-tokenize", str(text),
+            "--other-file", str(reference), "--reference-format", "spaces",
+        ],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    assert "Precision: 66.7%  Recall: 40.0%" in result.stdout
+    assert "1:1  durak: [3 Mart 2024]  reference: [3] [Mart] [2024]" in result.stdout
+
+
+def test_cli_diff_tokenize_rewriting_config(tmp_path):
+    """Test diff-tokenize with a config whose tokenizer rewrites tokens."""
+    pytest.importorskip("durak._durak_core")
+    text = tmp_path / "text.txt"
+    text.write_text("Bugün #GüzelGün İstanbul\n", encoding="utf-8")
+    reference = tmp_path / "gold.txt"
+    reference.write_text("Bugün #GüzelGün İstanbul\n", encoding="utf-8")
+    config = tmp_path / "pipeline.toml"
+    config.write_text("[tokenizer]\nsplit_hashtags = true\n", encoding="utf-8")
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "diff-tokenize", str(text),
+            "--other-file", str(reference), "--reference-format", "spaces",
+            "--config", str(config),
+        ],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0, result.stderr
+    assert "matching: 2" in result.stdout
+    assert "1:7  durak: [Güzel] [Gün]  reference: [#GüzelGün]" in result.stdout
+
+
 def test_cli_stopwords_command():
     """Test stopwords command via subprocess."""
     result = subprocess.run(
@@ -177,3 +289,732 @@ def test_cli_lemmatize_with_strategy():
         encoding="utf-8",
     )
     assert result.returncode == 0
+
+
+def test_cli_ner_command():
+    """Test ner command via subprocess."""
+    try:
+        from durak import _durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "ner", "-", "--format", "json"],
+        input="Ahmet Yılmaz 12 Mart 2024'te İstanbul'a gitti.",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    data = json.loads(result.stdout)
+    labels = {(e["text"], e["label"]) for e in data["entities"]}
+    assert ("Ahmet Yılmaz", "PER") in labels
+    assert ("12 Mart 2024", "DATE") in labels
+    assert ("İstanbul", "LOC") in labels
+
+
+def test_cli_ner_label_filter():
+    """Test ner command restricted to a single label."""
+    try:
+        from durak import _durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "ner", "-", "--label", "LOC"],
+        input="Ahmet Yılmaz İstanbul'a gitti.",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    assert result.stdout.strip() == "İstanbul\tLOC\t13\t21"
+
+
+def test_cli_stats_command():
+    """Test stats command with JSON output."""
+    try:
+        from durak import _durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "stats", "-", "--format", "json"],
+        input="Evlerimizde oturuyoruz.",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    data = json.loads(result.stdout)
+    assert data["words"] == 2
+    assert data["mean_morphemes_per_word"] == 3.5
+
+
+def test_cli_grammar_command():
+    """Test grammar command with JSON output."""
+    try:
+        from durak import _durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "grammar", "-", "--format", "json"],
+        input="Bende geldim. Geldinmi?",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    data = json.loads(result.stdout)
+    assert data["count"] == 2
+    assert [i["suggestion"] for i in data["issues"]] == ["Ben de geldim", "Geldin mi"]
+    assert data["issues"][0]["rule"] == "de-da-attached"
+
+
+def test_cli_detect_command():
+    """Test detect command reports and filters by language."""
+    try:
+        from durak import _durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "detect", "-", "--format", "json"],
+        input="Bugün hava çok güzel, dışarı çıkalım mı?",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    data = json.loads(result.stdout)
+    assert data["language"] == "tr"
+    assert data["confidence"] > 0.9
+
+    lines = [
+        "Toplantı yarın saat onda yapılacaktır.",
+        "The meeting will be held tomorrow at ten.",
+        '{"id": 3, "text": "Kitapları masanın üstüne bıraktım."}',
+        "12345",
+    ]
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "detect", "-", "--keep", "tr"],
+        input="\n".join(lines) + "\n",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    assert result.stdout.splitlines() == [lines[0], lines[2]]
+
+
+def test_cli_filter_command():
+    """Test filter command drops spam, table and markup lines."""
+    try:
+        from durak import _durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    lines = [
+        "Yarın saat onda toplantı yapılacak.",
+        "SÜPER FIRSAT HEMEN ARAYIN",
+        "|---|---|---|",
+        '{"id": 4, "text": "Kitapları masanın üstüne bıraktım."}',
+        "0212 555 12 34",
+    ]
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "filter", "-"],
+        input="\n".join(lines) + "\n",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    assert result.stdout.splitlines() == [lines[0], lines[3]]
+
+
+def test_cli_field_selection():
+    """Test --field reads the text from a nested field of ndjson lines."""
+    try:
+        from durak import _durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    lines = [
+        '{"id": 1, "retweeted_status": {"text": "Bugün hava çok güzel"}, "text": "RT"}',
+        '{"id": 2, "delete": {"status": 7}}',
+        '{"id": 3, "retweeted_status": {"text": "See you tomorrow"}}',
+    ]
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "normalize", "-",
+            "-f", "jsonl", "--field", "retweeted_status.text",
+        ],
+        input="\n".join(lines) + "\n",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0, result.stderr
+    records = [json.loads(line) for line in result.stdout.splitlines()]
+    assert [record["line"] for record in records] == [1, 3]
+    assert "id" not in records[0]
+
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "detect", "-", "--keep", "tr",
+            "--field", "retweeted_status.text",
+        ],
+        input="\n".join(lines) + "\n",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0, result.stderr
+    assert result.stdout.splitlines() == [lines[0]]
+
+
+def test_cli_ngrams_command():
+    """Test ngrams command counts word and character n-grams."""
+    try:
+        from durak import _durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "ngrams", "-", "--n", "2"],
+        input="ben de geldim, ben de gittim.",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    assert result.stdout.splitlines()[:2] == ["ben de\t2", "de geldim\t1"]
+
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "ngrams", "-",
+            "--n", "3", "--chars", "--top", "1", "--format", "json",
+        ],
+        input="anana",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    assert json.loads(result.stdout) == {
+        "ngrams": [{"ngram": "ana", "count": 2}],
+        "count": 1,
+    }
+
+
+def test_cli_stopwords_diff_command(tmp_path):
+    """Test stopwords diff compares normalized lists."""
+    list_a = tmp_path / "a.txt"
+    list_b = tmp_path / "b.txt"
+    list_a.write_text("# base\nve\nİçin\nama\n", encoding="utf-8")
+    list_b.write_text("ve\niçin\nrt\n", encoding="utf-8")
+
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "stopwords", "diff",
+            str(list_a), str(list_b), "--format", "json", "--exit-code",
+        ],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 1
+    diff = json.loads(result.stdout)
+    assert diff["only_in_a"] == ["ama"]
+    assert diff["only_in_b"] == ["rt"]
+    assert diff["common"] == ["için", "ve"]
+
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "stopwords", "diff",
+            str(list_a), str(list_b),
+        ],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    assert "- ama" in result.stdout
+    assert "+ rt" in result.stdout
+
+
+def test_cli_stopwords_merge_command(tmp_path):
+    """Test stopwords merge writes a deduplicated list."""
+    list_a = tmp_path / "a.txt"
+    list_b = tmp_path / "b.txt"
+    merged = tmp_path / "merged.txt"
+    list_a.write_text("ve\nAma\n", encoding="utf-8")
+    list_b.write_text("VE\nrt\n", encoding="utf-8")
+
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "stopwords", "merge",
+            str(list_a), str(list_b), "-o", str(merged),
+        ],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    assert merged.read_text(encoding="utf-8") == "ama\nrt\nve\n"
+
+
+def test_cli_pipeline_jsonl_output(tmp_path):
+    """Test pipeline emits one JSON object per non-empty input line."""
+    corpus = tmp_path / "corpus.txt"
+    text = "Kitaplarımdan birini OKUDUM.\n\nBu çok güzel!\n"
+    corpus.write_text(text, encoding="utf-8")
+
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "pipeline", str(corpus), "-s", "-l"],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    records = [json.loads(line) for line in result.stdout.splitlines()]
+    assert [r["line"] for r in records] == [1, 3]
+    assert records[0]["tokens"][:2] == ["kitaplarımdan", "birini"]
+    assert records[0]["lemmas"][0] == "kitap"
+    assert "bu" not in records[1]["tokens"]
+
+
+def test_cli_pipeline_jsonl_input_conll_output():
+    """Test pipeline reads a JSONL text field and writes CoNLL-U."""
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "pipeline", "-",
+            "-i", "jsonl", "--text-field", "body", "-f", "conll", "-l",
+        ],
+        input='{"id": 7, "body": "Kitaplar geldi."}\n',
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    lines = result.stdout.splitlines()
+    assert lines[0] == "# sent_id = 1"
+    assert lines[1] == "# text = Kitaplar geldi."
+    assert lines[2].split("\t")[:3] == ["1", "kitaplar", "kitap"]
+
+
+def test_cli_pipeline_conllu_output():
+    """Test pipeline writes UD CoNLL-U with UPOS and FEATS for every token."""
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "pipeline", "-", "-f", "conllu", "-s"],
+        input="Kitaplarımızı okudum.\n\nVe geldim\n",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    sentences = result.stdout.split("\n\n")
+    lines = sentences[0].splitlines()
+    assert lines[:2] == ["# sent_id = 1", "# text = Kitaplarımızı okudum."]
+    kitap = lines[2].split("\t")
+    assert kitap[:5] == ["1", "Kitaplarımızı", "kitap", "NOUN", "Noun"]
+    assert "Case=Acc" in kitap[5].split("|")
+    assert lines[3].split("\t")[3] == "VERB"
+    assert lines[3].endswith("SpaceAfter=No")
+    # Stopwords stay in the sentence; blank lines are skipped
+    second = sentences[1].splitlines()
+    assert second[:2] == ["# sent_id = 3", "# text = Ve geldim"]
+    assert [line.split("\t")[1] for line in second[2:]] == ["Ve", "geldim"]
+
+
+def test_cli_pipeline_tsv_output_file(tmp_path):
+    """Test pipeline writes a TSV file with a header row."""
+    output = tmp_path / "out.tsv"
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "pipeline", "-",
+            "-f", "tsv", "-o", str(output),
+        ],
+        input="Merhaba dünya\n",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    rows = output.read_text(encoding="utf-8").splitlines()
+    assert rows == ["line\tindex\ttoken", "1\t1\tmerhaba", "1\t2\tdünya"]
+
+
+def test_cli_pipeline_config_file(tmp_path):
+    """Test pipeline reads steps and stopwords from a TOML config."""
+    (tmp_path / "extra.txt").write_text("dünya\n", encoding="utf-8")
+    config = tmp_path / "pipeline.toml"
+    config.write_text(
+        'steps = ["normalize", "tokenize", "remove_stopwords"]\n'
+        "[stopwords]\n"
+        'files = ["extra.txt"]\n',
+        encoding="utf-8",
+    )
+    command = [
+        sys.executable, "-m", "durak.cli", "pipeline", "-", "--config", str(config),
+    ]
+    result = subprocess.run(
+        command,
+        input="Bu kitap ve dünya\n",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    assert json.loads(result.stdout)["tokens"] == ["kitap"]
+
+    # Flags on the command line override the file
+    result = subprocess.run(
+        command + ["-l"],
+        input="Kitaplar\n",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert json.loads(result.stdout)["lemmas"] == ["kitap"]
+
+
+def test_cli_pipeline_rejects_invalid_jsonl():
+    """Test pipeline reports the line of a malformed JSONL record."""
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "pipeline", "-", "-i", "jsonl"],
+        input='{"text": "ok"}\nnot json\n',
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode != 0
+    assert "line 2" in result.stderr
+
+
+def test_cli_tokenize_jsonl_one_document_per_line():
+    """Test --format jsonl emits one object per input line, keeping extra fields."""
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "tokenize", "-", "-f", "jsonl"],
+        input='Merhaba dünya\n\n{"id": 7, "text": "Kitaplar geldi"}\n',
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    records = [json.loads(line) for line in result.stdout.splitlines()]
+    assert records == [
+        {"tokens": ["merhaba", "dünya"], "count": 2},
+        {"id": 7, "tokens": ["kitaplar", "geldi"], "count": 2},
+    ]
+
+
+def test_cli_input_output_options_jsonl(tmp_path):
+    """Test --input/--output with JSONL across document commands."""
+    input_file = tmp_path / "docs.jsonl"
+    input_file.write_text(
+        '{"id": "a", "text": "İSTANBUL"}\n{"id": "b", "text": "Ankara"}\n',
+        encoding="utf-8",
+    )
+    for command in ["clean", "normalize", "ner", "stats", "lemmatize"]:
+        output = tmp_path / f"{command}.jsonl"
+        result = subprocess.run(
+            [
+                sys.executable, "-m", "durak.cli", command,
+                "--input", str(input_file), "--output", str(output),
+                "--format", "jsonl",
+            ],
+            capture_output=True,
+            text=True,
+            encoding="utf-8",
+        )
+        assert result.returncode == 0, result.stderr
+        assert "2 documents written" in result.stdout
+        records = [
+            json.loads(line)
+            for line in output.read_text(encoding="utf-8").splitlines()
+        ]
+        assert [record["id"] for record in records] == ["a", "b"]
+
+    normalized = (tmp_path / "normalize.jsonl").read_text(encoding="utf-8")
+    assert json.loads(normalized.splitlines()[0])["text"] == "istanbul"
+
+
+def test_cli_rejects_input_file_and_input_option(tmp_path):
+    """Test INPUT_FILE and --input cannot both be given."""
+    input_file = tmp_path / "in.txt"
+    input_file.write_text("Merhaba", encoding="utf-8")
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "clean",
+            str(input_file), "--input", str(input_file),
+        ],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode != 0
+    assert "not both" in result.stderr
+
+
+def test_cli_pipeline_threads_preserve_order():
+    """Test pipeline output is identical and in input order for any --threads."""
+    lines = "".join(f"Kitaplar {i} geldi\n" for i in range(3000))
+    outputs = []
+    for threads in ["1", "4", "0"]:
+        result = subprocess.run(
+            [
+                sys.executable, "-m", "durak.cli", "pipeline", "-",
+                "-l", "--threads", threads,
+            ],
+            input=lines,
+            capture_output=True,
+            text=True,
+            encoding="utf-8",
+        )
+        assert result.returncode == 0, result.stderr
+        outputs.append(result.stdout)
+    assert outputs[0] == outputs[1] == outputs[2]
+    records = [json.loads(line) for line in outputs[0].splitlines()]
+    assert [record["line"] for record in records] == list(range(1, 3001))
+    assert records[41]["tokens"] == ["kitaplar", "41", "geldi"]
+    assert records[41]["lemmas"][0] == "kitap"
+
+
+def test_cli_pipeline_sample_is_reproducible(tmp_path):
+    """Test --sample picks the same documents for a seed, keeping line numbers."""
+    corpus = tmp_path / "corpus.txt"
+    corpus.write_text("".join(f"Belge {i}\n" for i in range(1, 201)), encoding="utf-8")
+
+    def run(seed):
+        result = subprocess.run(
+            [
+                sys.executable, "-m", "durak.cli", "pipeline", str(corpus),
+                "--sample", "5", "--seed", seed,
+            ],
+            capture_output=True,
+            text=True,
+            encoding="utf-8",
+        )
+        assert result.returncode == 0, result.stderr
+        return [json.loads(line) for line in result.stdout.splitlines()]
+
+    first = run("42")
+    assert first == run("42")
+    assert first != run("7")
+    assert len(first) == 5
+    lines = [record["line"] for record in first]
+    assert lines == sorted(lines)
+    assert all(record["tokens"] == ["belge", str(record["line"])] for record in first)
+
+
+def test_cli_train_subword(tmp_path):
+    """Test train-subword writes a model and vocab that load back."""
+    corpus = tmp_path / "corpus.txt"
+    corpus.write_text(
+        "Kitaplarım evde.\nKitaplardan birini okudum.\nOkullarda kitaplar var.\n" * 10,
+        encoding="utf-8",
+    )
+    prefix = tmp_path / "tr"
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "train-subword", str(corpus),
+            "-v", "30", "-o", str(prefix),
+        ],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0, result.stderr
+    assert "Trained 30 pieces" in result.stdout
+
+    from durak import UnigramModel
+
+    model = UnigramModel.load(f"{prefix}.model")
+    assert len(model) == 30
+    assert len((tmp_path / "tr.vocab").read_text(encoding="utf-8").splitlines()) == 30
+
+    too_large = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "train-subword", str(corpus),
+            "-v", "5000", "-o", str(prefix),
+        ],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert too_large.returncode != 0
+    assert "too large" in too_large.stderr
+
+
+def test_cli_stopwords_list_domain():
+    """Test stopwords list includes domain words on top of the base list."""
+    base = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "stopwords", "list", "-f", "json"],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    domain = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "stopwords", "list",
+            "--domain", "social_media", "-f", "json",
+        ],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert base.returncode == 0 and domain.returncode == 0
+    base_words = set(json.loads(base.stdout))
+    domain_words = set(json.loads(domain.stdout))
+    assert "ve" in base_words
+    assert "rt" in domain_words - base_words
+    assert base_words < domain_words
+
+
+def test_cli_stopwords_filter():
+    """Test stopwords filter on arguments and on piped lines."""
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "stopwords", "filter", "bu kitap ve kalem"],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    assert result.stdout.strip() == "kitap kalem"
+
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "stopwords", "filter",
+            "--domain", "social_media", "--keep", "bu",
+        ],
+        input="rt bu haber\nve sonra\n",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    assert result.stdout.splitlines() == ["bu haber", "sonra"]
+
+
+def test_cli_stopwords_unknown_domain():
+    """Test an unknown domain is reported without a traceback."""
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "stopwords", "list", "--domain", "nope"],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode != 0
+    assert "Unknown stopword resource" in result.stderr
+    assert "Traceback" not in result.stderr
+
+
+def test_cli_analyze_columns():
+    """Test analyze prints root, morphemes and tags of the preferred parse."""
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "analyze", "kitaplarımdan"],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    header, row = result.stdout.splitlines()
+    assert header.split() == ["WORD", "ROOT", "POS", "MORPHEMES", "TAGS"]
+    assert row.split() == [
+        "kitaplarımdan", "kitap", "Noun", "kitap+lar+ım+dan", "Noun+Pl+P1sg+Abl"
+    ]
+
+
+def test_cli_analyze_json_all_parses():
+    """Test analyze --format json lists every alternative parse."""
+    result = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "analyze", "yüzü", "xqz", "-f", "json"],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0
+    records = json.loads(result.stdout)
+    assert [record["word"] for record in records] == ["yüzü", "xqz"]
+    tags = [analysis["tags"] for analysis in records[0]["analyses"]]
+    assert ["P3sg"] in tags and ["Acc"] in tags
+    assert records[0]["analyses"][0]["morphemes"][0]["surface"] == "ü"
+    assert records[1]["analyses"] == []
+
+
+def test_cli_run_manifest(tmp_path):
+    """Test --manifest records hashes, config, versions and counts."""
+    input_file = tmp_path / "corpus.txt"
+    input_file.write_text("Kitaplar geldi.\nBu ve şu\n", encoding="utf-8")
+    output = tmp_path / "out.jsonl"
+    manifest = tmp_path / "manifest.json"
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "pipeline", str(input_file),
+            "-l", "-o", str(output), "--manifest", str(manifest),
+        ],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0, result.stderr
+
+    record = json.loads(manifest.read_text(encoding="utf-8"))
+    expected_hash = hashlib.sha256(input_file.read_bytes()).hexdigest()
+    assert record["command"] == "pipeline"
+    assert record["input"]["sha256"] == expected_hash
+    assert record["output"]["sha256"] == hashlib.sha256(output.read_bytes()).hexdigest()
+    assert record["config"]["lemmatize_tokens"] is True
+    assert record["counts"] == {"documents": 2, "tokens": 6}
+    assert record["durak_version"] == record["build"]["durak_version"]
+    assert "stopwords_base" in record["resources"]
+    assert record["elapsed_seconds"] >= 0
+
+
+def test_cli_run_manifest_document_command(tmp_path):
+    """Test --manifest on a JSONL document command counts input lines."""
+    manifest = tmp_path / "manifest.json"
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "normalize", "-",
+            "-f", "jsonl", "--manifest", str(manifest),
+        ],
+        input="İSTANBUL\nANKARA\nIZMIR\n",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0, result.stderr
+    record = json.loads(manifest.read_text(encoding="utf-8"))
+    assert record["counts"] == {"documents": 3}
+    assert record["input"] == {"path": "-", "sha256": None, "bytes": None}
+    assert record["output"] is None
+
+
+def test_cli_bench_command(tmp_path):
+    """Test bench reports throughput for every stage of a corpus."""
+    corpus = tmp_path / "corpus.txt"
+    corpus.write_text(
+        "Kitapları okudum.\n\nYarın İstanbul'a gideceğiz.\n", encoding="utf-8"
+    )
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "bench", "--file", str(corpus),
+            "-n", "1", "-j", "2", "-f", "json",
+        ],
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0, result.stderr
+    summary = json.loads(result.stdout)
+    assert summary["documents"] == 2
+    assert summary["tokens"] == 7
+    assert [s["stage"] for s in summary["stages"]] == [
+        "normalize", "tokenize", "lemmatize", "batch",
+    ]
+    assert all(
+        s["tokens_per_sec"] > 0 and s["mb_per_sec"] > 0 for s in summary["stages"]
+    )
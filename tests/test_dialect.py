@@ -0,0 +1,45 @@
+"""Tests for dialect and variant cue detection."""
+
+import pytest
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+def test_detect_dialect():
+    from durak import detect_dialect
+
+    text = "Gız bööle dedi, yarın gidecağam."
+    assert detect_dialect(text) == [
+        ("Gız", "ANATOLIAN", "Kız", 0, 3),
+        ("bööle", "ANATOLIAN", "böyle", 4, 9),
+        ("gidecağam", "EASTERN", "gideceğim", 22, 31),
+    ]
+    assert detect_dialect("Kız böyle dedi, yarın gideceğim.") == []
+
+
+def test_azerbaijani_forms():
+    from durak import detect_dialect
+
+    cues = detect_dialect("Çox sağ olun, gəlirəm")
+    assert [(t, variety, std) for t, variety, std, _, _ in cues] == [
+        ("Çox", "AZERBAIJANI", "Çok"),
+        ("gəlirəm", "AZERBAIJANI", None),
+    ]
+
+
+def test_dialect_flags_align_with_tokens():
+    from durak import DIALECT_VARIETIES, dialect_flags
+
+    tokens = ["geliyrum", "ama", "yox", "."]
+    flags = dialect_flags(tokens)
+    assert flags == ["BLACK_SEA", None, "AZERBAIJANI", None]
+    assert all(flag is None or flag in DIALECT_VARIETIES for flag in flags)
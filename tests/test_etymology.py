@@ -0,0 +1,48 @@
+"""Tests for loanword origin tags."""
+
+import pytest
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+def test_word_origin():
+    from durak import ETYMOLOGY_ORIGINS, word_origin
+
+    assert word_origin("kitap") == "ARABIC"
+    assert word_origin("Dost") == "PERSIAN"
+    assert word_origin("OTOBÜS") == "FRENCH"
+    assert word_origin("futbol") == "ENGLISH"
+    assert word_origin("ev") is None
+    assert {word_origin(w) for w in ["hayat", "renk", "garaj", "maç"]} == set(
+        ETYMOLOGY_ORIGINS
+    )
+
+
+def test_inflected_tokens_use_root():
+    from durak import etymology_tags, tokenize
+
+    tokens = tokenize("Kitaplarımızı saatlerde okuduk.")
+    assert etymology_tags(tokens) == ["ARABIC", "ARABIC", None, None]
+    assert etymology_tags([]) == []
+
+
+def test_front_harmony_exceptions():
+    from durak import analyze, takes_front_harmony
+    from durak._durak_core import check_vowel_harmony_py
+
+    assert takes_front_harmony("saat")
+    assert takes_front_harmony("gol")
+    assert not takes_front_harmony("kitap")
+    assert check_vowel_harmony_py("saat", "ler")
+    assert not check_vowel_harmony_py("saat", "lar")
+    assert [a[0] for a in analyze("saatler")] == ["saat"]
+    assert analyze("saatlar") == []
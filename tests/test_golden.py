@@ -0,0 +1,105 @@
+"""Golden-corpus regression tests.
+
+Every sentence of ``tests/data/golden/corpus.txt`` is tokenized and
+lemmatized and compared with ``snapshot.jsonl`` next to it, one JSON object
+per sentence. Changes to the token regex, suffix lists or lemma dictionary
+fail here with the affected sentences, so they are reviewed instead of
+silently shifting downstream results. After an intended change, regenerate
+the snapshot and commit it with the code:
+
+    DURAK_UPDATE_GOLDEN=1 pytest tests/test_golden.py
+"""
+
+from __future__ import annotations
+
+import json
+import os
+from pathlib import Path
+from typing import Any
+
+import pytest
+
+from durak import Lemmatizer, tokenize
+
+GOLDEN_DIR = Path(__file__).parent / "data" / "golden"
+CORPUS_PATH = GOLDEN_DIR / "corpus.txt"
+SNAPSHOT_PATH = GOLDEN_DIR / "snapshot.jsonl"
+UPDATE_ENV = "DURAK_UPDATE_GOLDEN"
+FIELDS = ("tokens", "typed", "lemmas")
+
+
+def _sentences() -> list[str]:
+    lines = CORPUS_PATH.read_text(encoding="utf-8").splitlines()
+    return [line for line in lines if line.strip() and not line.startswith("#")]
+
+
+def _record(text: str, lemmatizer: Lemmatizer) -> dict[str, Any]:
+    from durak._durak_core import tokenize_with_types
+
+    return {
+        "text": text,
+        # Python regex tokenizer and Rust tokenizer with token types
+        "tokens": tokenize(text),
+        "typed": [[token, kind] for token, kind, _, _ in tokenize_with_types(text)],
+        "lemmas": [lemmatizer(token) for token in tokenize(text, strip_punct=True)],
+    }
+
+
+def _changes(
+    expected: list[dict[str, Any]], actual: list[dict[str, Any]]
+) -> list[str]:
+    """Readable differences, per sentence and field."""
+    previous = {record["text"]: record for record in expected}
+    changes: list[str] = []
+    for record in actual:
+        old = previous.get(record["text"])
+        if old is None:
+            changes.append(f"+ {record['text']}")
+            continue
+        for field in FIELDS:
+            if old[field] != record[field]:
+                changes.append(
+                    f"~ {record['text']}\n"
+                    f"    {field} was: {old[field]}\n"
+                    f"    {field} now: {record[field]}"
+                )
+    current = {record["text"] for record in actual}
+    changes.extend(f"- {text}" for text in previous if text not in current)
+    return changes
+
+
+def test_golden_corpus() -> None:
+    try:
+        import durak._durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    lemmatizer = Lemmatizer(strategy="hybrid")
+    actual = [_record(text, lemmatizer) for text in _sentences()]
+    if os.environ.get(UPDATE_ENV):
+        lines = [json.dumps(record, ensure_ascii=False) for record in actual]
+        SNAPSHOT_PATH.write_text("\n".join(lines) + "\n", encoding="utf-8")
+        return
+
+    expected = [
+        json.loads(line)
+        for line in SNAPSHOT_PATH.read_text(encoding="utf-8").splitlines()
+    ]
+    changes = _changes(expected, actual)
+    if changes or expected != actual:
+        pytest.fail(
+            "Golden corpus output changed (- removed, + added, ~ changed). "
+            f"If intended, rerun with {UPDATE_ENV}=1 and commit "
+            f"{SNAPSHOT_PATH.name}:\n" + "\n".join(changes or ["order changed"])
+        )
+
+
+def test_changes_report() -> None:
+    old = {"text": "Ev", "tokens": ["Ev"], "typed": [["Ev", "WORD"]], "lemmas": ["ev"]}
+    new = {**old, "lemmas": ["e"]}
+    assert _changes([old], [old]) == []
+    assert _changes([old], [new]) == [
+        "~ Ev\n    lemmas was: ['ev']\n    lemmas now: ['e']"
+    ]
+    assert _changes([old], []) == ["- Ev"]
+    assert _changes([], [old]) == ["+ Ev"]
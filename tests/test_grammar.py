@@ -0,0 +1,50 @@
+"""Tests for rule-based grammar checking."""
+
+import pytest
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+def fixes(text):
+    from durak import check_grammar
+
+    return [(rule, suggestion) for _, rule, _, suggestion, _, _ in check_grammar(text)]
+
+
+def test_clitic_de_da():
+    assert fixes("Bende geldim.") == [("de-da-attached", "Ben de geldim")]
+    assert fixes("kitap ta var") == [("de-da-hardened", "kitap da")]
+    assert fixes("okul de kapalı") == [("de-da-harmony", "okul da")]
+    assert fixes("Ben de geldim, kitap da var.") == []
+
+
+def test_ki_and_question_particle():
+    assert fixes("evde ki kitap") == [("ki-detached", "evdeki")]
+    assert fixes("dedimki gel") == [("ki-attached", "dedim ki")]
+    assert fixes("gelecekmisin") == [("mi-attached", "gelecek misin")]
+    assert fixes("Evdeki kitabı gördün mü?") == []
+
+
+def test_double_negation():
+    assert fixes("Ne çay ne kahve içmedim.") == [
+        ("ne-ne-negative", "Ne çay ne kahve içtim")
+    ]
+
+
+def test_issue_fields():
+    from durak import check_grammar
+
+    text = "Merhaba, şimdi geldinmi?"
+    [(span, _, message, _, start, end)] = check_grammar(text)
+    assert span == "geldinmi"
+    assert text[start:end] == span
+    assert "mi" in message
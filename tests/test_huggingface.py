@@ -0,0 +1,41 @@
+"""Tests for the HuggingFace tokenizers pre-tokenizer."""
+
+from __future__ import annotations
+
+import pytest
+
+from durak import pre_tokenize, tokenize
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+TEXT = "İstanbul'da 3 gün kaldık 😂 https://durak.dev"
+
+
+def test_pre_tokenize_byte_offsets():
+    tokens = pre_tokenize(TEXT)
+    encoded = TEXT.encode("utf-8")
+    assert [token for token, _ in tokens] == tokenize(TEXT)
+    for token, (start, end) in tokens:
+        assert encoded[start:end].decode("utf-8") == token
+    assert tokens[0] == ("İstanbul'da", (0, 12))
+
+
+def test_durak_pre_tokenizer():
+    pytest.importorskip("tokenizers")
+    from tokenizers import pre_tokenizers
+
+    from durak.huggingface import durak_pre_tokenizer
+
+    pre_tokenizer = durak_pre_tokenizer()
+    assert isinstance(pre_tokenizer, pre_tokenizers.PreTokenizer)
+    pieces = pre_tokenizer.pre_tokenize_str(TEXT)
+    assert [piece for piece, _ in pieces] == tokenize(TEXT)
@@ -0,0 +1,56 @@
+"""Tests for the Turkish keyboard layout tables."""
+
+from __future__ import annotations
+
+import pytest
+
+from durak import (
+    keyboard_confusion_pairs,
+    keyboard_distance,
+    keyboard_edit_distance,
+    keyboard_layout,
+    keyboard_neighbors,
+)
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+def test_layouts() -> None:
+    assert keyboard_layout() == ["qwertyuıopğü", "asdfghjklşi", "zxcvbnmöç"]
+    assert keyboard_layout("f")[0] == "fgğıodrnhpqw"
+
+
+def test_neighbors() -> None:
+    assert keyboard_neighbors("g") == ["t", "y", "f", "h", "v", "b"]
+    assert keyboard_neighbors("G") == keyboard_neighbors("g")
+    assert keyboard_neighbors("a", "f") == ["ı", "o", "e", "ü", "v", "c"]
+    assert keyboard_neighbors("5") == []
+
+
+def test_distances() -> None:
+    assert keyboard_distance("f", "g") == 1.0
+    assert keyboard_distance("f", "!") is None
+    assert keyboard_edit_distance("kitap", "kitao") == 0.5
+    assert keyboard_edit_distance("kitap", "kitak") == 1.0
+    assert keyboard_edit_distance("ev", "evler") == 3.0
+
+
+def test_confusion_pairs() -> None:
+    pairs = set(keyboard_confusion_pairs())
+    assert ("p", "o") in pairs
+    assert all((b, a) in pairs for a, b in pairs)
+    assert ("a", "s") not in set(keyboard_confusion_pairs("f"))
+
+
+def test_unknown_layout() -> None:
+    with pytest.raises(ValueError, match="unknown keyboard layout"):
+        keyboard_layout("dvorak")
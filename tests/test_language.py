@@ -0,0 +1,54 @@
+"""Tests for language detection."""
+
+import pytest
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+@pytest.mark.parametrize(
+    ("text", "language"),
+    [
+        ("Bugün hava çok güzel, dışarı çıkalım mı?", "tr"),
+        ("Bence bu film çok sıkıcıydı", "tr"),
+        ("Mən sabah Bakıya gedirəm", "az"),
+        ("Tu çawa yî? Ez baş im", "ku"),
+        ("This is a test of the filter", "en"),
+        ("Das ist nicht gut", "de"),
+        ("Je ne sais pas ce que tu veux dire", "fr"),
+        ("Muchas gracias por tu ayuda", "es"),
+        ("Grazie mille per il tuo aiuto", "it"),
+    ],
+)
+def test_detect_language(text, language):
+    from durak import SUPPORTED_LANGUAGES, detect_language
+
+    detected, confidence = detect_language(text)
+    assert detected == language
+    assert detected in SUPPORTED_LANGUAGES
+    assert 0.5 < confidence <= 1.0
+
+
+def test_undetermined_text():
+    from durak import detect_language
+
+    assert detect_language("") == ("und", 0.0)
+    assert detect_language("2024-01-01 12:30") == ("und", 0.0)
+    assert detect_language("Привет, как дела?") == ("und", 0.0)
+
+
+def test_is_turkish():
+    from durak import is_turkish
+
+    assert is_turkish("Toplantı yarın saat onda yapılacaktır.")
+    assert not is_turkish("The meeting will be held tomorrow.")
+    assert not is_turkish("12345")
+    assert not is_turkish("merhaba", threshold=1.0)
@@ -1,5 +1,6 @@
 import pytest
 from durak.lemmatizer import Lemmatizer
+from durak.exceptions import ConfigurationError
 
 
 def test_tier1_lookup():
@@ -290,6 +291,36 @@ def test_root_validation_hybrid():
     assert result == "araba"
 
 
+def test_root_validation_vowel_drop():
+    """Roots that drop their last vowel before a suffix are restored"""
+    try:
+        from durak import _durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    lemmatizer = Lemmatizer(strategy="hybrid", validate_roots=True)
+
+    assert lemmatizer("burnu") == "burun"
+    assert lemmatizer("oğlum") == "oğul"
+    assert lemmatizer("ağzımız") == "ağız"
+    assert lemmatizer("burunda") == "burun"
+
+
+def test_root_validation_proper_nouns():
+    """Only the suffixes after an apostrophe are stripped from proper nouns"""
+    try:
+        from durak._durak_core import strip_suffixes_validated
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    lemmatizer = Lemmatizer(strategy="hybrid", validate_roots=True)
+
+    assert lemmatizer("Ankara'dan") == "Ankara"
+    assert lemmatizer("Türkiye'nin") == "Türkiye"
+    assert strip_suffixes_validated("ANKARA'DAN") == "ANKARA"
+    unprotected = strip_suffixes_validated("Ankara'dan", preserve_proper_nouns=False)
+    assert unprotected != "Ankara"
+
 def test_lemmatizer_repr_with_validation():
     """Test __repr__ includes validation parameters"""
     lemmatizer = Lemmatizer(
@@ -312,3 +343,105 @@ def test_lemmatizer_repr_without_validation():
     
     repr_str = repr(lemmatizer)
     assert repr_str == "Lemmatizer(strategy='lookup')"
+
+
+def test_lookup_lemma_prefix():
+    """Prefix queries stream sorted entries from the embedded FST"""
+    try:
+        from durak import _durak_core
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    results = _durak_core.lookup_lemma_prefix("kitap")
+    assert ("kitaplar", "kitap") in results
+    assert all(form.startswith("kitap") for form, _ in results)
+    assert results == sorted(results)
+
+    assert len(_durak_core.lookup_lemma_prefix("", limit=5)) == 5
+    assert _durak_core.lookup_lemma_prefix("xyz") == []
+
+
+def test_lemmatizer_tie_break():
+    """Tie-break policies pick among dictionary readings and stripped roots."""
+    assert Lemmatizer(tie_break="return-all").candidates("gelir") == ["gel", "gelir"]
+    assert Lemmatizer().candidates("gelir") == ["gel"]
+    assert Lemmatizer(tie_break="prefer-shortest")("gözlükçü") == "göz"
+    assert Lemmatizer(tie_break="prefer-most-frequent")("gözlükçü") == "göz"
+    assert Lemmatizer(tie_break="return-all")("gelir") == "gel"
+    assert (
+        repr(Lemmatizer(tie_break="prefer-shortest"))
+        == "Lemmatizer(strategy='hybrid', tie_break='prefer-shortest')"
+    )
+    with pytest.raises(ConfigurationError):
+        Lemmatizer(tie_break="prefer_shortest")
+
+
+def test_lemmatize_batch_tie_break():
+    from durak._durak_core import lemmatize_batch
+
+    assert lemmatize_batch(["gelir", None], tie_break="return-all") == [
+        ["gel", "gelir"],
+        None,
+    ]
+    assert lemmatize_batch(["gözlükçü"], tie_break="prefer-shortest") == ["göz"]
+    with pytest.raises(ValueError, match="tie_break"):
+        lemmatize_batch(["gelir"], tie_break="shortest")
+
+
+def test_lemma_cache():
+    try:
+        from durak import _durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+    from durak import clear_lemma_cache, lemma_cache_info, set_lemma_cache_size
+
+    lemmatizer = Lemmatizer()
+    clear_lemma_cache()
+    assert [lemmatizer(word) for word in ["kitaplar", "kitaplar", "evler"]] == [
+        "kitap",
+        "kitap",
+        "ev",
+    ]
+    info = lemma_cache_info()
+    assert (info["hits"], info["misses"], info["size"]) == (1, 2, 2)
+    # Other options are cached separately
+    assert Lemmatizer(strategy="lookup")("kitaplar") == "kitap"
+    assert lemma_cache_info()["misses"] == 3
+
+    try:
+        set_lemma_cache_size(0)
+        assert lemmatizer("kitaplar") == "kitap"
+        assert lemma_cache_info() == {"hits": 0, "misses": 0, "size": 0, "maxsize": 0}
+    finally:
+        set_lemma_cache_size(100_000)
+
+
+def test_evaluate_lemmatizer():
+    """Tiers are scored against gold lemmas, overall and per POS."""
+    from durak.lemmatizer import evaluate_lemmatizer
+
+    report = evaluate_lemmatizer(
+        [("Evler", "ev"), ("kitaplar", "kitap"), ("geldim", "gel", "VERB")],
+        sample=1,
+    )
+    assert report.total == 3
+    assert report.correct["lookup_lemma"] == 3
+    assert report.accuracy("lookup_lemma") == 1.0
+    assert report.correct["strip_suffixes"] < 3
+    assert report.per_pos["VERB"]["lookup_lemma"] == (1, 1)
+    assert report.per_pos["Noun"]["strip_suffixes_validated"][1] == 2
+    # evler: lookup and validation agree on "ev", plain stripping does not
+    assert report.confusion == [
+        {
+            "word": "Evler",
+            "gold": "ev",
+            "pos": "Noun",
+            "lookup_lemma": "ev",
+            "strip_suffixes": "evl",
+            "strip_suffixes_validated": "ev",
+        }
+    ]
+    assert report.to_dict()["tiers"]["lookup_lemma"]["accuracy"] == 1.0
+    assert "strip_suffixes_validated" in str(report)
+    with pytest.raises(ValueError, match="gold pairs"):
+        evaluate_lemmatizer([("kitaplar",)])
@@ -0,0 +1,98 @@
+"""Tests for morphological generation."""
+
+from __future__ import annotations
+
+import pytest
+
+from durak import analyze, analyze_all, check_agreement, generate
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+@pytest.mark.parametrize(
+    ("root", "tags", "expected"),
+    [
+        ("kitap", ["P1sg", "Loc"], "kitabımda"),
+        ("ağaç", ["Acc"], "ağacı"),
+        ("çocuk", ["P3sg"], "çocuğu"),
+        ("masa", ["P3sg", "Loc"], "masasında"),
+        ("ev", ["Pl", "P1pl", "Loc"], "evlerimizde"),
+        ("git", ["Fut", "A1sg"], "gideceğim"),
+        ("gel", ["Neg", "Prog1", "A1sg"], "gelmiyorum"),
+        ("ben", ["Dat"], "bana"),
+    ],
+)
+def test_generate(root: str, tags: list[str], expected: str) -> None:
+    assert generate(root, tags) == expected
+
+
+def test_generate_with_pos() -> None:
+    assert generate("yaz", ["Past", "A1sg"]) == "yazdım"
+    assert generate("yaz", ["Loc"], pos="Noun") == "yazda"
+    assert generate("bilgisayar", ["Pl", "Dat"]) == "bilgisayarlara"
+
+
+def test_generate_inverts_analyze() -> None:
+    for word in ["kitaplarımdan", "evindeki", "gelmedim"]:
+        root, pos, morphemes = analyze(word)[0]
+        assert generate(root, [tag for tag, _ in morphemes], pos=pos) == word
+
+
+@pytest.mark.parametrize(
+    ("tags", "pos", "message"),
+    [
+        (["Xyz"], None, "unknown suffix tag"),
+        (["Loc", "Pl"], None, "cannot appear at position 1"),
+        (["Loc"], "Adverb", "unknown part of speech"),
+    ],
+)
+def test_generate_errors(tags: list[str], pos: str | None, message: str) -> None:
+    with pytest.raises(ValueError, match=message):
+        generate("ev", tags, pos=pos)
+
+
+def test_analyze_all_ranks_parses() -> None:
+    parses = analyze_all("evin")
+    assert [morphemes for _, _, morphemes, _ in parses] == [
+        [("Gen", "in")],
+        [("P2sg", "in")],
+    ]
+    assert parses[0][3] > 0.9
+    assert sum(p for *_, p in parses) == pytest.approx(1.0)
+
+
+def test_analyze_all_matches_analyze() -> None:
+    parses = analyze_all("geldik")
+    assert [tag for tag, _ in parses[0][2]] == ["Past", "A1pl"]
+    assert sorted(p[:3] for p in parses) == sorted(analyze("geldik"))
+    assert analyze_all("xyzqw") == []
+
+
+def test_check_agreement_flags_possessor_and_person() -> None:
+    text = "Benim arabası bozuldu. Ben dün geldin."
+    assert check_agreement(text) == [
+        ("Benim arabası", "POSSESSOR", "P1sg", "P3sg", 0, 13),
+        ("Ben dün geldin", "PERSON", "A1sg", "A2sg", 23, 37),
+    ]
+
+
+@pytest.mark.parametrize(
+    "text",
+    [
+        "Benim arabam bozuldu, ben de yürüdüm.",
+        "Ali'nin evi güzel.",
+        "Onlar geldi ve biz gittik.",
+        "Bizim ev uzak.",
+    ],
+)
+def test_check_agreement_accepts_correct_text(text: str) -> None:
+    assert check_agreement(text) == []
@@ -0,0 +1,197 @@
+"""Tests for rule and gazetteer based named entity recognition."""
+
+from __future__ import annotations
+
+import pytest
+
+from durak import (
+    ENTITY_LABELS,
+    LINK_KINDS,
+    MASK_LABELS,
+    KeywordMatcher,
+    PhraseMatcher,
+    extract_dates,
+    extract_entities,
+    link_pronouns,
+    mask_entities,
+    unmask_entities,
+)
+from durak.exceptions import ConfigurationError
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+def test_person_date_location() -> None:
+    text = "Ahmet Yılmaz 12 Mart 2024'te İstanbul'a gitti."
+    assert extract_entities(text) == [
+        ("Ahmet Yılmaz", "PER", 0, 12),
+        ("12 Mart 2024", "DATE", 13, 25),
+        ("İstanbul", "LOC", 29, 37),
+    ]
+
+
+def test_offsets_point_into_original_text() -> None:
+    text = "Şükrü Öztürk Boğaziçi Üniversitesi'nde 1.500 TL burs aldı."
+    entities = extract_entities(text)
+    assert entities
+    for entity_text, _, start, end in entities:
+        assert text[start:end] == entity_text
+
+
+def test_organizations() -> None:
+    labels = {(t, lbl) for t, lbl, _, _ in extract_entities(
+        "TBMM ve Koç Holding açıklama yaptı. Ege Üniversitesi de katıldı."
+    )}
+    assert ("TBMM", "ORG") in labels
+    assert ("Koç Holding", "ORG") in labels
+    assert ("Ege Üniversitesi", "ORG") in labels
+
+
+def test_money_and_urls() -> None:
+    entities = extract_entities("Bilet ₺250, detaylar www.example.com adresinde.")
+    assert [(t, lbl) for t, lbl, _, _ in entities] == [
+        ("₺250", "MONEY"),
+        ("www.example.com", "URL"),
+    ]
+
+
+def test_lowercase_is_not_an_entity() -> None:
+    assert extract_entities("ahmet ankaraya gitti") == []
+
+
+def test_labels_are_known() -> None:
+    text = "Ayşe 2024-01-05 tarihinde https://durak.dev üzerinden 10 dolar ödedi."
+    assert {lbl for _, lbl, _, _ in extract_entities(text)} <= set(ENTITY_LABELS)
+
+
+def test_extract_dates() -> None:
+    text = "Toplantı 3 Mart 2024 saat 14:30'da, 31.02.2024 değil 29.02.2024."
+    assert extract_dates(text) == [
+        ("3 Mart 2024", "DATE", "2024-03-03", 9, 20),
+        ("saat 14:30", "TIME", "14:30", 21, 31),
+        ("29.02.2024", "DATE", "2024-02-29", 53, 63),
+    ]
+    assert extract_dates("Mart 2024, 5 Ocak, saat 9") == [
+        ("Mart 2024", "DATE", "2024-03", 0, 9),
+        ("5 Ocak", "DATE", "--01-05", 11, 17),
+        ("saat 9", "TIME", "09:00", 19, 25),
+    ]
+
+
+def test_link_pronouns():
+    text = "Ayşe Kaya dün geldi. Bugün eve gitti. Onu çok özledik."
+    links = link_pronouns(text)
+    assert links == [
+        ("gitti", "ZERO", 31, 36, "Ayşe Kaya", 0, 9),
+        ("Onu", "PRONOUN", 38, 41, "Ayşe Kaya", 0, 9),
+    ]
+    for anaphor, kind, start, end, antecedent, a_start, a_end in links:
+        assert kind in LINK_KINDS
+        assert text[start:end] == anaphor
+        assert text[a_start:a_end] == antecedent
+
+
+def test_link_pronouns_window():
+    text = "Ali geldi. Hava güzel. Yağmur yağdı. Onu gördüm."
+    assert link_pronouns(text, window=1) == []
+    assert link_pronouns(text, window=3)[0][4] == "Ali"
+    # Demonstrative "o" is not a pronoun
+    assert link_pronouns("Ali geldi. O kitabı okudum.") == []
+
+
+def test_keyword_matcher():
+    matcher = KeywordMatcher(
+        {"Türk Telekom": "BRAND", "Türk": "NATIONALITY", "Koç": "BRAND"}
+    )
+    text = "TÜRK TELEKOM'u bıraktım, Koçak değil Koç'un"
+    matches = matcher.find(text)
+    assert matches == [("TÜRK TELEKOM", "BRAND", 0, 12), ("Koç", "BRAND", 37, 40)]
+    for span, _, start, end in matches:
+        assert text[start:end] == span
+    assert ("TÜRK", "NATIONALITY", 0, 4) in matcher.find(text, overlapping=True)
+    assert matcher.find_batch([text, "türk kahvesi"], threads=2) == [
+        matches,
+        [("türk", "NATIONALITY", 0, 4)],
+    ]
+    assert len(matcher) == 3
+
+    exact = KeywordMatcher(["ev"], normalize=False, whole_words=False)
+    assert exact.find("Evet ev") == [("ev", "ev", 5, 7)]
+    with pytest.raises(ValueError, match="empty keyword at index 1"):
+        KeywordMatcher(["ev", ""])
+
+
+def test_keyword_matcher_from_file(tmp_path):
+    path = tmp_path / "brands.txt"
+    path.write_text("# watch list\nTurkcell\tBRAND\n#tatil\n", encoding="utf-8")
+    matcher = KeywordMatcher.from_file(path)
+    assert matcher.find("Turkcell ile #tatil") == [
+        ("Turkcell", "BRAND", 0, 8),
+        ("#tatil", "#tatil", 13, 19),
+    ]
+
+
+def test_phrase_matcher():
+    matcher = PhraseMatcher({"kitap oku": "READING", "kitap": "BOOK"})
+    text = "Dün kitabı okudum, yarın Kitapları okuyorlar."
+    matches = matcher.find(text)
+    assert matches == [
+        ("kitabı okudum", "READING", 4, 17),
+        ("Kitapları okuyorlar", "READING", 25, 44),
+    ]
+    for span, _, start, end in matches:
+        assert text[start:end] == span
+    assert ("kitabı", "BOOK", 4, 10) in matcher.find(text, overlapping=True)
+    assert matcher.find_batch([text, "Kitaplar"], threads=2) == [
+        matches,
+        [("Kitaplar", "BOOK", 0, 8)],
+    ]
+    assert len(matcher) == 2
+    # Duplicates by lemma keep their first label
+    assert len(PhraseMatcher({"kitap oku": "A", "kitabı okudum": "B"})) == 1
+    with pytest.raises(ValueError, match="empty pattern at index 1"):
+        PhraseMatcher(["kitap", " "])
+
+
+def test_phrase_matcher_from_file(tmp_path):
+    path = tmp_path / "patterns.txt"
+    path.write_text("# reading\nkitap oku\tREADING\n", encoding="utf-8")
+    matcher = PhraseMatcher.from_file(path)
+    assert matcher.find("Kitabı okudun mu?") == [("Kitabı okudun", "READING", 0, 13)]
+
+
+def test_mask_entities():
+    text = (
+        "Ayşe Yılmaz aradı: 0532 123 45 67, ayse@ornek.com.tr. "
+        "IBAN TR33 0006 1005 1978 6457 8413 26, Ankara şubesi."
+    )
+    masked, mapping = mask_entities(text)
+    assert masked == (
+        "[MASKED] aradı: [MASKED], [MASKED]. IBAN [MASKED], Ankara şubesi."
+    )
+    assert [(original, label) for original, label, _, _ in mapping] == [
+        ("Ayşe Yılmaz", "PER"),
+        ("0532 123 45 67", "PHONE"),
+        ("ayse@ornek.com.tr", "EMAIL"),
+        ("TR33 0006 1005 1978 6457 8413 26", "IBAN"),
+    ]
+    for _, _, start, end in mapping:
+        assert masked[start:end] == "[MASKED]"
+    assert unmask_entities(masked, mapping) == text
+
+    masked, mapping = mask_entities(text, ["PERSON", "LOC"], "<{label}>")
+    assert masked.startswith("<PER> aradı: 0532")
+    assert masked.endswith("<LOC> şubesi.")
+    assert unmask_entities(masked, mapping) == text
+    assert set(MASK_LABELS) >= {"PER", "EMAIL", "PHONE", "IBAN"}
+    with pytest.raises(ConfigurationError, match="Unknown label: 'NAME'"):
+        mask_entities(text, ["NAME"])
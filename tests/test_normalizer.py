@@ -133,3 +133,169 @@ def test_long_string(normalizer) -> None:
     with patch("durak.normalizer.fast_normalize") as mock_fast:
         normalizer(long_text)
         mock_fast.assert_called()
+
+
+# --- Confusion Pair Tests --- #
+def test_fix_confusions_disabled_by_default(normalizer) -> None:
+    """Confusion correction is opt-in."""
+    with patch("durak.normalizer.correct_confusions") as mock_correct:
+        with patch("durak.normalizer.fast_normalize", lambda x, *args: x):
+            normalizer("herşey")
+        mock_correct.assert_not_called()
+
+
+def test_fix_confusions_applied_after_normalization() -> None:
+    """With fix_confusions=True the normalized text is passed to the corrector."""
+    normalizer = Normalizer(fix_confusions=True)
+    with patch("durak.normalizer.fast_normalize", lambda x, *args: x.lower()):
+        with patch(
+            "durak.normalizer.correct_confusions", lambda x: x.replace("x", "y")
+        ):
+            assert normalizer("XX") == "yy"
+
+
+def test_fix_confusions_rust() -> None:
+    try:
+        from durak import _durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    normalizer = Normalizer(fix_confusions=True)
+    assert normalizer("Herşey YANLIZ") == "her şey yalnız"
+    assert normalizer("telefonu şarz ettim") == "telefonu şarj ettim"
+
+    # Casing is preserved when lowercasing is disabled
+    keep_case = Normalizer(lowercase=False, fix_confusions=True)
+    assert keep_case("Herşey") == "Her şey"
+
+
+def test_get_confusion_pairs() -> None:
+    try:
+        from durak import _durak_core
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    pairs = _durak_core.get_confusion_pairs()
+    assert ("herşey", "her şey") in pairs
+    assert pairs == sorted(pairs)
+
+
+def test_repr_with_fix_confusions() -> None:
+    normalizer = Normalizer(fix_confusions=True)
+    assert repr(normalizer) == (
+        "Normalizer(lowercase=True, handle_turkish_i=True, fix_confusions=True)"
+    )
+
+
+# --- Joined Word Splitting Tests --- #
+def test_split_joined_applied_per_word() -> None:
+    """With split_joined=True every word is passed through the splitter."""
+    normalizer = Normalizer(split_joined=True)
+    with patch("durak.normalizer.fast_normalize", lambda x, *args: x):
+        with patch(
+            "durak.normalizer.split_joined",
+            lambda w: ["a", "b"] if w == "ab" else [w],
+        ):
+            assert normalizer("ab, cd ab!") == "a b, cd a b!"
+
+
+def test_split_joined_rust() -> None:
+    try:
+        from durak import _durak_core
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    assert _durak_core.split_joined("çokgüzel") == ["çok", "güzel"]
+    assert _durak_core.split_joined("geliyorummusun") == ["geliyorum", "musun"]
+    assert _durak_core.split_joined("kitapta") == ["kitapta"]
+
+    normalizer = Normalizer(split_joined=True)
+    assert normalizer("Bu film ÇOKGÜZEL!") == "bu film çok güzel!"
+
+
+def test_repr_with_split_joined() -> None:
+    normalizer = Normalizer(split_joined=True)
+    assert repr(normalizer) == (
+        "Normalizer(lowercase=True, handle_turkish_i=True, split_joined=True)"
+    )
+
+
+# --- Unicode Normalization Tests --- #
+def test_unicode_form_passed_to_rust() -> None:
+    calls = []
+
+    def fake_normalize(text, lowercase, handle_turkish_i, unicode_form=None):
+        calls.append(unicode_form)
+        return text
+
+    with patch("durak.normalizer.fast_normalize", fake_normalize):
+        Normalizer(unicode_form="nfkc")("x")
+        Normalizer()("x")
+    assert calls == ["NFKC", None]
+
+
+def test_invalid_unicode_form() -> None:
+    with pytest.raises(NormalizerError, match="unicode_form"):
+        Normalizer(unicode_form="NFX")
+
+
+def test_repr_with_unicode_form() -> None:
+    normalizer = Normalizer(unicode_form="NFC")
+    assert repr(normalizer) == (
+        "Normalizer(lowercase=True, handle_turkish_i=True, unicode_form='NFC')"
+    )
+
+
+def test_unicode_form_rust() -> None:
+    try:
+        from durak import _durak_core
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    # "g" + combining breve and "I" + combining dot above, as copied from PDFs
+    decomposed = "DAG\u0306 I\u0307STANBUL"
+    assert Normalizer(unicode_form="NFC")(decomposed) == "dağ istanbul"
+    assert _durak_core.fast_normalize(decomposed, unicode_form="NFC") == (
+        "dağ istanbul"
+    )
+    assert _durak_core.unicode_normalize("Dag\u0306") == "Dağ"
+    assert _durak_core.unicode_normalize("ﬁlm", "NFKC") == "film"
+    assert _durak_core.unicode_normalize("ğ", "NFD") == "g\u0306"
+    with pytest.raises(ValueError, match="unknown normalization form"):
+        _durak_core.unicode_normalize("x", "NFX")
+
+
+# --- Case Conversion Tests --- #
+def test_turkish_case_conversion_rust() -> None:
+    try:
+        from durak import _durak_core
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    assert _durak_core.fast_uppercase("istanbul ışık") == "İSTANBUL IŞIK"
+    assert _durak_core.turkish_casefold("İSTANBUL", "lower") == "istanbul"
+    assert _durak_core.turkish_casefold("kırmızı", "upper") == "KIRMIZI"
+    assert _durak_core.turkish_casefold("IŞIK") == _durak_core.turkish_casefold("ışık")
+    assert _durak_core.turkish_casefold("ISIK") != _durak_core.turkish_casefold("isik")
+    with pytest.raises(ValueError, match="unknown case mode"):
+        _durak_core.turkish_casefold("x", "title")
+
+
+def test_normalize_with_alignment_rust() -> None:
+    try:
+        from durak import normalize_with_alignment
+        from durak import _durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    raw = "Dağ İSTANBUL'da"
+    normalized, alignment = normalize_with_alignment(raw, unicode_form="NFC")
+    assert normalized == "dağ istanbul'da"
+    assert len(alignment) == len(normalized) + 1
+    start = normalized.index("istanbul")
+    end = start + len("istanbul")
+    assert raw[alignment[start] : alignment[end]] == "İSTANBUL"
+    assert raw[alignment[0] : alignment[3]] == "Dağ"
+    assert normalize_with_alignment("İSTANBUL") == ("istanbul", list(range(9)))
+    with pytest.raises(ValueError, match="unknown normalization form"):
+        normalize_with_alignment("x", unicode_form="NFX")
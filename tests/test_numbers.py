@@ -0,0 +1,60 @@
+"""Tests for Turkish numbers in words."""
+
+from __future__ import annotations
+
+import pytest
+
+from durak import extract_numbers, number_to_words, words_to_number
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+@pytest.mark.parametrize(
+    ("n", "words"),
+    [
+        (0, "sıfır"),
+        (7, "yedi"),
+        (100, "yüz"),
+        (342, "üç yüz kırk iki"),
+        (1250, "bin iki yüz elli"),
+        (2024, "iki bin yirmi dört"),
+        (1_000_000, "bir milyon"),
+    ],
+)
+def test_number_to_words(n: int, words: str) -> None:
+    assert number_to_words(n) == words
+    assert words_to_number(words) == n
+
+
+def test_words_to_number_variants() -> None:
+    assert words_to_number("Bin Dokuz Yüz Seksen Dört") == 1984
+    assert words_to_number("binikiyüzelli") == 1250
+    assert words_to_number("İKİ BİN") == 2000
+
+
+@pytest.mark.parametrize("text", ["", "bir yüz", "iki iki", "bin milyon", "ev"])
+def test_words_to_number_rejects(text: str) -> None:
+    with pytest.raises(ValueError, match="not a Turkish number"):
+        words_to_number(text)
+
+
+def test_number_to_words_rejects_negative() -> None:
+    with pytest.raises(OverflowError):
+        number_to_words(-1)
+
+
+def test_extract_numbers() -> None:
+    assert extract_numbers("Üç yüz kırk iki kişi geldi") == [
+        ("Üç yüz kırk iki", 342, 0, 15)
+    ]
+    assert [value for _, value, _, _ in extract_numbers("iki üç elma")] == [2, 3]
+    assert extract_numbers("Bir gün masanın altı boştu") == []
@@ -179,3 +179,235 @@ class TestProcessTextDeprecated:
             warnings.simplefilter("ignore", DeprecationWarning)
             with pytest.raises(PipelineError, match="must be a string"):
                 process_text(12345)  # type: ignore[arg-type]
+
+
+class TestProcess:
+    """Tests for the single-call Rust pipeline."""
+
+    def test_process_annotates_tokens(self):
+        from durak.pipeline import process
+
+        doc = process("Ve İstanbul'a kitapları okudum.")
+        assert doc.text == "Ve İstanbul'a kitapları okudum."
+        assert [(t.norm, t.is_stop, t.lemma) for t in doc] == [
+            ("ve", True, "ve"),
+            ("istanbul'a", False, "istanbul"),
+            ("kitapları", False, "kitap"),
+            ("okudum", False, "oku"),
+            (".", False, "."),
+        ]
+        assert (doc[1].start, doc[1].end, doc[1].kind) == (3, 13, "WORD")
+
+    def test_process_stage_flags(self):
+        from durak.pipeline import process
+
+        doc = process("Ve kitapları", normalize=False, lemmatize=False)
+        assert [(t.norm, t.is_stop, t.lemma) for t in doc] == [
+            ("Ve", False, "Ve"),
+            ("kitapları", False, "kitapları"),
+        ]
+        assert not process("ve", stopwords=False)[0].is_stop
+
+
+class TestConllu:
+    """Tests for CoNLL-U reading and re-annotation."""
+
+    TREEBANK = (
+        "# sent_id = 1\n"
+        "# text = Kitapları okudum.\n"
+        "1\tKitapları\tkitaplar\tNOUN\t_\t_\t2\tobj\t_\t_\n"
+        "2\tokudum\toku\tVERB\t_\t_\t0\troot\t_\tSpaceAfter=No\n"
+        "3\t.\t.\tPUNCT\t_\t_\t2\tpunct\t_\t_\n"
+        "\n"
+    )
+
+    def test_read_conllu(self, tmp_path):
+        from durak.pipeline import read_conllu, to_conllu
+
+        path = tmp_path / "tb.conllu"
+        path.write_text(self.TREEBANK, encoding="utf-8")
+        (doc,) = read_conllu(path)
+        assert doc.text == "Kitapları okudum."
+        assert [(t.text, t.lemma, t.start) for t in doc] == [
+            ("Kitapları", "kitaplar", 0),
+            ("okudum", "oku", 10),
+            (".", ".", 16),
+        ]
+        assert doc[2].kind == "PUNCT"
+        assert to_conllu(doc).splitlines()[1].split("\t")[3] == "NOUN"
+
+        path.write_text("1\tev\n", encoding="utf-8")
+        with pytest.raises(ValueError, match="line 1: expected 10 columns"):
+            read_conllu(path)
+
+    def test_reannotate_conllu(self):
+        from durak.pipeline import reannotate_conllu
+
+        lines = reannotate_conllu(self.TREEBANK, columns=["lemma"]).splitlines()
+        assert lines[:2] == self.TREEBANK.splitlines()[:2]
+        assert lines[2].split("\t") == [
+            "1", "Kitapları", "kitap", "NOUN", "_", "_", "2", "obj", "_", "_"
+        ]
+        retagged = reannotate_conllu(self.TREEBANK).splitlines()[3].split("\t")
+        assert retagged[2:5] == ["oku", "VERB", "Verb"]
+        assert "Tense=Past" in retagged[5]
+        assert retagged[6:] == ["0", "root", "_", "SpaceAfter=No"]
+        with pytest.raises(ValueError, match="unknown column 'head'"):
+            reannotate_conllu(self.TREEBANK, columns=["head"])
+
+class TestPipelineConfig:
+    """Tests for pipelines built from config files."""
+
+    def test_from_toml(self, tmp_path):
+        (tmp_path / "project.txt").write_text("kitap\n", encoding="utf-8")
+        config = tmp_path / "pipeline.toml"
+        config.write_text(
+            'steps = ["normalize", "tokenize", "remove_stopwords", "lemmatize"]\n'
+            "[stopwords]\n"
+            'domains = ["social_media"]\n'
+            'files = ["project.txt"]\n'
+            'keep = ["ve"]\n'
+            "[lemmatizer]\n"
+            'strategy = "lookup"\n',
+            encoding="utf-8",
+        )
+        pipeline = Pipeline.from_config(config)
+        assert pipeline.step_names == [
+            "normalize",
+            "tokenize",
+            "remove_stopwords",
+            "lemmatize",
+        ]
+        assert pipeline("Bu kitap ve kalemler rt") == ["ve", "kalemler"]
+
+    def test_from_yaml(self, tmp_path):
+        pytest.importorskip("yaml")
+        config = tmp_path / "pipeline.yaml"
+        config.write_text(
+            "steps: [tokenize, lemmatize]\nlemmatizer:\n  min_root_length: 3\n",
+            encoding="utf-8",
+        )
+        assert Pipeline.from_config(config)("kitaplar") == ["kitap"]
+
+    def test_rewrite_step(self, tmp_path):
+        (tmp_path / "house.rules").write_text(
+            "<NUMBER> tl => $1 lira\n", encoding="utf-8"
+        )
+        config = tmp_path / "pipeline.toml"
+        config.write_text(
+            'steps = ["normalize", "tokenize", "rewrite"]\n'
+            "[rewrite]\n"
+            'files = ["house.rules"]\n',
+            encoding="utf-8",
+        )
+        assert Pipeline.from_config(config)("50 TL ödedim") == ["50", "lira", "ödedim"]
+
+        (tmp_path / "house.rules").write_text("<NUM> => x\n", encoding="utf-8")
+        with pytest.raises(ConfigurationError, match="line 1: unknown token type"):
+            Pipeline.from_config(config)
+
+    def test_invalid_configs(self, tmp_path):
+        from durak.config import PipelineConfig
+
+        for data, message in [
+            ({"step": ["tokenize"]}, "unknown pipeline config keys: step"),
+            ({"steps": ["lemmatize", "tokenize"]}, "must come after 'tokenize'"),
+            ({"steps": ["tokenize", "stem"]}, "Unknown pipeline step: 'stem'"),
+            ({"lemmatizer": {"min_root_length": True}}, "must be int"),
+            ({"stopwords": {"domain": ["x"]}}, "keys in \\[stopwords\\]: domain"),
+            ({"steps": ["tokenize", "rewrite"]}, "needs \\[rewrite\\] files"),
+        ]:
+            with pytest.raises(ConfigurationError, match=message):
+                PipelineConfig.from_dict(data)
+        config = tmp_path / "pipeline.json"
+        config.write_text("{}", encoding="utf-8")
+        with pytest.raises(ConfigurationError, match="expected .toml"):
+            Pipeline.from_config(config)
+
+    def test_process_wide_defaults(self):
+        try:
+            from durak._durak_core import strip_suffixes_validated
+        except ImportError:
+            pytest.skip("Rust extension not installed")
+        from durak import Lemmatizer, remove_stopwords, tokenize_with_offsets
+        from durak.defaults import configure, get_config, set_config
+
+        heuristic = Lemmatizer(strategy="heuristic", validate_roots=True)
+        assert heuristic("evler") == "ev"
+        with configure(
+            min_root_length=3, offsets="byte", stopword_domains=["social_media"]
+        ) as config:
+            assert config["offsets"] == "byte"
+            assert heuristic("evler") == "evl"
+            explicit = Lemmatizer(
+                strategy="heuristic", validate_roots=True, min_root_length=2
+            )
+            assert explicit("evler") == "ev"
+            assert tokenize_with_offsets("ğ a") == [("ğ", 0, 2), ("a", 3, 4)]
+            assert tokenize_with_offsets("ğ a", offsets="char")[1] == ("a", 2, 3)
+            assert remove_stopwords(["rt", "harika"]) == ["harika"]
+        assert get_config()["min_root_length"] == 2
+        assert tokenize_with_offsets("ğ a")[1] == ("a", 2, 3)
+        assert remove_stopwords(["rt", "harika"]) == ["rt", "harika"]
+
+        with configure(check_harmony=False):
+            assert strip_suffixes_validated("elmalar") == "elm"
+        assert strip_suffixes_validated("elmalar") == "elma"
+        for options, message in [
+            ({"min_root_length": 0}, "at least 1"),
+            ({"offsets": "utf16"}, "unknown offset mode 'utf16'"),
+            ({"stopword_domains": ["sports"]}, "Unknown stopword domain"),
+        ]:
+            with pytest.raises(ConfigurationError, match=message):
+                set_config(**options)
+        assert get_config()["stopword_domains"] == []
+
+    def test_profiles(self):
+        from durak.config import PipelineConfig, list_profiles
+
+        assert set(list_profiles()) == {"news", "social", "legal", "medical"}
+        pipeline = Pipeline.from_profile("social")
+        assert pipeline.step_names == ["tokenize", "remove_stopwords"]
+        assert pipeline("#GünaydınTürkiye arkadaşlar! RT") == [
+            "günaydın",
+            "türkiye",
+            "arkadaşlar",
+        ]
+        legal = PipelineConfig.from_profile("legal")
+        assert legal.clitics == "detach" and legal.tokenizer_config.numbers
+        assert "değil" in legal.stopword_keep
+        with pytest.raises(ConfigurationError, match="Available profiles: news"):
+            Pipeline.from_profile("sports")
+
+    def test_tokenizer_section(self):
+        from durak.config import PipelineConfig
+
+        config = PipelineConfig.from_dict(
+            {"tokenizer": {"strip_punct": True, "dates": False}}
+        )
+        assert config.step("tokenize")("3 Mart 2024, Ankara") == [
+            "3",
+            "Mart",
+            "2024",
+            "Ankara",
+        ]
+        for data, message in [
+            ({"tokenizer": {"clitics": "split"}}, "'attach' or 'detach'"),
+            ({"tokenizer": {"numbers": 1}}, "'tokenizer.numbers' must be bool"),
+        ]:
+            with pytest.raises(ConfigurationError, match=message):
+                PipelineConfig.from_dict(data)
+        with pytest.raises(ConfigurationError, match="tokenizer options"):
+            config.to_worker_config()
+
+    def test_to_worker_config(self):
+        from durak.config import PipelineConfig
+
+        config = PipelineConfig(
+            steps=("tokenize", "remove_stopwords"),
+            stopword_domains=("social_media",),
+        ).to_worker_config()
+        assert not config.normalize and config.remove_stopwords
+        assert "rt" in config.stopword_additions
+        with pytest.raises(ConfigurationError, match="not supported"):
+            PipelineConfig(steps=("clean", "tokenize")).to_worker_config()
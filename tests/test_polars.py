@@ -0,0 +1,83 @@
+"""Tests for the Polars ``durak`` namespace and the batch functions behind it."""
+
+from __future__ import annotations
+
+import pytest
+
+from durak.exceptions import ConfigurationError
+
+try:
+    from durak import _durak_core
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+def test_lemmatize_batch_matches_lemmatizer():
+    from durak import Lemmatizer
+
+    words = ["kitaplar", "geliyorum", "masalar", "evlerde"]
+    for strategy in ("lookup", "heuristic", "hybrid"):
+        lemmatizer = Lemmatizer(strategy=strategy, validate_roots=True)
+        expected = [lemmatizer(word) for word in words]
+        result = _durak_core.lemmatize_batch(
+            words, strategy=strategy, validate_roots=True
+        )
+        assert result == expected
+
+
+def test_batch_functions_preserve_nulls():
+    assert _durak_core.lemmatize_batch(["kitaplar", None]) == ["kitap", None]
+    assert _durak_core.normalize_batch(["İSTANBUL", None]) == ["istanbul", None]
+    assert _durak_core.tokenize_batch(["Merhaba dünya!", None]) == [
+        ["Merhaba", "dünya", "!"],
+        None,
+    ]
+
+
+def test_lemmatize_batch_rejects_unknown_strategy():
+    with pytest.raises(ValueError, match="unknown strategy"):
+        _durak_core.lemmatize_batch(["kitaplar"], strategy="fuzzy")
+
+
+def test_expression_namespace():
+    pl = pytest.importorskip("polars")
+    import durak.polars  # noqa: F401
+
+    df = pl.DataFrame({"word": ["Kitaplar", "geliyorum", None]})
+    result = df.select(
+        pl.col("word").durak.normalize().durak.lemmatize().alias("lemma")
+    )
+    assert result["lemma"].to_list() == ["kitap", "gel", None]
+
+
+def test_tokenize_expression():
+    pl = pytest.importorskip("polars")
+    import durak.polars  # noqa: F401
+
+    df = pl.DataFrame({"text": ["Merhaba dünya!", "İstanbul'a gittim"]})
+    tokens = df.select(pl.col("text").durak.tokenize()).explode("text")
+    assert tokens["text"].to_list() == [
+        "Merhaba",
+        "dünya",
+        "!",
+        "İstanbul'a",
+        "gittim",
+    ]
+
+
+def test_series_namespace():
+    pl = pytest.importorskip("polars")
+    import durak.polars  # noqa: F401
+
+    series = pl.Series("word", ["kitaplar", "evler"])
+    assert series.durak.lemmatize().to_list() == ["kitap", "ev"]
+    assert series.durak.lemmatize().name == "word"
+
+    with pytest.raises(ConfigurationError):
+        series.durak.lemmatize(strategy="fuzzy")
@@ -0,0 +1,63 @@
+"""Tests for part-of-speech tagging."""
+
+from __future__ import annotations
+
+import pytest
+
+from durak import POS_TAGS, pos_tag, tokenize
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+def test_pos_tag_sentence() -> None:
+    tokens = ["Ben", "İstanbul'a", "yarın", "gideceğim", "."]
+    assert pos_tag(tokens) == [
+        ("Ben", "Pron"),
+        ("İstanbul'a", "Noun"),
+        ("yarın", "Adv"),
+        ("gideceğim", "Verb"),
+        (".", "Punc"),
+    ]
+
+
+def test_pos_tag_numbers_and_adjectives() -> None:
+    tags = dict(pos_tag(["3", "1.500", "iki", "güzel", "evli"]))
+    assert tags == {
+        "3": "Num",
+        "1.500": "Num",
+        "iki": "Num",
+        "güzel": "Adj",
+        "evli": "Adj",
+    }
+
+
+def test_pos_tag_converbs_are_adverbs() -> None:
+    assert pos_tag(["gelince", "gelip"]) == [("gelince", "Adv"), ("gelip", "Adv")]
+
+
+def test_pos_tag_unknown_words_use_suffix_fallback() -> None:
+    assert pos_tag(["koşturuyor", "kokulu", "bilgisayar"]) == [
+        ("koşturuyor", "Verb"),
+        ("kokulu", "Adj"),
+        ("bilgisayar", "Noun"),
+    ]
+
+
+def test_pos_tag_tags_are_known() -> None:
+    tokens = tokenize("Kitaplarımı dün hızlıca okudum, çok güzeldi!")
+    tagged = pos_tag(tokens)
+    assert [token for token, _ in tagged] == tokens
+    assert all(tag in POS_TAGS for _, tag in tagged)
+
+
+def test_pos_tag_empty() -> None:
+    assert pos_tag([]) == []
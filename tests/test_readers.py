@@ -0,0 +1,65 @@
+"""Tests for the streaming ndjson reader."""
+
+from __future__ import annotations
+
+import io
+
+import pytest
+
+from durak import NdjsonReader
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+DUMP = (
+    '{"id": 1, "user": {"bio": "Selam"}, "text": "Merhaba dünya"}\n'
+    "\n"
+    '{"id": 2, "deleted": true}\n'
+    "düz metin satırı\n"
+    '{"id": 3, "text": "alıntı: \\"evet\\""}\r\n'
+)
+
+
+def test_reader_selects_text_field(tmp_path):
+    path = tmp_path / "dump.jsonl"
+    path.write_text(DUMP, encoding="utf-8")
+
+    reader = NdjsonReader(path)
+    assert list(reader) == [
+        (1, "Merhaba dünya"),
+        (4, "düz metin satırı"),
+        (5, 'alıntı: "evet"'),
+    ]
+    assert reader.skipped == 1
+
+
+def test_reader_nested_field_and_raw_lines():
+    reader = NdjsonReader(io.StringIO(DUMP), "user.bio", raw=True)
+    records = list(reader)
+    assert records[0] == (1, "Selam", DUMP.splitlines()[0])
+    assert [line_no for line_no, _, _ in records] == [1, 4]
+    assert reader.skipped == 2
+
+
+def test_reader_small_blocks_match():
+    binary = io.BytesIO(DUMP.encode("utf-8"))
+    assert list(NdjsonReader(binary, block_size=5)) == list(
+        NdjsonReader(io.StringIO(DUMP))
+    )
+
+
+def test_reader_errors():
+    with pytest.raises(ValueError, match="line 2: invalid JSON"):
+        list(NdjsonReader(io.StringIO('{"text": "a"}\n{"text": ')))
+    with pytest.raises(ValueError, match="invalid field path"):
+        NdjsonReader(io.StringIO(""), "user..bio")
+    with pytest.raises(ValueError, match="block_size"):
+        NdjsonReader(io.StringIO(""), block_size=0)
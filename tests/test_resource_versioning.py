@@ -61,6 +61,14 @@ def test_build_info_rust_version_is_valid():
     assert re.match(r"^\d+\.\d+", rust_version)
 
 
+def test_build_info_reports_lemma_dict_pruning():
+    """Lemma dictionary pruning (DURAK_LEMMA_TOP_N) is recorded in build info."""
+    info = get_build_info()
+
+    assert info["lemma_dict_top_n"] == "all" or info["lemma_dict_top_n"].isdigit()
+    assert int(info["lemma_dict_forms"]) > 0
+
+
 def test_get_resource_info_returns_dict():
     """Resource info should return a dictionary of resources."""
     info = get_resource_info()
@@ -282,6 +290,16 @@ def test_stopwords_item_count_matches_actual_file():
     assert stopwords_info["item_count"] == actual_count, "Item count mismatch!"
 
 
+def test_warm_up_initializes_components():
+    """warm_up should report every lazily built component, spell on request."""
+    timings = durak.warm_up()
+
+    assert {"lemma_dict", "tokenizer", "stopwords", "ner"} <= set(timings)
+    assert "spell" not in timings
+    assert all(seconds >= 0 for seconds in timings.values())
+    assert "spell" in durak.warm_up(include_spell=True)
+
+
 def test_api_is_exposed_in_main_module():
     """Resource versioning API should be accessible from main module."""
     assert hasattr(durak, "get_build_info")
@@ -0,0 +1,90 @@
+import pytest
+from durak import (
+    ResourceRegistry,
+    attach_detached_suffixes,
+    get_registry,
+    remove_stopwords,
+)
+from durak.exceptions import ConfigurationError, ResourceError
+from durak.stopwords import BASE_STOPWORDS
+
+try:
+    from durak import _durak_core
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+
+def _write(path, text):
+    path.parent.mkdir(parents=True, exist_ok=True)
+    path.write_text(text, encoding="utf-8")
+
+
+def test_registry_precedence(tmp_path) -> None:
+    first, second = tmp_path / "first", tmp_path / "second"
+    _write(first / "stopwords/base/turkish.txt", "# proje\nKitap\n")
+    _write(second / "stopwords/base/turkish.txt", "kalem\n")
+    _write(second / "labels/DETACHED_SUFFIXES.txt", "cik\n")
+    registry = ResourceRegistry([first, second])
+
+    assert registry.overrides() == {
+        "stopwords": first / "stopwords/base/turkish.txt",
+        "detached_suffixes": second / "labels/DETACHED_SUFFIXES.txt",
+    }
+    assert registry.stopwords() == frozenset({"kitap"})
+    assert registry.detached_suffixes() == ("cik",)
+    assert registry.lemmas()["kitaplar"] == "kitap"
+    assert ResourceRegistry().stopwords() is BASE_STOPWORDS
+    with pytest.raises(ConfigurationError, match="Unknown resource: 'gazetteers'"):
+        registry.path("gazetteers")
+
+    _write(first / "lemmas/turkish_lemma_dict.txt", "tek sütun\n")
+    registry.reload()
+    with pytest.raises(ResourceError, match="turkish_lemma_dict.txt:1"):
+        registry.lemmas()
+
+
+@pytest.mark.skipif(not RUST_AVAILABLE, reason="Rust extension not installed")
+def test_registry_activation_and_reload(tmp_path) -> None:
+    from durak import Lemmatizer, process
+
+    _write(tmp_path / "stopwords/base/turkish.txt", "kitap\n")
+    _write(tmp_path / "lemmas/turkish_lemma_dict.txt", "gözlükçüler\tgözlükçü\n")
+    registry = ResourceRegistry([tmp_path])
+    lemmatizer = Lemmatizer(strategy="lookup")
+    assert get_registry().overrides() == {}
+
+    with registry:
+        assert get_registry() is registry
+        assert remove_stopwords(["ve", "kitap"]) == ["ve"]
+        assert [t.is_stop for t in process("ve kitap")] == [False, True]
+        assert lemmatizer("gözlükçüler") == "gözlükçü"
+        assert lemmatizer("kitaplar") == "kitaplar"
+        assert dict(_durak_core.resource_overrides()) == {
+            "lemmas": tmp_path / "lemmas/turkish_lemma_dict.txt",
+            "stopwords": tmp_path / "stopwords/base/turkish.txt",
+        }
+
+        # Hot swap: edit the files, then reload
+        _write(tmp_path / "stopwords/base/turkish.txt", "ve\n")
+        registry.reload()
+        assert remove_stopwords(["ve", "kitap"]) == ["kitap"]
+        assert attach_detached_suffixes(["ankara", "da"]) == ["ankarada"]
+
+    assert get_registry().overrides() == {}
+    assert remove_stopwords(["ve", "kitap"]) == ["kitap"]
+    assert lemmatizer("kitaplar") == "kitap"
+    assert _durak_core.resource_overrides() == []
+
+
+@pytest.mark.skipif(not RUST_AVAILABLE, reason="Rust extension not installed")
+def test_stopword_override_reaches_segmentation_and_spelling(tmp_path) -> None:
+    _write(tmp_path / "stopwords/base/turkish.txt", "zırt\n")
+
+    with ResourceRegistry([tmp_path]):
+        assert _durak_core.split_joined("zırtkitap") == ["zırt", "kitap"]
+        assert _durak_core.is_correct("zırt")
+
+    assert _durak_core.split_joined("zırtkitap") == ["zırtkitap"]
+    assert not _durak_core.is_correct("zırt")
@@ -0,0 +1,66 @@
+"""Tests for seeded reservoir sampling."""
+
+from __future__ import annotations
+
+import io
+
+import pytest
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+from durak import sample_lines, sample_sentences, split_sentences
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+CORPUS = "".join(f"satır {i}\n" for i in range(1, 101))
+
+
+def test_sample_lines_reproducible(tmp_path):
+    path = tmp_path / "corpus.txt"
+    path.write_text(CORPUS, encoding="utf-8")
+
+    sample = sample_lines(str(path), 10, seed=42)
+    assert len(sample) == 10
+    assert sample == sample_lines(path, 10, seed=42)
+    assert sample != sample_lines(path, 10, seed=43)
+    # File objects give the same sample as paths
+    assert sample == sample_lines(io.StringIO(CORPUS), 10, seed=42)
+    assert sample == sample_lines(io.BytesIO(CORPUS.encode("utf-8")), 10, seed=42)
+
+
+def test_sample_lines_order_and_line_numbers():
+    numbered = sample_lines(io.StringIO(CORPUS), 10, seed=3, line_numbers=True)
+    numbers = [line_no for line_no, _ in numbered]
+    assert numbers == sorted(numbers)
+    assert all(line == f"satır {line_no}" for line_no, line in numbered)
+
+
+def test_sample_lines_skips_blank_lines():
+    text = "bir\r\n\n   \niki\n"
+    assert sample_lines(io.StringIO(text), 10) == ["bir", "iki"]
+    assert sample_lines(io.StringIO(text), 10, line_numbers=True) == [
+        (1, "bir"),
+        (4, "iki"),
+    ]
+
+
+def test_sample_lines_invalid_utf8():
+    with pytest.raises(ValueError):
+        sample_lines(io.BytesIO(b"ok\n\xff\n"), 1)
+
+
+def test_sample_sentences_matches_split_sentences():
+    text = "Dr. Ahmet geldi. Nasılsın? Çok iyiyim… Yarın görüşürüz!"
+    assert sample_sentences(text, 10) == split_sentences(text)
+
+    sample = sample_sentences(text, 2, seed=5)
+    assert sample == sample_sentences(text, 2, seed=5)
+    assert len(sample) == 2
+    assert all(sentence in split_sentences(text) for sentence in sample)
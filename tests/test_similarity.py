@@ -0,0 +1,57 @@
+"""Tests for Turkish-aware fuzzy string matching."""
+
+from __future__ import annotations
+
+import pytest
+
+from durak import closest_matches, similarity
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+@pytest.mark.parametrize("metric", ["levenshtein", "jaro", "jaro_winkler"])
+def test_diacritics_are_cheap(metric) -> None:
+    near = similarity("Eskisehir", "Eskişehir", metric)
+    far = similarity("Eskisehir", "Eskimeler", metric)
+    assert far < 0.9 < near < 1.0
+    assert similarity("cigkofte", "çiğköfte", metric) > far
+    assert similarity("ev", "ev", metric) == 1.0
+    assert similarity("ev", "", metric) == 0.0
+
+
+def test_levenshtein() -> None:
+    assert similarity("Eskisehir", "Eskişehir", "levenshtein") == pytest.approx(
+        1 - 0.2 / 9
+    )
+    assert similarity("ISTANBUL", "İstanbul", "levenshtein") == pytest.approx(
+        1 - 0.2 / 8
+    )
+    assert similarity("ISTANBUL", "İstanbul", "levenshtein", ignore_case=False) == 0.0
+    assert similarity("martha", "marhta", "jaro_winkler") == pytest.approx(
+        0.9611, abs=1e-4
+    )
+
+
+def test_closest_matches() -> None:
+    provinces = ["Eskişehir", "Kırşehir", "Kırıkkale", "Kırklareli"]
+    matches = closest_matches("kirsehir", provinces, k=2)
+    assert [name for name, _ in matches] == ["Kırşehir", "Eskişehir"]
+    assert matches[0][1] > matches[1][1]
+    assert closest_matches("istanbul", ["İzmir", "İstanbul"], min_score=0.9) == [
+        ("İstanbul", 1.0)
+    ]
+    assert closest_matches("ev", [], k=3) == []
+
+
+def test_unknown_metric() -> None:
+    with pytest.raises(ValueError, match="unknown metric"):
+        similarity("a", "b", "hamming")
@@ -0,0 +1,42 @@
+"""Tests for the spaCy tokenizer integration."""
+
+from __future__ import annotations
+
+import pytest
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+TEXT = "  İstanbul'da  3 gün kaldık 😂\nHarika!"
+
+
+def test_tokenize_for_spacy_rebuilds_text():
+    from durak import tokenize, tokenize_for_spacy
+
+    words, spaces, spans = tokenize_for_spacy(TEXT)
+    rebuilt = "".join(w + (" " if space else "") for w, space in zip(words, spaces))
+    assert rebuilt == TEXT
+    assert [TEXT[start:end] for start, end in spans] == words
+    assert [w for w in words if w.strip()] == tokenize(TEXT)
+    assert words[:3] == ["  ", "İstanbul'da", " "]
+    assert tokenize_for_spacy("") == ([], [], [])
+
+
+def test_durak_tokenizer_in_spacy_pipeline():
+    spacy = pytest.importorskip("spacy")
+    from durak.spacy import DurakTokenizer
+
+    nlp = spacy.blank("tr")
+    nlp.tokenizer = DurakTokenizer(nlp.vocab)
+    doc = nlp(TEXT)
+    assert doc.text == TEXT
+    token = next(t for t in doc if t.text == "kaldık")
+    assert TEXT[token.idx:token.idx + len(token)] == "kaldık"
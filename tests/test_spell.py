@@ -0,0 +1,81 @@
+"""Tests for spell checking."""
+
+from __future__ import annotations
+
+import pytest
+
+from durak import (
+    SUGGESTION_SOURCES,
+    Suggestion,
+    is_correct,
+    ranked_suggestions,
+    suggest,
+)
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+@pytest.mark.parametrize(
+    "word", ["kitaplarımdan", "Geliyorum", "gelmiyorum", "ve", "Ankara'da", "2024"]
+)
+def test_is_correct_accepts_valid_words(word: str) -> None:
+    assert is_correct(word)
+
+
+@pytest.mark.parametrize("word", ["geliyorm", "kitaplarimdan", "cocugu"])
+def test_is_correct_rejects_misspellings(word: str) -> None:
+    assert not is_correct(word)
+
+
+def test_suggest_restores_diacritics() -> None:
+    assert suggest("kitaplarimdan")[0] == "kitaplarımdan"
+    assert suggest("cocugu")[0] == "çocuğu"
+    assert suggest("guzel")[0] == "güzel"
+
+
+def test_suggest_fixes_typos() -> None:
+    assert "gelmiyorum" in suggest("gelmiyrum")
+    assert "evlerimizde" in suggest("evlerimzde")
+
+
+def test_suggest_prefers_neighbouring_keys() -> None:
+    # k and l are neighbours, k and n or r are not
+    assert suggest("okuk", max_edits=1)[0] == "okul"
+
+
+def test_suggest_correct_word_returns_itself() -> None:
+    assert suggest("Kitabı") == ["kitabı"]
+
+
+def test_suggest_limits() -> None:
+    assert len(suggest("geliyorm", k=2)) == 2
+    assert suggest("geliyorm", k=0) == []
+    assert suggest("xyzqwvbn", max_edits=1) == []
+
+
+def test_ranked_suggestions_sources() -> None:
+    best = ranked_suggestions("cocugu", k=3)[0]
+    assert (best.text, best.source) == ("çocuğu", "deascii")
+    best = ranked_suggestions("yanlız", k=3)[0]
+    assert (best.text, best.source) == ("yalnız", "confusion")
+    assert all(s.source in SUGGESTION_SOURCES for s in ranked_suggestions("geliyorm"))
+
+
+def test_ranked_suggestions_scores() -> None:
+    suggestions = ranked_suggestions("kitaplarimdan", k=5)
+    assert isinstance(suggestions[0], Suggestion)
+    assert suggestions[0].text == "kitaplarımdan"
+    scores = [s.score for s in suggestions]
+    assert scores == sorted(scores, reverse=True)
+    assert 0 < sum(scores) <= 1.0 + 1e-9
+    assert len(ranked_suggestions("geliyorm", k=2)) <= 2
+    assert ranked_suggestions("") == []
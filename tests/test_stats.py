@@ -0,0 +1,197 @@
+"""Tests for document statistics."""
+
+from __future__ import annotations
+
+import io
+
+import pytest
+
+from durak import (
+    CorpusStats,
+    analyze,
+    char_ngrams,
+    formality_score,
+    line_anomalies,
+    morphological_complexity,
+    ngrams,
+    syllabify,
+    term_counts,
+    text_stats,
+)
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+def test_analyze() -> None:
+    root, pos, morphemes = analyze("kitaplarımdan")[0]
+    assert (root, pos) == ("kitap", "Noun")
+    assert morphemes == [("Pl", "lar"), ("P1sg", "ım"), ("Abl", "dan")]
+
+
+def test_analyze_unknown_word() -> None:
+    assert analyze("xyzabc") == []
+
+
+def test_morphological_complexity_keys() -> None:
+    metrics = morphological_complexity("Kitaplarımdan birini okudum.")
+    assert set(metrics) == {
+        "words",
+        "analyzed",
+        "coverage",
+        "mean_morphemes_per_word",
+        "suffix_entropy",
+        "verb_noun_ratio",
+    }
+    assert metrics["words"] == 3
+
+
+def test_morphological_complexity_values() -> None:
+    metrics = morphological_complexity("Evlerimizde oturuyoruz. Kitap okudum.")
+    assert metrics["coverage"] == 1.0
+    # ev+Pl+P1pl+Loc, otur+Prog1+A1pl, kitap, oku+Past+A1sg
+    assert metrics["mean_morphemes_per_word"] == pytest.approx(11 / 4)
+    assert metrics["verb_noun_ratio"] == pytest.approx(1.0)
+    assert metrics["suffix_entropy"] > 0
+
+
+def test_empty_text() -> None:
+    metrics = morphological_complexity("")
+    assert metrics["words"] == 0
+    assert metrics["coverage"] == 0.0
+
+
+def test_ngrams():
+    assert ngrams(["ben", "de", "geldim"]) == [("ben", "de"), ("de", "geldim")]
+    assert ngrams(["ben", "de"], 3) == []
+    counts = ngrams(["a", "b", "a", "b", "a"], counts=True)
+    assert counts == {("a", "b"): 2, ("b", "a"): 2}
+    assert list(counts) == [("a", "b"), ("b", "a")]
+
+
+def test_char_ngrams():
+    assert char_ngrams("çiçek", 3) == ["çiç", "içe", "çek"]
+    assert char_ngrams("ev ev", counts=True) == {"ev": 2, "v ": 1, " e": 1}
+    with pytest.raises(ValueError):
+        char_ngrams("ev", 0)
+    with pytest.raises(ValueError):
+        ngrams(["ev"], 0)
+
+
+def test_formality_score():
+    formal = formality_score("Toplantı yarın saat onda yapılacaktır.")
+    assert formal["formality"] == 1.0
+    assert formal["words"] == 5
+
+    informal = formality_score("Kanka naber, yarın gelcem 😂😂")
+    assert (informal["slang"], informal["contractions"]) == (2, 1)
+    assert informal["emoji"] == 2
+    assert informal["emoji_density"] == pytest.approx(0.5)
+    assert informal["formality"] < formal["formality"]
+
+
+def test_formality_second_person():
+    scores = formality_score("Sen dün geldin, siz dün geldiniz.")
+    assert scores["second_person"] == 2
+    assert 0 < scores["formality"] < 1
+
+
+def test_text_stats():
+    stats = text_stats("Ali okula gitti. Ali eve geldi.")
+    assert (stats["tokens"], stats["words"], stats["types"]) == (8, 6, 5)
+    assert stats["sentences"] == 2
+    assert stats["avg_word_length"] == 4
+    assert stats["avg_syllables_per_word"] == pytest.approx(13 / 6)
+    assert stats["avg_sentence_length"] == 3
+    expected = 198.825 - 40.175 * 13 / 6 - 2.610 * 3
+    assert stats["atesman"] == pytest.approx(expected)
+
+
+def test_text_stats_readability_ordering():
+    easy = text_stats("Ali topu at. Ayşe ip atla.")
+    hard = text_stats(
+        "Cumhurbaşkanlığı hükümet sisteminin uygulanmasına ilişkin "
+        "düzenlemeler, yasama ve yürütme arasındaki dengeleri "
+        "değiştirmiştir."
+    )
+    assert easy["atesman"] > 90
+    assert hard["atesman"] < easy["atesman"]
+    assert text_stats("")["atesman"] == 0
+
+
+def test_line_anomalies():
+    scores = line_anomalies(
+        "Yarın saat onda toplantı var.\nSÜPER FIRSAT HEMEN ARAYIN\n\n1 | 2 | 3"
+    )
+    assert len(scores) == 4
+    assert [s["anomaly"] > 1 for s in scores] == [False, True, False, True]
+    assert scores[1]["uppercase"] == 1.0
+    assert scores[2] == {
+        "punctuation": 0.0,
+        "uppercase": 0.0,
+        "digits": 0.0,
+        "whitespace": 0.0,
+        "anomaly": 0.0,
+    }
+    assert line_anomalies("TBMM'de 3 kanun görüşüldü.")[0]["anomaly"] < 1
+
+
+def test_syllabify():
+    assert syllabify("kitaplar") == ["ki", "tap", "lar"]
+    assert syllabify("İstanbul") == ["İs", "tan", "bul"]
+    assert syllabify("saat") == ["sa", "at"]
+    assert syllabify("tren") == ["tren"]
+
+
+def test_corpus_stats_tfidf():
+    stats = CorpusStats(["Türk kahvesi içtim.", "Türk kahvesi ve çay."])
+    stats.add_document("Bugün çay içtim.")
+    assert (stats.documents, stats.tokens, len(stats)) == (3, 10, 3)
+    assert stats.term_frequency("TÜRK") == 2
+    assert stats.document_frequency("çay") == 2
+    assert stats.tfidf("yok") == 0.0
+    assert stats.tfidf("bugün") < stats.tfidf("türk")
+    keywords = stats.top_keywords(3)
+    assert len(keywords) == 3
+    assert keywords == sorted(keywords, key=lambda pair: -pair[1])
+
+
+def test_corpus_stats_collocations():
+    stats = CorpusStats()
+    for text in ["Türk kahvesi içtim", "Türk kahvesi ve çay", "çay içtim"]:
+        stats.add_document(text)
+    first, second, pmi, count = stats.top_collocations(1)[0]
+    assert (first, second, count) == ("türk", "kahvesi", 2)
+    assert pmi > 0
+    assert stats.top_collocations(5, min_count=3) == []
+    assert repr(stats) == "CorpusStats(documents=3, tokens=9)"
+
+
+def test_term_counts():
+    corpus = "Kitap okudum.\nKİTAP okudum, dergi okudum\n\ndergi aldım\n"
+    counted = term_counts(io.StringIO(corpus))
+    assert counted[:3] == [("okudum", 3), ("dergi", 2), ("kitap", 2)]
+    assert term_counts(io.BytesIO(corpus.encode()), top=1) == [("okudum", 3)]
+    assert term_counts(io.StringIO(corpus), 2, min_count=2) == [("kitap okudum", 2)]
+    with pytest.raises(ValueError, match="positive"):
+        term_counts(io.StringIO(corpus), 0)
+
+
+def test_term_counts_memory_cap_gives_the_same_counts(tmp_path):
+    letters = "abcçdefgğhıijklmnoöprsştuüvyz"
+    words = ["kelime" + letters[i // 29 % 29] + letters[i % 29] for i in range(20000)]
+    path = tmp_path / "corpus.txt"
+    path.write_text("\n".join(" ".join(words[i : i + 20]) for i in range(0, 20000, 20)))
+    uncapped = term_counts(path)
+    assert len(uncapped) == 29 * 29
+    # A 0 MB cap spills on every new word and still merges to the same counts
+    assert term_counts(path, max_memory_mb=0) == uncapped
+    assert term_counts(str(path), top=5, max_memory_mb=0) == uncapped[:5]
@@ -0,0 +1,46 @@
+"""Tests for aggressive stemming."""
+
+from __future__ import annotations
+
+import pytest
+
+from durak import Lemmatizer, stem
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+def test_cascade_strips_without_dictionary() -> None:
+    assert stem("kitaplarımızdan") == "kitap"
+    assert stem("Evlerde") == "ev"
+    assert stem("geliyorum") == "gel"
+    # No dictionary gating: over-stemming is allowed, unlike the lemmatizer
+    assert stem("okul") == "ok"
+    assert Lemmatizer()("okul") == "okul"
+
+
+def test_max_suffixes() -> None:
+    assert stem("kitaplarımızdan", max_suffixes=1) == "kitaplarımız"
+    assert stem("kitaplarımızdan", max_suffixes=2) == "kitaplar"
+    assert stem("kitaplarımızdan", max_suffixes=0) == "kitaplarımızdan"
+
+
+def test_prefix_method() -> None:
+    assert stem("Bilgisayarlar", method="prefix") == "bilgi"
+    assert stem("bilgisayarlar", method="prefix", prefix_length=7) == "bilgisa"
+    assert stem("ev", method="prefix") == "ev"
+
+
+def test_proper_nouns_and_errors() -> None:
+    assert stem("Ankara'dan") == "ankara"
+    assert stem("İSTANBUL'A", method="prefix", prefix_length=3) == "ist"
+    with pytest.raises(ValueError, match="unknown method 'porter'"):
+        stem("ev", method="porter")
@@ -1,4 +1,6 @@
 import json
+import threading
+from http.server import BaseHTTPRequestHandler, HTTPServer
 from pathlib import Path
 
 import pytest
@@ -7,12 +9,21 @@ from durak import (
     DEFAULT_STOPWORD_RESOURCE,
     StopwordManager,
     StopwordSnapshot,
+    diff_stopwords,
+    discover_stopwords,
     is_stopword,
+    list_stopword_domains,
     list_stopwords,
+    load_stopword_domain,
     load_stopword_resource,
     load_stopwords,
+    merge_stopwords,
     remove_stopwords,
+    stopword_domain,
 )
+from durak import stopwords as stopwords_module
+from durak.config import PipelineConfig
+from durak.exceptions import ConfigurationError, StopwordError
 
 
 def test_base_stopwords_contains_common_tokens() -> None:
@@ -126,3 +137,95 @@ def test_list_stopwords_supports_alias_resources() -> None:
 @pytest.fixture
 def data_dir() -> Path:
     return Path(__file__).parent / "data"
+
+
+def test_diff_stopwords_normalizes_entries() -> None:
+    diff = diff_stopwords([" ve", "İçin", "ama"], ["ve", "için", "rt"])
+    assert diff.only_in_a == {"ama"}
+    assert diff.only_in_b == {"rt"}
+    assert diff.common == {"ve", "için"}
+    assert not diff.identical
+    assert diff.to_dict()["common"] == ["için", "ve"]
+
+
+def test_diff_stopwords_case_sensitive() -> None:
+    diff = diff_stopwords(["Ve"], ["ve"], case_sensitive=True)
+    assert diff.only_in_a == {"Ve"}
+    assert diff.only_in_b == {"ve"}
+    assert diff_stopwords(["Ve"], ["ve"]).identical
+
+
+def test_merge_stopwords_deduplicates() -> None:
+    merged = merge_stopwords(["ve", "ama", ""], ["VE", "rt"], ["IĞDIR"])
+    assert merged == ["ama", "rt", "ve", "ığdır"]
+    assert merge_stopwords() == []
+
+
+def test_load_stopword_domain_from_file(tmp_path: Path, monkeypatch) -> None:
+    monkeypatch.setattr(stopwords_module, "_DOMAINS", {})
+    path = tmp_path / "medical.txt"
+    path.write_text("# klinik\nHasta\ntedavi\n", encoding="utf-8")
+    assert load_stopword_domain("medical", path) == {"hasta", "tedavi"}
+    assert list_stopword_domains() == ["medical", "social_media"]
+    tokens = ["ve", "Hasta", "ateşli", "rt"]
+    assert remove_stopwords(tokens, domains=["medical"]) == ["ateşli", "rt"]
+    assert remove_stopwords(tokens, domains=["medical", "social_media"]) == [
+        "ateşli"
+    ]
+    config = PipelineConfig(steps=("tokenize",), stopword_domains=("medical",))
+    assert config.stopword_resources() == {"hasta", "tedavi"}
+
+    with pytest.raises(StopwordError, match="Available domains: medical"):
+        stopword_domain("legal")
+    with pytest.raises(StopwordError, match="Unknown stopword domain"):
+        remove_stopwords(tokens, domains=["legal"])
+    with pytest.raises(ConfigurationError, match="without"):
+        load_stopword_domain("domains/medical", path)
+
+
+def test_load_stopword_domain_from_url(monkeypatch) -> None:
+    monkeypatch.setattr(stopwords_module, "_DOMAINS", {})
+
+    class Handler(BaseHTTPRequestHandler):
+        def do_GET(self) -> None:  # noqa: N802
+            found = self.path == "/legal.txt"
+            self.send_response(200 if found else 404)
+            self.end_headers()
+            if found:
+                self.wfile.write("madde\nfıkra\n".encode())
+
+        def log_message(self, *args: object) -> None:
+            pass
+
+    server = HTTPServer(("127.0.0.1", 0), Handler)
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    url = f"http://127.0.0.1:{server.server_port}"
+    try:
+        assert load_stopword_domain("legal", f"{url}/legal.txt") == {"madde", "fıkra"}
+        assert stopword_domain("legal") == {"madde", "fıkra"}
+        with pytest.raises(StopwordError, match="404"):
+            load_stopword_domain("tax", f"{url}/tax.txt")
+        assert "tax" not in list_stopword_domains()
+    finally:
+        server.shutdown()
+        server.server_close()
+
+
+def test_discover_stopwords() -> None:
+    from durak import CorpusStats
+
+    documents = [
+        "Hasta bugün yine ateşli, hasta tedaviye başladı.",
+        "Hasta yine kontrole geldi.",
+        "Doktor hasta için yine ilaç yazdı.",
+    ]
+    assert discover_stopwords(documents, top_k=2) == ["hasta", "yine"]
+    assert discover_stopwords(iter(documents), top_k=5, min_df=4) == []
+    scores = CorpusStats(documents).stopword_candidates(2)
+    assert [term for term, _ in scores] == ["hasta", "yine"]
+    assert scores[0][1] > scores[1][1]
+
+    merged = discover_stopwords(documents, top_k=2, merge_base=True)
+    assert merged[:2] == ["hasta", "yine"]
+    assert set(merged[2:]) == BASE_STOPWORDS - {"hasta", "yine"}
+    assert merged[2:] == sorted(merged[2:])
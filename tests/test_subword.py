@@ -0,0 +1,202 @@
+"""Tests for the unigram and BPE subword trainers."""
+
+from __future__ import annotations
+
+import io
+
+import pytest
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+from durak import (
+    BpeModel,
+    UnigramModel,
+    segment_morphemes,
+    segment_word,
+    train_bpe,
+    train_unigram,
+)
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+CORPUS = [
+    "Kitaplar masada duruyor.",
+    "Kitaplarım evde, kitaplardan birini okudum.",
+    "Evlerimiz okullara yakın.",
+    "Okullarda kitaplar var.",
+    "Geliyorum, geliyorsun, gidiyorum.",
+] * 20
+
+
+def _join(pieces: list[str]) -> str:
+    """Decode byte pieces and word markers back into text."""
+    data = b""
+    for piece in pieces:
+        if piece.startswith("<0x") and piece.endswith(">") and len(piece) == 6:
+            data += bytes([int(piece[3:5], 16)])
+        else:
+            data += piece.encode("utf-8")
+    return data.decode("utf-8").replace("▁", " ").lstrip(" ")
+
+
+def test_segment_word_uses_analyzer():
+    assert segment_word("Kitaplarımdan") == ["▁kitap", "lar", "ım", "dan"]
+    assert segment_word("GİTTİM", fallback="byte") == ["▁git", "ti", "m"]
+
+
+def test_segment_word_fallback():
+    assert segment_word("xğ") == ["▁x", "ğ"]
+    assert segment_word("xğ", fallback="byte") == ["▁", "<0x78>", "<0xC4>", "<0x9F>"]
+    with pytest.raises(ValueError, match="unknown fallback"):
+        segment_word("x", fallback="bpe")
+
+
+@pytest.mark.parametrize("fallback", ["char", "byte"])
+def test_segment_morphemes_is_total(fallback):
+    text = "Kitaplarımdan birini 3 günde okudum 😀 www.example.com"
+    pieces = segment_morphemes(text, fallback=fallback)
+    assert _join(pieces) == "kitaplarımdan birini 3 günde okudum 😀 www.example.com"
+    assert pieces[:4] == ["▁kitap", "lar", "ım", "dan"]
+
+
+def test_unigram_byte_fallback():
+    model = train_unigram(CORPUS, vocab_size=290, byte_fallback=True)
+    assert model.id_to_piece(3) == "<0x00>"
+    assert model.encode("evde 日")[-3:] == ["<0xE6>", "<0x97>", "<0xA5>"]
+    assert 0 not in model.encode_ids("evde 日")
+    assert model.decode_ids(model.encode_ids("evde 日")) == "evde 日"
+    with pytest.raises(ValueError, match="too small"):
+        train_unigram(CORPUS, vocab_size=200, byte_fallback=True)
+
+
+@pytest.fixture(scope="module")
+def model() -> UnigramModel:
+    return train_unigram(CORPUS, vocab_size=40)
+
+
+def test_train_unigram_vocab(model):
+    assert len(model) == 40
+    assert model.pieces[:3] == [("<unk>", 0.0), ("<s>", 0.0), ("</s>", 0.0)]
+    assert model.piece_to_id("<unk>") == 0
+    assert model.piece_to_id("yokböyleparça") == 0
+    assert model.id_to_piece(1) == "<s>"
+    with pytest.raises(IndexError):
+        model.id_to_piece(40)
+
+
+def test_encode_decode_round_trip(model):
+    pieces = model.encode("KİTAPLARIM  okullarda")
+    assert pieces[0].startswith("▁")
+    assert model.decode(pieces) == "kitaplarım okullarda"
+    ids = model.encode_ids("KİTAPLARIM  okullarda")
+    assert [model.id_to_piece(i) for i in ids] == pieces
+    assert model.decode_ids(ids) == "kitaplarım okullarda"
+
+
+def test_morpheme_boundaries():
+    guided = train_unigram(CORPUS, vocab_size=40).encode("kitaplarım")
+    plain = train_unigram(CORPUS, vocab_size=40, seed_morphemes=False)
+    assert guided[:2] == ["▁kitap", "lar"]
+    assert plain.encode("kitaplarım")[0] == "▁kitaplar"
+
+
+def test_train_from_file():
+    lines = io.StringIO("\n".join(CORPUS))
+    assert train_unigram(lines, vocab_size=40).pieces == train_unigram(
+        CORPUS, vocab_size=40
+    ).pieces
+
+
+def test_save_and_load(model, tmp_path):
+    prefix = str(tmp_path / "tr")
+    model.save(prefix)
+    loaded = UnigramModel.load(prefix + ".model")
+    assert loaded.pieces == model.pieces
+    assert loaded.encode("kitaplarım") == model.encode("kitaplarım")
+    vocab = (tmp_path / "tr.vocab").read_text(encoding="utf-8").splitlines()
+    assert len(vocab) == 40
+    assert vocab[0] == "<unk>\t0"
+    assert UnigramModel.from_bytes(model.to_bytes()).pieces == model.pieces
+
+
+def test_invalid_model_file():
+    with pytest.raises(ValueError, match="malformed"):
+        UnigramModel.from_bytes(b"\x0a\xff")
+
+
+@pytest.mark.parametrize(
+    "texts, kwargs, message",
+    [
+        ([], {}, "no words"),
+        (CORPUS, {"vocab_size": 5}, "too small"),
+        (CORPUS, {"vocab_size": 100_000}, "too large"),
+        (CORPUS, {"character_coverage": 0.0}, "character_coverage"),
+        (CORPUS, {"max_piece_length": 0}, "max_piece_length"),
+    ],
+)
+def test_train_unigram_errors(texts, kwargs, message):
+    with pytest.raises(ValueError, match=message):
+        train_unigram(texts, **kwargs)
+
+
+@pytest.fixture(scope="module")
+def bpe() -> BpeModel:
+    return train_bpe(CORPUS, vocab_size=80)
+
+
+def test_train_bpe_vocab(bpe):
+    assert len(bpe) == 80
+    assert bpe.pieces[0] == "<unk>"
+    assert bpe.piece_to_id("<unk>") == 0
+    for left, right in bpe.merges:
+        assert bpe.piece_to_id(left + right) > bpe.piece_to_id(left)
+    assert repr(bpe) == "BpeModel(vocab_size=80)"
+
+
+def test_bpe_encode_decode(bpe):
+    pieces = bpe.encode("KİTAPLARIM evde")
+    assert bpe.decode(pieces) == "kitaplarım evde"
+    ids = bpe.encode_ids("KİTAPLARIM evde")
+    assert [bpe.id_to_piece(i) for i in ids] == pieces
+    assert bpe.decode_ids(ids) == "kitaplarım evde"
+    assert bpe.encode("evde 日本")[-1] == "日本"
+    with pytest.raises(IndexError):
+        bpe.id_to_piece(10_000)
+
+
+def test_bpe_merges_follow_morphemes():
+    guided = train_bpe(CORPUS, vocab_size=200, boundary_penalty=1.0)
+    plain = train_bpe(CORPUS, vocab_size=200, morpheme_boundaries=False)
+    assert guided.encode("kitaplarım")[:2] == ["▁kitap", "lar"]
+    assert plain.encode("kitaplarım") == ["▁kitaplarım"]
+
+
+def test_bpe_save_and_load(bpe, tmp_path):
+    prefix = str(tmp_path / "tr")
+    bpe.save(prefix)
+    loaded = BpeModel.load(prefix)
+    assert loaded.pieces == bpe.pieces
+    assert loaded.merges == bpe.merges
+    assert loaded.encode("kitaplarım") == bpe.encode("kitaplarım")
+    merges = (tmp_path / "tr-merges.txt").read_text(encoding="utf-8")
+    assert merges.splitlines()[0] == "#version: 0.2"
+
+
+@pytest.mark.parametrize(
+    "texts, kwargs, message",
+    [
+        ([], {}, "no words"),
+        (CORPUS, {"vocab_size": 5}, "too small"),
+        (CORPUS, {"boundary_penalty": 1.5}, "boundary_penalty"),
+    ],
+)
+def test_train_bpe_errors(texts, kwargs, message):
+    with pytest.raises(ValueError, match=message):
+        train_bpe(texts, **kwargs)
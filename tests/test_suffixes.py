@@ -1,4 +1,6 @@
-from durak.suffixes import attach_detached_suffixes
+import pytest
+
+from durak.suffixes import attach_detached_suffixes, split_clitics
 
 
 def test_attach_detached_suffixes_with_apostrophe() -> None:
@@ -21,3 +23,19 @@ def test_attach_detached_suffixes_disable_without_apostrophe() -> None:
     assert attach_detached_suffixes(
         tokens, allow_without_apostrophe=False
     ) == tokens
+
+
+def test_attach_detached_suffixes_typographic_apostrophe() -> None:
+    assert attach_detached_suffixes(["ankara", "’", "da"]) == ["ankara’da"]
+
+
+def test_split_clitics() -> None:
+    try:
+        from durak import _durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    assert split_clitics("İstanbul'a") == ("İstanbul", "'a")
+    assert split_clitics("ANKARA’DA") == ("ANKARA", "’DA")
+    assert split_clitics("O'Neill") == ("O'Neill", "")
+    assert split_clitics("kitap") == ("kitap", "")
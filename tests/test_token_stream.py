@@ -0,0 +1,70 @@
+"""Tests for the streaming tokenizer."""
+
+from __future__ import annotations
+
+import io
+
+import pytest
+
+from durak import TokenStream, tokenize
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+TEXT = (
+    "Türkiye'ye gidiyorum. Çok güzel bir gün! "
+    "İstanbul'da 5-10 gün kalacağım, sonra Ankara'ya döneceğim.\n"
+) * 50
+
+
+def test_stream_from_path_matches_tokenize(tmp_path):
+    path = tmp_path / "corpus.txt"
+    path.write_text(TEXT, encoding="utf-8")
+
+    assert list(TokenStream(path, chunk_size=64)) == tokenize(TEXT)
+    assert list(TokenStream(str(path))) == tokenize(TEXT)
+
+
+def test_stream_from_file_objects():
+    expected = tokenize(TEXT)
+    assert list(TokenStream(io.StringIO(TEXT), chunk_size=100)) == expected
+    binary = io.BytesIO(TEXT.encode("utf-8"))
+    assert list(TokenStream(binary, chunk_size=7)) == expected
+
+
+def test_stream_strip_punct():
+    tokens = list(TokenStream(io.StringIO("Merhaba, dünya!"), strip_punct=True))
+    assert tokens == ["Merhaba", "dünya"]
+
+
+def test_stream_is_lazy():
+    stream = TokenStream(io.StringIO(TEXT), chunk_size=64)
+    assert iter(stream) is stream
+    assert next(stream) == "Türkiye'ye"
+    assert next(stream) == "gidiyorum"
+
+
+def test_stream_errors(tmp_path):
+    with pytest.raises(OSError):
+        TokenStream(tmp_path / "missing.txt")
+    with pytest.raises(ValueError):
+        TokenStream(io.StringIO(TEXT), chunk_size=0)
+    with pytest.raises(ValueError, match="UTF-8"):
+        list(TokenStream(io.BytesIO(b"abc \xff\xfe def")))
+
+
+def test_stream_reraises_reader_errors():
+    class BrokenReader:
+        def read(self, size: int) -> str:
+            raise RuntimeError("disk on fire")
+
+    with pytest.raises(RuntimeError, match="disk on fire"):
+        list(TokenStream(BrokenReader()))
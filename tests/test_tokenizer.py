@@ -2,7 +2,21 @@ from pathlib import Path
 
 import pytest
 from durak import tokenize
-from durak.tokenizer import normalize_tokens, split_sentences, tokenize_text
+from durak.exceptions import TokenizationError
+from durak.tokenizer import (
+    TOKENIZER_REGISTRY,
+    TokenDoc,
+    TokenizerConfig,
+    TokenPattern,
+    compare_segmentation,
+    detokenize,
+    normalize_tokens,
+    pattern_cache_info,
+    set_pattern_cache_size,
+    split_sentences,
+    tokenize_spans,
+    tokenize_text,
+)
 
 from tests.data.corpus_validator import validate_corpus
 
@@ -19,6 +33,11 @@ from tests.data.corpus_validator import validate_corpus
             "URL: https://karagoz.io/test?a=1",
             ["URL", ":", "https://karagoz.io/test?a=1"],
         ),
+        (
+            "Aile 👨‍👩‍👧 tatilde 🇹🇷 👍🏽!",
+            ["Aile", "👨‍👩‍👧", "tatilde", "🇹🇷", "👍🏽", "!"],
+        ),
+        ("Ödül ❤️ 1️⃣ :)", ["Ödül", "❤️", "1️⃣", ":)"]),
     ],
 )
 def test_regex_tokenize_preserves_turkish_features(
@@ -56,3 +75,411 @@ def test_tokenize_accepts_strip_punct_flag() -> None:
 
     tokens_without_punct = tokenize(text, strip_punct=True)
     assert tokens_without_punct == ["Durak", "kolay", "mı"]
+
+
+def test_strip_punct_keeps_emoji() -> None:
+    assert tokenize("Süper! 🔥😀", strip_punct=True) == ["Süper", "🔥", "😀"]
+    tokens = normalize_tokens(["Süper", "!", "🔥"], strip_punct=True)
+    assert tokens == ["süper", "🔥"]
+
+
+def test_emoji_tokens_match_rust_tokenizer() -> None:
+    try:
+        from durak._durak_core import tokenize_batch, tokenize_with_types
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    text = (
+        "Bayram 🇹🇷🎉 aile 👨‍👩‍👧‍👦, "
+        "❤️‍🔥 🧑🏻‍💻 #️⃣ ✌🏿 "
+        "©️ 🏳️‍🌈 😂😂"
+    )
+    assert tokenize(text) == tokenize_batch([text])[0]
+    types = tokenize_with_types(text)
+    emoji = [token for token, kind, _, _ in types if kind == "EMOJI"]
+    assert emoji == [
+        "🇹🇷", "🎉", "👨‍👩‍👧‍👦", "❤️‍🔥",
+        "🧑🏻‍💻", "#️⃣", "✌🏿", "©️",
+        "🏳️‍🌈", "😂", "😂",
+    ]
+
+
+def test_hashtags_and_mentions_are_single_tokens() -> None:
+    text = "@ali_veli #GünaydınTürkiye x@ bu#değil"
+    assert tokenize(text) == [
+        "@ali_veli", "#GünaydınTürkiye", "x", "@", "bu", "#", "değil",
+    ]
+
+
+def test_hashtag_and_mention_types_match_rust_tokenizer() -> None:
+    try:
+        from durak._durak_core import tokenize_batch, tokenize_with_types
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    text = "Selam @durak_nlp! #GünaydınTürkiye #2024 e-posta: ali@x.com"
+    assert tokenize(text) == tokenize_batch([text])[0]
+    kinds = {token: kind for token, kind, _, _ in tokenize_with_types(text)}
+    assert kinds["@durak_nlp"] == "MENTION"
+    assert kinds["#GünaydınTürkiye"] == "HASHTAG"
+    assert kinds["#2024"] == "HASHTAG"
+    assert kinds["ali@x.com"] == "EMAIL"
+
+
+def test_split_hashtags_option() -> None:
+    try:
+        from durak._durak_core import split_hashtag
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    assert split_hashtag("#HDPKongresi") == ["hdp", "kongresi"]
+    text = "#GünaydınTürkiye @durak #çokgüzel"
+    assert tokenize(text, split_hashtags=True) == [
+        "günaydın", "türkiye", "@durak", "çok", "güzel"
+    ]
+    assert tokenize_text(text, split_hashtags=True)[:2] == ["günaydın", "türkiye"]
+
+
+CONTACT_TEXT = (
+    "Yaz: ali.veli@ornek.com.tr. Tel 0532 123 45 67, +90 (212) 123-45-67; "
+    "IBAN TR33 0006 1005 1978 6457 8413 26"
+)
+
+
+def test_contact_patterns_are_single_tokens() -> None:
+    assert tokenize(CONTACT_TEXT) == [
+        "Yaz", ":", "ali.veli@ornek.com.tr", ".", "Tel", "0532 123 45 67", ",",
+        "+90 (212) 123-45-67", ";", "IBAN", "TR33 0006 1005 1978 6457 8413 26",
+    ]
+    assert tokenize("1500 2024 05321") == ["1500", "2024", "05321"]
+
+
+def test_tokenizer_config_toggles_patterns() -> None:
+    config = TokenizerConfig(emails=False, phones=False)
+    tokens = tokenize(CONTACT_TEXT, config=config)
+    assert "ali.veli@ornek.com.tr" not in tokens
+    assert "@" in tokens and "0532" in tokens
+    assert "TR33 0006 1005 1978 6457 8413 26" in tokens
+    assert tokenize(CONTACT_TEXT, config=TokenizerConfig()) == tokenize(CONTACT_TEXT)
+    with pytest.raises(TokenizationError):
+        tokenize(CONTACT_TEXT, strategy="other", config=config)
+
+
+def test_custom_token_patterns() -> None:
+    gene = TokenPattern("gene", r"\b[A-Z]{2,}\d+[A-Z]?\b")
+    citation = TokenPattern("citation", r"(\d+) sayılı (\w+) Kanunu?")
+    config = TokenizerConfig(patterns=[gene, citation])
+    text = "BRCA1 ve TP53 genleri 5237 sayılı Türk Ceza Kanunu'nda geçmez."
+    tokens = tokenize(text, config=config)
+    assert tokens[:4] == ["BRCA1", "ve", "TP53", "genleri"]
+    assert "5237 sayılı Türk" not in tokens
+    assert tokenize("5237 sayılı Ceza Kanunu", config=config) == [
+        "5237 sayılı Ceza Kanunu"
+    ]
+    assert config.token_name("TP53") == "gene"
+    assert config.token_name("genleri") is None
+    assert config.pattern() is TokenizerConfig(patterns=(gene, citation)).pattern()
+
+
+def test_custom_token_pattern_priority() -> None:
+    # Below the apostrophe words (50), "İstanbul'da" stays a single token
+    place = TokenPattern("place", "İstanbul", priority=40)
+    tokens = tokenize("İstanbul'da", config=TokenizerConfig(patterns=[place]))
+    assert tokens == ["İstanbul'da"]
+    place = TokenPattern("place", "İstanbul")
+    tokens = tokenize("İstanbul'da", config=TokenizerConfig(patterns=[place]))
+    assert tokens == ["İstanbul", "'", "da"]
+    # A URL (90) wins over a pattern below it
+    domain = TokenPattern("domain", r"\w+\.com", priority=85)
+    config = TokenizerConfig(patterns=[domain])
+    assert tokenize("www.ornek.com ornek.com", config=config) == [
+        "www.ornek.com", "ornek.com"
+    ]
+
+
+def test_custom_token_pattern_registration() -> None:
+    ticker = TokenPattern("ticker", "[$][A-Z]{2,5}")
+    TOKENIZER_REGISTRY["tickers"] = TokenizerConfig(patterns=[ticker]).tokenizer()
+    try:
+        assert tokenize("$THYAO %3 yükseldi", strategy="tickers") == [
+            "$THYAO", "%", "3", "yükseldi"
+        ]
+    finally:
+        del TOKENIZER_REGISTRY["tickers"]
+
+
+@pytest.mark.parametrize(
+    ("name", "regex"),
+    [("", "x"), ("broken", "(unclosed"), ("empty", "a*")],
+)
+def test_invalid_token_patterns(name: str, regex: str) -> None:
+    with pytest.raises(TokenizationError):
+        TokenPattern(name, regex)
+
+
+def test_duplicate_token_pattern_names() -> None:
+    with pytest.raises(TokenizationError, match="Duplicate"):
+        TokenizerConfig(patterns=[TokenPattern("a", "x"), TokenPattern("a", "y")])
+
+
+def test_pattern_cache() -> None:
+    assert pattern_cache_info()["maxsize"] == 0
+    set_pattern_cache_size(2)
+    try:
+        for _ in range(3):
+            ticker = TokenPattern("ticker", "[$][A-Z]{2,5}")
+            config = TokenizerConfig(patterns=[ticker])
+            assert tokenize("$THYAO yükseldi", config=config)[0] == "$THYAO"
+        # The ticker regex and the alternation: compiled once each
+        assert pattern_cache_info() == {
+            "hits": 4, "misses": 2, "size": 2, "maxsize": 2
+        }
+        TokenPattern("gene", r"[A-Z]+\d+")
+        assert pattern_cache_info()["size"] == 2
+        set_pattern_cache_size(1)
+        assert pattern_cache_info()["size"] == 1
+    finally:
+        set_pattern_cache_size(0)
+    assert pattern_cache_info() == {"hits": 0, "misses": 0, "size": 0, "maxsize": 0}
+    with pytest.raises(TokenizationError):
+        set_pattern_cache_size(-1)
+
+
+def test_contact_types_match_rust_tokenizer() -> None:
+    try:
+        from durak._durak_core import tokenize_batch, tokenize_with_types
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    assert tokenize(CONTACT_TEXT) == tokenize_batch([CONTACT_TEXT])[0]
+    kinds = [kind for _, kind, _, _ in tokenize_with_types(CONTACT_TEXT)]
+    assert kinds.count("PHONE") == 2
+    assert kinds.count("EMAIL") == 1
+    assert kinds.count("IBAN") == 1
+
+
+DATE_TEXT = (
+    "Toplantı 3 Mart 2024 saat 14:30'da, ödeme 12.03.2024 ve 2024-03-12 arası."
+)
+
+
+def test_date_and_time_patterns_are_single_tokens() -> None:
+    assert tokenize(DATE_TEXT) == [
+        "Toplantı", "3 Mart 2024", "saat 14:30", "'", "da", ",", "ödeme",
+        "12.03.2024", "ve", "2024-03-12", "arası", ".",
+    ]
+    tokens = tokenize(DATE_TEXT, config=TokenizerConfig(dates=False, times=False))
+    assert "3 Mart 2024" not in tokens and "saat 14:30" not in tokens
+    assert ["3", "Mart", "2024", "saat", "14", ":", "30"] == tokens[1:8]
+    assert tokenize("3.5 kilo, 45.13.2024") == ["3.5", "kilo", ",", "45.13.2024"]
+
+
+def test_date_types_match_rust_tokenizer() -> None:
+    try:
+        from durak._durak_core import tokenize_batch, tokenize_with_types
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    text = DATE_TEXT + " NİSAN 2025 Ekim 9:05:30 4/7/2023"
+    assert tokenize(text) == tokenize_batch([text])[0]
+    kinds = [kind for _, kind, _, _ in tokenize_with_types(text)]
+    assert kinds.count("DATE") == 5
+    assert kinds.count("TIME") == 2
+
+
+def test_rust_tokenizer_matches_config() -> None:
+    try:
+        from durak._durak_core import RegexTokenizer
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    text = CONTACT_TEXT + " " + DATE_TEXT
+    for config in [
+        TokenizerConfig(),
+        TokenizerConfig(emails=False, phones=False),
+        TokenizerConfig(ibans=False, dates=False, times=False),
+    ]:
+        tokenizer = config.rust_tokenizer()
+        assert tokenizer.tokenize(text) == tokenize(text, config=config)
+        batch = tokenizer.tokenize_batch([text, None])
+        assert batch == [tokenizer.tokenize(text), None]
+    tokenizer = RegexTokenizer(disable=["email", "date"])
+    assert "email" not in tokenizer.classes and "url" in tokenizer.classes
+    assert repr(tokenizer) == "RegexTokenizer(disable=['email', 'date'])"
+    with pytest.raises(ValueError):
+        RegexTokenizer(disable=["word"])
+    with pytest.raises(TokenizationError):
+        TokenizerConfig(numbers=True).rust_tokenizer()
+
+
+def test_number_words_config() -> None:
+    text = "Üç yüz kırk iki kişi, bir gün geldi."
+    assert "Üç yüz kırk iki" not in tokenize(text)
+    tokens = tokenize(text, config=TokenizerConfig(numbers=True))
+    assert tokens[:2] == ["Üç yüz kırk iki", "kişi"]
+    assert tokens[-5:] == [",", "bir", "gün", "geldi", "."]
+
+
+def test_clitics_option() -> None:
+    try:
+        from durak._durak_core import tokenize_batch
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    text = "İstanbul’a ve Ali'nin evine gittik."
+    assert tokenize(text)[:4] == ["İstanbul’a", "ve", "Ali'nin", "evine"]
+    assert tokenize(text) == tokenize_batch([text])[0]
+    assert tokenize(text, clitics="detach") == [
+        "İstanbul", "’a", "ve", "Ali", "'nin", "evine", "gittik", ".",
+    ]
+    assert tokenize_text("O'Neill", clitics="detach") == ["O'Neill"]
+    with pytest.raises(TokenizationError, match="Unknown clitics mode"):
+        tokenize(text, clitics="split")  # type: ignore[arg-type]
+
+
+def test_profile_option() -> None:
+    try:
+        from durak._durak_core import split_clitics  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    text = "TCK'nın 12 Mart 2024 tarihli değişikliği."
+    assert tokenize(text, profile="legal") == tokenize(
+        text, clitics="detach", config=TokenizerConfig(numbers=True)
+    )
+    news = tokenize_text("Durak, kolay mı?", profile="news")
+    assert news == ["Durak", "kolay", "mı"]
+    with pytest.raises(TokenizationError, match="cannot be combined"):
+        tokenize(text, profile="legal", strip_punct=True)
+
+
+def test_tokenize_columns_match_rows() -> None:
+    try:
+        from durak._durak_core import tokenize_columns, tokenize_with_types
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    text = "İstanbul'da 3 gün kaldık 😂 #tatil https://durak.dev"
+    texts, starts, ends, kinds = tokenize_columns(text)
+    assert list(zip(texts, kinds, starts, ends)) == tokenize_with_types(text)
+    assert text[starts[0]:ends[0]] == texts[0]
+    assert tokenize_columns("") == ([], [], [], [])
+
+
+def test_token_doc_reconstructs_text_after_edits() -> None:
+    try:
+        from durak import _durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    text = "  Bu kitap , çok güzel!\nYarın  da okurum.\n"
+    doc = TokenDoc.from_text(text)
+    assert doc.text == text
+    assert doc.prefix == "  "
+    assert len(doc) == len(tokenize(text))
+    assert doc.mask(lambda t: t == "kitap").text == (
+        "  Bu [MASK] , çok güzel!\nYarın  da okurum.\n"
+    )
+    removed = doc.remove(lambda t: t in {"Bu", ",", "da", "okurum", "."})
+    assert removed.text == "  kitap çok güzel!\nYarın\n"
+
+
+def test_token_doc_validates_lengths() -> None:
+    with pytest.raises(TokenizationError):
+        TokenDoc(("a", "b"), (" ",))
+
+
+def test_detokenize_spacing() -> None:
+    try:
+        from durak import _durak_core  # noqa: F401
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    tokens = ["\"", "Evet", "\"", "dedi", "(", "Ankara", "'", "da", ")", "."]
+    assert detokenize(tokens) == "\"Evet\" dedi (Ankara'da)."
+    assert detokenize(tokenize("Merhaba, dünya! %20 indirim...")) == (
+        "Merhaba, dünya! %20 indirim..."
+    )
+
+
+def test_long_tokens_degrade_to_blobs() -> None:
+    try:
+        from durak._durak_core import tokenize_with_types
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+    from durak import Lemmatizer, analyze
+
+    blob = "ler" * 5000
+    kinds = [kind for _, kind, _, _ in tokenize_with_types(f"kitap {blob}")]
+    assert kinds == ["WORD", "BLOB"]
+    assert Lemmatizer(strategy="heuristic")(blob) == blob
+    assert analyze(blob) == []
+
+
+def test_rewrite_rules(tmp_path: Path) -> None:
+    try:
+        from durak._durak_core import RewriteRules
+    except ImportError:
+        pytest.skip("Rust extension not installed")
+
+    rules = RewriteRules(
+        "# house style\n"
+        "TL => lira\n"
+        "<NUMBER> TL => $1 lira\n"
+        "<DATE> => $1:iso\n"
+        "yani =>\n"
+    )
+    assert len(rules) == 4
+    tokens = tokenize("Yani 12.03.2024 tarihinde 50 TL ödedim.")
+    assert rules(tokens) == ["2024-03-12", "tarihinde", "50", "lira", "ödedim", "."]
+    assert rules.apply(["tl"]) == ["lira"]
+
+    path = tmp_path / "house.rules"
+    path.write_text("bkz . => bakınız\n", encoding="utf-8")
+    assert RewriteRules.from_file(path)(["bkz", ".", "ek"]) == ["bakınız", "ek"]
+    with pytest.raises(ValueError, match="line 2: unknown token type <NUM>"):
+        RewriteRules("TL => lira\n<NUM> => sayı\n")
+
+
+def test_compare_segmentation():
+    text = "Ali 3 Mart'ta geldi. Ali gitti."
+    diff = compare_segmentation(
+        text,
+        ["Ali", "3 Mart", "'ta", "geldi", ".", "Ali", "gitti", "."],
+        ["Ali", "3", "Mart'ta", "geldi", ".", "Ali", "gitti", "."],
+    )
+    assert diff.matching == 6
+    assert diff.precision == diff.recall == diff.f1 == 0.75
+    # Overlapping spans of both sides make one region
+    assert diff.mismatches() == [(4, 13, ["3 Mart", "'ta"], ["3", "Mart'ta"])]
+    assert diff.to_dict(limit=0)["mismatches"] == []
+
+    sentences = compare_segmentation(
+        text, split_sentences(text), ["Ali 3 Mart'ta geldi. Ali gitti."]
+    )
+    assert (sentences.precision, sentences.recall) == (0.0, 0.0)
+    assert len(sentences.mismatches()) == 1
+
+    with pytest.raises(TokenizationError, match="Segment 2 \\('Veli'\\)"):
+        compare_segmentation(text, ["Ali"], ["Ali", "Veli"])
+
+
+def test_compare_segmentation_with_rewritten_tokens():
+    pytest.importorskip("durak._durak_core")
+    text = "Bugün #GüzelGün İstanbul'a"
+    spans = tokenize_spans(text, split_hashtags=True, clitics="detach")
+    assert spans == [
+        ("Bugün", 0, 5),
+        ("güzel", 7, 12),
+        ("gün", 12, 15),
+        ("İstanbul", 16, 24),
+        ("'a", 24, 26),
+    ]
+    assert [token for token, _, _ in spans] == tokenize(
+        text, split_hashtags=True, clitics="detach"
+    )
+    diff = compare_segmentation(
+        text, spans, ["Bugün", "#GüzelGün", "İstanbul", "'a"]
+    )
+    assert diff.matching == 3
+    assert diff.mismatches() == [(6, 15, ["Güzel", "Gün"], ["#GüzelGün"])]
@@ -179,3 +179,20 @@ def test_normalized_offsets_ner_use_case():
     assert entity_token is not None
     assert entity_token[0] == "istanbul"  # Normalized token
     assert text[entity_token[1]:entity_token[2]] == "İstanbul"  # Original text
+
+
+def test_doc_tokens():
+    from durak import Doc
+
+    doc = Doc("İstanbul'a geldim ve kitapları okudum.")
+    assert len(doc) == 6
+    assert doc.text == "İstanbul'a geldim ve kitapları okudum."
+    assert [(t.text, t.start, t.end) for t in doc] == tokenize_with_offsets(doc.text)
+    first = doc[0]
+    assert (first.kind, first.norm, first.lemma) == ("WORD", "istanbul'a", "istanbul")
+    assert [t.lemma for t in doc.tokens[1:5]] == ["gel", "ve", "kitap", "oku"]
+    assert [t.text for t in doc if t.is_stop] == ["ve"]
+    assert (doc[-1].text, doc[-1].kind, doc[-1].lemma) == (".", "PUNCT", ".")
+    assert len(first) == 10
+    with pytest.raises(IndexError):
+        doc[6]
@@ -0,0 +1,82 @@
+"""Tests for the stateless worker API."""
+
+from __future__ import annotations
+
+import pickle
+
+import pytest
+
+from durak.exceptions import ConfigurationError
+from durak.worker import WorkerConfig, init_worker, process_documents
+
+try:
+    from durak import _durak_core  # noqa: F401
+
+    RUST_AVAILABLE = True
+except ImportError:
+    RUST_AVAILABLE = False
+
+pytestmark = pytest.mark.skipif(
+    not RUST_AVAILABLE, reason="Rust extension not installed"
+)
+
+
+def test_process_documents_defaults():
+    assert process_documents(["Kitaplar geldi.", None]) == [
+        {"tokens": ["kitaplar", "geldi", "."]},
+        None,
+    ]
+
+
+def test_process_documents_lemmatize_and_stopwords():
+    config = WorkerConfig(
+        remove_stopwords=True, stopword_additions=["geldi"], lemmatize=True
+    )
+    result = process_documents(["Bu kitaplar geldi", "evler"], config.to_json())
+    assert result == [
+        {"tokens": ["kitaplar"], "lemmas": ["kitap"]},
+        {"tokens": ["evler"], "lemmas": ["ev"]},
+    ]
+
+
+def test_init_worker_returns_parsed_config():
+    config = init_worker('{"lemmatize": true, "stopword_keep": ["ve"]}')
+    assert config.lemmatize is True
+    assert config.stopword_keep == ("ve",)
+    assert init_worker(config.to_json()) == config
+
+
+def test_config_json_round_trip():
+    config = WorkerConfig(remove_stopwords=True, stopword_additions=("şey",))
+    assert WorkerConfig.from_json(config.to_json()) == config
+    assert pickle.loads(pickle.dumps(config)) == config
+    # Key order does not change the canonical form
+    assert (
+        WorkerConfig.from_json('{"lemmatize": true, "normalize": false}').to_json()
+        == WorkerConfig.from_json('{"normalize": false, "lemmatize": true}').to_json()
+    )
+
+
+@pytest.mark.parametrize(
+    "config_json",
+    [
+        "not json",
+        "[]",
+        '{"stopword_file": "/tmp/stopwords.txt"}',
+        '{"lemmatize": "yes"}',
+        '{"min_root_length": true}',
+        '{"stopword_additions": [1]}',
+        '{"strategy": "neural"}',
+    ],
+)
+def test_invalid_config_rejected(config_json):
+    with pytest.raises(ConfigurationError):
+        WorkerConfig.from_json(config_json)
+
+
+def test_process_documents_threads_do_not_change_output():
+    texts = [f"Kitaplar {i} geldi" if i % 5 else None for i in range(2000)]
+    config = WorkerConfig(lemmatize=True)
+    expected = process_documents(texts, config, threads=1)
+    assert process_documents(texts, config, threads=4) == expected
+    assert process_documents(texts, config, threads=0) == expected
@@ -0,0 +1,132 @@
+"""Tests for pluggable output writers."""
+
+from __future__ import annotations
+
+import json
+import subprocess
+import sys
+import threading
+from collections.abc import Iterator
+from http.server import BaseHTTPRequestHandler, HTTPServer
+
+import pytest
+
+from durak.writers import (
+    FileWriter,
+    HttpPutWriter,
+    S3Writer,
+    StdoutWriter,
+    WriterError,
+    open_writer,
+)
+
+
+@pytest.fixture
+def put_server() -> Iterator[tuple[str, dict[str, bytes]]]:
+    """Local HTTP server recording PUT bodies by path; /deny/* returns 403."""
+    received: dict[str, bytes] = {}
+
+    class Handler(BaseHTTPRequestHandler):
+        def do_PUT(self) -> None:  # noqa: N802
+            length = int(self.headers["Content-Length"])
+            received[self.path] = self.rfile.read(length)
+            self.send_response(403 if self.path.startswith("/deny") else 200)
+            self.end_headers()
+
+        def log_message(self, *args: object) -> None:
+            pass
+
+    server = HTTPServer(("127.0.0.1", 0), Handler)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield f"http://127.0.0.1:{server.server_port}", received
+    server.shutdown()
+    server.server_close()
+
+
+def test_open_writer_dispatch(tmp_path):
+    assert isinstance(open_writer(None), StdoutWriter)
+    assert isinstance(open_writer("-"), StdoutWriter)
+    assert isinstance(open_writer("https://example.com/shard"), HttpPutWriter)
+    with open_writer(str(tmp_path / "out.txt")) as writer:
+        assert isinstance(writer, FileWriter)
+
+
+def test_file_writer(tmp_path):
+    path = tmp_path / "out.jsonl"
+    with open_writer(str(path)) as writer:
+        writer.write("bir\n")
+        writer.write("iki ğüşiöç\n")
+    assert path.read_text(encoding="utf-8") == "bir\niki ğüşiöç\n"
+
+
+def test_http_put_writer(put_server):
+    url, received = put_server
+    with open_writer(f"{url}/shards/0001.jsonl") as writer:
+        writer.write('{"tokens": ["merhaba"]}\n')
+        writer.write('{"tokens": ["dünya"]}\n')
+    assert received["/shards/0001.jsonl"].decode("utf-8") == (
+        '{"tokens": ["merhaba"]}\n{"tokens": ["dünya"]}\n'
+    )
+
+
+def test_http_put_writer_reports_errors(put_server):
+    url, _ = put_server
+    writer = open_writer(f"{url}/deny/0001.jsonl")
+    writer.write("x")
+    with pytest.raises(WriterError, match="HTTP 403"):
+        writer.close()
+
+
+def test_remote_writer_does_not_upload_after_error(put_server):
+    url, received = put_server
+    with pytest.raises(RuntimeError):
+        with open_writer(f"{url}/partial.jsonl") as writer:
+            writer.write("half a shard")
+            raise RuntimeError("processing failed")
+    assert received == {}
+
+
+def test_s3_writer_with_client():
+    uploads = {}
+
+    class FakeClient:
+        def upload_fileobj(self, body, bucket, key):
+            uploads[(bucket, key)] = body.read()
+
+    with S3Writer("s3://corpora/tr/shard-0001.jsonl", client=FakeClient()) as writer:
+        writer.write("kitap\n")
+    assert uploads == {("corpora", "tr/shard-0001.jsonl"): b"kitap\n"}
+
+
+def test_s3_writer_requires_bucket_and_key():
+    with pytest.raises(WriterError, match="s3://bucket/key"):
+        S3Writer("s3://corpora", client=object())
+
+
+def test_cli_output_to_http_url(put_server):
+    """The CLI streams --output to an HTTP PUT target."""
+    url, received = put_server
+    result = subprocess.run(
+        [
+            sys.executable, "-m", "durak.cli", "pipeline", "-",
+            "-o", f"{url}/corpus.jsonl",
+        ],
+        input="Merhaba dünya\n",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert result.returncode == 0, result.stderr
+    body = received["/corpus.jsonl"].decode("utf-8")
+    assert json.loads(body) == {"line": 1, "tokens": ["merhaba", "dünya"]}
+
+    denied = subprocess.run(
+        [sys.executable, "-m", "durak.cli", "clean", "-", "-o", f"{url}/deny/x"],
+        input="Merhaba\n",
+        capture_output=True,
+        text=True,
+        encoding="utf-8",
+    )
+    assert denied.returncode != 0
+    assert "HTTP 403" in denied.stderr
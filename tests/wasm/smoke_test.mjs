@@ -0,0 +1,25 @@
+// Smoke test for the WebAssembly module; run by scripts/build_wasm.sh --check
+
+import { createRequire } from "node:module";
+import { resolve } from "node:path";
+
+// Node.js bindings generated by wasm-bindgen --target nodejs
+const [path] = process.argv.slice(2);
+const durak = createRequire(import.meta.url)(resolve(path));
+
+const checks = [
+    ["normalize", durak.normalize("İSTANBUL IRMAK"), "istanbul ırmak"],
+    ["lemmatize", durak.lemmatize("Kitaplar"), "kitap"],
+    ["tokenize", durak.tokenize("Ankara'ya gittim.").join("|"), "Ankara'ya|gittim|."],
+];
+let ok = true;
+for (const [label, actual, expected] of checks) {
+    if (actual !== expected) {
+        console.error(`${label}: expected "${expected}", got "${actual}"`);
+        ok = false;
+    }
+}
+if (!ok) {
+    process.exit(1);
+}
+console.log("durak: WebAssembly interface OK");